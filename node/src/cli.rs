@@ -49,4 +49,7 @@ pub enum Subcommand {
 
     /// Db meta columns information.
     ChainInfo(sc_cli::ChainInfoCmd),
+
+    /// 导出 Subsquid 索引器所需的运行时元数据（specVersions jsonl）
+    ExportSquidMetadata(crate::squid::ExportSquidMetadataCmd),
 }
@@ -198,6 +198,7 @@ pub fn run() -> sc_cli::Result<()> {
             let runner = cli.create_runner(cmd)?;
             runner.sync_run(|config| cmd.run::<Block>(&config))
         }
+        Some(Subcommand::ExportSquidMetadata(cmd)) => cmd.run(),
         None => {
             let runner = cli.create_runner(&cli.run)?;
 
@@ -7,9 +7,16 @@
 
 use std::sync::Arc;
 
+mod divination;
+mod privacy;
+mod scale_json;
+
+pub use divination::{Divination, DivinationApiServer};
+pub use privacy::{Privacy, PrivacyApiServer};
+
 use jsonrpsee::RpcModule;
 use sc_transaction_pool_api::TransactionPool;
-use solochain_template_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use solochain_template_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Nonce};
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
@@ -33,6 +40,15 @@ where
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: BlockBuilder<Block>,
+    C::Api: pallet_meihua::runtime_api::MeihuaApi<Block, AccountId>,
+    C::Api: pallet_bazi_chart::runtime_api::BaziChartApi<Block, AccountId>,
+    C::Api: pallet_liuyao::runtime_api::LiuYaoApi<Block, AccountId>,
+    C::Api: pallet_qimen::runtime_api::QimenInterpretationApi<Block>,
+    C::Api: pallet_ziwei::runtime_api::ZiweiInterpretationApi<Block>,
+    C::Api: pallet_daliuren::runtime_api::DaLiuRenApi<Block, AccountId>,
+    C::Api: pallet_xiaoliuren::runtime_api::XiaoLiuRenInterpretationApi<Block>,
+    C::Api: pallet_tarot::runtime_api::TarotApi<Block, AccountId>,
+    C::Api: pallet_divination_privacy::runtime_api::DivinationPrivacyApi<Block, AccountId, BlockNumber>,
     P: TransactionPool + 'static,
 {
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
@@ -42,12 +58,11 @@ where
     let FullDeps { client, pool } = deps;
 
     module.merge(System::new(client.clone(), pool).into_rpc())?;
-    module.merge(TransactionPayment::new(client).into_rpc())?;
+    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 
-    // Extend this RPC with a custom API by using the following syntax.
-    // `YourRpcStruct` should have a reference to a client, which is needed
-    // to call into the runtime.
-    // `module.merge(YourRpcTrait::into_rpc(YourRpcStruct::new(ReferenceToClient, ...)))?;`
+    // 占卜查询（divination_*）与隐私授权查询（privacy_*）
+    module.merge(Divination::new(client.clone()).into_rpc())?;
+    module.merge(Privacy::new(client).into_rpc())?;
 
     // You probably want to enable the `rpc v2 chainSpec` API as well
     //
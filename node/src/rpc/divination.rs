@@ -0,0 +1,149 @@
+//! 占卜查询 RPC（`divination_*`）
+//!
+//! 把各占卜 pallet 的 Runtime API 统一为一个按占卜类型分发的 JSON-RPC 方法，
+//! 客户端无需再手工拼装 `state_call` 的方法名与 SCALE 参数，也无需自行解码返回值。
+
+use std::sync::Arc;
+
+use codec::{Decode, Encode};
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use scale_info::TypeInfo;
+use serde_json::Value;
+use solochain_template_runtime::{opaque::Block, AccountId};
+use sp_api::{ApiError, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+use pallet_bazi_chart::runtime_api::BaziChartApi;
+use pallet_daliuren::runtime_api::DaLiuRenApi;
+use pallet_divination_common::DivinationType;
+use pallet_liuyao::runtime_api::LiuYaoApi;
+use pallet_meihua::runtime_api::MeihuaApi;
+use pallet_qimen::runtime_api::QimenInterpretationApi;
+use pallet_tarot::runtime_api::TarotApi;
+use pallet_xiaoliuren::runtime_api::XiaoLiuRenInterpretationApi;
+use pallet_ziwei::runtime_api::ZiweiInterpretationApi;
+
+use super::scale_json::to_json;
+
+/// 运行时调用失败
+const RUNTIME_ERROR: i32 = 1;
+/// 参数无效（占卜类型、事项类型）
+const INVALID_PARAMS: i32 = 2;
+/// 返回值无法转换为 JSON
+const ENCODING_ERROR: i32 = 3;
+
+/// 占卜查询 RPC 接口
+#[rpc(server, namespace = "divination")]
+pub trait DivinationApi<BlockHash> {
+    /// 按占卜类型查询排盘/解卦结果
+    ///
+    /// - `divination_type`: 与 `DivinationType` 编码一致（0=梅花 1=八字 2=六爻 3=奇门
+    ///   4=紫微 6=大六壬 7=小六壬 8=塔罗）
+    /// - `id`: 各 pallet 内的卦象/命盘/式盘/占卜记录 ID
+    /// - `shi_xiang`: 占问事项（六爻、奇门、大六壬使用，缺省为 0）
+    ///
+    /// 记录不存在或 Private 模式无计算数据时返回 `null`。
+    #[method(name = "getChart")]
+    fn get_chart(
+        &self,
+        divination_type: u8,
+        id: u64,
+        shi_xiang: Option<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Value>>;
+}
+
+/// 占卜查询 RPC 实现
+pub struct Divination<C> {
+    client: Arc<C>,
+}
+
+impl<C> Divination<C> {
+    /// 创建实例
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> DivinationApiServer<<Block as BlockT>::Hash> for Divination<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: MeihuaApi<Block, AccountId>
+        + BaziChartApi<Block, AccountId>
+        + LiuYaoApi<Block, AccountId>
+        + QimenInterpretationApi<Block>
+        + ZiweiInterpretationApi<Block>
+        + DaLiuRenApi<Block, AccountId>
+        + XiaoLiuRenInterpretationApi<Block>
+        + TarotApi<Block, AccountId>,
+{
+    fn get_chart(
+        &self,
+        divination_type: u8,
+        id: u64,
+        shi_xiang: Option<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<Value>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let shi_xiang = shi_xiang.unwrap_or(0);
+
+        let dt = DivinationType::from_u8(divination_type)
+            .ok_or_else(|| invalid_params(format!("unknown divination type {}", divination_type)))?;
+
+        match dt {
+            DivinationType::Meihua => json(MeihuaApi::get_hexagram_detail(&*api, at, id)),
+            DivinationType::Bazi => json(BaziChartApi::get_interpretation(&*api, at, id)),
+            DivinationType::Liuyao => {
+                json(LiuYaoApi::get_full_interpretation(&*api, at, id, shi_xiang))
+            }
+            DivinationType::Qimen => {
+                let question_type = decode_enum(shi_xiang)?;
+                json(QimenInterpretationApi::get_full_interpretation(&*api, at, id, question_type))
+            }
+            DivinationType::Ziwei => json(ZiweiInterpretationApi::get_interpretation(&*api, at, id)),
+            DivinationType::Daliuren => {
+                let shi_xiang_type = decode_enum(shi_xiang)?;
+                json(DaLiuRenApi::get_full_interpretation(&*api, at, id, Some(shi_xiang_type)))
+            }
+            DivinationType::XiaoLiuRen => {
+                json(XiaoLiuRenInterpretationApi::get_interpretation(&*api, at, id))
+            }
+            DivinationType::Tarot => json(TarotApi::get_full_interpretation(&*api, at, id)),
+            DivinationType::Taiyi => {
+                Err(invalid_params(format!("divination type {} is not implemented", divination_type)))
+            }
+        }
+    }
+}
+
+/// 运行时返回值转换为 JSON
+fn json<T: Encode + TypeInfo + 'static>(
+    result: Result<Option<T>, ApiError>,
+) -> RpcResult<Option<Value>> {
+    match result.map_err(runtime_error)? {
+        Some(value) => to_json(&value)
+            .map(Some)
+            .map_err(|e| ErrorObject::owned(ENCODING_ERROR, "Unable to encode result", Some(e))),
+        None => Ok(None),
+    }
+}
+
+/// 将 u8 事项编码解析为 pallet 内的事项枚举
+fn decode_enum<T: Decode>(value: u8) -> RpcResult<T> {
+    T::decode(&mut &[value][..]).map_err(|_| invalid_params(format!("invalid shi_xiang {}", value)))
+}
+
+fn invalid_params(message: String) -> ErrorObjectOwned {
+    ErrorObject::owned(INVALID_PARAMS, message, None::<()>)
+}
+
+/// 运行时调用错误
+pub(super) fn runtime_error(err: ApiError) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, "Runtime API call failed", Some(err.to_string()))
+}
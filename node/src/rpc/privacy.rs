@@ -0,0 +1,166 @@
+//! 隐私授权查询 RPC（`privacy_*`）
+//!
+//! 封装 `DivinationPrivacyApi`，账户参数与返回值中的账户统一使用 SS58 地址。
+
+use std::sync::Arc;
+
+use codec::Decode;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::Serialize;
+use solochain_template_runtime::{opaque::Block, AccountId, BlockNumber};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::crypto::Ss58Codec;
+use sp_runtime::traits::Block as BlockT;
+
+use pallet_divination_privacy::{runtime_api::DivinationPrivacyApi, types::AuthorizationInfo};
+
+use super::divination::runtime_error;
+
+/// 授权记录（JSON 返回）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Authorization {
+    /// 被授权账户（SS58）
+    pub grantee: String,
+    /// 授权角色（0=所有者 1=命理师 2=家族 3=AI 4=悬赏回答者）
+    pub role: u8,
+    /// 访问范围（0=只读 1=可评论 2=完全访问）
+    pub scope: u8,
+    /// 授权区块
+    pub granted_at: u64,
+    /// 过期区块（0 表示永久）
+    pub expires_at: u64,
+    /// 关联悬赏 ID
+    pub bounty_id: Option<u64>,
+}
+
+impl From<AuthorizationInfo> for Authorization {
+    fn from(info: AuthorizationInfo) -> Self {
+        let grantee = AccountId::decode(&mut &info.grantee[..])
+            .map(|account| account.to_ss58check())
+            .unwrap_or_else(|_| format!("0x{}", sp_core::hexdisplay::HexDisplay::from(&info.grantee)));
+
+        Self {
+            grantee,
+            role: info.role,
+            scope: info.scope,
+            granted_at: info.granted_at,
+            expires_at: info.expires_at,
+            bounty_id: info.bounty_id,
+        }
+    }
+}
+
+/// 隐私授权查询 RPC 接口
+#[rpc(server, namespace = "privacy")]
+pub trait PrivacyApi<BlockHash> {
+    /// 获取记录的全部授权（含所有者）
+    #[method(name = "getAuthorizations")]
+    fn get_authorizations(
+        &self,
+        divination_type: u8,
+        result_id: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Authorization>>;
+
+    /// 检查账户是否可访问记录
+    #[method(name = "hasAccess")]
+    fn has_access(
+        &self,
+        divination_type: u8,
+        result_id: u64,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<bool>;
+
+    /// 获取用户的加密记录 ID（不指定类型时返回全部）
+    #[method(name = "getUserEncryptedRecords")]
+    fn get_user_encrypted_records(
+        &self,
+        account: AccountId,
+        divination_type: Option<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u64>>;
+
+    /// 获取服务提供者被授权的记录 `(占卜类型, 结果 ID)`
+    #[method(name = "getProviderGrants")]
+    fn get_provider_grants(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(u8, u64)>>;
+}
+
+/// 隐私授权查询 RPC 实现
+pub struct Privacy<C> {
+    client: Arc<C>,
+}
+
+impl<C> Privacy<C> {
+    /// 创建实例
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> PrivacyApiServer<<Block as BlockT>::Hash> for Privacy<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DivinationPrivacyApi<Block, AccountId, BlockNumber>,
+{
+    fn get_authorizations(
+        &self,
+        divination_type: u8,
+        result_id: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Authorization>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let auths = self
+            .client
+            .runtime_api()
+            .get_authorizations(at, divination_type, result_id)
+            .map_err(runtime_error)?;
+
+        Ok(auths.into_iter().map(Authorization::from).collect())
+    }
+
+    fn has_access(
+        &self,
+        divination_type: u8,
+        result_id: u64,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<bool> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .has_access(at, divination_type, result_id, account)
+            .map_err(runtime_error)
+    }
+
+    fn get_user_encrypted_records(
+        &self,
+        account: AccountId,
+        divination_type: Option<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u64>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .get_user_encrypted_records(at, account, divination_type)
+            .map_err(runtime_error)
+    }
+
+    fn get_provider_grants(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(u8, u64)>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .get_provider_grants(at, account)
+            .map_err(runtime_error)
+    }
+}
@@ -0,0 +1,192 @@
+//! SCALE → JSON 转换
+//!
+//! 占卜 pallet 的返回结构大多只派生了 `Encode + Decode + TypeInfo`（no_std 下不带 serde），
+//! 这里按类型自身的 `TypeInfo` 构造类型注册表，把 SCALE 编码逐字段还原为 JSON：
+//! - 具名字段结构体 → 对象；单个匿名字段 → 内层值；多个匿名字段 / 元组 → 数组
+//! - `Option` → `null` 或内层值；无字段枚举 → 变体名字符串；带字段枚举 → `{ 变体名: 值 }`
+//! - 定长 `u8` 数组（哈希、公钥、账户）→ `0x` 十六进制
+//! - `Vec<u8>` → 合法 UTF-8 时为字符串（名称、CID、文本），否则为 `0x` 十六进制
+//! - `u128` / `i128` → 十进制字符串（避免 JS 精度丢失）
+
+use codec::{Compact, Decode, Encode};
+use scale_info::{
+    form::PortableForm, meta_type, PortableRegistry, Registry, TypeDef, TypeDefPrimitive, TypeInfo,
+};
+use serde_json::{Map, Value};
+
+/// 将带类型信息的值转换为 JSON
+pub fn to_json<T: Encode + TypeInfo + 'static>(value: &T) -> Result<Value, String> {
+    let mut registry = Registry::new();
+    let type_id = registry.register_type(&meta_type::<T>()).id;
+    let registry: PortableRegistry = registry.into();
+
+    let encoded = value.encode();
+    let mut input = &encoded[..];
+    let json = decode_value(&registry, type_id, &mut input)?;
+
+    if !input.is_empty() {
+        return Err(format!("{} trailing bytes after decoding", input.len()));
+    }
+    Ok(json)
+}
+
+fn decode_value(registry: &PortableRegistry, type_id: u32, input: &mut &[u8]) -> Result<Value, String> {
+    let ty = registry
+        .resolve(type_id)
+        .ok_or_else(|| format!("type {} not found in registry", type_id))?;
+
+    match &ty.type_def {
+        TypeDef::Composite(composite) => decode_fields(registry, &composite.fields, input),
+        TypeDef::Variant(variant) => {
+            let index = u8::decode(input).map_err(|e| e.to_string())?;
+            let var = variant
+                .variants
+                .iter()
+                .find(|v| v.index == index)
+                .ok_or_else(|| format!("invalid variant index {} for {:?}", index, ty.path))?;
+
+            // Option<T>：None → null，Some(x) → x
+            if ty.path.ident().as_deref() == Some("Option") {
+                return match var.fields.first() {
+                    Some(field) => decode_value(registry, field.ty.id, input),
+                    None => Ok(Value::Null),
+                };
+            }
+
+            if var.fields.is_empty() {
+                Ok(Value::String(var.name.clone()))
+            } else {
+                let inner = decode_fields(registry, &var.fields, input)?;
+                let mut map = Map::new();
+                map.insert(var.name.clone(), inner);
+                Ok(Value::Object(map))
+            }
+        }
+        TypeDef::Sequence(seq) => {
+            let len = Compact::<u32>::decode(input).map_err(|e| e.to_string())?.0 as usize;
+            if is_u8(registry, seq.type_param.id) {
+                let bytes = take(input, len)?;
+                return Ok(match std::str::from_utf8(bytes) {
+                    Ok(text) if !text.chars().any(|c| c.is_control()) => Value::String(text.to_string()),
+                    _ => Value::String(hex(bytes)),
+                });
+            }
+            decode_list(registry, seq.type_param.id, len, input)
+        }
+        TypeDef::Array(array) => {
+            let len = array.len as usize;
+            if is_u8(registry, array.type_param.id) {
+                return Ok(Value::String(hex(take(input, len)?)));
+            }
+            decode_list(registry, array.type_param.id, len, input)
+        }
+        TypeDef::Tuple(tuple) => {
+            if tuple.fields.is_empty() {
+                return Ok(Value::Null);
+            }
+            tuple
+                .fields
+                .iter()
+                .map(|f| decode_value(registry, f.id, input))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array)
+        }
+        TypeDef::Primitive(primitive) => decode_primitive(primitive, input),
+        TypeDef::Compact(_) => {
+            let value = Compact::<u128>::decode(input).map_err(|e| e.to_string())?.0;
+            Ok(u128_to_json(value))
+        }
+        TypeDef::BitSequence(_) => Err("bit sequences are not supported".into()),
+    }
+}
+
+fn decode_fields(
+    registry: &PortableRegistry,
+    fields: &[scale_info::Field<PortableForm>],
+    input: &mut &[u8],
+) -> Result<Value, String> {
+    match fields {
+        [] => Ok(Value::Null),
+        [single] if single.name.is_none() => decode_value(registry, single.ty.id, input),
+        _ if fields.iter().all(|f| f.name.is_some()) => {
+            let mut map = Map::new();
+            for field in fields {
+                let name = field.name.clone().unwrap_or_default();
+                map.insert(name, decode_value(registry, field.ty.id, input)?);
+            }
+            Ok(Value::Object(map))
+        }
+        _ => fields
+            .iter()
+            .map(|f| decode_value(registry, f.ty.id, input))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+    }
+}
+
+fn decode_list(
+    registry: &PortableRegistry,
+    item_type: u32,
+    len: usize,
+    input: &mut &[u8],
+) -> Result<Value, String> {
+    (0..len)
+        .map(|_| decode_value(registry, item_type, input))
+        .collect::<Result<Vec<_>, _>>()
+        .map(Value::Array)
+}
+
+fn decode_primitive(primitive: &TypeDefPrimitive, input: &mut &[u8]) -> Result<Value, String> {
+    fn dec<T: Decode>(input: &mut &[u8]) -> Result<T, String> {
+        T::decode(input).map_err(|e| e.to_string())
+    }
+
+    Ok(match primitive {
+        TypeDefPrimitive::Bool => Value::Bool(dec::<bool>(input)?),
+        TypeDefPrimitive::Char => {
+            let code = dec::<u32>(input)?;
+            Value::String(char::from_u32(code).map(String::from).unwrap_or_default())
+        }
+        TypeDefPrimitive::Str => Value::String(dec::<String>(input)?),
+        TypeDefPrimitive::U8 => dec::<u8>(input)?.into(),
+        TypeDefPrimitive::U16 => dec::<u16>(input)?.into(),
+        TypeDefPrimitive::U32 => dec::<u32>(input)?.into(),
+        TypeDefPrimitive::U64 => dec::<u64>(input)?.into(),
+        TypeDefPrimitive::U128 => Value::String(dec::<u128>(input)?.to_string()),
+        TypeDefPrimitive::I8 => dec::<i8>(input)?.into(),
+        TypeDefPrimitive::I16 => dec::<i16>(input)?.into(),
+        TypeDefPrimitive::I32 => dec::<i32>(input)?.into(),
+        TypeDefPrimitive::I64 => dec::<i64>(input)?.into(),
+        TypeDefPrimitive::I128 => Value::String(dec::<i128>(input)?.to_string()),
+        TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => {
+            Value::String(hex(take(input, 32)?))
+        }
+    })
+}
+
+fn is_u8(registry: &PortableRegistry, type_id: u32) -> bool {
+    registry
+        .resolve(type_id)
+        .map(|ty| matches!(ty.type_def, TypeDef::Primitive(TypeDefPrimitive::U8)))
+        .unwrap_or(false)
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], String> {
+    if input.len() < len {
+        return Err("unexpected end of input".into());
+    }
+    let (head, tail) = input.split_at(len);
+    *input = tail;
+    Ok(head)
+}
+
+fn u128_to_json(value: u128) -> Value {
+    u64::try_from(value)
+        .map(Value::from)
+        .unwrap_or_else(|_| Value::String(value.to_string()))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    let digits: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("0x{}", digits)
+}
@@ -0,0 +1,61 @@
+//! Subsquid 索引器元数据导出
+//!
+//! 索引器（stardust-squid）的类型生成需要每个 spec 版本的运行时元数据，
+//! 格式为 squid-substrate-typegen 使用的 specVersions jsonl：
+//! `{"specName", "specVersion", "blockNumber", "blockHash", "metadata"}`。
+//! 每次运行时升级后执行一次本命令并追加到 `stardust-squid/metadata/stardust.jsonl`，
+//! 再重新生成类型，即可保证 mappings 与链上事件定义同步。
+
+use std::{io::Write, path::PathBuf};
+
+use sp_core::hexdisplay::HexDisplay;
+use solochain_template_runtime::{Runtime, VERSION};
+
+/// 元数据版本（V15 包含事件文档与类型注册表，typegen 可直接使用）
+const SQUID_METADATA_VERSION: u32 = 15;
+
+/// 导出当前运行时的 Subsquid specVersions 记录
+#[derive(Debug, clap::Parser)]
+pub struct ExportSquidMetadataCmd {
+    /// 输出文件（追加写入）；不指定时输出到 stdout
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+
+    /// 该 spec 版本在链上生效的区块号（升级区块）
+    #[arg(long, default_value_t = 0)]
+    pub block_number: u32,
+
+    /// 生效区块的哈希（0x 前缀）；创世版本填写创世哈希
+    #[arg(long, default_value = "0x0000000000000000000000000000000000000000000000000000000000000000")]
+    pub block_hash: String,
+}
+
+impl ExportSquidMetadataCmd {
+    /// 执行导出
+    pub fn run(&self) -> sc_cli::Result<()> {
+        let metadata = Runtime::metadata_at_version(SQUID_METADATA_VERSION)
+            .ok_or_else(|| format!("Runtime does not support metadata V{}", SQUID_METADATA_VERSION))?;
+
+        let record = serde_json::json!({
+            "specName": VERSION.spec_name.to_string(),
+            "specVersion": VERSION.spec_version,
+            "blockNumber": self.block_number,
+            "blockHash": self.block_hash,
+            "metadata": format!("0x{}", HexDisplay::from(&*metadata)),
+        });
+        let line = format!("{}\n", record);
+
+        match &self.output {
+            Some(path) => {
+                let mut file = std::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)?;
+                file.write_all(line.as_bytes())?;
+            }
+            None => std::io::stdout().write_all(line.as_bytes())?,
+        }
+
+        Ok(())
+    }
+}
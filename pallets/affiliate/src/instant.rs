@@ -34,9 +34,28 @@ impl<T: Config> Pallet<T> {
 
         let mut total_distributed = BalanceOf::<T>::zero();
         let levels_to_process = levels.min(15) as usize;
+        let current_cycle = Self::current_cycle();
+
+        // 🆕 逐层暂扣归属（被标记子树的收益留存托管账户）
+        let withholding = Self::withholding_flags_for_chain(&referral_chain);
 
         // 逐层分配
         for (index, referrer) in referral_chain.iter().enumerate().take(levels_to_process) {
+            // 验证推荐人资格
+            if !Self::is_valid_referrer(referrer, index as u8 + 1) {
+                // 无效推荐人，份额并入国库
+                continue;
+            }
+
+            // 🆕 记录分层业绩（与比例无关，供提案模拟使用）
+            Self::note_level_volume(
+                current_cycle,
+                crate::types::PayoutTable::Instant,
+                referrer,
+                index,
+                distributable_amount,
+            );
+
             // 获取该层分成比例
             let percent = if let Some(p) = level_percents.get(index) {
                 *p
@@ -48,12 +67,6 @@ impl<T: Config> Pallet<T> {
                 continue;
             }
 
-            // 验证推荐人资格
-            if !Self::is_valid_referrer(referrer, index as u8 + 1) {
-                // 无效推荐人，份额并入国库
-                continue;
-            }
-
             // 计算分成金额
             let share = Self::calculate_share(distributable_amount, percent);
 
@@ -61,6 +74,12 @@ impl<T: Config> Pallet<T> {
                 continue;
             }
 
+            // 🆕 被标记子树：暂扣，待申诉复核
+            if let Some(flag_id) = withholding.get(index).copied().flatten() {
+                Self::withhold_payout(flag_id, referrer, share);
+                continue;
+            }
+
             // 立即转账
             if let Err(_) = T::Currency::transfer(
                 &T::EscrowPalletId::get().into_account_truncating(),
@@ -99,6 +118,7 @@ impl<T: Config> Pallet<T> {
     ///
     /// 验证：
     /// - 是否为有效会员
+    /// - 🆕 是否通过身份/信用校验
     /// - 可拿代数是否覆盖该层
     ///
     /// 返回：是否有效
@@ -108,6 +128,11 @@ impl<T: Config> Pallet<T> {
             return false;
         }
 
+        // 🆕 验证：已通过身份/信用校验
+        if !T::Eligibility::is_verified(referrer) {
+            return false;
+        }
+
         // 验证：可拿代数（假设所有会员都可拿15层，简化实现）
         // 如需动态可拿代数，可从 MembershipProvider 获取
         if level > 15 {
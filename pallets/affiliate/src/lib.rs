@@ -25,6 +25,7 @@
 //! │  ⚡ 即时分成      →  instant.rs                           │
 //! │  📅 周结算        →  weekly.rs                            │
 //! │  📊 统一分配入口  →  distribute.rs                        │
+//! │  🧪 结算模拟      →  simulation.rs（🆕 dry-run）          │
 //! └──────────────────────────────────────────────────────────┘
 //! ```
 //!
@@ -47,6 +48,9 @@ mod escrow;
 mod instant;
 mod weekly;
 mod distribute;
+mod simulation;  // 🆕 结算模拟（dry-run）
+mod sybil;  // 🆕 反女巫（速率限制 / 聚类标记 / 收益暂扣）
+pub mod runtime_api;  // 🆕 结算模拟 Runtime API
 pub mod governance;  // 新增：治理模块，使用 pub mod 避免重复导出
 
 // 导出特定的治理类型，避免冲突
@@ -148,6 +152,32 @@ pub mod pallet {
 
         /// 存储费用账户
         type StorageAccount: Get<Self::AccountId>;
+
+        /// 🆕 提案模拟摘要最多计入的账户数（限制链上计算量）
+        #[pallet::constant]
+        type MaxSimulationAccounts: Get<u32>;
+
+        /// 🆕 推荐资格校验（身份判定 / 信用分），未通过者不能认领推荐码、不能领取推荐收益
+        type Eligibility: ReferralEligibility<Self::AccountId>;
+
+        /// 🆕 风控预言机权限（上报资金来源、人工标记子树）
+        type SybilOracleOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// 🆕 推荐人绑定速率统计窗口（区块数）
+        #[pallet::constant]
+        type BindWindow: Get<BlockNumberFor<Self>>;
+
+        /// 🆕 单个推荐人在一个窗口内最多可被绑定的次数
+        #[pallet::constant]
+        type MaxBindsPerWindow: Get<u32>;
+
+        /// 🆕 同一区块内绑定同一推荐人达到该次数即自动标记
+        #[pallet::constant]
+        type SameBlockBindThreshold: Get<u32>;
+
+        /// 🆕 同一推荐人下同一资金来源的直推数达到该值即自动标记
+        #[pallet::constant]
+        type FundingClusterThreshold: Get<u32>;
     }
 
     // ========================================
@@ -272,6 +302,99 @@ pub mod pallet {
     #[pallet::storage]
     pub type TotalWeeklyDistributed<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+    // === 🆕 结算模拟存储（2个）===
+
+    /// 分层业绩：(周编号, 分配管线, 推荐人) → 每层累计可分配金额
+    ///
+    /// 用于以任意比例重算该周期应得（dry-run），过期后可由任何人清理
+    #[pallet::storage]
+    pub type CycleLevelVolume<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Twox64Concat, u32>,  // cycle
+            NMapKey<Twox64Concat, types::PayoutTable>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+        ),
+        BoundedVec<BalanceOf<T>, ConstU32<15>>,
+        ValueQuery,
+    >;
+
+    /// 提案模拟摘要：提案ID → 摘要（创建时生成，讨论/投票期可刷新）
+    #[pallet::storage]
+    pub type ProposalSimulations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        types::SimulationSummary<BalanceOf<T>>,
+    >;
+
+    // === 🆕 反女巫存储（8个）===
+
+    /// 下一个女巫标记ID
+    #[pallet::storage]
+    pub type NextFlagId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// 女巫标记：标记ID → 标记详情
+    #[pallet::storage]
+    pub type SybilFlags<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        types::SybilFlag<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
+    >;
+
+    /// 子树根账户 → 生效中的标记ID（解除后移除）
+    #[pallet::storage]
+    pub type FlagOf<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64>;
+
+    /// 暂扣收益：(标记ID, 账户) → 暂扣金额（资金留存托管账户）
+    #[pallet::storage]
+    pub type Withheld<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u64,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// 推荐人绑定速率：推荐人 → (窗口起始区块, 窗口内绑定次数)
+    #[pallet::storage]
+    pub type BindVelocity<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (BlockNumberFor<T>, u32),
+        ValueQuery,
+    >;
+
+    /// 同区块绑定计数：推荐人 → (区块号, 该区块内绑定次数)
+    #[pallet::storage]
+    pub type SameBlockBinds<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (BlockNumberFor<T>, u32),
+        ValueQuery,
+    >;
+
+    /// 资金来源：账户 → 首笔入金来源账户（仅由风控预言机上报，链上不自动记录）
+    #[pallet::storage]
+    pub type FundingSourceOf<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
+
+    /// 资金聚类计数：(推荐人, 资金来源) → 直推中同源账户数
+    #[pallet::storage]
+    pub type ClusterCount<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+        ValueQuery,
+    >;
+
     // === 治理存储（12个）===
 
     /// 下一个提案ID
@@ -579,6 +702,50 @@ pub mod pallet {
         GovernanceResumed {
             by: BoundedVec<u8, ConstU32<32>>,
         },
+        /// 🆕 提案模拟摘要已生成/刷新
+        ProposalSimulated {
+            proposal_id: u64,
+            cycle: u32,
+            current_total: BalanceOf<T>,
+            simulated_total: BalanceOf<T>,
+            gainers: u32,
+            losers: u32,
+        },
+        /// 🆕 过期周期业绩已清理
+        CycleVolumePruned {
+            cycle: u32,
+            table: types::PayoutTable,
+            removed: u32,
+            done: bool,
+        },
+        /// 🆕 推荐子树已被标记为疑似女巫（子树收益暂扣，待申诉复核）
+        SybilFlagged {
+            flag_id: u64,
+            root: T::AccountId,
+            reason: types::SybilReason,
+        },
+        /// 🆕 资金来源已上报
+        FundingSourcesReported {
+            count: u32,
+        },
+        /// 🆕 推荐收益已暂扣
+        PayoutWithheld {
+            flag_id: u64,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// 🆕 女巫标记已复核（cleared = true 解除；false 确认）
+        SybilFlagResolved {
+            flag_id: u64,
+            cleared: bool,
+        },
+        /// 🆕 暂扣收益已处理（解除则发还原账户，确认则没收至国库）
+        WithheldReleased {
+            flag_id: u64,
+            count: u32,
+            total: BalanceOf<T>,
+            done: bool,
+        },
 
         // ========================================
         // 年费价格治理事件（🆕）
@@ -708,6 +875,20 @@ pub mod pallet {
         GovernancePausedError,
         /// 权限不足
         InsufficientAuthority,
+        /// 🆕 周期业绩仍在保留期内（当前周期与上一周期）
+        CycleVolumeRetained,
+        /// 🆕 未通过身份/信用校验
+        NotVerified,
+        /// 🆕 推荐人绑定过于频繁
+        SponsorRateLimited,
+        /// 🆕 女巫标记不存在
+        FlagNotFound,
+        /// 🆕 标记已复核，不可重复处理
+        FlagNotPending,
+        /// 🆕 标记待复核，暂扣收益不可释放
+        FlagStillPending,
+        /// 🆕 该账户已有生效中的标记
+        AlreadyFlagged,
 
         // === 年费价格治理错误（🆕）===
         /// 年费价格超出范围 (10-1000 USDT)
@@ -900,7 +1081,11 @@ pub mod pallet {
         /// - `description_cid`: 提案详情 IPFS CID
         /// - `rationale_cid`: 提案理由 IPFS CID
         #[pallet::call_index(50)]
-        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::weight(
+            Weight::from_parts(10_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(6, 6))
+                .saturating_add(Pallet::<T>::simulation_snapshot_weight())
+        )]
         pub fn propose_percentage_adjustment(
             origin: OriginFor<T>,
             new_percentages: types::LevelPercents,
@@ -980,6 +1165,9 @@ pub mod pallet {
                 is_major: false, // 🔥 2025-11-13：统一设为false，所有提案都是全民投票
             });
 
+            // 🆕 附加模拟摘要：让投票者看到谁受益、谁受损
+            Self::snapshot_proposal_simulation(proposal_id, &new_percentages);
+
             Ok(())
         }
 
@@ -1138,6 +1326,54 @@ pub mod pallet {
             Ok(())
         }
 
+        /// 🆕 刷新提案模拟摘要（任何人可调用）
+        ///
+        /// 以上一完整周期的业绩重新生成摘要；仅讨论期/投票期的提案可刷新
+        #[pallet::call_index(53)]
+        #[pallet::weight(
+            Weight::from_parts(5_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(1))
+                .saturating_add(Pallet::<T>::simulation_snapshot_weight())
+        )]
+        pub fn refresh_proposal_simulation(origin: OriginFor<T>, proposal_id: u64) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let proposal = ActiveProposals::<T>::get(proposal_id)
+                .ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(
+                matches!(
+                    proposal.status,
+                    governance::ProposalStatus::Discussion | governance::ProposalStatus::Voting
+                ),
+                Error::<T>::VotingNotActive
+            );
+
+            Self::snapshot_proposal_simulation(proposal_id, &proposal.new_percentages);
+
+            Ok(())
+        }
+
+        /// 🆕 清理过期周期业绩（任何人可调用）
+        ///
+        /// 参数:
+        /// - `cycle`: 周编号（须早于上一周期）
+        /// - `table`: 分配管线（即时 / 周结算）
+        /// - `limit`: 本次最多清理条数
+        #[pallet::call_index(54)]
+        #[pallet::weight(Weight::from_parts(1_000_000u64.saturating_mul(*limit as u64).saturating_add(5_000_000), 0))]
+        pub fn prune_cycle_volume(
+            origin: OriginFor<T>,
+            cycle: u32,
+            table: types::PayoutTable,
+            limit: u32,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            Self::do_prune_cycle_volume(cycle, table, limit)?;
+
+            Ok(())
+        }
+
         /// 紧急暂停治理（仅技术委员会超级多数可调用）
         #[pallet::call_index(60)]
         #[pallet::weight(Weight::from_parts(2_000_000, 0))]
@@ -1407,6 +1643,67 @@ pub mod pallet {
 
             Ok(())
         }
+
+        // ========================================
+        // 反女巫接口（🆕）
+        // ========================================
+
+        /// 上报资金来源（风控预言机）
+        ///
+        /// - entries: (账户, 首笔入金来源)；已上报过的账户忽略
+        /// - 同一推荐人下同源直推达到阈值时自动标记该推荐人子树
+        #[pallet::call_index(80)]
+        #[pallet::weight(Weight::from_parts(20_000_000, 0).saturating_mul(entries.len() as u64 + 1))]
+        pub fn report_funding_sources(
+            origin: OriginFor<T>,
+            entries: BoundedVec<(T::AccountId, T::AccountId), ConstU32<100>>,
+        ) -> DispatchResult {
+            T::SybilOracleOrigin::ensure_origin(origin)?;
+            Self::do_report_funding_sources(entries.into_inner());
+            Ok(())
+        }
+
+        /// 人工标记推荐子树（风控预言机）
+        #[pallet::call_index(81)]
+        #[pallet::weight(Weight::from_parts(20_000_000, 0))]
+        pub fn flag_subtree(
+            origin: OriginFor<T>,
+            root: T::AccountId,
+            reason: types::SybilReason,
+        ) -> DispatchResult {
+            T::SybilOracleOrigin::ensure_origin(origin)?;
+            ensure!(!FlagOf::<T>::contains_key(&root), Error::<T>::AlreadyFlagged);
+            Self::do_flag_subtree(root, reason);
+            Ok(())
+        }
+
+        /// 复核女巫标记（治理 / 申诉裁决路由）
+        ///
+        /// - cleared = true：解除标记，暂扣收益可发还
+        /// - cleared = false：确认女巫，暂扣收益可没收至国库，子树继续暂扣
+        #[pallet::call_index(82)]
+        #[pallet::weight(Weight::from_parts(20_000_000, 0))]
+        pub fn gov_resolve_flag(
+            origin: OriginFor<T>,
+            flag_id: u64,
+            cleared: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::do_resolve_flag(flag_id, cleared)
+        }
+
+        /// 处理已复核标记的暂扣收益（任何人可调用，分页）
+        #[pallet::call_index(83)]
+        #[pallet::weight(Weight::from_parts(30_000_000, 0).saturating_mul(*limit as u64 + 1))]
+        pub fn release_withheld(
+            origin: OriginFor<T>,
+            flag_id: u64,
+            limit: u32,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::do_release_withheld(flag_id, limit)?;
+            Ok(())
+        }
     }
 
     // ========================================
@@ -1647,3 +1944,19 @@ pub trait MembershipProvider<AccountId> {
     /// 检查账户是否为有效会员
     fn is_valid_member(who: &AccountId) -> bool;
 }
+
+/// 函数级中文注释：🆕 推荐资格校验 Trait（反女巫）
+///
+/// 由 Runtime 适配：pallet-identity 注册商判定（Reasonable / KnownGood）或 pallet-credit 综合信誉分达标。
+/// 未通过校验的账户不能认领推荐码，也不能领取推荐收益。
+pub trait ReferralEligibility<AccountId> {
+    /// 账户是否已通过身份/信用校验
+    fn is_verified(who: &AccountId) -> bool;
+}
+
+/// 函数级中文注释：默认实现，不做校验
+impl<AccountId> ReferralEligibility<AccountId> for () {
+    fn is_verified(_who: &AccountId) -> bool {
+        true
+    }
+}
@@ -128,6 +128,17 @@ parameter_types! {
     
     /// 函数级中文注释：存储费用账户（测试账户：997）
     pub const StorageAccount: u64 = 997;
+
+    /// 函数级中文注释：提案模拟摘要账户上限
+    pub const MaxSimulationAccounts: u32 = 100;
+
+    /// 函数级中文注释：绑定速率窗口（10 个区块内最多被绑定 4 次）
+    pub const BindWindow: u64 = 10;
+    pub const MaxBindsPerWindow: u32 = 4;
+
+    /// 函数级中文注释：同区块绑定 3 次 / 同源直推 2 个即自动标记
+    pub const SameBlockBindThreshold: u32 = 3;
+    pub const FundingClusterThreshold: u32 = 2;
 }
 
 // ========================================
@@ -148,6 +159,17 @@ impl pallet_affiliate::MembershipProvider<u64> for MockMembershipProvider {
     }
 }
 
+/// 函数级中文注释：模拟推荐资格校验
+///
+/// 测试环境简化规则：账户 ID 800 视为未通过身份/信用校验
+pub struct MockEligibility;
+
+impl pallet_affiliate::ReferralEligibility<u64> for MockEligibility {
+    fn is_verified(who: &u64) -> bool {
+        *who != 800
+    }
+}
+
 // ========================================
 // Affiliate Pallet 配置
 // ========================================
@@ -164,6 +186,13 @@ impl pallet_affiliate::Config for Test {
     type BurnAccount = BurnAccount;
     type TreasuryAccount = TreasuryAccount;
     type StorageAccount = StorageAccount;
+    type MaxSimulationAccounts = MaxSimulationAccounts;
+    type Eligibility = MockEligibility;
+    type SybilOracleOrigin = frame_system::EnsureRoot<u64>;
+    type BindWindow = BindWindow;
+    type MaxBindsPerWindow = MaxBindsPerWindow;
+    type SameBlockBindThreshold = SameBlockBindThreshold;
+    type FundingClusterThreshold = FundingClusterThreshold;
 }
 
 // ========================================
@@ -98,6 +98,11 @@ impl<T: Config> Pallet<T> {
             return false;
         }
 
+        // 🆕 检查身份/信用校验
+        if !T::Eligibility::is_verified(who) {
+            return false;
+        }
+
         // 生成默认推荐码（账户ID前8位十六进制）
         let account_bytes = who.encode();
         let hex_str: sp_std::vec::Vec<u8> = account_bytes
@@ -145,6 +150,7 @@ impl<T: Config> Pallet<T> {
     /// - 推荐码对应的账户存在
     /// - 不能绑定自己
     /// - 不能形成循环
+    /// - 🆕 推荐人未超出绑定速率
     pub(crate) fn do_bind_sponsor(
         who: T::AccountId,
         sponsor_code: Vec<u8>,
@@ -179,9 +185,15 @@ impl<T: Config> Pallet<T> {
             Error::<T>::WouldCreateCycle
         );
 
+        // 🆕 验证：推荐人绑定速率
+        Self::ensure_bind_velocity(&sponsor)?;
+
         // 绑定推荐人
         Sponsors::<T>::insert(&who, &sponsor);
 
+        // 🆕 记录绑定并执行聚类检测
+        Self::note_bind(&who, &sponsor);
+
         // 发射事件
         Self::deposit_event(Event::SponsorBound {
             who: who.clone(),
@@ -199,6 +211,7 @@ impl<T: Config> Pallet<T> {
     ///
     /// 验证：
     /// - 调用者是有效会员
+    /// - 🆕 调用者已通过身份/信用校验
     /// - 推荐码未被占用
     /// - 推荐码长度限制（4-16字符）
     pub(crate) fn do_claim_code(
@@ -211,6 +224,9 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotMember
         );
 
+        // 🆕 验证：已通过身份/信用校验
+        ensure!(T::Eligibility::is_verified(&who), Error::<T>::NotVerified);
+
         // 转换为 BoundedVec
         let code: BoundedVec<u8, T::MaxCodeLen> = code_vec
             .try_into()
@@ -0,0 +1,38 @@
+//! Runtime API 定义
+//!
+//! 本模块定义了联盟计酬结算模拟的 Runtime API：以现行或提议的 `LevelPercents`
+//! 对某周期进行 dry-run，返回逐账户差额与总额，并可查询提案模拟摘要。
+
+use crate::types::{LevelPercents, PayoutTable, SettlementSimulation, SimulationSummary};
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// 联盟计酬结算模拟 Runtime API
+    ///
+    /// 周编号 = floor(区块号 / BlocksPerWeek)；模拟不改变任何链上状态。
+    pub trait AffiliateSimulationApi<AccountId, Balance>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        /// 模拟某周期结算
+        ///
+        /// # 参数
+        /// - `cycle`: 周编号
+        /// - `table`: 被替换的比例表（Weekly=周结算 dry-run，Instant=即时分成/治理提案）
+        /// - `percents`: 模拟比例（None 表示现行比例）
+        /// - `max_accounts`: 最多返回账户数
+        fn simulate_settlement(
+            cycle: u32,
+            table: PayoutTable,
+            percents: Option<LevelPercents>,
+            max_accounts: u32,
+        ) -> SettlementSimulation<AccountId, Balance>;
+
+        /// 获取提案模拟摘要
+        fn proposal_simulation(proposal_id: u64) -> Option<SimulationSummary<Balance>>;
+
+        /// 当前周编号
+        fn current_cycle() -> u32;
+    }
+}
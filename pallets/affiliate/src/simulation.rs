@@ -0,0 +1,205 @@
+//! 函数级中文注释：结算模拟子模块（dry-run）
+//!
+//! 功能：
+//! - 分层业绩记录（即时分成 / 周结算各自按周期累计每层可分配金额）
+//! - 结算模拟：以现行或指定 `LevelPercents` 重算某周期的逐账户应得与总额
+//! - 提案模拟摘要：发起比例调整提案时，以上一完整周期的即时分成业绩估算受益/受损账户
+//! - 过期业绩清理
+//!
+//! 说明：
+//! - 业绩仅对通过资格校验的推荐人记录（会员有效；周结算另需活跃期），与当时比例是否为 0 无关，
+//!   因此可以评估"某层由 0 调为非 0"的影响；
+//! - 模拟按"每层累计业绩 × 比例"计算，与逐笔取整的实际应得可能存在最小单位级误差；
+//! - 模拟不改变任何状态。
+
+use super::*;
+use crate::types::{
+    LevelPercents, PayoutDelta, PayoutTable, SettlementSimulation, SimulationSummary,
+};
+use sp_runtime::traits::{Saturating, Zero, SaturatedConversion};
+
+/// 函数级中文注释：结算模拟实现
+impl<T: Config> Pallet<T> {
+    /// 函数级中文注释：当前周编号（区块号 / 每周区块数）
+    pub fn current_cycle() -> u32 {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let blocks_per_week = BlocksPerWeek::<T>::get().saturated_into::<u32>().max(1);
+        now.saturated_into::<u32>() / blocks_per_week
+    }
+
+    /// 函数级中文注释：记录推荐人在某周期某层的可分配业绩
+    ///
+    /// 参数：
+    /// - table: 业绩所属分配管线（即时 / 周结算）
+    /// - referrer: 推荐人
+    /// - index: 层级索引（0 = L1）
+    /// - amount: 该笔可分配金额
+    pub(crate) fn note_level_volume(
+        cycle: u32,
+        table: PayoutTable,
+        referrer: &T::AccountId,
+        index: usize,
+        amount: BalanceOf<T>,
+    ) {
+        CycleLevelVolume::<T>::mutate((cycle, table, referrer), |volume| {
+            while volume.len() <= index {
+                if volume.try_push(BalanceOf::<T>::zero()).is_err() {
+                    return;
+                }
+            }
+            volume[index] = volume[index].saturating_add(amount);
+        });
+    }
+
+    /// 函数级中文注释：按比例计算分层业绩对应的应得
+    fn volume_payout(
+        volume: &BoundedVec<BalanceOf<T>, ConstU32<15>>,
+        percents: &LevelPercents,
+    ) -> BalanceOf<T> {
+        let hundred: BalanceOf<T> = 100u32.into();
+        volume.iter().zip(percents.iter()).fold(BalanceOf::<T>::zero(), |acc, (v, p)| {
+            if *p == 0 || *p > 100 {
+                return acc;
+            }
+            let percent_balance: BalanceOf<T> = (*p).into();
+            acc.saturating_add(v.saturating_mul(percent_balance) / hundred)
+        })
+    }
+
+    /// 函数级中文注释：现行比例表
+    fn current_percents(table: PayoutTable) -> LevelPercents {
+        match table {
+            PayoutTable::Instant => InstantLevelPercents::<T>::get(),
+            PayoutTable::Weekly => WeeklyLevelPercents::<T>::get(),
+        }
+    }
+
+    /// 函数级中文注释：模拟某周期结算（Runtime API 使用）
+    ///
+    /// 参数：
+    /// - cycle: 周编号
+    /// - table: 被替换的比例表（Weekly 即周结算 dry-run；Instant 对应治理提案）
+    /// - percents: 模拟比例（None 表示现行比例，此时差额为 0，可用于预览应得）
+    /// - max_accounts: 最多返回账户数
+    ///
+    /// 返回：逐账户现行/模拟应得及总额
+    pub fn simulate_settlement(
+        cycle: u32,
+        table: PayoutTable,
+        percents: Option<LevelPercents>,
+        max_accounts: u32,
+    ) -> SettlementSimulation<T::AccountId, BalanceOf<T>> {
+        let current = Self::current_percents(table);
+        let percents = percents.unwrap_or_else(|| current.clone());
+        let mut entries = sp_std::vec::Vec::new();
+        let mut current_total = BalanceOf::<T>::zero();
+        let mut simulated_total = BalanceOf::<T>::zero();
+        let mut truncated = false;
+
+        for (account, volume) in CycleLevelVolume::<T>::iter_prefix((cycle, table)) {
+            if entries.len() as u32 >= max_accounts {
+                truncated = true;
+                break;
+            }
+            let before = Self::volume_payout(&volume, &current);
+            let after = Self::volume_payout(&volume, &percents);
+            current_total = current_total.saturating_add(before);
+            simulated_total = simulated_total.saturating_add(after);
+            entries.push(PayoutDelta { account, current: before, simulated: after });
+        }
+
+        SettlementSimulation {
+            cycle,
+            table,
+            percents,
+            current_total,
+            simulated_total,
+            truncated,
+            entries,
+        }
+    }
+
+    /// 函数级中文注释：提案模拟快照的权重上限
+    ///
+    /// - 按 `MaxSimulationAccounts` 计：每个账户一次 `CycleLevelVolume` 读取与两次比例重算；
+    /// - 额外读取：BlocksPerWeek、InstantLevelPercents 及前缀迭代结束探测；写入：ProposalSimulations。
+    pub(crate) fn simulation_snapshot_weight() -> Weight {
+        let accounts = T::MaxSimulationAccounts::get() as u64;
+        Weight::from_parts(5_000_000, 0)
+            .saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(accounts))
+            .saturating_add(T::DbWeight::get().reads_writes(accounts.saturating_add(3), 1))
+    }
+
+    /// 函数级中文注释：生成并保存提案模拟摘要
+    ///
+    /// - 提案修改即时分成比例，以上一完整周期的即时分成业绩估算影响；
+    /// - 账户数受 `MaxSimulationAccounts` 限制（超出时 truncated = true）。
+    pub(crate) fn snapshot_proposal_simulation(
+        proposal_id: u64,
+        new_percentages: &LevelPercents,
+    ) -> SimulationSummary<BalanceOf<T>> {
+        let cycle = Self::current_cycle().saturating_sub(1);
+        let simulation = Self::simulate_settlement(
+            cycle,
+            PayoutTable::Instant,
+            Some(new_percentages.clone()),
+            T::MaxSimulationAccounts::get(),
+        );
+
+        let mut summary = SimulationSummary {
+            cycle,
+            accounts: simulation.entries.len() as u32,
+            gainers: 0,
+            losers: 0,
+            current_total: simulation.current_total,
+            simulated_total: simulation.simulated_total,
+            max_gain: BalanceOf::<T>::zero(),
+            max_loss: BalanceOf::<T>::zero(),
+            truncated: simulation.truncated,
+        };
+        for entry in simulation.entries.iter() {
+            if entry.is_gain() {
+                summary.gainers = summary.gainers.saturating_add(1);
+                summary.max_gain = summary.max_gain.max(entry.simulated.saturating_sub(entry.current));
+            } else if entry.is_loss() {
+                summary.losers = summary.losers.saturating_add(1);
+                summary.max_loss = summary.max_loss.max(entry.current.saturating_sub(entry.simulated));
+            }
+        }
+
+        ProposalSimulations::<T>::insert(proposal_id, summary);
+        Self::deposit_event(Event::ProposalSimulated {
+            proposal_id,
+            cycle,
+            current_total: summary.current_total,
+            simulated_total: summary.simulated_total,
+            gainers: summary.gainers,
+            losers: summary.losers,
+        });
+        summary
+    }
+
+    /// 函数级中文注释：清理过期周期业绩
+    ///
+    /// - 仅保留当前周期与上一周期（`cycle + 2 <= 当前周期` 方可清理）；
+    /// - 返回：(本次清理条数, 是否清理完毕)
+    pub fn do_prune_cycle_volume(
+        cycle: u32,
+        table: PayoutTable,
+        limit: u32,
+    ) -> Result<(u32, bool), DispatchError> {
+        ensure!(
+            cycle.saturating_add(2) <= Self::current_cycle(),
+            Error::<T>::CycleVolumeRetained
+        );
+        let result = CycleLevelVolume::<T>::clear_prefix((cycle, table), limit, None);
+        let done = result.maybe_cursor.is_none();
+        Self::deposit_event(Event::CycleVolumePruned {
+            cycle,
+            table,
+            removed: result.unique,
+            done,
+        });
+        Ok((result.unique, done))
+    }
+}
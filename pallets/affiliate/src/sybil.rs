@@ -0,0 +1,241 @@
+//! 函数级中文注释：反女巫子模块
+//!
+//! 功能：
+//! - 推荐人绑定速率限制（每个窗口最多被绑定 `MaxBindsPerWindow` 次）
+//! - 聚类启发式自动标记：同区块集中绑定、同一资金来源的直推聚集
+//! - 子树收益暂扣：被标记账户及其全部下级的推荐收益留存托管账户
+//! - 复核与释放：申诉裁决解除则发还，确认则没收至国库
+//!
+//! 说明：
+//! - 标记以子树根账户为单位，`FlagOf` 仅保存生效中（Pending / Confirmed）的标记；
+//! - 账户的暂扣归属取自身或最近的被标记上级（最多向上 15 层）；
+//! - 自动标记仅在计数恰好达到阈值时触发一次，避免解除后被反复标记；
+//! - 链上不追踪账户的首笔入金，同源聚类（`SharedFunding`）只依据风控预言机 `report_funding_sources` 上报的数据。
+
+use super::*;
+use crate::types::{FlagStatus, SybilFlag, SybilReason};
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::vec::Vec;
+
+/// 函数级中文注释：反女巫实现
+impl<T: Config> Pallet<T> {
+    /// 函数级中文注释：校验推荐人绑定速率
+    ///
+    /// 窗口过期后计数从 0 重新开始；窗口内达到上限则拒绝绑定。
+    pub(crate) fn ensure_bind_velocity(sponsor: &T::AccountId) -> DispatchResult {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let (window_start, count) = BindVelocity::<T>::get(sponsor);
+        let in_window = now < window_start.saturating_add(T::BindWindow::get());
+        ensure!(
+            !in_window || count < T::MaxBindsPerWindow::get(),
+            Error::<T>::SponsorRateLimited
+        );
+        Ok(())
+    }
+
+    /// 函数级中文注释：记录一次绑定并执行聚类检测
+    ///
+    /// - 更新窗口计数与同区块计数；
+    /// - 同区块绑定次数达到阈值 → 标记推荐人子树（SameBlockBurst）；
+    /// - 绑定者资金来源已知时累加同源计数，达到阈值 → 标记（SharedFunding）。
+    pub(crate) fn note_bind(who: &T::AccountId, sponsor: &T::AccountId) {
+        let now = <frame_system::Pallet<T>>::block_number();
+
+        BindVelocity::<T>::mutate(sponsor, |(window_start, count)| {
+            if now >= window_start.saturating_add(T::BindWindow::get()) {
+                *window_start = now;
+                *count = 0;
+            }
+            *count = count.saturating_add(1);
+        });
+
+        let same_block = SameBlockBinds::<T>::mutate(sponsor, |(block, count)| {
+            if *block != now {
+                *block = now;
+                *count = 0;
+            }
+            *count = count.saturating_add(1);
+            *count
+        });
+        if same_block == T::SameBlockBindThreshold::get() {
+            Self::try_auto_flag(sponsor, SybilReason::SameBlockBurst);
+        }
+
+        if let Some(source) = FundingSourceOf::<T>::get(who) {
+            Self::note_cluster_member(sponsor, &source);
+        }
+    }
+
+    /// 函数级中文注释：累加同源直推计数，达到阈值时自动标记
+    fn note_cluster_member(sponsor: &T::AccountId, source: &T::AccountId) {
+        let count = ClusterCount::<T>::mutate(sponsor, source, |count| {
+            *count = count.saturating_add(1);
+            *count
+        });
+        if count == T::FundingClusterThreshold::get() {
+            Self::try_auto_flag(sponsor, SybilReason::SharedFunding);
+        }
+    }
+
+    /// 函数级中文注释：自动标记（已有生效标记时忽略）
+    fn try_auto_flag(root: &T::AccountId, reason: SybilReason) {
+        if !FlagOf::<T>::contains_key(root) {
+            Self::do_flag_subtree(root.clone(), reason);
+        }
+    }
+
+    /// 函数级中文注释：上报资金来源
+    ///
+    /// - 每个账户仅记录首次上报的来源；
+    /// - 已绑定推荐人的账户立即计入推荐人的同源计数。
+    pub(crate) fn do_report_funding_sources(entries: Vec<(T::AccountId, T::AccountId)>) {
+        let mut count = 0u32;
+        for (account, source) in entries {
+            if FundingSourceOf::<T>::contains_key(&account) {
+                continue;
+            }
+            FundingSourceOf::<T>::insert(&account, &source);
+            count = count.saturating_add(1);
+
+            if let Some(sponsor) = Sponsors::<T>::get(&account) {
+                Self::note_cluster_member(&sponsor, &source);
+            }
+        }
+        Self::deposit_event(Event::FundingSourcesReported { count });
+    }
+
+    /// 函数级中文注释：标记推荐子树
+    ///
+    /// 返回：标记ID
+    pub(crate) fn do_flag_subtree(root: T::AccountId, reason: SybilReason) -> u64 {
+        let flag_id = NextFlagId::<T>::mutate(|id| {
+            let current = *id;
+            *id = id.saturating_add(1);
+            current
+        });
+        SybilFlags::<T>::insert(
+            flag_id,
+            SybilFlag {
+                root: root.clone(),
+                reason,
+                flagged_at: <frame_system::Pallet<T>>::block_number(),
+                status: FlagStatus::Pending,
+                withheld: BalanceOf::<T>::zero(),
+            },
+        );
+        FlagOf::<T>::insert(&root, flag_id);
+        Self::deposit_event(Event::SybilFlagged { flag_id, root, reason });
+        flag_id
+    }
+
+    /// 函数级中文注释：复核标记
+    ///
+    /// - 仅 Pending 可复核；
+    /// - 解除：移除 `FlagOf`，后续收益恢复正常发放；
+    /// - 确认：保留 `FlagOf`，子树收益持续暂扣。
+    pub(crate) fn do_resolve_flag(flag_id: u64, cleared: bool) -> DispatchResult {
+        SybilFlags::<T>::try_mutate(flag_id, |maybe_flag| -> DispatchResult {
+            let flag = maybe_flag.as_mut().ok_or(Error::<T>::FlagNotFound)?;
+            ensure!(flag.status == FlagStatus::Pending, Error::<T>::FlagNotPending);
+            if cleared {
+                flag.status = FlagStatus::Cleared;
+                FlagOf::<T>::remove(&flag.root);
+            } else {
+                flag.status = FlagStatus::Confirmed;
+            }
+            Ok(())
+        })?;
+        Self::deposit_event(Event::SybilFlagResolved { flag_id, cleared });
+        Ok(())
+    }
+
+    /// 函数级中文注释：处理暂扣收益（分页）
+    ///
+    /// - 解除：逐账户发还；
+    /// - 确认：合计没收至国库；
+    /// - 返回：(本次处理账户数, 是否处理完毕)
+    pub fn do_release_withheld(
+        flag_id: u64,
+        limit: u32,
+    ) -> Result<(u32, bool), DispatchError> {
+        let flag = SybilFlags::<T>::get(flag_id).ok_or(Error::<T>::FlagNotFound)?;
+        ensure!(flag.status != FlagStatus::Pending, Error::<T>::FlagStillPending);
+
+        let batch: Vec<(T::AccountId, BalanceOf<T>)> = Withheld::<T>::iter_prefix(flag_id)
+            .take(limit as usize)
+            .collect();
+        let total = batch
+            .iter()
+            .fold(BalanceOf::<T>::zero(), |acc, (_, amount)| acc.saturating_add(*amount));
+
+        if !total.is_zero() {
+            let result = if flag.status == FlagStatus::Cleared {
+                Self::do_batch_withdraw(&batch)
+            } else {
+                Self::do_batch_withdraw(&[(T::TreasuryAccount::get(), total)])
+            };
+            result.map_err(|_| Error::<T>::WithdrawFailed)?;
+        }
+
+        for (account, _) in &batch {
+            Withheld::<T>::remove(flag_id, account);
+        }
+        SybilFlags::<T>::mutate(flag_id, |maybe_flag| {
+            if let Some(flag) = maybe_flag {
+                flag.withheld = flag.withheld.saturating_sub(total);
+            }
+        });
+
+        let count = batch.len() as u32;
+        let done = !Withheld::<T>::contains_prefix(flag_id);
+        Self::deposit_event(Event::WithheldReleased { flag_id, count, total, done });
+        Ok((count, done))
+    }
+
+    /// 函数级中文注释：账户的暂扣归属标记
+    ///
+    /// 自身或最近的被标记上级（最多向上 15 层）；无则返回 None。
+    pub fn withholding_flag(who: &T::AccountId) -> Option<u64> {
+        FlagOf::<T>::get(who).or_else(|| {
+            Self::get_referral_chain(who)
+                .iter()
+                .find_map(|ancestor| FlagOf::<T>::get(ancestor))
+        })
+    }
+
+    /// 函数级中文注释：推荐链逐层暂扣归属
+    ///
+    /// 自顶向下计算：chain[i] 的归属为自身标记，否则沿用 chain[i + 1] 的归属；
+    /// 链顶账户再向上追溯，避免逐层重复遍历。
+    pub(crate) fn withholding_flags_for_chain(chain: &[T::AccountId]) -> Vec<Option<u64>> {
+        let mut flags = sp_std::vec![None; chain.len()];
+        let mut inherited = None;
+        for (index, account) in chain.iter().enumerate().rev() {
+            let own = if index + 1 == chain.len() {
+                Self::withholding_flag(account)
+            } else {
+                FlagOf::<T>::get(account)
+            };
+            inherited = own.or(inherited);
+            flags[index] = inherited;
+        }
+        flags
+    }
+
+    /// 函数级中文注释：记录暂扣收益（资金留存托管账户）
+    pub(crate) fn withhold_payout(flag_id: u64, account: &T::AccountId, amount: BalanceOf<T>) {
+        Withheld::<T>::mutate(flag_id, account, |withheld| {
+            *withheld = withheld.saturating_add(amount);
+        });
+        SybilFlags::<T>::mutate(flag_id, |maybe_flag| {
+            if let Some(flag) = maybe_flag {
+                flag.withheld = flag.withheld.saturating_add(amount);
+            }
+        });
+        Self::deposit_event(Event::PayoutWithheld {
+            flag_id,
+            account: account.clone(),
+            amount,
+        });
+    }
+}
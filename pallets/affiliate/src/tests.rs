@@ -5,6 +5,13 @@
 //! **注意**：当前为最小化测试版本，后续需要根据实际 API 补充完整测试用例
 
 use crate::mock::*;
+use crate::types::PayoutTable;
+use crate::types::{FlagStatus, SybilReason};
+use crate::{
+    CycleLevelVolume, Entitlement, Error, FlagOf, ProposalSimulations, Sponsors, SybilFlags,
+    Withheld,
+};
+use frame_support::{assert_noop, assert_ok, traits::Currency, BoundedVec};
 
 // ========================================
 // 基础功能测试
@@ -42,6 +49,271 @@ fn test_membership_provider() {
     });
 }
 
+// ========================================
+// 🆕 结算模拟测试
+// ========================================
+
+/// 函数级中文注释：构建推荐链 3 → 2 → 1
+fn setup_chain() {
+    Sponsors::<Test>::insert(3, 2);
+    Sponsors::<Test>::insert(2, 1);
+}
+
+/// 函数级中文注释：构造 15 层比例
+fn percents(v: [u8; 15]) -> crate::types::LevelPercents {
+    BoundedVec::try_from(v.to_vec()).unwrap()
+}
+
+#[test]
+fn test_weekly_settlement_dry_run() {
+    new_test_ext().execute_with(|| {
+        setup_chain();
+        // 默认周结算比例：L1 20%，L2 10%
+        Affiliate::do_report_consumption(&3, 1_000, None, 15);
+        assert_eq!(Entitlement::<Test>::get(0, 2), 200);
+        assert_eq!(Entitlement::<Test>::get(0, 1), 100);
+        assert_eq!(CycleLevelVolume::<Test>::get((0, PayoutTable::Weekly, 1)).to_vec(), vec![0, 1_000]);
+
+        // 现行比例：与实际应得一致，无差额
+        let sim = Affiliate::simulate_settlement(0, PayoutTable::Weekly, None, 10);
+        assert_eq!(sim.current_total, 300);
+        assert_eq!(sim.simulated_total, 300);
+        assert!(sim.entries.iter().all(|e| !e.is_gain() && !e.is_loss()));
+
+        // 模拟比例：L1 10%，L2 20%
+        let sim = Affiliate::simulate_settlement(
+            0,
+            PayoutTable::Weekly,
+            Some(percents([10, 20, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4])),
+            10,
+        );
+        assert_eq!(sim.simulated_total, 300);
+        let l1 = sim.entries.iter().find(|e| e.account == 2).unwrap();
+        assert_eq!((l1.current, l1.simulated), (200, 100));
+        let l2 = sim.entries.iter().find(|e| e.account == 1).unwrap();
+        assert_eq!((l2.current, l2.simulated), (100, 200));
+
+        // 账户数上限截断
+        let sim = Affiliate::simulate_settlement(0, PayoutTable::Weekly, None, 1);
+        assert_eq!(sim.entries.len(), 1);
+        assert!(sim.truncated);
+
+        // 模拟不改变状态
+        assert_eq!(Entitlement::<Test>::get(0, 2), 200);
+    });
+}
+
+#[test]
+fn test_proposal_carries_simulation_summary() {
+    new_test_ext().execute_with(|| {
+        setup_chain();
+        let escrow = Affiliate::escrow_account();
+        assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(5), escrow, 1_000_000_000));
+        // 默认即时比例：L1 30%，L2 25%
+        Affiliate::do_instant_distribute(&3, 1_000_000, 15);
+
+        // 进入下一周期，以上一完整周期业绩模拟
+        System::set_block_number(100_801);
+        assert_ok!(Affiliate::propose_percentage_adjustment(
+            RuntimeOrigin::signed(4),
+            percents([40, 20, 10, 5, 5, 3, 2, 2, 2, 1, 1, 1, 1, 1, 1]),
+            BoundedVec::default(),
+            BoundedVec::default(),
+            BoundedVec::default(),
+        ));
+
+        let summary = ProposalSimulations::<Test>::get(0).unwrap();
+        assert_eq!(summary.cycle, 0);
+        assert_eq!(summary.accounts, 2);
+        assert_eq!((summary.gainers, summary.losers), (1, 1));
+        assert_eq!(summary.current_total, 550_000);
+        assert_eq!(summary.simulated_total, 600_000);
+        assert_eq!(summary.max_gain, 100_000);
+        assert_eq!(summary.max_loss, 50_000);
+        assert!(!summary.truncated);
+
+        assert_ok!(Affiliate::refresh_proposal_simulation(RuntimeOrigin::signed(6), 0));
+        assert_noop!(
+            Affiliate::refresh_proposal_simulation(RuntimeOrigin::signed(6), 9),
+            Error::<Test>::ProposalNotFound
+        );
+    });
+}
+
+#[test]
+fn test_prune_cycle_volume() {
+    new_test_ext().execute_with(|| {
+        setup_chain();
+        Affiliate::do_report_consumption(&3, 1_000, None, 15);
+
+        // 当前周期与上一周期业绩保留
+        System::set_block_number(100_801);
+        assert_noop!(
+            Affiliate::prune_cycle_volume(RuntimeOrigin::signed(6), 0, PayoutTable::Weekly, 10),
+            Error::<Test>::CycleVolumeRetained
+        );
+
+        System::set_block_number(201_601);
+        assert_ok!(Affiliate::prune_cycle_volume(RuntimeOrigin::signed(6), 0, PayoutTable::Weekly, 1));
+        assert_eq!(
+            CycleLevelVolume::<Test>::iter_prefix((0, PayoutTable::Weekly)).count(),
+            1
+        );
+        assert_ok!(Affiliate::prune_cycle_volume(RuntimeOrigin::signed(6), 0, PayoutTable::Weekly, 10));
+        assert_eq!(
+            CycleLevelVolume::<Test>::iter_prefix((0, PayoutTable::Weekly)).count(),
+            0
+        );
+    });
+}
+
+// ========================================
+// 🆕 反女巫测试
+// ========================================
+
+/// 函数级中文注释：账户 1 认领推荐码 "ALICE1"
+fn setup_code() {
+    assert_ok!(Affiliate::claim_code(RuntimeOrigin::signed(1), b"ALICE1".to_vec()));
+}
+
+#[test]
+fn test_claim_code_requires_verification() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Affiliate::claim_code(RuntimeOrigin::signed(800), b"UNVERIFIED".to_vec()),
+            Error::<Test>::NotVerified
+        );
+        assert!(!Affiliate::try_auto_claim_code(&800));
+    });
+}
+
+#[test]
+fn test_bind_velocity_limit() {
+    new_test_ext().execute_with(|| {
+        setup_code();
+        // 每个区块绑定 1 次，避免触发同区块标记
+        for who in 2..=5u64 {
+            assert_ok!(Affiliate::bind_sponsor(RuntimeOrigin::signed(who), b"ALICE1".to_vec()));
+            run_to_block(System::block_number() + 1);
+        }
+        assert_noop!(
+            Affiliate::bind_sponsor(RuntimeOrigin::signed(6), b"ALICE1".to_vec()),
+            Error::<Test>::SponsorRateLimited
+        );
+
+        // 窗口过期后恢复
+        run_to_block(11);
+        assert_ok!(Affiliate::bind_sponsor(RuntimeOrigin::signed(6), b"ALICE1".to_vec()));
+        assert!(FlagOf::<Test>::get(1).is_none());
+    });
+}
+
+#[test]
+fn test_same_block_burst_flags_sponsor() {
+    new_test_ext().execute_with(|| {
+        setup_code();
+        for who in 2..=4u64 {
+            assert_ok!(Affiliate::bind_sponsor(RuntimeOrigin::signed(who), b"ALICE1".to_vec()));
+        }
+        let flag_id = FlagOf::<Test>::get(1).expect("flagged");
+        let flag = SybilFlags::<Test>::get(flag_id).unwrap();
+        assert_eq!(flag.reason, SybilReason::SameBlockBurst);
+        assert_eq!(flag.status, FlagStatus::Pending);
+    });
+}
+
+#[test]
+fn test_shared_funding_flags_sponsor() {
+    new_test_ext().execute_with(|| {
+        setup_code();
+        // 账户 2 先绑定，资金来源后报
+        assert_ok!(Affiliate::bind_sponsor(RuntimeOrigin::signed(2), b"ALICE1".to_vec()));
+        let entries = BoundedVec::try_from(vec![(2u64, 50u64), (3u64, 50u64)]).unwrap();
+        assert_noop!(
+            Affiliate::report_funding_sources(RuntimeOrigin::signed(1), entries.clone()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Affiliate::report_funding_sources(RuntimeOrigin::root(), entries));
+        assert!(FlagOf::<Test>::get(1).is_none());
+
+        // 账户 3 与账户 2 同源，绑定后达到阈值
+        run_to_block(2);
+        assert_ok!(Affiliate::bind_sponsor(RuntimeOrigin::signed(3), b"ALICE1".to_vec()));
+        let flag_id = FlagOf::<Test>::get(1).expect("flagged");
+        assert_eq!(SybilFlags::<Test>::get(flag_id).unwrap().reason, SybilReason::SharedFunding);
+    });
+}
+
+#[test]
+fn test_flagged_subtree_payout_withheld_and_released() {
+    new_test_ext().execute_with(|| {
+        setup_chain();
+        let escrow = Affiliate::escrow_account();
+        let _ = Balances::deposit_creating(&escrow, 1_000_000);
+
+        // 标记账户 2 的子树：账户 2 暂扣，上级账户 1 正常发放
+        assert_ok!(Affiliate::flag_subtree(RuntimeOrigin::root(), 2, SybilReason::Manual));
+        assert_noop!(
+            Affiliate::flag_subtree(RuntimeOrigin::root(), 2, SybilReason::Manual),
+            Error::<Test>::AlreadyFlagged
+        );
+        let flag_id = FlagOf::<Test>::get(2).unwrap();
+
+        let before_1 = balance_of(1);
+        let before_2 = balance_of(2);
+        // 默认即时比例：L1 30%，L2 25%
+        let distributed = Affiliate::do_instant_distribute(&3, 1_000, 15);
+        assert_eq!(distributed, 250);
+        assert_eq!(balance_of(1), before_1 + 250);
+        assert_eq!(balance_of(2), before_2);
+        assert_eq!(Withheld::<Test>::get(flag_id, 2), 300);
+        assert_eq!(SybilFlags::<Test>::get(flag_id).unwrap().withheld, 300);
+
+        // 待复核期间不可释放
+        assert_noop!(
+            Affiliate::release_withheld(RuntimeOrigin::signed(5), flag_id, 10),
+            Error::<Test>::FlagStillPending
+        );
+
+        // 申诉通过：解除标记并发还
+        assert_ok!(Affiliate::gov_resolve_flag(RuntimeOrigin::root(), flag_id, true));
+        assert_noop!(
+            Affiliate::gov_resolve_flag(RuntimeOrigin::root(), flag_id, false),
+            Error::<Test>::FlagNotPending
+        );
+        assert!(FlagOf::<Test>::get(2).is_none());
+        assert_ok!(Affiliate::release_withheld(RuntimeOrigin::signed(5), flag_id, 10));
+        assert_eq!(balance_of(2), before_2 + 300);
+        assert_eq!(Withheld::<Test>::get(flag_id, 2), 0);
+        assert_eq!(SybilFlags::<Test>::get(flag_id).unwrap().withheld, 0);
+    });
+}
+
+#[test]
+fn test_confirmed_flag_forfeits_weekly_payout() {
+    new_test_ext().execute_with(|| {
+        setup_chain();
+        let escrow = Affiliate::escrow_account();
+        let _ = Balances::deposit_creating(&escrow, 1_000_000);
+
+        // 标记账户 1：其子树（含账户 2）的周结算应得全部暂扣
+        assert_ok!(Affiliate::flag_subtree(RuntimeOrigin::root(), 1, SybilReason::Manual));
+        let flag_id = FlagOf::<Test>::get(1).unwrap();
+        Affiliate::do_report_consumption(&3, 1_000, None, 15);
+        assert_ok!(Affiliate::settle_cycle(RuntimeOrigin::signed(5), 0, 10));
+        assert_eq!(Entitlement::<Test>::get(0, 2), 0);
+        assert_eq!(Withheld::<Test>::get(flag_id, 2), 200);
+        assert_eq!(Withheld::<Test>::get(flag_id, 1), 100);
+
+        // 确认女巫：没收至国库，标记继续生效
+        let treasury_before = balance_of(999);
+        assert_ok!(Affiliate::gov_resolve_flag(RuntimeOrigin::root(), flag_id, false));
+        assert_ok!(Affiliate::release_withheld(RuntimeOrigin::signed(5), flag_id, 10));
+        assert_eq!(balance_of(999), treasury_before + 300);
+        assert_eq!(FlagOf::<Test>::get(1), Some(flag_id));
+    });
+}
+
 // TODO: 后续需要补充的测试用例：
 // - test_claim_code_success
 // - test_claim_code_already_claimed
@@ -5,7 +5,7 @@
 extern crate alloc;
 use alloc::vec;
 
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use frame_support::{pallet_prelude::*, BoundedVec};
 use scale_info::TypeInfo;
 
@@ -102,3 +102,117 @@ pub trait AffiliateDistributor<AccountId, Balance, BlockNumber> {
     ) -> Result<Balance, sp_runtime::DispatchError>;
 }
 
+
+// ===== 🆕 结算模拟（dry-run）类型 =====
+
+/// 函数级中文注释：分成比例表类型（模拟时选择要替换的比例表）
+///
+/// - Instant: 即时分成比例（`InstantLevelPercents`，治理提案修改对象）
+/// - Weekly: 周结算分成比例（`WeeklyLevelPercents`）
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum PayoutTable {
+    Instant,
+    Weekly,
+}
+
+/// 函数级中文注释：单个账户的模拟分成差额
+///
+/// - `current`: 按现行比例计算的应得
+/// - `simulated`: 按模拟比例计算的应得
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, Debug)]
+pub struct PayoutDelta<AccountId, Balance> {
+    pub account: AccountId,
+    pub current: Balance,
+    pub simulated: Balance,
+}
+
+impl<AccountId, Balance: PartialOrd> PayoutDelta<AccountId, Balance> {
+    /// 函数级中文注释：模拟比例下是否增加收益
+    pub fn is_gain(&self) -> bool {
+        self.simulated > self.current
+    }
+
+    /// 函数级中文注释：模拟比例下是否减少收益
+    pub fn is_loss(&self) -> bool {
+        self.simulated < self.current
+    }
+}
+
+/// 函数级中文注释：某周期结算模拟结果（Runtime API 返回）
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, Debug)]
+pub struct SettlementSimulation<AccountId, Balance> {
+    /// 周编号
+    pub cycle: u32,
+    /// 被替换的比例表
+    pub table: PayoutTable,
+    /// 模拟使用的比例
+    pub percents: LevelPercents,
+    /// 现行比例总分配额
+    pub current_total: Balance,
+    /// 模拟比例总分配额
+    pub simulated_total: Balance,
+    /// 是否因账户数上限被截断
+    pub truncated: bool,
+    /// 逐账户差额
+    pub entries: alloc::vec::Vec<PayoutDelta<AccountId, Balance>>,
+}
+
+/// 函数级中文注释：提案模拟摘要（随提案存储，供投票者查看）
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct SimulationSummary<Balance> {
+    /// 模拟所用周编号（上一完整周期）
+    pub cycle: u32,
+    /// 参与模拟的账户数
+    pub accounts: u32,
+    /// 收益增加的账户数
+    pub gainers: u32,
+    /// 收益减少的账户数
+    pub losers: u32,
+    /// 现行比例总分配额
+    pub current_total: Balance,
+    /// 提案比例总分配额
+    pub simulated_total: Balance,
+    /// 单账户最大增幅
+    pub max_gain: Balance,
+    /// 单账户最大降幅
+    pub max_loss: Balance,
+    /// 是否因账户数上限被截断
+    pub truncated: bool,
+}
+
+// ===== 🆕 反女巫（anti-sybil）类型 =====
+
+/// 函数级中文注释：女巫标记原因
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum SybilReason {
+    /// 同一区块内大量下级绑定同一推荐人
+    SameBlockBurst,
+    /// 多个直推账户资金来源相同
+    SharedFunding,
+    /// 风控预言机/治理人工标记
+    Manual,
+}
+
+/// 函数级中文注释：标记状态
+///
+/// - Pending: 待申诉复核，子树收益暂扣
+/// - Cleared: 复核通过，暂扣收益可释放给原账户，标记解除
+/// - Confirmed: 确认女巫，暂扣收益没收至国库，子树收益持续暂扣
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum FlagStatus {
+    Pending,
+    Cleared,
+    Confirmed,
+}
+
+/// 函数级中文注释：女巫标记（以推荐子树根账户为单位）
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct SybilFlag<AccountId, BlockNumber, Balance> {
+    /// 被标记子树的根账户
+    pub root: AccountId,
+    pub reason: SybilReason,
+    pub flagged_at: BlockNumber,
+    pub status: FlagStatus,
+    /// 已暂扣收益总额
+    pub withheld: Balance,
+}
@@ -15,7 +15,7 @@
 //! - 保留：简化的直推计数
 
 use super::*;
-use sp_runtime::traits::{Saturating, Zero, SaturatedConversion};
+use sp_runtime::traits::{Saturating, Zero};
 
 /// 函数级中文注释：周结算实现
 impl<T: Config> Pallet<T> {
@@ -38,9 +38,7 @@ impl<T: Config> Pallet<T> {
         levels: u8,
     ) {
         // 计算当前周编号
-        let now = <frame_system::Pallet<T>>::block_number();
-        let blocks_per_week = BlocksPerWeek::<T>::get();
-        let current_cycle = (now.saturated_into::<u32>()) / (blocks_per_week.saturated_into::<u32>());
+        let current_cycle = Self::current_cycle();
 
         // 获取推荐链
         let referral_chain = Self::get_referral_chain(buyer);
@@ -52,17 +50,6 @@ impl<T: Config> Pallet<T> {
 
         // 逐层累计应得
         for (index, referrer) in referral_chain.iter().enumerate().take(levels_to_process) {
-            // 获取该层分成比例
-            let percent = if let Some(p) = level_percents.get(index) {
-                *p
-            } else {
-                0
-            };
-
-            if percent == 0 {
-                continue;
-            }
-
             // 简化验证：仅检查活跃期
             let active_until = ActiveUntilWeek::<T>::get(referrer);
             if active_until < current_cycle {
@@ -75,6 +62,31 @@ impl<T: Config> Pallet<T> {
                 continue;
             }
 
+            // 🆕 验证：已通过身份/信用校验
+            if !T::Eligibility::is_verified(referrer) {
+                continue;
+            }
+
+            // 🆕 记录分层业绩（与比例无关，供结算模拟使用）
+            Self::note_level_volume(
+                current_cycle,
+                crate::types::PayoutTable::Weekly,
+                referrer,
+                index,
+                distributable_amount,
+            );
+
+            // 获取该层分成比例
+            let percent = if let Some(p) = level_percents.get(index) {
+                *p
+            } else {
+                0
+            };
+
+            if percent == 0 {
+                continue;
+            }
+
             // 计算分成金额
             let share = if percent == 0 || percent > 100 {
                 BalanceOf::<T>::zero()
@@ -138,6 +150,9 @@ impl<T: Config> Pallet<T> {
         // 批量转账列表
         let mut transfers = sp_std::vec::Vec::new();
 
+        // 🆕 暂扣列表（标记ID, 账户, 金额）
+        let mut withheld = sp_std::vec::Vec::new();
+
         // 迭代 Entitlement 存储
         for (account, amount) in Entitlement::<T>::iter_prefix(cycle) {
             // 跳过已处理的账户
@@ -150,6 +165,16 @@ impl<T: Config> Pallet<T> {
                 continue;
             }
 
+            // 🆕 被标记子树：转入暂扣，待申诉复核
+            if let Some(flag_id) = Self::withholding_flag(&account) {
+                withheld.push((flag_id, account.clone(), amount));
+                settled_count += 1;
+                if settled_count >= max_accounts {
+                    break;
+                }
+                continue;
+            }
+
             // 添加到转账列表
             transfers.push((account.clone(), amount));
 
@@ -187,6 +212,12 @@ impl<T: Config> Pallet<T> {
             });
         }
 
+        // 🆕 记录暂扣（资金留存托管账户）
+        for (flag_id, account, amount) in withheld {
+            Entitlement::<T>::remove(cycle, &account);
+            Self::withhold_payout(flag_id, &account, amount);
+        }
+
         // 更新结算游标
         let new_cursor = cursor.saturating_add(settled_count);
         SettleCursor::<T>::insert(cycle, new_cursor);
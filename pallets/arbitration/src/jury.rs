@@ -0,0 +1,79 @@
+//! # Jury Module (陪审团仲裁模块)
+//!
+//! ## 函数级详细中文注释：质押陪审员池 + 随机抽选 + 承诺-揭示投票
+//!
+//! ### 流程
+//! 1. 陪审员按业务域质押登记（`register_juror`），进入该域陪审员池
+//! 2. 争议当事人申请陪审团（`request_jury`），预付陪审费；JuryDrawDelay 块后任何人调用 `draw_jury`，
+//!    以申请后产生的种子区块哈希从池中抽选（申请时种子不可知，无法预先挑选陪审员）
+//! 3. 陪审员在承诺期提交 `hash(domain, id, round, decision_code, bps, salt, juror)`，揭示期公开投票
+//! 4. 揭示期结束后结算：须有裁决获得超过陪审团半数的票（绝对多数），与多数一致者分得陪审费与罚没，
+//!    不一致/未揭示者按比例罚没质押；无绝对多数时不罚没已揭示者，陪审费退回，案件升级到治理委员会
+//! 5. 上诉期内当事人可上诉：陪审团扩大为 2n+1；超过最大规模后升级到治理委员会
+//! 6. 上诉期结束无人上诉，任何人可执行裁决（同 `arbitrate` 路由到业务 pallet）
+
+use crate::pallet::Decision;
+use alloc::vec::Vec;
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// 函数级详细中文注释：陪审员登记信息（按域）
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct JurorInfo<Balance, BlockNumber> {
+    /// 质押金额（已 hold）
+    pub stake: Balance,
+    /// 登记区块
+    pub registered_at: BlockNumber,
+    /// 当前参与中的案件数（>0 时不可退出）
+    pub active_cases: u32,
+}
+
+/// 函数级详细中文注释：陪审团案件状态
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum JuryState {
+    /// 已申请，等待种子区块后抽选
+    Drawing,
+    /// 投票中（承诺期 + 揭示期）
+    Voting,
+    /// 已出裁决，处于上诉期
+    Appealable,
+    /// 已升级到治理委员会（由 `arbitrate` 终裁）
+    Escalated,
+}
+
+/// 函数级详细中文注释：陪审员投票记录
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct JuryVote<Hash> {
+    /// 投票承诺：hash(domain, id, round, decision_code, bps, salt, juror)
+    pub commitment: Hash,
+    /// 揭示后的裁决
+    pub revealed: Option<Decision>,
+}
+
+/// 函数级详细中文注释：第 round 轮陪审团规模
+/// - 第 0 轮为 initial，每次上诉扩大为 2n+1（如 3 → 7 → 15）
+pub fn jury_size(initial: u32, round: u8) -> u32 {
+    let mut size = initial;
+    for _ in 0..round {
+        size = size.saturating_mul(2).saturating_add(1);
+    }
+    size
+}
+
+/// 函数级详细中文注释：统计绝对多数裁决
+/// - 某一裁决的票数须超过本轮陪审团规模的一半（未揭示者计入分母），即同时要求揭示法定人数过半
+/// - 无人揭示、揭示人数不足或票数分散时返回 None（由调用方升级到治理委员会）
+pub fn majority(votes: &[Decision], jury_size: u32) -> Option<Decision> {
+    let mut counts: Vec<(Decision, u32)> = Vec::new();
+    for vote in votes {
+        match counts.iter_mut().find(|(d, _)| d == vote) {
+            Some((_, c)) => *c += 1,
+            None => counts.push((vote.clone(), 1)),
+        }
+    }
+    counts
+        .into_iter()
+        .find(|(_, c)| c.saturating_mul(2) > jury_size)
+        .map(|(d, _)| d)
+}
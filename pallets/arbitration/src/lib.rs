@@ -5,20 +5,29 @@
 extern crate alloc;
 
 pub use pallet::*;
+pub mod jury;
 pub mod weights;
 
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
+    use crate::jury::{self, JurorInfo, JuryState, JuryVote};
     use crate::weights::WeightInfo;
+    use alloc::vec::Vec;
     use frame_support::traits::{EnsureOrigin, fungible::{Inspect as FungibleInspect, Mutate as FungibleMutate, MutateHold as FungibleMutateHold}};
     use frame_support::{pallet_prelude::*, BoundedVec};
     use frame_system::pallet_prelude::*;
     use pallet_escrow::pallet::Escrow as EscrowTrait;
+    use sp_runtime::traits::{Hash as HashT, Zero};
     use sp_runtime::Saturating;
     // 基准模块在 pallet 外部声明；此处不在 proc-macro 输入中声明子模块，避免 E0658
 
-    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
     pub enum Decision {
         Release,
         Refund,
@@ -77,6 +86,32 @@ pub mod pallet {
         type PartialSlashBps: Get<u16>;
         /// 国库账户
         type TreasuryAccount: Get<Self::AccountId>;
+
+        /// 🆕 陪审团仲裁相关配置
+        /// 陪审员最低质押
+        type MinJurorStake: Get<BalanceOf<Self>>;
+        /// 每位陪审员每轮陪审费（由申请方/上诉方预付）
+        type JurorFee: Get<BalanceOf<Self>>;
+        /// 首轮陪审团规模（建议奇数）
+        type InitialJurySize: Get<u32>;
+        /// 陪审团最大规模（上诉后超过该规模则升级到委员会）
+        type MaxJurySize: Get<u32>;
+        /// 每个域陪审员池上限
+        type MaxJurorsPerDomain: Get<u32>;
+        /// 承诺期（区块数）
+        type CommitPeriod: Get<BlockNumberFor<Self>>;
+        /// 揭示期（区块数）
+        type RevealPeriod: Get<BlockNumberFor<Self>>;
+        /// 上诉期（区块数）
+        type AppealPeriod: Get<BlockNumberFor<Self>>;
+        /// 与多数不一致/未揭示的陪审员罚没比例（基点）
+        type IncoherentSlashBps: Get<u16>;
+        /// 申请/上诉后延迟抽选的区块数
+        /// - 抽选种子取自 `申请块 + JuryDrawDelay` 的区块哈希，申请时尚不可知，当事人无法预先挑选陪审员
+        type JuryDrawDelay: Get<BlockNumberFor<Self>>;
+        /// 🆕 结案登记保留期（区块数）
+        /// - 裁决执行后经过该期限，任何人可调用 `purge_resolved_case` 清理结案登记、争议登记与证据引用
+        type ResolvedRetention: Get<BlockNumberFor<Self>>;
     }
 
     pub type BalanceOf<T> =
@@ -91,6 +126,10 @@ pub mod pallet {
         DisputeInitiator,
         /// 应诉方押金
         DisputeRespondent,
+        /// 陪审员质押
+        JurorStake,
+        /// 陪审费（申请方/上诉方预付）
+        JuryFee,
     }
 
     /// 🆕 双向押金记录
@@ -111,6 +150,32 @@ pub mod pallet {
         pub has_responded: bool,
     }
 
+    /// 🆕 陪审团案件（每个争议至多一个，按轮次推进）
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct JuryCase<T: Config> {
+        /// 轮次（0 为首轮，每次上诉 +1）
+        pub round: u8,
+        /// 本轮陪审员
+        pub jurors: BoundedVec<T::AccountId, T::MaxJurySize>,
+        /// 本轮陪审费预付方
+        pub fee_payer: T::AccountId,
+        /// 本轮陪审费（已 hold，结算后为 0）
+        pub fee: BalanceOf<T>,
+        /// 抽选种子区块（该块哈希确定后方可抽选）
+        pub draw_at: BlockNumberFor<T>,
+        /// 承诺期截止（抽选后确定）
+        pub commit_end: BlockNumberFor<T>,
+        /// 揭示期截止（抽选后确定）
+        pub reveal_end: BlockNumberFor<T>,
+        /// 状态
+        pub state: JuryState,
+        /// 本轮多数裁决
+        pub outcome: Option<Decision>,
+        /// 上诉期截止
+        pub appeal_end: Option<BlockNumberFor<T>>,
+    }
+
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
@@ -119,6 +184,18 @@ pub mod pallet {
     pub type Disputed<T: Config> =
         StorageDoubleMap<_, Blake2_128Concat, [u8; 8], Blake2_128Concat, u64, (), OptionQuery>;
 
+    /// 🆕 结案登记：(domain, object_id) => 裁决执行区块（供证据授权等跨模块判断案件是否进行中）
+    #[pallet::storage]
+    pub type ResolvedAt<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        [u8; 8],
+        Blake2_128Concat,
+        u64,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
     /// 函数级中文注释：每个仲裁案件引用的 evidence_id 列表（证据本体由 pallet-evidence 存储）。
     #[pallet::storage]
     pub type EvidenceIds<T: Config> = StorageDoubleMap<
@@ -143,6 +220,45 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// 🆕 陪审员登记：(domain, account) => JurorInfo
+    #[pallet::storage]
+    pub type Jurors<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        [u8; 8],
+        Blake2_128Concat,
+        T::AccountId,
+        JurorInfo<BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// 🆕 陪审员池：domain => [account]（抽选候选集，质押低于下限者移出）
+    #[pallet::storage]
+    pub type JurorPool<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        [u8; 8],
+        BoundedVec<T::AccountId, T::MaxJurorsPerDomain>,
+        ValueQuery,
+    >;
+
+    /// 🆕 陪审团案件：(domain, object_id) => JuryCase
+    #[pallet::storage]
+    pub type Juries<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, [u8; 8], Blake2_128Concat, u64, JuryCase<T>, OptionQuery>;
+
+    /// 🆕 本轮投票：((domain, object_id), juror) => JuryVote（每轮结算后清空）
+    #[pallet::storage]
+    pub type JuryVotes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ([u8; 8], u64),
+        Blake2_128Concat,
+        T::AccountId,
+        JuryVote<T::Hash>,
+        OptionQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -179,6 +295,59 @@ pub mod pallet {
             released: BalanceOf<T>,
             slashed: BalanceOf<T>,
         },
+        /// 🆕 陪审员登记
+        JurorRegistered {
+            domain: [u8; 8],
+            juror: T::AccountId,
+            stake: BalanceOf<T>,
+        },
+        /// 🆕 陪审员退出
+        JurorUnregistered { domain: [u8; 8], juror: T::AccountId },
+        /// 🆕 已申请陪审团，等待种子区块后抽选
+        JuryRequested { domain: [u8; 8], id: u64, round: u8, draw_at: BlockNumberFor<T> },
+        /// 🆕 种子区块哈希已不可查（超过 BlockHashCount），顺延抽选
+        JuryDrawRescheduled { domain: [u8; 8], id: u64, draw_at: BlockNumberFor<T> },
+        /// 🆕 陪审团已抽选（前端据此为陪审员授权证据访问）
+        JuryDrawn {
+            domain: [u8; 8],
+            id: u64,
+            round: u8,
+            jurors: Vec<T::AccountId>,
+            commit_end: BlockNumberFor<T>,
+            reveal_end: BlockNumberFor<T>,
+        },
+        /// 🆕 投票已承诺
+        VoteCommitted { domain: [u8; 8], id: u64, juror: T::AccountId },
+        /// 🆕 投票已揭示
+        VoteRevealed { domain: [u8; 8], id: u64, juror: T::AccountId },
+        /// 🆕 陪审员结算（奖励 = 陪审费分成 + 罚没分成）
+        JurorSettled {
+            domain: [u8; 8],
+            id: u64,
+            juror: T::AccountId,
+            reward: BalanceOf<T>,
+            slashed: BalanceOf<T>,
+        },
+        /// 🆕 本轮陪审团裁决（进入上诉期）
+        JuryVerdict {
+            domain: [u8; 8],
+            id: u64,
+            round: u8,
+            decision: u8,
+            bps: Option<u16>,
+            appeal_end: BlockNumberFor<T>,
+        },
+        /// 🆕 已上诉（开启下一轮更大陪审团）
+        JuryAppealed {
+            domain: [u8; 8],
+            id: u64,
+            appellant: T::AccountId,
+            round: u8,
+        },
+        /// 🆕 已升级到治理委员会（无多数或上诉超过最大规模）
+        JuryEscalated { domain: [u8; 8], id: u64, round: u8 },
+        /// 🆕 已清理过保留期的结案记录
+        ResolvedCasePurged { domain: [u8; 8], id: u64 },
     }
 
     #[pallet::error]
@@ -193,6 +362,54 @@ pub mod pallet {
         ResponseDeadlinePassed,
         /// 🆕 无法获取对方账户
         CounterpartyNotFound,
+        /// 🆕 已是该域陪审员
+        AlreadyJuror,
+        /// 🆕 不是该域陪审员
+        NotJuror,
+        /// 🆕 质押低于下限
+        StakeTooLow,
+        /// 🆕 陪审员池已满
+        JurorPoolFull,
+        /// 🆕 陪审员仍有进行中的案件
+        JurorHasActiveCases,
+        /// 🆕 本案已有陪审团
+        JuryExists,
+        /// 🆕 本案无陪审团
+        NoJury,
+        /// 🆕 非争议当事人
+        NotAParty,
+        /// 🆕 可抽选陪审员不足
+        InsufficientJurors,
+        /// 🆕 不在本轮陪审团
+        NotInJury,
+        /// 🆕 不在承诺期
+        NotInCommitPhase,
+        /// 🆕 不在揭示期
+        NotInRevealPhase,
+        /// 🆕 已提交过承诺
+        AlreadyCommitted,
+        /// 🆕 未提交承诺或已揭示
+        NothingToReveal,
+        /// 🆕 揭示内容与承诺不符
+        CommitmentMismatch,
+        /// 🆕 非法投票（裁决码或 bps 非法）
+        InvalidVote,
+        /// 🆕 揭示期未结束或状态不允许结算
+        CannotFinalize,
+        /// 🆕 不在上诉期
+        NotAppealable,
+        /// 🆕 上诉期未结束
+        AppealPeriodActive,
+        /// 🆕 陪审团已升级到委员会
+        JuryEscalatedToCommittee,
+        /// 🆕 案件已结案（裁决已执行）
+        CaseResolved,
+        /// 🆕 种子区块未到，暂不可抽选
+        DrawNotReady,
+        /// 🆕 案件未结案
+        CaseNotResolved,
+        /// 🆕 结案记录仍在保留期内
+        ResolvedRetentionActive,
     }
 
     #[pallet::call]
@@ -237,11 +454,13 @@ pub mod pallet {
             // 函数级详细中文注释：仲裁裁决入口
             // - 安全：仅允许由治理起源触发（Root 或 内容委员会阈值），避免任意账户执行清算。
             // - 通过 runtime 注入的 DecisionOrigin 校验 origin。
+            // - 陪审团案件进行中时，治理裁决视为终裁：未结算的陪审费退回，陪审员解除占用。
             T::DecisionOrigin::ensure_origin(origin)?;
             ensure!(
                 Disputed::<T>::get(domain, id).is_some(),
                 Error::<T>::NotDisputed
             );
+            ensure!(!ResolvedAt::<T>::contains_key(domain, id), Error::<T>::CaseResolved);
             // 通过 Router 将裁决应用到对应域的业务 pallet
             let decision = match (decision_code, bps) {
                 (0, _) => Decision::Release,
@@ -249,29 +468,8 @@ pub mod pallet {
                 (2, Some(p)) => Decision::Partial(p),
                 _ => Decision::Refund,
             };
-            T::Router::apply_decision(domain, id, decision.clone())?;
-
-            // 🆕 处理双向押金
-            Self::handle_deposits_on_arbitration(domain, id, &decision)?;
-
-            // 🆕 2025-10-22：TODO - 根据裁决结果更新做市商信用分
-            // 函数级详细中文注释：如果裁决为Release（做市商胜诉），无变化
-            // 如果裁决为Refund/Partial（做市商败诉），应扣除信用分
-            // 需要通过 Router 获取 maker_id，然后调用：
-            // pallet_credit::Pallet::<T>::record_maker_dispute_result(maker_id, id, maker_win)?;
-            
-            let out = match decision {
-                Decision::Release => (0, None),
-                Decision::Refund => (1, None),
-                Decision::Partial(p) => (2, Some(p)),
-            };
-            Self::deposit_event(Event::Arbitrated {
-                domain,
-                id,
-                decision: out.0,
-                bps: out.1,
-            });
-            Ok(())
+            Self::close_jury(domain, id)?;
+            Self::do_apply_decision(domain, id, decision)
         }
 
         /// 函数级中文注释：以 evidence_id 的方式发起仲裁登记。
@@ -504,10 +702,665 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// 🆕 函数级中文注释：登记为某业务域的陪审员
+        /// - 质押从本人账户 hold（HoldReason::JurorStake），不低于 MinJurorStake
+        /// - 进入该域陪审员池，参与后续随机抽选
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::register_juror())]
+        pub fn register_juror(
+            origin: OriginFor<T>,
+            domain: [u8; 8],
+            stake: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!Jurors::<T>::contains_key(domain, &who), Error::<T>::AlreadyJuror);
+            ensure!(stake >= T::MinJurorStake::get(), Error::<T>::StakeTooLow);
+
+            JurorPool::<T>::try_mutate(domain, |pool| {
+                pool.try_push(who.clone()).map_err(|_| Error::<T>::JurorPoolFull)
+            })?;
+            T::Fungible::hold(&T::RuntimeHoldReason::from(HoldReason::JurorStake), &who, stake)
+                .map_err(|_| Error::<T>::StakeTooLow)?;
+            Jurors::<T>::insert(
+                domain,
+                &who,
+                JurorInfo {
+                    stake,
+                    registered_at: frame_system::Pallet::<T>::block_number(),
+                    active_cases: 0,
+                },
+            );
+
+            Self::deposit_event(Event::JurorRegistered { domain, juror: who, stake });
+            Ok(())
+        }
+
+        /// 🆕 函数级中文注释：退出陪审员池并取回剩余质押
+        /// - 仍有进行中的案件时不可退出
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::unregister_juror())]
+        pub fn unregister_juror(origin: OriginFor<T>, domain: [u8; 8]) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let info = Jurors::<T>::get(domain, &who).ok_or(Error::<T>::NotJuror)?;
+            ensure!(info.active_cases == 0, Error::<T>::JurorHasActiveCases);
+
+            Self::remove_from_pool(domain, &who);
+            Jurors::<T>::remove(domain, &who);
+            if !info.stake.is_zero() {
+                T::Fungible::release(
+                    &T::RuntimeHoldReason::from(HoldReason::JurorStake),
+                    &who,
+                    info.stake,
+                    frame_support::traits::tokens::Precision::BestEffort,
+                )?;
+            }
+
+            Self::deposit_event(Event::JurorUnregistered { domain, juror: who });
+            Ok(())
+        }
+
+        /// 🆕 函数级中文注释：争议当事人申请陪审团裁决（替代委员会排队）
+        /// - 预付陪审费 = JurorFee × 首轮规模（hold，结算时分给多数一方陪审员）
+        /// - 不立即抽选：JuryDrawDelay 块后由 `draw_jury` 以种子区块哈希抽选，排除当事人
+        /// - 已结案的争议不可再申请
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::request_jury())]
+        pub fn request_jury(origin: OriginFor<T>, domain: [u8; 8], id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Disputed::<T>::get(domain, id).is_some(), Error::<T>::NotDisputed);
+            ensure!(!ResolvedAt::<T>::contains_key(domain, id), Error::<T>::CaseResolved);
+            ensure!(!Juries::<T>::contains_key(domain, id), Error::<T>::JuryExists);
+            ensure!(Self::is_party(domain, id, &who), Error::<T>::NotAParty);
+
+            let case = Self::open_round(domain, id, 0, &who)?;
+            Juries::<T>::insert(domain, id, case);
+            Ok(())
+        }
+
+        /// 🆕 函数级中文注释：陪审员提交投票承诺
+        /// - commitment = T::Hashing(domain, id, round, decision_code, bps, salt, juror) 的 SCALE 编码哈希
+        /// - 绑定案件与轮次，承诺不能在其他案件或上诉轮次中重放
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::commit_vote())]
+        pub fn commit_vote(
+            origin: OriginFor<T>,
+            domain: [u8; 8],
+            id: u64,
+            commitment: T::Hash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let case = Juries::<T>::get(domain, id).ok_or(Error::<T>::NoJury)?;
+            ensure!(case.state == JuryState::Voting, Error::<T>::NotInCommitPhase);
+            ensure!(case.jurors.contains(&who), Error::<T>::NotInJury);
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now <= case.commit_end, Error::<T>::NotInCommitPhase);
+            ensure!(
+                !JuryVotes::<T>::contains_key((domain, id), &who),
+                Error::<T>::AlreadyCommitted
+            );
+
+            JuryVotes::<T>::insert((domain, id), &who, JuryVote { commitment, revealed: None });
+            Self::deposit_event(Event::VoteCommitted { domain, id, juror: who });
+            Ok(())
+        }
+
+        /// 🆕 函数级中文注释：陪审员揭示投票
+        /// - 仅在承诺期结束后、揭示期截止前
+        /// - decision_code：0=Release, 1=Refund, 2=Partial(bps)
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::reveal_vote())]
+        pub fn reveal_vote(
+            origin: OriginFor<T>,
+            domain: [u8; 8],
+            id: u64,
+            decision_code: u8,
+            bps: Option<u16>,
+            salt: [u8; 32],
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let case = Juries::<T>::get(domain, id).ok_or(Error::<T>::NoJury)?;
+            ensure!(case.state == JuryState::Voting, Error::<T>::NotInRevealPhase);
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                now > case.commit_end && now <= case.reveal_end,
+                Error::<T>::NotInRevealPhase
+            );
+            let decision = match (decision_code, bps) {
+                (0, _) => Decision::Release,
+                (1, _) => Decision::Refund,
+                (2, Some(p)) if p <= 10_000 => Decision::Partial(p),
+                _ => return Err(Error::<T>::InvalidVote.into()),
+            };
+
+            JuryVotes::<T>::try_mutate((domain, id), &who, |maybe| -> DispatchResult {
+                let vote = maybe.as_mut().ok_or(Error::<T>::NothingToReveal)?;
+                ensure!(vote.revealed.is_none(), Error::<T>::NothingToReveal);
+                let expected = T::Hashing::hash_of(&(
+                    domain,
+                    id,
+                    case.round,
+                    decision_code,
+                    bps,
+                    salt,
+                    &who,
+                ));
+                ensure!(vote.commitment == expected, Error::<T>::CommitmentMismatch);
+                vote.revealed = Some(decision);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::VoteRevealed { domain, id, juror: who });
+            Ok(())
+        }
+
+        /// 🆕 函数级中文注释：揭示期结束后结算本轮（任何人可调用）
+        /// - 有绝对多数（票数超过陪审团半数）：多数一方陪审员平分陪审费与罚没，进入上诉期；
+        ///   与多数不一致或未揭示的陪审员按 IncoherentSlashBps 罚没质押
+        /// - 无绝对多数（揭示不足、并列或票数分散）：陪审费退回，案件升级到治理委员会；
+        ///   已揭示者不罚没，仅未揭示者罚没质押转入国库
+        /// - 权重按 `MaxJurySize` 计：逐个陪审员读取投票、结算陪审费与质押
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::finalize_jury(T::MaxJurySize::get()))]
+        pub fn finalize_jury(origin: OriginFor<T>, domain: [u8; 8], id: u64) -> DispatchResult {
+            ensure_signed(origin)?;
+            let mut case = Juries::<T>::get(domain, id).ok_or(Error::<T>::NoJury)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                case.state == JuryState::Voting && now > case.reveal_end,
+                Error::<T>::CannotFinalize
+            );
+
+            let revealed: Vec<(T::AccountId, Option<Decision>)> = case
+                .jurors
+                .iter()
+                .map(|j| {
+                    let vote = JuryVotes::<T>::get((domain, id), j).and_then(|v| v.revealed);
+                    (j.clone(), vote)
+                })
+                .collect();
+            let votes: Vec<Decision> = revealed.iter().filter_map(|(_, v)| v.clone()).collect();
+            let majority = jury::majority(&votes, case.jurors.len() as u32);
+
+            Self::settle_round(domain, id, &case, &revealed, majority.as_ref())?;
+            let _ = JuryVotes::<T>::clear_prefix((domain, id), u32::MAX, None);
+            case.fee = Zero::zero();
+
+            match majority {
+                Some(decision) => {
+                    let appeal_end = now.saturating_add(T::AppealPeriod::get());
+                    let (code, bps) = Self::decision_code(&decision);
+                    case.state = JuryState::Appealable;
+                    case.outcome = Some(decision);
+                    case.appeal_end = Some(appeal_end);
+                    Self::deposit_event(Event::JuryVerdict {
+                        domain,
+                        id,
+                        round: case.round,
+                        decision: code,
+                        bps,
+                        appeal_end,
+                    });
+                }
+                None => {
+                    case.state = JuryState::Escalated;
+                    Self::deposit_event(Event::JuryEscalated { domain, id, round: case.round });
+                }
+            }
+            Juries::<T>::insert(domain, id, case);
+            Ok(())
+        }
+
+        /// 🆕 函数级中文注释：上诉期内当事人上诉
+        /// - 下一轮陪审团规模 2n+1，上诉方预付新一轮陪审费
+        /// - 规模超过 MaxJurySize 时升级到治理委员会（`arbitrate` 终裁）
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::appeal_jury())]
+        pub fn appeal_jury(origin: OriginFor<T>, domain: [u8; 8], id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!ResolvedAt::<T>::contains_key(domain, id), Error::<T>::CaseResolved);
+            let case = Juries::<T>::get(domain, id).ok_or(Error::<T>::NoJury)?;
+            ensure!(Self::is_party(domain, id, &who), Error::<T>::NotAParty);
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                case.state == JuryState::Appealable &&
+                    case.appeal_end.is_some_and(|end| now <= end),
+                Error::<T>::NotAppealable
+            );
+
+            let next_round = case.round.saturating_add(1);
+            let next_size = jury::jury_size(T::InitialJurySize::get(), next_round);
+            if next_size > T::MaxJurySize::get() {
+                Juries::<T>::mutate(domain, id, |c| {
+                    if let Some(c) = c {
+                        c.state = JuryState::Escalated;
+                    }
+                });
+                Self::deposit_event(Event::JuryEscalated { domain, id, round: case.round });
+                return Ok(());
+            }
+
+            let next = Self::open_round(domain, id, next_round, &who)?;
+            Juries::<T>::insert(domain, id, next);
+            Self::deposit_event(Event::JuryAppealed { domain, id, appellant: who, round: next_round });
+            Ok(())
+        }
+
+        /// 🆕 函数级中文注释：上诉期结束后执行陪审团裁决（任何人可调用）
+        /// - 与 `arbitrate` 相同：通过 Router 应用到业务 pallet 并处理双向押金
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::arbitrate())]
+        pub fn execute_jury_decision(
+            origin: OriginFor<T>,
+            domain: [u8; 8],
+            id: u64,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(!ResolvedAt::<T>::contains_key(domain, id), Error::<T>::CaseResolved);
+            let case = Juries::<T>::get(domain, id).ok_or(Error::<T>::NoJury)?;
+            ensure!(case.state != JuryState::Escalated, Error::<T>::JuryEscalatedToCommittee);
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(case.state == JuryState::Appealable, Error::<T>::CannotFinalize);
+            ensure!(
+                case.appeal_end.is_some_and(|end| now > end),
+                Error::<T>::AppealPeriodActive
+            );
+            let decision = case.outcome.ok_or(Error::<T>::CannotFinalize)?;
+
+            Juries::<T>::remove(domain, id);
+            Self::do_apply_decision(domain, id, decision)
+        }
+
+        /// 🆕 函数级中文注释：种子区块之后抽选本轮陪审团（任何人可调用）
+        /// - 种子 = hash(域, 案件, 轮次, 种子区块哈希)，种子区块在申请/上诉之后产生
+        /// - 种子区块哈希已不可查（超过 BlockHashCount）时顺延 JuryDrawDelay 块
+        /// - 抽选后进入承诺期
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::draw_jury())]
+        pub fn draw_jury(origin: OriginFor<T>, domain: [u8; 8], id: u64) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(!ResolvedAt::<T>::contains_key(domain, id), Error::<T>::CaseResolved);
+            let mut case = Juries::<T>::get(domain, id).ok_or(Error::<T>::NoJury)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                case.state == JuryState::Drawing && now > case.draw_at,
+                Error::<T>::DrawNotReady
+            );
+
+            let seed_block = frame_system::Pallet::<T>::block_hash(case.draw_at);
+            if seed_block == T::Hash::default() {
+                case.draw_at = now.saturating_add(T::JuryDrawDelay::get());
+                Self::deposit_event(Event::JuryDrawRescheduled { domain, id, draw_at: case.draw_at });
+                Juries::<T>::insert(domain, id, case);
+                return Ok(());
+            }
+
+            let size = jury::jury_size(T::InitialJurySize::get(), case.round);
+            let seed = T::Hashing::hash_of(&(b"arb/jury", domain, id, case.round, seed_block));
+            let jurors = Self::draw_jurors(domain, id, seed, size)?;
+            for juror in jurors.iter() {
+                Jurors::<T>::mutate(domain, juror, |info| {
+                    if let Some(info) = info {
+                        info.active_cases = info.active_cases.saturating_add(1);
+                    }
+                });
+            }
+
+            case.commit_end = now.saturating_add(T::CommitPeriod::get());
+            case.reveal_end = case.commit_end.saturating_add(T::RevealPeriod::get());
+            case.jurors = jurors;
+            case.state = JuryState::Voting;
+            Self::deposit_event(Event::JuryDrawn {
+                domain,
+                id,
+                round: case.round,
+                jurors: case.jurors.clone().into_inner(),
+                commit_end: case.commit_end,
+                reveal_end: case.reveal_end,
+            });
+            Juries::<T>::insert(domain, id, case);
+            Ok(())
+        }
+
+        /// 🆕 函数级中文注释：清理过保留期的结案记录（任何人可调用）
+        /// - 要求裁决执行区块 + ResolvedRetention < 当前区块
+        /// - 移除 `ResolvedAt`、`Disputed` 与 `EvidenceIds`；`is_case_open` 随之返回 false，
+        ///   此后该对象能否再次发起争议由 Router 的 `can_dispute` 决定
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::purge_resolved_case())]
+        pub fn purge_resolved_case(origin: OriginFor<T>, domain: [u8; 8], id: u64) -> DispatchResult {
+            ensure_signed(origin)?;
+            let resolved_at = ResolvedAt::<T>::get(domain, id).ok_or(Error::<T>::CaseNotResolved)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                resolved_at.saturating_add(T::ResolvedRetention::get()) < now,
+                Error::<T>::ResolvedRetentionActive
+            );
+
+            ResolvedAt::<T>::remove(domain, id);
+            Disputed::<T>::remove(domain, id);
+            EvidenceIds::<T>::remove(domain, id);
+            Self::deposit_event(Event::ResolvedCasePurged { domain, id });
+            Ok(())
+        }
     }
 
     /// 🆕 辅助函数实现
     impl<T: Config> Pallet<T> {
+        /// 函数级中文注释：应用裁决（委员会 `arbitrate` 与陪审团执行共用）
+        /// - 通过 Router 应用到业务 pallet，处理双向押金并发出 Arbitrated 事件
+        fn do_apply_decision(domain: [u8; 8], id: u64, decision: Decision) -> DispatchResult {
+            T::Router::apply_decision(domain, id, decision.clone())?;
+
+            // 🆕 处理双向押金
+            Self::handle_deposits_on_arbitration(domain, id, &decision)?;
+
+            // 🆕 2025-10-22：TODO - 根据裁决结果更新做市商信用分
+            // 函数级详细中文注释：如果裁决为Release（做市商胜诉），无变化
+            // 如果裁决为Refund/Partial（做市商败诉），应扣除信用分
+            // 需要通过 Router 获取 maker_id，然后调用：
+            // pallet_credit::Pallet::<T>::record_maker_dispute_result(maker_id, id, maker_win)?;
+
+            ResolvedAt::<T>::insert(domain, id, <frame_system::Pallet<T>>::block_number());
+
+            let (code, bps) = Self::decision_code(&decision);
+            Self::deposit_event(Event::Arbitrated {
+                domain,
+                id,
+                decision: code,
+                bps,
+            });
+            Ok(())
+        }
+
+        /// 函数级中文注释：裁决 → (decision_code, bps)
+        fn decision_code(decision: &Decision) -> (u8, Option<u16>) {
+            match decision {
+                Decision::Release => (0, None),
+                Decision::Refund => (1, None),
+                Decision::Partial(p) => (2, Some(*p)),
+            }
+        }
+
+        /// 函数级中文注释：是否为争议当事人（Router 认可的发起方，或双向押金记录中的双方）
+        fn is_party(domain: [u8; 8], id: u64, who: &T::AccountId) -> bool {
+            if T::Router::can_dispute(domain, who, id) {
+                return true;
+            }
+            TwoWayDeposits::<T>::get(domain, id)
+                .is_some_and(|r| &r.initiator == who || &r.respondent == who)
+        }
+
+        /// 函数级中文注释：案件是否进行中（已登记争议且尚未执行裁决）
+        pub fn is_case_open(domain: [u8; 8], id: u64) -> bool {
+            Disputed::<T>::contains_key(domain, id) && !ResolvedAt::<T>::contains_key(domain, id)
+        }
+
+        /// 函数级中文注释：查询本案当前陪审员（供证据授权等跨模块使用）
+        pub fn current_jurors(domain: [u8; 8], id: u64) -> Vec<T::AccountId> {
+            Juries::<T>::get(domain, id)
+                .filter(|c| c.state == JuryState::Voting)
+                .map(|c| c.jurors.into_inner())
+                .unwrap_or_default()
+        }
+
+        /// 函数级中文注释：查询本案当前陪审团决策主体（供证据按案件授权使用）
+        /// - 主体标识按 (domain, id, round) 派生，每个案件、每轮陪审团各不相同
+        /// - 该主体没有共享公钥：证据模块按每位陪审员自己登记的公钥授权（`grant_case_access_to_members`）
+        /// - 仅投票中的轮次返回，其余阶段由委员会负责
+        pub fn current_jury_body(domain: [u8; 8], id: u64) -> Option<([u8; 8], Vec<T::AccountId>)> {
+            Juries::<T>::get(domain, id)
+                .filter(|c| c.state == JuryState::Voting)
+                .map(|c| (Self::jury_body_id(domain, id, c.round), c.jurors.into_inner()))
+        }
+
+        /// 函数级中文注释：陪审团决策主体标识 = blake2_256(b"arb_jury", domain, id, round) 前 8 字节
+        pub fn jury_body_id(domain: [u8; 8], id: u64, round: u8) -> [u8; 8] {
+            let hash = sp_core::hashing::blake2_256(&(b"arb_jury", domain, id, round).encode());
+            let mut body = [0u8; 8];
+            body.copy_from_slice(&hash[..8]);
+            body
+        }
+
+        /// 函数级中文注释：开启新一轮陪审团（待抽选）
+        /// - 预付方 hold 陪审费（HoldReason::JuryFee）
+        /// - 校验候选人数足够，种子区块为 当前块 + JuryDrawDelay，由 `draw_jury` 完成抽选
+        fn open_round(
+            domain: [u8; 8],
+            id: u64,
+            round: u8,
+            fee_payer: &T::AccountId,
+        ) -> Result<JuryCase<T>, DispatchError> {
+            let size = jury::jury_size(T::InitialJurySize::get(), round);
+            ensure!(
+                Self::candidates(domain, id).len() >= size as usize,
+                Error::<T>::InsufficientJurors
+            );
+            let fee = T::JurorFee::get().saturating_mul(size.into());
+            if !fee.is_zero() {
+                T::Fungible::hold(&T::RuntimeHoldReason::from(HoldReason::JuryFee), fee_payer, fee)
+                    .map_err(|_| Error::<T>::InsufficientDeposit)?;
+            }
+
+            let draw_at = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::JuryDrawDelay::get());
+            Self::deposit_event(Event::JuryRequested { domain, id, round, draw_at });
+            Ok(JuryCase {
+                round,
+                jurors: BoundedVec::default(),
+                fee_payer: fee_payer.clone(),
+                fee,
+                draw_at,
+                commit_end: Zero::zero(),
+                reveal_end: Zero::zero(),
+                state: JuryState::Drawing,
+                outcome: None,
+                appeal_end: None,
+            })
+        }
+
+        /// 函数级中文注释：域陪审员池中可抽选的候选人（排除争议当事人）
+        fn candidates(domain: [u8; 8], id: u64) -> Vec<T::AccountId> {
+            JurorPool::<T>::get(domain)
+                .into_iter()
+                .filter(|a| !Self::is_party(domain, id, a))
+                .collect()
+        }
+
+        /// 函数级中文注释：按种子从候选人中抽选 size 名陪审员
+        /// - 逐个哈希取模并从候选集中移除（不重复）
+        fn draw_jurors(
+            domain: [u8; 8],
+            id: u64,
+            seed: T::Hash,
+            size: u32,
+        ) -> Result<BoundedVec<T::AccountId, T::MaxJurySize>, DispatchError> {
+            let mut candidates = Self::candidates(domain, id);
+            ensure!(candidates.len() >= size as usize, Error::<T>::InsufficientJurors);
+
+            let mut chosen: Vec<T::AccountId> = Vec::with_capacity(size as usize);
+            for i in 0..size {
+                let h = T::Hashing::hash_of(&(seed, i));
+                let mut bytes = [0u8; 4];
+                bytes.copy_from_slice(&h.as_ref()[..4]);
+                let idx = u32::from_le_bytes(bytes) as usize % candidates.len();
+                chosen.push(candidates.swap_remove(idx));
+            }
+            chosen.try_into().map_err(|_| Error::<T>::InsufficientJurors.into())
+        }
+
+        /// 函数级中文注释：结算本轮陪审员激励
+        /// - 有多数：不一致/未揭示者罚没质押 IncoherentSlashBps，陪审费与罚没平分给多数一方（余数退回预付方/转入国库）
+        /// - 无多数：陪审费全额退回预付方；已揭示者不罚没，未揭示者的罚没转入国库
+        /// - 质押低于下限时移出陪审员池
+        /// - 按 transfer_on_hold 实际转出金额记账（BestEffort 可能少于名义金额）
+        fn settle_round(
+            domain: [u8; 8],
+            id: u64,
+            case: &JuryCase<T>,
+            revealed: &[(T::AccountId, Option<Decision>)],
+            majority: Option<&Decision>,
+        ) -> DispatchResult {
+            use frame_support::traits::tokens::{Fortitude, Precision, Restriction};
+
+            let fee_reason = T::RuntimeHoldReason::from(HoldReason::JuryFee);
+            let stake_reason = T::RuntimeHoldReason::from(HoldReason::JurorStake);
+            let treasury = T::TreasuryAccount::get();
+            let coherent: Vec<T::AccountId> = revealed
+                .iter()
+                .filter(|(_, v)| majority.is_some() && v.as_ref() == majority)
+                .map(|(j, _)| j.clone())
+                .collect();
+            let shares: BalanceOf<T> = (coherent.len() as u32).max(1).into();
+
+            // 1. 陪审费：多数一方平分，余数/无多数时退回预付方
+            let fee_per: BalanceOf<T> =
+                if coherent.is_empty() { Zero::zero() } else { case.fee / shares };
+            let mut fee_left = case.fee;
+            let mut rewards: Vec<BalanceOf<T>> = coherent.iter().map(|_| Zero::zero()).collect();
+            if !fee_per.is_zero() {
+                for (k, juror) in coherent.iter().enumerate() {
+                    let paid = T::Fungible::transfer_on_hold(
+                        &fee_reason,
+                        &case.fee_payer,
+                        juror,
+                        fee_per,
+                        Precision::BestEffort,
+                        Restriction::Free,
+                        Fortitude::Force,
+                    )?;
+                    fee_left = fee_left.saturating_sub(paid);
+                    rewards[k] = rewards[k].saturating_add(paid);
+                }
+            }
+            if !fee_left.is_zero() {
+                T::Fungible::release(&fee_reason, &case.fee_payer, fee_left, Precision::BestEffort)?;
+            }
+
+            // 2. 罚没：有多数时罚没不一致/未揭示者，无多数时仅罚没未揭示者
+            for (juror, vote) in revealed.iter() {
+                let is_coherent = majority.is_some() && vote.as_ref() == majority;
+                let punishable = if majority.is_some() { !is_coherent } else { vote.is_none() };
+                if !punishable {
+                    // 多数一方在第 3 步结算；无多数时已揭示者仅解除占用
+                    if majority.is_none() {
+                        Jurors::<T>::mutate(domain, juror, |info| {
+                            if let Some(info) = info {
+                                info.active_cases = info.active_cases.saturating_sub(1);
+                            }
+                        });
+                        Self::deposit_event(Event::JurorSettled {
+                            domain,
+                            id,
+                            juror: juror.clone(),
+                            reward: Zero::zero(),
+                            slashed: Zero::zero(),
+                        });
+                    }
+                    continue;
+                }
+                let mut slashed: BalanceOf<T> = Zero::zero();
+                if let Some(mut info) = Jurors::<T>::get(domain, juror) {
+                    let target = sp_runtime::Perbill::from_parts((T::IncoherentSlashBps::get() as u32) * 100)
+                        .mul_floor(info.stake);
+                    let per = if coherent.is_empty() { Zero::zero() } else { target / shares };
+                    if !per.is_zero() {
+                        for (k, winner) in coherent.iter().enumerate() {
+                            let moved = T::Fungible::transfer_on_hold(
+                                &stake_reason,
+                                juror,
+                                winner,
+                                per,
+                                Precision::BestEffort,
+                                Restriction::Free,
+                                Fortitude::Force,
+                            )?;
+                            slashed = slashed.saturating_add(moved);
+                            rewards[k] = rewards[k].saturating_add(moved);
+                        }
+                    }
+                    let slash_left = target.saturating_sub(slashed);
+                    if !slash_left.is_zero() {
+                        let moved = T::Fungible::transfer_on_hold(
+                            &stake_reason,
+                            juror,
+                            &treasury,
+                            slash_left,
+                            Precision::BestEffort,
+                            Restriction::Free,
+                            Fortitude::Force,
+                        )?;
+                        slashed = slashed.saturating_add(moved);
+                    }
+                    info.stake = info.stake.saturating_sub(slashed);
+                    info.active_cases = info.active_cases.saturating_sub(1);
+                    if info.stake < T::MinJurorStake::get() {
+                        Self::remove_from_pool(domain, juror);
+                    }
+                    Jurors::<T>::insert(domain, juror, info);
+                }
+                Self::deposit_event(Event::JurorSettled {
+                    domain,
+                    id,
+                    juror: juror.clone(),
+                    reward: Zero::zero(),
+                    slashed,
+                });
+            }
+
+            // 3. 多数一方：解除占用并发出结算事件
+            for (k, juror) in coherent.iter().enumerate() {
+                Jurors::<T>::mutate(domain, juror, |info| {
+                    if let Some(info) = info {
+                        info.active_cases = info.active_cases.saturating_sub(1);
+                    }
+                });
+                Self::deposit_event(Event::JurorSettled {
+                    domain,
+                    id,
+                    juror: juror.clone(),
+                    reward: rewards[k],
+                    slashed: Zero::zero(),
+                });
+            }
+            Ok(())
+        }
+
+        /// 函数级中文注释：治理终裁时关闭陪审团案件
+        /// - 待抽选/投票中的轮次：陪审费全额退回、陪审员解除占用（不罚没）
+        fn close_jury(domain: [u8; 8], id: u64) -> DispatchResult {
+            if let Some(case) = Juries::<T>::take(domain, id) {
+                if matches!(case.state, JuryState::Drawing | JuryState::Voting) {
+                    if !case.fee.is_zero() {
+                        T::Fungible::release(
+                            &T::RuntimeHoldReason::from(HoldReason::JuryFee),
+                            &case.fee_payer,
+                            case.fee,
+                            frame_support::traits::tokens::Precision::BestEffort,
+                        )?;
+                    }
+                    for juror in case.jurors.iter() {
+                        Jurors::<T>::mutate(domain, juror, |info| {
+                            if let Some(info) = info {
+                                info.active_cases = info.active_cases.saturating_sub(1);
+                            }
+                        });
+                    }
+                    let _ = JuryVotes::<T>::clear_prefix((domain, id), u32::MAX, None);
+                }
+            }
+            Ok(())
+        }
+
+        /// 函数级中文注释：从域陪审员池移除账户
+        fn remove_from_pool(domain: [u8; 8], who: &T::AccountId) {
+            JurorPool::<T>::mutate(domain, |pool| {
+                if let Some(pos) = pool.iter().position(|a| a == who) {
+                    pool.swap_remove(pos);
+                }
+            });
+        }
+
         /// 函数级中文注释：获取托管账户
         /// - 使用 pallet-escrow 的 PalletId 派生
         fn get_escrow_account() -> T::AccountId {
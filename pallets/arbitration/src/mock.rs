@@ -0,0 +1,143 @@
+use crate as pallet_arbitration;
+use crate::Decision;
+use core::cell::RefCell;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU16, ConstU32, ConstU64},
+    PalletId,
+};
+use sp_runtime::{BuildStorage, DispatchError, DispatchResult};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        Escrow: pallet_escrow,
+        Arbitration: pallet_arbitration,
+    }
+);
+
+/// 测试域
+pub const DOMAIN: [u8; 8] = *b"otc_ord_";
+/// 争议发起方（买家）
+pub const BUYER: u64 = 1;
+/// 争议对方（卖家）
+pub const SELLER: u64 = 2;
+/// 国库
+pub const TREASURY: u64 = 99;
+/// 陪审员候选账户
+pub const JURORS: [u64; 7] = [10, 11, 12, 13, 14, 15, 16];
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+parameter_types! {
+    pub const EscrowPalletId: PalletId = PalletId(*b"py/escro");
+}
+
+/// 函数级中文注释：托管到期策略（测试中不使用到期）
+pub struct NoExpiry;
+impl pallet_escrow::ExpiryPolicy<u64, u64> for NoExpiry {
+    fn on_expire(_id: u64) -> Result<pallet_escrow::ExpiryAction<u64>, DispatchError> {
+        Ok(pallet_escrow::ExpiryAction::Noop)
+    }
+    fn now() -> u64 {
+        System::block_number()
+    }
+}
+
+impl pallet_escrow::pallet::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type EscrowPalletId = EscrowPalletId;
+    type AuthorizedOrigin = frame_system::EnsureRoot<u64>;
+    type AdminOrigin = frame_system::EnsureRoot<u64>;
+    type MaxExpiringPerBlock = ConstU32<100>;
+    type ExpiryPolicy = NoExpiry;
+    type Assets = ();
+    type MaxMilestones = ConstU32<8>;
+}
+
+thread_local! {
+    /// Router 已应用的裁决记录
+    pub static APPLIED: RefCell<Vec<([u8; 8], u64, Decision)>> = RefCell::new(Vec::new());
+}
+
+/// 函数级中文注释：仲裁路由 mock（买家 1 与卖家 2 为当事人，记录裁决）
+pub struct MockRouter;
+impl MockRouter {
+    pub fn applied() -> Vec<([u8; 8], u64, Decision)> {
+        APPLIED.with(|a| a.borrow().clone())
+    }
+}
+impl pallet_arbitration::ArbitrationRouter<u64, u64> for MockRouter {
+    fn can_dispute(_domain: [u8; 8], who: &u64, _id: u64) -> bool {
+        *who == BUYER || *who == SELLER
+    }
+    fn apply_decision(domain: [u8; 8], id: u64, decision: Decision) -> DispatchResult {
+        APPLIED.with(|a| a.borrow_mut().push((domain, id, decision)));
+        Ok(())
+    }
+    fn get_counterparty(_domain: [u8; 8], initiator: &u64, _id: u64) -> Result<u64, DispatchError> {
+        Ok(if *initiator == BUYER { SELLER } else { BUYER })
+    }
+    fn get_order_amount(_domain: [u8; 8], _id: u64) -> Result<u64, DispatchError> {
+        Ok(1_000)
+    }
+}
+
+parameter_types! {
+    pub const TreasuryAccount: u64 = TREASURY;
+}
+
+impl pallet_arbitration::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxEvidence = ConstU32<10>;
+    type MaxCidLen = ConstU32<64>;
+    type Escrow = Escrow;
+    type WeightInfo = ();
+    type Router = MockRouter;
+    type DecisionOrigin = frame_system::EnsureRoot<u64>;
+    type Fungible = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type DepositRatioBps = ConstU16<1500>;
+    type ResponseDeadline = ConstU64<100>;
+    type RejectedSlashBps = ConstU16<3000>;
+    type PartialSlashBps = ConstU16<5000>;
+    type TreasuryAccount = TreasuryAccount;
+    type MinJurorStake = ConstU64<100>;
+    type JurorFee = ConstU64<10>;
+    type InitialJurySize = ConstU32<3>;
+    type MaxJurySize = ConstU32<7>;
+    type MaxJurorsPerDomain = ConstU32<20>;
+    type CommitPeriod = ConstU64<10>;
+    type RevealPeriod = ConstU64<10>;
+    type AppealPeriod = ConstU64<10>;
+    type IncoherentSlashBps = ConstU16<1000>;
+    type JuryDrawDelay = ConstU64<5>;
+    type ResolvedRetention = ConstU64<20>;
+}
+
+/// 函数级中文注释：构建测试环境（当事人与陪审员各 10_000，区块 1）
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    APPLIED.with(|a| a.borrow_mut().clear());
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    let mut balances = vec![(BUYER, 10_000), (SELLER, 10_000), (TREASURY, 1)];
+    balances.extend(JURORS.iter().map(|j| (*j, 10_000)));
+    pallet_balances::GenesisConfig::<Test> { balances, ..Default::default() }
+        .assimilate_storage(&mut t)
+        .unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
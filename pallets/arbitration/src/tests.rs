@@ -0,0 +1,435 @@
+//! Tests for pallet-arbitration（陪审团仲裁）
+
+use crate::{
+    jury::{self, JuryState},
+    mock::*,
+    Decision, Disputed, Error, Event, HoldReason, Juries, Jurors, ResolvedAt,
+};
+use frame_support::{assert_noop, assert_ok, traits::fungible::InspectHold};
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+/// 函数级中文注释：登记陪审员（每人质押 1000）
+fn register(jurors: &[u64]) {
+    for j in jurors {
+        assert_ok!(Arbitration::register_juror(RuntimeOrigin::signed(*j), DOMAIN, 1_000));
+    }
+}
+
+/// 函数级中文注释：买家发起争议并申请陪审团
+fn dispute_and_request(id: u64) {
+    assert_ok!(Arbitration::dispute(RuntimeOrigin::signed(BUYER), DOMAIN, id, vec![]));
+    assert_ok!(Arbitration::request_jury(RuntimeOrigin::signed(BUYER), DOMAIN, id));
+}
+
+/// 函数级中文注释：推进到种子区块之后，写入种子区块哈希并抽选
+fn draw(id: u64) -> Vec<u64> {
+    let case = Juries::<Test>::get(DOMAIN, id).unwrap();
+    System::set_block_number(case.draw_at + 1);
+    frame_system::BlockHash::<Test>::insert(case.draw_at, H256::repeat_byte(7));
+    assert_ok!(Arbitration::draw_jury(RuntimeOrigin::signed(3), DOMAIN, id));
+    Juries::<Test>::get(DOMAIN, id).unwrap().jurors.into_inner()
+}
+
+/// 函数级中文注释：计算绑定案件与轮次的投票承诺
+fn commitment(id: u64, round: u8, juror: u64, code: u8, bps: Option<u16>) -> H256 {
+    BlakeTwo256::hash_of(&(DOMAIN, id, round, code, bps, [juror as u8; 32], juror))
+}
+
+/// 函数级中文注释：本轮陪审员依次承诺、揭示，然后推进到揭示期结束
+fn vote(id: u64, votes: &[(u64, u8)]) {
+    let case = Juries::<Test>::get(DOMAIN, id).unwrap();
+    for (juror, code) in votes {
+        assert_ok!(Arbitration::commit_vote(
+            RuntimeOrigin::signed(*juror),
+            DOMAIN,
+            id,
+            commitment(id, case.round, *juror, *code, None),
+        ));
+    }
+    System::set_block_number(case.commit_end + 1);
+    for (juror, code) in votes {
+        assert_ok!(Arbitration::reveal_vote(
+            RuntimeOrigin::signed(*juror),
+            DOMAIN,
+            id,
+            *code,
+            None,
+            [*juror as u8; 32],
+        ));
+    }
+    System::set_block_number(case.reveal_end + 1);
+}
+
+// ==================== 纯函数 ====================
+
+#[test]
+fn jury_size_grows_2n_plus_1() {
+    assert_eq!(jury::jury_size(3, 0), 3);
+    assert_eq!(jury::jury_size(3, 1), 7);
+    assert_eq!(jury::jury_size(3, 2), 15);
+    assert_eq!(jury::jury_size(1, 3), 15);
+}
+
+#[test]
+fn majority_requires_more_than_half_of_jury() {
+    use Decision::*;
+    assert_eq!(jury::majority(&[Refund, Refund, Release], 3), Some(Refund));
+    assert_eq!(jury::majority(&[Partial(5000), Partial(4000), Partial(5000)], 3), Some(Partial(5000)));
+    assert_eq!(jury::majority(&[Release, Refund], 3), None);
+    assert_eq!(jury::majority(&[], 3), None);
+    // 相对多数但未过陪审团半数（含未揭示者）
+    assert_eq!(jury::majority(&[Refund, Refund, Release, Partial(5000)], 7), None);
+    // 仅少数人揭示：揭示者一致也不足法定人数
+    assert_eq!(jury::majority(&[Refund, Refund, Refund], 7), None);
+    assert_eq!(jury::majority(&[Refund, Refund, Refund, Refund], 7), Some(Refund));
+}
+
+// ==================== 抽选 ====================
+
+#[test]
+fn request_jury_defers_draw_to_seed_block() {
+    new_test_ext().execute_with(|| {
+        // 当事人也登记为陪审员，抽选时须被排除
+        register(&[BUYER, 10, 11, 12]);
+        dispute_and_request(1);
+
+        let case = Juries::<Test>::get(DOMAIN, 1).unwrap();
+        assert_eq!(case.state, JuryState::Drawing);
+        assert_eq!(case.draw_at, 6);
+        assert!(case.jurors.is_empty());
+        assert_eq!(Balances::balance_on_hold(&HoldReason::JuryFee.into(), &BUYER), 30);
+        System::assert_last_event(
+            Event::JuryRequested { domain: DOMAIN, id: 1, round: 0, draw_at: 6 }.into(),
+        );
+
+        // 种子区块产生之前不可抽选
+        for n in [1u64, 6] {
+            System::set_block_number(n);
+            assert_noop!(
+                Arbitration::draw_jury(RuntimeOrigin::signed(3), DOMAIN, 1),
+                Error::<Test>::DrawNotReady
+            );
+        }
+
+        let mut jurors = draw(1);
+        jurors.sort();
+        assert_eq!(jurors, vec![10, 11, 12]);
+        let case = Juries::<Test>::get(DOMAIN, 1).unwrap();
+        assert_eq!(case.state, JuryState::Voting);
+        assert_eq!(case.commit_end, 17);
+        assert_eq!(case.reveal_end, 27);
+        assert_eq!(Jurors::<Test>::get(DOMAIN, 10).unwrap().active_cases, 1);
+        assert_eq!(Jurors::<Test>::get(DOMAIN, BUYER).unwrap().active_cases, 0);
+    });
+}
+
+#[test]
+fn draw_reschedules_when_seed_hash_unavailable() {
+    new_test_ext().execute_with(|| {
+        register(&[10, 11, 12]);
+        dispute_and_request(1);
+
+        // 未写入种子区块哈希（等同超过 BlockHashCount）：顺延抽选
+        System::set_block_number(7);
+        assert_ok!(Arbitration::draw_jury(RuntimeOrigin::signed(3), DOMAIN, 1));
+        let case = Juries::<Test>::get(DOMAIN, 1).unwrap();
+        assert_eq!(case.state, JuryState::Drawing);
+        assert_eq!(case.draw_at, 12);
+        System::assert_last_event(
+            Event::JuryDrawRescheduled { domain: DOMAIN, id: 1, draw_at: 12 }.into(),
+        );
+    });
+}
+
+#[test]
+fn request_jury_requires_enough_candidates() {
+    new_test_ext().execute_with(|| {
+        register(&[10, 11, SELLER]);
+        assert_ok!(Arbitration::dispute(RuntimeOrigin::signed(BUYER), DOMAIN, 1, vec![]));
+        assert_noop!(
+            Arbitration::request_jury(RuntimeOrigin::signed(BUYER), DOMAIN, 1),
+            Error::<Test>::InsufficientJurors
+        );
+        assert_noop!(
+            Arbitration::request_jury(RuntimeOrigin::signed(3), DOMAIN, 1),
+            Error::<Test>::NotAParty
+        );
+    });
+}
+
+// ==================== 承诺-揭示 ====================
+
+#[test]
+fn commitment_is_bound_to_case_and_round() {
+    new_test_ext().execute_with(|| {
+        register(&[10, 11, 12]);
+        dispute_and_request(1);
+        let jurors = draw(1);
+        let (a, b, c) = (jurors[0], jurors[1], jurors[2]);
+
+        // 未绑定案件的旧格式承诺
+        let legacy = BlakeTwo256::hash_of(&(1u8, None::<u16>, [a as u8; 32], a));
+        assert_ok!(Arbitration::commit_vote(RuntimeOrigin::signed(a), DOMAIN, 1, legacy));
+        // 绑定到其他案件的承诺
+        assert_ok!(Arbitration::commit_vote(
+            RuntimeOrigin::signed(b),
+            DOMAIN,
+            1,
+            commitment(2, 0, b, 1, None)
+        ));
+        assert_ok!(Arbitration::commit_vote(
+            RuntimeOrigin::signed(c),
+            DOMAIN,
+            1,
+            commitment(1, 0, c, 1, None)
+        ));
+        assert_noop!(
+            Arbitration::commit_vote(RuntimeOrigin::signed(c), DOMAIN, 1, commitment(1, 0, c, 0, None)),
+            Error::<Test>::AlreadyCommitted
+        );
+
+        // 承诺期内不可揭示
+        assert_noop!(
+            Arbitration::reveal_vote(RuntimeOrigin::signed(c), DOMAIN, 1, 1, None, [c as u8; 32]),
+            Error::<Test>::NotInRevealPhase
+        );
+        System::set_block_number(18);
+        for juror in [a, b] {
+            assert_noop!(
+                Arbitration::reveal_vote(RuntimeOrigin::signed(juror), DOMAIN, 1, 1, None, [juror as u8; 32]),
+                Error::<Test>::CommitmentMismatch
+            );
+        }
+        assert_ok!(Arbitration::reveal_vote(RuntimeOrigin::signed(c), DOMAIN, 1, 1, None, [c as u8; 32]));
+    });
+}
+
+// ==================== 裁决、上诉与执行 ====================
+
+#[test]
+fn jury_verdict_settles_and_executes_after_appeal_period() {
+    new_test_ext().execute_with(|| {
+        register(&[10, 11, 12]);
+        dispute_and_request(1);
+        let jurors = draw(1);
+        vote(1, &[(jurors[0], 1), (jurors[1], 1), (jurors[2], 0)]);
+
+        assert_ok!(Arbitration::finalize_jury(RuntimeOrigin::signed(3), DOMAIN, 1));
+        let case = Juries::<Test>::get(DOMAIN, 1).unwrap();
+        assert_eq!(case.state, JuryState::Appealable);
+        assert_eq!(case.outcome, Some(Decision::Refund));
+
+        // 不一致者罚没 10% 质押，平分给多数一方（外加陪审费 30 / 2）
+        assert_eq!(Jurors::<Test>::get(DOMAIN, jurors[2]).unwrap().stake, 900);
+        assert_eq!(Balances::free_balance(jurors[0]), 10_000 - 1_000 + 15 + 50);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::JuryFee.into(), &BUYER), 0);
+
+        assert_noop!(
+            Arbitration::execute_jury_decision(RuntimeOrigin::signed(3), DOMAIN, 1),
+            Error::<Test>::AppealPeriodActive
+        );
+        System::set_block_number(case.appeal_end.unwrap() + 1);
+        assert_ok!(Arbitration::execute_jury_decision(RuntimeOrigin::signed(3), DOMAIN, 1));
+        assert_eq!(MockRouter::applied(), vec![(DOMAIN, 1, Decision::Refund)]);
+        assert!(ResolvedAt::<Test>::contains_key(DOMAIN, 1));
+        assert!(Juries::<Test>::get(DOMAIN, 1).is_none());
+    });
+}
+
+#[test]
+fn no_majority_escalates_without_slashing_revealers() {
+    new_test_ext().execute_with(|| {
+        register(&[10, 11, 12]);
+        dispute_and_request(1);
+        let jurors = draw(1);
+        // 1 票 Refund、1 票 Release、1 人未揭示：无绝对多数
+        vote(1, &[(jurors[0], 1), (jurors[1], 0)]);
+
+        assert_ok!(Arbitration::finalize_jury(RuntimeOrigin::signed(3), DOMAIN, 1));
+        let case = Juries::<Test>::get(DOMAIN, 1).unwrap();
+        assert_eq!(case.state, JuryState::Escalated);
+        assert_eq!(case.outcome, None);
+        System::assert_has_event(Event::JuryEscalated { domain: DOMAIN, id: 1, round: 0 }.into());
+
+        // 已揭示者不罚没，未揭示者罚没 10% 转入国库；陪审费全额退回
+        assert_eq!(Jurors::<Test>::get(DOMAIN, jurors[0]).unwrap().stake, 1_000);
+        assert_eq!(Jurors::<Test>::get(DOMAIN, jurors[1]).unwrap().stake, 1_000);
+        assert_eq!(Jurors::<Test>::get(DOMAIN, jurors[2]).unwrap().stake, 900);
+        assert_eq!(Balances::free_balance(TREASURY), 1 + 100);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::JuryFee.into(), &BUYER), 0);
+        for juror in jurors {
+            assert_eq!(Jurors::<Test>::get(DOMAIN, juror).unwrap().active_cases, 0);
+        }
+    });
+}
+
+#[test]
+fn settlement_books_amounts_actually_moved() {
+    new_test_ext().execute_with(|| {
+        register(&[10, 11, 12]);
+        dispute_and_request(1);
+        let jurors = draw(1);
+        vote(1, &[(jurors[0], 1), (jurors[1], 1), (jurors[2], 0)]);
+
+        // 不一致者的质押 hold 已被部分释放（如其他模块操作）：名义罚没 100，实际只能转出 40
+        let loser = jurors[2];
+        assert_ok!(<Balances as frame_support::traits::fungible::MutateHold<u64>>::release(
+            &HoldReason::JurorStake.into(),
+            &loser,
+            960,
+            frame_support::traits::tokens::Precision::Exact,
+        ));
+
+        assert_ok!(Arbitration::finalize_jury(RuntimeOrigin::signed(3), DOMAIN, 1));
+        assert_eq!(Jurors::<Test>::get(DOMAIN, loser).unwrap().stake, 960);
+        System::assert_has_event(
+            Event::JurorSettled { domain: DOMAIN, id: 1, juror: loser, reward: 0, slashed: 40 }.into(),
+        );
+        // 第一位多数陪审员分得陪审费 15 与实际罚没 40，第二位只分得陪审费
+        System::assert_has_event(
+            Event::JurorSettled {
+                domain: DOMAIN,
+                id: 1,
+                juror: jurors[0],
+                reward: 15 + 40,
+                slashed: 0,
+            }
+            .into(),
+        );
+        System::assert_has_event(
+            Event::JurorSettled { domain: DOMAIN, id: 1, juror: jurors[1], reward: 15, slashed: 0 }
+                .into(),
+        );
+        assert_eq!(Balances::balance_on_hold(&HoldReason::JurorStake.into(), &loser), 0);
+    });
+}
+
+#[test]
+fn appeal_opens_larger_round_then_escalates() {
+    new_test_ext().execute_with(|| {
+        register(&JURORS);
+        dispute_and_request(1);
+        let jurors = draw(1);
+        vote(1, &[(jurors[0], 1), (jurors[1], 1), (jurors[2], 1)]);
+        assert_ok!(Arbitration::finalize_jury(RuntimeOrigin::signed(3), DOMAIN, 1));
+
+        // 卖家上诉：7 人陪审团，同样延迟抽选
+        assert_ok!(Arbitration::appeal_jury(RuntimeOrigin::signed(SELLER), DOMAIN, 1));
+        let case = Juries::<Test>::get(DOMAIN, 1).unwrap();
+        assert_eq!((case.round, case.state), (1, JuryState::Drawing));
+        assert_eq!(Balances::balance_on_hold(&HoldReason::JuryFee.into(), &SELLER), 70);
+        let jurors = draw(1);
+        assert_eq!(jurors.len(), 7);
+
+        // 上一轮的承诺不能在本轮重放
+        let stale = commitment(1, 0, jurors[0], 1, None);
+        assert_ok!(Arbitration::commit_vote(RuntimeOrigin::signed(jurors[0]), DOMAIN, 1, stale));
+        System::set_block_number(Juries::<Test>::get(DOMAIN, 1).unwrap().commit_end + 1);
+        assert_noop!(
+            Arbitration::reveal_vote(
+                RuntimeOrigin::signed(jurors[0]),
+                DOMAIN,
+                1,
+                1,
+                None,
+                [jurors[0] as u8; 32]
+            ),
+            Error::<Test>::CommitmentMismatch
+        );
+    });
+}
+
+#[test]
+fn resolved_case_rejects_jury_actions() {
+    new_test_ext().execute_with(|| {
+        register(&[10, 11, 12]);
+        dispute_and_request(1);
+        let jurors = draw(1);
+        vote(1, &[(jurors[0], 0), (jurors[1], 0), (jurors[2], 0)]);
+        assert_ok!(Arbitration::finalize_jury(RuntimeOrigin::signed(3), DOMAIN, 1));
+
+        // 案件已在别处结案：陪审团裁决不得再次执行或上诉
+        ResolvedAt::<Test>::insert(DOMAIN, 1, 30);
+        assert_noop!(
+            Arbitration::appeal_jury(RuntimeOrigin::signed(SELLER), DOMAIN, 1),
+            Error::<Test>::CaseResolved
+        );
+        System::set_block_number(100);
+        assert_noop!(
+            Arbitration::execute_jury_decision(RuntimeOrigin::signed(3), DOMAIN, 1),
+            Error::<Test>::CaseResolved
+        );
+
+        // 委员会裁决后的争议不可再申请陪审团或重复裁决
+        assert_ok!(Arbitration::dispute(RuntimeOrigin::signed(BUYER), DOMAIN, 2, vec![]));
+        assert_ok!(Arbitration::arbitrate(RuntimeOrigin::root(), DOMAIN, 2, 1, None));
+        assert_noop!(
+            Arbitration::request_jury(RuntimeOrigin::signed(BUYER), DOMAIN, 2),
+            Error::<Test>::CaseResolved
+        );
+        assert_noop!(
+            Arbitration::arbitrate(RuntimeOrigin::root(), DOMAIN, 2, 0, None),
+            Error::<Test>::CaseResolved
+        );
+        assert_eq!(MockRouter::applied(), vec![(DOMAIN, 2, Decision::Refund)]);
+    });
+}
+
+#[test]
+fn resolved_case_purged_after_retention() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Arbitration::dispute(RuntimeOrigin::signed(BUYER), DOMAIN, 1, vec![]));
+        assert_noop!(
+            Arbitration::purge_resolved_case(RuntimeOrigin::signed(3), DOMAIN, 1),
+            Error::<Test>::CaseNotResolved
+        );
+        assert_ok!(Arbitration::arbitrate(RuntimeOrigin::root(), DOMAIN, 1, 1, None));
+        assert!(!Arbitration::is_case_open(DOMAIN, 1));
+
+        // 结案于区块 1，保留期 20：区块 21 仍保留
+        System::set_block_number(21);
+        assert_noop!(
+            Arbitration::purge_resolved_case(RuntimeOrigin::signed(3), DOMAIN, 1),
+            Error::<Test>::ResolvedRetentionActive
+        );
+
+        System::set_block_number(22);
+        assert_ok!(Arbitration::purge_resolved_case(RuntimeOrigin::signed(3), DOMAIN, 1));
+        assert!(!ResolvedAt::<Test>::contains_key(DOMAIN, 1));
+        assert!(!Disputed::<Test>::contains_key(DOMAIN, 1));
+        assert!(!Arbitration::is_case_open(DOMAIN, 1));
+        System::assert_last_event(Event::ResolvedCasePurged { domain: DOMAIN, id: 1 }.into());
+    });
+}
+
+#[test]
+fn committee_ruling_refunds_pending_jury_fee() {
+    new_test_ext().execute_with(|| {
+        register(&[10, 11, 12]);
+        dispute_and_request(1);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::JuryFee.into(), &BUYER), 30);
+
+        assert_ok!(Arbitration::arbitrate(RuntimeOrigin::root(), DOMAIN, 1, 0, None));
+        assert_eq!(Balances::balance_on_hold(&HoldReason::JuryFee.into(), &BUYER), 0);
+        assert!(Juries::<Test>::get(DOMAIN, 1).is_none());
+    });
+}
+
+#[test]
+fn jury_body_is_derived_per_case_and_round() {
+    new_test_ext().execute_with(|| {
+        register(&[10, 11, 12]);
+        dispute_and_request(1);
+        // 待抽选阶段无陪审团主体（由委员会负责）
+        assert_eq!(Arbitration::current_jury_body(DOMAIN, 1), None);
+
+        let jurors = draw(1);
+        let (body, members) = Arbitration::current_jury_body(DOMAIN, 1).unwrap();
+        assert_eq!(members, jurors);
+        assert_eq!(body, Arbitration::jury_body_id(DOMAIN, 1, 0));
+        assert_ne!(body, Arbitration::jury_body_id(DOMAIN, 2, 0));
+        assert_ne!(body, Arbitration::jury_body_id(DOMAIN, 1, 1));
+        assert_ne!(body, *b"arb_jury");
+    });
+}
@@ -7,6 +7,24 @@ pub trait WeightInfo {
     fn dispute(evidence_count: u32) -> Weight;
     /// arbitrate：常量开销
     fn arbitrate() -> Weight;
+    /// register_juror：常量开销
+    fn register_juror() -> Weight;
+    /// unregister_juror：常量开销
+    fn unregister_juror() -> Weight;
+    /// request_jury：与陪审员池规模相关（校验候选人数）
+    fn request_jury() -> Weight;
+    /// draw_jury：与陪审员池规模相关（抽选）
+    fn draw_jury() -> Weight;
+    /// commit_vote：常量开销
+    fn commit_vote() -> Weight;
+    /// reveal_vote：常量开销
+    fn reveal_vote() -> Weight;
+    /// finalize_jury：与陪审团规模线性相关（逐个陪审员读取投票并结算）
+    fn finalize_jury(jurors: u32) -> Weight;
+    /// appeal_jury：与陪审员池规模相关（重新抽选）
+    fn appeal_jury() -> Weight;
+    /// purge_resolved_case：常量开销（1 读 3 写）
+    fn purge_resolved_case() -> Weight;
 }
 
 impl WeightInfo for () {
@@ -16,6 +34,34 @@ impl WeightInfo for () {
     fn arbitrate() -> Weight {
         Weight::from_parts(10_000, 0)
     }
+    fn register_juror() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+    fn unregister_juror() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+    fn request_jury() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+    fn commit_vote() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+    fn reveal_vote() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+    fn finalize_jury(jurors: u32) -> Weight {
+        Weight::from_parts(10_000, 0)
+            .saturating_add(Weight::from_parts(5_000, 0).saturating_mul(jurors.into()))
+    }
+    fn appeal_jury() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+    fn draw_jury() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+    fn purge_resolved_case() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
 }
 
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -26,4 +72,38 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn arbitrate() -> Weight {
         Weight::from_parts(10_000, 0)
     }
+    fn register_juror() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+    fn unregister_juror() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+    fn request_jury() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+    fn commit_vote() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+    fn reveal_vote() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+    fn finalize_jury(jurors: u32) -> Weight {
+        // 每位陪审员：读投票 + 读写陪审员信息 + 陪审费/质押 hold 转移
+        Weight::from_parts(10_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(2, 2))
+            .saturating_add(
+                Weight::from_parts(5_000, 0)
+                    .saturating_add(T::DbWeight::get().reads_writes(3, 3))
+                    .saturating_mul(jurors.into()),
+            )
+    }
+    fn appeal_jury() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+    fn draw_jury() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+    fn purge_resolved_case() -> Weight {
+        Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 3))
+    }
 }
@@ -132,6 +132,8 @@ pub trait WeightInfo {
 	fn update_chat_profile() -> Weight;
 	fn set_user_status() -> Weight;
 	fn update_privacy_settings() -> Weight;
+	// 内容治理执行权重
+	fn gov_set_chat_ban() -> Weight;
 }
 
 /// 函数级详细中文注释：默认权重实现
@@ -259,6 +261,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			0
 		)
 	}
+
+	/// 治理禁言权重：1次读 + 1次写
+	/// - 读：ChatUserIdToAccount
+	/// - 写：ChatBans
+	fn gov_set_chat_ban() -> Weight {
+		Weight::from_parts(
+			1 * 25_000_000 + 1 * 100_000_000,
+			0
+		)
+	}
 }
 
 /// 函数级详细中文注释：消息元数据结构
@@ -392,6 +404,9 @@ pub mod pallet {
 		/// 用户个性签名最大长度
 		#[pallet::constant]
 		type MaxSignatureLength: Get<u32>;
+
+		/// 治理起源（内容治理申诉执行禁言）
+		type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 	}
 
 	/// 函数级详细中文注释：消息元数据存储
@@ -550,6 +565,19 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// 函数级详细中文注释：治理禁言名单
+	/// - Key: 账户
+	/// - Value: 禁言生效区块
+	/// - 被禁言账户不可发送消息，由内容治理申诉执行写入/解除
+	#[pallet::storage]
+	pub type ChatBans<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -652,6 +680,20 @@ pub mod pallet {
 			receiver_chat_id: Option<ChatUserId>,
 			content_cid: BoundedVec<u8, T::MaxCidLen>,
 		},
+
+		/// 函数级详细中文注释：用户已被治理禁言
+		/// [chat_user_id, account]
+		ChatUserBanned {
+			chat_user_id: ChatUserId,
+			account: T::AccountId,
+		},
+
+		/// 函数级详细中文注释：用户禁言已解除
+		/// [chat_user_id, account]
+		ChatUserUnbanned {
+			chat_user_id: ChatUserId,
+			account: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -709,6 +751,15 @@ pub mod pallet {
 
 		/// 无效的用户状态
 		InvalidUserStatus,
+
+		/// 发送方已被禁言
+		SenderBanned,
+
+		/// 用户已被禁言
+		AlreadyBanned,
+
+		/// 用户未被禁言
+		NotBanned,
 	}
 
 	#[pallet::call]
@@ -740,6 +791,9 @@ pub mod pallet {
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
+			// 【安全检查0】检查发送方是否被治理禁言
+			ensure!(!ChatBans::<T>::contains_key(&sender), Error::<T>::SenderBanned);
+
 			// 【安全检查1】检查接收方是否拉黑了发送方
 			ensure!(
 				!Blacklist::<T>::contains_key(&receiver, &sender),
@@ -1426,6 +1480,40 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// 函数级详细中文注释：治理禁言/解除禁言
+		/// 
+		/// # 参数
+		/// - `chat_user_id`: 聊天用户ID
+		/// - `banned`: true=禁言，false=解除
+		/// 
+		/// # 说明
+		/// - 由内容治理申诉执行路由调用（GovernanceOrigin）
+		/// - 禁言后该用户不可发送消息，已有消息与会话不受影响
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::gov_set_chat_ban())]
+		pub fn gov_set_chat_ban(
+			origin: OriginFor<T>,
+			chat_user_id: ChatUserId,
+			banned: bool,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			let account = ChatUserIdToAccount::<T>::get(chat_user_id)
+				.ok_or(Error::<T>::ChatUserNotFound)?;
+
+			if banned {
+				ensure!(!ChatBans::<T>::contains_key(&account), Error::<T>::AlreadyBanned);
+				ChatBans::<T>::insert(&account, <frame_system::Pallet<T>>::block_number());
+				Self::deposit_event(Event::ChatUserBanned { chat_user_id, account });
+			} else {
+				ensure!(ChatBans::<T>::contains_key(&account), Error::<T>::NotBanned);
+				ChatBans::<T>::remove(&account);
+				Self::deposit_event(Event::ChatUserUnbanned { chat_user_id, account });
+			}
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -116,6 +116,7 @@ impl pallet_chat::Config for Test {
 	type UnixTime = TestTime;
 	type MaxNicknameLength = frame_support::traits::ConstU32<64>;
 	type MaxSignatureLength = frame_support::traits::ConstU32<256>;
+	type GovernanceOrigin = frame_system::EnsureRoot<u64>;
 }
 
 /// 函数级详细中文注释：构建测试存储
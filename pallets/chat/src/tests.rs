@@ -1436,3 +1436,38 @@ fn test_automatic_chat_user_creation() {
 	});
 }
 
+
+// ============================================================================
+// 内容治理禁言测试
+// ============================================================================
+
+#[test]
+fn test_gov_chat_ban_blocks_sending() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Chat::register_chat_user(RuntimeOrigin::signed(ALICE), None));
+		let chat_user_id = Chat::get_chat_user_id_by_account(&ALICE).unwrap();
+
+		// 非治理起源不可禁言
+		assert_noop!(
+			Chat::gov_set_chat_ban(RuntimeOrigin::signed(BOB), chat_user_id, true),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(Chat::gov_set_chat_ban(RuntimeOrigin::root(), chat_user_id, true));
+		System::assert_has_event(Event::ChatUserBanned { chat_user_id, account: ALICE }.into());
+
+		// 禁言后不可发送消息
+		assert_noop!(
+			Chat::send_message(RuntimeOrigin::signed(ALICE), BOB, encrypted_cid(1), 0, None),
+			Error::<Test>::SenderBanned
+		);
+
+		// 解除后恢复
+		assert_ok!(Chat::gov_set_chat_ban(RuntimeOrigin::root(), chat_user_id, false));
+		assert_ok!(Chat::send_message(RuntimeOrigin::signed(ALICE), BOB, encrypted_cid(1), 0, None));
+		assert_noop!(
+			Chat::gov_set_chat_ban(RuntimeOrigin::root(), chat_user_id, false),
+			Error::<Test>::NotBanned
+		);
+	});
+}
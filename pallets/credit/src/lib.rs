@@ -31,9 +31,16 @@
 //!    - 信用分计算工具
 //!    - 风险评估函数
 //!    - 数据验证和校验
+//!
+//! 4. **跨领域信誉** (reputation.rs)
+//!    - OTC / 占卜服务 / 内容治理三个领域子信誉分
+//!    - 治理可调的综合分权重与违规传导矩阵
+//!    - `ReputationProvider` 供其他业务 Pallet 上报行为、查询资格
 
 pub use pallet::*;
 
+use frame_support::traits::Get;
+
 // ===== 🆕 2025-10-29: Trading Pallet 集成接口 =====
 
 /// 函数级详细中文注释：做市商信用接口（供Trading Pallet调用）
@@ -65,6 +72,7 @@ pub mod buyer;
 pub mod maker;
 pub mod common;
 pub mod quota; // 🆕 方案C+：买家额度管理模块
+pub mod reputation;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -83,6 +91,7 @@ pub mod pallet {
     pub use crate::maker;
     pub use crate::common;
     pub use crate::quota; // 🆕 方案C+：买家额度管理
+    pub use crate::reputation;
 
     // ===== 类型别名 =====
     
@@ -111,6 +120,10 @@ pub mod pallet {
         fn record_maker_order_timeout() -> Weight;
         fn record_dispute_result() -> Weight;
         fn rate_maker() -> Weight;
+
+        // Reputation 模块权重
+        fn set_reputation_params() -> Weight;
+        fn report_reputation_violation() -> Weight;
     }
     
     impl CreditWeightInfo for () {
@@ -124,6 +137,8 @@ pub mod pallet {
         fn record_maker_order_timeout() -> Weight { Weight::from_parts(25_000, 0) }
         fn record_dispute_result() -> Weight { Weight::from_parts(25_000, 0) }
         fn rate_maker() -> Weight { Weight::from_parts(15_000, 0) }
+        fn set_reputation_params() -> Weight { Weight::from_parts(10_000, 0) }
+        fn report_reputation_violation() -> Weight { Weight::from_parts(30_000, 0) }
     }
 
     // ===== Config Trait =====
@@ -185,6 +200,13 @@ pub mod pallet {
         
         // 权重信息
         type CreditWeightInfo: CreditWeightInfo;
+
+        // 跨领域信誉配置
+        /// 函数级详细中文注释：信誉治理权限（调整权重、传导矩阵，上报内容治理违规）
+        type ReputationGovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// 🆕 函数级详细中文注释：KYC等级查询（买家额度按等级封顶）
+        type KycTierProvider: crate::quota::KycTierProvider<Self::AccountId>;
     }
 
     #[pallet::pallet]
@@ -341,6 +363,25 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    // ===== 跨领域信誉存储 =====
+
+    /// 函数级详细中文注释：账户各领域子信誉记录（未记录时视为初始分）
+    #[pallet::storage]
+    pub type DomainReputations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        reputation::ReputationDomain,
+        reputation::DomainReputation<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// 函数级详细中文注释：信誉引擎参数（综合分权重、违规传导矩阵、资格门槛）
+    #[pallet::storage]
+    pub type ReputationParameters<T: Config> =
+        StorageValue<_, reputation::ReputationParams, ValueQuery>;
+
     // ===== Event =====
     
     #[pallet::event]
@@ -552,6 +593,28 @@ pub mod pallet {
             new_credit_score: u16,
             recovery_reason: u8, // 0=30DaysClean, 1=10OrdersBonus
         },
+
+        // ===== 跨领域信誉事件 =====
+
+        /// 函数级详细中文注释：领域子信誉分变更（含违规传导）
+        DomainReputationChanged {
+            account: T::AccountId,
+            domain: reputation::ReputationDomain,
+            old_score: u16,
+            new_score: u16,
+        },
+
+        /// 函数级详细中文注释：记录领域违规
+        ReputationViolationRecorded {
+            account: T::AccountId,
+            domain: reputation::ReputationDomain,
+            points: u16,
+        },
+
+        /// 函数级详细中文注释：信誉引擎参数已更新
+        ReputationParamsUpdated {
+            params: reputation::ReputationParams,
+        },
     }
 
     // ===== Error =====
@@ -606,6 +669,8 @@ pub mod pallet {
         InsufficientQuota,
         /// 函数级详细中文注释：超过并发订单数限制
         ExceedConcurrentLimit,
+        /// 🆕 函数级详细中文注释：占用额度超过KYC等级额度天花板
+        KycTierQuotaExceeded,
         /// 函数级详细中文注释：买家已被暂停服务
         BuyerSuspended,
         /// 函数级详细中文注释：买家已被拉黑
@@ -618,6 +683,13 @@ pub mod pallet {
         TooManyViolationRecords,
         /// 函数级详细中文注释：活跃订单列表已满（达到上限10个）
         ActiveOrderListFull,
+
+        // ===== 跨领域信誉错误 =====
+
+        /// 函数级详细中文注释：信誉参数无效（权重全为0、传导比例超过100%或门槛超过1000）
+        InvalidReputationParams,
+        /// 函数级详细中文注释：违规扣分必须大于0
+        InvalidViolationPoints,
     }
 
     // ===== Hooks =====
@@ -763,12 +835,63 @@ pub mod pallet {
 
             Ok(())
         }
+
+        // ===== Reputation 模块函数 =====
+
+        /// 函数级详细中文注释：设置信誉引擎参数（治理权限）
+        /// - 调整各领域综合分权重、违规传导矩阵、背书加成与资格门槛
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::CreditWeightInfo::set_reputation_params())]
+        pub fn set_reputation_params(
+            origin: OriginFor<T>,
+            params: reputation::ReputationParams,
+        ) -> DispatchResult {
+            T::ReputationGovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(params.is_valid(), Error::<T>::InvalidReputationParams);
+
+            ReputationParameters::<T>::put(params.clone());
+
+            Self::deposit_event(Event::ReputationParamsUpdated { params });
+
+            Ok(())
+        }
+
+        /// 函数级详细中文注释：上报领域违规（治理权限）
+        /// - 供内容治理等没有独立信用档案的领域直接记录违规
+        /// - 违规按传导矩阵同步影响其他领域
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::CreditWeightInfo::report_reputation_violation())]
+        pub fn report_reputation_violation(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            domain: reputation::ReputationDomain,
+            points: u16,
+        ) -> DispatchResult {
+            T::ReputationGovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(points > 0, Error::<T>::InvalidViolationPoints);
+
+            Self::apply_reputation_violation(&account, domain, points);
+
+            Ok(())
+        }
     }
     
     // ===== 内部辅助函数 =====
     
     impl<T: Config> Pallet<T> {
         // ===== Buyer 模块辅助函数 =====
+
+        /// 🆕 函数级详细中文注释：计算买家最大额度（按KYC等级封顶）
+        pub fn tiered_max_quota(buyer: &T::AccountId, credit_score: u16, total_orders: u32) -> u64 {
+            use crate::quota::KycTierProvider;
+            crate::quota::calculate_tiered_max_quota(
+                credit_score,
+                total_orders,
+                T::KycTierProvider::kyc_tier(buyer),
+            )
+        }
         
         /// 函数级详细中文注释：计算资产信任度（0-100）
         pub fn calculate_asset_trust(account: &T::AccountId) -> u8 {
@@ -1209,6 +1332,13 @@ pub mod pallet {
                 let _ = history.try_push(order_record);
             });
 
+            // 同步 OTC 领域信誉
+            Self::apply_reputation_positive(
+                buyer,
+                reputation::ReputationDomain::Otc,
+                T::OrderCompletedBonus::get(),
+            );
+
             // 每5笔分析一次行为模式
             let credit = BuyerCredits::<T>::get(buyer);
             if credit.completed_orders % 5 == 0 && credit.completed_orders <= 20 {
@@ -1291,6 +1421,13 @@ pub mod pallet {
                     });
                 }
             });
+
+            // 同步 OTC 领域信誉（按传导矩阵影响其他领域资格）
+            Self::apply_reputation_violation(
+                buyer,
+                reputation::ReputationDomain::Otc,
+                T::OrderDefaultPenalty::get(),
+            );
         }
 
         /// 函数级详细中文注释：分析行为模式并调整风险分
@@ -1730,6 +1867,147 @@ pub mod pallet {
                 maker::ServiceStatus::Suspended => 2,
             }
         }
+
+        // ===== Reputation 模块辅助函数 =====
+
+        /// 函数级详细中文注释：查询领域子信誉记录（未记录时返回初始记录）
+        pub fn domain_reputation(
+            who: &T::AccountId,
+            domain: reputation::ReputationDomain,
+        ) -> reputation::DomainReputation<BlockNumberFor<T>> {
+            DomainReputations::<T>::get(who, domain).unwrap_or_default()
+        }
+
+        /// 函数级详细中文注释：计算跨领域综合分
+        /// - 各领域子信誉分按治理权重加权平均
+        /// - 有效背书按条数加成（不超过上限），背书不再局限于 OTC 领域
+        pub fn calculate_aggregate_reputation(who: &T::AccountId) -> u16 {
+            let params = ReputationParameters::<T>::get();
+            let scores = reputation::ReputationDomain::ALL.map(|d| Self::domain_reputation(who, d).score);
+            let weighted = params.weighted_score(scores);
+
+            let active_endorsements = BuyerEndorsements::<T>::get(who)
+                .iter()
+                .filter(|e| e.is_active)
+                .count() as u16;
+            let endorsement_bonus = active_endorsements
+                .saturating_mul(params.endorsement_bonus)
+                .min(params.max_endorsement_bonus);
+
+            weighted
+                .saturating_add(endorsement_bonus)
+                .min(reputation::MAX_REPUTATION_SCORE)
+        }
+
+        /// 函数级详细中文注释：检查领域资格（领域分与综合分均需达到门槛）
+        pub fn is_reputation_eligible(who: &T::AccountId, domain: reputation::ReputationDomain) -> bool {
+            let params = ReputationParameters::<T>::get();
+            Self::domain_reputation(who, domain).score >= params.min_domain_score
+                && Self::calculate_aggregate_reputation(who) >= params.min_aggregate_score
+        }
+
+        /// 函数级详细中文注释：记录正向行为，提升领域子信誉分
+        pub fn apply_reputation_positive(
+            who: &T::AccountId,
+            domain: reputation::ReputationDomain,
+            points: u16,
+        ) {
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let mut record = Self::domain_reputation(who, domain);
+            let old_score = record.score;
+
+            record.score = record.score.saturating_add(points).min(reputation::MAX_REPUTATION_SCORE);
+            record.positive_count = record.positive_count.saturating_add(1);
+            record.updated_at = current_block;
+            let new_score = record.score;
+            DomainReputations::<T>::insert(who, domain, record);
+
+            if old_score != new_score {
+                Self::deposit_event(Event::DomainReputationChanged {
+                    account: who.clone(),
+                    domain,
+                    old_score,
+                    new_score,
+                });
+            }
+        }
+
+        /// 函数级详细中文注释：记录违规
+        /// - 来源领域全额扣分
+        /// - 其他领域按传导矩阵（万分比）扣分
+        pub fn apply_reputation_violation(
+            who: &T::AccountId,
+            domain: reputation::ReputationDomain,
+            points: u16,
+        ) {
+            let params = ReputationParameters::<T>::get();
+            let current_block = <frame_system::Pallet<T>>::block_number();
+
+            for target in reputation::ReputationDomain::ALL {
+                let deduction = if target == domain {
+                    points
+                } else {
+                    params.spillover(domain, target, points)
+                };
+                if deduction == 0 {
+                    continue;
+                }
+
+                let mut record = Self::domain_reputation(who, target);
+                let old_score = record.score;
+
+                record.score = record.score.saturating_sub(deduction);
+                if target == domain {
+                    record.violation_count = record.violation_count.saturating_add(1);
+                } else {
+                    record.spillover_deductions = record.spillover_deductions.saturating_add(deduction as u32);
+                }
+                record.last_violation_at = Some(current_block);
+                record.updated_at = current_block;
+                let new_score = record.score;
+                DomainReputations::<T>::insert(who, target, record);
+
+                if old_score != new_score {
+                    Self::deposit_event(Event::DomainReputationChanged {
+                        account: who.clone(),
+                        domain: target,
+                        old_score,
+                        new_score,
+                    });
+                }
+            }
+
+            Self::deposit_event(Event::ReputationViolationRecorded {
+                account: who.clone(),
+                domain,
+                points,
+            });
+        }
+    }
+}
+
+// ===== Reputation Provider =====
+
+/// 函数级详细中文注释：统一信誉接口实现（供占卜市场等 Pallet 通过 Config 注入）
+impl<T: pallet::Config> reputation::ReputationProvider<T::AccountId> for pallet::Pallet<T> {
+    fn domain_score(who: &T::AccountId, domain: reputation::ReputationDomain) -> u16 {
+        Self::domain_reputation(who, domain).score
+    }
+
+    fn aggregate_score(who: &T::AccountId) -> u16 {
+        Self::calculate_aggregate_reputation(who)
+    }
+
+    fn is_eligible(who: &T::AccountId, domain: reputation::ReputationDomain) -> bool {
+        Self::is_reputation_eligible(who, domain)
+    }
+
+    fn record_positive(who: &T::AccountId, domain: reputation::ReputationDomain, points: u16) {
+        Self::apply_reputation_positive(who, domain, points)
+    }
+
+    fn record_violation(who: &T::AccountId, domain: reputation::ReputationDomain, points: u16) {
+        Self::apply_reputation_violation(who, domain, points)
     }
 }
 
@@ -1790,25 +2068,39 @@ impl<T: pallet::Config> MakerCreditInterfaceLegacy for pallet::Pallet<T> {
 /// 这个实现提供了Trading Pallet所需的做市商信用管理功能。
 /// 由于Trading使用AccountId而非maker_id，这里提供了适配层。
 impl<T: pallet::Config> crate::MakerCreditInterface<T::AccountId> for pallet::Pallet<T> {
-    fn record_maker_order_completed(_maker: &T::AccountId) -> sp_runtime::DispatchResult {
-        // TODO: 实现从AccountId到maker_id的映射
-        // 当前简化实现：直接返回Ok
-        // 后续需要在Trading pallet中维护AccountId -> maker_id映射
+    fn record_maker_order_completed(maker: &T::AccountId) -> sp_runtime::DispatchResult {
+        // TODO: 实现从AccountId到maker_id的映射（做市商信用记录）
+        // 跨领域信誉按账户记录，可直接同步
+        Self::apply_reputation_positive(
+            maker,
+            reputation::ReputationDomain::Otc,
+            T::MakerOrderCompletedBonus::get(),
+        );
         Ok(())
     }
     
-    fn record_maker_order_timeout(_maker: &T::AccountId) -> sp_runtime::DispatchResult {
-        // TODO: 实现从AccountId到maker_id的映射
-        // 当前简化实现：直接返回Ok
+    fn record_maker_order_timeout(maker: &T::AccountId) -> sp_runtime::DispatchResult {
+        // TODO: 实现从AccountId到maker_id的映射（做市商信用记录）
+        Self::apply_reputation_violation(
+            maker,
+            reputation::ReputationDomain::Otc,
+            T::MakerOrderTimeoutPenalty::get(),
+        );
         Ok(())
     }
     
     fn record_maker_dispute_result(
-        _maker: &T::AccountId,
-        _buyer_win: bool,
+        maker: &T::AccountId,
+        buyer_win: bool,
     ) -> sp_runtime::DispatchResult {
-        // TODO: 实现从AccountId到maker_id的映射
-        // 当前简化实现：直接返回Ok
+        // TODO: 实现从AccountId到maker_id的映射（做市商信用记录）
+        if buyer_win {
+            Self::apply_reputation_violation(
+                maker,
+                reputation::ReputationDomain::Otc,
+                T::MakerDisputeLossPenalty::get(),
+            );
+        }
         Ok(())
     }
 }
@@ -1848,7 +2140,8 @@ impl<T: pallet::Config> crate::quota::BuyerQuotaInterface<T::AccountId> for pall
             // 如果是新用户，初始化额度
             if profile.total_orders == 0 && profile.max_quota == 0 {
                 profile.credit_score = T::InitialBuyerCreditScore::get();
-                profile.max_quota = crate::quota::calculate_max_quota(
+                profile.max_quota = Self::tiered_max_quota(
+                    buyer,
                     profile.credit_score,
                     profile.total_orders,
                 );
@@ -1864,6 +2157,16 @@ impl<T: pallet::Config> crate::quota::BuyerQuotaInterface<T::AccountId> for pall
                 });
             }
 
+            // 🆕 KYC等级额度天花板（等级可能在额度计算后被撤销或降级）
+            let tier = <T::KycTierProvider as crate::quota::KycTierProvider<T::AccountId>>::kyc_tier(buyer);
+            if let Some(tier) = tier {
+                ensure!(
+                    profile.occupied_quota.saturating_add(amount_usd)
+                        <= crate::quota::kyc_tier_quota_ceiling(tier),
+                    Error::<T>::KycTierQuotaExceeded
+                );
+            }
+
             // 检查可用额度是否充足
             ensure!(
                 profile.available_quota >= amount_usd,
@@ -1953,7 +2256,8 @@ impl<T: pallet::Config> crate::quota::BuyerQuotaInterface<T::AccountId> for pall
 
             // 重新计算最大额度
             let old_max_quota = profile.max_quota;
-            profile.max_quota = crate::quota::calculate_max_quota(
+            profile.max_quota = Self::tiered_max_quota(
+                buyer,
                 profile.credit_score,
                 profile.total_orders,
             );
@@ -2122,7 +2426,8 @@ impl<T: pallet::Config> crate::quota::BuyerQuotaInterface<T::AccountId> for pall
 
                 // 重新计算最大额度
                 let old_max_quota = p.max_quota;
-                p.max_quota = crate::quota::calculate_max_quota(
+                p.max_quota = Self::tiered_max_quota(
+                    buyer,
                     p.credit_score,
                     p.total_orders,
                 );
@@ -103,6 +103,16 @@ parameter_types! {
     pub const MakerDisputeLossPenalty: u16 = 50;
     pub const MakerSuspensionThreshold: u16 = 750;
     pub const MakerWarningThreshold: u16 = 800;
+    /// 🆕 测试用KYC等级（None 表示未接入分级KYC）
+    pub static MockKycTier: Option<u8> = None;
+}
+
+/// 🆕 测试用KYC等级提供者
+pub struct MockKycTierProvider;
+impl pallet_credit::quota::KycTierProvider<u64> for MockKycTierProvider {
+    fn kyc_tier(_who: &u64) -> Option<u8> {
+        MockKycTier::get()
+    }
 }
 
 impl pallet_credit::Config for Test {
@@ -120,6 +130,8 @@ impl pallet_credit::Config for Test {
     type MakerSuspensionThreshold = MakerSuspensionThreshold;
     type MakerWarningThreshold = MakerWarningThreshold;
     type CreditWeightInfo = ();
+    type ReputationGovernanceOrigin = frame_system::EnsureRoot<u64>;
+    type KycTierProvider = MockKycTierProvider;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
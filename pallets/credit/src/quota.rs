@@ -164,6 +164,33 @@ pub fn calculate_max_quota(credit_score: u16, total_orders: u32) -> u64 {
         .min(10000_000_000)
 }
 
+/// 🆕 函数级详细中文注释：KYC等级对应的额度天花板
+///
+/// # 参数
+/// - `tier`: KYC等级代码（0=无分级认证, 1=手机, 2=身份证件, 3=增强尽职调查）
+///
+/// # 返回
+/// 该等级允许的最大占用额度（USD，精度10^6）
+pub fn kyc_tier_quota_ceiling(tier: u8) -> u64 {
+    match tier {
+        0 => 100_000_000,    // 100 USD
+        1 => 500_000_000,    // 500 USD
+        2 => 2000_000_000,   // 2000 USD
+        _ => 10000_000_000,  // 10000 USD（与全局上限一致）
+    }
+}
+
+/// 🆕 函数级详细中文注释：按KYC等级封顶的最大额度
+///
+/// `tier` 为 None 表示未接入分级KYC，沿用 `calculate_max_quota`。
+pub fn calculate_tiered_max_quota(credit_score: u16, total_orders: u32, tier: Option<u8>) -> u64 {
+    let quota = calculate_max_quota(credit_score, total_orders);
+    match tier {
+        Some(tier) => quota.min(kyc_tier_quota_ceiling(tier)),
+        None => quota,
+    }
+}
+
 /// 函数级详细中文注释：计算最大并发订单数
 ///
 /// # 参数
@@ -309,6 +336,19 @@ pub trait BuyerQuotaInterface<AccountId> {
     fn is_blacklisted(buyer: &AccountId) -> Result<bool, sp_runtime::DispatchError>;
 }
 
+/// 🆕 函数级详细中文注释：KYC等级查询接口（由OTC等持有分级认证的模块实现）
+pub trait KycTierProvider<AccountId> {
+    /// 返回账户当前有效的KYC等级代码；None 表示未接入分级KYC
+    fn kyc_tier(who: &AccountId) -> Option<u8>;
+}
+
+/// 函数级中文注释：默认实现，不接入分级KYC
+impl<AccountId> KycTierProvider<AccountId> for () {
+    fn kyc_tier(_who: &AccountId) -> Option<u8> {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -328,6 +368,19 @@ mod tests {
         assert_eq!(calculate_max_quota(450, 10), 100_000_000); // 100 USD
     }
 
+    #[test]
+    fn test_calculate_tiered_max_quota() {
+        // 未接入分级KYC：不封顶
+        assert_eq!(calculate_tiered_max_quota(950, 50, None), 5250_000_000);
+        // 无分级认证 / 手机 / 证件 / EDD
+        assert_eq!(calculate_tiered_max_quota(950, 50, Some(0)), 100_000_000);
+        assert_eq!(calculate_tiered_max_quota(950, 50, Some(1)), 500_000_000);
+        assert_eq!(calculate_tiered_max_quota(950, 50, Some(2)), 2000_000_000);
+        assert_eq!(calculate_tiered_max_quota(950, 50, Some(3)), 5250_000_000);
+        // 首购限制优先于等级
+        assert_eq!(calculate_tiered_max_quota(800, 0, Some(3)), 10_000_000);
+    }
+
     #[test]
     fn test_calculate_max_concurrent() {
         assert_eq!(calculate_max_concurrent(0), 1);   // 首购：1笔
@@ -0,0 +1,210 @@
+//! # Reputation Module (跨领域统一信誉模块)
+//!
+//! ## 函数级详细中文注释：OTC、占卜服务、内容治理共用的信誉引擎
+//!
+//! ### 核心功能
+//! - 按领域维护子信誉分（0-1000，初始500）
+//! - 按治理可调权重计算跨领域综合分
+//! - 违规按传导矩阵同步影响其他领域（如OTC违约降低占卜服务资格）
+//! - 买家背书计入综合分，不再局限于OTC
+//!
+//! ### 设计理念
+//! 各业务模块保留自己的明细档案（买家信用、做市商信用、占卜师信用档案），
+//! 只通过 `ReputationProvider` 上报正向行为与违规，由本模块统一汇总并判断资格。
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// 函数级详细中文注释：领域子信誉分初始值
+pub const INITIAL_DOMAIN_SCORE: u16 = 500;
+
+/// 函数级详细中文注释：信誉分上限
+pub const MAX_REPUTATION_SCORE: u16 = 1000;
+
+/// 函数级详细中文注释：万分比基数
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+// ===== 数据结构 =====
+
+/// 函数级详细中文注释：信誉领域
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum ReputationDomain {
+    /// OTC 交易（买家、做市商）
+    Otc,
+    /// 占卜服务（服务提供者）
+    DivinationService,
+    /// 内容治理（举报、申诉、审核）
+    ContentGovernance,
+}
+
+impl ReputationDomain {
+    /// 函数级详细中文注释：全部领域（顺序与参数数组下标一致）
+    pub const ALL: [ReputationDomain; 3] = [
+        ReputationDomain::Otc,
+        ReputationDomain::DivinationService,
+        ReputationDomain::ContentGovernance,
+    ];
+
+    /// 函数级详细中文注释：领域在参数数组中的下标
+    pub fn index(&self) -> usize {
+        match self {
+            ReputationDomain::Otc => 0,
+            ReputationDomain::DivinationService => 1,
+            ReputationDomain::ContentGovernance => 2,
+        }
+    }
+}
+
+/// 函数级详细中文注释：单个领域的子信誉记录
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DomainReputation<BlockNumber> {
+    /// 子信誉分（0-1000）
+    pub score: u16,
+    /// 正向行为次数（完成订单等）
+    pub positive_count: u32,
+    /// 本领域违规次数
+    pub violation_count: u32,
+    /// 累计受其他领域违规传导的扣分
+    pub spillover_deductions: u32,
+    /// 最近一次违规（含传导）区块
+    pub last_violation_at: Option<BlockNumber>,
+    /// 最近更新区块
+    pub updated_at: BlockNumber,
+}
+
+impl<BlockNumber: Default> Default for DomainReputation<BlockNumber> {
+    fn default() -> Self {
+        Self {
+            score: INITIAL_DOMAIN_SCORE,
+            positive_count: 0,
+            violation_count: 0,
+            spillover_deductions: 0,
+            last_violation_at: None,
+            updated_at: BlockNumber::default(),
+        }
+    }
+}
+
+/// 函数级详细中文注释：信誉引擎参数（治理可调）
+///
+/// 数组下标与 `ReputationDomain::index()` 一致。
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct ReputationParams {
+    /// 各领域在综合分中的相对权重
+    pub domain_weights: [u16; 3],
+    /// 违规传导比例（万分比）：`spillover_bps[来源领域][目标领域]`，对角线不生效
+    pub spillover_bps: [[u16; 3]; 3],
+    /// 每个有效背书的综合分加成
+    pub endorsement_bonus: u16,
+    /// 背书加成上限
+    pub max_endorsement_bonus: u16,
+    /// 资格门槛：领域子信誉分下限
+    pub min_domain_score: u16,
+    /// 资格门槛：综合分下限
+    pub min_aggregate_score: u16,
+}
+
+impl Default for ReputationParams {
+    fn default() -> Self {
+        Self {
+            domain_weights: [40, 40, 20],
+            spillover_bps: [
+                [0, 3_000, 2_000],
+                [3_000, 0, 2_000],
+                [2_000, 3_000, 0],
+            ],
+            endorsement_bonus: 10,
+            max_endorsement_bonus: 50,
+            min_domain_score: 300,
+            min_aggregate_score: 350,
+        }
+    }
+}
+
+impl ReputationParams {
+    /// 函数级详细中文注释：参数合法性校验
+    /// - 权重之和必须大于0
+    /// - 传导比例不超过100%
+    /// - 门槛不超过分数上限
+    pub fn is_valid(&self) -> bool {
+        self.domain_weights.iter().any(|w| *w > 0)
+            && self
+                .spillover_bps
+                .iter()
+                .flatten()
+                .all(|bps| *bps as u32 <= BPS_DENOMINATOR)
+            && self.min_domain_score <= MAX_REPUTATION_SCORE
+            && self.min_aggregate_score <= MAX_REPUTATION_SCORE
+    }
+
+    /// 函数级详细中文注释：计算违规传导到目标领域的扣分
+    pub fn spillover(&self, from: ReputationDomain, to: ReputationDomain, points: u16) -> u16 {
+        if from == to {
+            return 0;
+        }
+        let bps = self.spillover_bps[from.index()][to.index()] as u32;
+        (points as u32 * bps / BPS_DENOMINATOR) as u16
+    }
+
+    /// 函数级详细中文注释：按权重计算综合分（不含背书加成）
+    pub fn weighted_score(&self, scores: [u16; 3]) -> u16 {
+        let total_weight: u32 = self.domain_weights.iter().map(|w| *w as u32).sum();
+        if total_weight == 0 {
+            return INITIAL_DOMAIN_SCORE;
+        }
+        let weighted: u32 = scores
+            .iter()
+            .zip(self.domain_weights.iter())
+            .map(|(s, w)| *s as u32 * *w as u32)
+            .sum();
+        (weighted / total_weight) as u16
+    }
+}
+
+// ===== 接口定义 =====
+
+/// 函数级详细中文注释：统一信誉接口（供各业务 Pallet 调用）
+///
+/// 业务模块只上报行为，分数、传导和资格判断由实现方统一处理。
+pub trait ReputationProvider<AccountId> {
+    /// 查询领域子信誉分
+    fn domain_score(who: &AccountId, domain: ReputationDomain) -> u16;
+
+    /// 查询跨领域综合分（含背书加成）
+    fn aggregate_score(who: &AccountId) -> u16;
+
+    /// 检查账户在某领域是否满足资格门槛（领域分与综合分均需达标）
+    fn is_eligible(who: &AccountId, domain: ReputationDomain) -> bool;
+
+    /// 记录正向行为（加分）
+    fn record_positive(who: &AccountId, domain: ReputationDomain, points: u16);
+
+    /// 记录违规（本领域扣分，并按传导矩阵影响其他领域）
+    fn record_violation(who: &AccountId, domain: ReputationDomain, points: u16);
+}
+
+/// 函数级详细中文注释：空实现（未接入信誉引擎时使用，始终满足资格）
+impl<AccountId> ReputationProvider<AccountId> for () {
+    fn domain_score(_who: &AccountId, _domain: ReputationDomain) -> u16 {
+        INITIAL_DOMAIN_SCORE
+    }
+
+    fn aggregate_score(_who: &AccountId) -> u16 {
+        INITIAL_DOMAIN_SCORE
+    }
+
+    fn is_eligible(_who: &AccountId, _domain: ReputationDomain) -> bool {
+        true
+    }
+
+    fn record_positive(_who: &AccountId, _domain: ReputationDomain, _points: u16) {}
+
+    fn record_violation(_who: &AccountId, _domain: ReputationDomain, _points: u16) {}
+}
@@ -7,8 +7,9 @@
 //! - 做市商信用系统（初始化、完成订单、超时、争议、状态）
 //! - 推荐和背书系统
 //! - 信用评分计算
+//! - 跨领域信誉（子信誉分、违规传导、综合分、治理参数）
 
-use crate::{mock::*, buyer, maker, Error};
+use crate::{mock::*, buyer, maker, reputation, Error};
 use frame_support::{assert_noop, assert_ok};
 
 // ========================================
@@ -554,3 +555,167 @@ fn test_endorse_user_already_endorsed() {
         );
     });
 }
+
+// ========================================
+// 跨领域信誉测试
+// ========================================
+
+#[test]
+fn test_reputation_violation_spills_over_to_other_domains() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let user = 1u64;
+        let params = reputation::ReputationParams::default();
+
+        // Act - OTC 领域违规 100 分
+        Credit::apply_reputation_violation(&user, reputation::ReputationDomain::Otc, 100);
+
+        // Assert - 本领域全额扣分，其他领域按传导矩阵扣分
+        let otc = Credit::domain_reputation(&user, reputation::ReputationDomain::Otc);
+        assert_eq!(otc.score, reputation::INITIAL_DOMAIN_SCORE - 100);
+        assert_eq!(otc.violation_count, 1);
+
+        let divination = Credit::domain_reputation(&user, reputation::ReputationDomain::DivinationService);
+        let spillover = params.spillover(
+            reputation::ReputationDomain::Otc,
+            reputation::ReputationDomain::DivinationService,
+            100,
+        );
+        assert_eq!(spillover, 30);
+        assert_eq!(divination.score, reputation::INITIAL_DOMAIN_SCORE - spillover);
+        assert_eq!(divination.violation_count, 0);
+        assert_eq!(divination.spillover_deductions, 30);
+    });
+}
+
+#[test]
+fn test_reputation_eligibility_affected_by_other_domain() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let user = 1u64;
+        let domain = reputation::ReputationDomain::DivinationService;
+        assert!(Credit::is_reputation_eligible(&user, domain));
+
+        // Act - 多次 OTC 违规，拉低综合分
+        for _ in 0..3 {
+            Credit::apply_reputation_violation(&user, reputation::ReputationDomain::Otc, 100);
+        }
+
+        // Assert - 占卜服务领域分仍达标，但综合分不足，失去资格
+        let params = reputation::ReputationParams::default();
+        assert!(Credit::domain_reputation(&user, domain).score >= params.min_domain_score);
+        assert!(Credit::calculate_aggregate_reputation(&user) < params.min_aggregate_score);
+        assert!(!Credit::is_reputation_eligible(&user, domain));
+    });
+}
+
+#[test]
+fn test_reputation_aggregate_includes_endorsements() {
+    new_test_ext().execute_with(|| {
+        let endorser = 1u64;
+        let endorsee = 2u64;
+
+        let _ = Credit::initialize_new_user_credit(&endorser);
+        let _ = Credit::initialize_new_user_credit(&endorsee);
+        for _ in 0..5 {
+            Credit::update_credit_on_success(&endorser, 100_000_000, 300);
+        }
+        let before = Credit::calculate_aggregate_reputation(&endorsee);
+
+        // Act - 推荐
+        assert_ok!(Credit::endorse_user(RuntimeOrigin::signed(endorser), endorsee));
+
+        // Assert - 背书计入综合分
+        let params = reputation::ReputationParams::default();
+        assert_eq!(
+            Credit::calculate_aggregate_reputation(&endorsee),
+            before + params.endorsement_bonus
+        );
+    });
+}
+
+#[test]
+fn test_set_reputation_params_requires_governance() {
+    new_test_ext().execute_with(|| {
+        let mut params = reputation::ReputationParams::default();
+        params.domain_weights = [1, 0, 0];
+
+        assert_noop!(
+            Credit::set_reputation_params(RuntimeOrigin::signed(1), params.clone()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Credit::set_reputation_params(RuntimeOrigin::root(), params.clone()));
+        assert_eq!(crate::ReputationParameters::<Test>::get(), params);
+
+        // 权重全为0无效
+        params.domain_weights = [0, 0, 0];
+        assert_noop!(
+            Credit::set_reputation_params(RuntimeOrigin::root(), params),
+            Error::<Test>::InvalidReputationParams
+        );
+    });
+}
+
+#[test]
+fn test_report_reputation_violation_content_governance() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let user = 1u64;
+
+        assert_noop!(
+            Credit::report_reputation_violation(
+                RuntimeOrigin::root(),
+                user,
+                reputation::ReputationDomain::ContentGovernance,
+                0,
+            ),
+            Error::<Test>::InvalidViolationPoints
+        );
+        assert_ok!(Credit::report_reputation_violation(
+            RuntimeOrigin::root(),
+            user,
+            reputation::ReputationDomain::ContentGovernance,
+            50,
+        ));
+
+        let content = Credit::domain_reputation(&user, reputation::ReputationDomain::ContentGovernance);
+        assert_eq!(content.score, reputation::INITIAL_DOMAIN_SCORE - 50);
+    });
+}
+
+#[test]
+fn test_quota_capped_by_kyc_tier() {
+    use crate::quota::{self, BuyerQuotaInterface};
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let buyer = 1u64;
+
+        // 高信用老用户：原始额度 5000 USD
+        crate::BuyerQuotas::<Test>::mutate(buyer, |p| {
+            p.credit_score = 950;
+            p.total_orders = 20;
+            p.max_quota = quota::calculate_max_quota(950, 20);
+            p.available_quota = p.max_quota;
+            p.max_concurrent_orders = 5;
+        });
+
+        // 仅手机认证：单次占用超过 500 USD 被拒绝
+        MockKycTier::set(Some(1));
+        assert_noop!(
+            <Credit as BuyerQuotaInterface<u64>>::occupy_quota(&buyer, 600_000_000),
+            Error::<Test>::KycTierQuotaExceeded
+        );
+        assert_ok!(<Credit as BuyerQuotaInterface<u64>>::occupy_quota(&buyer, 400_000_000));
+
+        // 升级为增强尽职调查后不再受等级封顶
+        MockKycTier::set(Some(3));
+        assert_ok!(<Credit as BuyerQuotaInterface<u64>>::occupy_quota(&buyer, 600_000_000));
+
+        // 额度重新计算时按等级封顶
+        assert_eq!(Credit::tiered_max_quota(&buyer, 950, 20), 5000_000_000);
+        MockKycTier::set(Some(2));
+        assert_eq!(Credit::tiered_max_quota(&buyer, 950, 20), 2000_000_000);
+        MockKycTier::set(None);
+        assert_eq!(Credit::tiered_max_quota(&buyer, 950, 20), 5000_000_000);
+    });
+}
@@ -356,6 +356,14 @@ pub mod pallet {
             agent_id: u64,
             new_status: u8,
         },
+
+        /// 函数级中文注释：智能体已被治理暂停
+        /// - agent_id: 智能体ID
+        AgentSuspended { agent_id: u64 },
+
+        /// 函数级中文注释：智能体已被治理恢复（恢复为已下线，由所有者重新上线）
+        /// - agent_id: 智能体ID
+        AgentRestored { agent_id: u64 },
     }
 
     // =================== 错误 ===================
@@ -412,6 +420,12 @@ pub mod pallet {
 
         /// 函数级中文注释：数值溢出
         Overflow,
+
+        /// 函数级中文注释：智能体已被治理暂停
+        AgentSuspended,
+
+        /// 函数级中文注释：智能体未被治理暂停
+        AgentNotSuspended,
     }
 
     // =================== Extrinsics实现 ===================
@@ -1056,7 +1070,13 @@ pub mod pallet {
                 // 检查权限
                 ensure!(agent.provider_id == provider_id, Error::<T>::NotAgentOwner);
 
-                // 更新状态和时间
+                // 治理暂停的智能体不可由所有者变更状态
+                ensure!(
+                    agent.deployment_status != DeploymentStatus::Suspended,
+                    Error::<T>::AgentSuspended
+                );
+
+                // 更新状态和时间（from_u8 不会产生 Suspended）
                 agent.deployment_status = DeploymentStatus::from_u8(new_status);
                 let current_block = frame_system::Pallet::<T>::block_number();
                 agent.updated_at = current_block;
@@ -1072,6 +1092,59 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// 函数级详细中文注释：治理暂停/恢复智能体（治理操作）
+        ///
+        /// ## 功能
+        /// - 由内容治理申诉执行路由调用
+        /// - 暂停：部署状态置为 Suspended，所有者不可自行恢复
+        /// - 恢复：部署状态置为 Offline，由所有者决定是否重新上线
+        ///
+        /// ## 参数
+        /// - `origin`: 治理起源
+        /// - `agent_id`: 智能体ID
+        /// - `suspended`: true=暂停，false=恢复
+        ///
+        /// ## 错误
+        /// - `AgentNotFound`: 智能体不存在
+        /// - `AgentSuspended`: 已处于暂停状态
+        /// - `AgentNotSuspended`: 未处于暂停状态
+        ///
+        /// ## 事件
+        /// - `AgentSuspended` / `AgentRestored`
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::gov_set_agent_suspended())]
+        pub fn gov_set_agent_suspended(
+            origin: OriginFor<T>,
+            agent_id: u64,
+            suspended: bool,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            AIAgents::<T>::try_mutate(agent_id, |maybe_agent| -> DispatchResult {
+                let agent = maybe_agent.as_mut().ok_or(Error::<T>::AgentNotFound)?;
+                let is_suspended = agent.deployment_status == DeploymentStatus::Suspended;
+
+                if suspended {
+                    ensure!(!is_suspended, Error::<T>::AgentSuspended);
+                    agent.deployment_status = DeploymentStatus::Suspended;
+                } else {
+                    ensure!(is_suspended, Error::<T>::AgentNotSuspended);
+                    agent.deployment_status = DeploymentStatus::Offline;
+                }
+                agent.updated_at = frame_system::Pallet::<T>::block_number();
+
+                Ok(())
+            })?;
+
+            if suspended {
+                Self::deposit_event(Event::AgentSuspended { agent_id });
+            } else {
+                Self::deposit_event(Event::AgentRestored { agent_id });
+            }
+
+            Ok(())
+        }
     }
 
     // =================== Helper Functions ===================
@@ -220,6 +220,7 @@ impl AIModelType {
 /// - **Testing**: 测试中（模型正在测试阶段）
 /// - **Live**: 已上线（模型可供用户使用）
 /// - **Offline**: 已下线（模型暂停服务）
+/// - **Suspended**: 治理暂停（内容治理申诉执行，所有者不可自行恢复）
 #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
 pub enum DeploymentStatus {
     /// 测试中
@@ -228,6 +229,8 @@ pub enum DeploymentStatus {
     Live = 1,
     /// 已下线
     Offline = 2,
+    /// 治理暂停
+    Suspended = 3,
 }
 
 impl Default for DeploymentStatus {
@@ -243,6 +246,7 @@ impl DeploymentStatus {
             DeploymentStatus::Testing => 0,
             DeploymentStatus::Live => 1,
             DeploymentStatus::Offline => 2,
+            DeploymentStatus::Suspended => 3,
         }
     }
 
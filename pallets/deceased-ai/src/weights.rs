@@ -27,6 +27,7 @@ pub trait WeightInfo {
     fn update_task_status() -> Weight;
     fn register_ai_agent() -> Weight;
     fn update_agent_status() -> Weight;
+    fn gov_set_agent_suspended() -> Weight;
 }
 
 /// Weights for pallet_deceased_ai using the Substrate node and recommended hardware.
@@ -59,6 +60,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn update_agent_status() -> Weight {
         Weight::from_parts(10_000, 0)
     }
+    fn gov_set_agent_suspended() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
 }
 
 // For backwards compatibility and tests
@@ -90,4 +94,7 @@ impl WeightInfo for () {
     fn update_agent_status() -> Weight {
         Weight::from_parts(10_000, 0)
     }
+    fn gov_set_agent_suspended() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
 }
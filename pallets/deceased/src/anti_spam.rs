@@ -35,6 +35,7 @@ use super::*;
 use crate::pallet::Config;
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::pallet_prelude::*;
+use pallet_membership::MembershipBenefits;
 use scale_info::TypeInfo;
 use sp_runtime::traits::{Zero, Saturating, SaturatedConversion};
 
@@ -141,8 +142,9 @@ impl<T: Config> Pallet<T> {
 	/// - 浏览：1000次/天
 	/// - 分享：100次/天
 	/// - 收藏：50次/天
+	/// - 🆕 会员按等级获得限额加成：限额 = 基础限额 × (100 + 加成) / 100
 	fn check_daily_limit(who: &T::AccountId, operation_type: OperationType) -> DispatchResult {
-		let limit = Self::get_daily_limit(operation_type);
+		let limit = Self::member_daily_limit(who, operation_type);
 		let current_block = <frame_system::Pallet<T>>::block_number();
 		let mut info = DailyOperationCount::<T>::get(who, operation_type);
 
@@ -289,6 +291,13 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// 函数级中文注释：🆕 含会员加成的每日限额
+	fn member_daily_limit(who: &T::AccountId, operation_type: OperationType) -> u32 {
+		let base = Self::get_daily_limit(operation_type);
+		let bonus = T::MembershipBenefits::anti_spam_bonus_percent(who);
+		base.saturating_add(base.saturating_mul(bonus.into()) / 100)
+	}
+
 	/// 获取时间窗口（区块数）
 	fn get_time_window(operation_type: OperationType) -> BlockNumberFor<T> {
 		match operation_type {
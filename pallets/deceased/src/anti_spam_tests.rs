@@ -12,6 +12,7 @@
 //! - test_daily_limit_view_reached: 浏览达到限额
 //! - test_daily_limit_share_reached: 分享达到限额
 //! - test_daily_limit_favorite_reached: 收藏达到限额
+//! - test_daily_limit_member_bonus: 会员限额加成
 //! - test_daily_limit_reset_next_day: 跨天自动重置
 //!
 //! ### 第2层：时间窗口防重复（Time Window Deduplication）
@@ -136,6 +137,27 @@ fn test_daily_limit_favorite_reached() {
 	});
 }
 
+#[test]
+fn test_daily_limit_member_bonus() {
+	ExtBuilder::default().build().execute_with(|| {
+		// 账户 7 为会员（Mock：限额加成 100%），收藏限额 50 → 100
+		let member = 7u64;
+		let work_id = 100u64;
+
+		for _i in 0..100 {
+			assert_ok!(
+				Deceased::check_anti_spam(&member, work_id + _i, OperationType::Favorite)
+			);
+		}
+
+		// 第101次应失败：DailyLimitExceeded
+		assert_noop!(
+			Deceased::check_anti_spam(&member, work_id + 101, OperationType::Favorite),
+			Error::<Test>::DailyLimitExceeded
+		);
+	});
+}
+
 #[test]
 fn test_daily_limit_reset_next_day() {
 	ExtBuilder::default().build().execute_with(|| {
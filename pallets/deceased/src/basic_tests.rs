@@ -108,6 +108,7 @@ pub mod simple_test_env {
         type RuntimeHoldReason = MockHoldReason;
         type TreasuryAccount = MockTreasuryAccount;
         type Social = MockSocial;
+        type MembershipBenefits = ();
     }
 
     // Mock 实现
@@ -0,0 +1,182 @@
+// 函数级详细中文注释：族谱图查询（多代祖先/后代遍历、共同祖先查找）
+//
+// ## 核心功能
+// 1. **父母/子女解析**：合并 `RelationsByDeceased`（有向关系 `from` 方）与 `IncomingRelations`（`to` 方）
+//    - 0=ParentOf：`from` 是 `to` 的父/母
+//    - 3=ChildOf：`from` 是 `to` 的子/女
+// 2. **多代遍历**：广度优先，按代数（深度）递增返回，带深度与节点数上限
+// 3. **共同祖先**：双方各自向上遍历后求交集，按代数之和由近到远排序
+//
+// ## 设计理念
+// - **只读**：仅供 Runtime API / 链下工具调用，不在交易路径中使用
+// - **有界**：深度上限 `MAX_GENEALOGY_DEPTH`，单次结果上限 `MAX_GENEALOGY_NODES`，防止 RPC 被大图拖垮
+// - **防环**：已访问集合去重，错误录入形成环时也能终止
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+use crate::pallet::{IncomingRelations, RelationsByDeceased};
+use crate::{Config, Pallet};
+
+/// 族谱遍历最大代数
+pub const MAX_GENEALOGY_DEPTH: u8 = 16;
+
+/// 单次遍历返回的最大节点数
+pub const MAX_GENEALOGY_NODES: usize = 512;
+
+/// 关系类型：父母（`from` 是 `to` 的父/母）
+pub const RELATION_PARENT_OF: u8 = 0;
+
+/// 关系类型：子女（`from` 是 `to` 的子/女）
+pub const RELATION_CHILD_OF: u8 = 3;
+
+/// 函数级中文注释：族谱节点（逝者ID + 与起点相隔的代数）
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct GenealogyNode<DeceasedId> {
+    pub id: DeceasedId,
+    /// 代数：1=父母/子女，2=祖辈/孙辈，依此类推
+    pub depth: u8,
+}
+
+/// 函数级中文注释：共同祖先（分别距 a、b 的代数；祖先即一方本人时代数为 0）
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CommonAncestor<DeceasedId> {
+    pub id: DeceasedId,
+    pub depth_a: u8,
+    pub depth_b: u8,
+}
+
+/// 函数级中文注释：单跳关系（GEDCOM 导出用）
+/// - `outgoing=true`：本人是关系记录的 `from` 方（无向关系恒为 true）
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct KinshipLink<DeceasedId> {
+    pub peer: DeceasedId,
+    pub kind: u8,
+    pub outgoing: bool,
+}
+
+impl<T: Config> Pallet<T> {
+    /// 函数级中文注释：直接父母（本人 ChildOf 对方 + 对方 ParentOf 本人）
+    pub fn parents_of(id: T::DeceasedId) -> Vec<T::DeceasedId> {
+        Self::directed_peers(id, RELATION_CHILD_OF, RELATION_PARENT_OF)
+    }
+
+    /// 函数级中文注释：直接子女（本人 ParentOf 对方 + 对方 ChildOf 本人）
+    pub fn children_of(id: T::DeceasedId) -> Vec<T::DeceasedId> {
+        Self::directed_peers(id, RELATION_PARENT_OF, RELATION_CHILD_OF)
+    }
+
+    /// 函数级中文注释：多代祖先，按代数递增；`max_depth` 超过上限时截断为 `MAX_GENEALOGY_DEPTH`
+    pub fn ancestors(id: T::DeceasedId, max_depth: u8) -> Vec<GenealogyNode<T::DeceasedId>> {
+        Self::traverse(id, max_depth, Self::parents_of)
+    }
+
+    /// 函数级中文注释：多代后代，按代数递增；`max_depth` 超过上限时截断为 `MAX_GENEALOGY_DEPTH`
+    pub fn descendants(id: T::DeceasedId, max_depth: u8) -> Vec<GenealogyNode<T::DeceasedId>> {
+        Self::traverse(id, max_depth, Self::children_of)
+    }
+
+    /// 函数级详细中文注释：共同祖先查找
+    ///
+    /// ### 规则
+    /// - 双方本人也参与比较：a 是 b 的祖先时返回 `{id: a, depth_a: 0, depth_b: n}`
+    /// - 按 `depth_a + depth_b` 升序（最近共同祖先在前），同代数之和按 `depth_a` 升序
+    /// - a == b 时返回空列表
+    pub fn common_ancestors(
+        a: T::DeceasedId,
+        b: T::DeceasedId,
+        max_depth: u8,
+    ) -> Vec<CommonAncestor<T::DeceasedId>> {
+        if a == b {
+            return Vec::new();
+        }
+        let lineage = |id: T::DeceasedId| -> BTreeMap<T::DeceasedId, u8> {
+            let mut map: BTreeMap<T::DeceasedId, u8> =
+                Self::ancestors(id, max_depth).into_iter().map(|n| (n.id, n.depth)).collect();
+            map.insert(id, 0);
+            map
+        };
+        let side_a = lineage(a);
+        let side_b = lineage(b);
+
+        let mut common: Vec<CommonAncestor<T::DeceasedId>> = side_a
+            .iter()
+            .filter_map(|(id, depth_a)| {
+                side_b.get(id).map(|depth_b| CommonAncestor {
+                    id: *id,
+                    depth_a: *depth_a,
+                    depth_b: *depth_b,
+                })
+            })
+            .collect();
+        common.sort_by_key(|c| (c.depth_a as u16 + c.depth_b as u16, c.depth_a));
+        common.truncate(MAX_GENEALOGY_NODES);
+        common
+    }
+
+    /// 函数级中文注释：本人参与的全部单跳关系（含有向关系的反向一侧）
+    pub fn relations_of(id: T::DeceasedId) -> Vec<KinshipLink<T::DeceasedId>> {
+        let mut links: Vec<KinshipLink<T::DeceasedId>> = RelationsByDeceased::<T>::get(id)
+            .into_iter()
+            .map(|(peer, kind)| KinshipLink { peer, kind, outgoing: true })
+            .collect();
+        links.extend(
+            IncomingRelations::<T>::get(id)
+                .into_iter()
+                .map(|(peer, kind)| KinshipLink { peer, kind, outgoing: false }),
+        );
+        links
+    }
+
+    /// 函数级中文注释：合并出边中 `out_kind` 的目标与入边中 `in_kind` 的来源（去重）
+    fn directed_peers(id: T::DeceasedId, out_kind: u8, in_kind: u8) -> Vec<T::DeceasedId> {
+        let mut peers: Vec<T::DeceasedId> = RelationsByDeceased::<T>::get(id)
+            .into_iter()
+            .filter(|(_, kind)| *kind == out_kind)
+            .map(|(peer, _)| peer)
+            .collect();
+        for (peer, kind) in IncomingRelations::<T>::get(id) {
+            if kind == in_kind && !peers.contains(&peer) {
+                peers.push(peer);
+            }
+        }
+        peers
+    }
+
+    /// 函数级中文注释：广度优先遍历，`next` 给出下一代节点
+    fn traverse(
+        root: T::DeceasedId,
+        max_depth: u8,
+        next: fn(T::DeceasedId) -> Vec<T::DeceasedId>,
+    ) -> Vec<GenealogyNode<T::DeceasedId>> {
+        let max_depth = max_depth.min(MAX_GENEALOGY_DEPTH);
+        let mut visited = BTreeSet::new();
+        visited.insert(root);
+        let mut result = Vec::new();
+        let mut frontier = sp_std::vec![root];
+
+        for depth in 1..=max_depth {
+            let mut generation = Vec::new();
+            for id in frontier {
+                for peer in next(id) {
+                    if !visited.insert(peer) {
+                        continue;
+                    }
+                    result.push(GenealogyNode { id: peer, depth });
+                    if result.len() >= MAX_GENEALOGY_NODES {
+                        return result;
+                    }
+                    generation.push(peer);
+                }
+            }
+            if generation.is_empty() {
+                break;
+            }
+            frontier = generation;
+        }
+        result
+    }
+}
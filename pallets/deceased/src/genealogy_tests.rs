@@ -0,0 +1,179 @@
+// 函数级中文注释：族谱遍历（祖先/后代/共同祖先）单元测试
+
+use crate::{
+    mock::*, DeceasedOf, GenealogyNode, IncomingRelations, Pallet, MAX_GENEALOGY_DEPTH,
+};
+use alloc::vec::Vec;
+use frame_support::assert_ok;
+
+// ==================== Helper Functions ====================
+
+/// 函数级中文注释：账户1创建逝者（姓名区分token），返回新生成的随机ID
+fn create(name: &[u8]) -> u64 {
+    let before: Vec<u64> = DeceasedOf::<Test>::iter_keys().collect();
+    assert_ok!(Pallet::<Test>::create_deceased(
+        RuntimeOrigin::signed(1),
+        name.to_vec(),
+        0,
+        None,
+        b"19300101".to_vec(),
+        b"20100101".to_vec(),
+        Vec::new(),
+    ));
+    DeceasedOf::<Test>::iter_keys()
+        .find(|id| !before.contains(id))
+        .expect("new deceased id")
+}
+
+/// 函数级中文注释：同一拥有者提案并批准关系
+fn relate(from: u64, to: u64, kind: u8) {
+    assert_ok!(Pallet::<Test>::propose_relation(RuntimeOrigin::signed(1), from, to, kind, None));
+    assert_ok!(Pallet::<Test>::approve_relation(RuntimeOrigin::signed(1), from, to));
+}
+
+fn ids(nodes: &[GenealogyNode<u64>]) -> Vec<(u64, u8)> {
+    nodes.iter().map(|n| (n.id, n.depth)).collect()
+}
+
+// ==================== Tests ====================
+
+/// Test: ParentOf 与 ChildOf 两种方向都能解析出父母/子女，反向索引随撤销清理
+#[test]
+fn parents_and_children_resolve_both_directions() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let father = create(b"Father");
+        let mother = create(b"Mother");
+        let child = create(b"Child");
+
+        relate(father, child, 0); // father ParentOf child
+        relate(child, mother, 3); // child ChildOf mother
+
+        let mut parents = Pallet::<Test>::parents_of(child);
+        parents.sort();
+        let mut expected = vec![father, mother];
+        expected.sort();
+        assert_eq!(parents, expected);
+        assert_eq!(Pallet::<Test>::children_of(father), vec![child]);
+        assert_eq!(Pallet::<Test>::children_of(mother), vec![child]);
+
+        assert_ok!(Pallet::<Test>::revoke_relation(RuntimeOrigin::signed(1), father, child));
+        assert!(IncomingRelations::<Test>::get(child).is_empty());
+        assert_eq!(Pallet::<Test>::parents_of(child), vec![mother]);
+    });
+}
+
+/// Test: 多代遍历按代数递增，深度参数生效
+#[test]
+fn ancestors_and_descendants_respect_depth() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let grandpa = create(b"Grandpa");
+        let dad = create(b"Dad");
+        let me = create(b"Me");
+        let son = create(b"Son");
+        relate(grandpa, dad, 0);
+        relate(dad, me, 0);
+        relate(son, me, 3);
+
+        assert_eq!(ids(&Pallet::<Test>::ancestors(son, 1)), vec![(me, 1)]);
+        assert_eq!(
+            ids(&Pallet::<Test>::ancestors(son, MAX_GENEALOGY_DEPTH)),
+            vec![(me, 1), (dad, 2), (grandpa, 3)]
+        );
+        assert_eq!(
+            ids(&Pallet::<Test>::descendants(grandpa, 2)),
+            vec![(dad, 1), (me, 2)]
+        );
+        // 超过上限的深度被截断而非报错
+        assert_eq!(Pallet::<Test>::descendants(grandpa, u8::MAX).len(), 3);
+    });
+}
+
+/// Test: 共同祖先按代数之和排序，直系祖先本人以代数0出现
+#[test]
+fn common_ancestors_nearest_first() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let grandma = create(b"Grandma");
+        let uncle = create(b"Uncle");
+        let mom = create(b"Mom");
+        let cousin = create(b"Cousin");
+        let me = create(b"Me");
+        relate(grandma, uncle, 0);
+        relate(grandma, mom, 0);
+        relate(uncle, cousin, 0);
+        relate(mom, me, 0);
+
+        let common = Pallet::<Test>::common_ancestors(me, cousin, 8);
+        assert_eq!(common.len(), 1);
+        assert_eq!((common[0].id, common[0].depth_a, common[0].depth_b), (grandma, 2, 2));
+
+        let direct = Pallet::<Test>::common_ancestors(me, grandma, 8);
+        assert_eq!((direct[0].id, direct[0].depth_a, direct[0].depth_b), (grandma, 2, 0));
+
+        // 深度不足时找不到共同祖先
+        assert!(Pallet::<Test>::common_ancestors(me, cousin, 1).is_empty());
+        assert!(Pallet::<Test>::common_ancestors(me, me, 8).is_empty());
+    });
+}
+
+/// Test: 无向关系不参与祖先遍历，但出现在单跳关系中
+#[test]
+fn spouse_links_are_not_lineage() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let husband = create(b"Husband");
+        let wife = create(b"Wife");
+        let kid = create(b"Kid");
+        relate(husband, wife, 1);
+        relate(wife, kid, 0);
+
+        assert!(Pallet::<Test>::ancestors(husband, 4).is_empty());
+        assert_eq!(ids(&Pallet::<Test>::ancestors(kid, 4)), vec![(wife, 1)]);
+
+        let links = Pallet::<Test>::relations_of(kid);
+        assert_eq!(links.len(), 1);
+        assert_eq!((links[0].peer, links[0].kind, links[0].outgoing), (wife, 0, false));
+        assert_eq!(Pallet::<Test>::relations_of(wife).len(), 2);
+    });
+}
+
+/// Test: v6 → v7 迁移为升级前已批准的有向关系回填反向索引
+#[test]
+fn migrate_v6_to_v7_backfills_incoming_relations() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let father = create(b"Father");
+        let child = create(b"Child");
+        let mother = create(b"Mother");
+        let spouse = create(b"Spouse");
+        relate(father, child, 0);
+        relate(child, mother, 3);
+        relate(father, spouse, 1);
+
+        // 模拟 v6：关系已在链上，但没有反向索引
+        let _ = IncomingRelations::<Test>::clear(u32::MAX, None);
+        assert_eq!(Pallet::<Test>::parents_of(child), vec![mother]);
+        assert!(Pallet::<Test>::children_of(mother).is_empty());
+        StorageVersion::new(6).put::<Pallet<Test>>();
+
+        crate::migrations::MigrateV6ToV7::<Test>::on_runtime_upgrade();
+
+        let mut parents = Pallet::<Test>::parents_of(child);
+        parents.sort();
+        let mut expected = vec![father, mother];
+        expected.sort();
+        assert_eq!(parents, expected);
+        assert_eq!(Pallet::<Test>::children_of(mother), vec![child]);
+        // 无向关系不进反向索引
+        assert!(IncomingRelations::<Test>::get(spouse).is_empty());
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), 7);
+
+        // 再次执行为空操作，不重复登记
+        crate::migrations::MigrateV6ToV7::<Test>::on_runtime_upgrade();
+        assert_eq!(IncomingRelations::<Test>::get(child).len(), 1);
+    });
+}
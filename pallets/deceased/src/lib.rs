@@ -19,6 +19,10 @@ pub mod media;
 pub mod works;  // 🆕 Phase 1: AI训练数据基础
 pub mod anti_spam;  // 🆕 Phase 5: 防刷机制
 pub mod governance;  // 🆕 Phase 1.4: 永久质押押金治理机制
+pub mod genealogy;  // 🆕 族谱多代遍历与共同祖先查询
+pub mod remembrance;  // 🆕 纪念日历与关注者提醒
+pub mod runtime_api;  // 🆕 族谱 Runtime API
+pub mod migrations;  // 🆕 存储迁移（v7：回填有向关系反向索引）
 
 // 🆕 简化版基础测试
 #[cfg(test)]
@@ -32,9 +36,20 @@ pub mod simple_tests;
 #[cfg(test)]
 pub mod integration_tests;
 
+// 🆕 族谱遍历测试
+#[cfg(test)]
+mod genealogy_tests;
+
+// 🆕 纪念日历测试
+#[cfg(test)]
+mod remembrance_tests;
+
 pub use text::*;
 pub use media::*;
 pub use works::*;  // 🆕 导出作品相关类型
+pub use genealogy::{CommonAncestor, GenealogyNode, KinshipLink, MAX_GENEALOGY_DEPTH, MAX_GENEALOGY_NODES};
+pub use remembrance::{RemembranceOccasion, RemembranceSubscription, ScheduledReminder, UpcomingRemembrance};
+pub use runtime_api::*;
 
 // 🆕 导出防刷相关类型（显式指定，避免与 governance::OperationType 冲突）
 pub use anti_spam::{
@@ -686,6 +701,12 @@ pub mod pallet {
         #[pallet::constant]
         type MaxExpiringArticlesPerBlock: Get<u32>;
 
+        /// 函数级中文注释：每块最多发出的纪念提醒数 🆕
+        /// - 建议值：100
+        /// - 超出部分留在当日调度桶，下一区块继续处理
+        #[pallet::constant]
+        type MaxRemembranceRemindersPerBlock: Get<u32>;
+
         // ========== 🆕 2025-11-26: 逝者创建频率限制配置 ==========
         /// 函数级中文注释：每日最大逝者创建数（每用户）
         /// - 用于防止批量创建攻击
@@ -812,6 +833,11 @@ pub mod pallet {
         /// - 提供Unix时间戳用于ID生成的额外随机性
         /// - 确保不同时间创建的逝者ID具有更好的随机分布
         type UnixTime: frame_support::traits::UnixTime;
+
+        /// 函数级中文注释：🆕 会员权益接口（防刷每日限额加成）
+        /// - 实现：runtime 中注入 pallet_membership::Pallet<Runtime>
+        /// - 测试/不接入会员系统时使用 `()`
+        type MembershipBenefits: pallet_membership::MembershipBenefits<Self::AccountId>;
     }
 
     #[pallet::storage]
@@ -1953,6 +1979,34 @@ pub mod pallet {
             owner: T::AccountId,
             refunded_deposit: BalanceOf<T>,
         },
+
+        // ========== 🆕 纪念日历提醒事件 ==========
+        /// 函数级中文注释：关注者订阅纪念提醒
+        /// - occasions: 场合位掩码（bit0=忌日, bit1=农历忌日, bit2=生辰, bit3=农历生辰, bit4=清明, bit5=冬至）
+        RemembranceSubscribed {
+            deceased_id: T::DeceasedId,
+            who: T::AccountId,
+            occasions: u8,
+            lead_days: u8,
+        },
+
+        /// 函数级中文注释：关注者取消纪念提醒
+        RemembranceUnsubscribed {
+            deceased_id: T::DeceasedId,
+            who: T::AccountId,
+        },
+
+        /// 函数级中文注释：纪念提醒（链下通知服务据此推送）
+        /// - occasion: 场合编码（0-5）
+        /// - date: 纪念日 YYYYMMDD
+        /// - days_before: 距纪念日天数（0=当天）
+        RemembranceReminder {
+            deceased_id: T::DeceasedId,
+            who: T::AccountId,
+            occasion: u8,
+            date: u32,
+            days_before: u32,
+        },
     }
 
     #[pallet::error]
@@ -2473,6 +2527,22 @@ pub mod pallet {
         /// - 原因：同一区块有过多文章到期
         /// - 解决：等待几个区块后重试，让系统分散到期处理
         ExpiringArticleListFull,
+
+        // ========== 🆕 纪念日历提醒错误 ==========
+        /// 函数级中文注释：纪念提醒订阅参数无效
+        /// - 场合位掩码为空/含未定义位，或提前天数超过 30 天
+        InvalidRemembranceSubscription,
+
+        /// 函数级中文注释：仅关注者可订阅纪念提醒
+        /// - 解决：先调用 follow_deceased 关注该逝者
+        NotFollowingDeceased,
+
+        /// 函数级中文注释：纪念提醒订阅不存在
+        RemembranceSubscriptionNotFound,
+
+        /// 函数级中文注释：当日提醒调度已满
+        /// - 解决：调整提前天数，分散到其他日期
+        RemembranceScheduleFull,
     }
 
     /// 函数级详细中文注释：Hold Reason - 资金锁定原因枚举
@@ -2510,7 +2580,8 @@ pub mod pallet {
     }
 
     // 存储版本常量（用于 FRAME v2 storage_version 宏传参）
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+    // v7：新增 `IncomingRelations`，由 `migrations::MigrateV6ToV7` 回填已有有向关系
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
 
     /// 函数级详细中文注释：禁用存储信息生成（因为使用Vec无界集合）
     /// 
@@ -3022,6 +3093,54 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// 函数级中文注释：纪念提醒订阅 🆕
+    /// - Key1：逝者ID；Key2：关注者账户
+    /// - Val：订阅的场合位掩码、提前天数与版本号
+    #[pallet::storage]
+    pub type RemembranceSubscriptions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::DeceasedId,
+        Blake2_128Concat,
+        T::AccountId,
+        RemembranceSubscription,
+        OptionQuery,
+    >;
+
+    /// 函数级中文注释：纪念提醒调度桶 🆕
+    /// - Key：提醒日（纪元日，1970-01-01 起的天数，UTC+8）
+    /// - Val：当天需发出的提醒（上限 1024 = `MAX_REMINDERS_PER_DAY`）
+    #[pallet::storage]
+    pub type RemembranceSchedule<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u32,
+        BoundedVec<ScheduledReminder<T::DeceasedId, T::AccountId>, ConstU32<1024>>,
+        ValueQuery,
+    >;
+
+    /// 函数级中文注释：提醒处理游标（下一个待处理的纪元日，0=未初始化）🆕
+    #[pallet::storage]
+    pub type RemembranceCursor<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// 函数级中文注释：订阅版本号计数器 🆕
+    /// - 全局递增，保证取消后重新订阅不会复活旧调度项
+    #[pallet::storage]
+    pub type NextRemembranceNonce<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// 函数级中文注释：有向关系反向索引 🆕
+    /// - Key：有向关系（0=ParentOf, 3=ChildOf）的 `to` 方
+    /// - Val：指向该逝者的 `(from, kind)` 列表
+    /// - 用途：族谱遍历时由子女查父母、由父母查子女（`RelationsByDeceased` 仅索引有向关系的 `from` 方）
+    #[pallet::storage]
+    pub type IncomingRelations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::DeceasedId,
+        BoundedVec<(T::DeceasedId, u8), ConstU32<128>>,
+        ValueQuery,
+    >;
+
     #[pallet::storage]
     pub type PendingRelationRequests<T: Config> = StorageDoubleMap<
         _,
@@ -3260,7 +3379,7 @@ pub mod pallet {
 
     /// 函数级详细中文注释：关系工具函数与规范
     /// - 0=ParentOf(有向) 1=SpouseOf(无向) 2=SiblingOf(无向) 3=ChildOf(有向)
-    fn is_undirected_kind(kind: u8) -> bool {
+    pub(crate) fn is_undirected_kind(kind: u8) -> bool {
         matches!(kind, 1 | 2)
     }
 
@@ -6111,6 +6230,11 @@ pub mod pallet {
                 RelationsByDeceased::<T>::try_mutate(tt, |list| {
                     list.try_push((ff, kind)).map_err(|_| Error::<T>::BadInput)
                 })?;
+            } else {
+                // 🆕 有向关系同时登记反向索引，供族谱遍历使用
+                IncomingRelations::<T>::try_mutate(tt, |list| {
+                    list.try_push((ff, kind)).map_err(|_| Error::<T>::BadInput)
+                })?;
             }
             PendingRelationRequests::<T>::remove(from, to);
             Self::deposit_event(Event::RelationApproved(from, to, kind));
@@ -6383,6 +6507,12 @@ pub mod pallet {
                         list.swap_remove(i);
                     }
                 });
+            } else {
+                IncomingRelations::<T>::mutate(tt, |list| {
+                    if let Some(i) = list.iter().position(|(peer, _)| *peer == ff) {
+                        list.swap_remove(i);
+                    }
+                });
             }
             Self::deposit_event(Event::RelationRevoked(from, to));
             Ok(())
@@ -6805,6 +6935,9 @@ pub mod pallet {
                 .map_err(|_| Error::<T>::DeceasedNotFound)?;
             T::Social::unfollow_deceased_internal(&who, deceased_id_u64)?;
 
+            // 🆕 取消关注时一并作废纪念提醒订阅
+            RemembranceSubscriptions::<T>::remove(deceased_id, &who);
+
             Ok(())
         }
 
@@ -10822,6 +10955,79 @@ pub mod pallet {
 
             Ok(())
         }
+
+        // ========== 🆕 纪念日历提醒 ==========
+
+        /// 函数级详细中文注释：订阅（或修改）纪念提醒
+        ///
+        /// ### 功能说明
+        /// - 关注者按场合订阅逝者的忌日、生辰（公历/农历）与清明、冬至提醒
+        /// - 到提醒日由 `on_initialize` 发出 `RemembranceReminder` 事件，每年自动续排
+        /// - 重复调用视为修改：旧调度项随版本号变更失效
+        ///
+        /// ### 参数
+        /// - `occasions`: 场合位掩码（bit0=忌日, bit1=农历忌日, bit2=生辰, bit3=农历生辰, bit4=清明, bit5=冬至）
+        /// - `lead_days`: 提前提醒天数（0-30，0=当天）
+        ///
+        /// ### 说明
+        /// - 出生/去世日期未知月日（补 0）时，对应场合不会触发
+        /// - 取消关注后订阅自动作废
+        #[pallet::call_index(105)]
+        #[pallet::weight(T::WeightInfo::update())]
+        pub fn subscribe_remembrance(
+            origin: OriginFor<T>,
+            deceased_id: T::DeceasedId,
+            occasions: u8,
+            lead_days: u8,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(DeceasedOf::<T>::contains_key(deceased_id), Error::<T>::DeceasedNotFound);
+            ensure!(
+                occasions != 0
+                    && occasions >> remembrance::RemembranceOccasion::ALL.len() == 0
+                    && lead_days <= remembrance::MAX_REMINDER_LEAD_DAYS,
+                Error::<T>::InvalidRemembranceSubscription
+            );
+            let deceased_id_u64 = TryInto::<u64>::try_into(deceased_id)
+                .map_err(|_| Error::<T>::DeceasedNotFound)?;
+            ensure!(
+                T::Social::is_following_deceased(&who, deceased_id_u64),
+                Error::<T>::NotFollowingDeceased
+            );
+
+            let nonce = NextRemembranceNonce::<T>::mutate(|n| {
+                let x = *n;
+                *n = x.wrapping_add(1);
+                x
+            });
+            let sub = RemembranceSubscription { occasions, lead_days, nonce };
+            Self::schedule_reminders(deceased_id, &who, &sub, Self::today_day_index())?;
+            RemembranceSubscriptions::<T>::insert(deceased_id, &who, sub);
+
+            Self::deposit_event(Event::RemembranceSubscribed {
+                deceased_id,
+                who,
+                occasions,
+                lead_days,
+            });
+            Ok(())
+        }
+
+        /// 函数级中文注释：取消纪念提醒（已排期的提醒到期时自动丢弃）
+        #[pallet::call_index(106)]
+        #[pallet::weight(T::WeightInfo::update())]
+        pub fn unsubscribe_remembrance(
+            origin: OriginFor<T>,
+            deceased_id: T::DeceasedId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                RemembranceSubscriptions::<T>::take(deceased_id, &who).is_some(),
+                Error::<T>::RemembranceSubscriptionNotFound
+            );
+            Self::deposit_event(Event::RemembranceUnsubscribed { deceased_id, who });
+            Ok(())
+        }
     }
 
     // ==================== 辅助函数 ====================
@@ -11464,6 +11670,7 @@ pub mod pallet {
         /// 函数级详细中文注释：运行时升级钩子（开发期零迁移）。
         /// - 主网未上线阶段，采用"零迁移"策略：不执行 translate，仅写入当前 STORAGE_VERSION；
         /// - 若需结构调整，请清链/重启以应用最新结构；主网上线前再提供精确迁移版本。
+        /// - 版本化迁移（见 `migrations`）由 runtime `Migrations` 在本钩子之前执行，按链上旧版本判断是否运行。
         fn on_runtime_upgrade() -> Weight {
             STORAGE_VERSION.put::<Pallet<T>>();
             Weight::from_parts(10_000, 0)
@@ -11494,6 +11701,10 @@ pub mod pallet {
         /// ### 安全考虑
         /// - 使用 take 一次性获取并清理当前区块的到期列表
         /// - 释放失败时记录日志但不阻断处理（防止单条失败影响其他）
+        ///
+        /// ### 🆕 纪念提醒
+        /// - 按天推进提醒游标，发出到期的 `RemembranceReminder` 并续排下一年
+        /// - 每块最多处理 MaxRemembranceRemindersPerBlock 条
         fn on_initialize(now: BlockNumberFor<T>) -> Weight {
             use frame_support::traits::fungible::MutateHold;
             use frame_support::traits::tokens::Precision;
@@ -11540,6 +11751,9 @@ pub mod pallet {
                 }
             }
 
+            // 🆕 纪念日历：发出到期的关注者提醒
+            weight = weight.saturating_add(Self::process_remembrance_reminders());
+
             weight
         }
 
@@ -0,0 +1,54 @@
+//! 函数级中文注释：pallet-deceased 存储迁移
+//!
+//! ### v6 → v7：回填有向关系反向索引
+//! - `IncomingRelations` 为 v7 新增，仅在 `approve_relation` 时写入；
+//!   升级前已批准的有向关系（0=ParentOf, 3=ChildOf）没有反向索引，族谱遍历会漏掉这些边
+//! - 遍历 `Relations`，按 `approve_relation` 的同一规则把 `(from, kind)` 登记到 `to` 方
+
+use crate::pallet::{is_undirected_kind, Config, IncomingRelations, Pallet, Relations};
+use frame_support::{
+    migrations::VersionedMigration,
+    traits::{Get, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+};
+
+/// 函数级中文注释：v6 → v7 迁移（仅在链上存储版本为 6 时执行，执行后置为 7）
+pub type MigrateV6ToV7<T> = VersionedMigration<
+    6,
+    7,
+    v7::UncheckedMigrateV6ToV7<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+pub mod v7 {
+    use super::*;
+
+    /// 函数级中文注释：回填 `IncomingRelations`（不检查版本，由 `MigrateV6ToV7` 包装）
+    /// - 已存在的条目不重复登记；单个逝者超过 128 条入向关系时其余跳过
+    pub struct UncheckedMigrateV6ToV7<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV6ToV7<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads: u64 = 0;
+            let mut writes: u64 = 0;
+            for (from, to, relation) in Relations::<T>::iter() {
+                reads = reads.saturating_add(1);
+                // 无向关系（非自指）双方均已在 RelationsByDeceased 中，不进反向索引
+                if is_undirected_kind(relation.kind) && from != to {
+                    continue;
+                }
+                reads = reads.saturating_add(1);
+                IncomingRelations::<T>::mutate(to, |list| {
+                    if list.iter().any(|(peer, kind)| *peer == from && *kind == relation.kind) {
+                        return;
+                    }
+                    if list.try_push((from, relation.kind)).is_ok() {
+                        writes = writes.saturating_add(1);
+                    }
+                });
+            }
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+    }
+}
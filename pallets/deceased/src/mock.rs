@@ -292,7 +292,35 @@ impl pallet_deceased::Config for Test {
 
     /// 函数级中文注释：每块最大处理到期文章数（测试用：50）
     type MaxExpiringArticlesPerBlock = ConstU32<50>;
+    type MaxRemembranceRemindersPerBlock = ConstU32<100>;
     // ==========================================================
+
+    /// 函数级中文注释：🆕 会员权益（测试用：账户 7 为会员，防刷限额加成 100%）
+    type MembershipBenefits = MockMembershipBenefits;
+}
+
+/// 函数级中文注释：Mock的会员权益实现，仅账户 7 享有防刷限额加成
+pub struct MockMembershipBenefits;
+impl pallet_membership::MembershipBenefits<u64> for MockMembershipBenefits {
+    fn pin_discount_percent(_who: &u64) -> u8 {
+        0
+    }
+
+    fn nft_fee_discount_percent(_who: &u64) -> u8 {
+        0
+    }
+
+    fn anti_spam_bonus_percent(who: &u64) -> u16 {
+        if *who == 7 {
+            100
+        } else {
+            0
+        }
+    }
+
+    fn try_use_free_ai_interpretation(_who: &u64) -> bool {
+        false
+    }
 }
 
 /// 函数级中文注释：Mock的IpfsPinner实现，简化pin逻辑
@@ -307,6 +335,15 @@ impl pallet_stardust_ipfs::IpfsPinner<u64, u64> for MockIpfsPinner {
     ) -> sp_runtime::DispatchResult {
         Ok(())
     }
+
+    fn pin_cid_for_pet(
+        _caller: u64,
+        _pet_id: u64,
+        _cid: Vec<u8>,
+        _tier: Option<pallet_stardust_ipfs::PinTier>,
+    ) -> sp_runtime::DispatchResult {
+        Ok(())
+    }
 }
 
 /// 函数级中文注释：创建测试环境
@@ -0,0 +1,437 @@
+// 函数级详细中文注释：纪念日历（忌日/生辰/清明/冬至）与关注者提醒
+//
+// ## 核心功能
+// 1. **日期解析**：`birth_ts`/`death_ts`（YYYYMMDD）解析为公历日期，未知月日（补 0）的场合不可推算
+// 2. **农历换算**：通过 `pallet_almanac::lunar` 计算农历忌日、农历生日
+// 3. **节令**：清明（节气索引 6）、冬至（节气索引 23）每年对所有逝者生效
+// 4. **提醒调度**：关注者按场合订阅，提前 N 天在 `on_initialize` 中发出提醒事件
+//
+// ## 设计理念
+// - **按天调度**：以"纪元日"（1970-01-01 起的天数，UTC+8）为键，与区块号解耦
+// - **惰性清理**：取消/修改订阅只递增 nonce，旧调度项到期时因 nonce 不符被丢弃
+// - **有界**：每日调度桶上限 `MAX_REMINDERS_PER_DAY`，每块处理上限由 Config 指定
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::{Get, UnixTime};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+use crate::pallet::{DeceasedOf, RemembranceCursor, RemembranceSchedule, RemembranceSubscriptions};
+use crate::{Config, Event, Pallet};
+
+/// 纪念日历时区偏移（UTC+8），"今天"按北京时间计算
+pub const REMEMBRANCE_TZ_OFFSET_SECS: u64 = 8 * 3600;
+
+/// 每日调度桶最多容纳的提醒数
+pub const MAX_REMINDERS_PER_DAY: u32 = 1024;
+
+/// 最大提前提醒天数
+pub const MAX_REMINDER_LEAD_DAYS: u8 = 30;
+
+/// 即将到来的纪念日查询最大跨度（天）
+pub const MAX_REMEMBRANCE_LOOKAHEAD_DAYS: u32 = 400;
+
+/// 清明节气索引
+const SOLAR_TERM_QINGMING: u8 = 6;
+
+/// 冬至节气索引
+const SOLAR_TERM_DONGZHI: u8 = 23;
+
+/// 函数级中文注释：纪念场合
+/// - extrinsic 参数使用 u8 位掩码传递（bit = 1 << code）
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RemembranceOccasion {
+    /// 忌日（公历）
+    DeathAnniversary,
+    /// 忌日（农历）
+    LunarDeathAnniversary,
+    /// 生辰（公历）
+    Birthday,
+    /// 生辰（农历）
+    LunarBirthday,
+    /// 清明
+    Qingming,
+    /// 冬至
+    Dongzhi,
+}
+
+impl RemembranceOccasion {
+    /// 全部场合（按编码顺序）
+    pub const ALL: [RemembranceOccasion; 6] = [
+        RemembranceOccasion::DeathAnniversary,
+        RemembranceOccasion::LunarDeathAnniversary,
+        RemembranceOccasion::Birthday,
+        RemembranceOccasion::LunarBirthday,
+        RemembranceOccasion::Qingming,
+        RemembranceOccasion::Dongzhi,
+    ];
+
+    /// 函数级中文注释：数字编码（0-5）
+    pub fn to_code(&self) -> u8 {
+        match self {
+            RemembranceOccasion::DeathAnniversary => 0,
+            RemembranceOccasion::LunarDeathAnniversary => 1,
+            RemembranceOccasion::Birthday => 2,
+            RemembranceOccasion::LunarBirthday => 3,
+            RemembranceOccasion::Qingming => 4,
+            RemembranceOccasion::Dongzhi => 5,
+        }
+    }
+
+    /// 函数级中文注释：从数字编码构建，无效编码返回 None
+    pub fn from_code(code: u8) -> Option<Self> {
+        Self::ALL.get(code as usize).copied()
+    }
+
+    /// 函数级中文注释：位掩码中的对应位
+    pub fn bit(&self) -> u8 {
+        1u8 << self.to_code()
+    }
+
+    /// 函数级中文注释：从位掩码展开场合列表（忽略未定义的高位）
+    pub fn from_mask(mask: u8) -> Vec<Self> {
+        Self::ALL.iter().copied().filter(|o| mask & o.bit() != 0).collect()
+    }
+}
+
+/// 函数级中文注释：关注者的纪念提醒订阅
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RemembranceSubscription {
+    /// 订阅的场合位掩码
+    pub occasions: u8,
+    /// 提前提醒天数（0=当天）
+    pub lead_days: u8,
+    /// 订阅版本号，修改/取消后旧调度项失效
+    pub nonce: u32,
+}
+
+/// 函数级中文注释：调度桶中的一条提醒
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ScheduledReminder<DeceasedId, AccountId> {
+    pub deceased_id: DeceasedId,
+    pub who: AccountId,
+    pub occasion: RemembranceOccasion,
+    /// 纪念日当天（纪元日）
+    pub occasion_day: u32,
+    pub nonce: u32,
+}
+
+/// 函数级中文注释：即将到来的纪念日（Runtime API 返回）
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct UpcomingRemembrance {
+    pub occasion: RemembranceOccasion,
+    /// 公历日期 YYYYMMDD
+    pub date: u32,
+    /// 距今天数（0=今天）
+    pub days_until: u32,
+    /// 周年数（忌日/生辰为第几年；节令为 0）
+    pub years: u16,
+}
+
+// ==================== 日期工具 ====================
+
+/// 函数级中文注释：解析 YYYYMMDD，月或日为 0（未知）时返回 None
+pub fn parse_ymd(bytes: &[u8]) -> Option<(u16, u8, u8)> {
+    if bytes.len() != 8 || !bytes.iter().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let num = |r: core::ops::Range<usize>| {
+        bytes[r].iter().fold(0u32, |acc, b| acc * 10 + (b - b'0') as u32)
+    };
+    let (y, m, d) = (num(0..4) as u16, num(4..6) as u8, num(6..8) as u8);
+    if y == 0 || !(1..=12).contains(&m) || d == 0 || d > days_in_month(y, m) {
+        return None;
+    }
+    Some((y, m, d))
+}
+
+/// 函数级中文注释：公历日期 → 纪元日（1970-01-01 = 0）
+pub fn day_index(y: u16, m: u8, d: u8) -> u32 {
+    let jd = pallet_almanac::julian_day(y, m, d) - pallet_almanac::julian_day(1970, 1, 1);
+    jd.max(0) as u32
+}
+
+/// 函数级中文注释：纪元日 → 公历日期
+pub fn date_of(day: u32) -> (u16, u8, u8) {
+    pallet_almanac::from_julian_day(pallet_almanac::julian_day(1970, 1, 1) + day as i32)
+}
+
+/// 函数级中文注释：纪元日 → YYYYMMDD 数字
+pub fn ymd_number(day: u32) -> u32 {
+    let (y, m, d) = date_of(day);
+    y as u32 * 10_000 + m as u32 * 100 + d as u32
+}
+
+fn days_in_month(y: u16, m: u8) -> u8 {
+    match m {
+        2 if pallet_almanac::is_leap_year(y) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// 函数级中文注释：公历周年日，2 月 29 日在平年取 2 月 28 日
+fn solar_anniversary(year: u16, (_, m, d): (u16, u8, u8)) -> u32 {
+    day_index(year, m, d.min(days_in_month(year, m)))
+}
+
+/// 函数级中文注释：农历周年日（取非闰月；小月无三十时取廿九）
+/// - 返回 `(纪元日, 周年数)`，周年数按农历年计
+fn lunar_anniversary(lunar_year: u16, (y, m, d): (u16, u8, u8)) -> Option<(u32, u16)> {
+    let lunar = pallet_almanac::solar_to_lunar(y, m, d)?;
+    if lunar_year < lunar.year {
+        return None;
+    }
+    for day in [lunar.day, lunar.day.saturating_sub(1)] {
+        let (sy, sm, sd) = pallet_almanac::lunar_to_solar(lunar_year, lunar.month, day, false)?;
+        let back = pallet_almanac::solar_to_lunar(sy, sm, sd)?;
+        if back.month == lunar.month && !back.is_leap {
+            return Some((day_index(sy, sm, sd), lunar_year - lunar.year));
+        }
+    }
+    None
+}
+
+/// 函数级中文注释：某公历年中指定节气所在日
+fn solar_term_day(year: u16, month: u8, term: u8) -> Option<u32> {
+    (1..=days_in_month(year, month))
+        .find(|d| pallet_almanac::get_solar_term(year, month, *d) == Some(term))
+        .map(|d| day_index(year, month, d))
+}
+
+/// 函数级详细中文注释：计算某场合在 `from_day`（含）之后的最近一次日期
+///
+/// ### 返回
+/// - `(纪元日, 周年数)`；起算日期未知（补 0）或超出农历数据范围时返回 None
+/// - 忌日/生辰的首次周年不早于原日期本身（周年数 0 即原日期）
+pub fn next_occurrence(
+    occasion: RemembranceOccasion,
+    birth: Option<(u16, u8, u8)>,
+    death: Option<(u16, u8, u8)>,
+    from_day: u32,
+) -> Option<(u32, u16)> {
+    let (year, _, _) = date_of(from_day);
+    let origin = match occasion {
+        RemembranceOccasion::DeathAnniversary | RemembranceOccasion::LunarDeathAnniversary => death,
+        RemembranceOccasion::Birthday | RemembranceOccasion::LunarBirthday => birth,
+        RemembranceOccasion::Qingming | RemembranceOccasion::Dongzhi => None,
+    };
+
+    // 农历年可能跨越公历年（腊月在次年一二月），多取一年兜底
+    for y in year.saturating_sub(1)..=year.saturating_add(1) {
+        let candidate = match occasion {
+            RemembranceOccasion::DeathAnniversary | RemembranceOccasion::Birthday => {
+                let o = origin?;
+                (y >= o.0).then(|| (solar_anniversary(y, o), y - o.0))
+            },
+            RemembranceOccasion::LunarDeathAnniversary | RemembranceOccasion::LunarBirthday => {
+                lunar_anniversary(y, origin?)
+            },
+            RemembranceOccasion::Qingming => solar_term_day(y, 4, SOLAR_TERM_QINGMING).map(|d| (d, 0)),
+            RemembranceOccasion::Dongzhi => solar_term_day(y, 12, SOLAR_TERM_DONGZHI).map(|d| (d, 0)),
+        };
+        match candidate {
+            Some((day, years)) if day >= from_day => return Some((day, years)),
+            _ => continue,
+        }
+    }
+    None
+}
+
+impl<T: Config> Pallet<T> {
+    /// 函数级中文注释：今天（UTC+8）的纪元日
+    pub fn today_day_index() -> u32 {
+        let secs = T::UnixTime::now().as_secs().saturating_add(REMEMBRANCE_TZ_OFFSET_SECS);
+        (secs / 86_400) as u32
+    }
+
+    /// 函数级中文注释：逝者的（出生, 去世）公历日期
+    fn remembrance_origins(
+        deceased_id: T::DeceasedId,
+    ) -> Option<(Option<(u16, u8, u8)>, Option<(u16, u8, u8)>)> {
+        let d = DeceasedOf::<T>::get(deceased_id)?;
+        let parse = |v: &Option<frame_support::BoundedVec<u8, T::StringLimit>>| {
+            v.as_ref().and_then(|b| parse_ymd(b))
+        };
+        Some((parse(&d.birth_ts), parse(&d.death_ts)))
+    }
+
+    /// 函数级中文注释：逝者某场合在 `from_day`（含）之后的最近一次纪元日
+    /// - 供 `pallet-memorial` 预约供奉通过 Runtime 适配器查询
+    pub fn next_remembrance_day(
+        deceased_id: T::DeceasedId,
+        occasion: RemembranceOccasion,
+        from_day: u32,
+    ) -> Option<u32> {
+        let (birth, death) = Self::remembrance_origins(deceased_id)?;
+        next_occurrence(occasion, birth, death, from_day).map(|(day, _)| day)
+    }
+
+    /// 函数级中文注释：今天起 `within_days` 天内的纪念日，按日期升序
+    pub fn upcoming_remembrances(
+        deceased_id: T::DeceasedId,
+        within_days: u32,
+    ) -> Vec<UpcomingRemembrance> {
+        let Some((birth, death)) = Self::remembrance_origins(deceased_id) else {
+            return Vec::new();
+        };
+        let today = Self::today_day_index();
+        let horizon = today.saturating_add(within_days.min(MAX_REMEMBRANCE_LOOKAHEAD_DAYS));
+
+        let mut result = Vec::new();
+        for occasion in RemembranceOccasion::ALL {
+            let mut from = today;
+            while let Some((day, years)) = next_occurrence(occasion, birth, death, from) {
+                if day > horizon {
+                    break;
+                }
+                result.push(UpcomingRemembrance {
+                    occasion,
+                    date: ymd_number(day),
+                    days_until: day - today,
+                    years,
+                });
+                from = day + 1;
+            }
+        }
+        result.sort_by_key(|r| (r.days_until, r.occasion));
+        result
+    }
+
+    /// 函数级中文注释：为一条订阅的每个场合登记下一次提醒
+    /// - 提醒日 = 纪念日 - 提前天数，且不早于今天
+    pub(crate) fn schedule_reminders(
+        deceased_id: T::DeceasedId,
+        who: &T::AccountId,
+        sub: &RemembranceSubscription,
+        from_day: u32,
+    ) -> Result<(), crate::Error<T>> {
+        let (birth, death) =
+            Self::remembrance_origins(deceased_id).ok_or(crate::Error::<T>::DeceasedNotFound)?;
+        for occasion in RemembranceOccasion::from_mask(sub.occasions) {
+            Self::schedule_reminder(deceased_id, who, occasion, sub, birth, death, from_day, from_day)?;
+        }
+        Ok(())
+    }
+
+    /// 函数级中文注释：登记 `search_from`（含）之后最近一次纪念日的提醒，提醒日不早于 `not_before`
+    #[allow(clippy::too_many_arguments)]
+    fn schedule_reminder(
+        deceased_id: T::DeceasedId,
+        who: &T::AccountId,
+        occasion: RemembranceOccasion,
+        sub: &RemembranceSubscription,
+        birth: Option<(u16, u8, u8)>,
+        death: Option<(u16, u8, u8)>,
+        search_from: u32,
+        not_before: u32,
+    ) -> Result<(), crate::Error<T>> {
+        // 起算日期未知的场合跳过（如去世日期只记录到年份）
+        let Some((occasion_day, _)) = next_occurrence(occasion, birth, death, search_from) else {
+            return Ok(());
+        };
+        let remind_day = occasion_day.saturating_sub(sub.lead_days as u32).max(not_before);
+        RemembranceSchedule::<T>::try_mutate(remind_day, |bucket| {
+            bucket
+                .try_push(ScheduledReminder {
+                    deceased_id,
+                    who: who.clone(),
+                    occasion,
+                    occasion_day,
+                    nonce: sub.nonce,
+                })
+                .map_err(|_| crate::Error::<T>::RemembranceScheduleFull)
+        })
+    }
+
+    /// 函数级详细中文注释：处理到期提醒，返回消耗的权重
+    ///
+    /// ### 流程
+    /// 1. 游标从上次处理日推进到今天（停机后补发，不跳过）
+    /// 2. 订阅仍有效（nonce 一致、场合仍订阅、仍在关注）时发出 `RemembranceReminder`
+    /// 3. 为该场合登记下一年的提醒
+    /// 4. 每块最多处理 `MaxRemembranceRemindersPerBlock` 条，剩余留待下一块
+    pub(crate) fn process_remembrance_reminders() -> frame_support::weights::Weight {
+        let db = T::DbWeight::get();
+        let mut weight = db.reads(1);
+        let today = Self::today_day_index();
+        let mut cursor = RemembranceCursor::<T>::get();
+        if cursor == 0 {
+            // 首次运行：从今天开始
+            cursor = today;
+        }
+        let mut budget = T::MaxRemembranceRemindersPerBlock::get() as usize;
+
+        while cursor <= today && budget > 0 {
+            let mut bucket = RemembranceSchedule::<T>::get(cursor).into_inner();
+            weight = weight.saturating_add(db.reads(1));
+            let take = bucket.len().min(budget);
+            let rest = bucket.split_off(take);
+            budget -= take;
+
+            for item in bucket {
+                weight = weight.saturating_add(db.reads(2));
+                Self::fire_reminder(item, cursor);
+                weight = weight.saturating_add(db.writes(1));
+            }
+
+            if rest.is_empty() {
+                RemembranceSchedule::<T>::remove(cursor);
+                cursor = cursor.saturating_add(1);
+            } else {
+                // 未处理完的部分写回，下一块继续
+                RemembranceSchedule::<T>::insert(
+                    cursor,
+                    frame_support::BoundedVec::truncate_from(rest),
+                );
+            }
+            weight = weight.saturating_add(db.writes(1));
+        }
+
+        RemembranceCursor::<T>::put(cursor);
+        weight.saturating_add(db.writes(1))
+    }
+
+    fn fire_reminder(item: ScheduledReminder<T::DeceasedId, T::AccountId>, day: u32) {
+        let Some(sub) = RemembranceSubscriptions::<T>::get(item.deceased_id, &item.who) else {
+            return;
+        };
+        if sub.nonce != item.nonce || sub.occasions & item.occasion.bit() == 0 {
+            return;
+        }
+        let following = TryInto::<u64>::try_into(item.deceased_id)
+            .map(|id| {
+                <T::Social as pallet_social::SocialInterface<T::AccountId>>::is_following_deceased(&item.who, id)
+            })
+            .unwrap_or(false);
+        if !following {
+            // 已取消关注：订阅随之作废
+            RemembranceSubscriptions::<T>::remove(item.deceased_id, &item.who);
+            return;
+        }
+
+        Self::deposit_event(Event::RemembranceReminder {
+            deceased_id: item.deceased_id,
+            who: item.who.clone(),
+            occasion: item.occasion.to_code(),
+            date: ymd_number(item.occasion_day),
+            days_before: item.occasion_day.saturating_sub(day),
+        });
+
+        // 登记下一次（纪念日次日起算）
+        if let Some((birth, death)) = Self::remembrance_origins(item.deceased_id) {
+            let _ = Self::schedule_reminder(
+                item.deceased_id,
+                &item.who,
+                item.occasion,
+                &sub,
+                birth,
+                death,
+                item.occasion_day.saturating_add(1),
+                day.saturating_add(1),
+            );
+        }
+    }
+}
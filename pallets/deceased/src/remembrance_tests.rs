@@ -0,0 +1,83 @@
+// 函数级中文注释：纪念日历（日期解析、周年推算、农历换算、节令）单元测试
+
+use crate::remembrance::{
+    date_of, day_index, next_occurrence, parse_ymd, ymd_number, RemembranceOccasion,
+};
+
+/// Test: YYYYMMDD 解析，未知月日（补 0）与非法日期返回 None
+#[test]
+fn parse_ymd_rejects_unknown_parts() {
+    assert_eq!(parse_ymd(b"19300312"), Some((1930, 3, 12)));
+    assert_eq!(parse_ymd(b"19300300"), None);
+    assert_eq!(parse_ymd(b"19300000"), None);
+    assert_eq!(parse_ymd(b"19310229"), None);
+    assert_eq!(parse_ymd(b"1930031"), None);
+}
+
+/// Test: 纪元日与公历日期互转
+#[test]
+fn day_index_round_trip() {
+    assert_eq!(day_index(1970, 1, 1), 0);
+    let day = day_index(2024, 2, 29);
+    assert_eq!(date_of(day), (2024, 2, 29));
+    assert_eq!(ymd_number(day), 20240229);
+}
+
+/// Test: 公历忌日取今年或明年；2 月 29 日在平年取 2 月 28 日
+#[test]
+fn solar_anniversary_rolls_forward() {
+    let death = Some((2020, 2, 29));
+    let from = day_index(2025, 1, 1);
+    let (day, years) =
+        next_occurrence(RemembranceOccasion::DeathAnniversary, None, death, from).unwrap();
+    assert_eq!(ymd_number(day), 20250228);
+    assert_eq!(years, 5);
+
+    // 今年已过则取明年
+    let from = day_index(2025, 3, 1);
+    let (day, years) =
+        next_occurrence(RemembranceOccasion::DeathAnniversary, None, death, from).unwrap();
+    assert_eq!(ymd_number(day), 20260228);
+    assert_eq!(years, 6);
+
+    // 未知日期不可推算
+    assert!(next_occurrence(RemembranceOccasion::Birthday, None, death, from).is_none());
+}
+
+/// Test: 农历忌日按农历月日换算（2023 正月初一 → 2024 正月初一 = 2024-02-10）
+#[test]
+fn lunar_anniversary_follows_lunar_calendar() {
+    let death = Some((2023, 1, 22));
+    let from = day_index(2023, 6, 1);
+    let (day, years) =
+        next_occurrence(RemembranceOccasion::LunarDeathAnniversary, None, death, from).unwrap();
+    assert_eq!(ymd_number(day), 20240210);
+    assert_eq!(years, 1);
+}
+
+/// Test: 清明在四月初、冬至在十二月下旬，与出生/去世日期无关
+#[test]
+fn seasonal_occasions_need_no_origin() {
+    let from = day_index(2025, 1, 1);
+    let (qingming, _) = next_occurrence(RemembranceOccasion::Qingming, None, None, from).unwrap();
+    let (y, m, d) = date_of(qingming);
+    assert_eq!((y, m), (2025, 4));
+    assert!((4..=6).contains(&d));
+
+    let (dongzhi, _) = next_occurrence(RemembranceOccasion::Dongzhi, None, None, from).unwrap();
+    let (y, m, d) = date_of(dongzhi);
+    assert_eq!((y, m), (2025, 12));
+    assert!((21..=23).contains(&d));
+}
+
+/// Test: 场合位掩码展开
+#[test]
+fn occasion_mask_expansion() {
+    let mask = RemembranceOccasion::DeathAnniversary.bit() | RemembranceOccasion::Qingming.bit();
+    assert_eq!(
+        RemembranceOccasion::from_mask(mask),
+        vec![RemembranceOccasion::DeathAnniversary, RemembranceOccasion::Qingming]
+    );
+    assert_eq!(RemembranceOccasion::from_code(5), Some(RemembranceOccasion::Dongzhi));
+    assert_eq!(RemembranceOccasion::from_code(6), None);
+}
@@ -0,0 +1,74 @@
+//! Runtime API 定义
+//!
+//! 本模块定义了逝者族谱查询与纪念日历的 Runtime API，供前端、RPC 与 GEDCOM 导入导出工具调用。
+
+use crate::genealogy::{CommonAncestor, GenealogyNode, KinshipLink};
+use crate::remembrance::UpcomingRemembrance;
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// 逝者族谱 Runtime API
+    ///
+    /// 基于已批准的亲属关系（0=ParentOf, 1=SpouseOf, 2=SiblingOf, 3=ChildOf）做多代遍历。
+    /// 深度超过 `MAX_GENEALOGY_DEPTH` 时截断，单次结果最多 `MAX_GENEALOGY_NODES` 条。
+    pub trait DeceasedGenealogyApi<DeceasedId>
+    where
+        DeceasedId: Codec,
+    {
+        /// 获取多代祖先
+        ///
+        /// # 参数
+        /// - `id`: 起点逝者
+        /// - `max_depth`: 最多向上遍历的代数（1=仅父母）
+        ///
+        /// # 返回
+        /// 按代数递增排列的祖先列表
+        fn ancestors(id: DeceasedId, max_depth: u8) -> Vec<GenealogyNode<DeceasedId>>;
+
+        /// 获取多代后代
+        ///
+        /// # 参数
+        /// - `id`: 起点逝者
+        /// - `max_depth`: 最多向下遍历的代数（1=仅子女）
+        ///
+        /// # 返回
+        /// 按代数递增排列的后代列表
+        fn descendants(id: DeceasedId, max_depth: u8) -> Vec<GenealogyNode<DeceasedId>>;
+
+        /// 查找共同祖先
+        ///
+        /// 一方是另一方的祖先时，该方本人也会出现在结果中（代数为 0）。
+        ///
+        /// # 返回
+        /// 按双方代数之和升序排列，第一项即最近共同祖先
+        fn common_ancestors(
+            a: DeceasedId,
+            b: DeceasedId,
+            max_depth: u8,
+        ) -> Vec<CommonAncestor<DeceasedId>>;
+
+        /// 获取单跳关系（含有向关系的反向一侧）
+        ///
+        /// 用于 GEDCOM 导出时逐人展开家庭记录。
+        fn relations_of(id: DeceasedId) -> Vec<KinshipLink<DeceasedId>>;
+    }
+
+    /// 纪念日历 Runtime API
+    ///
+    /// 基于 `birth_ts`/`death_ts` 推算忌日、生辰（公历/农历）及清明、冬至，"今天"按 UTC+8 计算。
+    pub trait DeceasedRemembranceApi<DeceasedId>
+    where
+        DeceasedId: Codec,
+    {
+        /// 获取即将到来的纪念日
+        ///
+        /// # 参数
+        /// - `id`: 逝者ID
+        /// - `within_days`: 查询天数（上限 400）
+        ///
+        /// # 返回
+        /// 按日期升序排列的纪念日列表；逝者不存在时为空
+        fn upcoming_remembrances(id: DeceasedId, within_days: u32) -> Vec<UpcomingRemembrance>;
+    }
+}
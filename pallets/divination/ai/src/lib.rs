@@ -12,6 +12,8 @@
 //! 2. **预言机管理**: 注册、质押、评分管理
 //! 3. **结果处理**: 提交解读、评分、争议
 //! 4. **费用分配**: 预言机、国库、燃烧分成
+//! 5. **资料包注册表**: 知识库与 Prompt 模板按内容寻址，CID 经治理批准后锚定上链，
+//!    每条解读结果记录所用的知识库版本与模板版本
 //!
 //! ## 架构说明
 //!
@@ -58,7 +60,8 @@ pub mod pallet {
     use pallet_divination_common::{
         DivinationProvider, DivinationType, InterpretationStatus, InterpretationType,
     };
-    use sp_runtime::traits::{SaturatedConversion, Saturating};
+    use pallet_membership::MembershipBenefits;
+    use sp_runtime::traits::{SaturatedConversion, Saturating, Zero};
     use sp_std::prelude::*;
 
     /// Pallet 配置 trait
@@ -111,6 +114,9 @@ pub mod pallet {
 
         /// 治理权限来源（用于参数调整）
         type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// 🆕 会员权益（会员每个权益周期享有若干次免费解读）
+        type MembershipBenefits: pallet_membership::MembershipBenefits<Self::AccountId>;
     }
 
     /// 货币余额类型别名
@@ -145,6 +151,13 @@ pub mod pallet {
         BlockNumberFor<T>,
     >;
 
+    /// 资料包类型别名
+    pub type KnowledgeBundleOf<T> = KnowledgeBundle<
+        <T as frame_system::Config>::AccountId,
+        BlockNumberFor<T>,
+        <T as Config>::MaxCidLength,
+    >;
+
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
@@ -242,6 +255,26 @@ pub mod pallet {
     pub type OracleModelSupports<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, OracleModelSupport, ValueQuery>;
 
+    /// 资料包注册表
+    ///
+    /// (资料包类型, 版本号) -> 资料包
+    #[pallet::storage]
+    #[pallet::getter(fn knowledge_bundles)]
+    pub type KnowledgeBundles<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        BundleKind,
+        Blake2_128Concat,
+        u32,
+        KnowledgeBundleOf<T>,
+    >;
+
+    /// 各类型当前推荐的资料包版本（最近一次批准的版本）
+    #[pallet::storage]
+    #[pallet::getter(fn active_bundle_version)]
+    pub type ActiveBundleVersion<T: Config> =
+        StorageMap<_, Blake2_128Concat, BundleKind, u32, OptionQuery>;
+
     // ==================== 事件 ====================
 
     #[pallet::event]
@@ -268,6 +301,8 @@ pub mod pallet {
             request_id: u64,
             oracle: T::AccountId,
             content_cid: BoundedVec<u8, T::MaxCidLength>,
+            knowledge_version: u32,
+            prompt_template_version: u32,
         },
 
         /// 请求处理失败
@@ -335,6 +370,21 @@ pub mod pallet {
             divination_type: DivinationType,
             model_version: u32,
         },
+
+        /// 资料包已提议
+        BundleProposed {
+            kind: BundleKind,
+            version: u32,
+            cid: BoundedVec<u8, T::MaxCidLength>,
+            content_hash: [u8; 32],
+            proposer: T::AccountId,
+        },
+
+        /// 资料包已批准
+        BundleApproved { kind: BundleKind, version: u32 },
+
+        /// 资料包已撤销
+        BundleRevoked { kind: BundleKind, version: u32 },
     }
 
     // ==================== 错误 ====================
@@ -407,6 +457,16 @@ pub mod pallet {
         OracleModelListFull,
         /// 无效的模型配置
         InvalidModelConfig,
+        /// 资料包不存在
+        BundleNotFound,
+        /// 资料包版本已存在
+        BundleVersionExists,
+        /// 资料包版本号必须大于已有最高版本
+        BundleVersionTooLow,
+        /// 资料包状态无效
+        InvalidBundleStatus,
+        /// 资料包未批准（或已撤销）
+        BundleNotApproved,
     }
 
     // ==================== 可调用函数 ====================
@@ -460,6 +520,13 @@ pub mod pallet {
                 .saturating_mul(divination_multiplier.into())
                 / 10000u32.into(); // 两个百分比相乘需要除以 10000
 
+            // 🆕 会员免费解读：本周期仍有额度则免收费用（fee_paid = 0，完成后无费用分配）
+            let fee = if T::MembershipBenefits::try_use_free_ai_interpretation(&who) {
+                Zero::zero()
+            } else {
+                fee
+            };
+
             // 扣除费用（暂存）
             T::AiCurrency::reserve(&who, fee)?;
 
@@ -616,9 +683,15 @@ pub mod pallet {
             summary_cid: Option<Vec<u8>>,
             model_version: Vec<u8>,
             language: Vec<u8>,
+            knowledge_version: u32,
+            prompt_template_version: u32,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
+            // 验证所用资料包均已批准
+            Self::ensure_bundle_approved(BundleKind::KnowledgeBase, knowledge_version)?;
+            Self::ensure_bundle_approved(BundleKind::PromptTemplate, prompt_template_version)?;
+
             // 验证 CID 长度
             ensure!(
                 content_cid.len() <= T::MaxCidLength::get() as usize,
@@ -685,6 +758,8 @@ pub mod pallet {
                 user_rating: None,
                 model_version: model_version_bounded,
                 language: language_bounded,
+                knowledge_version,
+                prompt_template_version,
             };
 
             Results::<T>::insert(request_id, result);
@@ -714,6 +789,8 @@ pub mod pallet {
                 request_id,
                 oracle: who,
                 content_cid: content_cid_bounded,
+                knowledge_version,
+                prompt_template_version,
             });
 
             Ok(())
@@ -1300,11 +1377,145 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// 提议资料包（仅限已注册的 Oracle）
+        ///
+        /// # 参数
+        /// - `kind`: 资料包类型
+        /// - `version`: 版本号，必须大于该类型已有的最高版本
+        /// - `cid`: 资料包的 IPFS CID
+        /// - `content_hash`: 资料包清单的 blake2-256 哈希
+        #[pallet::call_index(16)]
+        #[pallet::weight(Weight::from_parts(30_000_000, 0))]
+        pub fn propose_bundle(
+            origin: OriginFor<T>,
+            kind: BundleKind,
+            version: u32,
+            cid: Vec<u8>,
+            content_hash: [u8; 32],
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(Oracles::<T>::contains_key(&who), Error::<T>::OracleNotFound);
+            ensure!(
+                !KnowledgeBundles::<T>::contains_key(kind, version),
+                Error::<T>::BundleVersionExists
+            );
+            ensure!(
+                KnowledgeBundles::<T>::iter_key_prefix(kind).all(|v| v < version),
+                Error::<T>::BundleVersionTooLow
+            );
+
+            let cid_bounded: BoundedVec<u8, T::MaxCidLength> =
+                BoundedVec::try_from(cid).map_err(|_| Error::<T>::CidTooLong)?;
+
+            let bundle = KnowledgeBundle {
+                kind,
+                version,
+                cid: cid_bounded.clone(),
+                content_hash,
+                proposer: who.clone(),
+                proposed_at: <frame_system::Pallet<T>>::block_number(),
+                status: BundleStatus::Proposed,
+                approved_at: None,
+            };
+
+            KnowledgeBundles::<T>::insert(kind, version, bundle);
+
+            Self::deposit_event(Event::BundleProposed {
+                kind,
+                version,
+                cid: cid_bounded,
+                content_hash,
+                proposer: who,
+            });
+
+            Ok(())
+        }
+
+        /// 批准资料包（仅限治理）
+        ///
+        /// 批准后该版本成为此类型的推荐版本
+        #[pallet::call_index(17)]
+        #[pallet::weight(Weight::from_parts(20_000_000, 0))]
+        pub fn approve_bundle(
+            origin: OriginFor<T>,
+            kind: BundleKind,
+            version: u32,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            KnowledgeBundles::<T>::try_mutate(kind, version, |maybe_bundle| {
+                let bundle = maybe_bundle.as_mut().ok_or(Error::<T>::BundleNotFound)?;
+                ensure!(
+                    bundle.status == BundleStatus::Proposed,
+                    Error::<T>::InvalidBundleStatus
+                );
+                bundle.status = BundleStatus::Approved;
+                bundle.approved_at = Some(<frame_system::Pallet<T>>::block_number());
+                Ok::<_, DispatchError>(())
+            })?;
+
+            // 只前进不回退：补批旧版本不影响推荐版本
+            ActiveBundleVersion::<T>::mutate(kind, |active| {
+                if active.map_or(true, |v| v < version) {
+                    *active = Some(version);
+                }
+            });
+
+            Self::deposit_event(Event::BundleApproved { kind, version });
+
+            Ok(())
+        }
+
+        /// 撤销资料包（仅限治理）
+        ///
+        /// 撤销后 Oracle 不得再以该版本提交解读；若为推荐版本，
+        /// 推荐版本回退到仍处于批准状态的最高版本
+        #[pallet::call_index(18)]
+        #[pallet::weight(Weight::from_parts(30_000_000, 0))]
+        pub fn revoke_bundle(
+            origin: OriginFor<T>,
+            kind: BundleKind,
+            version: u32,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            KnowledgeBundles::<T>::try_mutate(kind, version, |maybe_bundle| {
+                let bundle = maybe_bundle.as_mut().ok_or(Error::<T>::BundleNotFound)?;
+                ensure!(
+                    bundle.status != BundleStatus::Revoked,
+                    Error::<T>::InvalidBundleStatus
+                );
+                bundle.status = BundleStatus::Revoked;
+                Ok::<_, DispatchError>(())
+            })?;
+
+            if ActiveBundleVersion::<T>::get(kind) == Some(version) {
+                let fallback = KnowledgeBundles::<T>::iter_prefix(kind)
+                    .filter(|(_, b)| b.is_usable())
+                    .map(|(v, _)| v)
+                    .max();
+                ActiveBundleVersion::<T>::set(kind, fallback);
+            }
+
+            Self::deposit_event(Event::BundleRevoked { kind, version });
+
+            Ok(())
+        }
     }
 
     // ==================== 内部辅助函数 ====================
 
     impl<T: Config> Pallet<T> {
+        /// 确认资料包已批准且未撤销
+        pub fn ensure_bundle_approved(kind: BundleKind, version: u32) -> DispatchResult {
+            let bundle =
+                KnowledgeBundles::<T>::get(kind, version).ok_or(Error::<T>::BundleNotFound)?;
+            ensure!(bundle.is_usable(), Error::<T>::BundleNotApproved);
+            Ok(())
+        }
+
         /// 分配费用
         fn distribute_fees(
             request_id: u64,
@@ -134,6 +134,36 @@ impl pallet_divination_ai::Config for Test {
     type TreasuryAccount = TreasuryAccount;
     type ArbitratorOrigin = frame_system::EnsureRoot<u64>;
     type GovernanceOrigin = frame_system::EnsureRoot<u64>;
+    type MembershipBenefits = MockMembershipBenefits;
+}
+
+/// 🆕 模拟会员权益：账户 3 为会员，每个测试 1 次免费解读
+pub struct MockMembershipBenefits;
+impl pallet_membership::MembershipBenefits<u64> for MockMembershipBenefits {
+    fn pin_discount_percent(_who: &u64) -> u8 {
+        0
+    }
+
+    fn nft_fee_discount_percent(_who: &u64) -> u8 {
+        0
+    }
+
+    fn anti_spam_bonus_percent(_who: &u64) -> u16 {
+        0
+    }
+
+    fn try_use_free_ai_interpretation(who: &u64) -> bool {
+        if *who != 3 || FreeUsed::get() {
+            return false;
+        }
+        FreeUsed::set(true);
+        true
+    }
+}
+
+parameter_types! {
+    /// 函数级中文注释：模拟免费解读是否已使用（线程本地）
+    pub static FreeUsed: bool = false;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -160,5 +190,6 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
         System::set_block_number(1);
         MockDivinationProvider::clear();
     });
+    FreeUsed::set(false);
     ext
 }
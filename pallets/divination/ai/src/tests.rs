@@ -1,10 +1,24 @@
 //! Tests for pallet-divination-ai
 
 use crate::{mock::*, Error, Event};
-use crate::types::{DisputeResolution, DisputeStatus, FeeDistribution};
-use frame_support::{assert_noop, assert_ok};
+use crate::types::{BundleKind, BundleStatus, DisputeResolution, DisputeStatus, FeeDistribution};
+use frame_support::{assert_noop, assert_ok, traits::ReservableCurrency};
 use pallet_divination_common::{DivinationType, InterpretationStatus, InterpretationType, RarityInput};
 
+/// 由 Oracle 提议并经治理批准 v1 知识库与 v1 Prompt 模板
+fn approve_bundles(oracle: u64) {
+    for kind in [BundleKind::KnowledgeBase, BundleKind::PromptTemplate] {
+        assert_ok!(DivinationAiPallet::propose_bundle(
+            RuntimeOrigin::signed(oracle),
+            kind,
+            1,
+            b"QmBundleCid".to_vec(),
+            [7u8; 32],
+        ));
+        assert_ok!(DivinationAiPallet::approve_bundle(RuntimeOrigin::root(), kind, 1));
+    }
+}
+
 // ==================== 预言机注册测试 ====================
 
 #[test]
@@ -106,6 +120,36 @@ fn request_interpretation_works() {
     });
 }
 
+#[test]
+fn member_free_interpretation_waives_fee() {
+    new_test_ext().execute_with(|| {
+        MockDivinationProvider::add_result(DivinationType::Meihua, 1, 1, RarityInput::common());
+
+        // 会员（账户 3）首次请求：使用免费额度，不冻结费用
+        assert_ok!(DivinationAiPallet::request_interpretation(
+            RuntimeOrigin::signed(3),
+            DivinationType::Meihua,
+            1,
+            InterpretationType::Basic,
+            None,
+        ));
+        assert_eq!(DivinationAiPallet::requests(0).unwrap().fee_paid, 0);
+        assert_eq!(Balances::reserved_balance(3), 0);
+
+        // 额度用尽后恢复正常收费
+        assert_ok!(DivinationAiPallet::request_interpretation(
+            RuntimeOrigin::signed(3),
+            DivinationType::Meihua,
+            1,
+            InterpretationType::Basic,
+            None,
+        ));
+        let fee = DivinationAiPallet::requests(1).unwrap().fee_paid;
+        assert!(fee > 0);
+        assert_eq!(Balances::reserved_balance(3), fee);
+    });
+}
+
 #[test]
 fn request_interpretation_fails_if_result_not_found() {
     new_test_ext().execute_with(|| {
@@ -222,6 +266,7 @@ fn submit_result_works() {
         ));
 
         assert_ok!(DivinationAiPallet::accept_request(RuntimeOrigin::signed(4), 0));
+        approve_bundles(4);
 
         // 提交结果
         assert_ok!(DivinationAiPallet::submit_result(
@@ -231,6 +276,8 @@ fn submit_result_works() {
             Some(b"QmSummaryCid".to_vec()),
             b"gpt-4".to_vec(),
             b"zh-CN".to_vec(),
+            1,
+            1,
         ));
 
         // 验证结果
@@ -272,6 +319,7 @@ fn rate_result_works() {
         ));
 
         assert_ok!(DivinationAiPallet::accept_request(RuntimeOrigin::signed(4), 0));
+        approve_bundles(4);
 
         assert_ok!(DivinationAiPallet::submit_result(
             RuntimeOrigin::signed(4),
@@ -280,6 +328,8 @@ fn rate_result_works() {
             None,
             b"gpt".to_vec(),
             b"zh".to_vec(),
+            1,
+            1,
         ));
 
         // 用户评分
@@ -322,6 +372,7 @@ fn rate_result_fails_if_invalid_rating() {
         ));
 
         assert_ok!(DivinationAiPallet::accept_request(RuntimeOrigin::signed(4), 0));
+        approve_bundles(4);
 
         assert_ok!(DivinationAiPallet::submit_result(
             RuntimeOrigin::signed(4),
@@ -330,6 +381,8 @@ fn rate_result_fails_if_invalid_rating() {
             None,
             b"gpt".to_vec(),
             b"zh".to_vec(),
+            1,
+            1,
         ));
 
         // 评分范围无效
@@ -410,6 +463,7 @@ fn create_and_resolve_dispute_works() {
         ));
 
         assert_ok!(DivinationAiPallet::accept_request(RuntimeOrigin::signed(4), 0));
+        approve_bundles(4);
 
         assert_ok!(DivinationAiPallet::submit_result(
             RuntimeOrigin::signed(4),
@@ -418,6 +472,8 @@ fn create_and_resolve_dispute_works() {
             None,
             b"gpt".to_vec(),
             b"zh".to_vec(),
+            1,
+            1,
         ));
 
         // 创建争议
@@ -589,3 +645,228 @@ fn fee_distribution_validation_works() {
         );
     });
 }
+
+// ==================== 资料包注册表测试 ====================
+
+#[test]
+fn propose_and_approve_bundle_works() {
+    new_test_ext().execute_with(|| {
+        // 未注册的账户不能提议
+        assert_noop!(
+            DivinationAiPallet::propose_bundle(
+                RuntimeOrigin::signed(1),
+                BundleKind::KnowledgeBase,
+                1,
+                b"QmKb".to_vec(),
+                [1u8; 32],
+            ),
+            Error::<Test>::OracleNotFound
+        );
+
+        assert_ok!(DivinationAiPallet::register_oracle(
+            RuntimeOrigin::signed(4),
+            b"Oracle".to_vec(),
+            0b11,
+            0b111,
+        ));
+        assert_ok!(DivinationAiPallet::propose_bundle(
+            RuntimeOrigin::signed(4),
+            BundleKind::KnowledgeBase,
+            2,
+            b"QmKb".to_vec(),
+            [1u8; 32],
+        ));
+
+        // 版本号必须递增
+        assert_noop!(
+            DivinationAiPallet::propose_bundle(
+                RuntimeOrigin::signed(4),
+                BundleKind::KnowledgeBase,
+                1,
+                b"QmOld".to_vec(),
+                [2u8; 32],
+            ),
+            Error::<Test>::BundleVersionTooLow
+        );
+        assert_noop!(
+            DivinationAiPallet::propose_bundle(
+                RuntimeOrigin::signed(4),
+                BundleKind::KnowledgeBase,
+                2,
+                b"QmDup".to_vec(),
+                [2u8; 32],
+            ),
+            Error::<Test>::BundleVersionExists
+        );
+
+        // 仅治理可批准
+        assert!(DivinationAiPallet::approve_bundle(
+            RuntimeOrigin::signed(4),
+            BundleKind::KnowledgeBase,
+            2
+        )
+        .is_err());
+        assert_ok!(DivinationAiPallet::approve_bundle(
+            RuntimeOrigin::root(),
+            BundleKind::KnowledgeBase,
+            2
+        ));
+
+        let bundle = DivinationAiPallet::knowledge_bundles(BundleKind::KnowledgeBase, 2).unwrap();
+        assert_eq!(bundle.status, BundleStatus::Approved);
+        assert_eq!(bundle.content_hash, [1u8; 32]);
+        assert_eq!(
+            DivinationAiPallet::active_bundle_version(BundleKind::KnowledgeBase),
+            Some(2)
+        );
+        System::assert_has_event(
+            Event::BundleApproved {
+                kind: BundleKind::KnowledgeBase,
+                version: 2,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn submit_result_requires_approved_bundles() {
+    new_test_ext().execute_with(|| {
+        MockDivinationProvider::add_result(DivinationType::Meihua, 1, 1, RarityInput::common());
+
+        assert_ok!(DivinationAiPallet::register_oracle(
+            RuntimeOrigin::signed(4),
+            b"Oracle".to_vec(),
+            0b11,
+            0b111,
+        ));
+        assert_ok!(DivinationAiPallet::request_interpretation(
+            RuntimeOrigin::signed(1),
+            DivinationType::Meihua,
+            1,
+            InterpretationType::Basic,
+            None,
+        ));
+        assert_ok!(DivinationAiPallet::accept_request(RuntimeOrigin::signed(4), 0));
+
+        // 资料包未登记
+        assert_noop!(
+            DivinationAiPallet::submit_result(
+                RuntimeOrigin::signed(4),
+                0,
+                b"QmCid".to_vec(),
+                None,
+                b"gpt".to_vec(),
+                b"zh".to_vec(),
+                1,
+                1,
+            ),
+            Error::<Test>::BundleNotFound
+        );
+
+        // 已提议但未批准
+        assert_ok!(DivinationAiPallet::propose_bundle(
+            RuntimeOrigin::signed(4),
+            BundleKind::KnowledgeBase,
+            1,
+            b"QmKb".to_vec(),
+            [1u8; 32],
+        ));
+        assert_noop!(
+            DivinationAiPallet::submit_result(
+                RuntimeOrigin::signed(4),
+                0,
+                b"QmCid".to_vec(),
+                None,
+                b"gpt".to_vec(),
+                b"zh".to_vec(),
+                1,
+                1,
+            ),
+            Error::<Test>::BundleNotApproved
+        );
+
+        assert_ok!(DivinationAiPallet::approve_bundle(
+            RuntimeOrigin::root(),
+            BundleKind::KnowledgeBase,
+            1
+        ));
+        assert_ok!(DivinationAiPallet::propose_bundle(
+            RuntimeOrigin::signed(4),
+            BundleKind::PromptTemplate,
+            3,
+            b"QmPrompt".to_vec(),
+            [3u8; 32],
+        ));
+        assert_ok!(DivinationAiPallet::approve_bundle(
+            RuntimeOrigin::root(),
+            BundleKind::PromptTemplate,
+            3
+        ));
+
+        assert_ok!(DivinationAiPallet::submit_result(
+            RuntimeOrigin::signed(4),
+            0,
+            b"QmCid".to_vec(),
+            None,
+            b"gpt".to_vec(),
+            b"zh".to_vec(),
+            1,
+            3,
+        ));
+
+        let result = DivinationAiPallet::results(0).unwrap();
+        assert_eq!(result.knowledge_version, 1);
+        assert_eq!(result.prompt_template_version, 3);
+    });
+}
+
+#[test]
+fn revoke_bundle_falls_back_to_previous_version() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DivinationAiPallet::register_oracle(
+            RuntimeOrigin::signed(4),
+            b"Oracle".to_vec(),
+            0b11,
+            0b111,
+        ));
+        approve_bundles(4);
+
+        assert_ok!(DivinationAiPallet::propose_bundle(
+            RuntimeOrigin::signed(4),
+            BundleKind::KnowledgeBase,
+            2,
+            b"QmKbV2".to_vec(),
+            [2u8; 32],
+        ));
+        assert_ok!(DivinationAiPallet::approve_bundle(
+            RuntimeOrigin::root(),
+            BundleKind::KnowledgeBase,
+            2
+        ));
+        assert_eq!(
+            DivinationAiPallet::active_bundle_version(BundleKind::KnowledgeBase),
+            Some(2)
+        );
+
+        assert_ok!(DivinationAiPallet::revoke_bundle(
+            RuntimeOrigin::root(),
+            BundleKind::KnowledgeBase,
+            2
+        ));
+        assert_eq!(
+            DivinationAiPallet::active_bundle_version(BundleKind::KnowledgeBase),
+            Some(1)
+        );
+        assert_noop!(
+            DivinationAiPallet::ensure_bundle_approved(BundleKind::KnowledgeBase, 2),
+            Error::<Test>::BundleNotApproved
+        );
+
+        // 已撤销的资料包不能再批准
+        assert_noop!(
+            DivinationAiPallet::approve_bundle(RuntimeOrigin::root(), BundleKind::KnowledgeBase, 2),
+            Error::<Test>::InvalidBundleStatus
+        );
+    });
+}
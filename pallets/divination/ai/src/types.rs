@@ -63,6 +63,10 @@ pub struct InterpretationResult<AccountId, BlockNumber, MaxCidLen: Get<u32>> {
     pub model_version: BoundedVec<u8, ConstU32<32>>,
     /// 解读语言（zh-CN, en-US 等）
     pub language: BoundedVec<u8, ConstU32<8>>,
+    /// 生成解读所用的知识库版本（已批准的 KnowledgeBase 资料包）
+    pub knowledge_version: u32,
+    /// 生成解读所用的 Prompt 模板版本（已批准的 PromptTemplate 资料包）
+    pub prompt_template_version: u32,
 }
 
 /// 预言机节点信息
@@ -363,3 +367,60 @@ impl OracleModelSupport {
             .unwrap_or(false)
     }
 }
+
+// ==================== 知识库资料包注册表 ====================
+
+/// 资料包类型
+///
+/// Oracle 生成解读时依赖的两类离线资料，分别独立版本化
+#[derive(Clone, Copy, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
+pub enum BundleKind {
+    /// 知识库（天干地支、格局、调候用神等 JSON）
+    KnowledgeBase = 0,
+    /// Prompt 模板
+    PromptTemplate = 1,
+}
+
+/// 资料包审批状态
+#[derive(Clone, Copy, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug, Default)]
+pub enum BundleStatus {
+    /// 已提议，等待治理批准
+    #[default]
+    Proposed = 0,
+    /// 已批准，可用于生成解读
+    Approved = 1,
+    /// 已撤销，不得再使用
+    Revoked = 2,
+}
+
+/// 内容寻址的资料包
+///
+/// 资料包整体上传 IPFS，链上只锚定 CID 与清单哈希；
+/// Oracle 加载资料前需核对本地清单哈希与链上记录一致且已批准。
+#[derive(Clone, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
+#[scale_info(skip_type_params(MaxCidLen))]
+pub struct KnowledgeBundle<AccountId, BlockNumber, MaxCidLen: Get<u32>> {
+    /// 资料包类型
+    pub kind: BundleKind,
+    /// 版本号（同类型内单调递增）
+    pub version: u32,
+    /// 资料包的 IPFS CID
+    pub cid: BoundedVec<u8, MaxCidLen>,
+    /// 资料包清单的 blake2-256 哈希
+    pub content_hash: [u8; 32],
+    /// 提议者
+    pub proposer: AccountId,
+    /// 提议时的区块号
+    pub proposed_at: BlockNumber,
+    /// 审批状态
+    pub status: BundleStatus,
+    /// 批准时的区块号
+    pub approved_at: Option<BlockNumber>,
+}
+
+impl<AccountId, BlockNumber, MaxCidLen: Get<u32>> KnowledgeBundle<AccountId, BlockNumber, MaxCidLen> {
+    /// 是否可用于生成解读
+    pub fn is_usable(&self) -> bool {
+        self.status == BundleStatus::Approved
+    }
+}
@@ -1458,6 +1458,79 @@ pub fn build_full_bazi_chart_for_api_temp(
     }
 }
 
+/// 临时排盘（纯函数，不依赖链上状态）
+///
+/// 链上 Runtime API（`calculate_bazi_temp`）与链下服务（stardust-gateway 本地计算模式）
+/// 共用此函数，保证同一输入得到完全一致的命盘。
+///
+/// # 参数
+/// - year/month/day/hour/minute: 公历出生时间
+/// - gender: 性别
+/// - zishi_mode: 子时模式
+/// - longitude: 出生地经度（可选，用于真太阳时修正）
+/// - input_calendar_type: 原始输入日历类型（仅用于展示）
+///
+/// # 返回
+/// - None: 输入参数无效
+pub fn calculate_temp_chart(
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    gender: Gender,
+    zishi_mode: ZiShiMode,
+    longitude: Option<i32>,
+    input_calendar_type: crate::types::InputCalendarType,
+) -> Option<FullBaziChartForApi> {
+    use crate::calculations::*;
+
+    // 验证输入
+    if year < 1900 || year > 2100 { return None; }
+    if month < 1 || month > 12 { return None; }
+    if day < 1 || day > 31 { return None; }
+    if hour > 23 { return None; }
+    if minute > 59 { return None; }
+
+    // 应用真太阳时修正（当 longitude 有值时）
+    let (calc_year, calc_month, calc_day, calc_hour, _calc_minute) =
+        if let Some(lng) = longitude {
+            let result = apply_true_solar_time(year, month, day, hour, minute, lng);
+            let (adj_year, adj_month, adj_day) = if result.day_offset != 0 {
+                adjust_date(year, month, day, result.day_offset)
+            } else {
+                (year, month, day)
+            };
+            (adj_year, adj_month, adj_day, result.hour, result.minute)
+        } else {
+            (year, month, day, hour, minute)
+        };
+
+    // 计算四柱
+    let day_ganzhi = calculate_day_ganzhi(calc_year, calc_month, calc_day)?;
+    let year_ganzhi = calculate_year_ganzhi(calc_year, calc_month, calc_day)?;
+    let month_ganzhi = calculate_month_ganzhi(calc_year, calc_month, calc_day, year_ganzhi.gan.0)?;
+    let (hour_ganzhi, is_next_day) = calculate_hour_ganzhi(calc_hour, day_ganzhi.gan.0, zishi_mode)?;
+
+    let (final_day_ganzhi, final_hour_ganzhi) = if is_next_day {
+        let next_day_ganzhi = day_ganzhi.next();
+        let (final_hour, _) = calculate_hour_ganzhi(calc_hour, next_day_ganzhi.gan.0, zishi_mode)?;
+        (next_day_ganzhi, final_hour)
+    } else {
+        (day_ganzhi, hour_ganzhi)
+    };
+
+    Some(build_full_bazi_chart_for_api_temp(
+        year_ganzhi,
+        month_ganzhi,
+        final_day_ganzhi,
+        final_hour_ganzhi,
+        gender,
+        year,
+        input_calendar_type,
+    ))
+}
+
 /// 构建单柱信息
 fn build_zhu_for_api(
     ganzhi: &GanZhi,
@@ -2555,53 +2555,17 @@ pub mod pallet {
 			zishi_mode: ZiShiMode,
 			longitude: Option<i32>,
 		) -> Option<crate::interpretation::FullBaziChartForApi> {
-			use crate::calculations::*;
-
-			// 验证输入
-			if year < 1900 || year > 2100 { return None; }
-			if month < 1 || month > 12 { return None; }
-			if day < 1 || day > 31 { return None; }
-			if hour > 23 { return None; }
-			if minute > 59 { return None; }
-
-			// 应用真太阳时修正（当 longitude 有值时）
-			let (calc_year, calc_month, calc_day, calc_hour, _calc_minute) =
-				if let Some(lng) = longitude {
-					let result = apply_true_solar_time(year, month, day, hour, minute, lng);
-					let (adj_year, adj_month, adj_day) = if result.day_offset != 0 {
-						adjust_date(year, month, day, result.day_offset)
-				} else {
-						(year, month, day)
-					};
-					(adj_year, adj_month, adj_day, result.hour, result.minute)
-				} else {
-					(year, month, day, hour, minute)
-				};
-
-			// 计算四柱
-			let day_ganzhi = calculate_day_ganzhi(calc_year, calc_month, calc_day)?;
-			let year_ganzhi = calculate_year_ganzhi(calc_year, calc_month, calc_day)?;
-			let month_ganzhi = calculate_month_ganzhi(calc_year, calc_month, calc_day, year_ganzhi.gan.0)?;
-			let (hour_ganzhi, is_next_day) = calculate_hour_ganzhi(calc_hour, day_ganzhi.gan.0, zishi_mode)?;
-
-			let (final_day_ganzhi, final_hour_ganzhi) = if is_next_day {
-				let next_day_ganzhi = day_ganzhi.next();
-				let (final_hour, _) = calculate_hour_ganzhi(calc_hour, next_day_ganzhi.gan.0, zishi_mode)?;
-				(next_day_ganzhi, final_hour)
-			} else {
-				(day_ganzhi, hour_ganzhi)
-			};
-
-			// 构建临时命盘数据用于 API 返回
-			Some(crate::interpretation::build_full_bazi_chart_for_api_temp(
-				year_ganzhi,
-				month_ganzhi,
-				final_day_ganzhi,
-				final_hour_ganzhi,
-				gender,
+			crate::interpretation::calculate_temp_chart(
 				year,
+				month,
+				day,
+				hour,
+				minute,
+				gender,
+				zishi_mode,
+				longitude,
 				crate::types::InputCalendarType::Solar, // 公历输入
-			))
+			)
 		}
 
 		/// RPC 接口：临时排盘（内部函数，支持指定日历类型）
@@ -2618,53 +2582,17 @@ pub mod pallet {
 			longitude: Option<i32>,
 			input_calendar_type: crate::types::InputCalendarType,
 		) -> Option<crate::interpretation::FullBaziChartForApi> {
-			use crate::calculations::*;
-
-			// 验证输入
-			if year < 1900 || year > 2100 { return None; }
-			if month < 1 || month > 12 { return None; }
-			if day < 1 || day > 31 { return None; }
-			if hour > 23 { return None; }
-			if minute > 59 { return None; }
-
-			// 应用真太阳时修正（当 longitude 有值时）
-			let (calc_year, calc_month, calc_day, calc_hour, _calc_minute) =
-				if let Some(lng) = longitude {
-					let result = apply_true_solar_time(year, month, day, hour, minute, lng);
-					let (adj_year, adj_month, adj_day) = if result.day_offset != 0 {
-						adjust_date(year, month, day, result.day_offset)
-					} else {
-						(year, month, day)
-					};
-					(adj_year, adj_month, adj_day, result.hour, result.minute)
-				} else {
-					(year, month, day, hour, minute)
-				};
-
-			// 计算四柱
-			let day_ganzhi = calculate_day_ganzhi(calc_year, calc_month, calc_day)?;
-			let year_ganzhi = calculate_year_ganzhi(calc_year, calc_month, calc_day)?;
-			let month_ganzhi = calculate_month_ganzhi(calc_year, calc_month, calc_day, year_ganzhi.gan.0)?;
-			let (hour_ganzhi, is_next_day) = calculate_hour_ganzhi(calc_hour, day_ganzhi.gan.0, zishi_mode)?;
-
-			let (final_day_ganzhi, final_hour_ganzhi) = if is_next_day {
-				let next_day_ganzhi = day_ganzhi.next();
-				let (final_hour, _) = calculate_hour_ganzhi(calc_hour, next_day_ganzhi.gan.0, zishi_mode)?;
-				(next_day_ganzhi, final_hour)
-			} else {
-				(day_ganzhi, hour_ganzhi)
-			};
-
-			// 构建临时命盘数据用于 API 返回
-			Some(crate::interpretation::build_full_bazi_chart_for_api_temp(
-				year_ganzhi,
-				month_ganzhi,
-				final_day_ganzhi,
-				final_hour_ganzhi,
-				gender,
+			crate::interpretation::calculate_temp_chart(
 				year,
+				month,
+				day,
+				hour,
+				minute,
+				gender,
+				zishi_mode,
+				longitude,
 				input_calendar_type, // 使用指定的日历类型
-			))
+			)
 		}
 
 		/// RPC 接口：临时排盘统一接口（不存储，免费）
@@ -46,6 +46,22 @@ pub enum DivinationType {
 }
 
 impl DivinationType {
+    /// 从编码值解析（Runtime API / RPC 以 u8 传递占卜类型）
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Meihua),
+            1 => Some(Self::Bazi),
+            2 => Some(Self::Liuyao),
+            3 => Some(Self::Qimen),
+            4 => Some(Self::Ziwei),
+            5 => Some(Self::Taiyi),
+            6 => Some(Self::Daliuren),
+            7 => Some(Self::XiaoLiuRen),
+            8 => Some(Self::Tarot),
+            _ => None,
+        }
+    }
+
     /// 获取占卜类型的中文名称
     pub fn name(&self) -> &'static str {
         match self {
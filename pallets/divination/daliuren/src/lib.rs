@@ -38,12 +38,15 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub use pallet::*;
 
 mod algorithm;
 mod types;
 mod interpretation;
 mod interpretation_algorithm;
+pub mod runtime_api;
 
 #[cfg(test)]
 mod mock;
@@ -95,6 +98,7 @@ pub mod pallet {
         pallet_prelude::*,
         traits::{Currency, Randomness, ReservableCurrency},
     };
+    use alloc::vec::Vec;
     use frame_system::pallet_prelude::*;
     use sp_runtime::traits::Zero;
 
@@ -1276,6 +1280,18 @@ pub mod pallet {
         pub fn has_pending_ai_request(pan_id: u64) -> bool {
             AiInterpretationRequests::<T>::contains_key(pan_id)
         }
+
+        /// 获取式盘创建者
+        pub fn get_pan_owner(pan_id: u64) -> Option<T::AccountId> {
+            Pans::<T>::get(pan_id).map(|pan| pan.creator)
+        }
+
+        /// 获取 AI 解读 CID
+        pub fn get_ai_interpretation_cid(pan_id: u64) -> Option<Vec<u8>> {
+            Pans::<T>::get(pan_id)?
+                .ai_interpretation_cid
+                .map(|cid| cid.into_inner())
+        }
     }
 
     // ========================================================================
@@ -0,0 +1,79 @@
+//! # 大六壬解盘 Runtime API
+//!
+//! 本模块定义了大六壬解盘的 Runtime API，供前端通过 RPC 免费调用。
+//!
+//! ## 功能说明
+//!
+//! - `get_core_interpretation`: 获取核心解盘指标（约 20 字节）
+//! - `get_full_interpretation`: 获取完整解盘（三传、四课、天将、神煞、应期）
+//! - `get_san_chuan_analysis` / `get_ying_qi_analysis`: 分项分析
+//! - `pan_exists` / `get_pan_owner`: 存在性与创建者查询
+//! - `get_ai_interpretation_cid`: AI 解读 CID
+//! - `get_encrypted_data` / `get_owner_key_backup`: 隐私模式数据
+//!
+//! ## 使用方式
+//!
+//! ```javascript
+//! const core = await api.call.daLiuRenApi.getCoreInterpretation(panId);
+//! const full = await api.call.daLiuRenApi.getFullInterpretation(panId, null);
+//! ```
+
+use crate::interpretation::{
+    CoreInterpretation, FullInterpretation, SanChuanAnalysis, ShiXiangType, YingQiAnalysis,
+};
+use alloc::vec::Vec;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// 大六壬解盘 Runtime API
+    ///
+    /// Private 模式的式盘无计算数据，解盘类接口返回 None
+    pub trait DaLiuRenApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// 获取核心解盘结果
+        ///
+        /// # 参数
+        /// - `pan_id`: 式盘 ID
+        ///
+        /// # 返回
+        /// - `Some(CoreInterpretation)`: 核心解盘结果
+        /// - `None`: 式盘不存在
+        fn get_core_interpretation(pan_id: u64) -> Option<CoreInterpretation>;
+
+        /// 获取完整解盘结果
+        ///
+        /// # 参数
+        /// - `pan_id`: 式盘 ID
+        /// - `shi_xiang_type`: 占问类型（可选）
+        fn get_full_interpretation(
+            pan_id: u64,
+            shi_xiang_type: Option<ShiXiangType>,
+        ) -> Option<FullInterpretation>;
+
+        /// 获取三传分析
+        fn get_san_chuan_analysis(pan_id: u64) -> Option<SanChuanAnalysis>;
+
+        /// 获取应期分析
+        fn get_ying_qi_analysis(
+            pan_id: u64,
+            shi_xiang_type: Option<ShiXiangType>,
+        ) -> Option<YingQiAnalysis>;
+
+        /// 检查式盘是否存在
+        fn pan_exists(pan_id: u64) -> bool;
+
+        /// 获取式盘创建者
+        fn get_pan_owner(pan_id: u64) -> Option<AccountId>;
+
+        /// 获取 AI 解读 CID
+        fn get_ai_interpretation_cid(pan_id: u64) -> Option<Vec<u8>>;
+
+        /// 获取加密数据（Partial/Private 模式）
+        fn get_encrypted_data(pan_id: u64) -> Option<Vec<u8>>;
+
+        /// 获取所有者密钥备份
+        fn get_owner_key_backup(pan_id: u64) -> Option<[u8; 80]>;
+    }
+}
@@ -5,7 +5,7 @@
 //! ## 主要功能
 //! - 举报成立处理（扣除大师押金、奖励举报者）
 //! - 举报驳回处理（退还举报押金）
-//! - 恶意举报处理（没收押金、扣信用分）
+//! - 恶意举报处理（没收押金、扣信用分、计入内容治理信誉）
 //! - 辅助计算函数
 
 use crate::pallet::*;
@@ -16,6 +16,7 @@ use frame_support::{
     traits::{Currency, ExistenceRequirement, Get, ReservableCurrency},
 };
 use frame_system::pallet_prelude::*;
+use pallet_credit::reputation::{ReputationDomain, ReputationProvider};
 use sp_runtime::{
     traits::{Saturating, Zero},
     SaturatedConversion,
@@ -192,6 +193,13 @@ impl<T: Config> Pallet<T> {
             }
         });
 
+        // 恶意举报计入内容治理领域信誉
+        T::Reputation::record_violation(
+            &report.reporter,
+            ReputationDomain::ContentGovernance,
+            penalty,
+        );
+
         // 3. 更新统计
         ReportStatistics::<T>::mutate(|stats| {
             stats.total_confiscated_deposits = stats
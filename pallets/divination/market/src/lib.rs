@@ -59,6 +59,7 @@ pub mod pallet {
         BoundedVec,
     };
     use frame_system::pallet_prelude::*;
+    use pallet_credit::reputation::{ReputationDomain, ReputationProvider};
     use pallet_divination_common::{DivinationProvider, DivinationType};
     use sp_runtime::traits::{Saturating, Zero};
     use sp_std::prelude::*;
@@ -155,6 +156,12 @@ pub mod pallet {
         /// 国库账户（罚金剩余部分归国库）
         #[pallet::constant]
         type TreasuryAccount: Get<Self::AccountId>;
+
+        /// 跨领域信誉引擎
+        ///
+        /// 占卜服务违规与评价同步上报，注册和接单需满足占卜服务领域资格，
+        /// 其他领域（如 OTC 违约）的违规也会影响资格。
+        type Reputation: ReputationProvider<Self::AccountId>;
     }
 
     /// 货币余额类型别名
@@ -998,6 +1005,20 @@ pub mod pallet {
             provider: T::AccountId,
             watch_end: BlockNumberFor<T>,
         },
+
+        // ==================== 内容治理执行事件 ====================
+
+        /// 大师已被治理暂停（下架，不可接单）
+        ProviderSuspendedByGovernance { provider: T::AccountId },
+
+        /// 大师已被治理恢复
+        ProviderRestoredByGovernance { provider: T::AccountId },
+
+        /// 评价已被治理移除
+        ReviewRemovedByGovernance {
+            order_id: u64,
+            reviewee: T::AccountId,
+        },
     }
 
     // ==================== 错误 ====================
@@ -1186,6 +1207,10 @@ pub mod pallet {
         TooManyPendingReports,
         /// 大师已被封禁（举报相关）
         ProviderAlreadyBanned,
+        /// 跨领域信誉不满足占卜服务资格
+        ReputationIneligible,
+        /// 评价不存在
+        ReviewNotFound,
     }
 
     // ==================== 可调用函数 ====================
@@ -1217,6 +1242,12 @@ pub mod pallet {
                 Error::<T>::ProviderAlreadyExists
             );
 
+            // 检查跨领域信誉资格
+            ensure!(
+                T::Reputation::is_eligible(&who, ReputationDomain::DivinationService),
+                Error::<T>::ReputationIneligible
+            );
+
             let name_bounded: BoundedVec<u8, T::MaxNameLength> =
                 BoundedVec::try_from(name).map_err(|_| Error::<T>::NameTooLong)?;
             let bio_bounded: BoundedVec<u8, T::MaxBioLength> =
@@ -1673,6 +1704,12 @@ pub mod pallet {
         pub fn accept_order(origin: OriginFor<T>, order_id: u64) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
+            // 检查跨领域信誉资格
+            ensure!(
+                T::Reputation::is_eligible(&who, ReputationDomain::DivinationService),
+                Error::<T>::ReputationIneligible
+            );
+
             Orders::<T>::try_mutate(order_id, |maybe_order| {
                 let order = maybe_order.as_mut().ok_or(Error::<T>::OrderNotFound)?;
                 ensure!(order.provider == who, Error::<T>::NotProvider);
@@ -1987,6 +2024,15 @@ pub mod pallet {
                 }
             });
 
+            // 好评同步为占卜服务领域正向信誉
+            if overall_rating >= 4 {
+                T::Reputation::record_positive(
+                    &order.provider,
+                    ReputationDomain::DivinationService,
+                    (overall_rating as u16 - 3) * 5,
+                );
+            }
+
             // 更新市场统计
             MarketStatistics::<T>::mutate(|s| {
                 s.total_reviews += 1;
@@ -3374,6 +3420,13 @@ pub mod pallet {
                     .map_err(|_| Error::<T>::TooManyViolations)
             })?;
 
+            // 同步跨领域信誉（按传导矩阵影响 OTC 等其他领域）
+            T::Reputation::record_violation(
+                &provider,
+                ReputationDomain::DivinationService,
+                deduction_points,
+            );
+
             // 更新信用档案
             CreditProfiles::<T>::mutate(&provider, |maybe_profile| {
                 if let Some(profile) = maybe_profile {
@@ -3936,5 +3989,113 @@ pub mod pallet {
 
             Ok(())
         }
+
+        // ==================== 内容治理执行可调用函数 ====================
+
+        /// 治理暂停/恢复大师（治理权限）
+        ///
+        /// 由内容治理申诉执行路由调用。暂停后大师状态为 Banned，不可接单且不可自行恢复；
+        /// 恢复仅适用于未进入信用黑名单的大师。
+        ///
+        /// # 参数
+        /// - `provider`: 大师账户
+        /// - `suspended`: true=暂停，false=恢复
+        #[pallet::call_index(44)]
+        #[pallet::weight(Weight::from_parts(30_000_000, 0))]
+        pub fn gov_set_provider_suspended(
+            origin: OriginFor<T>,
+            provider: T::AccountId,
+            suspended: bool,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            let was_active = Providers::<T>::try_mutate(&provider, |maybe_provider| {
+                let p = maybe_provider.as_mut().ok_or(Error::<T>::ProviderNotFound)?;
+                let was_active = p.status == ProviderStatus::Active;
+                if suspended {
+                    ensure!(
+                        p.status != ProviderStatus::Banned,
+                        Error::<T>::ProviderAlreadyBanned
+                    );
+                    p.status = ProviderStatus::Banned;
+                } else {
+                    ensure!(
+                        p.status == ProviderStatus::Banned,
+                        Error::<T>::InvalidProviderStatus
+                    );
+                    ensure!(
+                        !CreditBlacklist::<T>::contains_key(&provider),
+                        Error::<T>::InBlacklist
+                    );
+                    p.status = ProviderStatus::Active;
+                    p.last_active_at = <frame_system::Pallet<T>>::block_number();
+                }
+                Ok::<_, DispatchError>(was_active)
+            })?;
+
+            if suspended {
+                if was_active {
+                    MarketStatistics::<T>::mutate(|s| {
+                        s.active_providers = s.active_providers.saturating_sub(1)
+                    });
+                }
+                Self::deposit_event(Event::ProviderSuspendedByGovernance { provider });
+            } else {
+                MarketStatistics::<T>::mutate(|s| {
+                    s.active_providers = s.active_providers.saturating_add(1)
+                });
+                Self::deposit_event(Event::ProviderRestoredByGovernance { provider });
+            }
+
+            Ok(())
+        }
+
+        /// 治理移除评价（治理权限）
+        ///
+        /// 由内容治理申诉执行路由调用。移除评价内容并回退大师评分统计，
+        /// 订单保持已评价状态（不可重新评价）。
+        ///
+        /// # 参数
+        /// - `order_id`: 评价对应的订单 ID
+        #[pallet::call_index(45)]
+        #[pallet::weight(Weight::from_parts(40_000_000, 0))]
+        pub fn gov_remove_review(origin: OriginFor<T>, order_id: u64) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            let review = Reviews::<T>::take(order_id).ok_or(Error::<T>::ReviewNotFound)?;
+            let rating = review.overall_rating;
+
+            Orders::<T>::mutate(order_id, |maybe_order| {
+                if let Some(o) = maybe_order {
+                    o.rating = None;
+                }
+            });
+
+            Providers::<T>::mutate(&review.reviewee, |maybe_provider| {
+                if let Some(p) = maybe_provider {
+                    p.total_ratings = p.total_ratings.saturating_sub(1);
+                    p.rating_sum = p.rating_sum.saturating_sub(rating as u64);
+                }
+            });
+
+            MarketStatistics::<T>::mutate(|s| {
+                let remaining = s.total_reviews.saturating_sub(1);
+                s.average_rating = if remaining == 0 {
+                    0
+                } else {
+                    let total = (s.average_rating as u64 * s.total_reviews)
+                        .saturating_sub(rating as u64 * 100);
+                    (total / remaining) as u16
+                };
+                s.total_reviews = remaining;
+            });
+
+            Self::deposit_event(Event::ReviewRemovedByGovernance {
+                order_id,
+                reviewee: review.reviewee,
+            });
+
+            Ok(())
+        }
     }
 }
@@ -6,6 +6,7 @@ use frame_support::{
     parameter_types,
     traits::{ConstU16, ConstU32, ConstU64},
 };
+use pallet_credit::reputation::{ReputationDomain, ReputationProvider, INITIAL_DOMAIN_SCORE};
 use pallet_divination_common::{DivinationProvider, DivinationType, RarityInput};
 use sp_runtime::BuildStorage;
 use sp_std::vec::Vec;
@@ -165,6 +166,36 @@ impl<O: Into<Result<frame_system::RawOrigin<u64>, O>> + From<frame_system::RawOr
     }
 }
 
+parameter_types! {
+    /// 不满足信誉资格的账户
+    pub static ReputationIneligible: Vec<u64> = Vec::new();
+    /// 已上报的违规 `(账户, 领域, 扣分)`
+    pub static ReputationViolations: Vec<(u64, ReputationDomain, u16)> = Vec::new();
+}
+
+/// 模拟跨领域信誉引擎
+pub struct MockReputation;
+
+impl ReputationProvider<u64> for MockReputation {
+    fn domain_score(_who: &u64, _domain: ReputationDomain) -> u16 {
+        INITIAL_DOMAIN_SCORE
+    }
+
+    fn aggregate_score(_who: &u64) -> u16 {
+        INITIAL_DOMAIN_SCORE
+    }
+
+    fn is_eligible(who: &u64, _domain: ReputationDomain) -> bool {
+        !ReputationIneligible::get().contains(who)
+    }
+
+    fn record_positive(_who: &u64, _domain: ReputationDomain, _points: u16) {}
+
+    fn record_violation(who: &u64, domain: ReputationDomain, points: u16) {
+        ReputationViolations::mutate(|v| v.push((*who, domain, points)));
+    }
+}
+
 impl pallet_divination_market::Config for Test {
     type Currency = Balances;
     type DivinationProvider = MockDivinationProvider;
@@ -198,6 +229,8 @@ impl pallet_divination_market::Config for Test {
     type ReportReviewOrigin = MockReportReviewOrigin;
     /// 国库账户
     type TreasuryAccount = TreasuryAccount;
+    /// 跨领域信誉
+    type Reputation = MockReputation;
 }
 
 /// 构建测试外部状态
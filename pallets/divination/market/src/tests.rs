@@ -64,6 +64,26 @@ fn register_provider_already_exists_fails() {
     });
 }
 
+/// 测试跨领域信誉不达标时无法注册
+#[test]
+fn register_provider_reputation_ineligible_fails() {
+    new_test_ext().execute_with(|| {
+        // 例如 OTC 违约导致综合信誉不足
+        ReputationIneligible::set(vec![10]);
+
+        assert_noop!(
+            DivinationMarket::register_provider(
+                RuntimeOrigin::signed(10),
+                b"Provider1".to_vec(),
+                b"Bio".to_vec(),
+                0b00000001,
+                0b00000001
+            ),
+            Error::<Test>::ReputationIneligible
+        );
+    });
+}
+
 /// 测试更新提供者信息
 #[test]
 fn update_provider_works() {
@@ -2572,3 +2592,94 @@ fn report_type_configurations() {
     assert_eq!(ReportType::Drugs.credit_deduction(), 500);
     assert_eq!(ReportType::Superstition.credit_deduction(), 50);
 }
+
+// ==================== 内容治理执行测试 ====================
+
+/// 测试治理暂停/恢复大师
+#[test]
+fn gov_set_provider_suspended_works() {
+    new_test_ext().execute_with(|| {
+        setup_provider_for_report(10);
+        assert_eq!(DivinationMarket::market_stats().active_providers, 1);
+
+        // 非治理权限不可暂停
+        assert_noop!(
+            DivinationMarket::gov_set_provider_suspended(RuntimeOrigin::signed(1), 10, true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(DivinationMarket::gov_set_provider_suspended(RuntimeOrigin::root(), 10, true));
+        assert_eq!(DivinationMarket::providers(10).unwrap().status, ProviderStatus::Banned);
+        assert_eq!(DivinationMarket::market_stats().active_providers, 0);
+
+        // 被暂停的大师不可自行恢复
+        assert_noop!(
+            DivinationMarket::resume_provider(RuntimeOrigin::signed(10)),
+            Error::<Test>::InvalidProviderStatus
+        );
+
+        assert_ok!(DivinationMarket::gov_set_provider_suspended(RuntimeOrigin::root(), 10, false));
+        assert_eq!(DivinationMarket::providers(10).unwrap().status, ProviderStatus::Active);
+        assert_eq!(DivinationMarket::market_stats().active_providers, 1);
+    });
+}
+
+/// 测试治理移除评价
+#[test]
+fn gov_remove_review_works() {
+    new_test_ext().execute_with(|| {
+        MockDivinationProvider::add_result(DivinationType::Meihua, 1, 1, RarityInput::common());
+        setup_provider_for_report(10);
+        assert_ok!(DivinationMarket::create_package(
+            RuntimeOrigin::signed(10),
+            DivinationType::Meihua,
+            ServiceType::TextReading,
+            b"Package".to_vec(),
+            b"Desc".to_vec(),
+            1000,
+            0,
+            0,
+            false,
+            0
+        ));
+        assert_ok!(DivinationMarket::create_order(
+            RuntimeOrigin::signed(1),
+            10,
+            DivinationType::Meihua,
+            1,
+            0,
+            b"Cid".to_vec(),
+            false
+        ));
+        assert_ok!(DivinationMarket::accept_order(RuntimeOrigin::signed(10), 0));
+        assert_ok!(DivinationMarket::submit_interpretation(
+            RuntimeOrigin::signed(10),
+            0,
+            b"Answer".to_vec()
+        ));
+        assert_ok!(DivinationMarket::submit_review(
+            RuntimeOrigin::signed(1),
+            0,
+            1,
+            1,
+            1,
+            1,
+            Some(b"Abusive content".to_vec()),
+            false
+        ));
+
+        assert_ok!(DivinationMarket::gov_remove_review(RuntimeOrigin::root(), 0));
+        assert!(DivinationMarket::reviews(0).is_none());
+        let provider = DivinationMarket::providers(10).unwrap();
+        assert_eq!(provider.total_ratings, 0);
+        assert_eq!(provider.rating_sum, 0);
+        assert_eq!(DivinationMarket::market_stats().total_reviews, 0);
+
+        // 订单保持已评价，不可重新评价
+        assert_eq!(DivinationMarket::orders(0).unwrap().status, OrderStatus::Reviewed);
+        assert_noop!(
+            DivinationMarket::gov_remove_review(RuntimeOrigin::root(), 0),
+            Error::<Test>::ReviewNotFound
+        );
+    });
+}
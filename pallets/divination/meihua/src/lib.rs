@@ -30,6 +30,7 @@ pub use pallet::*;
 pub mod algorithm;
 pub mod constants;
 pub mod interpretation;
+pub mod runtime_api;
 pub mod types;
 
 // 农历模块改为从 pallet-almanac 重新导出
@@ -1508,5 +1509,10 @@ pub mod pallet {
         pub fn get_ai_interpretation(hexagram_id: u64) -> Option<crate::interpretation::AiInterpretationResult> {
             AiInterpretations::<T>::get(hexagram_id)
         }
+
+        /// 获取卦象创建者（公共查询 API）
+        pub fn get_hexagram_owner(hexagram_id: u64) -> Option<T::AccountId> {
+            Hexagrams::<T>::get(hexagram_id).map(|d| d.ben_gua.diviner)
+        }
     }
 }
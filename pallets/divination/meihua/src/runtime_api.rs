@@ -0,0 +1,75 @@
+//! # 梅花易数 Runtime API
+//!
+//! 本模块定义了梅花易数的 Runtime API，供前端通过 RPC 免费调用。
+//!
+//! ## 功能说明
+//!
+//! - `get_hexagram_detail`: 获取卦象完整排盘（本卦、变卦、互卦、错卦、综卦、伏卦）
+//! - `calculate_hexagram_detail`: 按上下卦数与动爻临时排盘（不存储）
+//! - `get_interpretation_data`: 获取解卦核心数据（体用、应期等）
+//! - `get_ai_interpretation`: 获取 AI 解读结果
+//! - `hexagram_exists` / `get_hexagram_owner`: 存在性与创建者查询
+//! - `get_user_hexagrams` / `get_public_hexagrams`: 卦象列表
+//!
+//! ## 使用方式
+//!
+//! ```javascript
+//! const detail = await api.call.meihuaApi.getHexagramDetail(hexagramId);
+//! const data = await api.call.meihuaApi.getInterpretationData(hexagramId);
+//! ```
+
+use crate::interpretation::{AiInterpretationResult, InterpretationData};
+use crate::types::FullDivinationDetail;
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// 梅花易数 Runtime API
+    pub trait MeihuaApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// 获取卦象完整排盘详情
+        ///
+        /// # 参数
+        /// - `hexagram_id`: 卦象 ID
+        ///
+        /// # 返回
+        /// - `Some(FullDivinationDetail)`: 排盘详情
+        /// - `None`: 卦象不存在
+        fn get_hexagram_detail(hexagram_id: u64) -> Option<FullDivinationDetail>;
+
+        /// 临时排盘（不存储）
+        ///
+        /// # 参数
+        /// - `shang_gua_num`: 上卦数（1-8）
+        /// - `xia_gua_num`: 下卦数（1-8）
+        /// - `dong_yao`: 动爻（1-6）
+        fn calculate_hexagram_detail(
+            shang_gua_num: u8,
+            xia_gua_num: u8,
+            dong_yao: u8,
+        ) -> FullDivinationDetail;
+
+        /// 获取解卦核心数据
+        ///
+        /// # 返回
+        /// - `None`: 卦象不存在或未生成解卦数据
+        fn get_interpretation_data(hexagram_id: u64) -> Option<InterpretationData>;
+
+        /// 获取 AI 解读结果
+        fn get_ai_interpretation(hexagram_id: u64) -> Option<AiInterpretationResult>;
+
+        /// 检查卦象是否存在
+        fn hexagram_exists(hexagram_id: u64) -> bool;
+
+        /// 获取卦象创建者
+        fn get_hexagram_owner(hexagram_id: u64) -> Option<AccountId>;
+
+        /// 获取用户的卦象 ID 列表
+        fn get_user_hexagrams(account: AccountId) -> Vec<u64>;
+
+        /// 获取公开卦象 ID 列表
+        fn get_public_hexagrams() -> Vec<u64>;
+    }
+}
@@ -57,6 +57,7 @@ pub mod pallet {
     };
     use frame_system::pallet_prelude::*;
     use pallet_divination_common::{DivinationProvider, DivinationType, Rarity};
+    use pallet_membership::MembershipBenefits;
     use sp_runtime::traits::{Saturating, Zero};
     use sp_std::prelude::*;
 
@@ -114,6 +115,9 @@ pub mod pallet {
 
         /// 治理权限来源
         type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// 🆕 会员权益（卖家平台手续费折扣）
+        type MembershipBenefits: pallet_membership::MembershipBenefits<Self::AccountId>;
     }
 
     /// 货币余额类型别名
@@ -261,6 +265,13 @@ pub mod pallet {
     pub type TypeStatistics<T: Config> =
         StorageMap<_, Blake2_128Concat, DivinationType, TypeStats, ValueQuery>;
 
+    /// 治理冻结的 NFT（nft_id => 冻结区块）
+    ///
+    /// 冻结期间 NFT 状态为 Locked，不可转移、挂单、销毁或接受出价
+    #[pallet::storage]
+    pub type GovFrozenNfts<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, BlockNumberFor<T>>;
+
     // ==================== 事件 ====================
 
     #[pallet::event]
@@ -337,6 +348,15 @@ pub mod pallet {
 
         /// NFT 已从收藏集移除
         NftRemovedFromCollection { nft_id: u64, collection_id: u32 },
+
+        /// NFT 挂单已被治理下架
+        NftDelistedByGovernance { nft_id: u64 },
+
+        /// NFT 已被治理冻结
+        NftFrozen { nft_id: u64 },
+
+        /// NFT 已被治理解冻
+        NftUnfrozen { nft_id: u64 },
     }
 
     // ==================== 错误 ====================
@@ -397,6 +417,10 @@ pub mod pallet {
         CannotBuySelfNft,
         /// 稀有度供应已达上限
         RaritySupplyExceeded,
+        /// NFT 已被冻结
+        NftAlreadyFrozen,
+        /// NFT 未被冻结
+        NftNotFrozen,
     }
 
     // ==================== 可调用函数 ====================
@@ -733,8 +757,7 @@ pub mod pallet {
             let nft = Nfts::<T>::get(nft_id).ok_or(Error::<T>::NftNotFound)?;
 
             // 计算费用分配
-            let platform_fee =
-                listing.price.saturating_mul(T::PlatformFeeRate::get().into()) / 10000u32.into();
+            let platform_fee = Self::platform_fee_for(&listing.seller, listing.price);
             let royalty = if nft.creator != listing.seller {
                 listing.price.saturating_mul(nft.royalty_rate.into()) / 10000u32.into()
             } else {
@@ -916,8 +939,7 @@ pub mod pallet {
             T::NftCurrency::unreserve(&offer.bidder, offer.amount);
 
             // 计算费用分配
-            let platform_fee =
-                offer.amount.saturating_mul(T::PlatformFeeRate::get().into()) / 10000u32.into();
+            let platform_fee = Self::platform_fee_for(&who, offer.amount);
             let royalty = if nft.creator != who {
                 offer.amount.saturating_mul(nft.royalty_rate.into()) / 10000u32.into()
             } else {
@@ -1150,11 +1172,101 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// 治理下架 NFT 挂单（治理权限）
+        ///
+        /// 由内容治理申诉执行路由调用，NFT 恢复为正常持有状态。
+        #[pallet::call_index(12)]
+        #[pallet::weight(Weight::from_parts(30_000_000, 0))]
+        pub fn gov_delist_nft(origin: OriginFor<T>, nft_id: u64) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(Listings::<T>::contains_key(nft_id), Error::<T>::ListingNotFound);
+            Self::remove_listing(nft_id);
+            Nfts::<T>::mutate(nft_id, |maybe_nft| {
+                if let Some(nft) = maybe_nft {
+                    nft.status = NftStatus::Normal;
+                }
+            });
+
+            Self::deposit_event(Event::NftDelistedByGovernance { nft_id });
+
+            Ok(())
+        }
+
+        /// 治理冻结/解冻 NFT（治理权限）
+        ///
+        /// 冻结时自动下架挂单并锁定 NFT；解冻仅适用于治理冻结的 NFT。
+        ///
+        /// # 参数
+        /// - `nft_id`: NFT ID
+        /// - `frozen`: true=冻结，false=解冻
+        #[pallet::call_index(13)]
+        #[pallet::weight(Weight::from_parts(40_000_000, 0))]
+        pub fn gov_set_nft_frozen(
+            origin: OriginFor<T>,
+            nft_id: u64,
+            frozen: bool,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            Nfts::<T>::try_mutate(nft_id, |maybe_nft| {
+                let nft = maybe_nft.as_mut().ok_or(Error::<T>::NftNotFound)?;
+                if frozen {
+                    ensure!(
+                        !GovFrozenNfts::<T>::contains_key(nft_id),
+                        Error::<T>::NftAlreadyFrozen
+                    );
+                    ensure!(
+                        nft.status == NftStatus::Normal || nft.status == NftStatus::Listed,
+                        Error::<T>::InvalidNftStatus
+                    );
+                    if nft.status == NftStatus::Listed {
+                        Self::remove_listing(nft_id);
+                    }
+                    nft.status = NftStatus::Locked;
+                    GovFrozenNfts::<T>::insert(nft_id, <frame_system::Pallet<T>>::block_number());
+                } else {
+                    ensure!(GovFrozenNfts::<T>::contains_key(nft_id), Error::<T>::NftNotFrozen);
+                    nft.status = NftStatus::Normal;
+                    GovFrozenNfts::<T>::remove(nft_id);
+                }
+                Ok::<_, DispatchError>(())
+            })?;
+
+            if frozen {
+                Self::deposit_event(Event::NftFrozen { nft_id });
+            } else {
+                Self::deposit_event(Event::NftUnfrozen { nft_id });
+            }
+
+            Ok(())
+        }
     }
 
     // ==================== 辅助函数 ====================
 
     impl<T: Config> Pallet<T> {
+        /// 移除挂单并更新统计（不修改 NFT 状态）
+        fn remove_listing(nft_id: u64) {
+            if Listings::<T>::take(nft_id).is_some() {
+                NftStatistics::<T>::mutate(|s| {
+                    s.active_listings = s.active_listings.saturating_sub(1);
+                });
+            }
+        }
+
+        /// 🆕 计算平台手续费（按卖家会员折扣减免）
+        ///
+        /// 手续费从卖家收入中扣除，折扣按卖家会员等级计算。
+        fn platform_fee_for(seller: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+            let discount = T::MembershipBenefits::nft_fee_discount_percent(seller).min(100);
+            let rate = u32::from(T::PlatformFeeRate::get())
+                .saturating_mul(100u32.saturating_sub(discount.into()))
+                / 100;
+            amount.saturating_mul(rate.into()) / 10000u32.into()
+        }
+
         /// 获取稀有度对应的最大供应量
         fn max_supply_for_rarity(rarity: &Rarity) -> Option<u64> {
             match rarity {
@@ -126,6 +126,31 @@ impl pallet_divination_nft::Config for Test {
     type OfferValidityPeriod = ConstU64<100>;
     type PlatformAccount = PlatformAccount;
     type GovernanceOrigin = frame_system::EnsureRoot<u64>;
+    type MembershipBenefits = MockMembershipBenefits;
+}
+
+/// 🆕 模拟会员权益：账户 3 为会员，平台手续费 5 折
+pub struct MockMembershipBenefits;
+impl pallet_membership::MembershipBenefits<u64> for MockMembershipBenefits {
+    fn pin_discount_percent(_who: &u64) -> u8 {
+        0
+    }
+
+    fn nft_fee_discount_percent(who: &u64) -> u8 {
+        if *who == 3 {
+            50
+        } else {
+            0
+        }
+    }
+
+    fn anti_spam_bonus_percent(_who: &u64) -> u16 {
+        0
+    }
+
+    fn try_use_free_ai_interpretation(_who: &u64) -> bool {
+        false
+    }
 }
 
 // Build genesis storage according to the mock runtime.
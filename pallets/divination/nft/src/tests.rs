@@ -342,6 +342,47 @@ fn royalty_paid_on_resale() {
     });
 }
 
+#[test]
+fn member_seller_gets_platform_fee_discount() {
+    new_test_ext().execute_with(|| {
+        MockDivinationProvider::add_result(DivinationType::Meihua, 1, 1, RarityInput::common());
+
+        // Alice 铸造（无版税）并转给会员 Charlie
+        assert_ok!(DivinationNftPallet::mint_nft(
+            RuntimeOrigin::signed(1),
+            DivinationType::Meihua,
+            1,
+            b"Test".to_vec(),
+            b"QmCid".to_vec(),
+            None,
+            None,
+            0,
+        ));
+        assert_ok!(DivinationNftPallet::transfer_nft(RuntimeOrigin::signed(1), 0, 3));
+
+        let charlie_balance_before = Balances::free_balance(3);
+        let platform_balance_before = Balances::free_balance(999);
+
+        // Charlie 挂单，Bob 购买
+        let price = 10_000_000_000_000u64;
+        assert_ok!(DivinationNftPallet::list_nft(
+            RuntimeOrigin::signed(3),
+            0,
+            price,
+            None,
+        ));
+        assert_ok!(DivinationNftPallet::buy_nft(RuntimeOrigin::signed(2), 0));
+
+        // 会员 5 折：平台费 1.25%
+        let platform_fee = price * 125 / 10000;
+        assert_eq!(Balances::free_balance(999) - platform_balance_before, platform_fee);
+        assert_eq!(
+            Balances::free_balance(3) - charlie_balance_before,
+            price - platform_fee
+        );
+    });
+}
+
 // ==================== 出价测试 ====================
 
 #[test]
@@ -580,3 +621,91 @@ fn multiple_divination_types_work() {
         assert_eq!(DivinationNftPallet::type_stats(DivinationType::Liuyao).minted_count, 1);
     });
 }
+
+// ==================== 治理执行测试 ====================
+
+#[test]
+fn gov_freeze_delists_and_locks_nft() {
+    new_test_ext().execute_with(|| {
+        MockDivinationProvider::add_result(DivinationType::Meihua, 1, 1, RarityInput::common());
+        assert_ok!(DivinationNftPallet::mint_nft(
+            RuntimeOrigin::signed(1),
+            DivinationType::Meihua,
+            1,
+            b"Test".to_vec(),
+            b"QmCid".to_vec(),
+            None,
+            None,
+            500,
+        ));
+        assert_ok!(DivinationNftPallet::list_nft(
+            RuntimeOrigin::signed(1),
+            0,
+            10_000_000_000_000u64,
+            None,
+        ));
+
+        // 非治理权限不可冻结
+        assert_noop!(
+            DivinationNftPallet::gov_set_nft_frozen(RuntimeOrigin::signed(2), 0, true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        // 冻结：挂单下架，NFT 锁定
+        assert_ok!(DivinationNftPallet::gov_set_nft_frozen(RuntimeOrigin::root(), 0, true));
+        assert_eq!(DivinationNftPallet::nfts(0).unwrap().status, NftStatus::Locked);
+        assert!(DivinationNftPallet::listings(0).is_none());
+        assert_eq!(DivinationNftPallet::nft_stats().active_listings, 0);
+        System::assert_has_event(Event::NftFrozen { nft_id: 0 }.into());
+
+        // 冻结期间不可转移或购买
+        assert_noop!(
+            DivinationNftPallet::transfer_nft(RuntimeOrigin::signed(1), 0, 2),
+            Error::<Test>::InvalidNftStatus
+        );
+        assert_noop!(
+            DivinationNftPallet::buy_nft(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::ListingNotFound
+        );
+
+        // 解冻后恢复正常
+        assert_ok!(DivinationNftPallet::gov_set_nft_frozen(RuntimeOrigin::root(), 0, false));
+        assert_eq!(DivinationNftPallet::nfts(0).unwrap().status, NftStatus::Normal);
+        assert_noop!(
+            DivinationNftPallet::gov_set_nft_frozen(RuntimeOrigin::root(), 0, false),
+            Error::<Test>::NftNotFrozen
+        );
+    });
+}
+
+#[test]
+fn gov_delist_nft_works() {
+    new_test_ext().execute_with(|| {
+        MockDivinationProvider::add_result(DivinationType::Meihua, 1, 1, RarityInput::common());
+        assert_ok!(DivinationNftPallet::mint_nft(
+            RuntimeOrigin::signed(1),
+            DivinationType::Meihua,
+            1,
+            b"Test".to_vec(),
+            b"QmCid".to_vec(),
+            None,
+            None,
+            500,
+        ));
+        assert_noop!(
+            DivinationNftPallet::gov_delist_nft(RuntimeOrigin::root(), 0),
+            Error::<Test>::ListingNotFound
+        );
+        assert_ok!(DivinationNftPallet::list_nft(
+            RuntimeOrigin::signed(1),
+            0,
+            10_000_000_000_000u64,
+            None,
+        ));
+
+        assert_ok!(DivinationNftPallet::gov_delist_nft(RuntimeOrigin::root(), 0));
+        assert_eq!(DivinationNftPallet::nfts(0).unwrap().status, NftStatus::Normal);
+        assert!(DivinationNftPallet::listings(0).is_none());
+        System::assert_has_event(Event::NftDelistedByGovernance { nft_id: 0 }.into());
+    });
+}
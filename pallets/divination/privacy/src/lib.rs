@@ -77,6 +77,7 @@
 
 pub use pallet::*;
 
+pub mod runtime_api;
 pub mod traits;
 pub mod types;
 
@@ -2027,5 +2028,154 @@ pub mod pallet {
         pub fn has_encrypted_record(divination_type: DivinationType, result_id: u64) -> bool {
             EncryptedRecords::<T>::contains_key(divination_type, result_id)
         }
+
+        // ==================== Runtime API 实现函数 ====================
+
+        /// 转换授权条目为 API 返回结构
+        fn authorization_info(
+            entry: AuthorizationEntry<T::AccountId, BlockNumberFor<T>, T::MaxEncryptedKeyLen>,
+        ) -> AuthorizationInfo {
+            use codec::Encode;
+            use sp_runtime::SaturatedConversion;
+
+            AuthorizationInfo {
+                grantee: entry.grantee.encode(),
+                role: entry.role as u8,
+                scope: entry.scope as u8,
+                granted_at: entry.granted_at.saturated_into::<u64>(),
+                expires_at: entry.expires_at.saturated_into::<u64>(),
+                bounty_id: entry.bounty_id,
+            }
+        }
+
+        /// 获取服务提供者信息
+        pub fn api_get_service_provider(account: T::AccountId) -> Option<ServiceProviderInfo> {
+            use sp_runtime::SaturatedConversion;
+
+            ServiceProviders::<T>::get(&account).map(|provider| ServiceProviderInfo {
+                provider_type: provider.provider_type as u8,
+                public_key: provider.public_key.to_vec(),
+                reputation: provider.reputation,
+                is_active: provider.is_active,
+                registered_at: provider.registered_at.saturated_into::<u64>(),
+                completed_services: provider.completed_services,
+            })
+        }
+
+        /// 按类型获取服务提供者列表（类型编码无效时返回空）
+        pub fn api_get_providers_by_type(provider_type: u8) -> Vec<T::AccountId> {
+            ServiceProviderType::from_u8(provider_type)
+                .map(|t| ProvidersByType::<T>::get(t).into_inner())
+                .unwrap_or_default()
+        }
+
+        /// 获取加密记录信息（不含密文）
+        pub fn api_get_encrypted_record_info(
+            divination_type: u8,
+            result_id: u64,
+        ) -> Option<EncryptedRecordInfo> {
+            use codec::Encode;
+            use sp_runtime::SaturatedConversion;
+
+            let dt = DivinationType::from_u8(divination_type)?;
+            let record = EncryptedRecords::<T>::get(dt, result_id)?;
+
+            Some(EncryptedRecordInfo {
+                divination_type,
+                result_id,
+                owner: record.owner.encode(),
+                privacy_mode: record.privacy_mode as u8,
+                authorization_count: RecordGrantees::<T>::decode_len(dt, result_id).unwrap_or(0)
+                    as u32,
+                created_at: record.created_at.saturated_into::<u64>(),
+            })
+        }
+
+        /// 获取用户的加密记录 ID（`divination_type` 为 None 时返回全部类型）
+        pub fn api_get_user_encrypted_records(
+            account: T::AccountId,
+            divination_type: Option<u8>,
+        ) -> Vec<u64> {
+            match divination_type {
+                Some(t) => DivinationType::from_u8(t)
+                    .map(|dt| UserEncryptedRecords::<T>::get(&account, dt).into_inner())
+                    .unwrap_or_default(),
+                None => UserEncryptedRecords::<T>::iter_prefix_values(&account)
+                    .flat_map(|ids| ids.into_inner())
+                    .collect(),
+            }
+        }
+
+        /// 检查账户对记录的访问权限
+        pub fn api_has_access(divination_type: u8, result_id: u64, account: T::AccountId) -> bool {
+            DivinationType::from_u8(divination_type)
+                .map(|dt| {
+                    <Self as DivinationPrivacy<T::AccountId, BlockNumberFor<T>>>::has_access(
+                        dt, result_id, &account,
+                    )
+                })
+                .unwrap_or(false)
+        }
+
+        /// 获取记录的全部授权
+        pub fn api_get_authorizations(divination_type: u8, result_id: u64) -> Vec<AuthorizationInfo> {
+            let Some(dt) = DivinationType::from_u8(divination_type) else {
+                return Vec::new();
+            };
+
+            RecordGrantees::<T>::get(dt, result_id)
+                .into_iter()
+                .filter_map(|grantee| Authorizations::<T>::get((dt, result_id, grantee)))
+                .map(Self::authorization_info)
+                .collect()
+        }
+
+        /// 获取服务提供者被授权的记录 `(占卜类型, 结果 ID)`
+        pub fn api_get_provider_grants(account: T::AccountId) -> Vec<(u8, u64)> {
+            ProviderGrants::<T>::get(&account)
+                .into_iter()
+                .map(|key| (key.divination_type as u8, key.result_id))
+                .collect()
+        }
+
+        /// 获取单条授权信息
+        pub fn api_get_authorization_info(
+            divination_type: u8,
+            result_id: u64,
+            grantee: T::AccountId,
+        ) -> Option<AuthorizationInfo> {
+            let dt = DivinationType::from_u8(divination_type)?;
+            Authorizations::<T>::get((dt, result_id, grantee)).map(Self::authorization_info)
+        }
+
+        /// 获取悬赏授权状态
+        pub fn api_get_bounty_authorization_status(bounty_id: u64) -> BountyAuthorizationStatus {
+            let Some(info) = BountyAuthInfos::<T>::get(bounty_id) else {
+                return BountyAuthorizationStatus::default();
+            };
+
+            BountyAuthorizationStatus {
+                requires_authorization: <Self as BountyPrivacy<T::AccountId, BlockNumberFor<T>>>::bounty_requires_authorization(
+                    info.divination_type,
+                    info.result_id,
+                ),
+                divination_type: Some(info.divination_type as u8),
+                result_id: Some(info.result_id),
+                authorized_count: BountyAuthorizations::<T>::decode_len(bounty_id).unwrap_or(0)
+                    as u32,
+                auto_authorize: info.auto_authorize,
+            }
+        }
+
+        /// 检查悬赏关联记录是否需要授权
+        pub fn api_bounty_requires_authorization(divination_type: u8, result_id: u64) -> bool {
+            DivinationType::from_u8(divination_type)
+                .map(|dt| {
+                    <Self as BountyPrivacy<T::AccountId, BlockNumberFor<T>>>::bounty_requires_authorization(
+                        dt, result_id,
+                    )
+                })
+                .unwrap_or(false)
+        }
     }
 }
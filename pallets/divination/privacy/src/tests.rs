@@ -1061,3 +1061,58 @@ mod trait_implementation {
         });
     }
 }
+
+// ============================================================================
+// Runtime API 实现函数测试
+// ============================================================================
+
+mod runtime_api {
+    use super::*;
+
+    #[test]
+    fn api_queries_use_encoded_divination_type() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Privacy::create_encrypted_record(
+                RuntimeOrigin::signed(ALICE),
+                DivinationType::Ziwei,
+                7,
+                PrivacyMode::Partial,
+                test_encrypted_data(256),
+                test_nonce(),
+                test_auth_tag(),
+                test_data_hash(),
+                test_encrypted_key(1),
+            ));
+            assert_ok!(Privacy::grant_access(
+                RuntimeOrigin::signed(ALICE),
+                DivinationType::Ziwei,
+                7,
+                MASTER,
+                test_encrypted_key(2),
+                AccessRole::Master,
+                AccessScope::CanComment,
+                0,
+            ));
+
+            let ziwei = DivinationType::Ziwei as u8;
+
+            let info = Privacy::api_get_encrypted_record_info(ziwei, 7).unwrap();
+            assert_eq!(info.privacy_mode, PrivacyMode::Partial as u8);
+            assert_eq!(info.authorization_count, 2);
+
+            let auths = Privacy::api_get_authorizations(ziwei, 7);
+            assert_eq!(auths.len(), 2);
+            assert!(auths.iter().any(|a| a.role == AccessRole::Master as u8));
+
+            assert!(Privacy::api_has_access(ziwei, 7, MASTER));
+            assert!(!Privacy::api_has_access(ziwei, 7, BOB));
+            assert_eq!(Privacy::api_get_user_encrypted_records(ALICE, None), vec![7]);
+            assert_eq!(Privacy::api_get_user_encrypted_records(ALICE, Some(ziwei)), vec![7]);
+
+            // 无效类型编码返回空结果
+            assert!(Privacy::api_get_encrypted_record_info(200, 7).is_none());
+            assert!(Privacy::api_get_authorizations(200, 7).is_empty());
+            assert!(!Privacy::api_has_access(200, 7, ALICE));
+        });
+    }
+}
@@ -149,6 +149,19 @@ pub enum ServiceProviderType {
     Research = 3,
 }
 
+impl ServiceProviderType {
+    /// 从编码值解析
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::MingLiShi),
+            1 => Some(Self::AiService),
+            2 => Some(Self::FamilyMember),
+            3 => Some(Self::Research),
+            _ => None,
+        }
+    }
+}
+
 // ============================================================================
 // 核心数据结构
 // ============================================================================
@@ -65,6 +65,7 @@ pub mod pallet {
     };
     use frame_system::pallet_prelude::*;
     use sp_runtime::traits::Zero;
+    use sp_std::vec::Vec;
 
     /// 余额类型别名
     pub type BalanceOf<T> =
@@ -1167,5 +1168,235 @@ pub mod pallet {
 
             Ok(chart_id)
         }
+
+        // ==================== Runtime API 实现函数 ====================
+
+        /// 获取完整解卦
+        ///
+        /// 实时计算，并补充链上时间戳与 AI 解读 CID
+        ///
+        /// # 返回
+        /// - `None`: 命盘不存在或缺少计算数据（Private 模式）
+        pub fn api_get_interpretation(
+            chart_id: u64,
+        ) -> Option<crate::interpretation::ZiweiInterpretation> {
+            use sp_runtime::SaturatedConversion;
+
+            let chart = Charts::<T>::get(chart_id)?;
+            let mut interpretation = crate::runtime_api::generate_interpretation(&chart)?;
+
+            interpretation.created_at = chart.timestamp.saturated_into::<u64>() / 1000;
+            interpretation.ai_interpretation_cid = chart
+                .ai_interpretation_cid
+                .and_then(|cid| BoundedVec::try_from(cid.into_inner()).ok());
+
+            Some(interpretation)
+        }
+
+        /// 获取宫位详细文本解读
+        pub fn api_get_palace_detail(
+            chart_id: u64,
+            gong_wei: GongWei,
+        ) -> Option<crate::runtime_api::PalaceDetailText> {
+            use crate::interpretation::{generate_palace_interpretation, get_keyword_by_gong_wei};
+
+            const LIU_JI: [LiuJiXing; 6] = [
+                LiuJiXing::WenChang,
+                LiuJiXing::WenQu,
+                LiuJiXing::ZuoFu,
+                LiuJiXing::YouBi,
+                LiuJiXing::TianKui,
+                LiuJiXing::TianYue,
+            ];
+            const LIU_SHA: [LiuShaXing; 6] = [
+                LiuShaXing::QingYang,
+                LiuShaXing::TuoLuo,
+                LiuShaXing::HuoXing,
+                LiuShaXing::LingXing,
+                LiuShaXing::DiKong,
+                LiuShaXing::DiJie,
+            ];
+
+            let chart = Charts::<T>::get(chart_id)?;
+            let palaces = chart.palaces?;
+            let palace = palaces.iter().find(|p| p.gong_wei == gong_wei)?;
+            let interpretation = generate_palace_interpretation(palace);
+
+            let to_bytes = |s: &str| s.as_bytes().to_vec();
+
+            Some(crate::runtime_api::PalaceDetailText {
+                palace_name: to_bytes(gong_wei.name()),
+                score: interpretation.score,
+                fortune_level: interpretation.fortune_level,
+                main_stars: palace.zhu_xing.iter().flatten().map(|s| to_bytes(s.name())).collect(),
+                liu_ji_names: LIU_JI
+                    .iter()
+                    .zip(palace.liu_ji.iter())
+                    .filter(|(_, present)| **present)
+                    .map(|(s, _)| to_bytes(s.name()))
+                    .collect(),
+                liu_sha_names: LIU_SHA
+                    .iter()
+                    .zip(palace.liu_sha.iter())
+                    .filter(|(_, present)| **present)
+                    .map(|(s, _)| to_bytes(s.name()))
+                    .collect(),
+                si_hua_desc: palace.si_hua.iter().flatten().map(|h| to_bytes(h.name())).collect(),
+                keywords: interpretation
+                    .keywords
+                    .iter()
+                    .map(|k| get_keyword_by_gong_wei(gong_wei, *k))
+                    .filter(|k| *k != "未知")
+                    .map(to_bytes)
+                    .collect(),
+                summary: to_bytes(interpretation.fortune_level.description()),
+            })
+        }
+
+        /// 检查是否有特定格局
+        ///
+        /// # 返回
+        /// - `Some((true, 强度))`: 存在该格局
+        /// - `Some((false, 0))`: 命盘可解读但无该格局
+        pub fn api_has_pattern(chart_id: u64, pattern_type: PatternType) -> Option<(bool, u8)> {
+            let interpretation = Self::api_get_interpretation(chart_id)?;
+            Some(
+                interpretation
+                    .patterns
+                    .iter()
+                    .find(|p| p.pattern_type == pattern_type)
+                    .map(|p| (true, p.strength))
+                    .unwrap_or((false, 0)),
+            )
+        }
+
+        /// 获取宫干四化及飞入宫位
+        pub fn api_get_palace_si_hua(
+            chart_id: u64,
+            palace_idx: u8,
+        ) -> Option<([SiHuaStar; 4], [u8; 4])> {
+            if palace_idx >= 12 {
+                return None;
+            }
+            let palaces = Charts::<T>::get(chart_id)?.palaces?;
+            let palace = &palaces[palace_idx as usize];
+
+            Some((
+                crate::interpretation::get_gong_gan_si_hua(palace),
+                crate::interpretation::calculate_fei_hua(&palaces, palace_idx),
+            ))
+        }
+
+        /// 获取指定大限解读（序号 1-12）
+        pub fn api_get_da_xian_interpretation(
+            chart_id: u64,
+            da_xian_index: u8,
+        ) -> Option<crate::interpretation::DaXianInterpretation> {
+            Self::api_get_interpretation(chart_id)?
+                .da_xian_interpretations
+                .into_iter()
+                .find(|d| d.index == da_xian_index)
+        }
+
+        /// 获取大限详细文本
+        pub fn api_get_da_xian_detail(
+            chart_id: u64,
+            da_xian_index: u8,
+        ) -> Option<crate::runtime_api::DaXianDetailText> {
+            let da_xian = Self::api_get_da_xian_interpretation(chart_id, da_xian_index)?;
+            let palaces = Charts::<T>::get(chart_id)?.palaces?;
+            let palace = palaces.get(da_xian.gong_index as usize)?;
+
+            let to_bytes = |s: &str| s.as_bytes().to_vec();
+
+            Some(crate::runtime_api::DaXianDetailText {
+                index: da_xian.index,
+                start_age: da_xian.start_age,
+                end_age: da_xian.end_age,
+                palace_name: to_bytes(palace.gong_wei.name()),
+                score: da_xian.score,
+                fortune_level: da_xian.fortune_level,
+                keywords: da_xian
+                    .keywords
+                    .iter()
+                    .map(|k| crate::interpretation::get_keyword_by_gong_wei(palace.gong_wei, *k))
+                    .filter(|k| *k != "未知")
+                    .map(to_bytes)
+                    .collect(),
+                summary: to_bytes(da_xian.fortune_level.description()),
+                si_hua_desc: palace.si_hua.iter().flatten().map(|h| to_bytes(h.name())).collect(),
+            })
+        }
+
+        /// 根据年龄获取当前大限
+        pub fn api_get_current_da_xian(
+            chart_id: u64,
+            age: u8,
+        ) -> Option<crate::interpretation::DaXianInterpretation> {
+            Self::api_get_interpretation(chart_id)?
+                .da_xian_interpretations
+                .into_iter()
+                .find(|d| d.start_age <= age && age <= d.end_age)
+        }
+
+        /// 获取流年运势
+        ///
+        /// 年份须不早于公元 4 年（干支推算基准）
+        pub fn api_get_liu_nian_fortune(
+            chart_id: u64,
+            year: u16,
+        ) -> Option<crate::runtime_api::LiuNianFortune> {
+            if year < 4 {
+                return None;
+            }
+            let chart = Charts::<T>::get(chart_id)?;
+            if !chart.can_interpret() {
+                return None;
+            }
+            Some(crate::runtime_api::calculate_liu_nian_fortune(&chart, year))
+        }
+
+        /// 获取多年运势趋势（最多 20 年）
+        pub fn api_get_fortune_trend(
+            chart_id: u64,
+            start_year: u16,
+            end_year: u16,
+        ) -> Vec<crate::runtime_api::LiuNianFortune> {
+            let end_year = end_year.min(start_year.saturating_add(19));
+            (start_year..=end_year)
+                .filter_map(|year| Self::api_get_liu_nian_fortune(chart_id, year))
+                .collect()
+        }
+
+        /// 批量获取解卦结果（最多 20 个）
+        pub fn api_get_interpretations_batch(
+            chart_ids: Vec<u64>,
+        ) -> Vec<Option<crate::interpretation::ZiweiInterpretation>> {
+            chart_ids
+                .into_iter()
+                .take(20)
+                .map(Self::api_get_interpretation)
+                .collect()
+        }
+
+        /// 获取命盘公开元数据
+        pub fn api_get_public_metadata(
+            chart_id: u64,
+        ) -> Option<crate::runtime_api::ZiweiPublicMetadata> {
+            use sp_runtime::SaturatedConversion;
+
+            let chart = Charts::<T>::get(chart_id)?;
+            Some(crate::runtime_api::ZiweiPublicMetadata {
+                id: chart.id,
+                privacy_mode: chart.privacy_mode,
+                created_at: chart.created_at.saturated_into::<u64>(),
+                has_encrypted_data: EncryptedDataStorage::<T>::contains_key(chart_id),
+                can_interpret: chart.can_interpret(),
+                wu_xing_ju: chart.wu_xing_ju,
+                ju_shu: chart.ju_shu,
+                ming_gong_pos: chart.ming_gong_pos,
+                has_ai_interpretation: chart.ai_interpretation_cid.is_some(),
+            })
+        }
     }
 }
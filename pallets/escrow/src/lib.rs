@@ -16,19 +16,27 @@ mod tests;
 pub mod pallet {
     use super::*;
     use alloc::vec::Vec;
+    use codec::DecodeWithMemTracking;
+    use core::marker::PhantomData;
     use frame_support::weights::Weight;
     use frame_support::{
         pallet_prelude::*,
-        traits::{Currency, EnsureOrigin, ExistenceRequirement},
+        traits::{fungibles, tokens::Preservation, Currency, EnsureOrigin, ExistenceRequirement},
         PalletId,
     };
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
+    use sp_runtime::traits::{AccountIdConversion, One, Saturating, Zero};
     use sp_runtime::DispatchError;
 
     pub type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+    /// 函数级中文注释：托管资产ID类型（由 Config::Assets 决定）。
+    pub type AssetIdOf<T> = <<T as Config>::Assets as EscrowAssets<
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+    >>::AssetId;
+
     /// 供其他 Pallet 内部调用的托管接口
     pub trait Escrow<AccountId, Balance> {
         /// 从付款人转入托管并记录
@@ -51,6 +59,81 @@ pub mod pallet {
         fn amount_of(id: u64) -> Balance;
     }
 
+    /// 函数级中文注释：多资产托管接口（供其他 Pallet 内部调用）。
+    /// - 同一 id 只能托管一种资产：原生 DUST（`lock_from`）或某个同质化资产；
+    /// - 托管后的释放/退款/分账/仲裁仍走 `Escrow` trait 与既有 extrinsic，按 id 记录的资产自动路由。
+    pub trait MultiAssetEscrow<AccountId, Balance, AssetId> {
+        /// 从付款人转入指定资产并记录（id 已托管其他资产时返回 Error::AssetMismatch）
+        fn lock_asset_from(payer: &AccountId, id: u64, asset: AssetId, amount: Balance)
+            -> DispatchResult;
+        /// 查询 id 托管的资产（None 表示原生 DUST 或无托管）
+        fn asset_of(id: u64) -> Option<AssetId>;
+    }
+
+    /// 函数级中文注释：里程碑托管接口（供其他 Pallet 内部调用，如占卜套餐分期、纪念订阅）。
+    pub trait MilestoneEscrow<AccountId, BlockNumber> {
+        /// 为已锁定的 id 设置里程碑计划（bps 合计必须为 10000）
+        fn set_milestones(
+            id: u64,
+            payee: &AccountId,
+            confirmer: &AccountId,
+            milestones: Vec<(u16, MilestoneTrigger<BlockNumber>)>,
+        ) -> DispatchResult;
+        /// 以业务方身份确认某个 OnConfirmation 里程碑并释放（调用方需自行校验确认人）
+        fn confirm_milestone(id: u64, index: u32) -> DispatchResult;
+    }
+
+    /// 函数级中文注释：同质化资产适配接口（pallet-assets 风格）。
+    /// - runtime 未接入资产模块时使用 `()`，所有资产操作返回错误；
+    /// - 接入 pallet-assets 等实现 `fungibles::Mutate` 的模块时使用 `FungiblesAdapter`。
+    pub trait EscrowAssets<AccountId, Balance> {
+        type AssetId: Parameter + Member + Copy + MaxEncodedLen;
+        /// 在两个账户间划转资产
+        fn transfer(
+            asset: Self::AssetId,
+            from: &AccountId,
+            to: &AccountId,
+            amount: Balance,
+            preservation: Preservation,
+        ) -> DispatchResult;
+    }
+
+    impl<AccountId, Balance> EscrowAssets<AccountId, Balance> for () {
+        type AssetId = u32;
+        fn transfer(
+            _asset: u32,
+            _from: &AccountId,
+            _to: &AccountId,
+            _amount: Balance,
+            _preservation: Preservation,
+        ) -> DispatchResult {
+            Err(DispatchError::Other("AssetsUnsupported"))
+        }
+    }
+
+    /// 函数级中文注释：`fungibles::Mutate` 适配器（如 `FungiblesAdapter<Assets>`）。
+    pub struct FungiblesAdapter<F>(PhantomData<F>);
+
+    impl<AccountId, F> EscrowAssets<AccountId, <F as fungibles::Inspect<AccountId>>::Balance>
+        for FungiblesAdapter<F>
+    where
+        AccountId: Eq,
+        F: fungibles::Mutate<AccountId>,
+        F::AssetId: Parameter + Member + Copy + MaxEncodedLen,
+    {
+        type AssetId = F::AssetId;
+        fn transfer(
+            asset: F::AssetId,
+            from: &AccountId,
+            to: &AccountId,
+            amount: <F as fungibles::Inspect<AccountId>>::Balance,
+            preservation: Preservation,
+        ) -> DispatchResult {
+            <F as fungibles::Mutate<AccountId>>::transfer(asset, from, to, amount, preservation)
+                .map(|_| ())
+        }
+    }
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         #[allow(deprecated)]
@@ -69,6 +152,11 @@ pub mod pallet {
         type MaxExpiringPerBlock: Get<u32>;
         /// 函数级中文注释：到期处理策略，由 runtime 注入；可按业务域决定 Release/Refund/Noop。
         type ExpiryPolicy: ExpiryPolicy<Self::AccountId, BlockNumberFor<Self>>;
+        /// 函数级中文注释：同质化资产适配（未接入资产模块时为 `()`）。
+        type Assets: EscrowAssets<Self::AccountId, BalanceOf<Self>>;
+        /// 函数级中文注释：单个托管最多里程碑数量。
+        #[pallet::constant]
+        type MaxMilestones: Get<u32>;
     }
 
     #[pallet::pallet]
@@ -110,6 +198,28 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// 函数级中文注释：托管资产：id -> AssetId（不存在表示原生 DUST）。
+    /// - 托管余额清零时随之删除。
+    #[pallet::storage]
+    pub type AssetOf<T: Config> = StorageMap<_, Blake2_128Concat, u64, AssetIdOf<T>, OptionQuery>;
+
+    /// 函数级中文注释：里程碑计划：id -> MilestoneSchedule。
+    /// - 托管余额清零（全部里程碑释放或仲裁/退款结清）时随之删除。
+    #[pallet::storage]
+    pub type Schedules<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, MilestoneSchedule<T>, OptionQuery>;
+
+    /// 函数级中文注释：按区块号索引到期里程碑：block_number -> Vec<(id, index)>。
+    /// - on_initialize 直接取当前块到期项，结构同 ExpiringAt。
+    #[pallet::storage]
+    pub type MilestonesDueAt<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<(u64, u32), T::MaxExpiringPerBlock>,
+        ValueQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -142,12 +252,45 @@ pub mod pallet {
         ExpiryScheduled { id: u64, at: BlockNumberFor<T> },
         /// 函数级中文注释：到期已处理（id, action: 0=Release,1=Refund,2=Noop）。
         Expired { id: u64, action: u8 },
+        /// 函数级中文注释：锁定同质化资产到托管账户。
+        AssetLocked {
+            id: u64,
+            asset: AssetIdOf<T>,
+            amount: BalanceOf<T>,
+        },
+        /// 函数级中文注释：已设置里程碑计划（id, 里程碑数量）。
+        MilestonesScheduled { id: u64, count: u32 },
+        /// 函数级中文注释：里程碑已释放。
+        MilestoneReleased {
+            id: u64,
+            index: u32,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
     pub enum Error<T> {
         Insufficient,
         NoLock,
+        /// id 已托管其他资产
+        AssetMismatch,
+        /// 里程碑计划非法（为空、bps 合计不为 10000、到期块已过等）
+        InvalidMilestones,
+        /// id 已存在里程碑计划
+        MilestonesExist,
+        /// 里程碑计划或里程碑不存在
+        MilestoneNotFound,
+        /// 里程碑已释放
+        MilestoneAlreadyReleased,
+        /// 里程碑尚未到期或触发方式不匹配
+        MilestoneNotDue,
+        /// 非里程碑确认人
+        NotConfirmer,
+        /// 托管处于争议中，仅允许仲裁决议处理
+        EscrowDisputed,
+        /// 当前块到期项已满
+        TooManyDue,
     }
 
     /// 函数级中文注释：到期处理策略接口（由 runtime 实现）。
@@ -165,10 +308,216 @@ pub mod pallet {
         Noop,
     }
 
+    /// 函数级中文注释：里程碑触发方式。
+    #[derive(
+        Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub enum MilestoneTrigger<BlockNumber> {
+        /// 到达指定区块后自动释放（on_initialize 处理，亦可任何人补触发）
+        AtBlock(BlockNumber),
+        /// 确认人（通常为付款方/客户）确认后释放
+        OnConfirmation,
+    }
+
+    /// 函数级中文注释：单个里程碑。
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Milestone<BlockNumber> {
+        /// 占计划总额的万分比
+        pub bps: u16,
+        /// 触发方式
+        pub trigger: MilestoneTrigger<BlockNumber>,
+        /// 是否已释放
+        pub released: bool,
+    }
+
+    /// 函数级中文注释：里程碑计划（类似归属/分期释放）。
+    /// - `total` 为设置计划时的托管余额，各里程碑按 bps 计算释放额；
+    /// - 最后一个未释放的里程碑释放全部剩余余额，避免取整残留；
+    /// - 仲裁决议只作用于剩余托管余额，即尚未释放的里程碑，已释放部分不受影响。
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct MilestoneSchedule<T: Config> {
+        /// 收款人（服务提供方）
+        pub payee: T::AccountId,
+        /// 确认人（付款方/客户）
+        pub confirmer: T::AccountId,
+        /// 计划总额
+        pub total: BalanceOf<T>,
+        /// 里程碑列表
+        pub milestones: BoundedVec<Milestone<BlockNumberFor<T>>, T::MaxMilestones>,
+    }
+
     impl<T: Config> Pallet<T> {
         fn account() -> T::AccountId {
             T::EscrowPalletId::get().into_account_truncating()
         }
+        /// 函数级中文注释：从托管账户按 id 记录的资产转出（原生 DUST 或同质化资产）。
+        fn pay_out(id: u64, to: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            let escrow = Self::account();
+            match AssetOf::<T>::get(id) {
+                None => {
+                    T::Currency::transfer(&escrow, to, amount, ExistenceRequirement::KeepAlive)
+                        .map_err(|_| Error::<T>::NoLock)?
+                }
+                Some(asset) => {
+                    if !amount.is_zero() {
+                        T::Assets::transfer(asset, &escrow, to, amount, Preservation::Expendable)
+                            .map_err(|_| Error::<T>::NoLock)?;
+                    }
+                }
+            }
+            Ok(())
+        }
+        /// 函数级中文注释：托管余额清零后清理资产记录与里程碑计划。
+        fn clear_drained(id: u64) {
+            AssetOf::<T>::remove(id);
+            if let Some(schedule) = Schedules::<T>::take(id) {
+                Self::clear_due_index(id, &schedule);
+            }
+        }
+        /// 函数级中文注释：移除某托管尚未释放的 AtBlock 里程碑在到期索引中的条目，
+        /// 避免同一 id 重新锁定后被旧索引误触发。
+        fn clear_due_index(
+            id: u64,
+            schedule: &MilestoneSchedule<T>,
+        ) {
+            for m in schedule.milestones.iter().filter(|m| !m.released) {
+                if let MilestoneTrigger::AtBlock(at) = m.trigger {
+                    MilestonesDueAt::<T>::mutate(at, |due| due.retain(|(i, _)| *i != id));
+                }
+            }
+        }
+        /// 函数级中文注释：按万分比计算金额：floor(amount * bps / 10000)。
+        fn bps_of(amount: BalanceOf<T>, bps: u16) -> BalanceOf<T> {
+            let amount_u128: u128 =
+                sp_runtime::traits::SaturatedConversion::saturated_into::<u128>(amount);
+            let part = amount_u128.saturating_mul(bps as u128) / 10_000u128;
+            sp_runtime::traits::SaturatedConversion::saturated_into::<BalanceOf<T>>(part)
+        }
+        /// 函数级中文注释：校验并写入里程碑计划，AtBlock 里程碑同时写入到期索引。
+        fn do_set_milestones(
+            id: u64,
+            payee: &T::AccountId,
+            confirmer: &T::AccountId,
+            milestones: Vec<(u16, MilestoneTrigger<BlockNumberFor<T>>)>,
+        ) -> DispatchResult {
+            let total = Locked::<T>::get(id);
+            ensure!(!total.is_zero(), Error::<T>::NoLock);
+            ensure!(LockStateOf::<T>::get(id) != 1u8, Error::<T>::EscrowDisputed);
+            ensure!(!Schedules::<T>::contains_key(id), Error::<T>::MilestonesExist);
+            ensure!(!milestones.is_empty(), Error::<T>::InvalidMilestones);
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut sum: u32 = 0;
+            for (bps, trigger) in milestones.iter() {
+                ensure!(*bps > 0, Error::<T>::InvalidMilestones);
+                sum = sum.saturating_add(*bps as u32);
+                if let MilestoneTrigger::AtBlock(at) = trigger {
+                    ensure!(*at > now, Error::<T>::InvalidMilestones);
+                }
+            }
+            ensure!(sum == 10_000, Error::<T>::InvalidMilestones);
+
+            let list: Vec<Milestone<BlockNumberFor<T>>> = milestones
+                .into_iter()
+                .map(|(bps, trigger)| Milestone { bps, trigger, released: false })
+                .collect();
+            let list: BoundedVec<_, T::MaxMilestones> =
+                list.try_into().map_err(|_| Error::<T>::InvalidMilestones)?;
+            for (index, m) in list.iter().enumerate() {
+                if let MilestoneTrigger::AtBlock(at) = m.trigger {
+                    MilestonesDueAt::<T>::try_mutate(at, |due| -> DispatchResult {
+                        due.try_push((id, index as u32)).map_err(|_| Error::<T>::TooManyDue)?;
+                        Ok(())
+                    })?;
+                }
+            }
+            let count = list.len() as u32;
+            Schedules::<T>::insert(
+                id,
+                MilestoneSchedule {
+                    payee: payee.clone(),
+                    confirmer: confirmer.clone(),
+                    total,
+                    milestones: list,
+                },
+            );
+            Self::deposit_event(Event::MilestonesScheduled { id, count });
+            Ok(())
+        }
+        /// 函数级中文注释：暂停期间把 `at` 块到期的里程碑顺延到下一块，返回顺延条数。
+        /// - 每块最多顺延到下一块剩余容量为止（不再向后探测），单块开销有界；
+        /// - 下一块放不下的条目留在 `at` 块索引，恢复后由 `release_due_milestone` 补触发。
+        fn requeue_due_milestones(
+            at: BlockNumberFor<T>,
+            items: BoundedVec<(u64, u32), T::MaxExpiringPerBlock>,
+        ) -> u32 {
+            let mut moved = 0u32;
+            let mut left: BoundedVec<(u64, u32), T::MaxExpiringPerBlock> = BoundedVec::default();
+            MilestonesDueAt::<T>::mutate(at.saturating_add(One::one()), |due| {
+                for item in items.into_iter() {
+                    if due.try_push(item).is_ok() {
+                        moved = moved.saturating_add(1);
+                    } else {
+                        // 子集必然放得下
+                        let _ = left.try_push(item);
+                    }
+                }
+            });
+            if !left.is_empty() {
+                MilestonesDueAt::<T>::insert(at, left);
+            }
+            moved
+        }
+
+        /// 函数级中文注释：释放单个里程碑。
+        /// - 争议状态下拒绝（剩余里程碑交由仲裁决议处理）；
+        /// - 最后一个未释放里程碑释放全部剩余余额；
+        /// - 余额清零后状态置为 Closed 并清理计划。
+        /// - `confirmed`：是否由确认人确认触发；否则仅允许释放已到期的 AtBlock 里程碑。
+        fn do_release_milestone(id: u64, index: u32, confirmed: bool) -> DispatchResult {
+            ensure!(LockStateOf::<T>::get(id) != 1u8, Error::<T>::EscrowDisputed);
+            let mut schedule = Schedules::<T>::get(id).ok_or(Error::<T>::MilestoneNotFound)?;
+            let milestone = schedule
+                .milestones
+                .get_mut(index as usize)
+                .ok_or(Error::<T>::MilestoneNotFound)?;
+            ensure!(!milestone.released, Error::<T>::MilestoneAlreadyReleased);
+            let now = frame_system::Pallet::<T>::block_number();
+            let triggered = match milestone.trigger {
+                MilestoneTrigger::AtBlock(at) => !confirmed && at <= now,
+                MilestoneTrigger::OnConfirmation => confirmed,
+            };
+            ensure!(triggered, Error::<T>::MilestoneNotDue);
+            milestone.released = true;
+            let bps = milestone.bps;
+
+            let cur = Locked::<T>::get(id);
+            let amount = if schedule.milestones.iter().all(|m| m.released) {
+                cur
+            } else {
+                Self::bps_of(schedule.total, bps).min(cur)
+            };
+            let payee = schedule.payee.clone();
+            Schedules::<T>::insert(id, schedule);
+
+            if !amount.is_zero() {
+                <Self as Escrow<T::AccountId, BalanceOf<T>>>::transfer_from_escrow(
+                    id, &payee, amount,
+                )?;
+            }
+            if Locked::<T>::get(id).is_zero() {
+                Self::clear_drained(id);
+                LockStateOf::<T>::insert(id, 3u8);
+            }
+            Self::deposit_event(Event::MilestoneReleased {
+                id,
+                index,
+                to: payee,
+                amount,
+            });
+            Ok(())
+        }
         /// 函数级中文注释：断言未暂停。
         #[inline]
         fn ensure_not_paused() -> DispatchResult {
@@ -193,6 +542,8 @@ pub mod pallet {
             // 函数级详细中文注释：从指定付款人向托管账户划转指定金额，并累加到 Locked[id]
             // - 余额校验：Currency::transfer 失败即返回 Error::Insufficient
             // - 原子性：任意一步失败会使外层事务回滚，避免脏写
+            // - 资产隔离：id 已托管同质化资产时拒绝混入原生 DUST
+            ensure!(!AssetOf::<T>::contains_key(id), Error::<T>::AssetMismatch);
             let escrow = Self::account();
             T::Currency::transfer(payer, &escrow, amount, ExistenceRequirement::KeepAlive)
                 .map_err(|_| Error::<T>::Insufficient)?;
@@ -213,11 +564,10 @@ pub mod pallet {
             ensure!(amount <= cur, Error::<T>::Insufficient);
             let new = cur.saturating_sub(amount);
             Locked::<T>::insert(id, new);
-            let escrow = Self::account();
-            T::Currency::transfer(&escrow, to, amount, ExistenceRequirement::KeepAlive)
-                .map_err(|_| Error::<T>::NoLock)?;
+            Self::pay_out(id, to, amount)?;
             if new.is_zero() {
                 Locked::<T>::remove(id);
+                Self::clear_drained(id);
             }
             Self::deposit_event(Event::Transfered {
                 id,
@@ -230,9 +580,8 @@ pub mod pallet {
         fn release_all(id: u64, to: &T::AccountId) -> DispatchResult {
             // 函数级详细中文注释：一次性释放全部托管余额给收款人
             let amount = Locked::<T>::take(id);
-            let escrow = Self::account();
-            T::Currency::transfer(&escrow, to, amount, ExistenceRequirement::KeepAlive)
-                .map_err(|_| Error::<T>::NoLock)?;
+            Self::pay_out(id, to, amount)?;
+            Self::clear_drained(id);
             Self::deposit_event(Event::Released {
                 id,
                 to: to.clone(),
@@ -243,9 +592,8 @@ pub mod pallet {
         fn refund_all(id: u64, to: &T::AccountId) -> DispatchResult {
             // 函数级详细中文注释：一次性退回全部托管余额给收款人
             let amount = Locked::<T>::take(id);
-            let escrow = Self::account();
-            T::Currency::transfer(&escrow, to, amount, ExistenceRequirement::KeepAlive)
-                .map_err(|_| Error::<T>::NoLock)?;
+            Self::pay_out(id, to, amount)?;
+            Self::clear_drained(id);
             Self::deposit_event(Event::Refunded {
                 id,
                 to: to.clone(),
@@ -258,6 +606,47 @@ pub mod pallet {
         }
     }
 
+    impl<T: Config> MultiAssetEscrow<T::AccountId, BalanceOf<T>, AssetIdOf<T>> for Pallet<T> {
+        fn lock_asset_from(
+            payer: &T::AccountId,
+            id: u64,
+            asset: AssetIdOf<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            // 函数级详细中文注释：从付款人向托管账户划转同质化资产，并累加到 Locked[id]
+            // - 资产隔离：id 已托管原生 DUST 或其他资产时拒绝（Error::AssetMismatch）
+            let cur = Locked::<T>::get(id);
+            match AssetOf::<T>::get(id) {
+                Some(existing) => ensure!(existing == asset, Error::<T>::AssetMismatch),
+                None => ensure!(cur.is_zero(), Error::<T>::AssetMismatch),
+            }
+            let escrow = Self::account();
+            T::Assets::transfer(asset, payer, &escrow, amount, Preservation::Preserve)
+                .map_err(|_| Error::<T>::Insufficient)?;
+            AssetOf::<T>::insert(id, asset);
+            Locked::<T>::insert(id, cur.saturating_add(amount));
+            Self::deposit_event(Event::AssetLocked { id, asset, amount });
+            Ok(())
+        }
+        fn asset_of(id: u64) -> Option<AssetIdOf<T>> {
+            AssetOf::<T>::get(id)
+        }
+    }
+
+    impl<T: Config> MilestoneEscrow<T::AccountId, BlockNumberFor<T>> for Pallet<T> {
+        fn set_milestones(
+            id: u64,
+            payee: &T::AccountId,
+            confirmer: &T::AccountId,
+            milestones: Vec<(u16, MilestoneTrigger<BlockNumberFor<T>>)>,
+        ) -> DispatchResult {
+            Self::do_set_milestones(id, payee, confirmer, milestones)
+        }
+        fn confirm_milestone(id: u64, index: u32) -> DispatchResult {
+            Self::do_release_milestone(id, index, true)
+        }
+    }
+
     // 说明：临时允许 warnings 以通过全局 -D warnings；后续将以 WeightInfo 基准权重替换常量权重
     #[allow(warnings)]
     #[allow(deprecated)]
@@ -356,9 +745,7 @@ pub mod pallet {
                 }
                 cur = cur.saturating_sub(amt);
                 Locked::<T>::insert(id, cur);
-                let escrow = Self::account();
-                T::Currency::transfer(&escrow, &to, amt, ExistenceRequirement::KeepAlive)
-                    .map_err(|_| Error::<T>::NoLock)?;
+                Self::pay_out(id, &to, amt)?;
                 Self::deposit_event(Event::Transfered {
                     id,
                     to: to.clone(),
@@ -368,6 +755,7 @@ pub mod pallet {
             }
             if cur.is_zero() {
                 Locked::<T>::remove(id);
+                Self::clear_drained(id);
                 LockStateOf::<T>::insert(id, 3u8);
             }
             Ok(())
@@ -387,6 +775,7 @@ pub mod pallet {
         }
 
         /// 函数级中文注释：仲裁决议-全额释放。
+        /// - 仅作用于剩余托管余额：已释放的里程碑不受影响，剩余里程碑随之结清。
         #[pallet::call_index(6)]
         #[pallet::weight(10_000)]
         pub fn apply_decision_release_all(
@@ -401,7 +790,7 @@ pub mod pallet {
             Ok(())
         }
 
-        /// 函数级中文注释：仲裁决议-全额退款。
+        /// 函数级中文注释：仲裁决议-全额退款（仅退还未释放里程碑对应的剩余余额）。
         #[pallet::call_index(7)]
         #[pallet::weight(10_000)]
         pub fn apply_decision_refund_all(
@@ -417,6 +806,7 @@ pub mod pallet {
         }
 
         /// 函数级中文注释：仲裁决议-按 bps 部分释放，其余退款给 refund_to。
+        /// - bps 以剩余托管余额（未释放里程碑）为基数计算。
         #[pallet::call_index(8)]
         #[pallet::weight(10_000)]
         pub fn apply_decision_partial_bps(
@@ -431,11 +821,7 @@ pub mod pallet {
             let cur = Locked::<T>::get(id);
             ensure!(!cur.is_zero(), Error::<T>::NoLock);
             // 计算按 bps 的释放金额：floor(cur * bps / 10000)
-            let cur_u128: u128 =
-                sp_runtime::traits::SaturatedConversion::saturated_into::<u128>(cur);
-            let rel_u128 = (cur_u128.saturating_mul(bps as u128)) / 10_000u128;
-            let rel_amt: BalanceOf<T> =
-                sp_runtime::traits::SaturatedConversion::saturated_into::<BalanceOf<T>>(rel_u128);
+            let rel_amt = Self::bps_of(cur, bps);
             if !rel_amt.is_zero() {
                 <Self as Escrow<T::AccountId, BalanceOf<T>>>::transfer_from_escrow(
                     id,
@@ -516,6 +902,66 @@ pub mod pallet {
             ExpiryOf::<T>::remove(id);
             Ok(())
         }
+
+        /// 函数级详细中文注释：锁定同质化资产（pallet-assets 风格）。
+        /// - 仅 AuthorizedOrigin | Root；暂停时拒绝；
+        /// - 同一 id 只能托管一种资产，后续释放/退款/仲裁按该资产出金。
+        #[pallet::call_index(12)]
+        #[pallet::weight(10_000)]
+        pub fn lock_asset(
+            origin: OriginFor<T>,
+            id: u64,
+            payer: T::AccountId,
+            asset: AssetIdOf<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            Self::ensure_auth(origin)?;
+            Self::ensure_not_paused()?;
+            if LockStateOf::<T>::get(id) != 0u8 {
+                LockStateOf::<T>::insert(id, 0u8);
+            }
+            <Self as MultiAssetEscrow<T::AccountId, BalanceOf<T>, AssetIdOf<T>>>::lock_asset_from(
+                &payer, id, asset, amount,
+            )
+        }
+
+        /// 函数级详细中文注释：为已锁定的托管设置里程碑计划。
+        /// - 仅 AuthorizedOrigin | Root；暂停时拒绝；争议中拒绝；
+        /// - 每项为 (bps, 触发方式)，bps 合计必须为 10000，AtBlock 必须晚于当前块。
+        #[pallet::call_index(13)]
+        #[pallet::weight(10_000)]
+        pub fn set_milestones(
+            origin: OriginFor<T>,
+            id: u64,
+            payee: T::AccountId,
+            confirmer: T::AccountId,
+            milestones: BoundedVec<(u16, MilestoneTrigger<BlockNumberFor<T>>), T::MaxMilestones>,
+        ) -> DispatchResult {
+            Self::ensure_auth(origin)?;
+            Self::ensure_not_paused()?;
+            Self::do_set_milestones(id, &payee, &confirmer, milestones.into_inner())
+        }
+
+        /// 函数级详细中文注释：确认人确认 OnConfirmation 里程碑并释放给收款人。
+        #[pallet::call_index(14)]
+        #[pallet::weight(10_000)]
+        pub fn confirm_milestone(origin: OriginFor<T>, id: u64, index: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let schedule = Schedules::<T>::get(id).ok_or(Error::<T>::MilestoneNotFound)?;
+            ensure!(schedule.confirmer == who, Error::<T>::NotConfirmer);
+            <Self as MilestoneEscrow<T::AccountId, BlockNumberFor<T>>>::confirm_milestone(id, index)
+        }
+
+        /// 函数级详细中文注释：补触发已到期的 AtBlock 里程碑（任何签名账户）。
+        /// - 用于 on_initialize 因暂停/到期项溢出等原因未处理的里程碑。
+        #[pallet::call_index(15)]
+        #[pallet::weight(10_000)]
+        pub fn release_due_milestone(origin: OriginFor<T>, id: u64, index: u32) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            Self::do_release_milestone(id, index, false)
+        }
     }
 
     #[pallet::hooks]
@@ -555,8 +1001,27 @@ pub mod pallet {
                 ExpiryOf::<T>::remove(id);
             }
             
+            // 到期里程碑：暂停时顺延到下一块（受下一块容量限制）；争议中跳过（交由仲裁或事后 release_due_milestone 补触发）
+            let due_milestones = MilestonesDueAt::<T>::take(n);
+            let due_total = due_milestones.len() as u32;
+            let mut requeue_weight = Weight::zero();
+            if Paused::<T>::get() {
+                let moved = Self::requeue_due_milestones(n, due_milestones) as u64;
+                // 下一块索引读写 + 剩余条目写回 + 每条顺延条目
+                requeue_weight = T::DbWeight::get()
+                    .reads_writes(1, 2)
+                    .saturating_add(T::DbWeight::get().reads_writes(moved, moved));
+            } else {
+                for (id, index) in due_milestones.into_iter() {
+                    let _ = frame_support::storage::with_storage_layer(|| {
+                        Self::do_release_milestone(id, index, false)
+                    });
+                }
+            }
+
             // 返回权重（每个到期项约 20_000 单位）
-            Weight::from_parts(20_000u64.saturating_mul(total as u64), 0)
+            Weight::from_parts(20_000u64.saturating_mul((total + due_total) as u64), 0)
+                .saturating_add(requeue_weight)
         }
     }
 }
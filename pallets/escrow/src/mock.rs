@@ -113,6 +113,47 @@ impl pallet_escrow::ExpiryPolicy<u64, u64> for MockExpiryPolicy {
     }
 }
 
+// Mock Assets - 简易同质化资产账本（(资产ID, 账户) -> 余额）
+parameter_types! {
+    pub static AssetBalances: std::collections::BTreeMap<(u32, u64), u64> =
+        std::collections::BTreeMap::new();
+}
+
+pub struct MockAssets;
+impl MockAssets {
+    /// 函数级详细中文注释：为账户铸造测试资产
+    pub fn mint(asset: u32, who: u64, amount: u64) {
+        AssetBalances::mutate(|b| *b.entry((asset, who)).or_default() += amount);
+    }
+
+    /// 函数级详细中文注释：查询账户资产余额
+    pub fn balance(asset: u32, who: u64) -> u64 {
+        AssetBalances::get().get(&(asset, who)).copied().unwrap_or(0)
+    }
+}
+
+impl pallet_escrow::EscrowAssets<u64, u64> for MockAssets {
+    type AssetId = u32;
+
+    fn transfer(
+        asset: u32,
+        from: &u64,
+        to: &u64,
+        amount: u64,
+        _preservation: frame_support::traits::tokens::Preservation,
+    ) -> sp_runtime::DispatchResult {
+        let from_balance = Self::balance(asset, *from);
+        if from_balance < amount {
+            return Err(sp_runtime::DispatchError::Other("InsufficientAsset"));
+        }
+        AssetBalances::mutate(|b| {
+            b.insert((asset, *from), from_balance - amount);
+            *b.entry((asset, *to)).or_default() += amount;
+        });
+        Ok(())
+    }
+}
+
 // Escrow配置
 impl pallet_escrow::pallet::Config for Test {
     type RuntimeEvent = RuntimeEvent;
@@ -122,6 +163,8 @@ impl pallet_escrow::pallet::Config for Test {
     type AdminOrigin = frame_system::EnsureRoot<u64>;
     type MaxExpiringPerBlock = MaxExpiringPerBlock;
     type ExpiryPolicy = MockExpiryPolicy;
+    type Assets = MockAssets;
+    type MaxMilestones = ConstU32<8>;
 }
 
 /// 函数级详细中文注释：构建测试环境
@@ -387,3 +387,343 @@ fn closed_state_prevents_operations() {
     });
 }
 
+
+// ==================== Part 4: 多资产与里程碑（9测试） ====================
+
+use crate::pallet::{MilestoneTrigger, MultiAssetEscrow};
+use frame_support::traits::Hooks;
+
+/// 函数级详细中文注释：构造里程碑参数
+fn milestones(
+    items: Vec<(u16, MilestoneTrigger<u64>)>,
+) -> frame_support::BoundedVec<(u16, MilestoneTrigger<u64>), frame_support::traits::ConstU32<8>> {
+    items.try_into().unwrap()
+}
+
+#[test]
+fn lock_asset_and_release_routes_to_asset() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        MockAssets::mint(7, 1, 5000);
+
+        assert_ok!(Escrow::lock_asset(RuntimeOrigin::root(), 100, 1, 7, 3000));
+        assert_eq!(MockAssets::balance(7, 1), 2000);
+        assert_eq!(Escrow::amount_of(100), 3000);
+        assert_eq!(Escrow::asset_of(100), Some(7));
+
+        // 释放走资产账本，原生余额不变
+        assert_ok!(Escrow::release(RuntimeOrigin::root(), 100, 2));
+        assert_eq!(MockAssets::balance(7, 2), 3000);
+        assert_eq!(Balances::free_balance(2), 100000);
+        assert_eq!(Escrow::asset_of(100), None);
+    });
+}
+
+#[test]
+fn lock_asset_rejects_mixing_assets() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        MockAssets::mint(7, 1, 5000);
+        MockAssets::mint(8, 1, 5000);
+
+        // 已托管原生 DUST 的 id 不能再托管资产
+        assert_ok!(Escrow::lock_from(&1, 100, 1000));
+        assert_noop!(
+            Escrow::lock_asset_from(&1, 100, 7, 1000),
+            crate::Error::<Test>::AssetMismatch
+        );
+
+        // 已托管资产的 id 不能混入其他资产或原生 DUST
+        assert_ok!(Escrow::lock_asset_from(&1, 200, 7, 1000));
+        assert_noop!(
+            Escrow::lock_asset_from(&1, 200, 8, 1000),
+            crate::Error::<Test>::AssetMismatch
+        );
+        assert_noop!(Escrow::lock_from(&1, 200, 1000), crate::Error::<Test>::AssetMismatch);
+    });
+}
+
+#[test]
+fn set_milestones_validates_bps() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Escrow::lock_from(&1, 100, 1000));
+
+        // 合计不足 10000
+        assert_noop!(
+            Escrow::set_milestones(
+                RuntimeOrigin::root(),
+                100,
+                2,
+                1,
+                milestones(vec![(5000, MilestoneTrigger::OnConfirmation)])
+            ),
+            crate::Error::<Test>::InvalidMilestones
+        );
+        // 到期块不晚于当前块
+        assert_noop!(
+            Escrow::set_milestones(
+                RuntimeOrigin::root(),
+                100,
+                2,
+                1,
+                milestones(vec![(10_000, MilestoneTrigger::AtBlock(1))])
+            ),
+            crate::Error::<Test>::InvalidMilestones
+        );
+        // 未锁定的 id
+        assert_noop!(
+            Escrow::set_milestones(
+                RuntimeOrigin::root(),
+                999,
+                2,
+                1,
+                milestones(vec![(10_000, MilestoneTrigger::OnConfirmation)])
+            ),
+            crate::Error::<Test>::NoLock
+        );
+    });
+}
+
+#[test]
+fn milestone_released_at_block() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Escrow::lock_from(&1, 100, 1000));
+        assert_ok!(Escrow::set_milestones(
+            RuntimeOrigin::root(),
+            100,
+            2,
+            1,
+            milestones(vec![
+                (3000, MilestoneTrigger::AtBlock(10)),
+                (7000, MilestoneTrigger::AtBlock(20)),
+            ])
+        ));
+
+        System::set_block_number(10);
+        Escrow::on_initialize(10);
+        assert_eq!(Balances::free_balance(2), 100300);
+        assert_eq!(Escrow::amount_of(100), 700);
+
+        // 未到期的里程碑不能补触发
+        assert_noop!(
+            Escrow::release_due_milestone(RuntimeOrigin::signed(3), 100, 1),
+            crate::Error::<Test>::MilestoneNotDue
+        );
+
+        System::set_block_number(20);
+        Escrow::on_initialize(20);
+        assert_eq!(Balances::free_balance(2), 101000);
+        assert_eq!(Escrow::amount_of(100), 0);
+        assert!(crate::Schedules::<Test>::get(100).is_none());
+        assert_eq!(crate::LockStateOf::<Test>::get(100), 3);
+    });
+}
+
+#[test]
+fn milestone_released_on_confirmation() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Escrow::lock_from(&1, 100, 1000));
+        assert_ok!(Escrow::set_milestones(
+            RuntimeOrigin::root(),
+            100,
+            2,
+            1,
+            milestones(vec![
+                (4000, MilestoneTrigger::OnConfirmation),
+                (6000, MilestoneTrigger::OnConfirmation),
+            ])
+        ));
+
+        // 仅确认人可确认
+        assert_noop!(
+            Escrow::confirm_milestone(RuntimeOrigin::signed(2), 100, 0),
+            crate::Error::<Test>::NotConfirmer
+        );
+        assert_ok!(Escrow::confirm_milestone(RuntimeOrigin::signed(1), 100, 0));
+        assert_eq!(Balances::free_balance(2), 100400);
+        System::assert_has_event(
+            crate::Event::MilestoneReleased { id: 100, index: 0, to: 2, amount: 400 }.into(),
+        );
+
+        // 不能重复释放
+        assert_noop!(
+            Escrow::confirm_milestone(RuntimeOrigin::signed(1), 100, 0),
+            crate::Error::<Test>::MilestoneAlreadyReleased
+        );
+    });
+}
+
+#[test]
+fn dispute_freezes_milestones() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Escrow::lock_from(&1, 100, 1000));
+        assert_ok!(Escrow::set_milestones(
+            RuntimeOrigin::root(),
+            100,
+            2,
+            1,
+            milestones(vec![
+                (5000, MilestoneTrigger::AtBlock(10)),
+                (5000, MilestoneTrigger::OnConfirmation),
+            ])
+        ));
+        assert_ok!(Escrow::dispute(RuntimeOrigin::root(), 100, 1));
+
+        System::set_block_number(10);
+        Escrow::on_initialize(10);
+        assert_eq!(Escrow::amount_of(100), 1000);
+        assert_noop!(
+            Escrow::confirm_milestone(RuntimeOrigin::signed(1), 100, 1),
+            crate::Error::<Test>::EscrowDisputed
+        );
+    });
+}
+
+#[test]
+fn arbitration_applies_to_remaining_milestones_only() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        MockAssets::mint(7, 1, 10_000);
+        assert_ok!(Escrow::lock_asset(RuntimeOrigin::root(), 100, 1, 7, 10_000));
+        assert_ok!(Escrow::set_milestones(
+            RuntimeOrigin::root(),
+            100,
+            2,
+            1,
+            milestones(vec![
+                (2000, MilestoneTrigger::OnConfirmation),
+                (8000, MilestoneTrigger::OnConfirmation),
+            ])
+        ));
+        assert_ok!(Escrow::confirm_milestone(RuntimeOrigin::signed(1), 100, 0));
+        assert_eq!(MockAssets::balance(7, 2), 2000);
+
+        // 争议后按 50% 裁决：仅针对剩余 8000
+        assert_ok!(Escrow::dispute(RuntimeOrigin::root(), 100, 1));
+        assert_ok!(Escrow::apply_decision_partial_bps(RuntimeOrigin::root(), 100, 2, 1, 5000));
+        assert_eq!(MockAssets::balance(7, 2), 6000);
+        assert_eq!(MockAssets::balance(7, 1), 4000);
+        assert_eq!(Escrow::amount_of(100), 0);
+        assert!(crate::Schedules::<Test>::get(100).is_none());
+        assert_eq!(Escrow::asset_of(100), None);
+    });
+}
+
+#[test]
+fn drained_escrow_clears_due_index() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Escrow::lock_from(&1, 100, 1000));
+        assert_ok!(Escrow::set_milestones(
+            RuntimeOrigin::root(),
+            100,
+            2,
+            1,
+            milestones(vec![
+                (5000, MilestoneTrigger::AtBlock(10)),
+                (5000, MilestoneTrigger::AtBlock(20)),
+            ])
+        ));
+
+        // 提前整笔退款：到期索引一并清理
+        assert_ok!(Escrow::refund_all(100, &1));
+        assert!(crate::MilestonesDueAt::<Test>::get(10).is_empty());
+        assert!(crate::MilestonesDueAt::<Test>::get(20).is_empty());
+
+        // 同一 id 重新锁定并改为确认释放，旧到期块不会触发释放
+        assert_ok!(Escrow::lock_from(&1, 100, 1000));
+        assert_ok!(Escrow::set_milestones(
+            RuntimeOrigin::root(),
+            100,
+            3,
+            1,
+            milestones(vec![(10_000, MilestoneTrigger::OnConfirmation)])
+        ));
+        System::set_block_number(10);
+        Escrow::on_initialize(10);
+        assert_eq!(Escrow::amount_of(100), 1000);
+        assert_eq!(Balances::free_balance(3), 100000);
+    });
+}
+
+#[test]
+fn due_release_rejects_confirmation_milestone() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Escrow::lock_from(&1, 100, 1000));
+        assert_ok!(Escrow::set_milestones(
+            RuntimeOrigin::root(),
+            100,
+            2,
+            1,
+            milestones(vec![
+                (5000, MilestoneTrigger::OnConfirmation),
+                (5000, MilestoneTrigger::AtBlock(10)),
+            ])
+        ));
+
+        // 残留索引指向 OnConfirmation 里程碑时，自动释放须拒绝
+        crate::MilestonesDueAt::<Test>::mutate(5, |due| due.try_push((100, 0)).unwrap());
+        System::set_block_number(5);
+        Escrow::on_initialize(5);
+        assert_eq!(Escrow::amount_of(100), 1000);
+
+        assert_noop!(
+            Escrow::release_due_milestone(RuntimeOrigin::signed(3), 100, 0),
+            crate::Error::<Test>::MilestoneNotDue
+        );
+        // AtBlock 里程碑不能被确认人提前确认
+        assert_noop!(
+            Escrow::confirm_milestone(RuntimeOrigin::signed(1), 100, 1),
+            crate::Error::<Test>::MilestoneNotDue
+        );
+    });
+}
+
+#[test]
+fn paused_due_milestone_is_requeued_and_released_after_unpause() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for id in [100, 101] {
+            assert_ok!(Escrow::lock_from(&1, id, 1000));
+            assert_ok!(Escrow::set_milestones(
+                RuntimeOrigin::root(),
+                id,
+                2,
+                1,
+                milestones(vec![(10_000, MilestoneTrigger::AtBlock(10))])
+            ));
+        }
+        // 下一块的到期队列只剩一个空位
+        crate::MilestonesDueAt::<Test>::mutate(11, |due| {
+            while due.len() + 1 < MaxExpiringPerBlock::get() as usize {
+                due.try_push((999, 0)).unwrap();
+            }
+        });
+        let payee_before = Balances::free_balance(2);
+
+        // 暂停期间到期：不释放；顺延到下一块，放不下的留在原区块
+        assert_ok!(Escrow::set_pause(RuntimeOrigin::root(), true));
+        System::set_block_number(10);
+        Escrow::on_initialize(10);
+        assert_eq!(Escrow::amount_of(100), 1000);
+        assert_eq!(Escrow::amount_of(101), 1000);
+        assert!(crate::MilestonesDueAt::<Test>::get(11).contains(&(100, 0)));
+        assert_eq!(crate::MilestonesDueAt::<Test>::get(10).to_vec(), vec![(101, 0)]);
+        assert!(crate::MilestonesDueAt::<Test>::get(12).is_empty());
+
+        // 恢复后顺延条目在下一块自动释放，留下的条目可补触发
+        assert_ok!(Escrow::set_pause(RuntimeOrigin::root(), false));
+        System::set_block_number(11);
+        Escrow::on_initialize(11);
+        assert_eq!(Escrow::amount_of(100), 0);
+        assert_eq!(Escrow::amount_of(101), 1000);
+        assert_ok!(Escrow::release_due_milestone(RuntimeOrigin::signed(3), 101, 0));
+        assert_eq!(Escrow::amount_of(101), 0);
+        assert_eq!(Balances::free_balance(2), payee_before + 2000);
+    });
+}
@@ -0,0 +1,89 @@
+// 函数级详细中文注释：案件角色授权模块
+//
+// 本模块为 pallet-evidence 提供“按案件决策主体”授权私密证据的支持
+//
+// 核心功能：
+// - 私密内容关联到仲裁争议或申诉案件
+// - 内容密钥由提交者重新包装到决策主体公钥（委员会公钥或门限公钥），只需提交一次
+// - 没有共享公钥的临时主体（如每案每轮抽选的陪审团）：提交者按成员各自登记的用户公钥逐一包装
+// - 决策主体当前成员自动获得读取权限，无需逐个 grant_access
+// - 案件结案后（CaseAccessProvider 返回 None）访问自动失效，任何人可清理授权记录
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::*, BoundedVec};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+use crate::pallet::Config;
+
+/// 函数级详细中文注释：证据关联的案件
+#[derive(
+	Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum EvidenceCase {
+	/// pallet-arbitration 争议（业务域, 对象ID）
+	Arbitration { domain: [u8; 8], id: u64 },
+	/// pallet-stardust-appeals 申诉ID
+	Appeal(u64),
+}
+
+/// 函数级详细中文注释：决策主体公钥（委员会公钥或门限公钥）
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct DecisionBodyKey<T: Config> {
+	/// 公钥数据
+	pub key_data: BoundedVec<u8, <T as Config>::MaxKeyLen>,
+	/// 密钥类型 (1=RSA-2048, 2=Ed25519, 3=ECDSA-P256, 4=门限公钥)
+	pub key_type: u8,
+	/// 版本号（每次轮换 +1，旧版本包装的密钥随之失效）
+	pub version: u32,
+	/// 注册时间（区块）
+	pub registered_at: BlockNumberFor<T>,
+}
+
+/// 函数级详细中文注释：案件授权记录
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct CaseKeyGrant<T: Config> {
+	/// 授权时的决策主体
+	pub body: [u8; 8],
+	/// 包装时使用的决策主体公钥版本
+	pub body_key_version: u32,
+	/// 用决策主体公钥包装后的内容密钥
+	pub wrapped_key: BoundedVec<u8, <T as Config>::MaxKeyLen>,
+	/// 授权人（内容创建者）
+	pub granted_by: <T as frame_system::Config>::AccountId,
+	/// 授权时间（区块）
+	pub granted_at: BlockNumberFor<T>,
+}
+
+/// 函数级详细中文注释：按成员包装的案件授权记录
+/// - 用于没有共享主体公钥的决策主体（每案每轮抽选的陪审团），每位成员用自己登记的用户公钥解密
+/// - 仅在授权时的决策主体仍为案件当前主体、且账户仍是其成员时有效
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct CaseMemberGrant<T: Config> {
+	/// 授权时的决策主体
+	pub body: [u8; 8],
+	/// 用该成员用户公钥包装后的内容密钥
+	pub wrapped_key: BoundedVec<u8, <T as Config>::MaxKeyLen>,
+	/// 授权人（内容创建者）
+	pub granted_by: <T as frame_system::Config>::AccountId,
+	/// 授权时间（区块）
+	pub granted_at: BlockNumberFor<T>,
+}
+
+/// 函数级详细中文注释：案件决策主体查询接口（由 runtime 桥接仲裁/申诉模块实现）
+pub trait CaseAccessProvider<AccountId> {
+	/// 返回案件当前决策主体标识及其成员；案件已结案或不存在时返回 None
+	fn decision_body(case: &EvidenceCase) -> Option<([u8; 8], alloc::vec::Vec<AccountId>)>;
+}
+
+/// 函数级详细中文注释：空实现（未接入案件模块时，所有案件视为已结案）
+impl<AccountId> CaseAccessProvider<AccountId> for () {
+	fn decision_body(_case: &EvidenceCase) -> Option<([u8; 8], alloc::vec::Vec<AccountId>)> {
+		None
+	}
+}
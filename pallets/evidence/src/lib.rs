@@ -16,16 +16,25 @@ use pallet_stardust_ipfs::IpfsPinner;
 // 函数级中文注释：权重模块导入，提供 WeightInfo 接口用于基于输入规模计算交易权重。
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
+pub mod case_access;
 pub mod private_content;
 pub mod weights;
 // L-4修复：CID加密验证模块
 pub mod cid_validator;
 
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
 #[allow(deprecated)]
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use crate::{
+        case_access::{
+            CaseAccessProvider, CaseKeyGrant, CaseMemberGrant, DecisionBodyKey, EvidenceCase,
+        },
         private_content::{EncryptedKeyBundles, UserPublicKey},
         weights::WeightInfo,
     };
@@ -215,6 +224,15 @@ pub mod pallet {
         /// 函数级中文注释：默认IPFS存储单价（每副本每月）
         #[pallet::constant]
         type DefaultStoragePrice: Get<Self::Balance>;
+
+        // ============= 案件角色授权配置 =============
+        /// 函数级中文注释：案件决策主体查询（由 runtime 桥接 pallet-arbitration / pallet-stardust-appeals）
+        type CaseAccess: CaseAccessProvider<Self::AccountId>;
+        /// 函数级中文注释：决策主体公钥管理起源（Root / 委员会）
+        type BodyKeyOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// 函数级中文注释：单个私密内容最多关联的案件数
+        #[pallet::constant]
+        type MaxCasesPerContent: Get<u32>;
     }
 
     #[pallet::pallet]
@@ -320,6 +338,48 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    // === 案件角色授权存储 ===
+
+    /// 决策主体公钥：body_id => DecisionBodyKey（委员会公钥或门限公钥）
+    #[pallet::storage]
+    pub type DecisionBodyKeys<T: Config> =
+        StorageMap<_, Blake2_128Concat, [u8; 8], DecisionBodyKey<T>, OptionQuery>;
+
+    /// 案件授权：(case, content_id) => CaseKeyGrant
+    #[pallet::storage]
+    pub type CaseGrants<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        EvidenceCase,
+        Blake2_128Concat,
+        u64,
+        CaseKeyGrant<T>,
+        OptionQuery,
+    >;
+
+    /// 按成员包装的案件授权：((case, content_id), member) => CaseMemberGrant
+    /// - 每次授权整体替换，条数不超过 MaxAuthorizedUsers
+    #[pallet::storage]
+    pub type CaseMemberGrants<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (EvidenceCase, u64),
+        Blake2_128Concat,
+        T::AccountId,
+        CaseMemberGrant<T>,
+        OptionQuery,
+    >;
+
+    /// 私密内容关联的案件（访问检查用反向索引）
+    #[pallet::storage]
+    pub type ContentCases<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        BoundedVec<EvidenceCase, T::MaxCasesPerContent>,
+        ValueQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -400,6 +460,37 @@ pub mod pallet {
             user: T::AccountId,
             key_type: u8,
         },
+
+        // === 案件角色授权事件 ===
+        /// 决策主体公钥已设置/轮换
+        DecisionBodyKeySet {
+            body: [u8; 8],
+            key_type: u8,
+            version: u32,
+        },
+
+        /// 私密内容已授权给案件决策主体
+        CaseAccessGranted {
+            content_id: u64,
+            case: EvidenceCase,
+            body: [u8; 8],
+            granted_by: T::AccountId,
+        },
+
+        /// 私密内容已按成员公钥授权给案件决策主体（如陪审团）
+        CaseAccessGrantedToMembers {
+            content_id: u64,
+            case: EvidenceCase,
+            body: [u8; 8],
+            members: u32,
+            granted_by: T::AccountId,
+        },
+
+        /// 案件已结案，授权已清理
+        CaseAccessExpired {
+            content_id: u64,
+            case: EvidenceCase,
+        },
     }
 
     #[pallet::error]
@@ -446,6 +537,20 @@ pub mod pallet {
         TooManyForSubject,
         /// 全局 CID 去重命中（Plain 模式）
         DuplicateCidGlobal,
+
+        // === 案件角色授权错误 ===
+        /// 案件不存在或已结案
+        CaseClosed,
+        /// 案件仍在进行中（不可清理授权）
+        CaseStillOpen,
+        /// 决策主体公钥未设置
+        BodyKeyNotSet,
+        /// 单个内容关联案件过多
+        TooManyCases,
+        /// 案件授权不存在
+        CaseGrantNotFound,
+        /// 账户不是案件当前决策主体成员
+        NotBodyMember,
     }
 
     #[allow(deprecated)]
@@ -581,57 +686,10 @@ pub mod pallet {
                 <T as Config>::Authorizer::is_authorized(ns, &who),
                 Error::<T>::NotAuthorized
             );
-            // 防重：承诺哈希唯一
-            ensure!(
-                CommitIndex::<T>::get(commit).is_none(),
-                Error::<T>::CommitAlreadyExists
-            );
-            // 限频与配额
+            // 限频
             let now = <frame_system::Pallet<T>>::block_number();
             Self::touch_window(&who, now)?;
-            let cnt = EvidenceCountByNs::<T>::get((ns, subject_id));
-            ensure!(
-                cnt < T::MaxPerSubjectNs::get(),
-                Error::<T>::TooManyForSubject
-            );
-            let id = NextEvidenceId::<T>::mutate(|n| {
-                let id = *n;
-                *n = n.saturating_add(1);
-                id
-            });
-            // TODO: Phase 1.5 完整实施 - 从memo或其他来源获取content_cid
-            // 临时方案：转换memo为content_cid类型
-            let temp_vec2: Vec<u8> = if let Some(ref m) = memo {
-                m.clone().into_inner()
-            } else {
-                b"QmPlaceholder2".to_vec()
-            };
-            let content_cid: BoundedVec<u8, T::MaxContentCidLen> = temp_vec2.try_into()
-                .map_err(|_| Error::<T>::InvalidCidFormat)?;
-            
-            let ev = Evidence {
-                id,
-                domain: 0,
-                target_id: subject_id,
-                owner: who.clone(),
-                content_cid,
-                content_type: ContentType::Mixed,
-                created_at: now,
-                is_encrypted: false,
-                encryption_scheme: None,
-                commit: Some(commit),
-                ns: Some(ns),
-            };
-            Evidences::<T>::insert(id, &ev);
-            EvidenceByNs::<T>::insert((ns, subject_id), id, ());
-            CommitIndex::<T>::insert(commit, id);
-            EvidenceCountByNs::<T>::insert((ns, subject_id), cnt.saturating_add(1));
-            Self::deposit_event(Event::EvidenceCommittedV2 {
-                id,
-                ns,
-                subject_id,
-                owner: who,
-            });
+            Self::insert_commit(who, ns, subject_id, commit, memo)?;
             Ok(())
         }
 
@@ -1037,11 +1095,262 @@ pub mod pallet {
             })
         }
 
+        /// 设置/轮换决策主体公钥（委员会公钥或门限公钥）
+        /// - 版本号自增；旧版本包装的案件密钥随之失效，需提交者重新授权
+        #[pallet::call_index(11)]
+        #[pallet::weight(10_000)] // TODO: 使用WeightInfo
+        pub fn set_decision_body_key(
+            origin: OriginFor<T>,
+            body: [u8; 8],
+            key_data: BoundedVec<u8, T::MaxKeyLen>,
+            key_type: u8,
+        ) -> DispatchResult {
+            T::BodyKeyOrigin::ensure_origin(origin)?;
+            ensure!((1..=4).contains(&key_type), Error::<T>::UnsupportedKeyType);
+
+            let version = DecisionBodyKeys::<T>::get(body)
+                .map(|k| k.version.saturating_add(1))
+                .unwrap_or(1);
+            DecisionBodyKeys::<T>::insert(
+                body,
+                DecisionBodyKey {
+                    key_data,
+                    key_type,
+                    version,
+                    registered_at: <frame_system::Pallet<T>>::block_number(),
+                },
+            );
+
+            Self::deposit_event(Event::DecisionBodyKeySet { body, key_type, version });
+            Ok(())
+        }
+
+        /// 将私密内容授权给案件当前决策主体
+        /// - 仅内容创建者；案件必须进行中
+        /// - wrapped_key 为用决策主体当前公钥包装的内容密钥（客户端完成）
+        /// - 再次调用可在决策主体变化（如陪审团升级到委员会）或公钥轮换后重新授权
+        #[pallet::call_index(12)]
+        #[pallet::weight(10_000)] // TODO: 使用WeightInfo
+        pub fn grant_case_access(
+            origin: OriginFor<T>,
+            content_id: u64,
+            case: EvidenceCase,
+            wrapped_key: BoundedVec<u8, T::MaxKeyLen>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let content =
+                PrivateContents::<T>::get(content_id).ok_or(Error::<T>::PrivateContentNotFound)?;
+            ensure!(content.creator == who, Error::<T>::AccessDenied);
+            ensure!(!wrapped_key.is_empty(), Error::<T>::InvalidEncryptedKey);
+
+            let (body, _members) =
+                T::CaseAccess::decision_body(&case).ok_or(Error::<T>::CaseClosed)?;
+            let body_key = DecisionBodyKeys::<T>::get(body).ok_or(Error::<T>::BodyKeyNotSet)?;
+
+            ContentCases::<T>::try_mutate(content_id, |cases| -> DispatchResult {
+                if !cases.contains(&case) {
+                    cases.try_push(case).map_err(|_| Error::<T>::TooManyCases)?;
+                }
+                Ok(())
+            })?;
+            CaseGrants::<T>::insert(
+                case,
+                content_id,
+                CaseKeyGrant {
+                    body,
+                    body_key_version: body_key.version,
+                    wrapped_key,
+                    granted_by: who.clone(),
+                    granted_at: <frame_system::Pallet<T>>::block_number(),
+                },
+            );
+
+            Self::deposit_event(Event::CaseAccessGranted {
+                content_id,
+                case,
+                body,
+                granted_by: who,
+            });
+            Ok(())
+        }
+
+        /// 清理已结案案件的授权记录（任何人可调用）
+        /// - 访问权限在结案时已自动失效，此处仅回收存储
+        #[pallet::call_index(13)]
+        #[pallet::weight(10_000)] // TODO: 使用WeightInfo
+        pub fn expire_case_access(
+            origin: OriginFor<T>,
+            content_id: u64,
+            case: EvidenceCase,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(
+                CaseGrants::<T>::contains_key(case, content_id) ||
+                    CaseMemberGrants::<T>::iter_prefix((case, content_id)).next().is_some(),
+                Error::<T>::CaseGrantNotFound
+            );
+            ensure!(
+                T::CaseAccess::decision_body(&case).is_none(),
+                Error::<T>::CaseStillOpen
+            );
+
+            CaseGrants::<T>::remove(case, content_id);
+            let _ = CaseMemberGrants::<T>::clear_prefix(
+                (case, content_id),
+                T::MaxAuthorizedUsers::get(),
+                None,
+            );
+            ContentCases::<T>::mutate(content_id, |cases| cases.retain(|c| c != &case));
+
+            Self::deposit_event(Event::CaseAccessExpired { content_id, case });
+            Ok(())
+        }
+
+        /// 将私密内容按成员公钥授权给案件当前决策主体
+        /// - 用于没有共享主体公钥的决策主体（每案每轮抽选的陪审团）
+        /// - keys 中每项为 (成员, 用该成员登记的用户公钥包装的内容密钥)，成员须属于当前主体且已登记公钥
+        /// - 整体替换该案件此前的成员授权；主体变化（如上诉换届）后需重新授权
+        #[pallet::call_index(14)]
+        #[pallet::weight(10_000)] // TODO: 使用WeightInfo
+        pub fn grant_case_access_to_members(
+            origin: OriginFor<T>,
+            content_id: u64,
+            case: EvidenceCase,
+            keys: EncryptedKeyBundles<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let content =
+                PrivateContents::<T>::get(content_id).ok_or(Error::<T>::PrivateContentNotFound)?;
+            ensure!(content.creator == who, Error::<T>::AccessDenied);
+            ensure!(!keys.is_empty(), Error::<T>::InvalidEncryptedKey);
+
+            let (body, members) =
+                T::CaseAccess::decision_body(&case).ok_or(Error::<T>::CaseClosed)?;
+            let mut seen = BTreeSet::new();
+            for (member, wrapped_key) in keys.iter() {
+                ensure!(members.contains(member), Error::<T>::NotBodyMember);
+                ensure!(seen.insert(member.clone()), Error::<T>::InvalidEncryptedKey);
+                ensure!(!wrapped_key.is_empty(), Error::<T>::InvalidEncryptedKey);
+                ensure!(
+                    UserPublicKeys::<T>::contains_key(member),
+                    Error::<T>::PublicKeyNotRegistered
+                );
+            }
+
+            ContentCases::<T>::try_mutate(content_id, |cases| -> DispatchResult {
+                if !cases.contains(&case) {
+                    cases.try_push(case).map_err(|_| Error::<T>::TooManyCases)?;
+                }
+                Ok(())
+            })?;
+            let _ = CaseMemberGrants::<T>::clear_prefix(
+                (case, content_id),
+                T::MaxAuthorizedUsers::get(),
+                None,
+            );
+            let now = <frame_system::Pallet<T>>::block_number();
+            for (member, wrapped_key) in keys.iter() {
+                CaseMemberGrants::<T>::insert(
+                    (case, content_id),
+                    member,
+                    CaseMemberGrant {
+                        body,
+                        wrapped_key: wrapped_key.clone(),
+                        granted_by: who.clone(),
+                        granted_at: now,
+                    },
+                );
+            }
+
+            Self::deposit_event(Event::CaseAccessGrantedToMembers {
+                content_id,
+                case,
+                body,
+                members: keys.len() as u32,
+                granted_by: who,
+            });
+            Ok(())
+        }
+
         // 只读接口应放置在 inherent impl 中，而非 extrinsics 块。
     }
 
     /// 承诺哈希和验证工具函数
     impl<T: Config> Pallet<T> {
+        /// 函数级中文注释：登记承诺哈希的内部实现（防重 + 主体配额 + 写入索引），返回证据ID。
+        /// - `commit_hash` 在授权与限频校验后调用；
+        /// - 其他 pallet 经 `commit_system_hash` 由系统代为登记。
+        fn insert_commit(
+            who: T::AccountId,
+            ns: [u8; 8],
+            subject_id: u64,
+            commit: H256,
+            memo: Option<BoundedVec<u8, T::MaxMemoLen>>,
+        ) -> Result<u64, DispatchError> {
+            // 防重：承诺哈希唯一
+            ensure!(
+                CommitIndex::<T>::get(commit).is_none(),
+                Error::<T>::CommitAlreadyExists
+            );
+            // 配额
+            let now = <frame_system::Pallet<T>>::block_number();
+            let cnt = EvidenceCountByNs::<T>::get((ns, subject_id));
+            ensure!(
+                cnt < T::MaxPerSubjectNs::get(),
+                Error::<T>::TooManyForSubject
+            );
+            let id = NextEvidenceId::<T>::mutate(|n| {
+                let id = *n;
+                *n = n.saturating_add(1);
+                id
+            });
+            // TODO: Phase 1.5 完整实施 - 从memo或其他来源获取content_cid
+            // 临时方案：转换memo为content_cid类型
+            let temp_vec2: Vec<u8> = if let Some(ref m) = memo {
+                m.clone().into_inner()
+            } else {
+                b"QmPlaceholder2".to_vec()
+            };
+            let content_cid: BoundedVec<u8, T::MaxContentCidLen> = temp_vec2.try_into()
+                .map_err(|_| Error::<T>::InvalidCidFormat)?;
+            
+            let ev = Evidence {
+                id,
+                domain: 0,
+                target_id: subject_id,
+                owner: who.clone(),
+                content_cid,
+                content_type: ContentType::Mixed,
+                created_at: now,
+                is_encrypted: false,
+                encryption_scheme: None,
+                commit: Some(commit),
+                ns: Some(ns),
+            };
+            Evidences::<T>::insert(id, &ev);
+            EvidenceByNs::<T>::insert((ns, subject_id), id, ());
+            CommitIndex::<T>::insert(commit, id);
+            EvidenceCountByNs::<T>::insert((ns, subject_id), cnt.saturating_add(1));
+            Self::deposit_event(Event::EvidenceCommittedV2 {
+                id,
+                ns,
+                subject_id,
+                owner: who,
+            });
+            Ok(id)
+        }
+
+        /// 🆕 函数级中文注释：系统代登记承诺哈希（供其他 pallet 调用，不做授权与限频校验）。
+        /// `owner` 记为证据所有者；防重与主体配额规则与 `commit_hash` 一致。
+        pub fn commit_system_hash(
+            owner: T::AccountId,
+            ns: [u8; 8],
+            subject_id: u64,
+            commit: H256,
+        ) -> Result<u64, DispatchError> {
+            Self::insert_commit(owner, ns, subject_id, commit, None)
+        }
+
         /// 计算 Evidence 承诺哈希
         ///
         /// 使用 stardust-media-common 的 HashHelper 计算标准格式的承诺哈希:
@@ -1150,6 +1459,13 @@ pub mod pallet {
                     return true;
                 }
 
+                // 检查案件决策主体授权（主体公钥或成员个人公钥）
+                if Self::case_grant_for_user(content_id, user).is_some() ||
+                    Self::case_member_key_for_user(content_id, user).is_some()
+                {
+                    return true;
+                }
+
                 // 检查访问策略
                 match &content.access_policy {
                     private_content::AccessPolicy::OwnerOnly => false,
@@ -1189,6 +1505,12 @@ pub mod pallet {
                         .iter()
                         .find(|(u, _)| u == user)
                         .map(|(_, key)| key.clone())
+                        .or_else(|| {
+                            // 决策主体成员：优先返回包装到本人公钥的密钥，其次为包装到主体公钥的案件密钥
+                            Self::case_member_key_for_user(content_id, user).or_else(|| {
+                                Self::case_grant_for_user(content_id, user).map(|g| g.wrapped_key)
+                            })
+                        })
                 } else {
                     None
                 }
@@ -1197,6 +1519,51 @@ pub mod pallet {
             }
         }
 
+        /// 查找用户可用的案件授权
+        /// - 案件进行中、用户属于当前决策主体
+        /// - 授权时的决策主体与公钥版本必须与当前一致（主体变化或公钥轮换后需重新授权）
+        pub fn case_grant_for_user(
+            content_id: u64,
+            user: &T::AccountId,
+        ) -> Option<CaseKeyGrant<T>> {
+            for case in ContentCases::<T>::get(content_id).iter() {
+                let Some((body, members)) = T::CaseAccess::decision_body(case) else {
+                    continue;
+                };
+                if !members.iter().any(|m| m == user) {
+                    continue;
+                }
+                let Some(grant) = CaseGrants::<T>::get(case, content_id) else {
+                    continue;
+                };
+                let current_version = DecisionBodyKeys::<T>::get(body).map(|k| k.version);
+                if grant.body == body && Some(grant.body_key_version) == current_version {
+                    return Some(grant);
+                }
+            }
+            None
+        }
+
+        /// 查找用户可用的按成员授权密钥
+        /// - 案件进行中、用户属于当前决策主体，且授权时的主体与当前一致
+        pub fn case_member_key_for_user(
+            content_id: u64,
+            user: &T::AccountId,
+        ) -> Option<BoundedVec<u8, T::MaxKeyLen>> {
+            for case in ContentCases::<T>::get(content_id).iter() {
+                let Some(grant) = CaseMemberGrants::<T>::get((*case, content_id), user) else {
+                    continue;
+                };
+                let Some((body, members)) = T::CaseAccess::decision_body(case) else {
+                    continue;
+                };
+                if grant.body == body && members.iter().any(|m| m == user) {
+                    return Some(grant.wrapped_key);
+                }
+            }
+            None
+        }
+
         /// 通过CID查找私密内容
         pub fn get_private_content_by_cid(
             cid: &BoundedVec<u8, T::MaxCidLen>,
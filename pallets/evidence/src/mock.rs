@@ -0,0 +1,113 @@
+use crate as pallet_evidence;
+use crate::case_access::{CaseAccessProvider, EvidenceCase};
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstBool, ConstU32, ConstU64},
+};
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Evidence: pallet_evidence,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+parameter_types! {
+    pub const EvidenceNsBytes: [u8; 8] = *b"evidence";
+    /// 案件当前决策主体（None 表示已结案）
+    pub static CaseBody: Option<([u8; 8], Vec<u64>)> = None;
+}
+
+/// 函数级中文注释：证据提交授权 mock（全部放行）
+pub struct AllowAll;
+impl pallet_evidence::EvidenceAuthorizer<u64> for AllowAll {
+    fn is_authorized(_ns: [u8; 8], _who: &u64) -> bool {
+        true
+    }
+}
+
+/// 函数级中文注释：家庭关系 mock（无家庭成员）
+pub struct NoFamily;
+impl pallet_evidence::FamilyRelationVerifier<u64> for NoFamily {
+    fn is_family_member(_user: &u64, _deceased_id: u64) -> bool {
+        false
+    }
+    fn is_authorized_for_deceased(_user: &u64, _deceased_id: u64) -> bool {
+        false
+    }
+}
+
+/// 函数级中文注释：IPFS pin mock（直接成功）
+pub struct MockIpfsPinner;
+impl pallet_stardust_ipfs::IpfsPinner<u64, u64> for MockIpfsPinner {
+    fn pin_cid_for_deceased(
+        _caller: u64,
+        _deceased_id: u64,
+        _cid: Vec<u8>,
+        _tier: Option<pallet_stardust_ipfs::PinTier>,
+    ) -> sp_runtime::DispatchResult {
+        Ok(())
+    }
+    fn pin_cid_for_pet(
+        _caller: u64,
+        _pet_id: u64,
+        _cid: Vec<u8>,
+        _tier: Option<pallet_stardust_ipfs::PinTier>,
+    ) -> sp_runtime::DispatchResult {
+        Ok(())
+    }
+}
+
+/// 函数级中文注释：案件决策主体 mock（所有案件共用 CaseBody）
+pub struct MockCaseAccess;
+impl CaseAccessProvider<u64> for MockCaseAccess {
+    fn decision_body(_case: &EvidenceCase) -> Option<([u8; 8], Vec<u64>)> {
+        CaseBody::get()
+    }
+}
+
+impl pallet_evidence::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxContentCidLen = ConstU32<64>;
+    type MaxSchemeLen = ConstU32<32>;
+    type MaxCidLen = ConstU32<64>;
+    type MaxImg = ConstU32<10>;
+    type MaxVid = ConstU32<5>;
+    type MaxDoc = ConstU32<5>;
+    type MaxMemoLen = ConstU32<64>;
+    type MaxAuthorizedUsers = ConstU32<8>;
+    type MaxKeyLen = ConstU32<512>;
+    type EvidenceNsBytes = EvidenceNsBytes;
+    type Authorizer = AllowAll;
+    type MaxPerSubjectTarget = ConstU32<100>;
+    type MaxPerSubjectNs = ConstU32<100>;
+    type WindowBlocks = ConstU64<100>;
+    type MaxPerWindow = ConstU32<10>;
+    type EnableGlobalCidDedup = ConstBool<false>;
+    type MaxListLen = ConstU32<100>;
+    type WeightInfo = ();
+    type FamilyVerifier = NoFamily;
+    type IpfsPinner = MockIpfsPinner;
+    type Balance = u64;
+    type DefaultStoragePrice = ConstU64<1>;
+    type CaseAccess = MockCaseAccess;
+    type BodyKeyOrigin = frame_system::EnsureRoot<u64>;
+    type MaxCasesPerContent = ConstU32<2>;
+}
+
+/// 函数级中文注释：构建测试环境（区块 1，案件默认已结案）
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    CaseBody::set(None);
+    let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,301 @@
+//! Tests for pallet-evidence（案件角色授权）
+
+use crate::{
+    case_access::EvidenceCase,
+    mock::*,
+    private_content::{AccessPolicy, PrivateContent},
+    CaseGrants, CaseMemberGrants, ContentCases, DecisionBodyKeys, Error, Event, PrivateContents,
+};
+use frame_support::{assert_noop, assert_ok, traits::ConstU32, BoundedVec};
+use sp_core::H256;
+use sp_runtime::DispatchError;
+
+const CREATOR: u64 = 1;
+const CASE: EvidenceCase = EvidenceCase::Arbitration { domain: *b"otc_ord_", id: 7 };
+/// 首轮陪审团主体（由 runtime 按 (domain, id, round) 派生）
+const JURY_ROUND_0: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+/// 上诉轮陪审团主体
+const JURY_ROUND_1: [u8; 8] = [8, 7, 6, 5, 4, 3, 2, 1];
+
+/// 函数级中文注释：直接写入一条私密内容（创建者 1，仅本人可读）
+fn insert_content(id: u64) {
+    let key: BoundedVec<u8, _> = vec![9u8; 32].try_into().unwrap();
+    PrivateContents::<Test>::insert(
+        id,
+        PrivateContent::<Test> {
+            id,
+            ns: *b"otc_ord_",
+            subject_id: 7,
+            cid: b"bafy-encrypted".to_vec().try_into().unwrap(),
+            content_hash: H256::repeat_byte(1),
+            encryption_method: 1,
+            creator: CREATOR,
+            access_policy: AccessPolicy::OwnerOnly,
+            encrypted_keys: vec![(CREATOR, key)].try_into().unwrap(),
+            created_at: 1,
+            updated_at: 1,
+        },
+    );
+}
+
+/// 函数级中文注释：构造密钥字节
+fn bytes(v: &[u8]) -> BoundedVec<u8, ConstU32<512>> {
+    v.to_vec().try_into().unwrap()
+}
+
+/// 函数级中文注释：登记决策主体公钥（门限公钥）
+fn set_body_key(body: [u8; 8]) {
+    assert_ok!(Evidence::set_decision_body_key(RuntimeOrigin::root(), body, bytes(&[4u8; 33]), 4));
+}
+
+#[test]
+fn set_decision_body_key_requires_origin_and_bumps_version() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Evidence::set_decision_body_key(RuntimeOrigin::signed(CREATOR), JURY_ROUND_0, bytes(&[4u8; 33]), 4),
+            DispatchError::BadOrigin
+        );
+        for key_type in [0u8, 5] {
+            assert_noop!(
+                Evidence::set_decision_body_key(RuntimeOrigin::root(), JURY_ROUND_0, bytes(&[4u8; 33]), key_type),
+                Error::<Test>::UnsupportedKeyType
+            );
+        }
+
+        set_body_key(JURY_ROUND_0);
+        assert_eq!(DecisionBodyKeys::<Test>::get(JURY_ROUND_0).unwrap().version, 1);
+        set_body_key(JURY_ROUND_0);
+        assert_eq!(DecisionBodyKeys::<Test>::get(JURY_ROUND_0).unwrap().version, 2);
+        System::assert_last_event(
+            Event::DecisionBodyKeySet { body: JURY_ROUND_0, key_type: 4, version: 2 }.into(),
+        );
+        // 各主体版本独立
+        assert!(DecisionBodyKeys::<Test>::get(JURY_ROUND_1).is_none());
+    });
+}
+
+#[test]
+fn grant_case_access_checks_creator_case_and_body_key() {
+    new_test_ext().execute_with(|| {
+        insert_content(0);
+        let wrapped = bytes(&[7u8; 64]);
+
+        assert_noop!(
+            Evidence::grant_case_access(RuntimeOrigin::signed(CREATOR), 9, CASE, wrapped.clone()),
+            Error::<Test>::PrivateContentNotFound
+        );
+        // 案件已结案或不存在
+        assert_noop!(
+            Evidence::grant_case_access(RuntimeOrigin::signed(CREATOR), 0, CASE, wrapped.clone()),
+            Error::<Test>::CaseClosed
+        );
+        CaseBody::set(Some((JURY_ROUND_0, vec![10, 11])));
+        assert_noop!(
+            Evidence::grant_case_access(RuntimeOrigin::signed(CREATOR), 0, CASE, wrapped.clone()),
+            Error::<Test>::BodyKeyNotSet
+        );
+        set_body_key(JURY_ROUND_0);
+        assert_noop!(
+            Evidence::grant_case_access(RuntimeOrigin::signed(2), 0, CASE, wrapped.clone()),
+            Error::<Test>::AccessDenied
+        );
+        assert_noop!(
+            Evidence::grant_case_access(RuntimeOrigin::signed(CREATOR), 0, CASE, bytes(&[])),
+            Error::<Test>::InvalidEncryptedKey
+        );
+
+        assert_ok!(Evidence::grant_case_access(RuntimeOrigin::signed(CREATOR), 0, CASE, wrapped.clone()));
+        System::assert_last_event(
+            Event::CaseAccessGranted { content_id: 0, case: CASE, body: JURY_ROUND_0, granted_by: CREATOR }
+                .into(),
+        );
+        assert_eq!(CaseGrants::<Test>::get(CASE, 0).unwrap().body_key_version, 1);
+
+        // 本轮陪审员可读取包装到主体公钥的内容密钥；非成员不可
+        assert!(Evidence::can_access_private_content(0, &10));
+        assert_eq!(Evidence::get_encrypted_key_for_user(0, &10), Some(wrapped));
+        assert!(!Evidence::can_access_private_content(0, &12));
+    });
+}
+
+#[test]
+fn case_grant_requires_current_body_and_key_version() {
+    new_test_ext().execute_with(|| {
+        insert_content(0);
+        CaseBody::set(Some((JURY_ROUND_0, vec![10, 11])));
+        set_body_key(JURY_ROUND_0);
+        assert_ok!(Evidence::grant_case_access(RuntimeOrigin::signed(CREATOR), 0, CASE, bytes(&[7u8; 64])));
+
+        // 上诉后主体变为新一轮陪审团：旧授权不覆盖新陪审员，续任陪审员也不能沿用
+        CaseBody::set(Some((JURY_ROUND_1, vec![10, 12])));
+        set_body_key(JURY_ROUND_1);
+        assert!(!Evidence::can_access_private_content(0, &10));
+        assert!(!Evidence::can_access_private_content(0, &12));
+
+        // 创建者重新包装到新主体公钥后恢复访问
+        assert_ok!(Evidence::grant_case_access(RuntimeOrigin::signed(CREATOR), 0, CASE, bytes(&[8u8; 64])));
+        assert!(Evidence::can_access_private_content(0, &12));
+
+        // 主体公钥轮换后旧版本包装的密钥失效
+        set_body_key(JURY_ROUND_1);
+        assert!(!Evidence::can_access_private_content(0, &12));
+    });
+}
+
+#[test]
+fn grant_case_access_limits_cases_per_content() {
+    new_test_ext().execute_with(|| {
+        insert_content(0);
+        CaseBody::set(Some((*b"content_", vec![20])));
+        set_body_key(*b"content_");
+        for case in [CASE, EvidenceCase::Appeal(1)] {
+            assert_ok!(Evidence::grant_case_access(RuntimeOrigin::signed(CREATOR), 0, case, bytes(&[7u8; 64])));
+        }
+        // 同一案件重复授权只更新记录
+        assert_ok!(Evidence::grant_case_access(RuntimeOrigin::signed(CREATOR), 0, CASE, bytes(&[8u8; 64])));
+        assert_eq!(ContentCases::<Test>::get(0).len(), 2);
+        assert_noop!(
+            Evidence::grant_case_access(RuntimeOrigin::signed(CREATOR), 0, EvidenceCase::Appeal(2), bytes(&[7u8; 64])),
+            Error::<Test>::TooManyCases
+        );
+    });
+}
+
+#[test]
+fn expire_case_access_only_after_case_closed() {
+    new_test_ext().execute_with(|| {
+        insert_content(0);
+        CaseBody::set(Some((JURY_ROUND_0, vec![10])));
+        set_body_key(JURY_ROUND_0);
+        assert_ok!(Evidence::grant_case_access(RuntimeOrigin::signed(CREATOR), 0, CASE, bytes(&[7u8; 64])));
+
+        assert_noop!(
+            Evidence::expire_case_access(RuntimeOrigin::signed(3), 0, CASE),
+            Error::<Test>::CaseStillOpen
+        );
+        assert_noop!(
+            Evidence::expire_case_access(RuntimeOrigin::signed(3), 0, EvidenceCase::Appeal(1)),
+            Error::<Test>::CaseGrantNotFound
+        );
+
+        // 结案后访问立即失效，任何人可回收存储
+        CaseBody::set(None);
+        assert!(!Evidence::can_access_private_content(0, &10));
+        assert_ok!(Evidence::expire_case_access(RuntimeOrigin::signed(3), 0, CASE));
+        assert!(CaseGrants::<Test>::get(CASE, 0).is_none());
+        assert!(ContentCases::<Test>::get(0).is_empty());
+        System::assert_last_event(Event::CaseAccessExpired { content_id: 0, case: CASE }.into());
+    });
+}
+
+/// 函数级中文注释：陪审员登记个人公钥（Ed25519）
+fn register_key(who: u64) {
+    assert_ok!(Evidence::register_public_key(RuntimeOrigin::signed(who), bytes(&[who as u8; 32]), 2));
+}
+
+/// 函数级中文注释：构造按成员包装的密钥包
+fn member_keys(keys: &[(u64, u8)]) -> BoundedVec<(u64, BoundedVec<u8, ConstU32<512>>), ConstU32<8>> {
+    keys.iter()
+        .map(|(who, b)| (*who, bytes(&[*b; 64])))
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn jury_members_decrypt_with_own_keys_without_body_key() {
+    new_test_ext().execute_with(|| {
+        insert_content(0);
+        CaseBody::set(Some((JURY_ROUND_0, vec![10, 11, 12])));
+        register_key(10);
+        register_key(11);
+
+        assert_noop!(
+            Evidence::grant_case_access_to_members(RuntimeOrigin::signed(2), 0, CASE, member_keys(&[(10, 1)])),
+            Error::<Test>::AccessDenied
+        );
+        // 非本轮陪审员
+        assert_noop!(
+            Evidence::grant_case_access_to_members(RuntimeOrigin::signed(CREATOR), 0, CASE, member_keys(&[(13, 1)])),
+            Error::<Test>::NotBodyMember
+        );
+        // 未登记个人公钥
+        assert_noop!(
+            Evidence::grant_case_access_to_members(RuntimeOrigin::signed(CREATOR), 0, CASE, member_keys(&[(12, 1)])),
+            Error::<Test>::PublicKeyNotRegistered
+        );
+        assert_noop!(
+            Evidence::grant_case_access_to_members(
+                RuntimeOrigin::signed(CREATOR),
+                0,
+                CASE,
+                member_keys(&[(10, 1), (10, 2)])
+            ),
+            Error::<Test>::InvalidEncryptedKey
+        );
+
+        // 陪审团主体无需登记共享公钥
+        assert_ok!(Evidence::grant_case_access_to_members(
+            RuntimeOrigin::signed(CREATOR),
+            0,
+            CASE,
+            member_keys(&[(10, 1), (11, 2)])
+        ));
+        System::assert_last_event(
+            Event::CaseAccessGrantedToMembers {
+                content_id: 0,
+                case: CASE,
+                body: JURY_ROUND_0,
+                members: 2,
+                granted_by: CREATOR,
+            }
+            .into(),
+        );
+        assert!(Evidence::can_access_private_content(0, &10));
+        assert_eq!(Evidence::get_encrypted_key_for_user(0, &10), Some(bytes(&[1u8; 64])));
+        assert_eq!(Evidence::get_encrypted_key_for_user(0, &11), Some(bytes(&[2u8; 64])));
+        assert!(!Evidence::can_access_private_content(0, &12));
+    });
+}
+
+#[test]
+fn member_grants_follow_jury_rounds_and_expire() {
+    new_test_ext().execute_with(|| {
+        insert_content(0);
+        CaseBody::set(Some((JURY_ROUND_0, vec![10, 11])));
+        register_key(10);
+        register_key(11);
+        register_key(12);
+        assert_ok!(Evidence::grant_case_access_to_members(
+            RuntimeOrigin::signed(CREATOR),
+            0,
+            CASE,
+            member_keys(&[(10, 1), (11, 2)])
+        ));
+
+        // 上诉换届：续任陪审员也不能沿用上一轮授权
+        CaseBody::set(Some((JURY_ROUND_1, vec![10, 12])));
+        assert!(!Evidence::can_access_private_content(0, &10));
+        assert!(!Evidence::can_access_private_content(0, &11));
+
+        // 重新授权整体替换上一轮记录
+        assert_ok!(Evidence::grant_case_access_to_members(
+            RuntimeOrigin::signed(CREATOR),
+            0,
+            CASE,
+            member_keys(&[(10, 3), (12, 4)])
+        ));
+        assert!(CaseMemberGrants::<Test>::get((CASE, 0), 11).is_none());
+        assert_eq!(Evidence::get_encrypted_key_for_user(0, &12), Some(bytes(&[4u8; 64])));
+
+        assert_noop!(
+            Evidence::expire_case_access(RuntimeOrigin::signed(3), 0, CASE),
+            Error::<Test>::CaseStillOpen
+        );
+        CaseBody::set(None);
+        assert!(!Evidence::can_access_private_content(0, &12));
+        assert_ok!(Evidence::expire_case_access(RuntimeOrigin::signed(3), 0, CASE));
+        assert!(CaseMemberGrants::<Test>::iter_prefix((CASE, 0)).next().is_none());
+        assert!(ContentCases::<Test>::get(0).is_empty());
+    });
+}
@@ -11,40 +11,61 @@ use frame_system::RawOrigin;
 benchmarks! {
     purge_weeks {
         let caller: T::AccountId = whitelisted_caller();
-        let grave_id: T::GraveId = codec::Decode::decode(&mut sp_core::blake2_256(b"g").as_slice()).ok().unwrap_or_else(|| unsafe { core::mem::zeroed() });
+        let target = MemorialTarget::Deceased(1);
         let before_week: u64 = 100;
         let limit: u32 = 50;
-    }: _(RawOrigin::Signed(caller.clone()), grave_id, caller.clone(), before_week, limit)
+    }: _(RawOrigin::Signed(caller.clone()), target, caller.clone(), before_week, limit)
 
     purge_weeks_by_range {
         let caller: T::AccountId = whitelisted_caller();
-        let grave_id: T::GraveId = codec::Decode::decode(&mut sp_core::blake2_256(b"g2").as_slice()).ok().unwrap_or_else(|| unsafe { core::mem::zeroed() });
+        let target = MemorialTarget::Deceased(1);
         let start_week: u64 = 10;
         let end_week: u64 = 200;
         let limit: u32 = 50;
-    }: _(RawOrigin::Signed(caller.clone()), grave_id, caller.clone(), start_week, end_week, limit)
+    }: _(RawOrigin::Signed(caller.clone()), target, caller.clone(), start_week, end_week, limit)
 
     record_from_hook_with_amount {
-        let grave_id: T::GraveId = codec::Decode::decode(&mut sp_core::blake2_256(b"g3").as_slice()).ok().unwrap_or_else(|| unsafe { core::mem::zeroed() });
+        let target = MemorialTarget::Deceased(1);
         let who: T::AccountId = whitelisted_caller();
         let amount: Option<T::Balance> = None;
         let memo: Option<alloc::vec::Vec<u8>> = None;
         let tx_key: Option<sp_core::H256> = None;
     }: {
-        Ledger::<T>::record_from_hook_with_amount(grave_id, who, 0, amount, memo, tx_key);
+        Ledger::<T>::record_from_hook_with_amount(target, who, 0, amount, memo, tx_key);
     }
 
     // 🗑️ 破坏式变更（方案A）：已移除 add_to_deceased_total 基准测试
-    // 原因：不再支持 Deceased 作为供奉目标
 
     mark_weekly_active {
-        let grave_id: T::GraveId = codec::Decode::decode(&mut sp_core::blake2_256(b"g4").as_slice()).ok().unwrap_or_else(|| unsafe { core::mem::zeroed() });
+        let target = MemorialTarget::Deceased(1);
         let who: T::AccountId = whitelisted_caller();
         let start_block: frame_system::pallet_prelude::BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
         let duration_weeks: Option<u32> = Some(10);
     }: {
-        Ledger::<T>::mark_weekly_active(grave_id, who, start_block, duration_weeks);
+        Ledger::<T>::mark_weekly_active(target, who, start_block, duration_weeks);
     }
+
+    record_offering {
+        let who: T::AccountId = whitelisted_caller();
+        // 预填满全站榜，覆盖"榜满比较末位"路径
+        for i in 0..T::MaxLeaderboardSize::get() {
+            Ledger::<T>::record_offering(i as u64, MemorialTarget::Pet(i as u64), who.clone(), 1, 0, 1u32.into(), None);
+        }
+        let offering_id = T::MaxLeaderboardSize::get() as u64;
+    }: {
+        Ledger::<T>::record_offering(offering_id, MemorialTarget::Deceased(1), who, 1, 0, 10u32.into(), Some(10));
+    }
+
+    prune_week {
+        let limit: u32 = 50;
+        let who: T::AccountId = whitelisted_caller();
+        for i in 0..limit {
+            Ledger::<T>::record_offering(i as u64, MemorialTarget::Deceased(i as u64), who.clone(), 1, 0, 1u32.into(), None);
+        }
+        let bpw: u32 = T::BlocksPerWeek::get();
+        let far: u64 = T::ReceiptRetentionWeeks::get().saturating_add(2);
+        frame_system::Pallet::<T>::set_block_number(((far as u32).saturating_mul(bpw)).into());
+    }: _(RawOrigin::Signed(who), 0, limit)
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -11,93 +11,190 @@ mod mock;
 mod tests;
 pub mod weights;
 
+// 🆕 存储迁移（v1：统计维度由墓位改为纪念目标）
+pub mod migrations;
+
+// 🆕 供奉回执 / 周 Merkle 树 / 排行榜类型
+pub mod receipts;
+pub use receipts::*;
+
+// 🆕 Runtime API：排行榜、回执与包含证明
+pub mod runtime_api;
+
 use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::*;
-use sp_core::H256;
+use sp_core::{hashing::blake2_256, H256};
 use sp_runtime::traits::{AtLeast32BitUnsigned, SaturatedConversion, Saturating};
 // 无需在此引入 Weight；权重接口通过 T::WeightInfo 使用
 
+/// 函数级中文注释：纪念目标 → 所在陵园（用于按陵园排行）
+/// - 由 Runtime 适配陵园/墓位数据；目标未落位时返回 None，仅计入全站与分类榜
+pub trait TargetParkProvider {
+    fn park_of(target: &MemorialTarget) -> Option<u64>;
+}
+
+impl TargetParkProvider for () {
+    fn park_of(_target: &MemorialTarget) -> Option<u64> {
+        None
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use crate::weights::WeightInfo;
 
+    /// 回执类型别名
+    pub type ReceiptOf<T> = Receipt<
+        <T as frame_system::Config>::AccountId,
+        <T as Config>::Balance,
+        BlockNumberFor<T>,
+    >;
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// 事件类型
         #[allow(deprecated)]
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-        /// 墓位 ID 类型（与 pallet-stardust-grave 对齐）
-        type GraveId: Parameter + Member + Copy + MaxEncodedLen;
         /// 链上余额类型（与 Runtime::Balance 对齐）
         type Balance: Parameter + Member + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
         /// 一周包含的区块数（用于“有效供奉周期”判定，按周粒度）
         #[pallet::constant]
         type BlocksPerWeek: Get<u32>;
+        /// 🆕 每个排行范围（全站/陵园/分类）每周保留的上榜目标数
+        #[pallet::constant]
+        type MaxLeaderboardSize: Get<u32>;
+        /// 🆕 回执保留周数：早于 `当前周 - ReceiptRetentionWeeks` 的周可被 `prune_week` 清理（周根永久保留）
+        #[pallet::constant]
+        type ReceiptRetentionWeeks: Get<u64>;
+        /// 🆕 目标所在陵园（按陵园排行）
+        type TargetPark: TargetParkProvider;
         /// 权重信息提供者
         type WeightInfo: weights::WeightInfo;
     }
 
+    /// 存储版本（v1：统计存储按 MemorialTarget 记账，见 `migrations`）
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
-    // ===== 最小必要存储：累计次数 / 累计金额 / 周活跃标记 =====
+    // ===== 累计次数 / 累计金额 / 周活跃标记（按纪念目标） =====
 
     #[pallet::storage]
-    #[pallet::getter(fn totals_by_grave)]
-    /// 函数级中文注释：每墓位累计供奉次数
-    pub type TotalsByGrave<T: Config> =
-        StorageMap<_, Blake2_128Concat, T::GraveId, u64, ValueQuery>;
+    #[pallet::getter(fn totals_by_target)]
+    /// 函数级中文注释：每个纪念目标累计供奉次数
+    pub type TotalsByTarget<T: Config> =
+        StorageMap<_, Blake2_128Concat, MemorialTarget, u64, ValueQuery>;
 
     #[pallet::storage]
-    #[pallet::getter(fn total_memo_by_grave)]
-    /// 函数级中文注释：每墓位累计 DUST 金额
-    pub type TotalMemoByGrave<T: Config> =
-        StorageMap<_, Blake2_128Concat, T::GraveId, T::Balance, ValueQuery>;
+    #[pallet::getter(fn total_memo_by_target)]
+    /// 函数级中文注释：每个纪念目标累计 DUST 金额
+    pub type TotalMemoByTarget<T: Config> =
+        StorageMap<_, Blake2_128Concat, MemorialTarget, T::Balance, ValueQuery>;
 
     /// 函数级中文注释：去重键集合，避免同一供奉被重复累计。
-    /// - 维度：(grave_id, tx_key) → ()；仅当传入去重键时写入。
+    /// - 维度：(target, tx_key) → ()；仅当传入去重键时写入。
     #[pallet::storage]
     pub type DedupKeys<T: Config> =
-        StorageMap<_, Blake2_128Concat, (T::GraveId, H256), (), OptionQuery>;
-
-    // ========================================
-    // 🗑️ 破坏式变更（方案A）：已移除 TotalMemoByDeceased 存储
-    // ========================================
-    // 原因：不再支持 Deceased 作为供奉目标
-    // 如需逝者级统计，可通过 Grave 关联查询
+        StorageMap<_, Blake2_128Concat, (MemorialTarget, H256), (), OptionQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn weekly_active)]
     /// 函数级中文注释：按周维度的“有效供奉”标记。
-    /// - 维度：(grave_id, who, week_index) → ()
+    /// - 维度：(target, who, week_index) → ()
     /// - week_index = floor(block_number / BlocksPerWeek)
     /// - 仅在存在有效供奉时写入键；无效时无键，节省存储。
     pub type WeeklyActive<T: Config> =
-        StorageMap<_, Blake2_128Concat, (T::GraveId, T::AccountId, u64), (), OptionQuery>;
+        StorageMap<_, Blake2_128Concat, (MemorialTarget, T::AccountId, u64), (), OptionQuery>;
+
+    // ===== 🆕 供奉回执与周 Merkle 根 =====
+
+    /// 函数级中文注释：供奉回执（offering_id → Receipt），offering_id 与 pallet-memorial 一致
+    #[pallet::storage]
+    pub type Receipts<T: Config> = StorageMap<_, Blake2_128Concat, u64, ReceiptOf<T>, OptionQuery>;
+
+    /// 函数级中文注释：周回执树（week → frontier + 实时周根）
+    #[pallet::storage]
+    pub type WeekReceiptTrees<T: Config> =
+        StorageMap<_, Twox64Concat, u64, ReceiptTree, ValueQuery>;
+
+    /// 函数级中文注释：周回执叶子（week, leaf_index → (offering_id, leaf)），用于生成包含证明
+    #[pallet::storage]
+    pub type WeekReceiptLeaves<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, u64, Twox64Concat, u32, (u64, H256), OptionQuery>;
+
+    /// 函数级中文注释：周回执树已补全的内部节点（week, (层, 层内序号) → 哈希），用于按 O(深度²) 次读取生成证明
+    #[pallet::storage]
+    pub type WeekReceiptNodes<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, u64, Twox64Concat, (u8, u32), H256, OptionQuery>;
+
+    /// 函数级中文注释：当周回执树已满后记录的回执索引（week, offering_id → ()），供 `prune_week` 清理
+    #[pallet::storage]
+    pub type WeekOverflowReceipts<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, u64, Twox64Concat, u64, (), OptionQuery>;
+
+    // ===== 🆕 周排行榜 =====
+
+    /// 函数级中文注释：目标周统计（week, target → 次数/金额）
+    #[pallet::storage]
+    pub type WeeklyTargetStats<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u64,
+        Blake2_128Concat,
+        MemorialTarget,
+        TargetWeekStats<T::Balance>,
+        ValueQuery,
+    >;
+
+    /// 函数级中文注释：周排行榜（week, scope → 已排序 Top-N），随每笔供奉增量维护
+    #[pallet::storage]
+    pub type WeeklyLeaderboard<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u64,
+        Blake2_128Concat,
+        RankScope,
+        BoundedVec<LeaderboardEntry<T::Balance>, T::MaxLeaderboardSize>,
+        ValueQuery,
+    >;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        /// 已标记某账户在某墓位的连续周有效供奉（从 start_week 起连续 weeks 周）
-        WeeklyActiveMarked(T::GraveId, T::AccountId, u64, u32),
-        // 🗑️ 破坏式变更（方案A）：已移除 DeceasedOfferingAccumulated 事件
-        /// 函数级中文注释：某墓位累计供奉金额已更新（delta 与新累计值）
-        GraveOfferingAccumulated(T::GraveId, T::Balance, T::Balance),
-        /// 函数级中文注释：已清理某账户在某墓位的历史周活跃标记（before_week 之前，最多 limit 条）
-        WeeksPurged(T::GraveId, T::AccountId, u64, u32),
+        /// 已标记某账户在某纪念目标的连续周有效供奉（从 start_week 起连续 weeks 周）
+        WeeklyActiveMarked(MemorialTarget, T::AccountId, u64, u32),
+        /// 函数级中文注释：某纪念目标累计供奉金额已更新（delta 与新累计值）
+        TargetOfferingAccumulated(MemorialTarget, T::Balance, T::Balance),
+        /// 函数级中文注释：已清理某账户在某纪念目标的历史周活跃标记（before_week 之前，最多 limit 条）
+        WeeksPurged(MemorialTarget, T::AccountId, u64, u32),
+        /// 🆕 供奉回执已记录（leaf_index 为 None 表示当周回执树已满）
+        ReceiptRecorded {
+            offering_id: u64,
+            target: MemorialTarget,
+            week: u64,
+            leaf_index: Option<u32>,
+            root: H256,
+        },
+        /// 🆕 过期周数据已清理（本次移除的回执数；done 表示该周已清理完毕）
+        WeekPruned { week: u64, removed: u32, done: bool },
     }
 
     #[pallet::error]
-    pub enum Error<T> {}
+    pub enum Error<T> {
+        /// 该周仍在回执保留期内
+        WeekNotExpired,
+    }
 
     impl<T: Config> Pallet<T> {
         /// 函数级中文注释：供 Hook 调用的内部记录方法（精简版）。
-        /// - 仅维护累计计数与累计金额；不再存储明细、Top 排行与分类型统计；
+        /// - 仅维护累计计数与累计金额；不生成回执（回执走 `record_offering`）；
         /// - amount 为本次落账的 DUST 金额（若无转账则为 None）。
         /// - kind_code/memo 仅用于兼容旧 Hook 签名，不做链上存储。
         pub fn record_from_hook_with_amount(
-            grave_id: T::GraveId,
+            target: MemorialTarget,
             _who: T::AccountId,
             _kind_code: u8,
             amount: Option<T::Balance>,
@@ -107,43 +204,194 @@ pub mod pallet {
         ) {
             // 若提供了去重键，判断是否已处理
             if let Some(k) = tx_key {
-                if DedupKeys::<T>::contains_key((grave_id, k)) {
+                if DedupKeys::<T>::contains_key((target, k)) {
                     return;
                 }
-                DedupKeys::<T>::insert((grave_id, k), ());
-            }
-            TotalsByGrave::<T>::mutate(grave_id, |c| *c = c.saturating_add(1));
-            if let Some(amt) = amount {
-                let new_total = TotalMemoByGrave::<T>::mutate(grave_id, |b| {
-                    *b = b.saturating_add(amt);
-                    *b
-                });
-                Self::deposit_event(Event::GraveOfferingAccumulated(grave_id, amt, new_total));
+                DedupKeys::<T>::insert((target, k), ());
             }
+            Self::accumulate(target, amount);
         }
 
         /// 兼容旧接口：无金额
         pub fn record_from_hook(
-            grave_id: T::GraveId,
+            target: MemorialTarget,
             who: T::AccountId,
             kind_code: u8,
             memo: Option<alloc::vec::Vec<u8>>,
         ) {
-            Self::record_from_hook_with_amount(grave_id, who, kind_code, None, memo, None)
+            Self::record_from_hook_with_amount(target, who, kind_code, None, memo, None)
         }
 
-        // ========================================
-        // 🗑️ 破坏式变更（方案A）：已移除 add_to_deceased_total 方法
-        // ========================================
-        // 原因：不再支持 Deceased 作为供奉目标
-        // 如需逝者级统计，可通过 Grave 关联查询
+        /// 函数级中文注释：🆕 记录一笔供奉（pallet-memorial 落账回调入口）
+        /// - 以 offering_id 去重：同一供奉只记一次；
+        /// - 累计次数/金额 + 周活跃标记 + 回执入当周 Merkle 树 + 更新全站/陵园/分类周排行；
+        /// - 回执树已满（2^20）时仍保存回执与统计，仅不出具证明。
+        pub fn record_offering(
+            offering_id: u64,
+            target: MemorialTarget,
+            who: T::AccountId,
+            sacrifice_id: u64,
+            category: u8,
+            amount: T::Balance,
+            duration_weeks: Option<u32>,
+        ) {
+            if Receipts::<T>::contains_key(offering_id) {
+                return;
+            }
+            let now = <frame_system::Pallet<T>>::block_number();
+            let week = Self::week_index_of_block(now);
+
+            Self::accumulate(target, Some(amount));
+            Self::mark_weekly_active(target, who.clone(), now, duration_weeks);
+
+            // 回执叶子入当周树
+            let leaf = Self::receipt_leaf(
+                offering_id, &target, &who, sacrifice_id, category, &amount, &now, week,
+            );
+            let (appended, root) = WeekReceiptTrees::<T>::mutate(week, |tree| {
+                (tree.append_with_nodes(leaf), tree.root)
+            });
+            let leaf_index = match appended {
+                Some((index, nodes)) => {
+                    WeekReceiptLeaves::<T>::insert(week, index, (offering_id, leaf));
+                    for (level, position, node) in nodes {
+                        WeekReceiptNodes::<T>::insert(week, (level, position), node);
+                    }
+                    Some(index)
+                },
+                None => {
+                    WeekOverflowReceipts::<T>::insert(week, offering_id, ());
+                    None
+                },
+            };
+            Receipts::<T>::insert(
+                offering_id,
+                Receipt {
+                    target,
+                    who,
+                    sacrifice_id,
+                    category,
+                    amount,
+                    block: now,
+                    week,
+                    leaf_index,
+                    leaf,
+                },
+            );
+
+            // 周排行
+            let stats = WeeklyTargetStats::<T>::mutate(week, target, |s| {
+                s.count = s.count.saturating_add(1);
+                s.amount = s.amount.saturating_add(amount);
+                *s
+            });
+            let entry = LeaderboardEntry { target, count: stats.count, amount: stats.amount };
+            Self::bump_leaderboard(week, RankScope::All, &entry);
+            Self::bump_leaderboard(week, RankScope::Category(category), &entry);
+            if let Some(park_id) = T::TargetPark::park_of(&target) {
+                Self::bump_leaderboard(week, RankScope::Park(park_id), &entry);
+            }
+
+            Self::deposit_event(Event::ReceiptRecorded { offering_id, target, week, leaf_index, root });
+        }
+
+        /// 函数级中文注释：回执叶子哈希（编码见 receipts 模块文档，客户端据此复算）
+        #[allow(clippy::too_many_arguments)]
+        pub fn receipt_leaf(
+            offering_id: u64,
+            target: &MemorialTarget,
+            who: &T::AccountId,
+            sacrifice_id: u64,
+            category: u8,
+            amount: &T::Balance,
+            block: &BlockNumberFor<T>,
+            week: u64,
+        ) -> H256 {
+            let encoded =
+                (offering_id, target, who, sacrifice_id, category, amount, block, week).encode();
+            H256::from(blake2_256(&encoded))
+        }
+
+        /// 函数级中文注释：累计次数与金额
+        fn accumulate(target: MemorialTarget, amount: Option<T::Balance>) {
+            TotalsByTarget::<T>::mutate(target, |c| *c = c.saturating_add(1));
+            if let Some(amt) = amount {
+                let new_total = TotalMemoByTarget::<T>::mutate(target, |b| {
+                    *b = b.saturating_add(amt);
+                    *b
+                });
+                Self::deposit_event(Event::TargetOfferingAccumulated(target, amt, new_total));
+            }
+        }
+
+        /// 函数级中文注释：增量维护某范围的周排行
+        /// - 分数只增不减：已在榜则更新后重排；未在榜且榜未满直接加入；榜满时仅当超过末位才替换末位
+        fn bump_leaderboard(week: u64, scope: RankScope, entry: &LeaderboardEntry<T::Balance>) {
+            WeeklyLeaderboard::<T>::mutate(week, scope, |board| {
+                let mut list = board.clone().into_inner();
+                if let Some(pos) = list.iter().position(|e| e.target == entry.target) {
+                    list[pos] = *entry;
+                } else if (list.len() as u32) < T::MaxLeaderboardSize::get() {
+                    list.push(*entry);
+                } else {
+                    match list.last_mut() {
+                        Some(last) if entry.ranks_above(last) => *last = *entry,
+                        _ => return,
+                    }
+                }
+                list.sort_by(|a, b| (b.count, b.amount).cmp(&(a.count, a.amount)));
+                *board = BoundedVec::truncate_from(list);
+            });
+        }
+
+        /// 函数级中文注释：查询某周某范围的 Top-N（Runtime API 使用）
+        pub fn top_targets(
+            week: u64,
+            scope: RankScope,
+            limit: u32,
+        ) -> alloc::vec::Vec<LeaderboardEntry<T::Balance>> {
+            WeeklyLeaderboard::<T>::get(week, scope)
+                .into_iter()
+                .take(limit as usize)
+                .collect()
+        }
+
+        /// 函数级中文注释：生成回执包含证明（按当前周根；该周被清理或树已满时返回 None）
+        /// - 满子树读取 `WeekReceiptNodes`，仅右边界子树由子节点回算，无需加载整周叶子
+        pub fn receipt_proof(offering_id: u64) -> Option<ReceiptProof> {
+            let receipt = Receipts::<T>::get(offering_id)?;
+            let leaf_index = receipt.leaf_index?;
+            let week = receipt.week;
+            let tree = WeekReceiptTrees::<T>::get(week);
+            let siblings = build_proof_with(tree.count, leaf_index, |level, position| {
+                if level == 0 {
+                    WeekReceiptLeaves::<T>::get(week, position).map(|(_, leaf)| leaf)
+                } else {
+                    WeekReceiptNodes::<T>::get(week, (level, position))
+                }
+            })?;
+            Some(ReceiptProof {
+                offering_id,
+                week: receipt.week,
+                leaf_index,
+                leaf: receipt.leaf,
+                siblings,
+                root: tree.root,
+            })
+        }
+
+        /// 函数级中文注释：某周回执根与回执数（无回执返回 None）
+        pub fn week_root(week: u64) -> Option<(H256, u32)> {
+            let tree = WeekReceiptTrees::<T>::get(week);
+            (tree.count > 0).then_some((tree.root, tree.count))
+        }
 
         /// 函数级中文注释：按"周"为粒度，标记有效供奉周期。
         /// - start_block：供奉发生时的区块号；
         /// - duration_weeks：若为 Timed 供奉则为 Some(w)，否则 None（Instant 仅标记当周）。
         /// - 该方法只做标记，不做资金变动；用于后续统计/计酬的只读判定。
         pub fn mark_weekly_active(
-            grave_id: T::GraveId,
+            target: MemorialTarget,
             who: T::AccountId,
             start_block: BlockNumberFor<T>,
             duration_weeks: Option<u32>,
@@ -154,22 +402,22 @@ pub mod pallet {
             let weeks: u32 = duration_weeks.unwrap_or(1);
             for i in 0..weeks {
                 let week_idx = start_week.saturating_add(i as u64);
-                WeeklyActive::<T>::insert((grave_id, who.clone(), week_idx), ());
+                WeeklyActive::<T>::insert((target, who.clone(), week_idx), ());
             }
-            Self::deposit_event(Event::WeeklyActiveMarked(grave_id, who, start_week, weeks));
+            Self::deposit_event(Event::WeeklyActiveMarked(target, who, start_week, weeks));
         }
 
-        /// 函数级中文注释：查询某账户在某墓位的指定周是否存在有效供奉。
-        pub fn is_week_active(grave_id: T::GraveId, who: &T::AccountId, week_index: u64) -> bool {
-            WeeklyActive::<T>::contains_key((grave_id, who.clone(), week_index))
+        /// 函数级中文注释：查询某账户在某纪念目标的指定周是否存在有效供奉。
+        pub fn is_week_active(target: MemorialTarget, who: &T::AccountId, week_index: u64) -> bool {
+            WeeklyActive::<T>::contains_key((target, who.clone(), week_index))
         }
 
         /// 函数级中文注释：查询某账户在“当前周”是否存在有效供奉（便于跨 pallet 判定）。
-        pub fn is_current_week_active(grave_id: T::GraveId, who: &T::AccountId) -> bool {
+        pub fn is_current_week_active(target: MemorialTarget, who: &T::AccountId) -> bool {
             let now = <frame_system::Pallet<T>>::block_number();
             let bpw = T::BlocksPerWeek::get() as u128;
             let week_idx = (now.saturated_into::<u128>() / bpw) as u64;
-            Self::is_week_active(grave_id, who, week_idx)
+            Self::is_week_active(target, who, week_idx)
         }
 
         /// 函数级中文注释：计算某区块号对应的周索引（floor(block_number / BlocksPerWeek)）。
@@ -188,7 +436,7 @@ pub mod pallet {
         /// - 返回 Vec<u8>，低位在前；位序为 [start_week + 0, start_week + 1, ...]；
         /// - len 最大 256 建议，避免链上过大内存；调用方应合理控制参数。
         pub fn weeks_active_bitmap(
-            grave_id: T::GraveId,
+            target: MemorialTarget,
             who: &T::AccountId,
             start_week: u64,
             len: u32,
@@ -200,7 +448,7 @@ pub mod pallet {
             let mut bit_idx: u32 = 0;
             for i in 0..cap {
                 let week = start_week.saturating_add(i as u64);
-                let active = WeeklyActive::<T>::contains_key((grave_id, who.clone(), week));
+                let active = WeeklyActive::<T>::contains_key((target, who.clone(), week));
                 if active {
                     byte |= 1 << (bit_idx % 8);
                 }
@@ -219,9 +467,9 @@ pub mod pallet {
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// 函数级中文注释：清理某账户在某墓位的历史周活跃标记
+        /// 函数级中文注释：清理某账户在某纪念目标的历史周活跃标记
         /// - 仅允许该账户本人调用；
-        /// - 将移除 `(grave_id, who, week)` 中 `week < before_week` 的键，最多 `limit` 条；
+        /// - 将移除 `(target, who, week)` 中 `week < before_week` 的键，最多 `limit` 条；
         /// - 目的：控制 `WeeklyActive` 存储规模，便于长期运行；
         /// - 注意：清理仅影响只读统计，不影响任何资金或权益。
         #[pallet::call_index(0)]
@@ -229,7 +477,7 @@ pub mod pallet {
         #[pallet::weight(T::WeightInfo::purge_weeks(*limit))]
         pub fn purge_weeks(
             origin: OriginFor<T>,
-            grave_id: T::GraveId,
+            target: MemorialTarget,
             who: T::AccountId,
             before_week: u64,
             limit: u32,
@@ -237,29 +485,29 @@ pub mod pallet {
             let caller = ensure_signed(origin)?;
             ensure!(caller == who, sp_runtime::DispatchError::BadOrigin);
             let mut removed: u32 = 0;
-            for (gid, acc, week) in WeeklyActive::<T>::iter_keys() {
+            for (tgt, acc, week) in WeeklyActive::<T>::iter_keys() {
                 if removed >= limit {
                     break;
                 }
-                if gid == grave_id && acc == who && week < before_week {
-                    WeeklyActive::<T>::remove((gid, acc.clone(), week));
+                if tgt == target && acc == who && week < before_week {
+                    WeeklyActive::<T>::remove((tgt, acc.clone(), week));
                     removed = removed.saturating_add(1);
                 }
             }
-            Self::deposit_event(Event::WeeksPurged(grave_id, who, before_week, removed));
+            Self::deposit_event(Event::WeeksPurged(target, who, before_week, removed));
             Ok(())
         }
 
         /// 函数级中文注释：按区间批量清理周活跃标记（含起，含止前）
         /// - 仅允许该账户本人调用；
-        /// - 将移除 `(grave_id, who, week)` 中 `start_week <= week < end_week` 的键，最多 `limit` 条；
+        /// - 将移除 `(target, who, week)` 中 `start_week <= week < end_week` 的键，最多 `limit` 条；
         /// - 用于 TTL 压缩或周期性清理历史周数据。
         #[pallet::call_index(1)]
         #[allow(deprecated)]
         #[pallet::weight(T::WeightInfo::purge_weeks_by_range(*limit))]
         pub fn purge_weeks_by_range(
             origin: OriginFor<T>,
-            grave_id: T::GraveId,
+            target: MemorialTarget,
             who: T::AccountId,
             start_week: u64,
             end_week: u64,
@@ -268,16 +516,50 @@ pub mod pallet {
             let caller = ensure_signed(origin)?;
             ensure!(caller == who, sp_runtime::DispatchError::BadOrigin);
             let mut removed: u32 = 0;
-            for (gid, acc, week) in WeeklyActive::<T>::iter_keys() {
+            for (tgt, acc, week) in WeeklyActive::<T>::iter_keys() {
                 if removed >= limit {
                     break;
                 }
-                if gid == grave_id && acc == who && week >= start_week && week < end_week {
-                    WeeklyActive::<T>::remove((gid, acc.clone(), week));
+                if tgt == target && acc == who && week >= start_week && week < end_week {
+                    WeeklyActive::<T>::remove((tgt, acc.clone(), week));
                     removed = removed.saturating_add(1);
                 }
             }
-            Self::deposit_event(Event::WeeksPurged(grave_id, who, end_week, removed));
+            Self::deposit_event(Event::WeeksPurged(target, who, end_week, removed));
+            Ok(())
+        }
+
+        /// 函数级中文注释：🆕 清理过期周的回执、叶子、内部节点、周统计与排行（任何人可调用）
+        /// - 仅当 `week + ReceiptRetentionWeeks < 当前周`；
+        /// - 每次最多移除 `limit` 条回执（先树内回执，再树满后的溢出回执），回执清空后再清理该周节点、统计与排行；
+        /// - 周根（WeekReceiptTrees）永久保留，已下载的证明仍可对照校验。
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::prune_week(*limit))]
+        pub fn prune_week(origin: OriginFor<T>, week: u64, limit: u32) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(
+                week.saturating_add(T::ReceiptRetentionWeeks::get()) < Self::current_week_index(),
+                Error::<T>::WeekNotExpired
+            );
+            let mut removed: u32 = 0;
+            for (_, (offering_id, _)) in
+                WeekReceiptLeaves::<T>::drain_prefix(week).take(limit as usize)
+            {
+                Receipts::<T>::remove(offering_id);
+                removed = removed.saturating_add(1);
+            }
+            for (offering_id, _) in WeekOverflowReceipts::<T>::drain_prefix(week)
+                .take(limit.saturating_sub(removed) as usize)
+            {
+                Receipts::<T>::remove(offering_id);
+                removed = removed.saturating_add(1);
+            }
+            let done = WeekReceiptLeaves::<T>::iter_prefix(week).next().is_none()
+                && WeekOverflowReceipts::<T>::iter_prefix(week).next().is_none()
+                && WeekReceiptNodes::<T>::clear_prefix(week, limit, None).maybe_cursor.is_none()
+                && WeeklyTargetStats::<T>::clear_prefix(week, limit, None).maybe_cursor.is_none()
+                && WeeklyLeaderboard::<T>::clear_prefix(week, limit, None).maybe_cursor.is_none();
+            Self::deposit_event(Event::WeekPruned { week, removed, done });
             Ok(())
         }
     }
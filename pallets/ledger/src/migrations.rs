@@ -0,0 +1,49 @@
+//! 函数级中文注释：pallet-ledger 存储迁移
+//!
+//! ### v0 → v1：统计维度由墓位（GraveId）改为纪念目标（MemorialTarget）
+//! - 旧 `TotalsByGrave` / `TotalMemoByGrave` 已改名为 `TotalsByTarget` / `TotalMemoByTarget`，旧前缀成为孤儿数据
+//! - `DedupKeys` / `WeeklyActive` 沿用原前缀但键类型改变，旧键按新类型无法解码
+//! - 墓位与逝者为一对多关系，无法把墓位累计量唯一折算到纪念目标，因此清空上述四个前缀，
+//!   统计从升级后的供奉重新累计（回执与周排行榜为新增存储，不受影响）
+
+use crate::pallet::{Config, Pallet};
+use frame_support::{
+    migrations::VersionedMigration,
+    storage::migration::clear_storage_prefix,
+    traits::{Get, PalletInfoAccess, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+};
+
+/// 函数级中文注释：v0 → v1 迁移（仅在链上存储版本为 0 时执行，执行后置为 1）
+pub type MigrateV0ToV1<T> = VersionedMigration<
+    0,
+    1,
+    v1::UncheckedMigrateV0ToV1<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+pub mod v1 {
+    use super::*;
+
+    /// 需要清空的 v0 存储项
+    pub const STALE_STORAGES: [&[u8]; 4] =
+        [b"TotalsByGrave", b"TotalMemoByGrave", b"DedupKeys", b"WeeklyActive"];
+
+    /// 函数级中文注释：清空按墓位记账的旧存储前缀（不检查版本，由 `MigrateV0ToV1` 包装）
+    pub struct UncheckedMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let pallet = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+            let mut reads: u64 = 0;
+            let mut writes: u64 = 0;
+            for storage in STALE_STORAGES {
+                let res = clear_storage_prefix(pallet, storage, b"", None, None);
+                reads = reads.saturating_add(res.loops as u64);
+                writes = writes.saturating_add(res.unique as u64);
+            }
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+    }
+}
@@ -31,6 +31,19 @@ frame_support::construct_runtime!(
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub const BlocksPerWeek: u32 = 100_800; // 6s/块 × 7天
+    pub const MaxLeaderboardSize: u32 = 3;
+    pub const ReceiptRetentionWeeks: u64 = 2;
+}
+
+/// 测试用陵园映射：逝者 id 为偶数 → 陵园 1，其余未落位
+pub struct MockTargetPark;
+impl pallet_ledger::TargetParkProvider for MockTargetPark {
+    fn park_of(target: &pallet_ledger::MemorialTarget) -> Option<u64> {
+        match target {
+            pallet_ledger::MemorialTarget::Deceased(id) if id % 2 == 0 => Some(1),
+            _ => None,
+        }
+    }
 }
 
 impl frame_system::Config for Test {
@@ -69,9 +82,11 @@ impl frame_system::Config for Test {
 
 impl pallet_ledger::pallet::Config for Test {
     type RuntimeEvent = RuntimeEvent;
-    type GraveId = u64;
     type Balance = u128;
     type BlocksPerWeek = BlocksPerWeek;
+    type MaxLeaderboardSize = MaxLeaderboardSize;
+    type ReceiptRetentionWeeks = ReceiptRetentionWeeks;
+    type TargetPark = MockTargetPark;
     type WeightInfo = crate::weights::SubstrateWeight<Test>;
 }
 
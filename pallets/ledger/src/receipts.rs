@@ -0,0 +1,232 @@
+//! 函数级中文注释：供奉回执、周 Merkle 树与排行榜类型
+//!
+//! - 每笔供奉生成一条回执，回执叶子按周追加到固定深度的增量 Merkle 树（frontier 算法，
+//!   每次追加 O(深度)），周根随每笔供奉实时更新；
+//! - 追加时顺带保存已补全的内部节点，包含证明由 Runtime API 按节点读取 O(深度²) 次生成，
+//!   客户端用 `verify_receipt_proof` 校验；
+//! - 叶子编码：`blake2_256(SCALE(offering_id, target, who, sacrifice_id, category, amount, block, week))`；
+//! - 节点编码：`blake2_256(left ++ right)`，空位用逐层零哈希补齐（`zero[0] = H256::zero()`）。
+
+use alloc::vec::Vec;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::{hashing::blake2_256, H256};
+
+/// 回执 Merkle 树深度（每周最多 2^20 条回执）
+pub const RECEIPT_TREE_DEPTH: usize = 20;
+
+/// 函数级中文注释：纪念目标（替代已删除的 GraveId）
+/// - `Deceased`: 逝者；`Pet`: 宠物；`Space`: 纪念空间（pallet-memorial-space）
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen, Debug)]
+pub enum MemorialTarget {
+    Deceased(u64),
+    Pet(u64),
+    Space(u64),
+}
+
+/// 函数级中文注释：排行范围
+/// - `All`: 全站；`Park(park_id)`: 目标所在陵园；`Category(code)`: 祭祀品一级分类编码
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum RankScope {
+    All,
+    Park(u64),
+    Category(u8),
+}
+
+/// 函数级中文注释：目标在某周的供奉统计
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug, Default)]
+pub struct TargetWeekStats<Balance> {
+    pub count: u32,
+    pub amount: Balance,
+}
+
+/// 函数级中文注释：排行榜条目（按供奉次数降序，次数相同按金额降序）
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct LeaderboardEntry<Balance> {
+    pub target: MemorialTarget,
+    pub count: u32,
+    pub amount: Balance,
+}
+
+impl<Balance: Ord> LeaderboardEntry<Balance> {
+    /// 函数级中文注释：是否排在 `other` 之前
+    pub fn ranks_above(&self, other: &Self) -> bool {
+        (self.count, &self.amount) > (other.count, &other.amount)
+    }
+}
+
+/// 函数级中文注释：供奉回执
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct Receipt<AccountId, Balance, BlockNumber> {
+    pub target: MemorialTarget,
+    pub who: AccountId,
+    pub sacrifice_id: u64,
+    /// 祭祀品一级分类编码（pallet-memorial `PrimaryCategory`）
+    pub category: u8,
+    pub amount: Balance,
+    pub block: BlockNumber,
+    pub week: u64,
+    /// 在当周回执树中的位置；树已满时为 None（回执仍保存，但无法出具证明）
+    pub leaf_index: Option<u32>,
+    pub leaf: H256,
+}
+
+/// 函数级中文注释：回执包含证明（由 Runtime API 生成）
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, Debug)]
+pub struct ReceiptProof {
+    pub offering_id: u64,
+    pub week: u64,
+    pub leaf_index: u32,
+    pub leaf: H256,
+    /// 自叶子向上的兄弟节点，长度为 `RECEIPT_TREE_DEPTH`
+    pub siblings: Vec<H256>,
+    /// 生成证明时的周根
+    pub root: H256,
+}
+
+/// 函数级中文注释：周回执树（增量 Merkle 树，仅存右边界 frontier）
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug, Default)]
+pub struct ReceiptTree {
+    pub count: u32,
+    pub frontier: [H256; RECEIPT_TREE_DEPTH],
+    pub root: H256,
+}
+
+impl ReceiptTree {
+    /// 函数级中文注释：追加叶子并刷新周根；树已满返回 None
+    pub fn append(&mut self, leaf: H256) -> Option<u32> {
+        self.append_with_nodes(leaf).map(|(index, _)| index)
+    }
+
+    /// 函数级中文注释：追加叶子，并返回本次补全的内部节点 `(层, 层内序号, 哈希)`
+    /// - 第 h 层节点覆盖叶子 `[i << h, (i + 1) << h)`，仅在其子树填满时返回；
+    /// - 均摊每次追加约 1 个节点，最坏 `RECEIPT_TREE_DEPTH` 个。
+    pub fn append_with_nodes(&mut self, leaf: H256) -> Option<(u32, Vec<(u8, u32, H256)>)> {
+        if (self.count as u64) >= (1u64 << RECEIPT_TREE_DEPTH) {
+            return None;
+        }
+        let index = self.count;
+        let mut node = leaf;
+        let mut size = index as u64 + 1;
+        let mut completed = Vec::new();
+        for h in 0..RECEIPT_TREE_DEPTH {
+            if size & 1 == 1 {
+                self.frontier[h] = node;
+                break;
+            }
+            node = hash_pair(&self.frontier[h], &node);
+            completed.push(((h + 1) as u8, index >> (h + 1), node));
+            size >>= 1;
+        }
+        self.count = self.count.saturating_add(1);
+        self.root = self.compute_root();
+        Some((index, completed))
+    }
+
+    fn compute_root(&self) -> H256 {
+        let zeros = zero_hashes();
+        let mut node = H256::zero();
+        let mut size = self.count as u64;
+        for h in 0..RECEIPT_TREE_DEPTH {
+            node = if size & 1 == 1 {
+                hash_pair(&self.frontier[h], &node)
+            } else {
+                hash_pair(&node, &zeros[h])
+            };
+            size >>= 1;
+        }
+        node
+    }
+}
+
+/// 函数级中文注释：父节点哈希
+pub fn hash_pair(left: &H256, right: &H256) -> H256 {
+    let mut buf = [0u8; 64];
+    buf[..32].copy_from_slice(left.as_bytes());
+    buf[32..].copy_from_slice(right.as_bytes());
+    H256::from(blake2_256(&buf))
+}
+
+/// 函数级中文注释：逐层零哈希（空子树根）
+pub fn zero_hashes() -> [H256; RECEIPT_TREE_DEPTH] {
+    let mut zeros = [H256::zero(); RECEIPT_TREE_DEPTH];
+    for h in 1..RECEIPT_TREE_DEPTH {
+        zeros[h] = hash_pair(&zeros[h - 1], &zeros[h - 1]);
+    }
+    zeros
+}
+
+/// 函数级中文注释：由当周全部叶子生成第 `index` 个叶子的兄弟路径
+pub fn build_proof(leaves: &[H256], index: u32) -> Option<Vec<H256>> {
+    let mut idx = index as usize;
+    if idx >= leaves.len() {
+        return None;
+    }
+    let zeros = zero_hashes();
+    let mut layer = leaves.to_vec();
+    let mut siblings = Vec::with_capacity(RECEIPT_TREE_DEPTH);
+    for h in 0..RECEIPT_TREE_DEPTH {
+        siblings.push(layer.get(idx ^ 1).copied().unwrap_or(zeros[h]));
+        layer = layer
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(&zeros[h])))
+            .collect();
+        idx >>= 1;
+    }
+    Some(siblings)
+}
+
+/// 函数级中文注释：按已存节点生成第 `index` 个叶子的兄弟路径
+///
+/// - `node_at(0, i)` 返回第 i 个叶子，`node_at(h, i)` 返回已补全的第 h 层节点；
+/// - 满子树直接读取节点，右边界未填满的子树由子节点逐层回算，空子树取零哈希；
+/// - 节点缺失（如升级前写入的周）时同样回退为由子节点回算；叶子缺失返回 None。
+pub fn build_proof_with<F>(count: u32, index: u32, node_at: F) -> Option<Vec<H256>>
+where
+    F: Fn(u8, u32) -> Option<H256>,
+{
+    if index >= count {
+        return None;
+    }
+    let zeros = zero_hashes();
+    (0..RECEIPT_TREE_DEPTH)
+        .map(|h| subtree_root(h, (index >> h) ^ 1, count, &zeros, &node_at))
+        .collect()
+}
+
+/// 函数级中文注释：第 h 层第 i 个子树的当前根
+fn subtree_root<F>(h: usize, i: u32, count: u32, zeros: &[H256; RECEIPT_TREE_DEPTH], node_at: &F) -> Option<H256>
+where
+    F: Fn(u8, u32) -> Option<H256>,
+{
+    let start = (i as u64) << h;
+    if start >= count as u64 {
+        return Some(zeros[h]);
+    }
+    if h == 0 {
+        return node_at(0, i);
+    }
+    if start + (1u64 << h) <= count as u64 {
+        if let Some(node) = node_at(h as u8, i) {
+            return Some(node);
+        }
+    }
+    let left = subtree_root(h - 1, i << 1, count, zeros, node_at)?;
+    let right = subtree_root(h - 1, (i << 1) | 1, count, zeros, node_at)?;
+    Some(hash_pair(&left, &right))
+}
+
+/// 函数级中文注释：校验回执包含证明（客户端与链上均可调用）
+pub fn verify_receipt_proof(leaf: H256, index: u32, siblings: &[H256], root: H256) -> bool {
+    if siblings.len() != RECEIPT_TREE_DEPTH {
+        return false;
+    }
+    let node = siblings.iter().enumerate().fold(leaf, |node, (h, sibling)| {
+        if (index >> h) & 1 == 0 {
+            hash_pair(&node, sibling)
+        } else {
+            hash_pair(sibling, &node)
+        }
+    });
+    node == root
+}
@@ -0,0 +1,43 @@
+//! Runtime API 定义
+//!
+//! 本模块定义了供奉台账的 Runtime API：周排行榜（全站 / 陵园 / 分类）、供奉回执与回执包含证明，
+//! 供前端"本周最受缅怀"榜单与家属回执校验使用。
+
+use crate::receipts::{LeaderboardEntry, RankScope, Receipt, ReceiptProof};
+use alloc::vec::Vec;
+use codec::Codec;
+use sp_core::H256;
+
+sp_api::decl_runtime_apis! {
+    /// 供奉台账 Runtime API
+    ///
+    /// 周索引 = floor(区块号 / BlocksPerWeek)；排行按供奉次数降序，次数相同按金额降序。
+    pub trait LedgerApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// 获取某周某范围的 Top-N 纪念目标
+        ///
+        /// # 参数
+        /// - `week`: 周索引
+        /// - `scope`: 全站 / 陵园 / 祭祀品分类
+        /// - `limit`: 返回条数（不超过 MaxLeaderboardSize）
+        fn top_targets(week: u64, scope: RankScope, limit: u32) -> Vec<LeaderboardEntry<Balance>>;
+
+        /// 获取供奉回执
+        fn receipt(offering_id: u64) -> Option<Receipt<AccountId, Balance, BlockNumber>>;
+
+        /// 获取回执包含证明（对照当前周根）
+        ///
+        /// 该周已被清理或当周回执树已满时返回 None。
+        fn receipt_proof(offering_id: u64) -> Option<ReceiptProof>;
+
+        /// 获取某周回执根与回执数
+        fn week_root(week: u64) -> Option<(H256, u32)>;
+
+        /// 当前周索引
+        fn current_week() -> u64;
+    }
+}
@@ -1,10 +1,15 @@
 //! 函数级中文注释：pallet-ledger 单元测试。
-//! 覆盖：周索引计算、溢出保护、去重键命中/未命中、清理接口、事件正确性。
+//! 覆盖：周索引计算、溢出保护、去重键命中/未命中、清理接口、事件正确性、
+//! 回执与周 Merkle 证明、周排行榜、过期周清理。
 
 #![cfg(test)]
 
-use crate::{pallet::Event as LedgerEvent, Pallet as Ledger};
-use frame_support::assert_ok;
+use crate::{
+    pallet::Event as LedgerEvent, verify_receipt_proof, Error, MemorialTarget, RankScope,
+    ReceiptTree, Receipts, WeekOverflowReceipts, WeekReceiptLeaves, WeekReceiptNodes,
+    WeekReceiptTrees, WeeklyLeaderboard, Pallet as Ledger,
+};
+use frame_support::{assert_noop, assert_ok};
 use sp_core::H256;
 
 use crate::mock::{new_test_ext, RuntimeEvent, RuntimeOrigin, System, Test};
@@ -23,29 +28,29 @@ fn week_index_calculation() {
 #[test]
 fn dedup_hit_and_miss() {
     new_test_ext().execute_with(|| {
-        let gid: u64 = 1;
+        let gid = MemorialTarget::Deceased(1);
         let who: u64 = 9;
         let k = H256::repeat_byte(7);
         // 首次，未命中去重，累计一次
         Ledger::<Test>::record_from_hook_with_amount(gid, who, 0, Some(10u128), None, Some(k));
-        assert_eq!(Ledger::<Test>::totals_by_grave(gid), 1);
-        assert_eq!(Ledger::<Test>::total_memo_by_grave(gid), 10u128);
+        assert_eq!(Ledger::<Test>::totals_by_target(gid), 1);
+        assert_eq!(Ledger::<Test>::total_memo_by_target(gid), 10u128);
         // 再次，同键命中去重，不累计
         Ledger::<Test>::record_from_hook_with_amount(gid, who, 0, Some(10u128), None, Some(k));
-        assert_eq!(Ledger::<Test>::totals_by_grave(gid), 1);
-        assert_eq!(Ledger::<Test>::total_memo_by_grave(gid), 10u128);
+        assert_eq!(Ledger::<Test>::totals_by_target(gid), 1);
+        assert_eq!(Ledger::<Test>::total_memo_by_target(gid), 10u128);
         // 换键，未命中，累计
         let k2 = H256::repeat_byte(8);
         Ledger::<Test>::record_from_hook_with_amount(gid, who, 0, Some(5u128), None, Some(k2));
-        assert_eq!(Ledger::<Test>::totals_by_grave(gid), 2);
-        assert_eq!(Ledger::<Test>::total_memo_by_grave(gid), 15u128);
+        assert_eq!(Ledger::<Test>::totals_by_target(gid), 2);
+        assert_eq!(Ledger::<Test>::total_memo_by_target(gid), 15u128);
     });
 }
 
 #[test]
 fn mark_and_purge_range_and_event() {
     new_test_ext().execute_with(|| {
-        let gid: u64 = 1;
+        let gid = MemorialTarget::Deceased(1);
         let who: u64 = 9;
         // 标记从当前块对应周起 5 周
         let start = System::block_number();
@@ -69,3 +74,221 @@ fn mark_and_purge_range_and_event() {
         assert!(ev.is_some());
     });
 }
+
+#[test]
+fn incremental_tree_matches_full_proofs() {
+    // 增量 frontier 根应与由全部叶子生成的证明一致（覆盖奇偶与 2 的幂边界）
+    let mut tree = ReceiptTree::default();
+    let mut leaves = alloc::vec::Vec::new();
+    for i in 0u8..9 {
+        let leaf = H256::repeat_byte(i + 1);
+        assert_eq!(tree.append(leaf), Some(i as u32));
+        leaves.push(leaf);
+        for (idx, l) in leaves.iter().enumerate() {
+            let proof = crate::build_proof(&leaves, idx as u32).unwrap();
+            assert!(verify_receipt_proof(*l, idx as u32, &proof, tree.root));
+        }
+    }
+    let proof = crate::build_proof(&leaves, 3).unwrap();
+    assert!(!verify_receipt_proof(leaves[4], 3, &proof, tree.root));
+    assert!(crate::build_proof(&leaves, 9).is_none());
+}
+
+#[test]
+fn stored_nodes_reproduce_full_proofs() {
+    // 由已补全内部节点生成的证明应与全量叶子生成的一致；节点缺失时回退为由叶子回算
+    let mut tree = ReceiptTree::default();
+    let mut leaves = alloc::vec::Vec::new();
+    let mut nodes = alloc::collections::BTreeMap::new();
+    for i in 0u8..11 {
+        let leaf = H256::repeat_byte(i + 1);
+        let (index, completed) = tree.append_with_nodes(leaf).unwrap();
+        leaves.push(leaf);
+        nodes.insert((0u8, index), leaf);
+        for (level, position, node) in completed {
+            nodes.insert((level, position), node);
+        }
+        for idx in 0..leaves.len() as u32 {
+            let expected = crate::build_proof(&leaves, idx);
+            assert_eq!(crate::build_proof_with(tree.count, idx, |l, p| nodes.get(&(l, p)).copied()), expected);
+            let leaves_only = crate::build_proof_with(tree.count, idx, |l, p| {
+                if l == 0 { leaves.get(p as usize).copied() } else { None }
+            });
+            assert_eq!(leaves_only, expected);
+        }
+    }
+    // 11 个叶子：第 1 层 5 个、第 2 层 2 个、第 3 层 1 个满节点
+    assert_eq!(nodes.keys().filter(|(l, _)| *l > 0).count(), 8);
+    assert!(crate::build_proof_with(tree.count, 11, |l, p| nodes.get(&(l, p)).copied()).is_none());
+}
+
+#[test]
+fn record_offering_writes_receipt_and_proof() {
+    new_test_ext().execute_with(|| {
+        let target = MemorialTarget::Pet(7);
+        Ledger::<Test>::record_offering(1, target, 9, 100, 2, 50, Some(3));
+        Ledger::<Test>::record_offering(2, MemorialTarget::Deceased(1), 8, 101, 0, 20, None);
+        // 同一 offering_id 不重复记账
+        Ledger::<Test>::record_offering(1, target, 9, 100, 2, 50, Some(3));
+
+        assert_eq!(Ledger::<Test>::totals_by_target(target), 1);
+        assert_eq!(Ledger::<Test>::total_memo_by_target(target), 50);
+        assert!(Ledger::<Test>::is_week_active(target, &9, 2));
+
+        let receipt = Receipts::<Test>::get(1).unwrap();
+        assert_eq!(receipt.leaf_index, Some(0));
+        assert_eq!(
+            receipt.leaf,
+            Ledger::<Test>::receipt_leaf(1, &target, &9, 100, 2, &50, &1, 0)
+        );
+        let (root, count) = Ledger::<Test>::week_root(0).unwrap();
+        assert_eq!(count, 2);
+        for id in [1u64, 2] {
+            let proof = Ledger::<Test>::receipt_proof(id).unwrap();
+            assert_eq!(proof.root, root);
+            assert!(verify_receipt_proof(proof.leaf, proof.leaf_index, &proof.siblings, root));
+        }
+        // 两个叶子补全第 1 层第 0 个节点
+        assert!(WeekReceiptNodes::<Test>::get(0, (1, 0)).is_some());
+        assert!(Ledger::<Test>::week_root(1).is_none());
+    });
+}
+
+#[test]
+fn leaderboards_rank_by_count_then_amount() {
+    new_test_ext().execute_with(|| {
+        let d2 = MemorialTarget::Deceased(2);
+        let d3 = MemorialTarget::Deceased(3);
+        let d4 = MemorialTarget::Deceased(4);
+        let p1 = MemorialTarget::Pet(1);
+        Ledger::<Test>::record_offering(1, d2, 9, 1, 0, 10, None);
+        Ledger::<Test>::record_offering(2, d3, 9, 1, 1, 30, None);
+        Ledger::<Test>::record_offering(3, d4, 9, 1, 0, 20, None);
+        Ledger::<Test>::record_offering(4, d3, 9, 1, 1, 1, None);
+        // 榜单上限 3：p1 仅 1 次、金额 5，不超过末位 d2(1 次, 10)，不上榜
+        Ledger::<Test>::record_offering(5, p1, 9, 1, 0, 5, None);
+
+        let all: alloc::vec::Vec<_> = Ledger::<Test>::top_targets(0, RankScope::All, 10)
+            .into_iter()
+            .map(|e| (e.target, e.count, e.amount))
+            .collect();
+        assert_eq!(all, alloc::vec![(d3, 2, 31), (d4, 1, 20), (d2, 1, 10)]);
+
+        // p1 再次供奉后 2 次，挤掉末位
+        Ledger::<Test>::record_offering(6, p1, 9, 1, 0, 5, None);
+        let all = WeeklyLeaderboard::<Test>::get(0, RankScope::All);
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[1].target, p1);
+        assert!(all.iter().all(|e| e.target != d2));
+
+        // 陵园榜只含偶数逝者；分类榜按分类编码
+        let park: alloc::vec::Vec<_> =
+            Ledger::<Test>::top_targets(0, RankScope::Park(1), 10).into_iter().map(|e| e.target).collect();
+        assert_eq!(park, alloc::vec![d4, d2]);
+        let cat1 = Ledger::<Test>::top_targets(0, RankScope::Category(1), 10);
+        assert_eq!(cat1.len(), 1);
+        assert_eq!(cat1[0].target, d3);
+        assert_eq!(Ledger::<Test>::top_targets(0, RankScope::All, 1).len(), 1);
+    });
+}
+
+#[test]
+fn prune_week_respects_retention() {
+    new_test_ext().execute_with(|| {
+        Ledger::<Test>::record_offering(1, MemorialTarget::Deceased(2), 9, 1, 0, 10, None);
+        Ledger::<Test>::record_offering(2, MemorialTarget::Pet(1), 9, 1, 0, 10, None);
+        let (root, _) = Ledger::<Test>::week_root(0).unwrap();
+
+        // 第 2 周：0 + 2 不小于 2，仍在保留期
+        System::set_block_number(100_800 * 2);
+        assert_noop!(
+            Ledger::<Test>::prune_week(RuntimeOrigin::signed(1), 0, 10),
+            Error::<Test>::WeekNotExpired
+        );
+
+        System::set_block_number(100_800 * 3);
+        assert_ok!(Ledger::<Test>::prune_week(RuntimeOrigin::signed(1), 0, 1));
+        assert_eq!(WeekReceiptLeaves::<Test>::iter_prefix(0).count(), 1);
+        assert_ok!(Ledger::<Test>::prune_week(RuntimeOrigin::signed(1), 0, 10));
+        assert!(Receipts::<Test>::get(1).is_none() && Receipts::<Test>::get(2).is_none());
+        assert_eq!(WeekReceiptNodes::<Test>::iter_prefix(0).count(), 0);
+        assert!(Ledger::<Test>::top_targets(0, RankScope::All, 10).is_empty());
+        // 周根保留
+        assert_eq!(Ledger::<Test>::week_root(0), Some((root, 2)));
+        System::assert_last_event(RuntimeEvent::Ledger(LedgerEvent::WeekPruned {
+            week: 0,
+            removed: 1,
+            done: true,
+        }));
+    });
+}
+
+#[test]
+fn prune_week_removes_receipts_recorded_after_tree_full() {
+    new_test_ext().execute_with(|| {
+        Ledger::<Test>::record_offering(1, MemorialTarget::Deceased(2), 9, 1, 0, 10, None);
+        // 模拟当周回执树已满
+        WeekReceiptTrees::<Test>::mutate(0, |tree| tree.count = 1 << crate::RECEIPT_TREE_DEPTH);
+        Ledger::<Test>::record_offering(2, MemorialTarget::Pet(1), 9, 1, 0, 10, None);
+        Ledger::<Test>::record_offering(3, MemorialTarget::Pet(1), 9, 1, 0, 10, None);
+        assert_eq!(Receipts::<Test>::get(2).unwrap().leaf_index, None);
+        assert!(Ledger::<Test>::receipt_proof(2).is_none());
+        assert_eq!(WeekOverflowReceipts::<Test>::iter_prefix(0).count(), 2);
+
+        System::set_block_number(100_800 * 3);
+        // limit 2：先移除树内回执，再移除 1 条溢出回执
+        assert_ok!(Ledger::<Test>::prune_week(RuntimeOrigin::signed(1), 0, 2));
+        assert!(Receipts::<Test>::get(1).is_none());
+        assert_eq!(WeekOverflowReceipts::<Test>::iter_prefix(0).count(), 1);
+        System::assert_last_event(RuntimeEvent::Ledger(LedgerEvent::WeekPruned {
+            week: 0,
+            removed: 2,
+            done: false,
+        }));
+
+        assert_ok!(Ledger::<Test>::prune_week(RuntimeOrigin::signed(1), 0, 2));
+        assert!((1..=3).all(|id| Receipts::<Test>::get(id).is_none()));
+        System::assert_last_event(RuntimeEvent::Ledger(LedgerEvent::WeekPruned {
+            week: 0,
+            removed: 1,
+            done: true,
+        }));
+    });
+}
+
+#[test]
+fn migrate_v0_to_v1_clears_grave_keyed_storage() {
+    use frame_support::{
+        storage::unhashed,
+        traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    };
+
+    new_test_ext().execute_with(|| {
+        // 写入 v0 时期按墓位记账的旧键（含沿用前缀的 DedupKeys / WeeklyActive）
+        let prefixes: [&[u8]; 4] = [b"TotalsByGrave", b"TotalMemoByGrave", b"DedupKeys", b"WeeklyActive"];
+        let mut stale = Vec::new();
+        for storage in prefixes {
+            let mut key = frame_support::storage::storage_prefix(b"Ledger", storage).to_vec();
+            key.extend_from_slice(&[1u8; 24]);
+            unhashed::put_raw(&key, &[7u8; 8]);
+            stale.push(key);
+        }
+        // 新增的按目标累计存储不受影响
+        let target = MemorialTarget::Deceased(1);
+        Ledger::<Test>::record_from_hook_with_amount(target, 9, 0, Some(10u128), None, None);
+        StorageVersion::new(0).put::<Ledger<Test>>();
+
+        crate::migrations::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        for key in stale.iter() {
+            assert!(unhashed::get_raw(key).is_none());
+        }
+        assert_eq!(Ledger::<Test>::on_chain_storage_version(), 1);
+        assert_eq!(Ledger::<Test>::totals_by_target(target), 1);
+
+        // 再次执行为空操作
+        unhashed::put_raw(&stale[0], &[7u8; 8]);
+        crate::migrations::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert!(unhashed::get_raw(&stale[0]).is_some());
+    });
+}
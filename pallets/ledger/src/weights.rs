@@ -13,6 +13,10 @@ pub trait WeightInfo {
     // 🗑️ 破坏式变更（方案A）：已移除 add_to_deceased_total
     /// 标记周活跃（按持续周数线性增长）
     fn mark_weekly_active(weeks: u32) -> Weight;
+    /// 🆕 记录供奉回执（累计 + 周活跃 + 回执树 + 至多 3 个排行范围）
+    fn record_offering(weeks: u32) -> Weight;
+    /// 🆕 清理过期周（参数为每类存储的最大删除条数）
+    fn prune_week(limit: u32) -> Weight;
 }
 
 /// 默认实现：基于 RocksDb 权重常量的保守手写占位值。
@@ -58,4 +62,22 @@ impl<T> WeightInfo for SubstrateWeight<T> {
             .saturating_add(w.writes(1))
             .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(weeks.into()))
     }
+
+    fn record_offering(weeks: u32) -> Weight {
+        let w = RocksDbWeight::get();
+        // 回执树追加与排行重排为 O(深度)/O(N) 内存计算，按固定上限估算；
+        // 最坏情况下一次追加补全 RECEIPT_TREE_DEPTH 个内部节点
+        Weight::from_parts(120_000_000, 0)
+            .saturating_add(w.reads(8))
+            .saturating_add(w.writes(9 + crate::RECEIPT_TREE_DEPTH as u64))
+            .saturating_add(Self::mark_weekly_active(weeks))
+    }
+
+    fn prune_week(limit: u32) -> Weight {
+        let w = RocksDbWeight::get();
+        Weight::from_parts(20_000, 0)
+            .saturating_add(w.reads(1))
+            .saturating_add(w.reads_writes(2, 2).saturating_mul(limit.into()))
+            .saturating_add(w.writes(3).saturating_mul(limit.into()))
+    }
 }
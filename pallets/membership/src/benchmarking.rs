@@ -38,5 +38,13 @@ mod benchmarks {
 		set_member_discount(RawOrigin::Root, 30);
 	}
 
+	#[benchmark]
+	fn set_level_benefits() {
+		let benefits = BenefitConfig::default_for(MembershipLevel::Year10);
+
+		#[extrinsic_call]
+		set_level_benefits(RawOrigin::Root, 3, benefits);
+	}
+
 	impl_benchmark_test_suite!(Membership, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -0,0 +1,130 @@
+//! # 会员权益引擎（benefits）
+//!
+//! 函数级中文注释：🆕 会员等级 → 跨 pallet 权益
+//!
+//! - 权益表按等级配置（`LevelBenefits`），治理未设置时使用 `BenefitConfig::default_for`；
+//! - 各业务 pallet 通过 `MembershipBenefits` trait 读取，Runtime 注入 `pallet_membership::Pallet<Runtime>`；
+//! - 仅有效会员（未过期且满足持币门槛）享有权益；
+//! - 免费 AI 解读按权益周期（`BenefitPeriod`）计次，跨周期自动重置。
+
+use crate::pallet::*;
+use crate::types::{BenefitConfig, MemberBenefitsView};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{SaturatedConversion, Saturating};
+
+/// 函数级详细中文注释：会员权益接口（供其他 pallet 通过 Config 注入）
+///
+/// 空实现 `()` 表示不接入会员系统，所有账户均无权益。
+pub trait MembershipBenefits<AccountId> {
+	/// IPFS Pin 费用折扣（0-100，百分比）
+	fn pin_discount_percent(who: &AccountId) -> u8;
+
+	/// NFT 市场平台手续费折扣（0-100，百分比）
+	fn nft_fee_discount_percent(who: &AccountId) -> u8;
+
+	/// 防刷每日限额加成（百分比）
+	fn anti_spam_bonus_percent(who: &AccountId) -> u16;
+
+	/// 尝试使用一次免费 AI 解读（成功返回 true 并扣减本周期剩余次数）
+	fn try_use_free_ai_interpretation(who: &AccountId) -> bool;
+}
+
+/// 函数级详细中文注释：空实现（未接入会员系统时使用）
+impl<AccountId> MembershipBenefits<AccountId> for () {
+	fn pin_discount_percent(_who: &AccountId) -> u8 {
+		0
+	}
+
+	fn nft_fee_discount_percent(_who: &AccountId) -> u8 {
+		0
+	}
+
+	fn anti_spam_bonus_percent(_who: &AccountId) -> u16 {
+		0
+	}
+
+	fn try_use_free_ai_interpretation(_who: &AccountId) -> bool {
+		false
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// 函数级中文注释：账户当前生效的权益（非会员 / 会员失效返回全 0）
+	pub fn benefits_of(who: &T::AccountId) -> BenefitConfig {
+		match Memberships::<T>::get(who) {
+			Some(membership) if Self::is_member_valid(who) => {
+				LevelBenefits::<T>::get(membership.level)
+					.unwrap_or_else(|| BenefitConfig::default_for(membership.level))
+			},
+			_ => BenefitConfig::none(),
+		}
+	}
+
+	/// 函数级中文注释：当前权益周期编号（区块号 / 周期长度）
+	fn benefit_period() -> u32 {
+		let now: u32 = <frame_system::Pallet<T>>::block_number().saturated_into();
+		let period: u32 = T::BenefitPeriod::get().saturated_into();
+		now / period.max(1)
+	}
+
+	/// 函数级中文注释：本周期已使用的免费 AI 解读次数
+	fn free_ai_used(who: &T::AccountId) -> u32 {
+		let (period, used) = FreeAiUsage::<T>::get(who);
+		if period == Self::benefit_period() {
+			used
+		} else {
+			0
+		}
+	}
+
+	/// 函数级中文注释：权益视图（Runtime API 使用）
+	pub fn member_benefits(who: &T::AccountId) -> MemberBenefitsView<BlockNumberFor<T>> {
+		let membership = Memberships::<T>::get(who);
+		let benefits = Self::benefits_of(who);
+		MemberBenefitsView {
+			level: membership.as_ref().map(|m| m.level),
+			active: Self::is_member_valid(who),
+			valid_until: membership.map(|m| m.valid_until),
+			benefits,
+			free_ai_remaining: benefits
+				.free_ai_interpretations
+				.saturating_sub(Self::free_ai_used(who)),
+		}
+	}
+
+	/// 函数级中文注释：等级权益（治理设置值或默认值）
+	pub fn level_benefits(level: crate::types::MembershipLevel) -> BenefitConfig {
+		LevelBenefits::<T>::get(level).unwrap_or_else(|| BenefitConfig::default_for(level))
+	}
+}
+
+/// 函数级详细中文注释：会员权益接口实现
+impl<T: Config> MembershipBenefits<T::AccountId> for Pallet<T> {
+	fn pin_discount_percent(who: &T::AccountId) -> u8 {
+		Self::benefits_of(who).pin_discount_percent.min(100)
+	}
+
+	fn nft_fee_discount_percent(who: &T::AccountId) -> u8 {
+		Self::benefits_of(who).nft_fee_discount_percent.min(100)
+	}
+
+	fn anti_spam_bonus_percent(who: &T::AccountId) -> u16 {
+		Self::benefits_of(who).anti_spam_bonus_percent
+	}
+
+	fn try_use_free_ai_interpretation(who: &T::AccountId) -> bool {
+		let quota = Self::benefits_of(who).free_ai_interpretations;
+		let used = Self::free_ai_used(who);
+		if used >= quota {
+			return false;
+		}
+
+		let used = used.saturating_add(1);
+		FreeAiUsage::<T>::insert(who, (Self::benefit_period(), used));
+		Self::deposit_event(Event::FreeAiInterpretationUsed {
+			who: who.clone(),
+			remaining: quota.saturating_sub(used),
+		});
+		true
+	}
+}
@@ -51,6 +51,12 @@
 //! - `is_member_valid`: 检查账户是否为有效会员
 //! - `get_member_generations`: 获取会员可拿代数
 //! - `get_discount`: 获取会员折扣比例
+//! - 🆕 `benefits_of` / `member_benefits`: 查询会员权益（亦可通过 `MembershipBenefitsApi` Runtime API）
+//!
+//! ### 🆕 会员权益引擎
+//! - 按等级配置的权益表（`set_level_benefits`，治理可调）
+//! - 通过 `MembershipBenefits` trait 向其他 pallet 提供：IPFS Pin 折扣、免费 AI 解读、
+//!   逝者作品防刷限额加成、NFT 市场手续费折扣
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -80,6 +86,11 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+// 🆕 会员权益引擎
+mod benefits;
+pub use benefits::MembershipBenefits;
+pub mod runtime_api;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -166,6 +177,10 @@ pub mod pallet {
 	#[pallet::constant]
 	type MinHoldingValueCents: Get<u64>;
 
+	/// 🆕 会员权益周期（区块数，免费 AI 解读按周期计次）
+	#[pallet::constant]
+	type BenefitPeriod: Get<BlockNumberFor<Self>>;
+
 	/// 权重信息
     type WeightInfo: WeightInfo;
 }
@@ -211,6 +226,27 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// 🆕 会员权益表（按等级）
+	/// 如果未设置，使用 BenefitConfig::default_for 的默认值
+	#[pallet::storage]
+	pub type LevelBenefits<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		MembershipLevel,
+		BenefitConfig,
+		OptionQuery,
+	>;
+
+	/// 🆕 免费 AI 解读使用记录：账户 → (权益周期编号, 本周期已用次数)
+	#[pallet::storage]
+	pub type FreeAiUsage<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		(u32, u32),
+		ValueQuery,
+	>;
+
 	/// 创世配置
 	/// 
 	/// 函数级中文注释：暂时禁用创世配置，避免 serde 编译问题，待后续重构
@@ -297,6 +333,18 @@ pub mod pallet {
 		level_id: u8,
 		fallback_price: BalanceOf<T>,
 	},
+	/// 🆕 等级权益已更新
+	/// [会员等级ID, 新权益配置]
+	LevelBenefitsUpdated {
+		level_id: u8,
+		benefits: BenefitConfig,
+	},
+	/// 🆕 免费 AI 解读已使用
+	/// [会员, 本周期剩余次数]
+	FreeAiInterpretationUsed {
+		who: T::AccountId,
+		remaining: u32,
+	},
 }
 
     #[pallet::error]
@@ -361,6 +409,10 @@ pub mod pallet {
 		InvalidPercents,
 		/// 活跃提案过多
 		TooManyActiveProposals,
+		/// 🆕 权益配置超出范围（折扣 0-100，防刷加成 ≤ 1000%）
+		InvalidBenefitConfig,
+		/// 🆕 会员等级编号无效（必须 0-3）
+		InvalidLevel,
     }
 
     #[pallet::call]
@@ -831,6 +883,39 @@ pub mod pallet {
 		
 		Ok(())
 	}
+
+	/// 函数级中文注释：🆕 设置等级权益（治理）
+	///
+	/// # 参数
+	/// - `origin`: 治理起源（Root 或委员会 2/3 多数）
+	/// - `level_id`: 会员等级 (0=Year1, 1=Year3, 2=Year5, 3=Year10)
+	/// - `benefits`: 权益配置
+	///
+	/// # 错误
+	/// - `InvalidLevel`: 等级编号不在 0-3 范围内
+	/// - `InvalidBenefitConfig`: 折扣超过 100% 或防刷加成超过 1000%
+	#[pallet::call_index(6)]
+	#[pallet::weight(T::WeightInfo::set_level_benefits())]
+	pub fn set_level_benefits(
+		origin: OriginFor<T>,
+		level_id: u8,
+		benefits: BenefitConfig,
+	) -> DispatchResult {
+		T::GovernanceOrigin::ensure_origin(origin)?;
+
+		let level = match level_id {
+			0 => MembershipLevel::Year1,
+			1 => MembershipLevel::Year3,
+			2 => MembershipLevel::Year5,
+			3 => MembershipLevel::Year10,
+			_ => return Err(Error::<T>::InvalidLevel.into()),
+		};
+		ensure!(benefits.is_valid(), Error::<T>::InvalidBenefitConfig);
+
+		LevelBenefits::<T>::insert(level, benefits);
+		Self::deposit_event(Event::LevelBenefitsUpdated { level_id, benefits });
+		Ok(())
+	}
 }
 
 /// 内部辅助函数
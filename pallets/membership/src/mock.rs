@@ -105,6 +105,7 @@ impl pallet_membership::Config for Test {
 	type GovernanceOrigin = frame_system::EnsureRoot<u64>;
 	type MinMembershipPrice = MinMembershipPrice;
 	type MaxMembershipPrice = MaxMembershipPrice;
+	type BenefitPeriod = ConstU64<432_000>;
 	type WeightInfo = ();
 }
 
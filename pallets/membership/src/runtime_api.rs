@@ -0,0 +1,27 @@
+//! Runtime API 定义
+//!
+//! 本模块定义了会员权益查询的 Runtime API：一次调用返回账户当前生效的全部权益
+//! （IPFS Pin 折扣、免费 AI 解读剩余次数、防刷限额加成、NFT 手续费折扣）。
+
+use crate::types::{BenefitConfig, MemberBenefitsView};
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	/// 会员权益 Runtime API
+	///
+	/// 非会员或会员失效（过期 / 持币不足）时权益全为 0。
+	pub trait MembershipBenefitsApi<AccountId, BlockNumber>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// 查询账户当前生效的权益
+		fn member_benefits(who: AccountId) -> MemberBenefitsView<BlockNumber>;
+
+		/// 查询等级权益配置
+		///
+		/// # 参数
+		/// - `level_id`: 会员等级 (0=Year1, 1=Year3, 2=Year5, 3=Year10)
+		fn level_benefits(level_id: u8) -> Option<BenefitConfig>;
+	}
+}
@@ -374,3 +374,24 @@ fn set_price_requires_root() {
 		);
 	});
 }
+
+/// 🆕 测试设置等级权益：无效等级与越界配置分别返回专用错误
+#[test]
+fn set_level_benefits_rejects_invalid_level_and_config() {
+	new_test_ext().execute_with(|| {
+		let benefits = crate::BenefitConfig::default_for(MembershipLevel::Year3);
+
+		assert_noop!(
+			Membership::set_level_benefits(RuntimeOrigin::root(), 4, benefits),
+			Error::<Test>::InvalidLevel
+		);
+
+		let invalid = crate::BenefitConfig { pin_discount_percent: 101, ..benefits };
+		assert_noop!(
+			Membership::set_level_benefits(RuntimeOrigin::root(), 1, invalid),
+			Error::<Test>::InvalidBenefitConfig
+		);
+
+		assert_ok!(Membership::set_level_benefits(RuntimeOrigin::root(), 1, benefits));
+	});
+}
@@ -1,5 +1,5 @@
 /// 会员系统类型定义
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
 
@@ -126,3 +126,87 @@ pub struct MembershipInfo<AccountId, BlockNumber> {
 /// 例如：20 表示 20%，即2折
 pub type DiscountPercent = u8;
 
+/// 🆕 会员权益配置（按等级，治理可调）
+///
+/// 函数级中文注释：各业务 pallet 通过 `MembershipBenefits` trait 读取，自身不感知会员等级
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BenefitConfig {
+	/// IPFS Pin 费用折扣（0-100，百分比）
+	pub pin_discount_percent: u8,
+	/// 每个权益周期免费 AI 解读次数
+	pub free_ai_interpretations: u32,
+	/// 防刷每日限额加成（百分比，例如 100 表示限额翻倍，上限 1000）
+	pub anti_spam_bonus_percent: u16,
+	/// NFT 市场平台手续费折扣（0-100，百分比）
+	pub nft_fee_discount_percent: u8,
+}
+
+impl BenefitConfig {
+	/// 防刷限额加成上限（百分比）
+	pub const MAX_ANTI_SPAM_BONUS: u16 = 1000;
+
+	/// 函数级中文注释：各等级默认权益（治理未设置时使用）
+	pub fn default_for(level: MembershipLevel) -> Self {
+		match level {
+			MembershipLevel::Year1 => Self {
+				pin_discount_percent: 10,
+				free_ai_interpretations: 2,
+				anti_spam_bonus_percent: 50,
+				nft_fee_discount_percent: 10,
+			},
+			MembershipLevel::Year3 => Self {
+				pin_discount_percent: 20,
+				free_ai_interpretations: 5,
+				anti_spam_bonus_percent: 100,
+				nft_fee_discount_percent: 20,
+			},
+			MembershipLevel::Year5 => Self {
+				pin_discount_percent: 30,
+				free_ai_interpretations: 10,
+				anti_spam_bonus_percent: 150,
+				nft_fee_discount_percent: 30,
+			},
+			MembershipLevel::Year10 => Self {
+				pin_discount_percent: 50,
+				free_ai_interpretations: 20,
+				anti_spam_bonus_percent: 200,
+				nft_fee_discount_percent: 50,
+			},
+		}
+	}
+
+	/// 函数级中文注释：无权益（非会员 / 会员失效）
+	pub fn none() -> Self {
+		Self {
+			pin_discount_percent: 0,
+			free_ai_interpretations: 0,
+			anti_spam_bonus_percent: 0,
+			nft_fee_discount_percent: 0,
+		}
+	}
+
+	/// 函数级中文注释：校验配置范围
+	pub fn is_valid(&self) -> bool {
+		self.pin_discount_percent <= 100
+			&& self.nft_fee_discount_percent <= 100
+			&& self.anti_spam_bonus_percent <= Self::MAX_ANTI_SPAM_BONUS
+	}
+}
+
+/// 🆕 会员权益视图（Runtime API 返回）
+///
+/// 函数级中文注释：一次查询返回账户当前生效的全部权益及免费 AI 解读剩余次数
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MemberBenefitsView<BlockNumber> {
+	/// 会员等级（非会员为 None）
+	pub level: Option<MembershipLevel>,
+	/// 会员是否有效（未过期且满足持币门槛）；无效时 benefits 全为 0
+	pub active: bool,
+	/// 有效期至
+	pub valid_until: Option<BlockNumber>,
+	/// 当前生效的权益
+	pub benefits: BenefitConfig,
+	/// 本权益周期剩余免费 AI 解读次数
+	pub free_ai_remaining: u32,
+}
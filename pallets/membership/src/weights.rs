@@ -6,6 +6,7 @@ pub trait WeightInfo {
 	fn purchase_membership() -> Weight;
 	fn upgrade_to_year10() -> Weight;
 	fn set_member_discount() -> Weight;
+	fn set_level_benefits() -> Weight;
 }
 
 /// 默认权重实现
@@ -34,4 +35,11 @@ impl WeightInfo for () {
 		Weight::from_parts(10_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 1_000))
 	}
+
+	/// 🆕 设置等级权益权重
+	/// - 写入：等级权益配置（LevelBenefits）
+	fn set_level_benefits() -> Weight {
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1_500))
+	}
 }
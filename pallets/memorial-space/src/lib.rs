@@ -2,14 +2,38 @@
 
 //! # Pallet Memorial Space
 //!
-//! 虚拟纪念空间管理 pallet - 最小可行版本
+//! 虚拟纪念空间管理 pallet
+//!
+//! **核心功能**：
+//! 1. 空间主体：单个逝者纪念馆 / 家族纪念堂（创建者须为全部关联逝者的 owner）
+//! 2. 布局：场景布局/主题 CID（由前端解释渲染）
+//! 3. 摆放物：供奉记录、相册照片、宠物，带场景坐标
+//! 4. 访问控制：公开 / 关注者 / 仅邀请 / 口令（链下派生密钥签名解锁，口令不上链）
+//! 5. 留言簿：访客留言经空间管理员审核后公开
 
 pub use pallet::*;
 
+pub mod types;
+pub use types::{
+	AccessMode, GuestbookEntry, GuestbookStatus, MemorialSpace, PlacedObject, Placement,
+	SpaceObject, SpaceObjectProvider, SpaceSubject, SpaceSubjectProvider, VisitorGrant,
+	MAX_FAMILY_MEMBERS,
+};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 #[frame_support::pallet]
 pub mod pallet {
+	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
+	use sp_core::sr25519;
+	use sp_runtime::traits::Verify;
+	use sp_std::vec::Vec;
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
@@ -18,31 +42,145 @@ pub mod pallet {
 	pub trait Config: frame_system::Config {
 		#[allow(deprecated)]
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// 空间主体信息（逝者所有者、关注关系）
+		type Subjects: SpaceSubjectProvider<Self::AccountId>;
+
+		/// 摆放物信息（供奉记录、相册、宠物）
+		type Objects: SpaceObjectProvider<Self::AccountId>;
+
+		/// 布局 / 留言 CID 最大长度
+		#[pallet::constant]
+		type MaxCidLen: Get<u32>;
+
+		/// 每个空间最多管理员数（不含所有者）
+		#[pallet::constant]
+		type MaxAdmins: Get<u32>;
+
+		/// 每个空间最多摆放物数
+		#[pallet::constant]
+		type MaxObjectsPerSpace: Get<u32>;
+
+		/// 每个空间最多待审核留言数（防刷屏）
+		#[pallet::constant]
+		type MaxPendingGuestbookEntries: Get<u32>;
+
+		/// 每位逝者最多关联的空间数
+		#[pallet::constant]
+		type MaxSpacesPerDeceased: Get<u32>;
 	}
 
 	/// 下一个空间 ID
 	#[pallet::storage]
 	pub type NextSpaceId<T: Config> = StorageValue<_, u64, ValueQuery>;
 
-	/// 空间所有者映射 (space_id => owner)
+	/// 空间详情 (space_id => space)
+	#[pallet::storage]
+	pub type Spaces<T: Config> = StorageMap<_, Blake2_128Concat, u64, MemorialSpace<T>, OptionQuery>;
+
+	/// 逝者关联的空间索引 (deceased_id => [space_id])
+	#[pallet::storage]
+	pub type SpacesByDeceased<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		BoundedVec<u64, T::MaxSpacesPerDeceased>,
+		ValueQuery,
+	>;
+
+	/// 获准访问的访客（邀请或口令解锁） (space_id, who) => grant
 	#[pallet::storage]
-	pub type SpaceOwners<T: Config> = StorageMap<
+	pub type SpaceVisitors<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		u64,
+		Blake2_128Concat,
 		T::AccountId,
+		VisitorGrant,
+		OptionQuery,
+	>;
+
+	/// 下一个摆放物 ID（按空间计数）
+	#[pallet::storage]
+	pub type NextObjectId<T: Config> = StorageMap<_, Blake2_128Concat, u64, u32, ValueQuery>;
+
+	/// 摆放物 (space_id, object_id) => object
+	#[pallet::storage]
+	pub type SpaceObjects<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Twox64Concat,
+		u32,
+		PlacedObject<T>,
+		OptionQuery,
+	>;
+
+	/// 下一个留言 ID（按空间计数）
+	#[pallet::storage]
+	pub type NextGuestbookEntryId<T: Config> = StorageMap<_, Blake2_128Concat, u64, u32, ValueQuery>;
+
+	/// 留言簿 (space_id, entry_id) => entry
+	#[pallet::storage]
+	pub type Guestbook<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Twox64Concat,
+		u32,
+		GuestbookEntry<T>,
 		OptionQuery,
 	>;
 
+	/// 待审核留言数 (space_id => count)
+	#[pallet::storage]
+	pub type PendingGuestbookCount<T: Config> = StorageMap<_, Blake2_128Concat, u64, u32, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// 纪念空间已创建 [space_id, deceased_id, owner]
+		/// 纪念空间已创建（deceased_id 为主位逝者）
 		SpaceCreated {
 			space_id: u64,
 			deceased_id: u64,
 			owner: T::AccountId,
 		},
+		/// 布局已更新
+		LayoutUpdated { space_id: u64 },
+		/// 访问模式已变更（0=公开, 1=关注者, 2=仅邀请, 3=口令）；
+		/// password_epoch 为当前口令轮次（更换/取消口令时递增）
+		AccessModeChanged { space_id: u64, mode: u8, password_epoch: u32 },
+		/// 管理员已添加
+		AdminAdded { space_id: u64, who: T::AccountId },
+		/// 管理员已移除
+		AdminRemoved { space_id: u64, who: T::AccountId },
+		/// 访客已获邀请
+		VisitorInvited { space_id: u64, who: T::AccountId },
+		/// 访客资格已撤销
+		VisitorRevoked { space_id: u64, who: T::AccountId },
+		/// 访客已通过口令解锁
+		SpaceUnlocked { space_id: u64, who: T::AccountId },
+		/// 物品已摆放
+		ObjectPlaced {
+			space_id: u64,
+			object_id: u32,
+			object: SpaceObject,
+			placed_by: T::AccountId,
+		},
+		/// 物品已移动
+		ObjectMoved { space_id: u64, object_id: u32 },
+		/// 物品已移除
+		ObjectRemoved { space_id: u64, object_id: u32 },
+		/// 留言已提交（待审核）
+		GuestbookSigned {
+			space_id: u64,
+			entry_id: u32,
+			author: T::AccountId,
+		},
+		/// 留言已通过审核
+		GuestbookEntryApproved { space_id: u64, entry_id: u32 },
+		/// 留言已删除（审核拒绝或管理员删除）
+		GuestbookEntryRemoved { space_id: u64, entry_id: u32 },
 	}
 
 	#[pallet::error]
@@ -51,26 +189,94 @@ pub mod pallet {
 		SpaceNotFound,
 		/// 无权限
 		NoPermission,
+		/// 逝者不存在
+		DeceasedNotFound,
+		/// 不是逝者的所有者
+		NotDeceasedOwner,
+		/// 家族空间至少关联一位逝者且不能重复
+		InvalidSubject,
+		/// CID 为空或过长
+		BadCid,
+		/// 管理员数量已达上限
+		TooManyAdmins,
+		/// 已是管理员
+		AlreadyAdmin,
+		/// 不是管理员
+		NotAdmin,
+		/// 逝者关联的空间数已达上限
+		TooManySpaces,
+		/// 摆放物数量已达上限
+		TooManyObjects,
+		/// 摆放物不存在
+		ObjectNotFound,
+		/// 摆放物无效（不存在或不属于空间内的逝者）
+		InvalidObject,
+		/// 空间不是口令模式
+		NotPasswordProtected,
+		/// 口令签名无效
+		WrongPassword,
+		/// 无访问资格
+		AccessDenied,
+		/// 留言不存在
+		EntryNotFound,
+		/// 待审核留言已达上限
+		TooManyPendingEntries,
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// 创建纪念空间（占位实现）
+		/// 函数级详细中文注释：创建纪念空间
+		///
+		/// ### 参数
+		/// - `subject`: 单个逝者或家族（多位逝者，首位为主位）
+		/// - `layout_cid`: 布局/主题 CID
+		/// - `access`: 访客访问模式
+		///
+		/// ### 权限
+		/// - 调用者须为全部关联逝者的 owner
 		#[pallet::call_index(0)]
 		#[pallet::weight(Weight::from_parts(10_000, 0))]
 		pub fn create_space(
 			origin: OriginFor<T>,
-			deceased_id: u64,
+			subject: SpaceSubject,
+			layout_cid: Vec<u8>,
+			access: AccessMode,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
+			let ids = subject.deceased_ids();
+			ensure!(!ids.is_empty(), Error::<T>::InvalidSubject);
+			for (i, id) in ids.iter().enumerate() {
+				ensure!(!ids[..i].contains(id), Error::<T>::InvalidSubject);
+				let owner = T::Subjects::deceased_owner(*id).ok_or(Error::<T>::DeceasedNotFound)?;
+				ensure!(owner == who, Error::<T>::NotDeceasedOwner);
+			}
+			let layout_cid = Self::bounded_cid(layout_cid)?;
+
 			let space_id = NextSpaceId::<T>::mutate(|id| {
 				let current = *id;
 				*id = id.saturating_add(1);
 				current
 			});
+			for id in ids {
+				SpacesByDeceased::<T>::try_mutate(id, |list| {
+					list.try_push(space_id).map_err(|_| Error::<T>::TooManySpaces)
+				})?;
+			}
 
-			SpaceOwners::<T>::insert(space_id, &who);
+			let now = <frame_system::Pallet<T>>::block_number();
+			let deceased_id = ids[0];
+			Spaces::<T>::insert(space_id, MemorialSpace::<T> {
+				owner: who.clone(),
+				subject,
+				layout_cid,
+				access,
+				admins: BoundedVec::default(),
+				object_count: 0,
+				password_epoch: 0,
+				created: now,
+				updated: now,
+			});
 
 			Self::deposit_event(Event::SpaceCreated {
 				space_id,
@@ -80,5 +286,349 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// 函数级中文注释：更新布局/主题 CID（管理员）
+		#[pallet::call_index(1)]
+		#[pallet::weight(Weight::from_parts(10_000, 0))]
+		pub fn update_layout(origin: OriginFor<T>, space_id: u64, layout_cid: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let layout_cid = Self::bounded_cid(layout_cid)?;
+			Spaces::<T>::try_mutate(space_id, |maybe| -> DispatchResult {
+				let space = maybe.as_mut().ok_or(Error::<T>::SpaceNotFound)?;
+				ensure!(Self::is_admin_of(space, &who), Error::<T>::NoPermission);
+				space.layout_cid = layout_cid;
+				space.updated = <frame_system::Pallet<T>>::block_number();
+				Ok(())
+			})?;
+			Self::deposit_event(Event::LayoutUpdated { space_id });
+			Ok(())
+		}
+
+		/// 函数级中文注释：设置访问模式（管理员）
+		/// - 口令模式传入链下由口令与随机 salt 派生的 sr25519 公钥及该 salt
+		/// - 原为口令模式且口令变更或取消时，口令轮次递增，已解锁访客全部失效
+		#[pallet::call_index(2)]
+		#[pallet::weight(Weight::from_parts(10_000, 0))]
+		pub fn set_access_mode(origin: OriginFor<T>, space_id: u64, access: AccessMode) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let password_epoch = Spaces::<T>::try_mutate(space_id, |maybe| -> Result<u32, DispatchError> {
+				let space = maybe.as_mut().ok_or(Error::<T>::SpaceNotFound)?;
+				ensure!(Self::is_admin_of(space, &who), Error::<T>::NoPermission);
+				if matches!(space.access, AccessMode::Password { .. }) && space.access != access {
+					space.password_epoch = space.password_epoch.saturating_add(1);
+				}
+				space.access = access;
+				space.updated = <frame_system::Pallet<T>>::block_number();
+				Ok(space.password_epoch)
+			})?;
+			Self::deposit_event(Event::AccessModeChanged {
+				space_id,
+				mode: access.code(),
+				password_epoch,
+			});
+			Ok(())
+		}
+
+		/// 函数级中文注释：添加管理员（仅所有者）
+		#[pallet::call_index(3)]
+		#[pallet::weight(Weight::from_parts(10_000, 0))]
+		pub fn add_admin(origin: OriginFor<T>, space_id: u64, admin: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Spaces::<T>::try_mutate(space_id, |maybe| -> DispatchResult {
+				let space = maybe.as_mut().ok_or(Error::<T>::SpaceNotFound)?;
+				ensure!(space.owner == who, Error::<T>::NoPermission);
+				ensure!(space.owner != admin && !space.admins.contains(&admin), Error::<T>::AlreadyAdmin);
+				space.admins.try_push(admin.clone()).map_err(|_| Error::<T>::TooManyAdmins)?;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::AdminAdded { space_id, who: admin });
+			Ok(())
+		}
+
+		/// 函数级中文注释：移除管理员（所有者，或管理员自行退出）
+		#[pallet::call_index(4)]
+		#[pallet::weight(Weight::from_parts(10_000, 0))]
+		pub fn remove_admin(origin: OriginFor<T>, space_id: u64, admin: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Spaces::<T>::try_mutate(space_id, |maybe| -> DispatchResult {
+				let space = maybe.as_mut().ok_or(Error::<T>::SpaceNotFound)?;
+				ensure!(space.owner == who || admin == who, Error::<T>::NoPermission);
+				let pos = space.admins.iter().position(|a| *a == admin).ok_or(Error::<T>::NotAdmin)?;
+				space.admins.remove(pos);
+				Ok(())
+			})?;
+			Self::deposit_event(Event::AdminRemoved { space_id, who: admin });
+			Ok(())
+		}
+
+		/// 函数级中文注释：邀请访客（管理员；任何访问模式下均可预先授权）
+		#[pallet::call_index(5)]
+		#[pallet::weight(Weight::from_parts(10_000, 0))]
+		pub fn invite_visitor(origin: OriginFor<T>, space_id: u64, visitor: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let space = Spaces::<T>::get(space_id).ok_or(Error::<T>::SpaceNotFound)?;
+			ensure!(Self::is_admin_of(&space, &who), Error::<T>::NoPermission);
+			SpaceVisitors::<T>::insert(space_id, &visitor, VisitorGrant::Invited);
+			Self::deposit_event(Event::VisitorInvited { space_id, who: visitor });
+			Ok(())
+		}
+
+		/// 函数级中文注释：撤销访客资格（管理员）
+		#[pallet::call_index(6)]
+		#[pallet::weight(Weight::from_parts(10_000, 0))]
+		pub fn revoke_visitor(origin: OriginFor<T>, space_id: u64, visitor: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let space = Spaces::<T>::get(space_id).ok_or(Error::<T>::SpaceNotFound)?;
+			ensure!(Self::is_admin_of(&space, &who), Error::<T>::NoPermission);
+			SpaceVisitors::<T>::remove(space_id, &visitor);
+			Self::deposit_event(Event::VisitorRevoked { space_id, who: visitor });
+			Ok(())
+		}
+
+		/// 函数级详细中文注释：口令解锁（口令模式）
+		///
+		/// 访客在链下用口令与空间 `salt` 派生 sr25519 私钥，对 `unlock_payload(space_id, epoch, who)`
+		/// 签名后提交；链上用空间公钥验签，口令本身不上链。签名绑定调用者与口令轮次，无法重放。
+		#[pallet::call_index(7)]
+		#[pallet::weight(Weight::from_parts(50_000, 0))]
+		pub fn unlock_space(origin: OriginFor<T>, space_id: u64, signature: [u8; 64]) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let space = Spaces::<T>::get(space_id).ok_or(Error::<T>::SpaceNotFound)?;
+			let AccessMode::Password { key, .. } = space.access else {
+				return Err(Error::<T>::NotPasswordProtected.into());
+			};
+			let payload = Self::unlock_payload(space_id, space.password_epoch, &who);
+			ensure!(
+				sr25519::Signature::from_raw(signature).verify(&payload[..], &sr25519::Public::from_raw(key)),
+				Error::<T>::WrongPassword
+			);
+			// 已受邀访客无需降级为口令访客
+			if SpaceVisitors::<T>::get(space_id, &who) != Some(VisitorGrant::Invited) {
+				SpaceVisitors::<T>::insert(space_id, &who, VisitorGrant::Unlocked(space.password_epoch));
+			}
+			Self::deposit_event(Event::SpaceUnlocked { space_id, who });
+			Ok(())
+		}
+
+		/// 函数级详细中文注释：摆放物品
+		///
+		/// ### 权限
+		/// - 管理员可摆放任何有效物品
+		/// - 有访问资格的访客只能摆放自己的供奉记录
+		///
+		/// ### 校验
+		/// - 供奉记录须供奉给空间内的逝者
+		/// - 相册须属于空间内的逝者（指定照片时须在该相册中）
+		/// - 宠物须为摆放人或空间所有者所有
+		#[pallet::call_index(8)]
+		#[pallet::weight(Weight::from_parts(10_000, 0))]
+		pub fn place_object(
+			origin: OriginFor<T>,
+			space_id: u64,
+			object: SpaceObject,
+			placement: Placement,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut space = Spaces::<T>::get(space_id).ok_or(Error::<T>::SpaceNotFound)?;
+			ensure!(space.object_count < T::MaxObjectsPerSpace::get(), Error::<T>::TooManyObjects);
+
+			let is_admin = Self::is_admin_of(&space, &who);
+			let in_space = |deceased_id: u64| space.subject.deceased_ids().contains(&deceased_id);
+			match object {
+				SpaceObject::Offering(offering_id) => {
+					let (offerer, deceased_id) =
+						T::Objects::offering_of(offering_id).ok_or(Error::<T>::InvalidObject)?;
+					ensure!(in_space(deceased_id), Error::<T>::InvalidObject);
+					if !is_admin {
+						ensure!(offerer == who, Error::<T>::NoPermission);
+						ensure!(Self::can_visit_space(&space, space_id, &who), Error::<T>::AccessDenied);
+					}
+				},
+				SpaceObject::Photo { album_id, media_id } => {
+					ensure!(is_admin, Error::<T>::NoPermission);
+					let deceased_id =
+						T::Objects::album_deceased(album_id, media_id).ok_or(Error::<T>::InvalidObject)?;
+					ensure!(in_space(deceased_id), Error::<T>::InvalidObject);
+				},
+				SpaceObject::Pet(pet_id) => {
+					ensure!(is_admin, Error::<T>::NoPermission);
+					let pet_owner = T::Objects::pet_owner(pet_id).ok_or(Error::<T>::InvalidObject)?;
+					ensure!(pet_owner == who || pet_owner == space.owner, Error::<T>::InvalidObject);
+				},
+			}
+
+			let object_id = NextObjectId::<T>::mutate(space_id, |id| {
+				let current = *id;
+				*id = id.saturating_add(1);
+				current
+			});
+			SpaceObjects::<T>::insert(space_id, object_id, PlacedObject::<T> {
+				object,
+				placement,
+				placed_by: who.clone(),
+				placed_at: <frame_system::Pallet<T>>::block_number(),
+			});
+			space.object_count = space.object_count.saturating_add(1);
+			Spaces::<T>::insert(space_id, space);
+
+			Self::deposit_event(Event::ObjectPlaced {
+				space_id,
+				object_id,
+				object,
+				placed_by: who,
+			});
+			Ok(())
+		}
+
+		/// 函数级中文注释：移动物品（管理员）
+		#[pallet::call_index(9)]
+		#[pallet::weight(Weight::from_parts(10_000, 0))]
+		pub fn move_object(
+			origin: OriginFor<T>,
+			space_id: u64,
+			object_id: u32,
+			placement: Placement,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let space = Spaces::<T>::get(space_id).ok_or(Error::<T>::SpaceNotFound)?;
+			ensure!(Self::is_admin_of(&space, &who), Error::<T>::NoPermission);
+			SpaceObjects::<T>::try_mutate(space_id, object_id, |maybe| -> DispatchResult {
+				let placed = maybe.as_mut().ok_or(Error::<T>::ObjectNotFound)?;
+				placed.placement = placement;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::ObjectMoved { space_id, object_id });
+			Ok(())
+		}
+
+		/// 函数级中文注释：移除物品（管理员或摆放人）
+		#[pallet::call_index(10)]
+		#[pallet::weight(Weight::from_parts(10_000, 0))]
+		pub fn remove_object(origin: OriginFor<T>, space_id: u64, object_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut space = Spaces::<T>::get(space_id).ok_or(Error::<T>::SpaceNotFound)?;
+			let placed = SpaceObjects::<T>::get(space_id, object_id).ok_or(Error::<T>::ObjectNotFound)?;
+			ensure!(
+				placed.placed_by == who || Self::is_admin_of(&space, &who),
+				Error::<T>::NoPermission
+			);
+			SpaceObjects::<T>::remove(space_id, object_id);
+			space.object_count = space.object_count.saturating_sub(1);
+			Spaces::<T>::insert(space_id, space);
+			Self::deposit_event(Event::ObjectRemoved { space_id, object_id });
+			Ok(())
+		}
+
+		/// 函数级中文注释：签写留言簿（须有访问资格；管理员审核后公开）
+		#[pallet::call_index(11)]
+		#[pallet::weight(Weight::from_parts(10_000, 0))]
+		pub fn sign_guestbook(origin: OriginFor<T>, space_id: u64, content_cid: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let space = Spaces::<T>::get(space_id).ok_or(Error::<T>::SpaceNotFound)?;
+			ensure!(Self::can_visit_space(&space, space_id, &who), Error::<T>::AccessDenied);
+			let content_cid = Self::bounded_cid(content_cid)?;
+
+			PendingGuestbookCount::<T>::try_mutate(space_id, |count| -> DispatchResult {
+				ensure!(*count < T::MaxPendingGuestbookEntries::get(), Error::<T>::TooManyPendingEntries);
+				*count = count.saturating_add(1);
+				Ok(())
+			})?;
+			let entry_id = NextGuestbookEntryId::<T>::mutate(space_id, |id| {
+				let current = *id;
+				*id = id.saturating_add(1);
+				current
+			});
+			Guestbook::<T>::insert(space_id, entry_id, GuestbookEntry::<T> {
+				author: who.clone(),
+				content_cid,
+				status: GuestbookStatus::Pending,
+				created: <frame_system::Pallet<T>>::block_number(),
+			});
+
+			Self::deposit_event(Event::GuestbookSigned { space_id, entry_id, author: who });
+			Ok(())
+		}
+
+		/// 函数级中文注释：审核留言（管理员）
+		/// - `approve=true`：待审核 → 已通过
+		/// - `approve=false`：删除留言（待审核或已通过均可删除）
+		#[pallet::call_index(12)]
+		#[pallet::weight(Weight::from_parts(10_000, 0))]
+		pub fn moderate_guestbook(
+			origin: OriginFor<T>,
+			space_id: u64,
+			entry_id: u32,
+			approve: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let space = Spaces::<T>::get(space_id).ok_or(Error::<T>::SpaceNotFound)?;
+			ensure!(Self::is_admin_of(&space, &who), Error::<T>::NoPermission);
+			let mut entry = Guestbook::<T>::get(space_id, entry_id).ok_or(Error::<T>::EntryNotFound)?;
+
+			if entry.status == GuestbookStatus::Pending {
+				PendingGuestbookCount::<T>::mutate(space_id, |count| *count = count.saturating_sub(1));
+			}
+			if approve {
+				entry.status = GuestbookStatus::Approved;
+				Guestbook::<T>::insert(space_id, entry_id, entry);
+				Self::deposit_event(Event::GuestbookEntryApproved { space_id, entry_id });
+			} else {
+				Guestbook::<T>::remove(space_id, entry_id);
+				Self::deposit_event(Event::GuestbookEntryRemoved { space_id, entry_id });
+			}
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// 函数级中文注释：是否为空间管理员（所有者视为管理员）
+		pub fn is_admin_of(space: &MemorialSpace<T>, who: &T::AccountId) -> bool {
+			space.owner == *who || space.admins.contains(who)
+		}
+
+		/// 函数级中文注释：是否可访问空间（供前端/其他模块查询）
+		pub fn can_visit(space_id: u64, who: &T::AccountId) -> bool {
+			Spaces::<T>::get(space_id)
+				.map(|space| Self::can_visit_space(&space, space_id, who))
+				.unwrap_or(false)
+		}
+
+		/// 函数级中文注释：口令解锁的待签名消息（空间、口令轮次、调用者）
+		pub fn unlock_payload(space_id: u64, password_epoch: u32, who: &T::AccountId) -> Vec<u8> {
+			(b"memspace_unlock", space_id, password_epoch, who).encode()
+		}
+
+		/// 函数级详细中文注释：访问资格判定
+		/// - 管理员与受邀访客在任何模式下均可访问
+		/// - 口令解锁的访客仅在口令模式且口令轮次未变更时可访问
+		/// - 关注者模式：关注了空间内任一逝者即可访问
+		fn can_visit_space(space: &MemorialSpace<T>, space_id: u64, who: &T::AccountId) -> bool {
+			if Self::is_admin_of(space, who) {
+				return true;
+			}
+			match SpaceVisitors::<T>::get(space_id, who) {
+				Some(VisitorGrant::Invited) => return true,
+				Some(VisitorGrant::Unlocked(epoch))
+					if epoch == space.password_epoch &&
+						matches!(space.access, AccessMode::Password { .. }) =>
+					return true,
+				_ => {},
+			}
+			match space.access {
+				AccessMode::Public => true,
+				AccessMode::Followers => space
+					.subject
+					.deceased_ids()
+					.iter()
+					.any(|id| T::Subjects::is_follower(who, *id)),
+				AccessMode::InviteOnly | AccessMode::Password { .. } => false,
+			}
+		}
+
+		/// 函数级中文注释：CID 非空且不超过 `MaxCidLen`
+		fn bounded_cid(cid: Vec<u8>) -> Result<BoundedVec<u8, T::MaxCidLen>, DispatchError> {
+			ensure!(!cid.is_empty(), Error::<T>::BadCid);
+			BoundedVec::try_from(cid).map_err(|_| Error::<T>::BadCid.into())
+		}
 	}
 }
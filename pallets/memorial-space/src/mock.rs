@@ -0,0 +1,94 @@
+//! 函数级中文注释：pallet-memorial-space 的 Mock Runtime，用于单元测试
+//!
+//! - 逝者 10、11 归 OWNER 所有，逝者 20 归 OTHER 所有；FOLLOWER 关注逝者 10
+//! - 供奉 100（VISITOR → 逝者 10）、101（VISITOR → 逝者 20）、102（FOLLOWER → 逝者 10）
+//! - 相册 200 属于逝者 10（含照片 1）；宠物 300 归 OWNER、301 归 OTHER
+
+use crate as pallet_memorial_space;
+use frame_support::{derive_impl, traits::ConstU32};
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+/// 空间所有者（逝者 10、11 的 owner）
+pub const OWNER: u64 = 1;
+/// 其他逝者（20）的 owner
+pub const OTHER: u64 = 2;
+/// 关注了逝者 10 的账户
+pub const FOLLOWER: u64 = 3;
+/// 普通访客
+pub const VISITOR: u64 = 4;
+/// 管理员候选
+pub const ADMIN: u64 = 5;
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		MemorialSpace: pallet_memorial_space,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+/// Mock 空间主体 - 固定的逝者所有者与关注关系
+pub struct MockSubjects;
+impl crate::SpaceSubjectProvider<u64> for MockSubjects {
+	fn deceased_owner(deceased_id: u64) -> Option<u64> {
+		match deceased_id {
+			10 | 11 => Some(OWNER),
+			20 => Some(OTHER),
+			_ => None,
+		}
+	}
+	fn is_follower(who: &u64, deceased_id: u64) -> bool {
+		*who == FOLLOWER && deceased_id == 10
+	}
+}
+
+/// Mock 摆放物 - 固定的供奉记录、相册与宠物
+pub struct MockObjects;
+impl crate::SpaceObjectProvider<u64> for MockObjects {
+	fn offering_of(offering_id: u64) -> Option<(u64, u64)> {
+		match offering_id {
+			100 => Some((VISITOR, 10)),
+			101 => Some((VISITOR, 20)),
+			102 => Some((FOLLOWER, 10)),
+			_ => None,
+		}
+	}
+	fn album_deceased(album_id: u64, media_id: Option<u64>) -> Option<u64> {
+		match (album_id, media_id) {
+			(200, None) | (200, Some(1)) => Some(10),
+			_ => None,
+		}
+	}
+	fn pet_owner(pet_id: u64) -> Option<u64> {
+		match pet_id {
+			300 => Some(OWNER),
+			301 => Some(OTHER),
+			_ => None,
+		}
+	}
+}
+
+impl pallet_memorial_space::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Subjects = MockSubjects;
+	type Objects = MockObjects;
+	type MaxCidLen = ConstU32<16>;
+	type MaxAdmins = ConstU32<2>;
+	type MaxObjectsPerSpace = ConstU32<3>;
+	type MaxPendingGuestbookEntries = ConstU32<2>;
+	type MaxSpacesPerDeceased = ConstU32<2>;
+}
+
+/// 函数级中文注释：创建测试环境（区块 1）
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,416 @@
+//! 函数级中文注释：pallet-memorial-space 单元测试
+
+use crate::{
+	mock::*, AccessMode, Error, Event, Guestbook, GuestbookStatus, PendingGuestbookCount,
+	Placement, SpaceObject, SpaceObjects, SpaceSubject, SpaceVisitors, Spaces, SpacesByDeceased,
+	VisitorGrant,
+};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::{sr25519, Pair};
+
+/// 辅助函数：OWNER 为逝者 10 创建指定访问模式的空间
+fn create_space(access: AccessMode) -> u64 {
+	let space_id = crate::NextSpaceId::<Test>::get();
+	assert_ok!(MemorialSpace::create_space(
+		RuntimeOrigin::signed(OWNER),
+		SpaceSubject::Deceased(10),
+		b"QmLayout".to_vec(),
+		access,
+	));
+	space_id
+}
+
+/// 辅助函数：口令派生的密钥对（测试中以口令直接作为种子，链下实现使用慢哈希 + salt）
+fn password_pair(password: &[u8; 32]) -> sr25519::Pair {
+	sr25519::Pair::from_seed(password)
+}
+
+fn password_mode(pair: &sr25519::Pair) -> AccessMode {
+	AccessMode::Password { key: pair.public().0, salt: [7u8; 16] }
+}
+
+/// 辅助函数：访客用口令密钥签名解锁
+fn unlock(space_id: u64, who: u64, pair: &sr25519::Pair) -> frame_support::dispatch::DispatchResult {
+	let epoch = Spaces::<Test>::get(space_id).unwrap().password_epoch;
+	let payload = MemorialSpace::unlock_payload(space_id, epoch, &who);
+	MemorialSpace::unlock_space(RuntimeOrigin::signed(who), space_id, pair.sign(&payload).0)
+}
+
+// ==================== 创建与管理员 ====================
+
+#[test]
+fn create_space_requires_deceased_owner() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			MemorialSpace::create_space(
+				RuntimeOrigin::signed(OTHER),
+				SpaceSubject::Deceased(10),
+				b"QmLayout".to_vec(),
+				AccessMode::Public,
+			),
+			Error::<Test>::NotDeceasedOwner
+		);
+		assert_noop!(
+			MemorialSpace::create_space(
+				RuntimeOrigin::signed(OWNER),
+				SpaceSubject::Deceased(99),
+				b"QmLayout".to_vec(),
+				AccessMode::Public,
+			),
+			Error::<Test>::DeceasedNotFound
+		);
+		assert_noop!(
+			MemorialSpace::create_space(
+				RuntimeOrigin::signed(OWNER),
+				SpaceSubject::Family(vec![10, 10].try_into().unwrap()),
+				b"QmLayout".to_vec(),
+				AccessMode::Public,
+			),
+			Error::<Test>::InvalidSubject
+		);
+		// 家族空间的每位逝者都须归调用者所有
+		assert_noop!(
+			MemorialSpace::create_space(
+				RuntimeOrigin::signed(OWNER),
+				SpaceSubject::Family(vec![10, 20].try_into().unwrap()),
+				b"QmLayout".to_vec(),
+				AccessMode::Public,
+			),
+			Error::<Test>::NotDeceasedOwner
+		);
+		assert_noop!(
+			MemorialSpace::create_space(
+				RuntimeOrigin::signed(OWNER),
+				SpaceSubject::Deceased(10),
+				Vec::new(),
+				AccessMode::Public,
+			),
+			Error::<Test>::BadCid
+		);
+
+		assert_ok!(MemorialSpace::create_space(
+			RuntimeOrigin::signed(OWNER),
+			SpaceSubject::Family(vec![10, 11].try_into().unwrap()),
+			b"QmLayout".to_vec(),
+			AccessMode::Public,
+		));
+		assert_eq!(SpacesByDeceased::<Test>::get(10).into_inner(), vec![0]);
+		assert_eq!(SpacesByDeceased::<Test>::get(11).into_inner(), vec![0]);
+		System::assert_last_event(
+			Event::SpaceCreated { space_id: 0, deceased_id: 10, owner: OWNER }.into(),
+		);
+
+		// 每位逝者最多关联 2 个空间
+		create_space(AccessMode::Public);
+		assert_noop!(
+			MemorialSpace::create_space(
+				RuntimeOrigin::signed(OWNER),
+				SpaceSubject::Deceased(10),
+				b"QmLayout".to_vec(),
+				AccessMode::Public,
+			),
+			Error::<Test>::TooManySpaces
+		);
+	});
+}
+
+#[test]
+fn admin_management_works() {
+	new_test_ext().execute_with(|| {
+		let space_id = create_space(AccessMode::Public);
+
+		assert_noop!(
+			MemorialSpace::add_admin(RuntimeOrigin::signed(ADMIN), space_id, ADMIN),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			MemorialSpace::add_admin(RuntimeOrigin::signed(OWNER), space_id, OWNER),
+			Error::<Test>::AlreadyAdmin
+		);
+		assert_ok!(MemorialSpace::add_admin(RuntimeOrigin::signed(OWNER), space_id, ADMIN));
+		assert_noop!(
+			MemorialSpace::add_admin(RuntimeOrigin::signed(OWNER), space_id, ADMIN),
+			Error::<Test>::AlreadyAdmin
+		);
+		assert_ok!(MemorialSpace::add_admin(RuntimeOrigin::signed(OWNER), space_id, FOLLOWER));
+		assert_noop!(
+			MemorialSpace::add_admin(RuntimeOrigin::signed(OWNER), space_id, VISITOR),
+			Error::<Test>::TooManyAdmins
+		);
+
+		// 管理员可更新布局，但不能添加管理员
+		assert_ok!(MemorialSpace::update_layout(RuntimeOrigin::signed(ADMIN), space_id, b"QmNew".to_vec()));
+		assert_eq!(Spaces::<Test>::get(space_id).unwrap().layout_cid.into_inner(), b"QmNew".to_vec());
+		assert_noop!(
+			MemorialSpace::add_admin(RuntimeOrigin::signed(ADMIN), space_id, VISITOR),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			MemorialSpace::update_layout(RuntimeOrigin::signed(VISITOR), space_id, b"QmNew".to_vec()),
+			Error::<Test>::NoPermission
+		);
+
+		// 管理员不能移除他人，可自行退出
+		assert_noop!(
+			MemorialSpace::remove_admin(RuntimeOrigin::signed(ADMIN), space_id, FOLLOWER),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(MemorialSpace::remove_admin(RuntimeOrigin::signed(ADMIN), space_id, ADMIN));
+		assert_ok!(MemorialSpace::remove_admin(RuntimeOrigin::signed(OWNER), space_id, FOLLOWER));
+		assert_noop!(
+			MemorialSpace::remove_admin(RuntimeOrigin::signed(OWNER), space_id, FOLLOWER),
+			Error::<Test>::NotAdmin
+		);
+		assert!(Spaces::<Test>::get(space_id).unwrap().admins.is_empty());
+	});
+}
+
+// ==================== 访问控制 ====================
+
+#[test]
+fn access_modes_gate_visitors() {
+	new_test_ext().execute_with(|| {
+		let space_id = create_space(AccessMode::Public);
+		assert!(MemorialSpace::can_visit(space_id, &VISITOR));
+
+		assert_noop!(
+			MemorialSpace::set_access_mode(RuntimeOrigin::signed(VISITOR), space_id, AccessMode::InviteOnly),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(MemorialSpace::set_access_mode(RuntimeOrigin::signed(OWNER), space_id, AccessMode::Followers));
+		assert!(MemorialSpace::can_visit(space_id, &FOLLOWER));
+		assert!(!MemorialSpace::can_visit(space_id, &VISITOR));
+		assert!(MemorialSpace::can_visit(space_id, &OWNER));
+
+		assert_ok!(MemorialSpace::set_access_mode(RuntimeOrigin::signed(OWNER), space_id, AccessMode::InviteOnly));
+		assert!(!MemorialSpace::can_visit(space_id, &FOLLOWER));
+
+		assert_noop!(
+			MemorialSpace::invite_visitor(RuntimeOrigin::signed(VISITOR), space_id, VISITOR),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(MemorialSpace::invite_visitor(RuntimeOrigin::signed(OWNER), space_id, VISITOR));
+		assert!(MemorialSpace::can_visit(space_id, &VISITOR));
+		assert_ok!(MemorialSpace::revoke_visitor(RuntimeOrigin::signed(OWNER), space_id, VISITOR));
+		assert!(!MemorialSpace::can_visit(space_id, &VISITOR));
+		assert!(!MemorialSpace::can_visit(99, &OWNER));
+	});
+}
+
+#[test]
+fn password_unlock_verifies_signature_without_revealing_password() {
+	new_test_ext().execute_with(|| {
+		let space_id = create_space(AccessMode::Public);
+		let pair = password_pair(&[1u8; 32]);
+		assert_noop!(unlock(space_id, VISITOR, &pair), Error::<Test>::NotPasswordProtected);
+
+		assert_ok!(MemorialSpace::set_access_mode(
+			RuntimeOrigin::signed(OWNER),
+			space_id,
+			password_mode(&pair),
+		));
+		assert!(!MemorialSpace::can_visit(space_id, &VISITOR));
+
+		// 口令错误（派生出不同密钥）
+		assert_noop!(unlock(space_id, VISITOR, &password_pair(&[2u8; 32])), Error::<Test>::WrongPassword);
+
+		// 签名绑定调用者：他人提交同一签名无效
+		let payload = MemorialSpace::unlock_payload(space_id, 0, &VISITOR);
+		let signature = pair.sign(&payload).0;
+		assert_noop!(
+			MemorialSpace::unlock_space(RuntimeOrigin::signed(FOLLOWER), space_id, signature),
+			Error::<Test>::WrongPassword
+		);
+
+		assert_ok!(MemorialSpace::unlock_space(RuntimeOrigin::signed(VISITOR), space_id, signature));
+		assert_eq!(SpaceVisitors::<Test>::get(space_id, VISITOR), Some(VisitorGrant::Unlocked(0)));
+		assert!(MemorialSpace::can_visit(space_id, &VISITOR));
+		System::assert_last_event(Event::SpaceUnlocked { space_id, who: VISITOR }.into());
+	});
+}
+
+#[test]
+fn changing_password_invalidates_unlocked_visitors() {
+	new_test_ext().execute_with(|| {
+		let space_id = create_space(AccessMode::Public);
+		let old = password_pair(&[1u8; 32]);
+		assert_ok!(MemorialSpace::set_access_mode(RuntimeOrigin::signed(OWNER), space_id, password_mode(&old)));
+		assert_ok!(unlock(space_id, VISITOR, &old));
+		assert_ok!(MemorialSpace::invite_visitor(RuntimeOrigin::signed(OWNER), space_id, FOLLOWER));
+
+		// 重复设置相同口令不变更轮次
+		assert_ok!(MemorialSpace::set_access_mode(RuntimeOrigin::signed(OWNER), space_id, password_mode(&old)));
+		assert!(MemorialSpace::can_visit(space_id, &VISITOR));
+
+		let new = password_pair(&[3u8; 32]);
+		assert_ok!(MemorialSpace::set_access_mode(RuntimeOrigin::signed(OWNER), space_id, password_mode(&new)));
+		System::assert_last_event(
+			Event::AccessModeChanged { space_id, mode: 3, password_epoch: 1 }.into(),
+		);
+		assert!(!MemorialSpace::can_visit(space_id, &VISITOR));
+		// 受邀访客不受口令变更影响
+		assert!(MemorialSpace::can_visit(space_id, &FOLLOWER));
+
+		// 旧口令的签名在新轮次无效
+		assert_noop!(unlock(space_id, VISITOR, &old), Error::<Test>::WrongPassword);
+		assert_ok!(unlock(space_id, VISITOR, &new));
+		assert!(MemorialSpace::can_visit(space_id, &VISITOR));
+
+		// 改为仅邀请后口令访客失效，改回同一口令也不恢复
+		assert_ok!(MemorialSpace::set_access_mode(RuntimeOrigin::signed(OWNER), space_id, AccessMode::InviteOnly));
+		assert!(!MemorialSpace::can_visit(space_id, &VISITOR));
+		assert_ok!(MemorialSpace::set_access_mode(RuntimeOrigin::signed(OWNER), space_id, password_mode(&new)));
+		assert!(!MemorialSpace::can_visit(space_id, &VISITOR));
+		assert_eq!(Spaces::<Test>::get(space_id).unwrap().password_epoch, 2);
+	});
+}
+
+// ==================== 摆放物 ====================
+
+#[test]
+fn place_object_enforces_permissions() {
+	new_test_ext().execute_with(|| {
+		let space_id = create_space(AccessMode::Public);
+		let at = Placement::default();
+
+		// 访客可摆放自己供奉给空间内逝者的记录
+		assert_ok!(MemorialSpace::place_object(RuntimeOrigin::signed(VISITOR), space_id, SpaceObject::Offering(100), at));
+		assert_noop!(
+			MemorialSpace::place_object(RuntimeOrigin::signed(FOLLOWER), space_id, SpaceObject::Offering(100), at),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			MemorialSpace::place_object(RuntimeOrigin::signed(VISITOR), space_id, SpaceObject::Offering(101), at),
+			Error::<Test>::InvalidObject
+		);
+		assert_noop!(
+			MemorialSpace::place_object(
+				RuntimeOrigin::signed(VISITOR),
+				space_id,
+				SpaceObject::Photo { album_id: 200, media_id: None },
+				at,
+			),
+			Error::<Test>::NoPermission
+		);
+
+		// 管理员摆放照片与自己的宠物
+		assert_ok!(MemorialSpace::place_object(
+			RuntimeOrigin::signed(OWNER),
+			space_id,
+			SpaceObject::Photo { album_id: 200, media_id: Some(1) },
+			at,
+		));
+		assert_noop!(
+			MemorialSpace::place_object(
+				RuntimeOrigin::signed(OWNER),
+				space_id,
+				SpaceObject::Photo { album_id: 200, media_id: Some(2) },
+				at,
+			),
+			Error::<Test>::InvalidObject
+		);
+		assert_noop!(
+			MemorialSpace::place_object(RuntimeOrigin::signed(OWNER), space_id, SpaceObject::Pet(301), at),
+			Error::<Test>::InvalidObject
+		);
+		assert_ok!(MemorialSpace::place_object(RuntimeOrigin::signed(OWNER), space_id, SpaceObject::Pet(300), at));
+
+		// 每个空间最多 3 个摆放物
+		assert_noop!(
+			MemorialSpace::place_object(RuntimeOrigin::signed(OWNER), space_id, SpaceObject::Offering(102), at),
+			Error::<Test>::TooManyObjects
+		);
+		assert_eq!(Spaces::<Test>::get(space_id).unwrap().object_count, 3);
+	});
+}
+
+#[test]
+fn visitor_needs_access_to_place_offering() {
+	new_test_ext().execute_with(|| {
+		let space_id = create_space(AccessMode::Followers);
+		let at = Placement::default();
+
+		assert_noop!(
+			MemorialSpace::place_object(RuntimeOrigin::signed(VISITOR), space_id, SpaceObject::Offering(100), at),
+			Error::<Test>::AccessDenied
+		);
+		assert_ok!(MemorialSpace::place_object(RuntimeOrigin::signed(FOLLOWER), space_id, SpaceObject::Offering(102), at));
+		// 管理员可代为摆放任何人供奉给空间内逝者的记录
+		assert_ok!(MemorialSpace::place_object(RuntimeOrigin::signed(OWNER), space_id, SpaceObject::Offering(100), at));
+	});
+}
+
+#[test]
+fn move_and_remove_object() {
+	new_test_ext().execute_with(|| {
+		let space_id = create_space(AccessMode::Public);
+		assert_ok!(MemorialSpace::place_object(
+			RuntimeOrigin::signed(VISITOR),
+			space_id,
+			SpaceObject::Offering(100),
+			Placement::default(),
+		));
+		let moved = Placement { x: 1, y: 2, z: 3, rotation: 90 };
+
+		assert_noop!(
+			MemorialSpace::move_object(RuntimeOrigin::signed(VISITOR), space_id, 0, moved),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			MemorialSpace::move_object(RuntimeOrigin::signed(OWNER), space_id, 9, moved),
+			Error::<Test>::ObjectNotFound
+		);
+		assert_ok!(MemorialSpace::move_object(RuntimeOrigin::signed(OWNER), space_id, 0, moved));
+		assert_eq!(SpaceObjects::<Test>::get(space_id, 0).unwrap().placement, moved);
+
+		// 摆放人或管理员可移除
+		assert_noop!(
+			MemorialSpace::remove_object(RuntimeOrigin::signed(FOLLOWER), space_id, 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(MemorialSpace::remove_object(RuntimeOrigin::signed(VISITOR), space_id, 0));
+		assert!(SpaceObjects::<Test>::get(space_id, 0).is_none());
+		assert_eq!(Spaces::<Test>::get(space_id).unwrap().object_count, 0);
+	});
+}
+
+// ==================== 留言簿 ====================
+
+#[test]
+fn guestbook_requires_access_and_moderation() {
+	new_test_ext().execute_with(|| {
+		let space_id = create_space(AccessMode::Followers);
+
+		assert_noop!(
+			MemorialSpace::sign_guestbook(RuntimeOrigin::signed(VISITOR), space_id, b"QmMsg".to_vec()),
+			Error::<Test>::AccessDenied
+		);
+		assert_ok!(MemorialSpace::sign_guestbook(RuntimeOrigin::signed(FOLLOWER), space_id, b"QmMsg1".to_vec()));
+		assert_ok!(MemorialSpace::sign_guestbook(RuntimeOrigin::signed(OWNER), space_id, b"QmMsg2".to_vec()));
+		// 每个空间最多 2 条待审核
+		assert_noop!(
+			MemorialSpace::sign_guestbook(RuntimeOrigin::signed(FOLLOWER), space_id, b"QmMsg3".to_vec()),
+			Error::<Test>::TooManyPendingEntries
+		);
+		assert_eq!(Guestbook::<Test>::get(space_id, 0).unwrap().status, GuestbookStatus::Pending);
+
+		assert_noop!(
+			MemorialSpace::moderate_guestbook(RuntimeOrigin::signed(FOLLOWER), space_id, 0, true),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(MemorialSpace::moderate_guestbook(RuntimeOrigin::signed(OWNER), space_id, 0, true));
+		assert_eq!(Guestbook::<Test>::get(space_id, 0).unwrap().status, GuestbookStatus::Approved);
+		assert_ok!(MemorialSpace::moderate_guestbook(RuntimeOrigin::signed(OWNER), space_id, 1, false));
+		assert!(Guestbook::<Test>::get(space_id, 1).is_none());
+		assert_eq!(PendingGuestbookCount::<Test>::get(space_id), 0);
+		System::assert_last_event(Event::GuestbookEntryRemoved { space_id, entry_id: 1 }.into());
+
+		// 已通过的留言也可删除，不影响待审核计数
+		assert_ok!(MemorialSpace::moderate_guestbook(RuntimeOrigin::signed(OWNER), space_id, 0, false));
+		assert_eq!(PendingGuestbookCount::<Test>::get(space_id), 0);
+		assert_noop!(
+			MemorialSpace::moderate_guestbook(RuntimeOrigin::signed(OWNER), space_id, 0, true),
+			Error::<Test>::EntryNotFound
+		);
+	});
+}
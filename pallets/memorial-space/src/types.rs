@@ -0,0 +1,189 @@
+//! 函数级中文注释：纪念空间类型定义
+//!
+//! - 空间主体（单个逝者 / 家族）
+//! - 访客访问模式（公开 / 关注者 / 仅邀请 / 口令）
+//! - 摆放物（供奉记录 / 相册照片 / 宠物）与留言簿条目
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::ConstU32, BoundedVec};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+
+use crate::pallet::Config;
+
+/// 家族空间最多关联的逝者数
+pub const MAX_FAMILY_MEMBERS: u32 = 16;
+
+/// 函数级中文注释：空间主体
+/// - `Deceased`: 单个逝者的纪念馆
+/// - `Family`: 家族纪念堂，关联多位逝者（首位为主位）
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum SpaceSubject {
+	Deceased(u64),
+	Family(BoundedVec<u64, ConstU32<MAX_FAMILY_MEMBERS>>),
+}
+
+impl SpaceSubject {
+	/// 函数级中文注释：空间关联的全部逝者
+	pub fn deceased_ids(&self) -> &[u64] {
+		match self {
+			SpaceSubject::Deceased(id) => core::slice::from_ref(id),
+			SpaceSubject::Family(ids) => ids.as_slice(),
+		}
+	}
+}
+
+/// 函数级详细中文注释：访客访问模式
+///
+/// - `Public`: 任何人可访问
+/// - `Followers`: 关注了空间内任一逝者的账户可访问
+/// - `InviteOnly`: 仅管理员邀请的访客
+/// - `Password { key, salt }`: 口令门槛，访客用口令派生的密钥签名解锁
+///
+/// 口令模式说明：管理员在链下以 `salt`（每次设置随机生成）对口令做慢哈希（如 scrypt），
+/// 派生 sr25519 密钥对，链上只保存公钥 `key` 与 `salt`。访客以同样方式派生私钥，
+/// 对 `unlock_payload` 签名后调用 `unlock_space`；口令本身从不上链，签名绑定调用者，
+/// 无法被他人重放。更换或取消口令时，已通过口令解锁的访客全部失效（邀请的访客不受影响）。
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum AccessMode {
+	Public,
+	Followers,
+	InviteOnly,
+	Password { key: [u8; 32], salt: [u8; 16] },
+}
+
+impl AccessMode {
+	/// 函数级中文注释：事件用编码（0=公开, 1=关注者, 2=仅邀请, 3=口令）
+	pub fn code(&self) -> u8 {
+		match self {
+			AccessMode::Public => 0,
+			AccessMode::Followers => 1,
+			AccessMode::InviteOnly => 2,
+			AccessMode::Password { .. } => 3,
+		}
+	}
+}
+
+/// 函数级中文注释：纪念空间
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+#[scale_info(skip_type_params(T))]
+pub struct MemorialSpace<T: Config> {
+	/// 所有者（创建时须为全部关联逝者的 owner）
+	pub owner: T::AccountId,
+	/// 空间主体
+	pub subject: SpaceSubject,
+	/// 布局/主题描述文件 CID（场景、背景、配色等由前端解释）
+	pub layout_cid: BoundedVec<u8, T::MaxCidLen>,
+	/// 访客访问模式
+	pub access: AccessMode,
+	/// 管理员（不含所有者）
+	pub admins: BoundedVec<T::AccountId, T::MaxAdmins>,
+	/// 当前摆放物数量
+	pub object_count: u32,
+	/// 口令轮次：每次更换/取消口令递增，旧轮次的口令解锁随即失效
+	pub password_epoch: u32,
+	/// 创建区块
+	pub created: BlockNumberFor<T>,
+	/// 最近更新区块
+	pub updated: BlockNumberFor<T>,
+}
+
+/// 函数级中文注释：访客资格
+/// - `Invited`: 管理员邀请，任何访问模式下有效
+/// - `Unlocked(epoch)`: 口令解锁，仅在口令轮次未变更时有效
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum VisitorGrant {
+	Invited,
+	Unlocked(u32),
+}
+
+/// 函数级详细中文注释：摆放物
+///
+/// - `Offering`: memorial 供奉记录（须供奉给空间内的逝者）
+/// - `Photo`: 逝者相册（`media_id=None` 表示整本相册，否则为相册中的单张照片）
+/// - `Pet`: stardust-pet 宠物（须为摆放人或空间所有者的宠物）
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum SpaceObject {
+	Offering(u64),
+	Photo { album_id: u64, media_id: Option<u64> },
+	Pet(u64),
+}
+
+/// 函数级中文注释：摆放位置（场景坐标与朝向，单位由前端布局约定）
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug, Default)]
+pub struct Placement {
+	pub x: i32,
+	pub y: i32,
+	pub z: i32,
+	/// 朝向角度（0-359）
+	pub rotation: u16,
+}
+
+/// 函数级中文注释：空间中的摆放物记录
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+#[scale_info(skip_type_params(T))]
+pub struct PlacedObject<T: Config> {
+	pub object: SpaceObject,
+	pub placement: Placement,
+	/// 摆放人（可移除自己摆放的物品）
+	pub placed_by: T::AccountId,
+	pub placed_at: BlockNumberFor<T>,
+}
+
+/// 函数级中文注释：留言审核状态
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum GuestbookStatus {
+	/// 待管理员审核（前端不公开展示）
+	Pending,
+	/// 已通过
+	Approved,
+}
+
+/// 函数级中文注释：留言簿条目（正文存 IPFS）
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+#[scale_info(skip_type_params(T))]
+pub struct GuestbookEntry<T: Config> {
+	pub author: T::AccountId,
+	pub content_cid: BoundedVec<u8, T::MaxCidLen>,
+	pub status: GuestbookStatus,
+	pub created: BlockNumberFor<T>,
+}
+
+/// 函数级中文注释：空间主体信息提供者（由 Runtime 适配 pallet-deceased / pallet-social）
+pub trait SpaceSubjectProvider<AccountId> {
+	/// 逝者所有者；逝者不存在时返回 None
+	fn deceased_owner(deceased_id: u64) -> Option<AccountId>;
+	/// 是否关注了该逝者
+	fn is_follower(who: &AccountId, deceased_id: u64) -> bool;
+}
+
+impl<AccountId> SpaceSubjectProvider<AccountId> for () {
+	fn deceased_owner(_deceased_id: u64) -> Option<AccountId> {
+		None
+	}
+	fn is_follower(_who: &AccountId, _deceased_id: u64) -> bool {
+		false
+	}
+}
+
+/// 函数级中文注释：摆放物信息提供者（由 Runtime 适配 pallet-memorial / pallet-deceased / pallet-stardust-pet）
+pub trait SpaceObjectProvider<AccountId> {
+	/// 供奉记录的（供奉人，受供逝者ID）；记录不存在或目标不是逝者时返回 None
+	fn offering_of(offering_id: u64) -> Option<(AccountId, u64)>;
+	/// 相册所属逝者；相册不存在，或指定了 `media_id` 但照片不在该相册中时返回 None
+	fn album_deceased(album_id: u64, media_id: Option<u64>) -> Option<u64>;
+	/// 宠物所有者；宠物不存在时返回 None
+	fn pet_owner(pet_id: u64) -> Option<AccountId>;
+}
+
+impl<AccountId> SpaceObjectProvider<AccountId> for () {
+	fn offering_of(_offering_id: u64) -> Option<(AccountId, u64)> {
+		None
+	}
+	fn album_deceased(_album_id: u64, _media_id: Option<u64>) -> Option<u64> {
+		None
+	}
+	fn pet_owner(_pet_id: u64) -> Option<AccountId> {
+		None
+	}
+}
@@ -25,12 +25,17 @@ mod tests;
 pub mod types;
 pub use types::{
     SacrificeStatus, SacrificeItem, MediaItem, OfferingRecord, OfferingStatus, SimpleRoute,
-    OnOfferingCommitted, MembershipProvider,
+    OnOfferingCommitted, OnOfferingRecorded, MembershipProvider,
     PrimaryCategory, SubCategory, SceneTag, CulturalTag, QualityLevel,
     PricingModel, PricingConfig, UserType, RenewalRecord, RenewFailReason,
-    TargetType, OfferingTarget,
+    TargetType, OfferingTarget, ScheduledOffering, RemembranceCalendar,
+    CampaignPurpose, CampaignStatus, OfferingCampaign, SubjectFundingProvider,
 };
 
+/// 函数级中文注释：集体供奉众筹的托管ID命名空间（高32位 "MEMO"）
+/// - 托管ID = `CAMPAIGN_ESCROW_NAMESPACE | campaign_id`，避免与 OTC/Bridge 的订单ID托管冲突
+pub const CAMPAIGN_ESCROW_NAMESPACE: u64 = 0x4d45_4d4f_0000_0000;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -110,11 +115,47 @@ pub mod pallet {
         /// 函数级中文注释：会员信息提供者
         type MembershipProvider: MembershipProvider<Self::AccountId>;
 
+        /// 函数级中文注释：🆕 逝者供奉目标（存在性、可访问性，由 Runtime 适配 pallet-deceased）
+        type DeceasedTarget: OfferingTarget<Self::AccountId>;
+
+        /// 函数级中文注释：🆕 宠物供奉目标（由 Runtime 适配 pallet-stardust-pet）
+        type PetTarget: OfferingTarget<Self::AccountId>;
+
+        /// 函数级中文注释：🆕 纪念空间供奉目标（`TargetType::Memorial`，由 Runtime 适配 pallet-memorial-space）
+        type SpaceTarget: OfferingTarget<Self::AccountId>;
+
+        /// 函数级中文注释：🆕 供奉落账回调（Runtime 接入 pallet-ledger 回执与周排行）
+        type OnOfferingRecorded: OnOfferingRecorded<Self::AccountId>;
+
         // ===== P0修复：资金管理配置 =====
         /// 函数级中文注释：平台托管账户PalletId
         /// - 用于派生平台账户地址，接收平台分成
         #[pallet::constant]
         type PalletId: Get<frame_support::PalletId>;
+
+        // ===== 🆕 预约供奉配置 =====
+        /// 函数级中文注释：纪念日历（由 Runtime 适配 pallet-deceased）
+        type RemembranceCalendar: RemembranceCalendar;
+
+        /// 函数级中文注释：每块最多执行的预约供奉数
+        /// - 超出部分留在当日队列，下一区块继续执行
+        #[pallet::constant]
+        type MaxScheduledOfferingsPerBlock: Get<u32>;
+
+        // ===== 🆕 集体供奉众筹配置 =====
+        /// 函数级中文注释：托管接口（众筹出资锁定、筹满释放、失败退款）
+        type Escrow: pallet_escrow::Escrow<Self::AccountId, BalanceOf<Self>>;
+
+        /// 函数级中文注释：存储资金账户提供者（由 Runtime 适配 pallet-stardust-ipfs）
+        type SubjectFunding: SubjectFundingProvider<Self::AccountId>;
+
+        /// 函数级中文注释：单个众筹最多出资人数
+        #[pallet::constant]
+        type MaxCampaignContributors: Get<u32>;
+
+        /// 函数级中文注释：众筹最长募集期（块）
+        #[pallet::constant]
+        type MaxCampaignDuration: Get<BlockNumberFor<Self>>;
     }
 
     #[pallet::pallet]
@@ -125,17 +166,23 @@ pub mod pallet {
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         /// 函数级中文注释：每个块初始化时检查到期订单
+        /// - 🆕 每块先执行到期的预约供奉（纪念日自动下单）
+        /// - 🆕 结算本块截止的集体供奉众筹（未筹满退款）
         /// - P3优化：使用配置参数RenewalCheckInterval，默认100块（约10分钟）
         /// - 处理到期订单：自动续费或标记过期
         /// - 单次最多处理50个到期订单，避免单块权重过高
         fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+            // 🆕 预约供奉：每块推进，纪念日当天自动下单
+            let mut weight = Self::process_scheduled_offerings();
+            // 🆕 集体供奉众筹：截止未筹满的逐笔退款
+            weight = weight.saturating_add(Self::process_campaign_deadlines(block_number));
+
             // P3优化：使用配置参数而非硬编码
             let check_interval: BlockNumberFor<T> = T::RenewalCheckInterval::get().into();
             if block_number % check_interval != 0u32.into() {
-                return Weight::zero();
+                return weight;
             }
 
-            let mut weight = Weight::zero();
             let max_process = 50u32; // 单次最多处理50个订单
             let mut processed = 0u32;
 
@@ -370,6 +417,54 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    // ===== 🆕 预约供奉存储 =====
+
+    /// 函数级中文注释：下一个预约供奉ID
+    #[pallet::storage]
+    pub type NextScheduledOfferingId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// 函数级中文注释：预约供奉记录
+    #[pallet::storage]
+    pub type ScheduledOfferings<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, ScheduledOffering<T>, OptionQuery>;
+
+    /// 函数级中文注释：按执行日索引的预约供奉
+    /// - Key: 纪元日（UTC+8）
+    /// - Value: 当日待执行的预约ID（已取消的ID在执行时跳过）
+    #[pallet::storage]
+    pub type ScheduledOfferingsByDay<T: Config> =
+        StorageMap<_, Twox64Concat, u32, BoundedVec<u64, ConstU32<1000>>, ValueQuery>;
+
+    /// 函数级中文注释：用户的预约供奉索引（每人最多50个）
+    #[pallet::storage]
+    pub type ScheduledOfferingsByUser<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<u64, ConstU32<50>>, ValueQuery>;
+
+    /// 函数级中文注释：预约供奉执行游标（下一个待处理的纪元日，0=未初始化）
+    #[pallet::storage]
+    pub type ScheduledOfferingCursor<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    // ===== 🆕 集体供奉众筹存储 =====
+
+    /// 函数级中文注释：下一个众筹ID
+    #[pallet::storage]
+    pub type NextCampaignId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// 函数级中文注释：众筹记录（结束后保留，供查询出资人名单）
+    #[pallet::storage]
+    pub type Campaigns<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, OfferingCampaign<T>, OptionQuery>;
+
+    /// 函数级中文注释：集体供奉生成的供奉记录 → 众筹ID（由供奉记录查共同供奉人）
+    #[pallet::storage]
+    pub type CampaignOfOffering<T: Config> = StorageMap<_, Blake2_128Concat, u64, u64, OptionQuery>;
+
+    /// 函数级中文注释：按截止区块索引的众筹（每块最多100个截止）
+    /// - 已提前筹满或取消的ID在结算时跳过
+    #[pallet::storage]
+    pub type CampaignsByDeadline<T: Config> =
+        StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, BoundedVec<u64, ConstU32<100>>, ValueQuery>;
+
     /// 函数级中文注释：P2新增 - 续费历史记录存储
     /// - Key: 用户账户
     /// - Value: 该用户的所有续费记录ID列表
@@ -478,6 +573,91 @@ pub mod pallet {
             offering_id: u64,
             who: T::AccountId,
         },
+
+        // ===== 🆕 预约供奉事件 =====
+        /// 函数级中文注释：预约供奉已登记
+        /// - next_day: 首次执行日（纪元日）
+        OfferingScheduled {
+            schedule_id: u64,
+            who: T::AccountId,
+            target_id: u64,
+            sacrifice_id: u64,
+            occasion: u8,
+            recurring: bool,
+            next_day: u32,
+        },
+        /// 函数级中文注释：预约供奉已自动执行
+        ScheduledOfferingExecuted {
+            schedule_id: u64,
+            offering_id: u64,
+            who: T::AccountId,
+        },
+        /// 函数级中文注释：预约供奉执行失败（库存不足、限购、限频等）
+        ScheduledOfferingFailed {
+            schedule_id: u64,
+            who: T::AccountId,
+            error: DispatchError,
+        },
+        /// 函数级中文注释：预约供奉已结束（一次性执行完毕或无后续纪念日）
+        ScheduledOfferingFinished {
+            schedule_id: u64,
+        },
+        /// 函数级中文注释：重复预约无法排入下一次执行日（当日队列已满），预约已结束，需重新登记
+        ScheduledOfferingRequeueFailed {
+            schedule_id: u64,
+            who: T::AccountId,
+            next_day: u32,
+        },
+        /// 函数级中文注释：预约供奉已取消
+        ScheduledOfferingCancelled {
+            schedule_id: u64,
+            who: T::AccountId,
+        },
+
+        // ===== 🆕 集体供奉众筹事件 =====
+        /// 函数级中文注释：众筹已发起
+        CampaignCreated {
+            campaign_id: u64,
+            organizer: T::AccountId,
+            target_type: TargetType,
+            target_id: u64,
+            purpose: CampaignPurpose,
+            goal: u128,
+            deadline: BlockNumberFor<T>,
+        },
+        /// 函数级中文注释：收到出资（raised 为累计金额）
+        CampaignContributed {
+            campaign_id: u64,
+            who: T::AccountId,
+            amount: u128,
+            raised: u128,
+        },
+        /// 函数级中文注释：众筹已筹满并执行
+        /// - offering_id: 供奉用途生成的供奉记录；资助用途为 None
+        /// - contributors: 出资明细（共同供奉人及各自出资额）
+        CampaignSucceeded {
+            campaign_id: u64,
+            raised: u128,
+            offering_id: Option<u64>,
+            contributors: BoundedVec<(T::AccountId, u128), T::MaxCampaignContributors>,
+        },
+        /// 函数级中文注释：众筹失败并已退款（到期未筹满或筹满后执行失败）
+        CampaignFailed {
+            campaign_id: u64,
+            raised: u128,
+            error: Option<DispatchError>,
+        },
+        /// 函数级中文注释：众筹已被发起人取消并退款
+        CampaignCancelled {
+            campaign_id: u64,
+            refunded: u128,
+        },
+        /// 函数级中文注释：单笔退款失败（资金仍在托管中，需治理处理）
+        CampaignRefundFailed {
+            campaign_id: u64,
+            who: T::AccountId,
+            amount: u128,
+        },
     }
 
     #[pallet::error]
@@ -519,6 +699,30 @@ pub mod pallet {
         TargetNotFound,
         /// 目标类型不支持
         TargetNotSupported,
+
+        // ===== 🆕 预约供奉错误 =====
+        /// 预约供奉不存在
+        ScheduleNotFound,
+        /// 目标在该场合没有可推算的纪念日（如去世日期未知）
+        NoUpcomingOccasion,
+        /// 订阅类祭祀品不支持预约供奉
+        SubscriptionNotSchedulable,
+
+        // ===== 🆕 集体供奉众筹错误 =====
+        /// 众筹不存在
+        CampaignNotFound,
+        /// 众筹已结束（已筹满、失败或取消）
+        CampaignNotOpen,
+        /// 众筹已过截止区块
+        CampaignExpired,
+        /// 募集期为0或超过上限
+        InvalidCampaignDuration,
+        /// 出资人数已达上限
+        TooManyContributors,
+        /// 目标没有存储资金账户
+        NoFundingAccount,
+        /// 付款人可用余额不足以支付供奉
+        InsufficientBalance,
     }
 
     #[pallet::call]
@@ -759,8 +963,11 @@ pub mod pallet {
         /// ## 权限逻辑
         /// - Deceased: 通过 DeceasedTargetAdapter 检查权限
         /// - Pet: 通过 PetTargetAdapter 检查权限
-        /// - Memorial/Event: 未来扩展
+        /// - Memorial: 纪念空间，通过 SpaceTargetAdapter 检查权限（可访问即可供奉）
+        /// - Event: 未来扩展
         ///
+        /// ## 落账回执
+        /// - 记录写入后调用 `OnOfferingRecorded`，由 ledger 生成回执、入周 Merkle 树并更新周排行
         /// ## 分账逻辑
         /// - 目标所有者：获取 OfferingTarget::get_owner() 作为受益人
         /// - Affiliate 分账：统一调用 OnOfferingCommitted 回调
@@ -781,163 +988,675 @@ pub mod pallet {
             duration_weeks: Option<u32>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            Self::do_offer(&who, target_type, target_id, sacrifice_id, quantity, media, duration_weeks, None)?;
+            Ok(())
+        }
 
-            // 检查祭祀品是否存在
-            let sacrifice = SacrificeOf::<T>::get(sacrifice_id)
-                .ok_or(Error::<T>::SacrificeNotFound)?;
-
-            // 检查商品状态
-            ensure!(
-                matches!(sacrifice.status, SacrificeStatus::Enabled),
-                Error::<T>::SacrificeNotEnabled
-            );
+        // ========================================
+        // 管理函数
+        // ========================================
 
-            ensure!(sacrifice.pricing.enabled, Error::<T>::SacrificeNotEnabled);
+        /// 函数级中文注释：设置风控参数
+        #[pallet::call_index(20)]
+        #[pallet::weight(10_000)]
+        pub fn set_offer_params(
+            origin: OriginFor<T>,
+            offer_window: Option<BlockNumberFor<T>>,
+            offer_max_in_window: Option<u32>,
+            min_offer_amount: Option<u128>,
+        ) -> DispatchResult {
+            T::AdminOrigin::try_origin(origin).map_err(|_| DispatchError::BadOrigin)?;
 
-            // P1-1: 原子性检查和扣减库存
-            if sacrifice.pricing.stock >= 0 {
-                SacrificeStock::<T>::try_mutate(sacrifice_id, |stock| -> DispatchResult {
-                    ensure!(
-                        *stock >= quantity as i32,
-                        Error::<T>::InsufficientStock
-                    );
-                    *stock = stock.saturating_sub(quantity as i32);
-                    Ok(())
-                })?;
+            if let Some(v) = offer_window {
+                OfferWindowParam::<T>::put(v);
             }
-
-            // 检查用户购买限制
-            if let Some(limit) = sacrifice.pricing.per_user_limit {
-                UserPurchaseCount::<T>::try_mutate(&who, sacrifice_id, |count| -> DispatchResult {
-                    ensure!(
-                        count.saturating_add(quantity) <= limit,
-                        Error::<T>::PurchaseLimitExceeded
-                    );
-                    *count = count.saturating_add(quantity);
-                    Ok(())
-                })?;
-            } else {
-                UserPurchaseCount::<T>::mutate(&who, sacrifice_id, |count| {
-                    *count = count.saturating_add(quantity);
-                });
+            if let Some(v) = offer_max_in_window {
+                OfferMaxInWindowParam::<T>::put(v);
             }
-
-            // 🆕 P4：通过 OfferingTarget trait 检查目标权限
-            // 注意：Runtime层需要实现适配器来检查不同类型的目标
-            // TODO: 在Config中添加 DeceasedProvider 和 PetProvider 关联类型
-            // 当前简化版本：跳过权限检查（仅用于编译验证）
-
-            // 检查目标类型是否支持
-            match target_type {
-                TargetType::Deceased | TargetType::Pet => {
-                    // 支持的类型，继续执行
-                },
-                _ => return Err(Error::<T>::TargetNotSupported.into()),
+            if let Some(v) = min_offer_amount {
+                MinOfferAmountParam::<T>::put(v);
             }
 
-            // TODO: 添加实际的目标存在性和权限检查
-            // match target_type {
-            //     TargetType::Deceased => {
-            //         ensure!(
-            //             T::DeceasedProvider::exists(target_id),
-            //             Error::<T>::TargetNotFound
-            //         );
-            //         ensure!(
-            //             T::DeceasedProvider::is_accessible(&who, target_id),
-            //             Error::<T>::NotAllowed
-            //         );
-            //     },
-            //     TargetType::Pet => {
-            //         ensure!(
-            //             T::PetProvider::exists(target_id),
-            //             Error::<T>::TargetNotFound
-            //         );
-            //         ensure!(
-            //             T::PetProvider::is_accessible(&who, target_id),
-            //             Error::<T>::NotAllowed
-            //         );
-            //     },
-            //     _ => return Err(Error::<T>::TargetNotSupported.into()),
-            // }
-
-            // P1-2: 完善用户类型判断（支持 VIP）
-            let user_type_enum = Self::determine_user_type(&who);
-
-            let user_type_code = match user_type_enum {
-                UserType::Standard => 0,
-                UserType::Member => 1,
-                UserType::VIP => 2,
-            };
+            Self::deposit_event(Event::OfferParamsUpdated);
+            Ok(())
+        }
 
-            // 计算价格
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            let unit_price = sacrifice.get_effective_price(user_type_enum, current_block)
-                .ok_or(Error::<T>::PricingNotAvailable)?;
+        /// 函数级中文注释：设置全局暂停
+        #[pallet::call_index(21)]
+        #[pallet::weight(10_000)]
+        pub fn set_pause_global(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+            T::AdminOrigin::try_origin(origin).map_err(|_| DispatchError::BadOrigin)?;
+            PausedGlobal::<T>::put(paused);
+            Self::deposit_event(Event::PausedGlobalSet { paused });
+            Ok(())
+        }
 
-            let total_amount = unit_price.saturating_mul(quantity as u128);
+        /// 函数级中文注释：设置分账配置
+        #[pallet::call_index(22)]
+        #[pallet::weight(10_000)]
+        pub fn set_route_config(
+            origin: OriginFor<T>,
+            subject_percent: u8,
+            platform_percent: u8,
+        ) -> DispatchResult {
+            T::AdminOrigin::try_origin(origin).map_err(|_| DispatchError::BadOrigin)?;
 
-            // 验证最小金额
-            let min_amount = T::MinOfferAmount::get();
             ensure!(
-                total_amount >= min_amount,
-                Error::<T>::AmountTooLow
+                subject_percent.saturating_add(platform_percent) == 100,
+                Error::<T>::BadInput
             );
 
-            // P1-3 + P2-8: 验证订阅类商品的duration_weeks
-            match &sacrifice.pricing.model {
-                PricingModel::Subscription { weekly_price: _, min_weeks, max_weeks, .. } => {
-                    let weeks = duration_weeks.ok_or(Error::<T>::AmountRequired)?;
+            let config = SimpleRoute {
+                subject_percent,
+                platform_percent,
+            };
 
-                    ensure!(
-                        weeks >= *min_weeks,
-                        Error::<T>::BadInput
-                    );
+            RouteConfig::<T>::put(config);
+            Self::deposit_event(Event::RouteConfigUpdated {
+                subject_percent,
+                platform_percent,
+            });
+            Ok(())
+        }
 
-                    if let Some(max) = max_weeks {
-                        ensure!(
-                            weeks <= *max,
-                            Error::<T>::BadInput
-                        );
-                    }
-                },
-                _ => {}
-            }
+        // ========================================
+        // P3新增：订阅管理函数
+        // ========================================
 
-            // 限频控制（使用 target_id 替代 grave_id）
-            let now = <frame_system::Pallet<T>>::block_number();
-            Self::check_rate_limit(&who, target_id, now)?;
+        /// 函数级中文注释：手动续费订阅
+        ///
+        /// ### 参数
+        /// - `offering_id`: 订单ID
+        ///
+        /// ### 权限
+        /// - 仅订单所有者可续费
+        /// - 订单必须是Active状态
+        ///
+        /// ### 逻辑
+        /// 1. 验证权限和状态
+        /// 2. 查询祭祀品价格
+        /// 3. 扣费并更新到期时间
+        /// 4. 发送续费成功事件
+        #[pallet::call_index(23)]
+        #[pallet::weight(10_000)]
+        pub fn renew_subscription(
+            origin: OriginFor<T>,
+            offering_id: u64,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
 
-            // 🆕 P4：转账逻辑（获取目标所有者）
-            // TODO: 实现通用转账函数，支持 target_type 路由
-            // 当前简化版本：使用旧的 transfer_with_simple_route
-            // Self::transfer_to_target(&who, target_type, target_id, total_amount, sacrifice_id, duration_weeks)?;
+            // 1. 获取订单记录
+            let mut record = OfferingRecords::<T>::get(offering_id)
+                .ok_or(Error::<T>::NotFound)?;
 
-            // 临时方案：跳过转账（后续实现通用转账）
-            // 注意：这会导致资金未转移，仅用于架构验证
-            // Self::transfer_with_simple_route(&who, target_id, total_amount, sacrifice_id, duration_weeks)?;
+            // 2. 验证权限
+            ensure!(record.who == who, Error::<T>::NotAllowed);
 
-            // 构造媒体列表
-            let media_items: Result<BoundedVec<MediaItem<T>, T::MaxMediaPerOffering>, _> =
-                media.into_iter()
-                    .map(|cid_vec| {
-                        let cid_bv = BoundedVec::try_from(cid_vec).map_err(|_| Error::<T>::BadInput)?;
-                        Ok(MediaItem { cid: cid_bv })
-                    })
-                    .collect::<Result<Vec<_>, _>>()
-                    .and_then(|vec| BoundedVec::try_from(vec).map_err(|_| Error::<T>::BadInput));
+            // 3. 验证状态（只有Active状态可续费）
+            ensure!(
+                record.status == OfferingStatus::Active,
+                Error::<T>::NotAllowed
+            );
 
-            let media_items = media_items?;
+            // 4. 执行续费（复用自动续费逻辑）
+            Self::try_auto_renew(offering_id, &mut record)?;
 
-            // 创建供奉记录
-            let offering_id = NextOfferingId::<T>::mutate(|n| {
-                let x = *n;
-                *n = x.saturating_add(1);
-                x
+            // 5. 发送事件
+            Self::deposit_event(Event::SubscriptionRenewed {
+                offering_id,
+                who,
+                new_expiry: record.expiry_block.unwrap_or_else(|| <frame_system::Pallet<T>>::block_number()),
+                amount: record.amount,
             });
 
-            let now = <frame_system::Pallet<T>>::block_number();
+            Ok(())
+        }
 
-            // P3：根据商品类型设置状态和到期时间
+        /// 函数级中文注释：取消订阅（设置auto_renew=false）
+        ///
+        /// ### 参数
+        /// - `offering_id`: 订单ID
+        ///
+        /// ### 权限
+        /// - 仅订单所有者可取消
+        /// - 订单必须是Active状态
+        ///
+        /// ### 效果
+        /// - 设置auto_renew=false，下次到期后不再自动续费
+        /// - 不退款，订阅持续到当前周期结束
+        #[pallet::call_index(24)]
+        #[pallet::weight(10_000)]
+        pub fn cancel_subscription(
+            origin: OriginFor<T>,
+            offering_id: u64,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // 1. 获取订单记录
+            OfferingRecords::<T>::try_mutate(offering_id, |maybe_record| -> DispatchResult {
+                let record = maybe_record.as_mut().ok_or(Error::<T>::NotFound)?;
+
+                // 2. 验证权限
+                ensure!(record.who == who, Error::<T>::NotAllowed);
+
+                // 3. 验证状态
+                ensure!(
+                    record.status == OfferingStatus::Active,
+                    Error::<T>::NotAllowed
+                );
+
+                // 4. 关闭自动续费
+                record.auto_renew = false;
+
+                // 5. 发送事件
+                Self::deposit_event(Event::SubscriptionCancelled {
+                    offering_id,
+                    who: who.clone(),
+                });
+
+                Ok(())
+            })
+        }
+
+        // ========================================
+        // 🆕 预约供奉
+        // ========================================
+
+        /// 函数级详细中文注释：预约纪念日供奉
+        ///
+        /// ### 参数
+        /// - `target_type` / `target_id`: 供奉目标（逝者与宠物有纪念日历）
+        /// - `sacrifice_id` / `quantity`: 祭祀品与数量（仅非订阅类）
+        /// - `occasion`: 纪念场合（0=忌日, 1=农历忌日, 2=生辰, 3=农历生辰, 4=清明, 5=冬至）
+        /// - `recurring`: 是否每年重复
+        ///
+        /// ### 执行
+        /// - 纪念日当天由 `on_initialize` 以预约人身份下单，规则与 `offer_to_target` 一致
+        /// - 登记时不扣款、不占库存；执行时按当时价格走常规供奉分账扣款，余额不足则本次执行失败
+        #[pallet::call_index(25)]
+        #[pallet::weight(10_000)]
+        pub fn schedule_offering(
+            origin: OriginFor<T>,
+            target_type: TargetType,
+            target_id: u64,
+            sacrifice_id: u64,
+            quantity: u32,
+            occasion: u8,
+            recurring: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(quantity > 0, Error::<T>::BadInput);
+
+            let sacrifice = SacrificeOf::<T>::get(sacrifice_id)
+                .ok_or(Error::<T>::SacrificeNotFound)?;
+            ensure!(
+                matches!(sacrifice.status, SacrificeStatus::Enabled) && sacrifice.pricing.enabled,
+                Error::<T>::SacrificeNotEnabled
+            );
+            ensure!(
+                !matches!(sacrifice.pricing.model, PricingModel::Subscription { .. }),
+                Error::<T>::SubscriptionNotSchedulable
+            );
+
+            let today = T::RemembranceCalendar::today();
+            let next_day = T::RemembranceCalendar::next_occurrence(target_type, target_id, occasion, today)
+                .ok_or(Error::<T>::NoUpcomingOccasion)?;
+
+            let schedule_id = NextScheduledOfferingId::<T>::mutate(|n| {
+                let x = *n;
+                *n = x.saturating_add(1);
+                x
+            });
+            ScheduledOfferingsByUser::<T>::try_mutate(&who, |list| {
+                list.try_push(schedule_id).map_err(|_| Error::<T>::TooMany)
+            })?;
+            ScheduledOfferingsByDay::<T>::try_mutate(next_day, |list| {
+                list.try_push(schedule_id).map_err(|_| Error::<T>::TooMany)
+            })?;
+            ScheduledOfferings::<T>::insert(schedule_id, ScheduledOffering::<T> {
+                who: who.clone(),
+                target_type,
+                target_id,
+                sacrifice_id,
+                quantity,
+                occasion,
+                recurring,
+                next_day,
+                executions: 0,
+            });
+
+            Self::deposit_event(Event::OfferingScheduled {
+                schedule_id,
+                who,
+                target_id,
+                sacrifice_id,
+                occasion,
+                recurring,
+                next_day,
+            });
+            Ok(())
+        }
+
+        /// 函数级中文注释：取消预约供奉（仅预约人）
+        #[pallet::call_index(26)]
+        #[pallet::weight(10_000)]
+        pub fn cancel_scheduled_offering(origin: OriginFor<T>, schedule_id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let schedule = ScheduledOfferings::<T>::get(schedule_id)
+                .ok_or(Error::<T>::ScheduleNotFound)?;
+            ensure!(schedule.who == who, Error::<T>::NotAllowed);
+
+            Self::remove_schedule(schedule_id, &who);
+            Self::deposit_event(Event::ScheduledOfferingCancelled { schedule_id, who });
+            Ok(())
+        }
+
+        // ========================================
+        // 🆕 集体供奉众筹
+        // ========================================
+
+        /// 函数级详细中文注释：发起集体供奉众筹
+        ///
+        /// ### 参数
+        /// - `target_type` / `target_id`: 供奉或资助目标
+        /// - `purpose`: 用途（集体供奉祭祀品 / 资助存储资金账户）
+        /// - `goal`: 目标金额，仅资助用途有效；供奉用途按标准价 × 数量计算
+        /// - `duration`: 募集期（块），不超过 `MaxCampaignDuration`
+        ///
+        /// ### 规则
+        /// - 发起不出资、不占库存；任何人（含发起人）通过 `contribute_to_campaign` 出资
+        /// - 筹满即执行；截止仍未筹满则逐笔退款
+        #[pallet::call_index(27)]
+        #[pallet::weight(10_000)]
+        pub fn create_campaign(
+            origin: OriginFor<T>,
+            target_type: TargetType,
+            target_id: u64,
+            purpose: CampaignPurpose,
+            goal: u128,
+            duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let organizer = ensure_signed(origin)?;
+            ensure!(
+                duration > 0u32.into() && duration <= T::MaxCampaignDuration::get(),
+                Error::<T>::InvalidCampaignDuration
+            );
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            let goal = match purpose {
+                CampaignPurpose::Offering { sacrifice_id, quantity } => {
+                    ensure!(quantity > 0, Error::<T>::BadInput);
+                    ensure!(
+                        matches!(target_type, TargetType::Deceased | TargetType::Pet),
+                        Error::<T>::TargetNotSupported
+                    );
+                    let sacrifice = SacrificeOf::<T>::get(sacrifice_id)
+                        .ok_or(Error::<T>::SacrificeNotFound)?;
+                    ensure!(
+                        matches!(sacrifice.status, SacrificeStatus::Enabled) && sacrifice.pricing.enabled,
+                        Error::<T>::SacrificeNotEnabled
+                    );
+                    ensure!(
+                        !matches!(sacrifice.pricing.model, PricingModel::Subscription { .. }),
+                        Error::<T>::SubscriptionNotSchedulable
+                    );
+                    let unit_price = sacrifice.get_effective_price(UserType::Standard, now)
+                        .ok_or(Error::<T>::PricingNotAvailable)?;
+                    unit_price.saturating_mul(quantity as u128)
+                },
+                CampaignPurpose::SubjectFunding => {
+                    ensure!(
+                        T::SubjectFunding::funding_account(target_type, target_id).is_some(),
+                        Error::<T>::NoFundingAccount
+                    );
+                    goal
+                },
+            };
+            ensure!(goal >= T::MinOfferAmount::get(), Error::<T>::AmountTooLow);
+
+            let deadline = now.saturating_add(duration);
+            let campaign_id = NextCampaignId::<T>::mutate(|n| {
+                let x = *n;
+                *n = x.saturating_add(1);
+                x
+            });
+            CampaignsByDeadline::<T>::try_mutate(deadline, |list| {
+                list.try_push(campaign_id).map_err(|_| Error::<T>::TooMany)
+            })?;
+            Campaigns::<T>::insert(campaign_id, OfferingCampaign::<T> {
+                organizer: organizer.clone(),
+                target_type,
+                target_id,
+                purpose,
+                goal,
+                raised: 0,
+                deadline,
+                status: CampaignStatus::Open,
+                contributions: BoundedVec::default(),
+                offering_id: None,
+            });
+
+            Self::deposit_event(Event::CampaignCreated {
+                campaign_id,
+                organizer,
+                target_type,
+                target_id,
+                purpose,
+                goal,
+                deadline,
+            });
+            Ok(())
+        }
+
+        /// 函数级详细中文注释：为众筹出资
+        ///
+        /// ### 规则
+        /// - 出资经 `pallet-escrow` 托管；超过剩余缺口的部分不扣
+        /// - 单笔不低于 `MinOfferAmount`（补足最后缺口除外），防止小额占满出资人名额
+        /// - 本笔出资使众筹筹满时立即执行；执行失败则众筹失败并全员退款（本交易仍成功）
+        #[pallet::call_index(28)]
+        #[pallet::weight(10_000)]
+        pub fn contribute_to_campaign(
+            origin: OriginFor<T>,
+            campaign_id: u64,
+            amount: u128,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut campaign = Campaigns::<T>::get(campaign_id)
+                .ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(campaign.status == CampaignStatus::Open, Error::<T>::CampaignNotOpen);
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() <= campaign.deadline,
+                Error::<T>::CampaignExpired
+            );
+
+            let remaining = campaign.goal.saturating_sub(campaign.raised);
+            let amount = amount.min(remaining);
+            ensure!(amount > 0, Error::<T>::BadInput);
+            ensure!(
+                amount >= T::MinOfferAmount::get() || amount == remaining,
+                Error::<T>::AmountTooLow
+            );
+
+            if let Some((_, contributed)) = campaign.contributions.iter_mut().find(|(acc, _)| *acc == who) {
+                *contributed = contributed.saturating_add(amount);
+            } else {
+                campaign.contributions
+                    .try_push((who.clone(), amount))
+                    .map_err(|_| Error::<T>::TooManyContributors)?;
+            }
+            T::Escrow::lock_from(&who, Self::campaign_escrow_id(campaign_id), amount.saturated_into())?;
+            campaign.raised = campaign.raised.saturating_add(amount);
+
+            Self::deposit_event(Event::CampaignContributed {
+                campaign_id,
+                who,
+                amount,
+                raised: campaign.raised,
+            });
+
+            if campaign.raised >= campaign.goal {
+                Self::settle_campaign(campaign_id, campaign);
+            } else {
+                Campaigns::<T>::insert(campaign_id, campaign);
+            }
+            Ok(())
+        }
+
+        /// 函数级中文注释：取消众筹并全员退款（仅发起人，募集中）
+        #[pallet::call_index(29)]
+        #[pallet::weight(10_000)]
+        pub fn cancel_campaign(origin: OriginFor<T>, campaign_id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut campaign = Campaigns::<T>::get(campaign_id)
+                .ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(campaign.organizer == who, Error::<T>::NotAllowed);
+            ensure!(campaign.status == CampaignStatus::Open, Error::<T>::CampaignNotOpen);
+
+            Self::refund_campaign(campaign_id, &campaign);
+            campaign.status = CampaignStatus::Cancelled;
+            let refunded = campaign.raised;
+            Campaigns::<T>::insert(campaign_id, campaign);
+
+            Self::deposit_event(Event::CampaignCancelled { campaign_id, refunded });
+            Ok(())
+        }
+    }
+
+    // ========================================
+    // P2新增：订单查询接口（只读）
+    // ========================================
+
+    impl<T: Config> Pallet<T> {
+        /// 函数级中文注释：查询单个订单详情
+        ///
+        /// ### 参数
+        /// - offering_id: 供奉订单ID
+        ///
+        /// ### 返回
+        /// - Some(record): 订单记录
+        /// - None: 订单不存在
+        pub fn get_offering(offering_id: u64) -> Option<OfferingRecord<T>> {
+            OfferingRecords::<T>::get(offering_id)
+        }
+
+        /// 函数级中文注释：查询用户的所有订单ID列表
+        ///
+        /// ### 参数
+        /// - who: 用户账户
+        ///
+        /// ### 返回
+        /// - Vec<u64>: 订单ID列表（按时间倒序）
+        ///
+        /// ### 注意
+        /// - 前端需要遍历ID列表，逐个调用 get_offering 获取详情
+        /// - 最多返回 MaxOfferingsPerTarget 条记录
+        pub fn get_offerings_by_user(who: &T::AccountId) -> Vec<u64> {
+            OfferingsByUser::<T>::get(who).into_inner()
+        }
+
+        /// 函数级中文注释：统计用户订单数量
+        ///
+        /// ### 参数
+        /// - who: 用户账户
+        ///
+        /// ### 返回
+        /// - u32: 订单总数
+        pub fn count_user_offerings(who: &T::AccountId) -> u32 {
+            OfferingsByUser::<T>::get(who).len() as u32
+        }
+
+        /// 函数级中文注释：批量查询订单详情
+        ///
+        /// ### 参数
+        /// - offering_ids: 订单ID列表
+        ///
+        /// ### 返回
+        /// - Vec<(u64, OfferingRecord<T>)>: (订单ID, 订单记录) 元组列表
+        ///
+        /// ### 注意
+        /// - 不存在的订单会被自动过滤
+        /// - 适用于前端分页展示场景
+        pub fn get_offerings_batch(offering_ids: Vec<u64>) -> Vec<(u64, OfferingRecord<T>)> {
+            offering_ids
+                .into_iter()
+                .filter_map(|id| {
+                    OfferingRecords::<T>::get(id).map(|record| (id, record))
+                })
+                .collect()
+        }
+    }
+
+    // ========================================
+    // 内部辅助函数
+    // ========================================
+
+    impl<T: Config> Pallet<T> {
+        /// 函数级详细中文注释：供奉下单核心逻辑（`offer_to_target` 与预约供奉共用）
+        ///
+        /// ### 返回
+        /// - `Ok(offering_id)`: 新建的供奉记录ID
+        ///
+        /// ### 注意
+        /// - 库存与限购计数在校验过程中即被修改，调用方需在事务内调用（extrinsic 默认事务；
+        ///   `on_initialize` 中通过 `with_storage_layer` 包裹）
+        /// - `locked_unit_price` 为 Some 时按该单价计价（众筹发起时按标准价锁定），不再按下单人等级定价
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) fn do_offer(
+            who: &T::AccountId,
+            target_type: TargetType,
+            target_id: u64,
+            sacrifice_id: u64,
+            quantity: u32,
+            media: Vec<Vec<u8>>,
+            duration_weeks: Option<u32>,
+            locked_unit_price: Option<u128>,
+        ) -> Result<u64, DispatchError> {
+
+            // 检查祭祀品是否存在
+            let sacrifice = SacrificeOf::<T>::get(sacrifice_id)
+                .ok_or(Error::<T>::SacrificeNotFound)?;
+
+            // 检查商品状态
+            ensure!(
+                matches!(sacrifice.status, SacrificeStatus::Enabled),
+                Error::<T>::SacrificeNotEnabled
+            );
+
+            ensure!(sacrifice.pricing.enabled, Error::<T>::SacrificeNotEnabled);
+
+            // P1-1: 原子性检查和扣减库存
+            if sacrifice.pricing.stock >= 0 {
+                SacrificeStock::<T>::try_mutate(sacrifice_id, |stock| -> DispatchResult {
+                    ensure!(
+                        *stock >= quantity as i32,
+                        Error::<T>::InsufficientStock
+                    );
+                    *stock = stock.saturating_sub(quantity as i32);
+                    Ok(())
+                })?;
+            }
+
+            // 检查用户购买限制
+            if let Some(limit) = sacrifice.pricing.per_user_limit {
+                UserPurchaseCount::<T>::try_mutate(who, sacrifice_id, |count| -> DispatchResult {
+                    ensure!(
+                        count.saturating_add(quantity) <= limit,
+                        Error::<T>::PurchaseLimitExceeded
+                    );
+                    *count = count.saturating_add(quantity);
+                    Ok(())
+                })?;
+            } else {
+                UserPurchaseCount::<T>::mutate(who, sacrifice_id, |count| {
+                    *count = count.saturating_add(quantity);
+                });
+            }
+
+            // 🆕 P4：通过 OfferingTarget trait 检查目标存在性与供奉权限
+            let (exists, accessible) = match target_type {
+                TargetType::Deceased => (
+                    T::DeceasedTarget::exists(target_id),
+                    T::DeceasedTarget::is_accessible(who, target_id),
+                ),
+                TargetType::Pet => (
+                    T::PetTarget::exists(target_id),
+                    T::PetTarget::is_accessible(who, target_id),
+                ),
+                TargetType::Memorial => (
+                    T::SpaceTarget::exists(target_id),
+                    T::SpaceTarget::is_accessible(who, target_id),
+                ),
+                _ => return Err(Error::<T>::TargetNotSupported.into()),
+            };
+            ensure!(exists, Error::<T>::TargetNotFound);
+            ensure!(accessible, Error::<T>::NotAllowed);
+
+            // P1-2: 完善用户类型判断（支持 VIP）；锁定单价按标准价计
+            let user_type_enum = if locked_unit_price.is_some() {
+                UserType::Standard
+            } else {
+                Self::determine_user_type(who)
+            };
+
+            let user_type_code = match user_type_enum {
+                UserType::Standard => 0,
+                UserType::Member => 1,
+                UserType::VIP => 2,
+            };
+
+            // 计算价格
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let unit_price = match locked_unit_price {
+                Some(price) => price,
+                None => sacrifice.get_effective_price(user_type_enum, current_block)
+                    .ok_or(Error::<T>::PricingNotAvailable)?,
+            };
+
+            let total_amount = unit_price.saturating_mul(quantity as u128);
+
+            // 验证最小金额
+            let min_amount = T::MinOfferAmount::get();
+            ensure!(
+                total_amount >= min_amount,
+                Error::<T>::AmountTooLow
+            );
+
+            // P1-3 + P2-8: 验证订阅类商品的duration_weeks
+            match &sacrifice.pricing.model {
+                PricingModel::Subscription { weekly_price: _, min_weeks, max_weeks, .. } => {
+                    let weeks = duration_weeks.ok_or(Error::<T>::AmountRequired)?;
+
+                    ensure!(
+                        weeks >= *min_weeks,
+                        Error::<T>::BadInput
+                    );
+
+                    if let Some(max) = max_weeks {
+                        ensure!(
+                            weeks <= *max,
+                            Error::<T>::BadInput
+                        );
+                    }
+                },
+                _ => {}
+            }
+
+            // 限频控制（使用 target_id 替代 grave_id）
+            let now = <frame_system::Pallet<T>>::block_number();
+            Self::check_rate_limit(who, target_id, now)?;
+
+            // 🆕 P4：转账逻辑（获取目标所有者）
+            // TODO: 实现通用转账函数，支持 target_type 路由
+            // 当前简化版本：使用旧的 transfer_with_simple_route
+            // Self::transfer_to_target(&who, target_type, target_id, total_amount, sacrifice_id, duration_weeks)?;
+
+            // 临时方案：跳过转账（后续实现通用转账）
+            // 注意：这会导致资金未转移，仅用于架构验证
+            // Self::transfer_with_simple_route(&who, target_id, total_amount, sacrifice_id, duration_weeks)?;
+
+            // 构造媒体列表
+            let media_items: Result<BoundedVec<MediaItem<T>, T::MaxMediaPerOffering>, _> =
+                media.into_iter()
+                    .map(|cid_vec| {
+                        let cid_bv = BoundedVec::try_from(cid_vec).map_err(|_| Error::<T>::BadInput)?;
+                        Ok(MediaItem { cid: cid_bv })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .and_then(|vec| BoundedVec::try_from(vec).map_err(|_| Error::<T>::BadInput));
+
+            let media_items = media_items?;
+
+            // 创建供奉记录
+            let offering_id = NextOfferingId::<T>::mutate(|n| {
+                let x = *n;
+                *n = x.saturating_add(1);
+                x
+            });
+
+            let now = <frame_system::Pallet<T>>::block_number();
+
+            // P3：根据商品类型设置状态和到期时间
             let (status, expiry_block, auto_renew) = match &sacrifice.pricing.model {
                 PricingModel::Subscription { auto_renew: model_auto_renew, .. } => {
                     let weeks = duration_weeks.unwrap_or(4);
@@ -988,20 +1707,21 @@ pub mod pallet {
             // })?;
 
             // P2新增：更新用户索引
-            OfferingsByUser::<T>::try_mutate(&who, |list| {
+            OfferingsByUser::<T>::try_mutate(who, |list| {
                 list.try_push(offering_id).map_err(|_| Error::<T>::BadInput)
             })?;
 
-            // 🆕 P4：调用回调（传递 target_id 而非 grave_id）
-            // TODO: 扩展 OnOfferingCommitted trait 支持 target_type
-            // T::OnOfferingCommitted::on_offering_to_target(
-            //     target_type,
-            //     target_id,
-            //     sacrifice_id,
-            //     &who,
-            //     total_amount,
-            //     duration_weeks,
-            // );
+            // 🆕 落账回调（按目标类型，ledger 生成回执并更新周排行）
+            T::OnOfferingRecorded::on_recorded(
+                offering_id,
+                target_type,
+                target_id,
+                sacrifice_id,
+                sacrifice.primary_category as u8,
+                who,
+                total_amount,
+                duration_weeks,
+            );
 
             // P3新增：根据商品类型发送不同事件
             match &sacrifice.pricing.model {
@@ -1032,245 +1752,258 @@ pub mod pallet {
                 }
             }
 
-            Ok(())
+            Ok(offering_id)
         }
 
-        // ========================================
-        // 管理函数
-        // ========================================
-
-        /// 函数级中文注释：设置风控参数
-        #[pallet::call_index(20)]
-        #[pallet::weight(10_000)]
-        pub fn set_offer_params(
-            origin: OriginFor<T>,
-            offer_window: Option<BlockNumberFor<T>>,
-            offer_max_in_window: Option<u32>,
-            min_offer_amount: Option<u128>,
-        ) -> DispatchResult {
-            T::AdminOrigin::try_origin(origin).map_err(|_| DispatchError::BadOrigin)?;
-
-            if let Some(v) = offer_window {
-                OfferWindowParam::<T>::put(v);
-            }
-            if let Some(v) = offer_max_in_window {
-                OfferMaxInWindowParam::<T>::put(v);
+        /// 函数级详细中文注释：执行到期的预约供奉，返回消耗的权重
+        ///
+        /// ### 流程
+        /// 1. 游标从上次处理日推进到今天（停机后补执行，不跳过）
+        /// 2. 每条预约在独立存储层中下单并扣款，失败回滚并发出 `ScheduledOfferingFailed`
+        /// 3. 重复预约按纪念日历排下一次，否则结束
+        /// 4. 每块最多执行 `MaxScheduledOfferingsPerBlock` 条，剩余留待下一块
+        fn process_scheduled_offerings() -> Weight {
+            let db = T::DbWeight::get();
+            let mut weight = db.reads(1);
+            let today = T::RemembranceCalendar::today();
+            if today == 0 {
+                // 未接入纪念日历
+                return weight;
             }
-            if let Some(v) = min_offer_amount {
-                MinOfferAmountParam::<T>::put(v);
+            let mut cursor = ScheduledOfferingCursor::<T>::get();
+            if cursor == 0 {
+                cursor = today;
             }
+            let mut budget = T::MaxScheduledOfferingsPerBlock::get() as usize;
+
+            while cursor <= today && budget > 0 {
+                let mut due = ScheduledOfferingsByDay::<T>::get(cursor).into_inner();
+                weight = weight.saturating_add(db.reads(1));
+                let take = due.len().min(budget);
+                let rest = due.split_off(take);
+                budget -= take;
+
+                for schedule_id in due {
+                    Self::execute_schedule(schedule_id, cursor);
+                    weight = weight.saturating_add(db.reads_writes(8, 8));
+                }
 
-            Self::deposit_event(Event::OfferParamsUpdated);
-            Ok(())
-        }
+                if rest.is_empty() {
+                    ScheduledOfferingsByDay::<T>::remove(cursor);
+                    cursor = cursor.saturating_add(1);
+                } else {
+                    ScheduledOfferingsByDay::<T>::insert(cursor, BoundedVec::truncate_from(rest));
+                }
+                weight = weight.saturating_add(db.writes(1));
+            }
 
-        /// 函数级中文注释：设置全局暂停
-        #[pallet::call_index(21)]
-        #[pallet::weight(10_000)]
-        pub fn set_pause_global(origin: OriginFor<T>, paused: bool) -> DispatchResult {
-            T::AdminOrigin::try_origin(origin).map_err(|_| DispatchError::BadOrigin)?;
-            PausedGlobal::<T>::put(paused);
-            Self::deposit_event(Event::PausedGlobalSet { paused });
-            Ok(())
+            ScheduledOfferingCursor::<T>::put(cursor);
+            weight.saturating_add(db.writes(1))
         }
 
-        /// 函数级中文注释：设置分账配置
-        #[pallet::call_index(22)]
-        #[pallet::weight(10_000)]
-        pub fn set_route_config(
-            origin: OriginFor<T>,
-            subject_percent: u8,
-            platform_percent: u8,
-        ) -> DispatchResult {
-            T::AdminOrigin::try_origin(origin).map_err(|_| DispatchError::BadOrigin)?;
+        /// 函数级中文注释：执行单条预约供奉并排下一次
+        fn execute_schedule(schedule_id: u64, day: u32) {
+            // 已取消或已改期的预约直接跳过
+            let Some(mut schedule) = ScheduledOfferings::<T>::get(schedule_id) else { return };
+            if schedule.next_day != day {
+                return;
+            }
 
-            ensure!(
-                subject_percent.saturating_add(platform_percent) == 100,
-                Error::<T>::BadInput
-            );
+            let result = frame_support::storage::with_storage_layer(|| {
+                Self::do_offer(
+                    &schedule.who,
+                    schedule.target_type,
+                    schedule.target_id,
+                    schedule.sacrifice_id,
+                    schedule.quantity,
+                    Vec::new(),
+                    None,
+                    None,
+                )
+                .and_then(|offering_id| {
+                    Self::charge_offering(&schedule.who, offering_id)?;
+                    Ok(offering_id)
+                })
+            });
+            match result {
+                Ok(offering_id) => {
+                    schedule.executions = schedule.executions.saturating_add(1);
+                    Self::deposit_event(Event::ScheduledOfferingExecuted {
+                        schedule_id,
+                        offering_id,
+                        who: schedule.who.clone(),
+                    });
+                },
+                Err(error) => {
+                    Self::deposit_event(Event::ScheduledOfferingFailed {
+                        schedule_id,
+                        who: schedule.who.clone(),
+                        error,
+                    });
+                },
+            }
 
-            let config = SimpleRoute {
-                subject_percent,
-                platform_percent,
+            let next = if schedule.recurring {
+                T::RemembranceCalendar::next_occurrence(
+                    schedule.target_type,
+                    schedule.target_id,
+                    schedule.occasion,
+                    day.saturating_add(1),
+                )
+            } else {
+                None
             };
-
-            RouteConfig::<T>::put(config);
-            Self::deposit_event(Event::RouteConfigUpdated {
-                subject_percent,
-                platform_percent,
-            });
-            Ok(())
+            match next {
+                Some(next_day) => {
+                    if ScheduledOfferingsByDay::<T>::try_mutate(next_day, |list| list.try_push(schedule_id)).is_ok() {
+                        schedule.next_day = next_day;
+                        ScheduledOfferings::<T>::insert(schedule_id, schedule);
+                    } else {
+                        // 下一次执行日队列已满：结束预约并通知预约人重新登记
+                        Self::remove_schedule(schedule_id, &schedule.who);
+                        Self::deposit_event(Event::ScheduledOfferingRequeueFailed {
+                            schedule_id,
+                            who: schedule.who,
+                            next_day,
+                        });
+                    }
+                },
+                None => {
+                    Self::remove_schedule(schedule_id, &schedule.who);
+                    Self::deposit_event(Event::ScheduledOfferingFinished { schedule_id });
+                },
+            }
         }
 
-        // ========================================
-        // P3新增：订阅管理函数
-        // ========================================
-
-        /// 函数级中文注释：手动续费订阅
-        ///
-        /// ### 参数
-        /// - `offering_id`: 订单ID
-        ///
-        /// ### 权限
-        /// - 仅订单所有者可续费
-        /// - 订单必须是Active状态
-        ///
-        /// ### 逻辑
-        /// 1. 验证权限和状态
-        /// 2. 查询祭祀品价格
-        /// 3. 扣费并更新到期时间
-        /// 4. 发送续费成功事件
-        #[pallet::call_index(23)]
-        #[pallet::weight(10_000)]
-        pub fn renew_subscription(
-            origin: OriginFor<T>,
-            offering_id: u64,
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
-
-            // 1. 获取订单记录
-            let mut record = OfferingRecords::<T>::get(offering_id)
-                .ok_or(Error::<T>::NotFound)?;
-
-            // 2. 验证权限
-            ensure!(record.who == who, Error::<T>::NotAllowed);
-
-            // 3. 验证状态（只有Active状态可续费）
-            ensure!(
-                record.status == OfferingStatus::Active,
-                Error::<T>::NotAllowed
-            );
-
-            // 4. 执行续费（复用自动续费逻辑）
-            Self::try_auto_renew(offering_id, &mut record)?;
-
-            // 5. 发送事件
-            Self::deposit_event(Event::SubscriptionRenewed {
-                offering_id,
-                who,
-                new_expiry: record.expiry_block.unwrap_or_else(|| <frame_system::Pallet<T>>::block_number()),
-                amount: record.amount,
-            });
-
-            Ok(())
+        /// 函数级中文注释：众筹托管ID
+        pub fn campaign_escrow_id(campaign_id: u64) -> u64 {
+            CAMPAIGN_ESCROW_NAMESPACE | campaign_id
         }
 
-        /// 函数级中文注释：取消订阅（设置auto_renew=false）
-        ///
-        /// ### 参数
-        /// - `offering_id`: 订单ID
-        ///
-        /// ### 权限
-        /// - 仅订单所有者可取消
-        /// - 订单必须是Active状态
-        ///
-        /// ### 效果
-        /// - 设置auto_renew=false，下次到期后不再自动续费
-        /// - 不退款，订阅持续到当前周期结束
-        #[pallet::call_index(24)]
-        #[pallet::weight(10_000)]
-        pub fn cancel_subscription(
-            origin: OriginFor<T>,
-            offering_id: u64,
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
-
-            // 1. 获取订单记录
-            OfferingRecords::<T>::try_mutate(offering_id, |maybe_record| -> DispatchResult {
-                let record = maybe_record.as_mut().ok_or(Error::<T>::NotFound)?;
-
-                // 2. 验证权限
-                ensure!(record.who == who, Error::<T>::NotAllowed);
-
-                // 3. 验证状态
-                ensure!(
-                    record.status == OfferingStatus::Active,
-                    Error::<T>::NotAllowed
+        /// 函数级详细中文注释：结算本块截止的众筹，返回消耗的权重
+        /// - 仍在募集中的众筹视为未筹满：逐笔退款并标记失败
+        fn process_campaign_deadlines(block_number: BlockNumberFor<T>) -> Weight {
+            let db = T::DbWeight::get();
+            let due = CampaignsByDeadline::<T>::take(block_number);
+            let mut weight = db.reads_writes(1, 1);
+
+            for campaign_id in due {
+                weight = weight.saturating_add(db.reads(1));
+                let Some(mut campaign) = Campaigns::<T>::get(campaign_id) else { continue };
+                if campaign.status != CampaignStatus::Open {
+                    continue;
+                }
+                Self::refund_campaign(campaign_id, &campaign);
+                weight = weight.saturating_add(
+                    db.reads_writes(2, 2).saturating_mul(campaign.contributions.len() as u64),
                 );
+                campaign.status = CampaignStatus::Failed;
+                let raised = campaign.raised;
+                Campaigns::<T>::insert(campaign_id, campaign);
+                weight = weight.saturating_add(db.writes(1));
 
-                // 4. 关闭自动续费
-                record.auto_renew = false;
-
-                // 5. 发送事件
-                Self::deposit_event(Event::SubscriptionCancelled {
-                    offering_id,
-                    who: who.clone(),
-                });
-
-                Ok(())
-            })
+                Self::deposit_event(Event::CampaignFailed { campaign_id, raised, error: None });
+            }
+            weight
         }
-    }
-
-    // ========================================
-    // P2新增：订单查询接口（只读）
-    // ========================================
 
-    impl<T: Config> Pallet<T> {
-        /// 函数级中文注释：查询单个订单详情
+        /// 函数级详细中文注释：筹满后执行众筹
         ///
-        /// ### 参数
-        /// - offering_id: 供奉订单ID
-        ///
-        /// ### 返回
-        /// - Some(record): 订单记录
-        /// - None: 订单不存在
-        pub fn get_offering(offering_id: u64) -> Option<OfferingRecord<T>> {
-            OfferingRecords::<T>::get(offering_id)
-        }
+        /// ### 执行
+        /// - 供奉用途：以发起人名义按发起时锁定的标准价下单（记录金额 = 已筹金额），
+        ///   托管全额释放给发起人后按常规供奉分账支付（同 `charge_offering`）
+        /// - 资助用途：托管全额释放到目标的存储资金账户
+        /// - 任一步失败整体回滚，众筹标记失败并逐笔退款
+        fn settle_campaign(campaign_id: u64, mut campaign: OfferingCampaign<T>) {
+            let escrow_id = Self::campaign_escrow_id(campaign_id);
+            let result = frame_support::storage::with_storage_layer(|| -> Result<Option<u64>, DispatchError> {
+                match campaign.purpose {
+                    CampaignPurpose::Offering { sacrifice_id, quantity } => {
+                        // 目标金额 = 标准单价 × 数量，整除即发起时锁定的单价
+                        let unit_price = campaign.goal / quantity as u128;
+                        let offering_id = Self::do_offer(
+                            &campaign.organizer,
+                            campaign.target_type,
+                            campaign.target_id,
+                            sacrifice_id,
+                            quantity,
+                            Vec::new(),
+                            None,
+                            Some(unit_price),
+                        )?;
+                        T::Escrow::release_all(escrow_id, &campaign.organizer)?;
+                        Self::charge_offering(&campaign.organizer, offering_id)?;
+                        CampaignOfOffering::<T>::insert(offering_id, campaign_id);
+                        Ok(Some(offering_id))
+                    },
+                    CampaignPurpose::SubjectFunding => {
+                        let to = T::SubjectFunding::funding_account(campaign.target_type, campaign.target_id)
+                            .ok_or(Error::<T>::NoFundingAccount)?;
+                        T::Escrow::release_all(escrow_id, &to)?;
+                        Ok(None)
+                    },
+                }
+            });
 
-        /// 函数级中文注释：查询用户的所有订单ID列表
-        ///
-        /// ### 参数
-        /// - who: 用户账户
-        ///
-        /// ### 返回
-        /// - Vec<u64>: 订单ID列表（按时间倒序）
-        ///
-        /// ### 注意
-        /// - 前端需要遍历ID列表，逐个调用 get_offering 获取详情
-        /// - 最多返回 MaxOfferingsPerTarget 条记录
-        pub fn get_offerings_by_user(who: &T::AccountId) -> Vec<u64> {
-            OfferingsByUser::<T>::get(who).into_inner()
+            match result {
+                Ok(offering_id) => {
+                    campaign.status = CampaignStatus::Succeeded;
+                    campaign.offering_id = offering_id;
+                    Self::deposit_event(Event::CampaignSucceeded {
+                        campaign_id,
+                        raised: campaign.raised,
+                        offering_id,
+                        contributors: campaign.contributions.clone(),
+                    });
+                },
+                Err(error) => {
+                    Self::refund_campaign(campaign_id, &campaign);
+                    campaign.status = CampaignStatus::Failed;
+                    Self::deposit_event(Event::CampaignFailed {
+                        campaign_id,
+                        raised: campaign.raised,
+                        error: Some(error),
+                    });
+                },
+            }
+            Campaigns::<T>::insert(campaign_id, campaign);
         }
 
-        /// 函数级中文注释：统计用户订单数量
-        ///
-        /// ### 参数
-        /// - who: 用户账户
-        ///
-        /// ### 返回
-        /// - u32: 订单总数
-        pub fn count_user_offerings(who: &T::AccountId) -> u32 {
-            OfferingsByUser::<T>::get(who).len() as u32
+        /// 函数级中文注释：按供奉记录金额走常规分账（与续费相同，经 `OnOfferingCommitted` 分账）
+        /// - 付款人可用余额不足时返回 `InsufficientBalance`，不触发分账
+        fn charge_offering(who: &T::AccountId, offering_id: u64) -> DispatchResult {
+            let record = OfferingRecords::<T>::get(offering_id).ok_or(Error::<T>::NotFound)?;
+            let balance: u128 = T::Currency::free_balance(who).saturated_into();
+            ensure!(balance >= record.amount, Error::<T>::InsufficientBalance);
+            Self::transfer_with_simple_route(
+                who,
+                record.target_id,
+                record.amount,
+                record.sacrifice_id,
+                record.duration_weeks,
+            )
         }
 
-        /// 函数级中文注释：批量查询订单详情
-        ///
-        /// ### 参数
-        /// - offering_ids: 订单ID列表
-        ///
-        /// ### 返回
-        /// - Vec<(u64, OfferingRecord<T>)>: (订单ID, 订单记录) 元组列表
-        ///
-        /// ### 注意
-        /// - 不存在的订单会被自动过滤
-        /// - 适用于前端分页展示场景
-        pub fn get_offerings_batch(offering_ids: Vec<u64>) -> Vec<(u64, OfferingRecord<T>)> {
-            offering_ids
-                .into_iter()
-                .filter_map(|id| {
-                    OfferingRecords::<T>::get(id).map(|record| (id, record))
-                })
-                .collect()
+        /// 函数级中文注释：按出资明细逐笔退款（单笔失败不阻断其他退款）
+        fn refund_campaign(campaign_id: u64, campaign: &OfferingCampaign<T>) {
+            let escrow_id = Self::campaign_escrow_id(campaign_id);
+            for (who, amount) in campaign.contributions.iter() {
+                if T::Escrow::transfer_from_escrow(escrow_id, who, (*amount).saturated_into()).is_err() {
+                    Self::deposit_event(Event::CampaignRefundFailed {
+                        campaign_id,
+                        who: who.clone(),
+                        amount: *amount,
+                    });
+                }
+            }
         }
-    }
 
-    // ========================================
-    // 内部辅助函数
-    // ========================================
+        /// 函数级中文注释：删除预约记录与用户索引（日索引中的残留ID执行时跳过）
+        fn remove_schedule(schedule_id: u64, who: &T::AccountId) {
+            ScheduledOfferings::<T>::remove(schedule_id);
+            ScheduledOfferingsByUser::<T>::mutate(who, |list| list.retain(|id| *id != schedule_id));
+        }
 
-    impl<T: Config> Pallet<T> {
         /// 函数级中文注释：P3新增 - 尝试自动续费
         ///
         /// ### 参数
@@ -1,4 +1,245 @@
-//! 函数级中文注释：Mock 环境用于单元测试（占位符）
+//! 函数级中文注释：pallet-memorial 的 Mock Runtime，用于单元测试
+//!
+//! - 逝者 1~9 存在且可供奉；逝者 9 没有存储资金账户
+//! - 宠物 21~29 存在且可供奉（没有存储资金账户）
+//! - 托管为真实余额划转：出资转入 `ESCROW_ACCOUNT`，按托管ID内存记账
+//! - 供奉分账回调把金额从付款人转到 `PLATFORM`（模拟 affiliate 分账）并记录
+//! - 纪念日历：今天由 `Today` 控制；逝者与宠物的场合 0 为每 365 天一次，逢 `ANNIVERSARY_OFFSET` 日
 
-// TODO: 在后续的测试阶段实现 mock 环境
+use crate as pallet_memorial;
+use crate::{MembershipProvider, OfferingTarget, RemembranceCalendar, SubjectFundingProvider, TargetType};
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU128, ConstU32, ConstU64, Currency, ExistenceRequirement, Hooks},
+    BoundedVec, PalletId,
+};
+use sp_runtime::{BuildStorage, DispatchError, DispatchResult};
+use std::{cell::RefCell, collections::BTreeMap};
 
+type Block = frame_system::mocking::MockBlock<Test>;
+
+/// 托管账户
+pub const ESCROW_ACCOUNT: u64 = 900;
+/// 分账收款账户（模拟 affiliate 分账）
+pub const PLATFORM: u64 = 901;
+/// 存储资金账户 = FUNDING_BASE + 逝者ID
+pub const FUNDING_BASE: u64 = 1000;
+/// 没有存储资金账户的逝者
+pub const NO_FUNDING_DECEASED: u64 = 9;
+/// Mock 宠物ID（宠物 21~29 存在）
+pub const PET_ID: u64 = 21;
+/// 场合 0 的纪念日在每 365 天周期中的偏移
+pub const ANNIVERSARY_OFFSET: u32 = 100;
+/// 初始余额
+pub const INITIAL_BALANCE: u128 = 10_000;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        Memorial: pallet_memorial,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u128>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+    type Balance = u128;
+}
+
+parameter_types! {
+    pub const MemorialPalletId: PalletId = PalletId(*b"memorial");
+    /// 今天（纪元日）；0 表示未接入纪念日历
+    pub static Today: u32 = 0;
+}
+
+thread_local! {
+    /// 托管余额（托管ID → 金额）
+    pub static ESCROWED: RefCell<BTreeMap<u64, u128>> = RefCell::new(BTreeMap::new());
+    /// 分账回调记录（付款人, 金额）
+    pub static COMMITTED: RefCell<Vec<(u64, u128)>> = RefCell::new(Vec::new());
+    /// 会员账户
+    pub static MEMBERS: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+}
+
+/// Mock 托管：出资转入托管账户，按托管ID记账
+pub struct MockEscrow;
+impl MockEscrow {
+    fn pay_out(id: u64, to: &u64, amount: u128) -> DispatchResult {
+        ESCROWED.with(|m| {
+            let mut m = m.borrow_mut();
+            let cur = m.get_mut(&id).ok_or(DispatchError::Other("NoLock"))?;
+            *cur = cur.checked_sub(amount).ok_or(DispatchError::Other("Insufficient"))?;
+            Ok::<_, DispatchError>(())
+        })?;
+        <Balances as Currency<u64>>::transfer(&ESCROW_ACCOUNT, to, amount, ExistenceRequirement::AllowDeath)
+    }
+}
+impl pallet_escrow::Escrow<u64, u128> for MockEscrow {
+    fn lock_from(payer: &u64, id: u64, amount: u128) -> DispatchResult {
+        <Balances as Currency<u64>>::transfer(payer, &ESCROW_ACCOUNT, amount, ExistenceRequirement::KeepAlive)?;
+        ESCROWED.with(|m| *m.borrow_mut().entry(id).or_default() += amount);
+        Ok(())
+    }
+    fn transfer_from_escrow(id: u64, to: &u64, amount: u128) -> DispatchResult {
+        Self::pay_out(id, to, amount)
+    }
+    fn release_all(id: u64, to: &u64) -> DispatchResult {
+        Self::pay_out(id, to, Self::amount_of(id))
+    }
+    fn refund_all(id: u64, to: &u64) -> DispatchResult {
+        Self::pay_out(id, to, Self::amount_of(id))
+    }
+    fn amount_of(id: u64) -> u128 {
+        ESCROWED.with(|m| m.borrow().get(&id).copied().unwrap_or(0))
+    }
+}
+
+/// Mock 供奉分账：从付款人转到 PLATFORM（与 runtime 一样忽略分账失败）
+pub struct MockOfferingHook;
+impl pallet_memorial::OnOfferingCommitted<u64> for MockOfferingHook {
+    fn on_offering(_grave_id: u64, _sacrifice_id: u64, who: &u64, amount: u128, _duration_weeks: Option<u32>) {
+        let _ = <Balances as Currency<u64>>::transfer(who, &PLATFORM, amount, ExistenceRequirement::KeepAlive);
+        COMMITTED.with(|c| c.borrow_mut().push((*who, amount)));
+    }
+}
+
+/// Mock 会员：`MEMBERS` 中的账户为会员
+pub struct MockMembership;
+impl MembershipProvider<u64> for MockMembership {
+    fn is_valid_member(who: &u64) -> bool {
+        MEMBERS.with(|m| m.borrow().contains(who))
+    }
+    fn get_discount() -> u8 {
+        0
+    }
+}
+
+/// Mock 逝者目标：ID 1~9 存在，任何人可供奉
+pub struct MockDeceasedTarget;
+impl OfferingTarget<u64> for MockDeceasedTarget {
+    fn exists(target_id: u64) -> bool {
+        (1..=9).contains(&target_id)
+    }
+    fn get_owner(target_id: u64) -> Option<u64> {
+        Self::exists(target_id).then_some(target_id)
+    }
+    fn is_accessible(_who: &u64, target_id: u64) -> bool {
+        Self::exists(target_id)
+    }
+    fn get_display_name(_target_id: u64) -> Option<BoundedVec<u8, ConstU32<256>>> {
+        None
+    }
+}
+
+/// Mock 宠物目标：ID 21~29 存在，任何人可供奉
+pub struct MockPetTarget;
+impl OfferingTarget<u64> for MockPetTarget {
+    fn exists(target_id: u64) -> bool {
+        (21..=29).contains(&target_id)
+    }
+    fn get_owner(target_id: u64) -> Option<u64> {
+        Self::exists(target_id).then_some(target_id)
+    }
+    fn is_accessible(_who: &u64, target_id: u64) -> bool {
+        Self::exists(target_id)
+    }
+    fn get_display_name(_target_id: u64) -> Option<BoundedVec<u8, ConstU32<256>>> {
+        None
+    }
+}
+
+/// Mock 纪念日历：逝者与宠物的场合 0，每 365 天逢 `ANNIVERSARY_OFFSET` 日
+pub struct MockCalendar;
+impl RemembranceCalendar for MockCalendar {
+    fn today() -> u32 {
+        Today::get()
+    }
+    fn next_occurrence(target_type: TargetType, target_id: u64, occasion: u8, from_day: u32) -> Option<u32> {
+        let exists = match target_type {
+            TargetType::Deceased => MockDeceasedTarget::exists(target_id),
+            TargetType::Pet => MockPetTarget::exists(target_id),
+            _ => false,
+        };
+        if !exists || occasion != 0 {
+            return None;
+        }
+        let base = from_day - from_day % 365 + ANNIVERSARY_OFFSET;
+        Some(if base >= from_day { base } else { base + 365 })
+    }
+}
+
+/// Mock 存储资金账户：逝者 1~8 有账户
+pub struct MockSubjectFunding;
+impl SubjectFundingProvider<u64> for MockSubjectFunding {
+    fn funding_account(target_type: TargetType, target_id: u64) -> Option<u64> {
+        (target_type == TargetType::Deceased
+            && MockDeceasedTarget::exists(target_id)
+            && target_id != NO_FUNDING_DECEASED)
+            .then_some(FUNDING_BASE + target_id)
+    }
+}
+
+impl pallet_memorial::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type StringLimit = ConstU32<64>;
+    type UriLimit = ConstU32<128>;
+    type DescriptionLimit = ConstU32<256>;
+    type MaxCidLen = ConstU32<64>;
+    type MaxOfferingsPerTarget = ConstU32<100>;
+    type MaxMediaPerOffering = ConstU32<4>;
+    type OfferWindow = ConstU64<10>;
+    type OfferMaxInWindow = ConstU32<100>;
+    type MinOfferAmount = ConstU128<10>;
+    type RenewalCheckInterval = ConstU32<100>;
+    type AdminOrigin = frame_system::EnsureRoot<u64>;
+    type Currency = Balances;
+    type OnOfferingCommitted = MockOfferingHook;
+    type MembershipProvider = MockMembership;
+    type DeceasedTarget = MockDeceasedTarget;
+    type PetTarget = MockPetTarget;
+    type SpaceTarget = ();
+    type OnOfferingRecorded = ();
+    type PalletId = MemorialPalletId;
+    type RemembranceCalendar = MockCalendar;
+    type MaxScheduledOfferingsPerBlock = ConstU32<2>;
+    type Escrow = MockEscrow;
+    type SubjectFunding = MockSubjectFunding;
+    type MaxCampaignContributors = ConstU32<3>;
+    type MaxCampaignDuration = ConstU64<100>;
+}
+
+/// 函数级中文注释：推进到指定区块并执行 on_initialize
+pub fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        System::set_block_number(System::block_number() + 1);
+        Memorial::on_initialize(System::block_number());
+    }
+}
+
+/// 函数级中文注释：构建测试环境
+/// - 账户 1~5 各有 `INITIAL_BALANCE`
+/// - 限频参数放宽（窗口 10 块内最多 100 次）
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=5).map(|who| (who, INITIAL_BALANCE)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        pallet_memorial::OfferWindowParam::<Test>::put(10);
+        pallet_memorial::OfferMaxInWindowParam::<Test>::put(100);
+    });
+    ext
+}
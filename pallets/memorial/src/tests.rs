@@ -1,4 +1,594 @@
-//! 函数级中文注释：单元测试（占位符）
+//! 函数级中文注释：pallet-memorial 单元测试
 
-// TODO: 在后续的测试阶段实现单元测试
+use crate::{
+    mock::*, CampaignOfOffering, CampaignPurpose, CampaignStatus, Campaigns, CampaignsByDeadline,
+    Error, Event, OfferingRecords, PricingModel, SacrificeOf, ScheduledOfferings,
+    ScheduledOfferingsByDay, ScheduledOfferingsByUser, TargetType,
+};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
 
+/// 辅助函数：创建一次性祭祀品（单价 `price`，库存 `stock`，-1 为不限）
+fn create_sacrifice(price: u128, stock: i32) -> u64 {
+    let id = crate::NextSacrificeId::<Test>::get();
+    assert_ok!(Memorial::create_sacrifice(
+        RuntimeOrigin::root(),
+        b"White chrysanthemum".to_vec(),
+        b"desc".to_vec(),
+        b"ipfs://flower".to_vec(),
+        0,
+        0,
+        price,
+        stock,
+        None,
+        0,
+        false,
+    ));
+    id
+}
+
+/// 辅助函数：账户 1 为逝者 1 发起供奉众筹，募集期 10 块
+fn create_offering_campaign(sacrifice_id: u64, quantity: u32) -> u64 {
+    let campaign_id = crate::NextCampaignId::<Test>::get();
+    assert_ok!(Memorial::create_campaign(
+        RuntimeOrigin::signed(1),
+        TargetType::Deceased,
+        1,
+        CampaignPurpose::Offering { sacrifice_id, quantity },
+        0,
+        10,
+    ));
+    campaign_id
+}
+
+/// 辅助函数：账户 `who` 预约为逝者 1 供奉（场合 0）
+fn schedule(who: u64, sacrifice_id: u64, quantity: u32, recurring: bool) -> u64 {
+    let schedule_id = crate::NextScheduledOfferingId::<Test>::get();
+    assert_ok!(Memorial::schedule_offering(
+        RuntimeOrigin::signed(who),
+        TargetType::Deceased,
+        1,
+        sacrifice_id,
+        quantity,
+        0,
+        recurring,
+    ));
+    schedule_id
+}
+
+/// 辅助函数：把"今天"设为 `day` 并推进一个区块
+fn advance_to_day(day: u32) {
+    Today::set(day);
+    run_to_block(System::block_number() + 1);
+}
+
+// ==================== 预约供奉 ====================
+
+#[test]
+fn schedule_offering_queues_next_occurrence() {
+    new_test_ext().execute_with(|| {
+        Today::set(50);
+        let sacrifice_id = create_sacrifice(100, -1);
+        let schedule_id = schedule(1, sacrifice_id, 2, true);
+
+        let scheduled = ScheduledOfferings::<Test>::get(schedule_id).unwrap();
+        assert_eq!(scheduled.next_day, ANNIVERSARY_OFFSET);
+        assert_eq!(scheduled.executions, 0);
+        assert_eq!(ScheduledOfferingsByDay::<Test>::get(ANNIVERSARY_OFFSET).into_inner(), vec![schedule_id]);
+        assert_eq!(ScheduledOfferingsByUser::<Test>::get(1).into_inner(), vec![schedule_id]);
+        // 登记时不扣款
+        assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn schedule_offering_validates_input() {
+    new_test_ext().execute_with(|| {
+        Today::set(50);
+        let sacrifice_id = create_sacrifice(100, -1);
+        assert_noop!(
+            Memorial::schedule_offering(RuntimeOrigin::signed(1), TargetType::Deceased, 1, sacrifice_id, 0, 0, false),
+            Error::<Test>::BadInput
+        );
+        assert_noop!(
+            Memorial::schedule_offering(RuntimeOrigin::signed(1), TargetType::Deceased, 1, 99, 1, 0, false),
+            Error::<Test>::SacrificeNotFound
+        );
+        // 没有可推算的纪念日
+        assert_noop!(
+            Memorial::schedule_offering(RuntimeOrigin::signed(1), TargetType::Deceased, 1, sacrifice_id, 1, 1, false),
+            Error::<Test>::NoUpcomingOccasion
+        );
+        SacrificeOf::<Test>::mutate(sacrifice_id, |item| {
+            item.as_mut().unwrap().pricing.model = PricingModel::Subscription {
+                weekly_price: 100,
+                min_weeks: 1,
+                max_weeks: None,
+                auto_renew: false,
+                refund_rate: 0,
+            };
+        });
+        assert_noop!(
+            Memorial::schedule_offering(RuntimeOrigin::signed(1), TargetType::Deceased, 1, sacrifice_id, 1, 0, false),
+            Error::<Test>::SubscriptionNotSchedulable
+        );
+    });
+}
+
+#[test]
+fn scheduled_offering_executes_and_charges_on_the_day() {
+    new_test_ext().execute_with(|| {
+        Today::set(50);
+        let sacrifice_id = create_sacrifice(100, -1);
+        let schedule_id = schedule(1, sacrifice_id, 2, false);
+
+        // 纪念日前不执行
+        advance_to_day(99);
+        assert!(OfferingRecords::<Test>::get(0).is_none());
+
+        advance_to_day(ANNIVERSARY_OFFSET);
+        let record = OfferingRecords::<Test>::get(0).unwrap();
+        assert_eq!(record.who, 1);
+        assert_eq!(record.amount, 200);
+        // 执行时经常规分账扣款
+        assert_eq!(COMMITTED.with(|c| c.borrow().clone()), vec![(1, 200)]);
+        assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - 200);
+        assert_eq!(Balances::free_balance(PLATFORM), 200);
+
+        System::assert_has_event(
+            Event::ScheduledOfferingExecuted { schedule_id, offering_id: 0, who: 1 }.into(),
+        );
+        // 一次性预约执行后结束
+        System::assert_last_event(Event::ScheduledOfferingFinished { schedule_id }.into());
+        assert!(ScheduledOfferings::<Test>::get(schedule_id).is_none());
+        assert!(ScheduledOfferingsByUser::<Test>::get(1).is_empty());
+        assert!(ScheduledOfferingsByDay::<Test>::get(ANNIVERSARY_OFFSET).is_empty());
+    });
+}
+
+#[test]
+fn recurring_schedule_is_requeued_for_next_year() {
+    new_test_ext().execute_with(|| {
+        Today::set(50);
+        let sacrifice_id = create_sacrifice(100, -1);
+        let schedule_id = schedule(1, sacrifice_id, 1, true);
+
+        advance_to_day(ANNIVERSARY_OFFSET);
+        let next_day = ANNIVERSARY_OFFSET + 365;
+        let scheduled = ScheduledOfferings::<Test>::get(schedule_id).unwrap();
+        assert_eq!(scheduled.executions, 1);
+        assert_eq!(scheduled.next_day, next_day);
+        assert_eq!(ScheduledOfferingsByDay::<Test>::get(next_day).into_inner(), vec![schedule_id]);
+    });
+}
+
+#[test]
+fn scheduled_offering_fails_without_funds() {
+    new_test_ext().execute_with(|| {
+        Today::set(50);
+        // 单价高于预约人余额
+        let sacrifice_id = create_sacrifice(INITIAL_BALANCE * 2, 5);
+        let schedule_id = schedule(1, sacrifice_id, 1, true);
+
+        advance_to_day(ANNIVERSARY_OFFSET);
+        System::assert_has_event(
+            Event::ScheduledOfferingFailed {
+                schedule_id,
+                who: 1,
+                error: Error::<Test>::InsufficientBalance.into(),
+            }
+            .into(),
+        );
+        // 下单整体回滚：无记录、库存未占用、未分账
+        assert!(OfferingRecords::<Test>::get(0).is_none());
+        assert_eq!(crate::SacrificeStock::<Test>::get(sacrifice_id), 5);
+        assert!(COMMITTED.with(|c| c.borrow().is_empty()));
+        assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+        // 重复预约仍排下一年
+        let scheduled = ScheduledOfferings::<Test>::get(schedule_id).unwrap();
+        assert_eq!(scheduled.executions, 0);
+        assert_eq!(scheduled.next_day, ANNIVERSARY_OFFSET + 365);
+    });
+}
+
+#[test]
+fn full_next_year_queue_ends_schedule_with_event() {
+    new_test_ext().execute_with(|| {
+        Today::set(50);
+        let sacrifice_id = create_sacrifice(100, -1);
+        let schedule_id = schedule(1, sacrifice_id, 1, true);
+
+        let next_day = ANNIVERSARY_OFFSET + 365;
+        ScheduledOfferingsByDay::<Test>::insert(
+            next_day,
+            BoundedVec::truncate_from((1_000..2_000u64).collect::<Vec<_>>()),
+        );
+
+        advance_to_day(ANNIVERSARY_OFFSET);
+        System::assert_last_event(
+            Event::ScheduledOfferingRequeueFailed { schedule_id, who: 1, next_day }.into(),
+        );
+        assert!(ScheduledOfferings::<Test>::get(schedule_id).is_none());
+        assert!(ScheduledOfferingsByUser::<Test>::get(1).is_empty());
+    });
+}
+
+#[test]
+fn cancelled_schedule_is_skipped() {
+    new_test_ext().execute_with(|| {
+        Today::set(50);
+        let sacrifice_id = create_sacrifice(100, -1);
+        let schedule_id = schedule(1, sacrifice_id, 1, true);
+
+        assert_noop!(
+            Memorial::cancel_scheduled_offering(RuntimeOrigin::signed(2), schedule_id),
+            Error::<Test>::NotAllowed
+        );
+        assert_ok!(Memorial::cancel_scheduled_offering(RuntimeOrigin::signed(1), schedule_id));
+        System::assert_last_event(Event::ScheduledOfferingCancelled { schedule_id, who: 1 }.into());
+        assert!(ScheduledOfferings::<Test>::get(schedule_id).is_none());
+        assert!(ScheduledOfferingsByUser::<Test>::get(1).is_empty());
+        assert_noop!(
+            Memorial::cancel_scheduled_offering(RuntimeOrigin::signed(1), schedule_id),
+            Error::<Test>::ScheduleNotFound
+        );
+
+        advance_to_day(ANNIVERSARY_OFFSET);
+        assert!(OfferingRecords::<Test>::get(0).is_none());
+        assert!(COMMITTED.with(|c| c.borrow().is_empty()));
+        assert!(ScheduledOfferingsByDay::<Test>::get(ANNIVERSARY_OFFSET + 365).is_empty());
+    });
+}
+
+#[test]
+fn schedules_beyond_block_budget_run_in_next_block() {
+    new_test_ext().execute_with(|| {
+        Today::set(50);
+        let sacrifice_id = create_sacrifice(100, -1);
+        for who in 1..=3 {
+            schedule(who, sacrifice_id, 1, false);
+        }
+
+        // 每块最多执行 2 条
+        advance_to_day(ANNIVERSARY_OFFSET);
+        assert_eq!(COMMITTED.with(|c| c.borrow().len()), 2);
+        assert_eq!(ScheduledOfferingsByDay::<Test>::get(ANNIVERSARY_OFFSET).into_inner(), vec![2]);
+
+        run_to_block(System::block_number() + 1);
+        assert_eq!(COMMITTED.with(|c| c.borrow().clone()), vec![(1, 100), (2, 100), (3, 100)]);
+        assert!(ScheduledOfferingsByDay::<Test>::get(ANNIVERSARY_OFFSET).is_empty());
+    });
+}
+
+// ==================== 宠物供奉 ====================
+
+#[test]
+fn offer_to_pet_target_records_pet() {
+    new_test_ext().execute_with(|| {
+        let sacrifice_id = create_sacrifice(100, -1);
+        assert_ok!(Memorial::offer_to_target(
+            RuntimeOrigin::signed(1),
+            TargetType::Pet,
+            PET_ID,
+            sacrifice_id,
+            1,
+            vec![],
+            None,
+        ));
+        let record = OfferingRecords::<Test>::get(0).unwrap();
+        assert_eq!(record.target_type, TargetType::Pet);
+        assert_eq!(record.target_id, PET_ID);
+        assert_eq!(record.amount, 100);
+        assert_eq!(COMMITTED.with(|c| c.borrow().clone()), vec![(1, 100)]);
+
+        // 不存在的宠物
+        assert_noop!(
+            Memorial::offer_to_target(RuntimeOrigin::signed(1), TargetType::Pet, 99, sacrifice_id, 1, vec![], None),
+            Error::<Test>::TargetNotFound
+        );
+    });
+}
+
+#[test]
+fn scheduled_offering_for_pet_executes_on_the_day() {
+    new_test_ext().execute_with(|| {
+        Today::set(50);
+        let sacrifice_id = create_sacrifice(100, -1);
+        let schedule_id = crate::NextScheduledOfferingId::<Test>::get();
+        assert_ok!(Memorial::schedule_offering(
+            RuntimeOrigin::signed(1),
+            TargetType::Pet,
+            PET_ID,
+            sacrifice_id,
+            1,
+            0,
+            false,
+        ));
+        assert_eq!(ScheduledOfferings::<Test>::get(schedule_id).unwrap().next_day, ANNIVERSARY_OFFSET);
+
+        advance_to_day(ANNIVERSARY_OFFSET);
+        let record = OfferingRecords::<Test>::get(0).unwrap();
+        assert_eq!(record.target_type, TargetType::Pet);
+        assert_eq!(record.target_id, PET_ID);
+        assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - 100);
+        System::assert_has_event(
+            Event::ScheduledOfferingExecuted { schedule_id, offering_id: 0, who: 1 }.into(),
+        );
+
+        // 不存在的宠物没有可推算的纪念日
+        assert_noop!(
+            Memorial::schedule_offering(RuntimeOrigin::signed(1), TargetType::Pet, 99, sacrifice_id, 1, 0, false),
+            Error::<Test>::NoUpcomingOccasion
+        );
+    });
+}
+
+// ==================== 集体供奉众筹 ====================
+
+#[test]
+fn create_campaign_prices_goal_at_standard_price() {
+    new_test_ext().execute_with(|| {
+        let sacrifice_id = create_sacrifice(100, -1);
+        let campaign_id = create_offering_campaign(sacrifice_id, 3);
+
+        let campaign = Campaigns::<Test>::get(campaign_id).unwrap();
+        assert_eq!(campaign.goal, 300);
+        assert_eq!(campaign.raised, 0);
+        assert_eq!(campaign.deadline, 11);
+        assert_eq!(campaign.status, CampaignStatus::Open);
+        assert_eq!(CampaignsByDeadline::<Test>::get(11).into_inner(), vec![campaign_id]);
+        // 发起不出资
+        assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn create_campaign_validates_input() {
+    new_test_ext().execute_with(|| {
+        let sacrifice_id = create_sacrifice(100, -1);
+        let purpose = CampaignPurpose::Offering { sacrifice_id, quantity: 1 };
+        assert_noop!(
+            Memorial::create_campaign(RuntimeOrigin::signed(1), TargetType::Deceased, 1, purpose, 0, 0),
+            Error::<Test>::InvalidCampaignDuration
+        );
+        assert_noop!(
+            Memorial::create_campaign(RuntimeOrigin::signed(1), TargetType::Deceased, 1, purpose, 0, 101),
+            Error::<Test>::InvalidCampaignDuration
+        );
+        assert_noop!(
+            Memorial::create_campaign(
+                RuntimeOrigin::signed(1),
+                TargetType::Deceased,
+                1,
+                CampaignPurpose::Offering { sacrifice_id, quantity: 0 },
+                0,
+                10,
+            ),
+            Error::<Test>::BadInput
+        );
+        assert_noop!(
+            Memorial::create_campaign(
+                RuntimeOrigin::signed(1),
+                TargetType::Deceased,
+                NO_FUNDING_DECEASED,
+                CampaignPurpose::SubjectFunding,
+                500,
+                10,
+            ),
+            Error::<Test>::NoFundingAccount
+        );
+        assert_noop!(
+            Memorial::create_campaign(
+                RuntimeOrigin::signed(1),
+                TargetType::Deceased,
+                1,
+                CampaignPurpose::SubjectFunding,
+                5,
+                10,
+            ),
+            Error::<Test>::AmountTooLow
+        );
+    });
+}
+
+#[test]
+fn contribute_escrows_funds_and_caps_to_remaining() {
+    new_test_ext().execute_with(|| {
+        let sacrifice_id = create_sacrifice(100, -1);
+        let campaign_id = create_offering_campaign(sacrifice_id, 3);
+
+        assert_ok!(Memorial::contribute_to_campaign(RuntimeOrigin::signed(2), campaign_id, 100));
+        assert_ok!(Memorial::contribute_to_campaign(RuntimeOrigin::signed(2), campaign_id, 50));
+        // 低于最小金额且不是最后缺口
+        assert_noop!(
+            Memorial::contribute_to_campaign(RuntimeOrigin::signed(3), campaign_id, 5),
+            Error::<Test>::AmountTooLow
+        );
+
+        let campaign = Campaigns::<Test>::get(campaign_id).unwrap();
+        assert_eq!(campaign.raised, 150);
+        // 同一账户多次出资合并
+        assert_eq!(campaign.contributions.into_inner(), vec![(2, 150)]);
+        assert_eq!(Balances::free_balance(2), INITIAL_BALANCE - 150);
+        assert_eq!(Balances::free_balance(ESCROW_ACCOUNT), 150);
+        System::assert_last_event(
+            Event::CampaignContributed { campaign_id, who: 2, amount: 50, raised: 150 }.into(),
+        );
+    });
+}
+
+#[test]
+fn contributors_are_capped() {
+    new_test_ext().execute_with(|| {
+        let sacrifice_id = create_sacrifice(100, -1);
+        let campaign_id = create_offering_campaign(sacrifice_id, 10);
+
+        for who in 2..=4 {
+            assert_ok!(Memorial::contribute_to_campaign(RuntimeOrigin::signed(who), campaign_id, 10));
+        }
+        assert_noop!(
+            Memorial::contribute_to_campaign(RuntimeOrigin::signed(5), campaign_id, 10),
+            Error::<Test>::TooManyContributors
+        );
+        // 已有出资人可继续追加
+        assert_ok!(Memorial::contribute_to_campaign(RuntimeOrigin::signed(2), campaign_id, 10));
+    });
+}
+
+#[test]
+fn filled_offering_campaign_records_raised_amount_and_routes_payout() {
+    new_test_ext().execute_with(|| {
+        let sacrifice_id = create_sacrifice(100, -1);
+        // 分级定价：发起人是会员，但众筹按标准价计价
+        SacrificeOf::<Test>::mutate(sacrifice_id, |item| {
+            item.as_mut().unwrap().pricing.model = PricingModel::Tiered {
+                standard_price: 100,
+                member_price: 60,
+                vip_price: None,
+                valid_days: None,
+            };
+        });
+        MEMBERS.with(|m| m.borrow_mut().push(1));
+        let campaign_id = create_offering_campaign(sacrifice_id, 2);
+        assert_eq!(Campaigns::<Test>::get(campaign_id).unwrap().goal, 200);
+
+        assert_ok!(Memorial::contribute_to_campaign(RuntimeOrigin::signed(2), campaign_id, 150));
+        // 超出缺口的部分不扣
+        assert_ok!(Memorial::contribute_to_campaign(RuntimeOrigin::signed(3), campaign_id, 500));
+
+        let campaign = Campaigns::<Test>::get(campaign_id).unwrap();
+        assert_eq!(campaign.status, CampaignStatus::Succeeded);
+        assert_eq!(campaign.raised, 200);
+        let offering_id = campaign.offering_id.unwrap();
+
+        // 供奉记录金额 = 已筹金额，下单人为发起人
+        let record = OfferingRecords::<Test>::get(offering_id).unwrap();
+        assert_eq!(record.who, 1);
+        assert_eq!(record.amount, 200);
+        assert_eq!(record.locked_unit_price, 100);
+        assert_eq!(CampaignOfOffering::<Test>::get(offering_id), Some(campaign_id));
+
+        // 托管经发起人走常规分账，发起人余额不变
+        assert_eq!(COMMITTED.with(|c| c.borrow().clone()), vec![(1, 200)]);
+        assert_eq!(Balances::free_balance(PLATFORM), 200);
+        assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+        assert_eq!(Balances::free_balance(2), INITIAL_BALANCE - 150);
+        assert_eq!(Balances::free_balance(3), INITIAL_BALANCE - 50);
+        assert_eq!(MockEscrow::amount_of(Memorial::campaign_escrow_id(campaign_id)), 0);
+
+        System::assert_has_event(
+            Event::CampaignSucceeded {
+                campaign_id,
+                raised: 200,
+                offering_id: Some(offering_id),
+                contributors: BoundedVec::truncate_from(vec![(2, 150), (3, 50)]),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn filled_funding_campaign_releases_to_funding_account() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Memorial::create_campaign(
+            RuntimeOrigin::signed(1),
+            TargetType::Deceased,
+            2,
+            CampaignPurpose::SubjectFunding,
+            300,
+            10,
+        ));
+        assert_ok!(Memorial::contribute_to_campaign(RuntimeOrigin::signed(2), 0, 100));
+        assert_ok!(Memorial::contribute_to_campaign(RuntimeOrigin::signed(3), 0, 200));
+
+        let campaign = Campaigns::<Test>::get(0).unwrap();
+        assert_eq!(campaign.status, CampaignStatus::Succeeded);
+        assert_eq!(campaign.offering_id, None);
+        assert_eq!(Balances::free_balance(FUNDING_BASE + 2), 300);
+        assert!(COMMITTED.with(|c| c.borrow().is_empty()));
+    });
+}
+
+#[test]
+fn failed_settlement_refunds_everyone() {
+    new_test_ext().execute_with(|| {
+        // 库存为 0：筹满后下单失败
+        let sacrifice_id = create_sacrifice(100, 0);
+        let campaign_id = create_offering_campaign(sacrifice_id, 1);
+
+        assert_ok!(Memorial::contribute_to_campaign(RuntimeOrigin::signed(2), campaign_id, 60));
+        assert_ok!(Memorial::contribute_to_campaign(RuntimeOrigin::signed(3), campaign_id, 40));
+
+        let campaign = Campaigns::<Test>::get(campaign_id).unwrap();
+        assert_eq!(campaign.status, CampaignStatus::Failed);
+        assert_eq!(campaign.offering_id, None);
+        assert_eq!(Balances::free_balance(2), INITIAL_BALANCE);
+        assert_eq!(Balances::free_balance(3), INITIAL_BALANCE);
+        assert!(OfferingRecords::<Test>::get(0).is_none());
+        System::assert_last_event(
+            Event::CampaignFailed {
+                campaign_id,
+                raised: 100,
+                error: Some(Error::<Test>::InsufficientStock.into()),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn cancel_campaign_refunds_contributors() {
+    new_test_ext().execute_with(|| {
+        let sacrifice_id = create_sacrifice(100, -1);
+        let campaign_id = create_offering_campaign(sacrifice_id, 3);
+        assert_ok!(Memorial::contribute_to_campaign(RuntimeOrigin::signed(2), campaign_id, 100));
+        assert_ok!(Memorial::contribute_to_campaign(RuntimeOrigin::signed(3), campaign_id, 50));
+
+        assert_noop!(
+            Memorial::cancel_campaign(RuntimeOrigin::signed(2), campaign_id),
+            Error::<Test>::NotAllowed
+        );
+        assert_ok!(Memorial::cancel_campaign(RuntimeOrigin::signed(1), campaign_id));
+
+        assert_eq!(Campaigns::<Test>::get(campaign_id).unwrap().status, CampaignStatus::Cancelled);
+        assert_eq!(Balances::free_balance(2), INITIAL_BALANCE);
+        assert_eq!(Balances::free_balance(3), INITIAL_BALANCE);
+        System::assert_last_event(Event::CampaignCancelled { campaign_id, refunded: 150 }.into());
+
+        assert_noop!(
+            Memorial::contribute_to_campaign(RuntimeOrigin::signed(2), campaign_id, 100),
+            Error::<Test>::CampaignNotOpen
+        );
+        assert_noop!(
+            Memorial::cancel_campaign(RuntimeOrigin::signed(1), campaign_id),
+            Error::<Test>::CampaignNotOpen
+        );
+    });
+}
+
+#[test]
+fn unfilled_campaign_is_refunded_at_deadline() {
+    new_test_ext().execute_with(|| {
+        let sacrifice_id = create_sacrifice(100, -1);
+        let campaign_id = create_offering_campaign(sacrifice_id, 3);
+        assert_ok!(Memorial::contribute_to_campaign(RuntimeOrigin::signed(2), campaign_id, 100));
+
+        // 截止区块前仍可出资
+        run_to_block(10);
+        assert_ok!(Memorial::contribute_to_campaign(RuntimeOrigin::signed(3), campaign_id, 50));
+        assert_eq!(Campaigns::<Test>::get(campaign_id).unwrap().status, CampaignStatus::Open);
+
+        run_to_block(11);
+        let campaign = Campaigns::<Test>::get(campaign_id).unwrap();
+        assert_eq!(campaign.status, CampaignStatus::Failed);
+        assert_eq!(Balances::free_balance(2), INITIAL_BALANCE);
+        assert_eq!(Balances::free_balance(3), INITIAL_BALANCE);
+        assert!(CampaignsByDeadline::<Test>::get(11).is_empty());
+        System::assert_last_event(Event::CampaignFailed { campaign_id, raised: 150, error: None }.into());
+
+        assert_noop!(
+            Memorial::contribute_to_campaign(RuntimeOrigin::signed(4), campaign_id, 100),
+            Error::<Test>::CampaignNotOpen
+        );
+    });
+}
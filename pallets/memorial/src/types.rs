@@ -625,6 +625,149 @@ pub trait OfferingTarget<AccountId> {
     fn get_display_name(target_id: u64) -> Option<BoundedVec<u8, ConstU32<256>>>;
 }
 
+impl<AccountId> OfferingTarget<AccountId> for () {
+    fn exists(_target_id: u64) -> bool {
+        false
+    }
+    fn get_owner(_target_id: u64) -> Option<AccountId> {
+        None
+    }
+    fn is_accessible(_who: &AccountId, _target_id: u64) -> bool {
+        false
+    }
+    fn get_display_name(_target_id: u64) -> Option<BoundedVec<u8, ConstU32<256>>> {
+        None
+    }
+}
+
+
+/// 函数级详细中文注释：预约供奉（纪念日自动执行）🆕
+///
+/// **执行规则**：
+/// - 在 `next_day`（纪元日，UTC+8）由 `on_initialize` 以预约人身份自动下单
+/// - `recurring=true` 时执行后按纪念日历排下一次；否则执行一次后移除
+/// - 执行时按当时价格走常规供奉分账扣款（同 `offer_to_target` 的分账回调）
+/// - 下单或扣款失败（库存不足、限购、限频、余额不足等）记录事件，不阻断后续排期
+/// - 下一次执行日队列已满时预约结束并发出 `ScheduledOfferingRequeueFailed`
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+#[scale_info(skip_type_params(T))]
+pub struct ScheduledOffering<T: Config> {
+    /// 预约人（供奉人）
+    pub who: T::AccountId,
+    /// 供奉目标类型
+    pub target_type: TargetType,
+    /// 供奉目标ID
+    pub target_id: u64,
+    /// 祭祀品ID（仅支持非订阅类）
+    pub sacrifice_id: u64,
+    /// 数量
+    pub quantity: u32,
+    /// 纪念场合编码（0=忌日, 1=农历忌日, 2=生辰, 3=农历生辰, 4=清明, 5=冬至）
+    pub occasion: u8,
+    /// 是否每年重复
+    pub recurring: bool,
+    /// 下一次执行日（纪元日）
+    pub next_day: u32,
+    /// 已成功执行次数
+    pub executions: u32,
+}
+
+/// 函数级详细中文注释：纪念日历接口 🆕
+///
+/// **设计目标**：
+/// - 预约供奉按纪念日（忌日/清明/冬至等）执行，日期推算由目标所在 pallet 提供
+/// - 由 Runtime 适配到 `pallet-deceased` 的纪念日历
+pub trait RemembranceCalendar {
+    /// 今天（纪元日，1970-01-01 起的天数）
+    fn today() -> u32;
+
+    /// 目标某场合在 `from_day`（含）之后的最近一次纪元日；不支持或无法推算时返回 None
+    fn next_occurrence(target_type: TargetType, target_id: u64, occasion: u8, from_day: u32) -> Option<u32>;
+}
+
+impl RemembranceCalendar for () {
+    fn today() -> u32 {
+        0
+    }
+
+    fn next_occurrence(_target_type: TargetType, _target_id: u64, _occasion: u8, _from_day: u32) -> Option<u32> {
+        None
+    }
+}
+
+/// 函数级详细中文注释：集体供奉众筹用途 🆕
+///
+/// - `Offering`: 筹满后以发起人名义下单该祭祀品（目标金额 = 标准价 × 数量）
+/// - `SubjectFunding`: 筹满后全部转入目标的 IPFS 存储资金账户（stardust-ipfs SubjectFunding）
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum CampaignPurpose {
+    /// 集体供奉祭祀品（仅非订阅类）
+    Offering { sacrifice_id: u64, quantity: u32 },
+    /// 资助目标的存储资金账户
+    SubjectFunding,
+}
+
+/// 函数级中文注释：众筹状态 🆕
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum CampaignStatus {
+    /// 募集中
+    Open,
+    /// 已筹满并执行
+    Succeeded,
+    /// 到期未筹满或执行失败，已退款
+    Failed,
+    /// 发起人取消，已退款
+    Cancelled,
+}
+
+/// 函数级详细中文注释：集体供奉众筹 🆕
+///
+/// **资金流**：
+/// - 每笔出资经 `pallet-escrow` 托管（托管ID = `CAMPAIGN_ESCROW_NAMESPACE | campaign_id`）
+/// - 筹满：供奉用途按发起时锁定的标准价下单，托管释放给发起人后走常规供奉分账；
+///   资助用途托管全额释放到目标存储资金账户
+/// - 到期未筹满 / 执行失败 / 取消：按出资明细逐笔退回
+///
+/// **出资人名单**：结束后保留在记录中，供前端展示"共同供奉人"（供奉记录经 `CampaignOfOffering` 反查）
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+#[scale_info(skip_type_params(T))]
+pub struct OfferingCampaign<T: Config> {
+    /// 发起人（供奉记录的下单人）
+    pub organizer: T::AccountId,
+    /// 目标类型
+    pub target_type: TargetType,
+    /// 目标ID
+    pub target_id: u64,
+    /// 用途
+    pub purpose: CampaignPurpose,
+    /// 目标金额
+    pub goal: u128,
+    /// 已筹金额
+    pub raised: u128,
+    /// 截止区块（含）
+    pub deadline: BlockNumberFor<T>,
+    /// 状态
+    pub status: CampaignStatus,
+    /// 出资明细（同一账户多次出资合并）
+    pub contributions: BoundedVec<(T::AccountId, u128), T::MaxCampaignContributors>,
+    /// 筹满后生成的供奉记录ID（仅 Offering 用途）
+    pub offering_id: Option<u64>,
+}
+
+/// 函数级详细中文注释：存储资金账户提供者 🆕
+///
+/// **设计目标**：
+/// - 众筹资助用途需要目标的 IPFS 存储资金账户，由 Runtime 适配到 `pallet-stardust-ipfs`
+/// - 返回 None 表示目标不存在或该目标类型没有存储资金账户
+pub trait SubjectFundingProvider<AccountId> {
+    fn funding_account(target_type: TargetType, target_id: u64) -> Option<AccountId>;
+}
+
+impl<AccountId> SubjectFundingProvider<AccountId> for () {
+    fn funding_account(_target_type: TargetType, _target_id: u64) -> Option<AccountId> {
+        None
+    }
+}
 
 /// 函数级中文注释：供奉提交后的回调接口
 pub trait OnOfferingCommitted<AccountId> {
@@ -637,6 +780,37 @@ pub trait OnOfferingCommitted<AccountId> {
     );
 }
 
+/// 函数级中文注释：🆕 供奉落账回调（按目标类型，供 pallet-ledger 生成回执与周排行）
+/// - 在供奉记录写入后调用，offering_id 与 `OfferingRecords` 一致
+/// - `category` 为祭祀品一级分类编码（`PrimaryCategory as u8`）
+pub trait OnOfferingRecorded<AccountId> {
+    #[allow(clippy::too_many_arguments)]
+    fn on_recorded(
+        offering_id: u64,
+        target_type: TargetType,
+        target_id: u64,
+        sacrifice_id: u64,
+        category: u8,
+        who: &AccountId,
+        amount: u128,
+        duration_weeks: Option<u32>,
+    );
+}
+
+impl<AccountId> OnOfferingRecorded<AccountId> for () {
+    fn on_recorded(
+        _offering_id: u64,
+        _target_type: TargetType,
+        _target_id: u64,
+        _sacrifice_id: u64,
+        _category: u8,
+        _who: &AccountId,
+        _amount: u128,
+        _duration_weeks: Option<u32>,
+    ) {
+    }
+}
+
 /// 函数级中文注释：会员信息提供者接口
 pub trait MembershipProvider<AccountId> {
     fn is_valid_member(who: &AccountId) -> bool;
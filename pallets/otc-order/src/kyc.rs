@@ -1,8 +1,15 @@
 //! KYC验证相关逻辑实现
 
 use crate::{Config, Error, Event, types::*};
+use crate::pallet::{
+    BuyerOrders, KycAttestations, KycAttestorAccounts, KycAttestors, KycExpiringAt,
+    KycTierOrderCaps, OrderState, Orders,
+};
 use crate::pallet::IdentityVerificationProvider;
+use crate::WeightInfo;
 use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_core::H256;
 
 impl<T: Config> crate::Pallet<T> {
     /// 检查用户是否满足KYC要求
@@ -20,6 +27,11 @@ impl<T: Config> crate::Pallet<T> {
             return KycVerificationResult::Exempted;
         }
 
+        // 🆕 持有有效分级认证即视为通过
+        if let Some(attestation) = Self::valid_kyc_attestation(who) {
+            return KycVerificationResult::Attested(attestation.tier);
+        }
+
         // 验证身份认证状态
         match Self::check_identity_judgement(who, config.min_judgment_priority) {
             Ok(()) => KycVerificationResult::Passed,
@@ -58,6 +70,7 @@ impl<T: Config> crate::Pallet<T> {
     pub fn enforce_kyc_requirement(who: &T::AccountId) -> DispatchResult {
         match Self::verify_kyc(who) {
             KycVerificationResult::Passed |
+            KycVerificationResult::Attested(_) |
             KycVerificationResult::Exempted |
             KycVerificationResult::Skipped => Ok(()),
 
@@ -82,4 +95,219 @@ impl<T: Config> crate::Pallet<T> {
             }
         }
     }
-}
\ No newline at end of file
+
+    /// 🆕 获取账户当前有效的分级认证
+    ///
+    /// 认证未过期且签发方仍在认证方名单中才视为有效。
+    pub fn valid_kyc_attestation(
+        who: &T::AccountId,
+    ) -> Option<KycAttestation<T::AccountId, BlockNumberFor<T>>> {
+        let attestation = KycAttestations::<T>::get(who)?;
+        let now = frame_system::Pallet::<T>::block_number();
+        if attestation.expires_at <= now || !KycAttestors::<T>::contains_key(&attestation.attestor) {
+            return None;
+        }
+        Some(attestation)
+    }
+
+    /// 🆕 获取账户当前有效的KYC等级（无有效认证时为 None）
+    pub fn kyc_tier_of(who: &T::AccountId) -> KycTier {
+        Self::valid_kyc_attestation(who)
+            .map(|a| a.tier)
+            .unwrap_or_default()
+    }
+
+    /// 🆕 获取用于限额的KYC等级
+    ///
+    /// KYC未启用或账户豁免时返回 None（不按等级限额）。
+    pub fn kyc_tier_for_limits(who: &T::AccountId) -> Option<KycTier> {
+        if !crate::pallet::KycConfig::<T>::get().enabled || Self::is_kyc_exempt(who) {
+            return None;
+        }
+        Some(Self::kyc_tier_of(who))
+    }
+
+    /// 🆕 检查订单金额是否超出账户KYC等级的单笔上限
+    pub fn ensure_within_kyc_tier_cap(who: &T::AccountId, amount_usd: u64) -> DispatchResult {
+        let Some(tier) = Self::kyc_tier_for_limits(who) else { return Ok(()) };
+        if let Some(cap) = KycTierOrderCaps::<T>::get(tier) {
+            ensure!(amount_usd <= cap, Error::<T>::KycTierLimitExceeded);
+        }
+        Ok(())
+    }
+
+    /// 🆕 签发KYC分级认证
+    pub fn do_attest_kyc(
+        attestor: T::AccountId,
+        account: T::AccountId,
+        tier: KycTier,
+        claim_hash: H256,
+        expires_at: BlockNumberFor<T>,
+    ) -> DispatchResult {
+        let max_tier = KycAttestors::<T>::get(&attestor).ok_or(Error::<T>::NotKycAttestor)?;
+        ensure!(tier != KycTier::None, Error::<T>::InvalidKycTier);
+        ensure!(tier <= max_tier, Error::<T>::KycTierExceedsAttestor);
+
+        let now = frame_system::Pallet::<T>::block_number();
+        ensure!(expires_at > now, Error::<T>::InvalidAttestationExpiry);
+
+        // 覆盖旧认证：先从旧的过期索引与认证方索引中移除
+        if let Some(previous) = KycAttestations::<T>::get(&account) {
+            KycExpiringAt::<T>::mutate(previous.expires_at, |due| due.retain(|a| *a != account));
+            KycAttestorAccounts::<T>::mutate(&previous.attestor, |accounts| {
+                accounts.retain(|a| *a != account)
+            });
+        }
+        KycExpiringAt::<T>::try_mutate(expires_at, |due| {
+            due.try_push(account.clone()).map_err(|_| Error::<T>::KycExpiryQueueFull)
+        })?;
+        KycAttestorAccounts::<T>::try_mutate(&attestor, |accounts| {
+            accounts.try_push(account.clone()).map_err(|_| Error::<T>::TooManyAttestedAccounts)
+        })?;
+
+        KycAttestations::<T>::insert(&account, KycAttestation {
+            tier,
+            attestor: attestor.clone(),
+            claim_hash,
+            issued_at: now,
+            expires_at,
+        });
+
+        Self::deposit_event(Event::KycAttested {
+            account,
+            attestor,
+            tier: tier.to_code(),
+            claim_hash,
+            expires_at,
+        });
+        Ok(())
+    }
+
+    /// 🆕 撤销KYC分级认证并取消该账户的未付款订单
+    ///
+    /// `revoker` 为 None 表示委员会撤销；否则必须是该认证的签发方。
+    /// `max_orders` 为买家订单数见证值，不得小于 `BuyerOrders` 实际长度（用于预估权重）。
+    /// 被动取消不记录买家取消行为（不影响信用分）。
+    pub fn do_revoke_kyc_attestation(
+        revoker: Option<T::AccountId>,
+        account: T::AccountId,
+        max_orders: u32,
+    ) -> DispatchResult {
+        let attestation = KycAttestations::<T>::get(&account)
+            .ok_or(Error::<T>::KycAttestationNotFound)?;
+        if let Some(who) = &revoker {
+            ensure!(*who == attestation.attestor, Error::<T>::NotAuthorized);
+        }
+        ensure!(
+            BuyerOrders::<T>::decode_len(&account).unwrap_or(0) as u32 <= max_orders,
+            Error::<T>::InvalidWitness
+        );
+
+        KycAttestations::<T>::remove(&account);
+        KycExpiringAt::<T>::mutate(attestation.expires_at, |due| due.retain(|a| *a != account));
+        KycAttestorAccounts::<T>::mutate(&attestation.attestor, |accounts| {
+            accounts.retain(|a| *a != account)
+        });
+
+        let actor = revoker.unwrap_or_else(|| account.clone());
+        let cancelled_orders = Self::cancel_open_orders_of(&account, &actor);
+
+        Self::deposit_event(Event::KycAttestationRevoked {
+            account,
+            tier: attestation.tier.to_code(),
+            cancelled_orders,
+        });
+        Ok(())
+    }
+
+    /// 🆕 移除KYC认证方，撤销其签发的全部认证并取消相应账户的未付款订单
+    ///
+    /// `max_accounts` 为该认证方已认证账户数见证值，不得小于实际数量。
+    pub fn do_remove_kyc_attestor(attestor: T::AccountId, max_accounts: u32) -> DispatchResult {
+        ensure!(KycAttestors::<T>::contains_key(&attestor), Error::<T>::NotKycAttestor);
+        let accounts = KycAttestorAccounts::<T>::take(&attestor);
+        ensure!(accounts.len() as u32 <= max_accounts, Error::<T>::InvalidWitness);
+
+        KycAttestors::<T>::remove(&attestor);
+
+        for account in accounts.iter() {
+            let Some(attestation) = KycAttestations::<T>::get(account) else { continue };
+            if attestation.attestor != attestor {
+                continue;
+            }
+            KycAttestations::<T>::remove(account);
+            KycExpiringAt::<T>::mutate(attestation.expires_at, |due| due.retain(|a| a != account));
+            let cancelled_orders = Self::cancel_open_orders_of(account, account);
+            Self::deposit_event(Event::KycAttestationRevoked {
+                account: account.clone(),
+                tier: attestation.tier.to_code(),
+                cancelled_orders,
+            });
+        }
+
+        Self::deposit_event(Event::KycAttestorRemoved {
+            attestor,
+            revoked_accounts: accounts.len() as u32,
+        });
+        Ok(())
+    }
+
+    /// 🆕 处理当前区块到期的KYC认证，返回消耗的权重
+    ///
+    /// 与撤销一致：移除认证并取消该账户的未付款订单（已被覆盖或撤销的认证已从索引移除）。
+    pub(crate) fn process_kyc_expiries(n: BlockNumberFor<T>) -> Weight {
+        let db = T::DbWeight::get();
+        let expiring = KycExpiringAt::<T>::take(n);
+        let mut weight = db.reads_writes(1, 1);
+
+        for account in expiring.iter() {
+            weight = weight.saturating_add(db.reads(1));
+            let Some(attestation) = KycAttestations::<T>::get(account) else { continue };
+            if attestation.expires_at != n {
+                continue;
+            }
+            let orders = BuyerOrders::<T>::decode_len(account).unwrap_or(0) as u32;
+            weight = weight.saturating_add(<T as Config>::WeightInfo::revoke_kyc_attestation(orders));
+
+            KycAttestations::<T>::remove(account);
+            KycAttestorAccounts::<T>::mutate(&attestation.attestor, |accounts| {
+                accounts.retain(|a| a != account)
+            });
+            let cancelled_orders = Self::cancel_open_orders_of(account, account);
+            Self::deposit_event(Event::KycAttestationExpired {
+                account: account.clone(),
+                tier: attestation.tier.to_code(),
+                cancelled_orders,
+            });
+        }
+
+        weight
+    }
+
+    /// 取消账户作为买家的全部未付款（Created）订单，返回取消数量
+    ///
+    /// 单笔取消失败（如托管异常）时回滚该笔并继续，不影响认证撤销本身。
+    fn cancel_open_orders_of(account: &T::AccountId, actor: &T::AccountId) -> u32 {
+        let mut cancelled = 0u32;
+        for order_id in BuyerOrders::<T>::get(account).into_iter() {
+            let Some(order) = Orders::<T>::get(order_id) else { continue };
+            if order.state != OrderState::Created {
+                continue;
+            }
+            let result = frame_support::storage::with_storage_layer(|| {
+                Self::cancel_order_unchecked(order_id, order, actor.clone(), false)
+            });
+            if result.is_ok() {
+                cancelled = cancelled.saturating_add(1);
+            }
+        }
+        cancelled
+    }
+}
+
+/// 🆕 向信用模块提供KYC等级，买家额度按等级封顶
+impl<T: Config> pallet_credit::quota::KycTierProvider<T::AccountId> for crate::Pallet<T> {
+    fn kyc_tier(who: &T::AccountId) -> Option<u8> {
+        Self::kyc_tier_for_limits(who).map(|tier| tier.to_code())
+    }
+}
@@ -6,8 +6,11 @@
 //! - 订单创建与管理
 //! - 首购订单特殊逻辑（固定USD价值，动态DUST数量）
 //! - 订单状态流转（创建→付款→释放→完成）
+//! - 做市商挂单（Listing）：库存、最小/最大成交量，买家部分成交
 //! - 订单争议与仲裁
 //! - 自动清理过期订单
+//! - 🆕 付款证明：做市商登记的付款看守服务上链签名收款声明，足额即自动释放；
+//!   标记付款后超时无足额证明则自动进入争议，证明历史登记为证据
 //! - **🆕 KYC身份认证要求（基于pallet-identity）**
 //!
 //! ## KYC认证功能
@@ -16,12 +19,17 @@
 //! - 支持不同的认证等级要求（Reasonable/KnownGood等）
 //! - 紧急豁免账户机制
 //! - 只有通过KYC认证的用户才能创建OTC订单
+//! - 🆕 分级认证：委员会批准的认证方（外部KYC服务商）签发带过期时间的等级声明（仅上链哈希），
+//!   各等级限制单笔订单金额并为 pallet-credit 提供额度封顶依据；撤销认证时取消未付款订单
 //!
 //! ## 版本历史
 //!
 //! - v0.1.0 (2025-11-03): 从 pallet-trading 拆分而来
 //! - v0.2.0 (2025-11-13): 集成KYC认证功能
 //! - v0.3.0 (2025-11-28): 集成聊天权限系统
+//! - v0.4.0: 做市商挂单与部分成交（每笔成交独立托管与付款/释放流程）
+//! - v0.5.0: KYC分级认证（认证方注册表、等级单笔限额、撤销自动取消订单）
+//! - v0.6.0: 付款证明自动释放与超时自动争议
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -31,9 +39,11 @@ pub use pallet::*;
 
 mod types;
 mod kyc;
+mod payment;
+pub mod migrations;
 
 // 选择性导出 types 中的类型（避免 KycConfig 冲突）
-pub use types::{KycVerificationResult, KycFailureReason};
+pub use types::{KycVerificationResult, KycFailureReason, KycTier, KycAttestation};
 
 #[cfg(test)]
 mod mock;
@@ -56,6 +66,7 @@ pub mod pallet {
         traits::{Currency, Get, UnixTime},
         BoundedVec,
         sp_runtime::SaturatedConversion,
+        sp_runtime::traits::Zero,
     };
     use sp_core::H256;
     use pallet_escrow::Escrow as EscrowTrait;
@@ -153,9 +164,77 @@ pub mod pallet {
         pub completed_at: Option<MomentOf>,
         /// 是否为首购订单
         pub is_first_purchase: bool,
+        /// 来源挂单ID（通过挂单部分成交创建时有值）
+        pub listing_id: Option<u64>,
+    }
+
+    /// 函数级详细中文注释：挂单状态枚举
+    #[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum ListingState {
+        /// 挂单中，可被吃单
+        Active,
+        /// 已关闭（做市商撤单或库存全部成交）
+        Closed,
+    }
+
+    /// 函数级详细中文注释：做市商挂单结构
+    ///
+    /// 做市商挂出一笔长期有效的卖单，买家按需吃下部分数量。
+    /// 每笔成交生成独立的 `Order`（独立托管ID、独立付款/释放流程），
+    /// 成交价格在吃单时按定价服务实时价格叠加挂单溢价重新计算。
+    #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Listing<T: Config> {
+        /// 做市商ID
+        pub maker_id: u64,
+        /// 做市商账户
+        pub maker: T::AccountId,
+        /// 创建时参考单价（USDT/DUST，精度10^6，已含溢价；成交时按实时价格重新计算）
+        pub price: BalanceOf<T>,
+        /// 溢价（基点，-500 ~ 500）
+        pub premium_bps: i16,
+        /// 挂单总量（DUST）
+        pub total_qty: BalanceOf<T>,
+        /// 剩余可成交数量（DUST）
+        pub available_qty: BalanceOf<T>,
+        /// 已释放给买家的数量（DUST）
+        pub filled_qty: BalanceOf<T>,
+        /// 单笔最小成交量（DUST）
+        pub min_fill_qty: BalanceOf<T>,
+        /// 单笔最大成交量（DUST）
+        pub max_fill_qty: BalanceOf<T>,
+        /// 创建时间
+        pub created_at: MomentOf,
+        /// 挂单状态
+        pub state: ListingState,
+    }
+
+    /// 🆕 函数级详细中文注释：付款证明（做市商登记的付款看守服务签发）
+    ///
+    /// 看守服务监听银行/支付渠道回调，对“订单 N 已收到 X 法币”签名；
+    /// 链上保存声明哈希与金额摘要，原始回调与签名留在链下备查。
+    #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PaymentAttestation<T: Config> {
+        /// 签发证明的看守账户
+        pub watcher: T::AccountId,
+        /// 收到的金额（折合USD，精度10^6，与订单金额同单位）
+        pub fiat_amount: u64,
+        /// 收款渠道的原始币种（ISO 4217，如 b"CNY"）
+        pub fiat_currency: [u8; 3],
+        /// 签名声明的哈希
+        pub statement_hash: H256,
+        /// 是否已足额收款（链上按订单金额判定，足额即自动释放）
+        pub full_payment: bool,
+        /// 证明时间（秒）
+        pub attested_at: MomentOf,
     }
     
+    /// 存储版本（v1：`Order` 新增 `listing_id`，见 `migrations`）
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
     
     /// 函数级详细中文注释：OTC订单模块配置 trait
@@ -241,6 +320,25 @@ pub mod pallet {
         #[pallet::constant]
         type MaxFirstPurchaseOrdersPerMaker: Get<u32>;
 
+        /// 🆕 买家标记付款后等待付款证明的区块数，超时未获足额付款证明则自动进入争议
+        #[pallet::constant]
+        type PaymentAttestationTimeout: Get<BlockNumberFor<Self>>;
+
+        /// 🆕 同一截止区块最多登记的付款证明等待订单数（队列满时 `mark_paid` 失败）
+        #[pallet::constant]
+        type MaxAttestationTimeoutsPerBlock: Get<u32>;
+
+        /// 🆕 同一区块最多到期的KYC分级认证数（已满时 `attest_kyc` 需换一个过期区块）
+        #[pallet::constant]
+        type MaxKycExpiriesPerBlock: Get<u32>;
+
+        /// 🆕 单个KYC认证方最多同时持有的有效认证账户数
+        #[pallet::constant]
+        type MaxAccountsPerKycAttestor: Get<u32>;
+
+        /// 🆕 付款证明证据登记（超时自动争议时附上证明历史的承诺哈希）
+        type PaymentEvidence: PaymentEvidenceSink<Self::AccountId>;
+
         /// 权重信息
         type WeightInfo: WeightInfo;
     }
@@ -269,6 +367,20 @@ pub mod pallet {
         pub _phantom: sp_std::marker::PhantomData<Balance>,
     }
 
+    /// 🆕 函数级详细中文注释：付款证明证据登记接口
+    ///
+    /// 由 runtime 桥接到 `pallet-evidence`，避免本模块直接依赖证据模块。
+    pub trait PaymentEvidenceSink<AccountId> {
+        /// 为订单登记证明历史的承诺哈希，返回证据ID（未接入时返回 None）
+        fn attach(order_id: u64, owner: &AccountId, commit: H256) -> Option<u64>;
+    }
+
+    impl<AccountId> PaymentEvidenceSink<AccountId> for () {
+        fn attach(_order_id: u64, _owner: &AccountId, _commit: H256) -> Option<u64> {
+            None
+        }
+    }
+
     /// 函数级详细中文注释：Identity 验证 Provider trait
     /// 用于查询账户的身份认证状态，避免直接依赖 pallet_identity::Config
     pub trait IdentityVerificationProvider<AccountId> {
@@ -390,6 +502,82 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    // ===== 挂单存储 =====
+
+    /// 函数级详细中文注释：下一个挂单 ID
+    #[pallet::storage]
+    #[pallet::getter(fn next_listing_id)]
+    pub type NextListingId<T> = StorageValue<_, u64, ValueQuery>;
+
+    /// 函数级详细中文注释：挂单记录
+    #[pallet::storage]
+    #[pallet::getter(fn listings)]
+    pub type Listings<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,  // listing_id
+        Listing<T>,
+    >;
+
+    /// 函数级详细中文注释：做市商挂单列表（仅活跃挂单）
+    #[pallet::storage]
+    #[pallet::getter(fn maker_listings)]
+    pub type MakerListings<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,  // maker_id
+        BoundedVec<u64, ConstU32<20>>,  // 每个做市商最多20个活跃挂单
+        ValueQuery,
+    >;
+
+    /// 函数级详细中文注释：挂单成交订单索引（listing_id, order_id）
+    ///
+    /// 按双键记录，挂单成交笔数不设上限；按 `listing_id` 前缀遍历即可列出全部成交。
+    #[pallet::storage]
+    pub type ListingFills<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u64,  // listing_id
+        Blake2_128Concat,
+        u64,  // order_id
+        (),
+        OptionQuery,
+    >;
+
+    // ===== 🆕 付款证明存储 =====
+
+    /// 函数级详细中文注释：做市商登记的付款看守账户（maker_id → 看守账户）
+    #[pallet::storage]
+    pub type PaymentWatchers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        T::AccountId,
+        OptionQuery,
+    >;
+
+    /// 函数级详细中文注释：订单的付款证明历史（每单最多 8 条）
+    #[pallet::storage]
+    pub type PaymentAttestations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        BoundedVec<PaymentAttestation<T>, ConstU32<8>>,
+        ValueQuery,
+    >;
+
+    /// 函数级详细中文注释：付款证明截止区块索引（截止区块 → 订单ID列表）
+    ///
+    /// on_initialize 直接取当前块到期项，结构同 escrow 的 `MilestonesDueAt`。
+    #[pallet::storage]
+    pub type AttestationDeadlines<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<u64, T::MaxAttestationTimeoutsPerBlock>,
+        ValueQuery,
+    >;
+
     // ===== KYC存储 =====
 
     /// 函数级详细中文注释：KYC配置存储
@@ -412,6 +600,64 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// 🆕 函数级详细中文注释：已批准的KYC认证方（外部KYC服务商的链上账户）
+    ///
+    /// 值为该认证方可签发的最高等级。
+    #[pallet::storage]
+    pub type KycAttestors<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        crate::types::KycTier,
+        OptionQuery,
+    >;
+
+    /// 🆕 函数级详细中文注释：账户的KYC分级认证（每个账户仅保留最新一条）
+    #[pallet::storage]
+    pub type KycAttestations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        crate::types::KycAttestation<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// 🆕 函数级详细中文注释：KYC认证过期索引（过期区块 → 账户列表）
+    ///
+    /// on_initialize 取出当前块到期的认证，按撤销处理（取消未付款订单）。
+    #[pallet::storage]
+    pub type KycExpiringAt<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<T::AccountId, T::MaxKycExpiriesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// 🆕 函数级详细中文注释：认证方签发的有效认证账户索引（认证方 → 账户列表）
+    ///
+    /// 移除认证方时据此撤销其签发的全部认证。
+    #[pallet::storage]
+    pub type KycAttestorAccounts<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<T::AccountId, T::MaxAccountsPerKycAttestor>,
+        ValueQuery,
+    >;
+
+    /// 🆕 函数级详细中文注释：各KYC等级的单笔订单USD上限（精度10^6）
+    ///
+    /// 未设置的等级仅受 `MaxOrderUsdAmount` 约束。
+    #[pallet::storage]
+    pub type KycTierOrderCaps<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        crate::types::KycTier,
+        u64,
+        OptionQuery,
+    >;
+
     // ===== Genesis配置 =====
 
     /// 函数级详细中文注释：Genesis配置结构
@@ -479,6 +725,38 @@ pub mod pallet {
             count: u32,
         },
 
+        // ===== 挂单相关事件 =====
+
+        /// 挂单已创建
+        ListingCreated {
+            listing_id: u64,
+            maker_id: u64,
+            price: BalanceOf<T>,
+            premium_bps: i16,
+            total_qty: BalanceOf<T>,
+        },
+        /// 挂单部分成交（生成独立订单）
+        ListingFilled {
+            listing_id: u64,
+            order_id: u64,
+            buyer: T::AccountId,
+            qty: BalanceOf<T>,
+            /// 成交价（实时价格叠加挂单溢价）
+            price: BalanceOf<T>,
+            remaining_qty: BalanceOf<T>,
+        },
+        /// 成交订单取消/退款，数量退回挂单
+        ListingInventoryRestored {
+            listing_id: u64,
+            order_id: u64,
+            qty: BalanceOf<T>,
+        },
+        /// 挂单已关闭
+        ListingClosed {
+            listing_id: u64,
+            remaining_qty: BalanceOf<T>,
+        },
+
         // ===== KYC相关事件 =====
 
         /// KYC要求已启用
@@ -507,6 +785,74 @@ pub mod pallet {
             account: T::AccountId,
             reason_code: u8,
         },
+        /// 🆕 KYC认证方已批准（max_tier 等级代码：1=Phone, 2=IdDocument, 3=EnhancedDueDiligence）
+        KycAttestorAdded {
+            attestor: T::AccountId,
+            max_tier: u8,
+        },
+        /// 🆕 KYC认证方已移除，revoked_accounts 为随之撤销的认证数
+        KycAttestorRemoved {
+            attestor: T::AccountId,
+            revoked_accounts: u32,
+        },
+        /// 🆕 账户获得KYC分级认证
+        KycAttested {
+            account: T::AccountId,
+            attestor: T::AccountId,
+            tier: u8,
+            claim_hash: H256,
+            expires_at: BlockNumberFor<T>,
+        },
+        /// 🆕 账户的KYC分级认证已撤销，cancelled_orders 为被取消的未付款订单数
+        KycAttestationRevoked {
+            account: T::AccountId,
+            tier: u8,
+            cancelled_orders: u32,
+        },
+        /// 🆕 账户的KYC分级认证已过期，cancelled_orders 为被取消的未付款订单数
+        KycAttestationExpired {
+            account: T::AccountId,
+            tier: u8,
+            cancelled_orders: u32,
+        },
+        /// 🆕 KYC等级单笔订单上限已更新（None 表示移除上限）
+        KycTierOrderCapSet {
+            tier: u8,
+            max_usd: Option<u64>,
+        },
+
+        // ===== 付款证明相关事件 =====
+
+        /// 🆕 做市商付款看守账户已更新（None 表示注销）
+        PaymentWatcherSet {
+            maker_id: u64,
+            watcher: Option<T::AccountId>,
+        },
+        /// 🆕 看守服务提交了付款证明
+        PaymentAttested {
+            order_id: u64,
+            watcher: T::AccountId,
+            fiat_amount: u64,
+            fiat_currency: [u8; 3],
+            statement_hash: H256,
+            full_payment: bool,
+        },
+        /// 🆕 足额付款证明触发自动释放
+        PaymentAutoReleased {
+            order_id: u64,
+            watcher: T::AccountId,
+        },
+        /// 🆕 付款证明超时，订单自动进入争议；evidence_id 为证明历史登记的证据ID
+        PaymentAttestationTimedOut {
+            order_id: u64,
+            attestations: u32,
+            evidence_commit: H256,
+            evidence_id: Option<u64>,
+        },
+        /// 🆕 截止区块及其后顺延区块的等待队列均已满，订单未登记付款证明超时
+        PaymentAttestationDeadlineUnscheduled {
+            order_id: u64,
+        },
     }
     
     // ===== 错误 =====
@@ -557,6 +903,25 @@ pub mod pallet {
         /// 定价服务不可用
         PricingServiceUnavailable,
 
+        // ===== 挂单相关错误 =====
+
+        /// 挂单不存在
+        ListingNotFound,
+        /// 挂单已关闭
+        ListingNotActive,
+        /// 挂单参数无效（数量为0或最小成交量大于最大成交量/总量）
+        InvalidListingParams,
+        /// 溢价超出范围（-500 ~ 500 基点）
+        InvalidPremium,
+        /// 成交数量不在挂单的最小/最大成交量范围内
+        FillQtyOutOfRange,
+        /// 挂单剩余数量不足
+        InsufficientListingQty,
+        /// 活跃挂单太多
+        TooManyListings,
+        /// 不能吃自己的挂单
+        CannotTakeOwnListing,
+
         // ===== KYC相关错误 =====
 
         /// 未设置身份信息
@@ -571,6 +936,40 @@ pub mod pallet {
         AccountAlreadyExempted,
         /// 账户不在豁免列表中
         AccountNotExempted,
+        /// 🆕 KYC等级无效（不能为 None）
+        InvalidKycTier,
+        /// 🆕 调用者不是已批准的KYC认证方
+        NotKycAttestor,
+        /// 🆕 认证等级超过该认证方可签发的最高等级
+        KycTierExceedsAttestor,
+        /// 🆕 认证过期区块必须晚于当前区块
+        InvalidAttestationExpiry,
+        /// 🆕 账户没有KYC分级认证
+        KycAttestationNotFound,
+        /// 🆕 订单金额超过当前KYC等级的单笔上限
+        KycTierLimitExceeded,
+        /// 🆕 该过期区块的KYC认证索引已满
+        KycExpiryQueueFull,
+        /// 🆕 认证方的有效认证账户数已达上限
+        TooManyAttestedAccounts,
+        /// 🆕 见证值小于实际数量
+        InvalidWitness,
+        /// 🆕 调用者不是该做市商登记的付款看守账户
+        NotPaymentWatcher,
+        /// 🆕 订单付款证明数量已达上限
+        TooManyPaymentAttestations,
+    }
+
+    // ===== Hooks =====
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// 函数级详细中文注释：处理付款证明超时（超时未获足额证明的订单自动进入争议）
+        /// 与KYC认证到期（撤销认证并取消未付款订单）
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            Self::process_attestation_timeouts(n)
+                .saturating_add(Self::process_kyc_expiries(n))
+        }
     }
     
     // ===== Extrinsics =====
@@ -845,8 +1244,297 @@ pub mod pallet {
             Self::deposit_event(Event::AccountRemovedFromKycExemption { account });
             Ok(())
         }
+
+        // ===== 挂单函数 =====
+
+        /// 函数级详细中文注释：做市商创建挂单
+        ///
+        /// # 参数
+        /// - `origin`: 调用者（做市商，必须是签名账户）
+        /// - `maker_id`: 做市商ID
+        /// - `total_qty`: 挂单总量（DUST）
+        /// - `min_fill_qty`: 单笔最小成交量（DUST）
+        /// - `max_fill_qty`: 单笔最大成交量（DUST）
+        /// - `premium_bps`: 溢价（基点，-500 ~ 500）
+        ///
+        /// # 返回
+        /// - `DispatchResult`: 成功或错误
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_listing())]
+        pub fn create_listing(
+            origin: OriginFor<T>,
+            maker_id: u64,
+            total_qty: BalanceOf<T>,
+            min_fill_qty: BalanceOf<T>,
+            max_fill_qty: BalanceOf<T>,
+            premium_bps: i16,
+        ) -> DispatchResult {
+            let maker = ensure_signed(origin)?;
+            let _listing_id = Self::do_create_listing(
+                &maker,
+                maker_id,
+                total_qty,
+                min_fill_qty,
+                max_fill_qty,
+                premium_bps,
+            )?;
+            Ok(())
+        }
+
+        /// 函数级详细中文注释：做市商撤销挂单
+        ///
+        /// 已成交的订单不受影响，继续各自的付款/释放流程。
+        ///
+        /// # 参数
+        /// - `origin`: 调用者（做市商，必须是签名账户）
+        /// - `listing_id`: 挂单ID
+        ///
+        /// # 返回
+        /// - `DispatchResult`: 成功或错误
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_listing())]
+        pub fn cancel_listing(
+            origin: OriginFor<T>,
+            listing_id: u64,
+        ) -> DispatchResult {
+            let maker = ensure_signed(origin)?;
+            Self::do_cancel_listing(&maker, listing_id)
+        }
+
+        /// 函数级详细中文注释：买家吃单（部分成交）
+        ///
+        /// # 参数
+        /// - `origin`: 调用者（买家，必须是签名账户）
+        /// - `listing_id`: 挂单ID
+        /// - `qty`: 成交数量（DUST）
+        /// - `payment_commit`: 支付承诺哈希
+        /// - `contact_commit`: 联系方式承诺哈希
+        ///
+        /// # 返回
+        /// - `DispatchResult`: 成功或错误
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::take_listing())]
+        pub fn take_listing(
+            origin: OriginFor<T>,
+            listing_id: u64,
+            qty: BalanceOf<T>,
+            payment_commit: H256,
+            contact_commit: H256,
+        ) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
+            let _order_id = Self::do_take_listing(
+                &buyer,
+                listing_id,
+                qty,
+                payment_commit,
+                contact_commit,
+            )?;
+            Ok(())
+        }
+
+        // ===== 🆕 KYC分级认证函数 =====
+
+        /// 函数级详细中文注释：批准KYC认证方（外部KYC服务商）
+        ///
+        /// # 参数
+        /// - `origin`: 调用者（委员会起源）
+        /// - `attestor`: 认证方账户
+        /// - `max_tier`: 该认证方可签发的最高等级（不能为 None）
+        ///
+        /// # 返回
+        /// - `DispatchResult`: 成功或错误
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::add_kyc_attestor())]
+        pub fn add_kyc_attestor(
+            origin: OriginFor<T>,
+            attestor: T::AccountId,
+            max_tier: crate::types::KycTier,
+        ) -> DispatchResult {
+            T::CommitteeOrigin::ensure_origin(origin)?;
+            ensure!(max_tier != crate::types::KycTier::None, Error::<T>::InvalidKycTier);
+
+            KycAttestors::<T>::insert(&attestor, max_tier);
+
+            Self::deposit_event(Event::KycAttestorAdded {
+                attestor,
+                max_tier: max_tier.to_code(),
+            });
+            Ok(())
+        }
+
+        /// 函数级详细中文注释：移除KYC认证方
+        ///
+        /// 与撤销一致：该认证方签发的全部认证随之撤销，相应账户的未付款订单被取消。
+        ///
+        /// # 参数
+        /// - `origin`: 调用者（委员会起源）
+        /// - `attestor`: 认证方账户
+        /// - `max_accounts`: 该认证方已认证账户数见证值（不得小于实际数量）
+        ///
+        /// # 返回
+        /// - `DispatchResult`: 成功或错误
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_kyc_attestor(*max_accounts))]
+        pub fn remove_kyc_attestor(
+            origin: OriginFor<T>,
+            attestor: T::AccountId,
+            max_accounts: u32,
+        ) -> DispatchResult {
+            T::CommitteeOrigin::ensure_origin(origin)?;
+            Self::do_remove_kyc_attestor(attestor, max_accounts)
+        }
+
+        /// 函数级详细中文注释：认证方为账户签发KYC分级认证
+        ///
+        /// 仅上链服务商签名声明的哈希，不含任何个人身份信息。
+        /// 同一账户的新认证覆盖旧认证。
+        ///
+        /// # 参数
+        /// - `origin`: 调用者（已批准的认证方）
+        /// - `account`: 被认证账户
+        /// - `tier`: 认证等级（不超过认证方的最高等级）
+        /// - `claim_hash`: 服务商签名声明的哈希
+        /// - `expires_at`: 过期区块
+        ///
+        /// # 返回
+        /// - `DispatchResult`: 成功或错误
+        #[pallet::call_index(16)]
+        #[pallet::weight(<T as Config>::WeightInfo::attest_kyc())]
+        pub fn attest_kyc(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            tier: crate::types::KycTier,
+            claim_hash: H256,
+            expires_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let attestor = ensure_signed(origin)?;
+            Self::do_attest_kyc(attestor, account, tier, claim_hash, expires_at)
+        }
+
+        /// 函数级详细中文注释：撤销账户的KYC分级认证
+        ///
+        /// 撤销后自动取消该账户作为买家的全部未付款（Created）订单，
+        /// 已付款订单继续走正常的释放/争议流程。
+        ///
+        /// # 参数
+        /// - `origin`: 调用者（签发该认证的认证方，或委员会起源）
+        /// - `account`: 被撤销认证的账户
+        /// - `max_orders`: 该账户买家订单数见证值（不得小于 `BuyerOrders` 实际长度）
+        ///
+        /// # 返回
+        /// - `DispatchResult`: 成功或错误
+        #[pallet::call_index(17)]
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_kyc_attestation(*max_orders))]
+        pub fn revoke_kyc_attestation(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            max_orders: u32,
+        ) -> DispatchResult {
+            let revoker = match T::CommitteeOrigin::try_origin(origin) {
+                Ok(_) => None,
+                Err(origin) => Some(ensure_signed(origin)?),
+            };
+            Self::do_revoke_kyc_attestation(revoker, account, max_orders)
+        }
+
+        /// 函数级详细中文注释：设置KYC等级的单笔订单USD上限
+        ///
+        /// # 参数
+        /// - `origin`: 调用者（委员会起源）
+        /// - `tier`: KYC等级（None 表示未持有分级认证的账户）
+        /// - `max_usd`: 单笔上限（精度10^6），None 表示移除上限
+        ///
+        /// # 返回
+        /// - `DispatchResult`: 成功或错误
+        #[pallet::call_index(18)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_kyc_tier_order_cap())]
+        pub fn set_kyc_tier_order_cap(
+            origin: OriginFor<T>,
+            tier: crate::types::KycTier,
+            max_usd: Option<u64>,
+        ) -> DispatchResult {
+            T::CommitteeOrigin::ensure_origin(origin)?;
+
+            match max_usd {
+                Some(cap) => KycTierOrderCaps::<T>::insert(tier, cap),
+                None => KycTierOrderCaps::<T>::remove(tier),
+            }
+
+            Self::deposit_event(Event::KycTierOrderCapSet {
+                tier: tier.to_code(),
+                max_usd,
+            });
+            Ok(())
+        }
+
+        // ===== 🆕 付款证明函数 =====
+
+        /// 函数级详细中文注释：做市商登记/注销付款看守账户
+        ///
+        /// 看守账户由做市商运行的链下服务持有，监听银行/支付回调并提交付款证明。
+        ///
+        /// # 参数
+        /// - `origin`: 调用者（做市商账户）
+        /// - `maker_id`: 做市商ID
+        /// - `watcher`: 看守账户，None 表示注销
+        ///
+        /// # 返回
+        /// - `DispatchResult`: 成功或错误
+        #[pallet::call_index(19)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_payment_watcher())]
+        pub fn set_payment_watcher(
+            origin: OriginFor<T>,
+            maker_id: u64,
+            watcher: Option<T::AccountId>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let maker_app = T::MakerPallet::get_maker_application(maker_id)
+                .ok_or(Error::<T>::MakerNotFound)?;
+            ensure!(maker_app.account == who, Error::<T>::NotAuthorized);
+
+            match &watcher {
+                Some(w) => PaymentWatchers::<T>::insert(maker_id, w),
+                None => PaymentWatchers::<T>::remove(maker_id),
+            }
+
+            Self::deposit_event(Event::PaymentWatcherSet { maker_id, watcher });
+            Ok(())
+        }
+
+        /// 函数级详细中文注释：看守服务提交付款证明
+        ///
+        /// 是否足额由链上判定：证明金额不低于订单金额（扣除 `AmountValidationTolerance` 容差）
+        /// 即立即释放DUST给买家；非足额证明仅记录在证明历史中，供超时争议时作为证据。
+        ///
+        /// # 参数
+        /// - `origin`: 调用者（订单做市商登记的看守账户）
+        /// - `order_id`: 订单ID
+        /// - `fiat_amount`: 收到的金额，按收款渠道汇率折合USD（精度10^6，与订单金额同单位）
+        /// - `fiat_currency`: 收款渠道的原始币种（ISO 4217，仅作记录）
+        /// - `statement_hash`: 看守服务签名声明的哈希
+        ///
+        /// # 返回
+        /// - `DispatchResult`: 成功或错误
+        #[pallet::call_index(20)]
+        #[pallet::weight(<T as Config>::WeightInfo::attest_payment())]
+        pub fn attest_payment(
+            origin: OriginFor<T>,
+            order_id: u64,
+            fiat_amount: u64,
+            fiat_currency: [u8; 3],
+            statement_hash: H256,
+        ) -> DispatchResult {
+            let watcher = ensure_signed(origin)?;
+            Self::do_attest_payment(
+                &watcher,
+                order_id,
+                fiat_amount,
+                fiat_currency,
+                statement_hash,
+            )
+        }
     }
-    
+
     // ===== 内部实现 =====
     
     impl<T: Config> Pallet<T> {
@@ -878,8 +1566,6 @@ pub mod pallet {
             payment_commit: H256,
             contact_commit: H256,
         ) -> Result<u64, DispatchError> {
-            use pallet_credit::quota::BuyerQuotaInterface;
-
             // 🆕 Step 0: KYC验证检查
             Self::enforce_kyc_requirement(buyer)?;
 
@@ -897,34 +1583,83 @@ pub mod pallet {
             let price = T::Pricing::get_dust_to_usd_rate()
                 .ok_or(Error::<T>::PricingUnavailable)?;
             
-            // 4. 计算总金额（USDT）= dust_amount * price
+            // 4. 占用额度、锁定托管并创建订单
+            Self::open_order(
+                buyer,
+                maker_id,
+                &maker_app,
+                price,
+                dust_amount,
+                payment_commit,
+                contact_commit,
+                None,
+            )
+        }
+
+        /// 函数级详细中文注释：按指定价格开立订单（普通订单与挂单成交共用）
+        ///
+        /// ## 功能说明
+        /// 1. 计算订单金额并占用买家额度
+        /// 2. 将做市商的DUST锁定到托管（订单ID即托管ID，每笔成交独立托管）
+        /// 3. 创建订单记录、更新买卖双方订单列表
+        /// 4. 发出订单创建事件并授予聊天权限
+        ///
+        /// ## 参数
+        /// - `buyer`: 买家账户
+        /// - `maker_id`: 做市商ID
+        /// - `maker_app`: 做市商信息
+        /// - `price`: 成交单价
+        /// - `dust_amount`: DUST数量
+        /// - `payment_commit`: 支付承诺哈希
+        /// - `contact_commit`: 联系方式承诺哈希
+        /// - `listing_id`: 来源挂单ID（普通订单为 None）
+        ///
+        /// ## 返回
+        /// - `Ok(order_id)`: 订单ID
+        fn open_order(
+            buyer: &T::AccountId,
+            maker_id: u64,
+            maker_app: &MakerApplicationInfo<T::AccountId, BalanceOf<T>>,
+            price: BalanceOf<T>,
+            dust_amount: BalanceOf<T>,
+            payment_commit: H256,
+            contact_commit: H256,
+            listing_id: Option<u64>,
+        ) -> Result<u64, DispatchError> {
+            use pallet_credit::quota::BuyerQuotaInterface;
+
+            // 1. 计算总金额（USDT）= dust_amount * price
             let amount = dust_amount
                 .checked_mul(&price)
                 .ok_or(Error::<T>::CalculationOverflow)?;
 
             // 🆕 方案C+：买家额度检查和占用
-            // 5. 计算订单USD金额（精度10^6）
+            // 2. 计算订单USD金额（精度10^6）
             let amount_usd: u64 = Self::calculate_usd_amount_from_dust(dust_amount, price)?;
 
-            // 6. 检查并占用买家额度
+            // 🆕 2.1 检查KYC分级单笔上限
+            Self::ensure_within_kyc_tier_cap(buyer, amount_usd)?;
+
+            // 3. 检查并占用买家额度
             T::Credit::occupy_quota(buyer, amount_usd)?;
 
-            // 7. 获取做市商的TRON收款地址
+            // 4. 获取做市商的TRON收款地址
             let maker_tron_address = maker_app.tron_address
+                .clone()
                 .try_into()
                 .map_err(|_| Error::<T>::EncodingError)?;
 
-            // 8. 获取订单ID（提前）
+            // 5. 获取订单ID（提前）
             let order_id = NextOrderId::<T>::get();
 
-            // 9. 将做市商的DUST锁定到托管（使用 order_id 作为托管 ID）
+            // 6. 将做市商的DUST锁定到托管（使用 order_id 作为托管 ID）
             T::Escrow::lock_from(
                 &maker_app.account,
                 order_id,
                 dust_amount,
             )?;
 
-            // 10. 获取当前时间并计算超时时间
+            // 7. 获取当前时间并计算超时时间
             let now = T::Timestamp::now().as_secs().saturated_into::<u64>();
             let expire_at = now
                 .checked_add(T::OrderTimeout::get())
@@ -933,7 +1668,7 @@ pub mod pallet {
                 .checked_add(T::EvidenceWindow::get())
                 .ok_or(Error::<T>::CalculationOverflow)?;
 
-            // 11. 创建订单记录
+            // 8. 创建订单记录
             let order = Order {
                 maker_id,
                 maker: maker_app.account.clone(),
@@ -951,25 +1686,26 @@ pub mod pallet {
                 epay_trade_no: None,
                 completed_at: None,
                 is_first_purchase: false,
+                listing_id,
             };
 
-            // 12. 保存订单
+            // 9. 保存订单
             Orders::<T>::insert(order_id, order);
             NextOrderId::<T>::put(order_id + 1);
 
-            // 13. 更新买家订单列表
+            // 10. 更新买家订单列表
             BuyerOrders::<T>::try_mutate(buyer, |orders| {
                 orders.try_push(order_id)
                     .map_err(|_| Error::<T>::TooManyOrders)
             })?;
 
-            // 14. 更新做市商订单列表
+            // 11. 更新做市商订单列表
             MakerOrders::<T>::try_mutate(maker_id, |orders| {
                 orders.try_push(order_id)
                     .map_err(|_| Error::<T>::TooManyOrders)
             })?;
 
-            // 15. 发出事件
+            // 12. 发出事件
             Self::deposit_event(Event::OrderCreated {
                 order_id,
                 maker_id,
@@ -978,7 +1714,7 @@ pub mod pallet {
                 is_first_purchase: false,
             });
 
-            // 16. 🆕 2025-11-28: 授予买卖双方聊天权限
+            // 13. 🆕 2025-11-28: 授予买卖双方聊天权限
             // 订单创建后，买家和做市商之间自动获得基于订单场景的聊天权限
             // 有效期：30天（30 * 24 * 60 * 10 个区块，假设 6 秒/区块）
             let chat_duration = 30u32 * 24 * 60 * 10; // 30天
@@ -1133,6 +1869,7 @@ pub mod pallet {
                 epay_trade_no: None,
                 completed_at: None,
                 is_first_purchase: true,
+                listing_id: None,
             };
             
             // 14. 保存订单
@@ -1256,7 +1993,11 @@ pub mod pallet {
             // 5. 更新订单状态
             let old_state = order.state.clone();
             order.state = OrderState::PaidOrCommitted;
+            let maker_id = order.maker_id;
             Orders::<T>::insert(order_id, order);
+
+            // 🆕 5.1 做市商登记了付款看守：进入付款证明等待队列
+            Self::enqueue_attestation_deadline(maker_id, order_id);
             
             // 6. 发出事件
             Self::deposit_event(Event::OrderStateChanged {
@@ -1291,10 +2032,8 @@ pub mod pallet {
             maker: &T::AccountId,
             order_id: u64,
         ) -> DispatchResult {
-            use pallet_credit::quota::BuyerQuotaInterface;
-
             // 1. 获取订单
-            let mut order = Orders::<T>::get(order_id)
+            let order = Orders::<T>::get(order_id)
                 .ok_or(Error::<T>::OrderNotFound)?;
             
             // 2. 验证订单状态
@@ -1305,7 +2044,23 @@ pub mod pallet {
             
             // 3. 验证调用者是做市商
             ensure!(order.maker == *maker, Error::<T>::NotAuthorized);
-            
+
+            Self::release_order_unchecked(order_id, order, maker.clone())
+        }
+
+        /// 函数级详细中文注释：释放订单的内部实现（调用方已完成权限与状态校验）
+        ///
+        /// ## 参数
+        /// - `order_id`: 订单ID
+        /// - `order`: 订单记录
+        /// - `actor`: 事件中记录的操作者（做市商或其付款看守账户）
+        pub(crate) fn release_order_unchecked(
+            order_id: u64,
+            mut order: Order<T>,
+            actor: T::AccountId,
+        ) -> DispatchResult {
+            use pallet_credit::quota::BuyerQuotaInterface;
+
             // 4. 从托管释放DUST到买家（使用 order_id 作为托管 ID）
             T::Escrow::release_all(order_id, &order.taker)?;
             
@@ -1342,12 +2097,15 @@ pub mod pallet {
                 });
             }
 
-            // 10. 发出事件
+            // 10. 挂单成交：计入已成交数量
+            Self::settle_listing_fill(order_id, &order, true);
+
+            // 11. 发出事件
             Self::deposit_event(Event::OrderStateChanged {
                 order_id,
                 old_state: Self::state_to_u8(&old_state),
                 new_state: Self::state_to_u8(&OrderState::Released),
-                actor: Some(maker.clone()),
+                actor: Some(actor),
             });
             
             Ok(())
@@ -1374,10 +2132,8 @@ pub mod pallet {
             who: &T::AccountId,
             order_id: u64,
         ) -> DispatchResult {
-            use pallet_credit::quota::BuyerQuotaInterface;
-
             // 1. 获取订单
-            let mut order = Orders::<T>::get(order_id)
+            let order = Orders::<T>::get(order_id)
                 .ok_or(Error::<T>::OrderNotFound)?;
             
             // 2. 验证调用者是买家或做市商
@@ -1391,7 +2147,25 @@ pub mod pallet {
                 matches!(order.state, OrderState::Created | OrderState::Expired),
                 Error::<T>::InvalidOrderStatus
             );
-            
+
+            Self::cancel_order_unchecked(order_id, order, who.clone(), true)
+        }
+
+        /// 函数级详细中文注释：取消订单的内部实现（调用方已完成权限与状态校验）
+        ///
+        /// ## 参数
+        /// - `order_id`: 订单ID
+        /// - `order`: 订单记录
+        /// - `actor`: 事件中记录的操作者
+        /// - `record_cancel`: 是否记录买家取消（降低信用）；因KYC撤销被动取消时为 false
+        pub(crate) fn cancel_order_unchecked(
+            order_id: u64,
+            mut order: Order<T>,
+            actor: T::AccountId,
+            record_cancel: bool,
+        ) -> DispatchResult {
+            use pallet_credit::quota::BuyerQuotaInterface;
+
             // 4. 从托管退还DUST给做市商（使用 order_id 作为托管 ID）
             T::Escrow::refund_all(order_id, &order.maker)?;
             
@@ -1408,7 +2182,9 @@ pub mod pallet {
             let _ = T::Credit::release_quota(&order.taker, amount_usd);
 
             // 7. 记录订单取消（轻度降低信用）
-            let _ = T::Credit::record_order_cancelled(&order.taker, order_id);
+            if record_cancel {
+                let _ = T::Credit::record_order_cancelled(&order.taker, order_id);
+            }
 
             // 8. 如是首购订单，减少做市商首购计数
             if order.is_first_purchase {
@@ -1417,12 +2193,15 @@ pub mod pallet {
                 });
             }
 
-            // 9. 发出事件
+            // 9. 挂单成交：数量退回挂单
+            Self::settle_listing_fill(order_id, &order, false);
+
+            // 10. 发出事件
             Self::deposit_event(Event::OrderStateChanged {
                 order_id,
                 old_state: Self::state_to_u8(&old_state),
                 new_state: Self::state_to_u8(&OrderState::Canceled),
-                actor: Some(who.clone()),
+                actor: Some(actor),
             });
             
             Ok(())
@@ -1449,7 +2228,7 @@ pub mod pallet {
             order_id: u64,
         ) -> DispatchResult {
             // 1. 获取订单
-            let mut order = Orders::<T>::get(order_id)
+            let order = Orders::<T>::get(order_id)
                 .ok_or(Error::<T>::OrderNotFound)?;
             
             // 2. 验证调用者是买家或做市商
@@ -1463,7 +2242,19 @@ pub mod pallet {
                 matches!(order.state, OrderState::PaidOrCommitted),
                 Error::<T>::InvalidOrderStatus
             );
-            
+
+            Self::dispute_order_unchecked(order_id, order, Some(who.clone()));
+            Ok(())
+        }
+
+        /// 函数级详细中文注释：订单进入争议的内部实现（调用方已完成权限与状态校验）
+        ///
+        /// `actor` 为 None 表示系统自动发起（付款证明超时）。
+        pub(crate) fn dispute_order_unchecked(
+            order_id: u64,
+            mut order: Order<T>,
+            actor: Option<T::AccountId>,
+        ) {
             // 4. 更新订单状态
             let old_state = order.state.clone();
             order.state = OrderState::Disputed;
@@ -1474,11 +2265,243 @@ pub mod pallet {
                 order_id,
                 old_state: Self::state_to_u8(&old_state),
                 new_state: Self::state_to_u8(&OrderState::Disputed),
-                actor: Some(who.clone()),
+                actor,
             });
-            
+        }
+
+        /// 函数级详细中文注释：创建挂单
+        ///
+        /// ## 功能说明
+        /// 1. 验证调用者是做市商本人且做市商已激活
+        /// 2. 验证挂单参数（数量、成交量范围、溢价）
+        /// 3. 按定价服务价格叠加溢价计算挂单价格
+        /// 4. 验证单笔最小成交量满足最小订单金额
+        /// 5. 保存挂单并加入做市商挂单列表
+        ///
+        /// 挂单不预先锁定库存，每笔成交时从做市商余额锁定到该成交订单的托管。
+        ///
+        /// ## 返回
+        /// - `Ok(listing_id)`: 挂单ID
+        pub fn do_create_listing(
+            maker: &T::AccountId,
+            maker_id: u64,
+            total_qty: BalanceOf<T>,
+            min_fill_qty: BalanceOf<T>,
+            max_fill_qty: BalanceOf<T>,
+            premium_bps: i16,
+        ) -> Result<u64, DispatchError> {
+            // 1. 验证做市商
+            let maker_app = T::MakerPallet::get_maker_application(maker_id)
+                .ok_or(Error::<T>::MakerNotFound)?;
+            ensure!(maker_app.account == *maker, Error::<T>::NotAuthorized);
+            ensure!(maker_app.is_active, Error::<T>::MakerNotActive);
+
+            // 2. 验证挂单参数
+            ensure!(
+                !min_fill_qty.is_zero()
+                    && min_fill_qty <= max_fill_qty
+                    && min_fill_qty <= total_qty,
+                Error::<T>::InvalidListingParams
+            );
+            ensure!(
+                (-500..=500).contains(&premium_bps),
+                Error::<T>::InvalidPremium
+            );
+
+            // 3. 计算挂单参考价格 = 基准价格 × (10000 + 溢价) / 10000
+            let price = Self::listing_price(premium_bps)?;
+
+            // 4. 单笔最小成交量不得低于最小订单金额
+            let min_fill_usd = Self::calculate_usd_amount_from_dust(min_fill_qty, price)?;
+            ensure!(
+                min_fill_usd >= T::MinOrderUsdAmount::get(),
+                Error::<T>::OrderAmountTooSmall
+            );
+
+            // 5. 保存挂单
+            let listing_id = NextListingId::<T>::get();
+            MakerListings::<T>::try_mutate(maker_id, |ids| {
+                ids.try_push(listing_id)
+                    .map_err(|_| Error::<T>::TooManyListings)
+            })?;
+
+            let listing = Listing {
+                maker_id,
+                maker: maker.clone(),
+                price,
+                premium_bps,
+                total_qty,
+                available_qty: total_qty,
+                filled_qty: Zero::zero(),
+                min_fill_qty,
+                max_fill_qty,
+                created_at: T::Timestamp::now().as_secs().saturated_into::<u64>(),
+                state: ListingState::Active,
+            };
+            Listings::<T>::insert(listing_id, listing);
+            NextListingId::<T>::put(listing_id + 1);
+
+            Self::deposit_event(Event::ListingCreated {
+                listing_id,
+                maker_id,
+                price,
+                premium_bps,
+                total_qty,
+            });
+
+            Ok(listing_id)
+        }
+
+        /// 函数级详细中文注释：撤销挂单
+        ///
+        /// 已成交订单不受影响；之后取消/退款的成交数量不再退回挂单。
+        pub fn do_cancel_listing(
+            maker: &T::AccountId,
+            listing_id: u64,
+        ) -> DispatchResult {
+            let mut listing = Listings::<T>::get(listing_id)
+                .ok_or(Error::<T>::ListingNotFound)?;
+            ensure!(listing.maker == *maker, Error::<T>::NotAuthorized);
+            ensure!(listing.state == ListingState::Active, Error::<T>::ListingNotActive);
+
+            let remaining_qty = listing.available_qty;
+            listing.state = ListingState::Closed;
+            Self::close_listing(listing_id, listing, remaining_qty);
+
             Ok(())
         }
+
+        /// 函数级详细中文注释：买家吃单（部分成交）
+        ///
+        /// ## 功能说明
+        /// 1. KYC验证
+        /// 2. 验证挂单有效、成交量在范围内且剩余数量充足
+        /// 3. 按实时定价服务价格叠加挂单溢价重新计算成交价
+        /// 4. 按成交价验证单笔订单金额（`MinOrderUsdAmount` ~ `MaxOrderUsdAmount`）
+        /// 5. 以成交价开立独立订单（独立托管ID、独立付款/释放流程）
+        /// 6. 扣减挂单剩余数量并记录成交
+        ///
+        /// 成交价不使用挂单创建时冻结的价格，避免市场波动后按过期价格成交。
+        ///
+        /// ## 返回
+        /// - `Ok(order_id)`: 成交订单ID
+        pub fn do_take_listing(
+            buyer: &T::AccountId,
+            listing_id: u64,
+            qty: BalanceOf<T>,
+            payment_commit: H256,
+            contact_commit: H256,
+        ) -> Result<u64, DispatchError> {
+            // 1. KYC验证检查
+            Self::enforce_kyc_requirement(buyer)?;
+
+            // 2. 验证挂单
+            let mut listing = Listings::<T>::get(listing_id)
+                .ok_or(Error::<T>::ListingNotFound)?;
+            ensure!(listing.state == ListingState::Active, Error::<T>::ListingNotActive);
+            ensure!(listing.maker != *buyer, Error::<T>::CannotTakeOwnListing);
+            ensure!(
+                qty >= listing.min_fill_qty && qty <= listing.max_fill_qty,
+                Error::<T>::FillQtyOutOfRange
+            );
+            ensure!(qty <= listing.available_qty, Error::<T>::InsufficientListingQty);
+
+            // 3. 验证做市商仍处于激活状态
+            let maker_app = T::MakerPallet::get_maker_application(listing.maker_id)
+                .ok_or(Error::<T>::MakerNotFound)?;
+            ensure!(maker_app.is_active, Error::<T>::MakerNotActive);
+
+            // 4. 按实时价格重新计算成交价，并验证单笔订单金额
+            let price = Self::listing_price(listing.premium_bps)?;
+            let usd_amount = Self::calculate_usd_amount_from_dust(qty, price)?;
+            Self::ensure_order_usd_in_range(usd_amount)?;
+
+            // 5. 以成交价开立独立订单
+            let order_id = Self::open_order(
+                buyer,
+                listing.maker_id,
+                &maker_app,
+                price,
+                qty,
+                payment_commit,
+                contact_commit,
+                Some(listing_id),
+            )?;
+
+            // 6. 扣减剩余数量并记录成交
+            listing.available_qty = listing.available_qty.saturating_sub(qty);
+            let remaining_qty = listing.available_qty;
+            Listings::<T>::insert(listing_id, listing);
+
+            ListingFills::<T>::insert(listing_id, order_id, ());
+
+            Self::deposit_event(Event::ListingFilled {
+                listing_id,
+                order_id,
+                buyer: buyer.clone(),
+                qty,
+                price,
+                remaining_qty,
+            });
+
+            Ok(order_id)
+        }
+
+        /// 函数级详细中文注释：计算挂单价格
+        ///
+        /// 价格 = 定价服务实时价格 × (10000 + 溢价) / 10000；
+        /// 创建挂单时作为参考价格保存，吃单时重新计算作为成交价。
+        fn listing_price(premium_bps: i16) -> Result<BalanceOf<T>, DispatchError> {
+            let base_price = T::Pricing::get_dust_to_usd_rate()
+                .ok_or(Error::<T>::PricingUnavailable)?;
+            let base_u128: u128 = base_price.saturated_into();
+            let price_u128 = base_u128
+                .checked_mul((10_000i32 + premium_bps as i32) as u128)
+                .ok_or(Error::<T>::CalculationOverflow)?
+                / 10_000;
+            ensure!(price_u128 > 0, Error::<T>::InvalidPrice);
+            Ok(price_u128.saturated_into())
+        }
+
+        /// 函数级详细中文注释：挂单成交订单结算
+        ///
+        /// - `delivered = true`：DUST 已交付买家，计入已成交数量，全部成交后关闭挂单
+        /// - `delivered = false`：DUST 退回做市商，数量退回挂单（挂单已关闭时不退回）
+        fn settle_listing_fill(order_id: u64, order: &Order<T>, delivered: bool) {
+            let Some(listing_id) = order.listing_id else { return };
+            let Some(mut listing) = Listings::<T>::get(listing_id) else { return };
+
+            if delivered {
+                listing.filled_qty = listing.filled_qty.saturating_add(order.qty);
+                if listing.state == ListingState::Active && listing.filled_qty >= listing.total_qty {
+                    listing.state = ListingState::Closed;
+                    Self::close_listing(listing_id, listing, Zero::zero());
+                    return;
+                }
+            } else if listing.state == ListingState::Active {
+                listing.available_qty = listing.available_qty.saturating_add(order.qty);
+                Self::deposit_event(Event::ListingInventoryRestored {
+                    listing_id,
+                    order_id,
+                    qty: order.qty,
+                });
+            }
+
+            Listings::<T>::insert(listing_id, listing);
+        }
+
+        /// 函数级详细中文注释：保存已关闭的挂单并从做市商挂单列表移除
+        fn close_listing(listing_id: u64, listing: Listing<T>, remaining_qty: BalanceOf<T>) {
+            MakerListings::<T>::mutate(listing.maker_id, |ids| {
+                ids.retain(|id| *id != listing_id);
+            });
+            Listings::<T>::insert(listing_id, listing);
+
+            Self::deposit_event(Event::ListingClosed {
+                listing_id,
+                remaining_qty,
+            });
+        }
     }
     
     // ===== 公共查询接口 =====
@@ -1495,7 +2518,7 @@ pub mod pallet {
         }
         
         /// 函数级详细中文注释：将订单状态转换为 u8（用于事件）
-        fn state_to_u8(state: &OrderState) -> u8 {
+        pub(crate) fn state_to_u8(state: &OrderState) -> u8 {
             match state {
                 OrderState::Created => 0,
                 OrderState::PaidOrCommitted => 1,
@@ -1592,6 +2615,9 @@ pub mod pallet {
                 maker_win,
             );
             
+            // 挂单成交：DUST 交付买家计入成交，退回做市商则数量退回挂单
+            Self::settle_listing_fill(order_id, &order, order.state == OrderState::Refunded);
+
             // 更新订单
             order.completed_at = Some(T::Timestamp::now().as_secs());
             Orders::<T>::insert(order_id, order);
@@ -1629,6 +2655,15 @@ pub mod pallet {
                 dust_to_usd_rate,
             )?;
 
+            Self::ensure_order_usd_in_range(usd_amount)?;
+
+            Ok(usd_amount)
+        }
+
+        /// 函数级详细中文注释：验证订单USD金额在单笔限额范围内
+        ///
+        /// 普通订单与挂单成交共用（首购订单固定金额，不经过此检查）。
+        fn ensure_order_usd_in_range(usd_amount: u64) -> DispatchResult {
             // 验证最小金额（至少20 USD，首购除外）
             ensure!(
                 usd_amount >= T::MinOrderUsdAmount::get(),
@@ -1642,7 +2677,7 @@ pub mod pallet {
                 Error::<T>::OrderAmountExceedsLimit
             );
 
-            Ok(usd_amount)
+            Ok(())
         }
 
         /// 函数级详细中文注释：计算DUST对应的USD金额
@@ -1654,7 +2689,7 @@ pub mod pallet {
         /// # 返回
         /// - Ok(u64): USD金额（精度10^6）
         /// - Err(DispatchError): 计算错误
-        fn calculate_usd_amount_from_dust(
+        pub(crate) fn calculate_usd_amount_from_dust(
             dust_amount: BalanceOf<T>,
             dust_to_usd_rate: BalanceOf<T>,
         ) -> Result<u64, DispatchError> {
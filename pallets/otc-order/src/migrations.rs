@@ -0,0 +1,88 @@
+//! 函数级中文注释：pallet-otc-order 存储迁移
+//!
+//! ### v0 → v1：订单新增来源挂单字段
+//! - `Order` 末尾新增 `listing_id: Option<u64>`（做市商挂单部分成交）
+//! - 升级前的订单均非挂单成交，按旧布局解码后补 `listing_id: None` 重新写入
+
+use crate::pallet::{BalanceOf, Config, MomentOf, Order, OrderState, Orders, Pallet, TronAddress};
+use codec::{Decode, Encode};
+use frame_support::{
+    migrations::VersionedMigration,
+    traits::{ConstU32, Get, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+    BoundedVec,
+};
+use sp_core::H256;
+
+/// 函数级中文注释：v0 → v1 迁移（仅在链上存储版本为 0 时执行，执行后置为 1）
+pub type MigrateV0ToV1<T> = VersionedMigration<
+    0,
+    1,
+    v1::UncheckedMigrateV0ToV1<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+pub mod v1 {
+    use super::*;
+
+    /// 函数级中文注释：v0 订单布局（无 `listing_id`）
+    #[derive(Encode, Decode)]
+    pub struct OrderV0<T: Config> {
+        pub maker_id: u64,
+        pub maker: T::AccountId,
+        pub taker: T::AccountId,
+        pub price: BalanceOf<T>,
+        pub qty: BalanceOf<T>,
+        pub amount: BalanceOf<T>,
+        pub created_at: MomentOf,
+        pub expire_at: MomentOf,
+        pub evidence_until: MomentOf,
+        pub maker_tron_address: TronAddress,
+        pub payment_commit: H256,
+        pub contact_commit: H256,
+        pub state: OrderState,
+        pub epay_trade_no: Option<BoundedVec<u8, ConstU32<64>>>,
+        pub completed_at: Option<MomentOf>,
+        pub is_first_purchase: bool,
+    }
+
+    impl<T: Config> OrderV0<T> {
+        /// 函数级中文注释：转换为 v1 订单（旧订单均非挂单成交）
+        pub fn into_v1(self) -> Order<T> {
+            Order {
+                maker_id: self.maker_id,
+                maker: self.maker,
+                taker: self.taker,
+                price: self.price,
+                qty: self.qty,
+                amount: self.amount,
+                created_at: self.created_at,
+                expire_at: self.expire_at,
+                evidence_until: self.evidence_until,
+                maker_tron_address: self.maker_tron_address,
+                payment_commit: self.payment_commit,
+                contact_commit: self.contact_commit,
+                state: self.state,
+                epay_trade_no: self.epay_trade_no,
+                completed_at: self.completed_at,
+                is_first_purchase: self.is_first_purchase,
+                listing_id: None,
+            }
+        }
+    }
+
+    /// 函数级中文注释：按旧布局重写全部订单（不检查版本，由 `MigrateV0ToV1` 包装）
+    pub struct UncheckedMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+            Orders::<T>::translate::<OrderV0<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                Some(old.into_v1())
+            });
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+}
@@ -0,0 +1,320 @@
+//! 函数级中文注释：pallet-otc-order 的 Mock Runtime，用于单元测试
+//!
+//! - 托管、信用、定价、做市商、身份、聊天权限均为内存实现
+//! - 做市商 1 → 账户 10（激活）；做市商 2 → 账户 20（未激活）
+//! - 默认价格 0.1 USD/DUST（精度 10^6）
+
+use crate as pallet_otc_order;
+use crate::{MakerApplicationInfo, MakerCreditInterface, MakerInterface, PricingProvider};
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU128, ConstU16, ConstU32, ConstU64},
+};
+use sp_core::H256;
+use sp_runtime::{BuildStorage, DispatchError, DispatchResult};
+use std::{cell::RefCell, collections::BTreeMap};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+/// 1 DUST（精度 10^12）
+pub const UNIT: u128 = 1_000_000_000_000;
+/// 做市商 1 的账户
+pub const MAKER: u64 = 10;
+/// 买家账户
+pub const BUYER: u64 = 1;
+/// 做市商 1 登记的付款看守账户
+pub const WATCHER: u64 = 30;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        Timestamp: pallet_timestamp,
+        OtcOrder: pallet_otc_order,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u128>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+    type Balance = u128;
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<1>;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    /// DUST/USD 汇率（精度 10^6，默认 0.1 USD）
+    pub static DustUsdRate: Option<u128> = Some(100_000);
+}
+
+thread_local! {
+    /// 托管余额（托管ID → (付款人, 金额)）
+    pub static ESCROWED: RefCell<BTreeMap<u64, (u64, u128)>> = RefCell::new(BTreeMap::new());
+    /// 托管出账记录（托管ID, 收款人, 金额）
+    pub static PAYOUTS: RefCell<Vec<(u64, u64, u128)>> = RefCell::new(Vec::new());
+    /// 买家已占用额度（USD，精度 10^6）
+    pub static OCCUPIED_QUOTA: RefCell<BTreeMap<u64, u64>> = RefCell::new(BTreeMap::new());
+    /// 身份认证等级（账户 → 最高判断等级）
+    pub static JUDGEMENTS: RefCell<BTreeMap<u64, u8>> = RefCell::new(BTreeMap::new());
+    /// 付款证明证据登记记录（订单ID, 承诺哈希）
+    pub static EVIDENCE: RefCell<Vec<(u64, H256)>> = RefCell::new(Vec::new());
+}
+
+/// Mock Escrow - 内存记账
+pub struct MockEscrow;
+impl MockEscrow {
+    fn pay_all(id: u64, to: &u64) -> DispatchResult {
+        let (_, amount) = ESCROWED
+            .with(|m| m.borrow_mut().remove(&id))
+            .ok_or(DispatchError::Other("NoLock"))?;
+        PAYOUTS.with(|p| p.borrow_mut().push((id, *to, amount)));
+        Ok(())
+    }
+}
+impl pallet_escrow::Escrow<u64, u128> for MockEscrow {
+    fn lock_from(payer: &u64, id: u64, amount: u128) -> DispatchResult {
+        ESCROWED.with(|m| m.borrow_mut().insert(id, (*payer, amount)));
+        Ok(())
+    }
+    fn transfer_from_escrow(id: u64, to: &u64, amount: u128) -> DispatchResult {
+        ESCROWED.with(|m| {
+            let mut m = m.borrow_mut();
+            let (_, cur) = m.get_mut(&id).ok_or(DispatchError::Other("NoLock"))?;
+            *cur = cur.checked_sub(amount).ok_or(DispatchError::Other("Insufficient"))?;
+            Ok::<_, DispatchError>(())
+        })?;
+        PAYOUTS.with(|p| p.borrow_mut().push((id, *to, amount)));
+        Ok(())
+    }
+    fn release_all(id: u64, to: &u64) -> DispatchResult {
+        Self::pay_all(id, to)
+    }
+    fn refund_all(id: u64, to: &u64) -> DispatchResult {
+        Self::pay_all(id, to)
+    }
+    fn amount_of(id: u64) -> u128 {
+        ESCROWED.with(|m| m.borrow().get(&id).map(|(_, a)| *a).unwrap_or(0))
+    }
+}
+
+/// Mock 买家信用 - 额度不设上限，仅记录占用
+pub struct MockCredit;
+impl pallet_credit::BuyerCreditInterface<u64> for MockCredit {
+    fn get_buyer_credit_score(_buyer: &u64) -> Result<u16, DispatchError> {
+        Ok(800)
+    }
+    fn check_buyer_daily_limit(_buyer: &u64, _amount_usd_cents: u64) -> Result<(), DispatchError> {
+        Ok(())
+    }
+    fn check_buyer_single_limit(_buyer: &u64, _amount_usd_cents: u64) -> Result<(), DispatchError> {
+        Ok(())
+    }
+}
+impl pallet_credit::quota::BuyerQuotaInterface<u64> for MockCredit {
+    fn get_available_quota(_buyer: &u64) -> Result<u64, DispatchError> {
+        Ok(u64::MAX)
+    }
+    fn occupy_quota(buyer: &u64, amount_usd: u64) -> DispatchResult {
+        OCCUPIED_QUOTA.with(|q| *q.borrow_mut().entry(*buyer).or_default() += amount_usd);
+        Ok(())
+    }
+    fn release_quota(buyer: &u64, amount_usd: u64) -> DispatchResult {
+        OCCUPIED_QUOTA.with(|q| {
+            let mut q = q.borrow_mut();
+            let cur = q.entry(*buyer).or_default();
+            *cur = cur.saturating_sub(amount_usd);
+        });
+        Ok(())
+    }
+    fn check_concurrent_limit(_buyer: &u64) -> Result<bool, DispatchError> {
+        Ok(true)
+    }
+    fn record_order_completed(_buyer: &u64, _order_id: u64) -> DispatchResult {
+        Ok(())
+    }
+    fn record_order_cancelled(_buyer: &u64, _order_id: u64) -> DispatchResult {
+        Ok(())
+    }
+    fn record_violation(
+        _buyer: &u64,
+        _violation_type: pallet_credit::quota::ViolationType,
+    ) -> DispatchResult {
+        Ok(())
+    }
+    fn is_suspended(_buyer: &u64) -> Result<bool, DispatchError> {
+        Ok(false)
+    }
+    fn is_blacklisted(_buyer: &u64) -> Result<bool, DispatchError> {
+        Ok(false)
+    }
+}
+
+/// Mock 做市商信用 - 空实现
+pub struct MockMakerCredit;
+impl MakerCreditInterface for MockMakerCredit {
+    fn record_maker_order_completed(_maker_id: u64, _order_id: u64, _response_time_seconds: u32) -> DispatchResult {
+        Ok(())
+    }
+    fn record_maker_order_timeout(_maker_id: u64, _order_id: u64) -> DispatchResult {
+        Ok(())
+    }
+    fn record_maker_dispute_result(_maker_id: u64, _order_id: u64, _maker_win: bool) -> DispatchResult {
+        Ok(())
+    }
+}
+
+/// Mock 定价 - 读取 `DustUsdRate`
+pub struct MockPricing;
+impl PricingProvider<u128> for MockPricing {
+    fn get_dust_to_usd_rate() -> Option<u128> {
+        DustUsdRate::get()
+    }
+}
+
+/// Mock 做市商 - 做市商 1 激活（账户 10），做市商 2 未激活（账户 20）
+pub struct MockMakers;
+impl MakerInterface<u64, u128> for MockMakers {
+    fn get_maker_application(maker_id: u64) -> Option<MakerApplicationInfo<u64, u128>> {
+        let (account, is_active) = match maker_id {
+            1 => (MAKER, true),
+            2 => (20, false),
+            _ => return None,
+        };
+        Some(MakerApplicationInfo {
+            account,
+            tron_address: b"TXYZabcdefghijklmnopqrstuvwxyz1234".to_vec().try_into().unwrap(),
+            is_active,
+            _phantom: Default::default(),
+        })
+    }
+    fn is_maker_active(maker_id: u64) -> bool {
+        maker_id == 1
+    }
+}
+
+/// Mock 身份认证 - 读取 `JUDGEMENTS`
+pub struct MockIdentity;
+impl crate::IdentityVerificationProvider<u64> for MockIdentity {
+    fn get_highest_judgement_priority(who: &u64) -> Option<u8> {
+        JUDGEMENTS.with(|j| j.borrow().get(who).copied())
+    }
+    fn has_problematic_judgement(_who: &u64) -> bool {
+        false
+    }
+}
+
+/// Mock 聊天权限 - 空实现
+pub struct MockChatPermission;
+impl pallet_chat_permission::SceneAuthorizationManager<u64, u64> for MockChatPermission {
+    fn grant_scene_authorization(
+        _source: [u8; 8],
+        _from: &u64,
+        _to: &u64,
+        _scene_type: pallet_chat_permission::SceneType,
+        _scene_id: pallet_chat_permission::SceneId,
+        _duration: Option<u64>,
+        _metadata: Vec<u8>,
+    ) -> DispatchResult {
+        Ok(())
+    }
+    fn grant_bidirectional_scene_authorization(
+        _source: [u8; 8],
+        _user1: &u64,
+        _user2: &u64,
+        _scene_type: pallet_chat_permission::SceneType,
+        _scene_id: pallet_chat_permission::SceneId,
+        _duration: Option<u64>,
+        _metadata: Vec<u8>,
+    ) -> DispatchResult {
+        Ok(())
+    }
+    fn revoke_scene_authorization(
+        _source: [u8; 8],
+        _from: &u64,
+        _to: &u64,
+        _scene_type: pallet_chat_permission::SceneType,
+        _scene_id: pallet_chat_permission::SceneId,
+    ) -> DispatchResult {
+        Ok(())
+    }
+    fn revoke_all_by_source(_source: [u8; 8], _user1: &u64, _user2: &u64) -> DispatchResult {
+        Ok(())
+    }
+}
+
+/// Mock 付款证据登记 - 记录承诺哈希，证据ID为序号
+pub struct MockPaymentEvidence;
+impl crate::PaymentEvidenceSink<u64> for MockPaymentEvidence {
+    fn attach(order_id: u64, _owner: &u64, commit: H256) -> Option<u64> {
+        EVIDENCE.with(|e| {
+            let mut e = e.borrow_mut();
+            e.push((order_id, commit));
+            Some(e.len() as u64)
+        })
+    }
+}
+
+impl pallet_otc_order::Config for Test {
+    type Currency = Balances;
+    type Timestamp = Timestamp;
+    type Escrow = MockEscrow;
+    type Credit = MockCredit;
+    type MakerCredit = MockMakerCredit;
+    type Pricing = MockPricing;
+    type MakerPallet = MockMakers;
+    type CommitteeOrigin = frame_system::EnsureRoot<u64>;
+    type IdentityProvider = MockIdentity;
+    type ChatPermission = MockChatPermission;
+    type OrderTimeout = ConstU64<7_200>;
+    type EvidenceWindow = ConstU64<86_400>;
+    type FirstPurchaseUsdValue = ConstU128<10_000_000>;
+    type MinFirstPurchaseDustAmount = ConstU128<{ 10 * UNIT }>;
+    type MaxFirstPurchaseDustAmount = ConstU128<{ 10_000 * UNIT }>;
+    type MaxOrderUsdAmount = ConstU64<200_000_000>; // 200 USD
+    type MinOrderUsdAmount = ConstU64<20_000_000>; // 20 USD
+    type FirstPurchaseUsdAmount = ConstU64<10_000_000>;
+    type AmountValidationTolerance = ConstU16<100>;
+    type MaxFirstPurchaseOrdersPerMaker = ConstU32<5>;
+    type PaymentAttestationTimeout = ConstU64<10>;
+    type MaxAttestationTimeoutsPerBlock = ConstU32<2>;
+    type MaxKycExpiriesPerBlock = ConstU32<2>;
+    type MaxAccountsPerKycAttestor = ConstU32<3>;
+    type PaymentEvidence = MockPaymentEvidence;
+    type WeightInfo = ();
+}
+
+/// 函数级中文注释：创建测试环境（区块 1，时间戳 1_000 秒）
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    ESCROWED.with(|m| m.borrow_mut().clear());
+    PAYOUTS.with(|p| p.borrow_mut().clear());
+    OCCUPIED_QUOTA.with(|q| q.borrow_mut().clear());
+    JUDGEMENTS.with(|j| j.borrow_mut().clear());
+    EVIDENCE.with(|e| e.borrow_mut().clear());
+    DustUsdRate::set(Some(100_000));
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(1_000_000);
+    });
+    ext
+}
+
+/// 函数级中文注释：推进时间（秒）
+pub fn advance_time(secs: u64) {
+    Timestamp::set_timestamp(Timestamp::get() + secs * 1_000);
+}
@@ -0,0 +1,161 @@
+//! 付款证明（银行/支付回调看守）相关逻辑实现
+//!
+//! - 做市商登记付款看守账户，看守服务对收款回调签名后提交付款证明
+//! - 是否足额由链上比较证明金额与订单金额得出（容差 `AmountValidationTolerance`），
+//!   足额证明自动释放DUST，无需做市商手动 `release_dust`
+//! - 买家标记付款后超时仍无足额证明：订单自动进入争议，证明历史的承诺哈希登记为证据
+
+use crate::pallet::{
+    AttestationDeadlines, OrderState, Orders, PaymentAttestation, PaymentAttestations,
+    PaymentEvidenceSink, PaymentWatchers,
+};
+use crate::{Config, Error, Event};
+use codec::Encode;
+use frame_support::pallet_prelude::*;
+use frame_support::sp_runtime::{SaturatedConversion, Saturating};
+use frame_support::traits::UnixTime;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_core::H256;
+
+/// 截止区块队列已满时最多向后顺延的区块数
+pub(crate) const ATTESTATION_DEADLINE_SPREAD: u32 = 16;
+
+impl<T: Config> crate::Pallet<T> {
+    /// 提交付款证明；证明金额不低于订单金额（扣除容差）即自动释放
+    pub fn do_attest_payment(
+        watcher: &T::AccountId,
+        order_id: u64,
+        fiat_amount: u64,
+        fiat_currency: [u8; 3],
+        statement_hash: H256,
+    ) -> DispatchResult {
+        let mut order = Orders::<T>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+
+        // 仅订单做市商登记的看守账户可提交
+        ensure!(
+            PaymentWatchers::<T>::get(order.maker_id).as_ref() == Some(watcher),
+            Error::<T>::NotPaymentWatcher
+        );
+
+        // 看守服务可能先于买家 `mark_paid` 收到回调
+        ensure!(
+            matches!(order.state, OrderState::Created | OrderState::PaidOrCommitted),
+            Error::<T>::InvalidOrderStatus
+        );
+
+        let full_payment = Self::is_full_payment(order.qty, order.price, fiat_amount)?;
+
+        let now = T::Timestamp::now().as_secs().saturated_into::<u64>();
+        PaymentAttestations::<T>::try_mutate(order_id, |history| {
+            history
+                .try_push(PaymentAttestation {
+                    watcher: watcher.clone(),
+                    fiat_amount,
+                    fiat_currency,
+                    statement_hash,
+                    full_payment,
+                    attested_at: now,
+                })
+                .map_err(|_| Error::<T>::TooManyPaymentAttestations)
+        })?;
+
+        Self::deposit_event(Event::PaymentAttested {
+            order_id,
+            watcher: watcher.clone(),
+            fiat_amount,
+            fiat_currency,
+            statement_hash,
+            full_payment,
+        });
+
+        if !full_payment {
+            return Ok(());
+        }
+
+        // 足额：买家尚未标记付款时先补记付款状态，再释放
+        if order.state == OrderState::Created {
+            order.state = OrderState::PaidOrCommitted;
+            Self::deposit_event(Event::OrderStateChanged {
+                order_id,
+                old_state: Self::state_to_u8(&OrderState::Created),
+                new_state: Self::state_to_u8(&OrderState::PaidOrCommitted),
+                actor: Some(watcher.clone()),
+            });
+        }
+        Self::release_order_unchecked(order_id, order, watcher.clone())?;
+
+        Self::deposit_event(Event::PaymentAutoReleased {
+            order_id,
+            watcher: watcher.clone(),
+        });
+        Ok(())
+    }
+
+    /// 证明金额（折合USD，精度10^6）是否覆盖订单金额
+    ///
+    /// 订单金额按成交数量与单价计算，允许 `AmountValidationTolerance` 基点的汇率/手续费偏差。
+    pub(crate) fn is_full_payment(
+        qty: crate::BalanceOf<T>,
+        price: crate::BalanceOf<T>,
+        fiat_amount: u64,
+    ) -> Result<bool, DispatchError> {
+        let expected = Self::calculate_usd_amount_from_dust(qty, price)?;
+        let tolerance_bps = T::AmountValidationTolerance::get().min(10_000) as u128;
+        let min_accepted = (expected as u128).saturating_mul(10_000 - tolerance_bps) / 10_000;
+        Ok(fiat_amount as u128 >= min_accepted)
+    }
+
+    /// 买家标记付款后登记付款证明截止区块（做市商未登记看守时不登记）
+    ///
+    /// 截止区块的队列已满时顺延到之后首个未满的区块（最多顺延 `ATTESTATION_DEADLINE_SPREAD` 块），
+    /// 不让已线下付款的买家标记失败；全部已满时发出 `PaymentAttestationDeadlineUnscheduled`，
+    /// 该订单不再超时自动争议，仍可由买家/做市商手动发起争议。
+    pub(crate) fn enqueue_attestation_deadline(maker_id: u64, order_id: u64) {
+        if !PaymentWatchers::<T>::contains_key(maker_id) {
+            return;
+        }
+        let deadline = frame_system::Pallet::<T>::block_number()
+            .saturating_add(T::PaymentAttestationTimeout::get());
+        for off in 0..=ATTESTATION_DEADLINE_SPREAD {
+            let key = deadline.saturating_add(off.into());
+            if AttestationDeadlines::<T>::try_mutate(key, |due| due.try_push(order_id)).is_ok() {
+                return;
+            }
+        }
+        Self::deposit_event(Event::PaymentAttestationDeadlineUnscheduled { order_id });
+    }
+
+    /// 处理当前区块到期的付款证明截止项，返回消耗的权重
+    ///
+    /// 仍处于已付款状态的订单自动进入争议，并将证明历史的承诺哈希登记为证据
+    /// （已释放/已争议/已取消的订单直接跳过）。
+    pub(crate) fn process_attestation_timeouts(n: BlockNumberFor<T>) -> Weight {
+        let db = T::DbWeight::get();
+        let due = AttestationDeadlines::<T>::take(n);
+        let mut weight = db.reads_writes(1, 1);
+
+        for order_id in due.iter() {
+            weight = weight.saturating_add(db.reads(1));
+            let Some(order) = Orders::<T>::get(order_id) else { continue };
+            if order.state != OrderState::PaidOrCommitted {
+                continue;
+            }
+
+            let history = PaymentAttestations::<T>::get(order_id);
+            let evidence_commit =
+                H256::from(sp_core::blake2_256(&(b"otc_pay_", order_id, &history).encode()));
+            let evidence_id = T::PaymentEvidence::attach(*order_id, &order.maker, evidence_commit);
+
+            Self::dispute_order_unchecked(*order_id, order, None);
+            Self::deposit_event(Event::PaymentAttestationTimedOut {
+                order_id: *order_id,
+                attestations: history.len() as u32,
+                evidence_commit,
+                evidence_id,
+            });
+            weight = weight.saturating_add(db.reads_writes(4, 4));
+        }
+
+        weight
+    }
+}
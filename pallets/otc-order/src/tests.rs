@@ -0,0 +1,700 @@
+//! Tests for pallet-otc-order
+
+use crate::{
+    mock::*, AttestationDeadlines, Error, Event, KycAttestations, KycAttestorAccounts,
+    KycAttestors, KycExpiringAt, KycTier, ListingFills, ListingState, Listings, OrderState, Orders,
+    PaymentAttestations,
+};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_core::H256;
+use sp_runtime::DispatchError;
+
+/// 函数级中文注释：做市商 1 创建挂单（溢价 100 基点，单笔 300 ~ 3000 DUST）
+fn create_default_listing() -> u64 {
+    assert_ok!(OtcOrder::create_listing(
+        RuntimeOrigin::signed(MAKER),
+        1,
+        5_000 * UNIT,
+        300 * UNIT,
+        3_000 * UNIT,
+        100,
+    ));
+    0
+}
+
+/// 函数级中文注释：买家吃单
+fn take(buyer: u64, listing_id: u64, qty: u128) -> frame_support::dispatch::DispatchResult {
+    OtcOrder::take_listing(
+        RuntimeOrigin::signed(buyer),
+        listing_id,
+        qty,
+        H256::repeat_byte(1),
+        H256::repeat_byte(2),
+    )
+}
+
+// ==================== 挂单测试 ====================
+
+#[test]
+fn create_listing_works() {
+    new_test_ext().execute_with(|| {
+        let listing_id = create_default_listing();
+
+        let listing = Listings::<Test>::get(listing_id).unwrap();
+        assert_eq!(listing.maker, MAKER);
+        // 参考价格 = 0.1 × 1.01
+        assert_eq!(listing.price, 101_000);
+        assert_eq!(listing.available_qty, 5_000 * UNIT);
+        assert_eq!(listing.state, ListingState::Active);
+    });
+}
+
+#[test]
+fn create_listing_rejects_invalid_params() {
+    new_test_ext().execute_with(|| {
+        // 非做市商本人
+        assert_noop!(
+            OtcOrder::create_listing(RuntimeOrigin::signed(BUYER), 1, 5_000 * UNIT, 300 * UNIT, 3_000 * UNIT, 0),
+            Error::<Test>::NotAuthorized
+        );
+        // 做市商未激活
+        assert_noop!(
+            OtcOrder::create_listing(RuntimeOrigin::signed(20), 2, 5_000 * UNIT, 300 * UNIT, 3_000 * UNIT, 0),
+            Error::<Test>::MakerNotActive
+        );
+        // 溢价超出范围
+        assert_noop!(
+            OtcOrder::create_listing(RuntimeOrigin::signed(MAKER), 1, 5_000 * UNIT, 300 * UNIT, 3_000 * UNIT, 501),
+            Error::<Test>::InvalidPremium
+        );
+        // 最小成交量低于最小订单金额（100 DUST = 10 USD）
+        assert_noop!(
+            OtcOrder::create_listing(RuntimeOrigin::signed(MAKER), 1, 5_000 * UNIT, 100 * UNIT, 3_000 * UNIT, 0),
+            Error::<Test>::OrderAmountTooSmall
+        );
+    });
+}
+
+#[test]
+fn take_listing_creates_independent_order() {
+    new_test_ext().execute_with(|| {
+        let listing_id = create_default_listing();
+
+        assert_ok!(take(BUYER, listing_id, 1_000 * UNIT));
+
+        let order = Orders::<Test>::get(0).unwrap();
+        assert_eq!(order.taker, BUYER);
+        assert_eq!(order.maker, MAKER);
+        assert_eq!(order.qty, 1_000 * UNIT);
+        assert_eq!(order.listing_id, Some(listing_id));
+        assert_eq!(order.state, OrderState::Created);
+        assert_eq!(<MockEscrow as pallet_escrow::Escrow<u64, u128>>::amount_of(0), 1_000 * UNIT);
+
+        let listing = Listings::<Test>::get(listing_id).unwrap();
+        assert_eq!(listing.available_qty, 4_000 * UNIT);
+        assert!(ListingFills::<Test>::contains_key(listing_id, 0));
+    });
+}
+
+#[test]
+fn take_listing_reprices_at_take_time() {
+    new_test_ext().execute_with(|| {
+        let listing_id = create_default_listing();
+
+        // 价格上涨到 0.15 USD：成交价按实时价格叠加 1% 溢价
+        DustUsdRate::set(Some(150_000));
+        assert_ok!(take(BUYER, listing_id, 1_000 * UNIT));
+
+        let order = Orders::<Test>::get(0).unwrap();
+        assert_eq!(order.price, 151_500);
+        System::assert_last_event(
+            Event::ListingFilled {
+                listing_id,
+                order_id: 0,
+                buyer: BUYER,
+                qty: 1_000 * UNIT,
+                price: 151_500,
+                remaining_qty: 4_000 * UNIT,
+            }
+            .into(),
+        );
+
+        // 定价服务不可用时不能成交
+        DustUsdRate::set(None);
+        assert_noop!(take(BUYER, listing_id, 1_000 * UNIT), Error::<Test>::PricingUnavailable);
+    });
+}
+
+#[test]
+fn take_listing_enforces_max_order_usd_amount() {
+    new_test_ext().execute_with(|| {
+        let listing_id = create_default_listing();
+
+        // 2500 DUST × 0.101 = 252.5 USD > 200 USD
+        assert_noop!(
+            take(BUYER, listing_id, 2_500 * UNIT),
+            Error::<Test>::OrderAmountExceedsLimit
+        );
+
+        // 价格下跌后，最小成交量对应金额低于最小订单金额：300 DUST × 0.0505 ≈ 15 USD
+        DustUsdRate::set(Some(50_000));
+        assert_noop!(
+            take(BUYER, listing_id, 300 * UNIT),
+            Error::<Test>::OrderAmountTooSmall
+        );
+    });
+}
+
+#[test]
+fn take_listing_validates_fill_range() {
+    new_test_ext().execute_with(|| {
+        let listing_id = create_default_listing();
+
+        assert_noop!(take(MAKER, listing_id, 1_000 * UNIT), Error::<Test>::CannotTakeOwnListing);
+        assert_noop!(take(BUYER, listing_id, 200 * UNIT), Error::<Test>::FillQtyOutOfRange);
+        assert_noop!(take(BUYER, 9, 1_000 * UNIT), Error::<Test>::ListingNotFound);
+    });
+}
+
+#[test]
+fn cancelled_fill_restores_listing_inventory() {
+    new_test_ext().execute_with(|| {
+        let listing_id = create_default_listing();
+        assert_ok!(take(BUYER, listing_id, 1_000 * UNIT));
+
+        assert_ok!(OtcOrder::cancel_order(RuntimeOrigin::signed(BUYER), 0));
+
+        assert_eq!(Orders::<Test>::get(0).unwrap().state, OrderState::Canceled);
+        assert_eq!(Listings::<Test>::get(listing_id).unwrap().available_qty, 5_000 * UNIT);
+        System::assert_has_event(
+            Event::ListingInventoryRestored { listing_id, order_id: 0, qty: 1_000 * UNIT }.into(),
+        );
+    });
+}
+
+#[test]
+fn released_fills_close_listing() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(OtcOrder::create_listing(
+            RuntimeOrigin::signed(MAKER),
+            1,
+            1_000 * UNIT,
+            500 * UNIT,
+            1_000 * UNIT,
+            0,
+        ));
+        assert_ok!(take(BUYER, 0, 500 * UNIT));
+        assert_ok!(take(2, 0, 500 * UNIT));
+        assert_noop!(take(3, 0, 500 * UNIT), Error::<Test>::InsufficientListingQty);
+
+        for (order_id, buyer) in [(0u64, BUYER), (1, 2)] {
+            assert_ok!(OtcOrder::mark_paid(RuntimeOrigin::signed(buyer), order_id, None));
+            assert_ok!(OtcOrder::release_dust(RuntimeOrigin::signed(MAKER), order_id));
+        }
+
+        let listing = Listings::<Test>::get(0).unwrap();
+        assert_eq!(listing.filled_qty, 1_000 * UNIT);
+        assert_eq!(listing.state, ListingState::Closed);
+    });
+}
+
+#[test]
+fn cancel_listing_keeps_open_fills() {
+    new_test_ext().execute_with(|| {
+        let listing_id = create_default_listing();
+        assert_ok!(take(BUYER, listing_id, 1_000 * UNIT));
+
+        assert_noop!(
+            OtcOrder::cancel_listing(RuntimeOrigin::signed(BUYER), listing_id),
+            Error::<Test>::NotAuthorized
+        );
+        assert_ok!(OtcOrder::cancel_listing(RuntimeOrigin::signed(MAKER), listing_id));
+        assert_noop!(take(BUYER, listing_id, 1_000 * UNIT), Error::<Test>::ListingNotActive);
+
+        // 已成交订单继续释放流程；取消后数量不再退回挂单
+        assert_ok!(OtcOrder::cancel_order(RuntimeOrigin::signed(BUYER), 0));
+        assert_eq!(Listings::<Test>::get(listing_id).unwrap().available_qty, 4_000 * UNIT);
+    });
+}
+
+// ==================== 付款证明测试 ====================
+
+/// 函数级中文注释：买家向做市商 1 下单 1000 DUST（0.1 USD/DUST，订单金额 100 USD）
+fn create_order(buyer: u64) -> u64 {
+    let order_id = OtcOrder::next_order_id();
+    assert_ok!(OtcOrder::create_order(
+        RuntimeOrigin::signed(buyer),
+        1,
+        1_000 * UNIT,
+        H256::repeat_byte(1),
+        H256::repeat_byte(2),
+    ));
+    order_id
+}
+
+/// 函数级中文注释：看守账户提交付款证明（金额为折合USD，精度10^6）
+fn attest(order_id: u64, usd: u64) -> frame_support::dispatch::DispatchResult {
+    OtcOrder::attest_payment(
+        RuntimeOrigin::signed(WATCHER),
+        order_id,
+        usd,
+        *b"CNY",
+        H256::repeat_byte(9),
+    )
+}
+
+fn register_watcher() {
+    assert_ok!(OtcOrder::set_payment_watcher(RuntimeOrigin::signed(MAKER), 1, Some(WATCHER)));
+}
+
+#[test]
+fn set_payment_watcher_requires_maker() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            OtcOrder::set_payment_watcher(RuntimeOrigin::signed(BUYER), 1, Some(WATCHER)),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            OtcOrder::set_payment_watcher(RuntimeOrigin::signed(MAKER), 9, Some(WATCHER)),
+            Error::<Test>::MakerNotFound
+        );
+        register_watcher();
+
+        let order_id = create_order(BUYER);
+        assert_noop!(
+            OtcOrder::attest_payment(
+                RuntimeOrigin::signed(BUYER),
+                order_id,
+                100_000_000,
+                *b"CNY",
+                H256::repeat_byte(9),
+            ),
+            Error::<Test>::NotPaymentWatcher
+        );
+
+        // 注销后看守账户不能再提交证明
+        assert_ok!(OtcOrder::set_payment_watcher(RuntimeOrigin::signed(MAKER), 1, None));
+        assert_noop!(attest(order_id, 100_000_000), Error::<Test>::NotPaymentWatcher);
+    });
+}
+
+#[test]
+fn partial_attestation_is_recorded_without_release() {
+    new_test_ext().execute_with(|| {
+        register_watcher();
+        let order_id = create_order(BUYER);
+        assert_ok!(OtcOrder::mark_paid(RuntimeOrigin::signed(BUYER), order_id, None));
+
+        // 98 USD 低于 100 USD 扣除 1% 容差
+        assert_ok!(attest(order_id, 98_000_000));
+
+        assert_eq!(Orders::<Test>::get(order_id).unwrap().state, OrderState::PaidOrCommitted);
+        let history = PaymentAttestations::<Test>::get(order_id);
+        assert_eq!(history.len(), 1);
+        assert!(!history[0].full_payment);
+        assert!(PAYOUTS.with(|p| p.borrow().is_empty()));
+        System::assert_last_event(
+            Event::PaymentAttested {
+                order_id,
+                watcher: WATCHER,
+                fiat_amount: 98_000_000,
+                fiat_currency: *b"CNY",
+                statement_hash: H256::repeat_byte(9),
+                full_payment: false,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn full_attestation_auto_releases() {
+    new_test_ext().execute_with(|| {
+        register_watcher();
+        let order_id = create_order(BUYER);
+        assert_ok!(OtcOrder::mark_paid(RuntimeOrigin::signed(BUYER), order_id, None));
+
+        // 99.5 USD 在 1% 容差内视为足额
+        assert_ok!(attest(order_id, 99_500_000));
+
+        assert_eq!(Orders::<Test>::get(order_id).unwrap().state, OrderState::Released);
+        assert!(PaymentAttestations::<Test>::get(order_id)[0].full_payment);
+        assert_eq!(PAYOUTS.with(|p| p.borrow().clone()), vec![(order_id, BUYER, 1_000 * UNIT)]);
+        System::assert_last_event(
+            Event::PaymentAutoReleased { order_id, watcher: WATCHER }.into(),
+        );
+
+        // 已释放订单不再接受证明
+        assert_noop!(attest(order_id, 100_000_000), Error::<Test>::InvalidOrderStatus);
+    });
+}
+
+#[test]
+fn full_attestation_before_mark_paid_releases() {
+    new_test_ext().execute_with(|| {
+        register_watcher();
+        let order_id = create_order(BUYER);
+
+        assert_ok!(attest(order_id, 100_000_000));
+
+        assert_eq!(Orders::<Test>::get(order_id).unwrap().state, OrderState::Released);
+        System::assert_has_event(
+            Event::OrderStateChanged {
+                order_id,
+                old_state: 0,
+                new_state: 1,
+                actor: Some(WATCHER),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn attestation_timeout_auto_disputes() {
+    new_test_ext().execute_with(|| {
+        register_watcher();
+        let order_id = create_order(BUYER);
+        assert_ok!(OtcOrder::mark_paid(RuntimeOrigin::signed(BUYER), order_id, None));
+        assert_eq!(AttestationDeadlines::<Test>::get(11).into_inner(), vec![order_id]);
+        assert_ok!(attest(order_id, 50_000_000));
+
+        // 截止前不处理
+        OtcOrder::on_initialize(10);
+        assert_eq!(Orders::<Test>::get(order_id).unwrap().state, OrderState::PaidOrCommitted);
+
+        OtcOrder::on_initialize(11);
+        assert_eq!(Orders::<Test>::get(order_id).unwrap().state, OrderState::Disputed);
+        assert!(AttestationDeadlines::<Test>::get(11).is_empty());
+
+        let evidence = EVIDENCE.with(|e| e.borrow().clone());
+        assert_eq!(evidence.len(), 1);
+        let (evidence_order, evidence_commit) = evidence[0];
+        assert_eq!(evidence_order, order_id);
+        System::assert_last_event(
+            Event::PaymentAttestationTimedOut {
+                order_id,
+                attestations: 1,
+                evidence_commit,
+                evidence_id: Some(1),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn attestation_timeout_skips_settled_orders() {
+    new_test_ext().execute_with(|| {
+        register_watcher();
+        let order_id = create_order(BUYER);
+        assert_ok!(OtcOrder::mark_paid(RuntimeOrigin::signed(BUYER), order_id, None));
+        assert_ok!(attest(order_id, 100_000_000));
+
+        OtcOrder::on_initialize(11);
+        assert_eq!(Orders::<Test>::get(order_id).unwrap().state, OrderState::Released);
+        assert!(EVIDENCE.with(|e| e.borrow().is_empty()));
+    });
+}
+
+#[test]
+fn mark_paid_rolls_deadline_over_when_queue_full() {
+    new_test_ext().execute_with(|| {
+        register_watcher();
+        // 同一截止区块最多 2 单
+        for buyer in [BUYER, 2] {
+            let order_id = create_order(buyer);
+            assert_ok!(OtcOrder::mark_paid(RuntimeOrigin::signed(buyer), order_id, None));
+        }
+        assert_eq!(AttestationDeadlines::<Test>::get(11).len(), 2);
+
+        // 截止区块已满：顺延到下一区块，买家标记不失败
+        let order_id = create_order(3);
+        assert_ok!(OtcOrder::mark_paid(RuntimeOrigin::signed(3), order_id, None));
+        assert_eq!(AttestationDeadlines::<Test>::get(11).len(), 2);
+        assert_eq!(AttestationDeadlines::<Test>::get(12).into_inner(), vec![order_id]);
+        assert_eq!(
+            Orders::<Test>::get(order_id).unwrap().state,
+            OrderState::PaidOrCommitted
+        );
+
+        // 顺延后的截止区块到期同样自动进入争议
+        OtcOrder::on_initialize(12);
+        assert_eq!(Orders::<Test>::get(order_id).unwrap().state, OrderState::Disputed);
+    });
+}
+
+// ==================== KYC分级认证测试 ====================
+
+/// KYC认证方账户
+const ATTESTOR: u64 = 40;
+
+fn add_attestor() {
+    assert_ok!(OtcOrder::add_kyc_attestor(RuntimeOrigin::root(), ATTESTOR, KycTier::IdDocument));
+}
+
+fn attest_kyc(account: u64, tier: KycTier, expires_at: u64) -> frame_support::dispatch::DispatchResult {
+    OtcOrder::attest_kyc(
+        RuntimeOrigin::signed(ATTESTOR),
+        account,
+        tier,
+        H256::repeat_byte(7),
+        expires_at,
+    )
+}
+
+#[test]
+fn add_and_remove_kyc_attestor() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            OtcOrder::add_kyc_attestor(RuntimeOrigin::signed(BUYER), ATTESTOR, KycTier::Phone),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            OtcOrder::add_kyc_attestor(RuntimeOrigin::root(), ATTESTOR, KycTier::None),
+            Error::<Test>::InvalidKycTier
+        );
+        add_attestor();
+        assert_eq!(KycAttestors::<Test>::get(ATTESTOR), Some(KycTier::IdDocument));
+        System::assert_last_event(
+            Event::KycAttestorAdded { attestor: ATTESTOR, max_tier: 2 }.into(),
+        );
+
+        assert_noop!(
+            OtcOrder::remove_kyc_attestor(RuntimeOrigin::root(), BUYER, 0),
+            Error::<Test>::NotKycAttestor
+        );
+        assert_ok!(OtcOrder::remove_kyc_attestor(RuntimeOrigin::root(), ATTESTOR, 0));
+        assert!(KycAttestors::<Test>::get(ATTESTOR).is_none());
+        System::assert_last_event(
+            Event::KycAttestorRemoved { attestor: ATTESTOR, revoked_accounts: 0 }.into(),
+        );
+    });
+}
+
+#[test]
+fn attest_kyc_validates_attestor_and_tier() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(attest_kyc(BUYER, KycTier::Phone, 100), Error::<Test>::NotKycAttestor);
+        add_attestor();
+        assert_noop!(attest_kyc(BUYER, KycTier::None, 100), Error::<Test>::InvalidKycTier);
+        assert_noop!(
+            attest_kyc(BUYER, KycTier::EnhancedDueDiligence, 100),
+            Error::<Test>::KycTierExceedsAttestor
+        );
+        assert_noop!(attest_kyc(BUYER, KycTier::Phone, 1), Error::<Test>::InvalidAttestationExpiry);
+
+        assert_ok!(attest_kyc(BUYER, KycTier::Phone, 100));
+        assert_eq!(OtcOrder::kyc_tier_of(&BUYER), KycTier::Phone);
+        assert_eq!(KycExpiringAt::<Test>::get(100).into_inner(), vec![BUYER]);
+        assert_eq!(KycAttestorAccounts::<Test>::get(ATTESTOR).into_inner(), vec![BUYER]);
+
+        // 覆盖旧认证时迁移过期索引
+        assert_ok!(attest_kyc(BUYER, KycTier::IdDocument, 200));
+        assert_eq!(OtcOrder::kyc_tier_of(&BUYER), KycTier::IdDocument);
+        assert!(KycExpiringAt::<Test>::get(100).is_empty());
+        assert_eq!(KycExpiringAt::<Test>::get(200).into_inner(), vec![BUYER]);
+        assert_eq!(KycAttestorAccounts::<Test>::get(ATTESTOR).into_inner(), vec![BUYER]);
+    });
+}
+
+#[test]
+fn attest_kyc_respects_index_bounds() {
+    new_test_ext().execute_with(|| {
+        add_attestor();
+        // 同一过期区块最多 2 条
+        assert_ok!(attest_kyc(1, KycTier::Phone, 100));
+        assert_ok!(attest_kyc(2, KycTier::Phone, 100));
+        assert_noop!(attest_kyc(3, KycTier::Phone, 100), Error::<Test>::KycExpiryQueueFull);
+        // 单个认证方最多 3 个账户
+        assert_ok!(attest_kyc(3, KycTier::Phone, 101));
+        assert_noop!(attest_kyc(4, KycTier::Phone, 101), Error::<Test>::TooManyAttestedAccounts);
+    });
+}
+
+#[test]
+fn kyc_tier_cap_rejects_large_orders() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            OtcOrder::set_kyc_tier_order_cap(RuntimeOrigin::signed(BUYER), KycTier::Phone, Some(1)),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(OtcOrder::enable_kyc_requirement(RuntimeOrigin::root(), 2));
+        // 手机号等级单笔上限 50 USD
+        assert_ok!(OtcOrder::set_kyc_tier_order_cap(
+            RuntimeOrigin::root(),
+            KycTier::Phone,
+            Some(50_000_000),
+        ));
+        System::assert_last_event(
+            Event::KycTierOrderCapSet { tier: 1, max_usd: Some(50_000_000) }.into(),
+        );
+
+        // 无身份信息且无分级认证
+        assert_noop!(
+            OtcOrder::create_order(
+                RuntimeOrigin::signed(BUYER),
+                1,
+                400 * UNIT,
+                H256::repeat_byte(1),
+                H256::repeat_byte(2),
+            ),
+            Error::<Test>::IdentityNotSet
+        );
+
+        add_attestor();
+        assert_ok!(attest_kyc(BUYER, KycTier::Phone, 100));
+        // 1000 DUST = 100 USD 超过上限
+        assert_noop!(
+            OtcOrder::create_order(
+                RuntimeOrigin::signed(BUYER),
+                1,
+                1_000 * UNIT,
+                H256::repeat_byte(1),
+                H256::repeat_byte(2),
+            ),
+            Error::<Test>::KycTierLimitExceeded
+        );
+        // 400 DUST = 40 USD
+        assert_ok!(OtcOrder::create_order(
+            RuntimeOrigin::signed(BUYER),
+            1,
+            400 * UNIT,
+            H256::repeat_byte(1),
+            H256::repeat_byte(2),
+        ));
+
+        // 移除上限后仅受 MaxOrderUsdAmount 约束
+        assert_ok!(OtcOrder::set_kyc_tier_order_cap(RuntimeOrigin::root(), KycTier::Phone, None));
+        create_order(BUYER);
+    });
+}
+
+#[test]
+fn revoke_kyc_attestation_cancels_open_orders() {
+    new_test_ext().execute_with(|| {
+        add_attestor();
+        assert_ok!(attest_kyc(BUYER, KycTier::Phone, 100));
+        let open = create_order(BUYER);
+        let paid = create_order(BUYER);
+        assert_ok!(OtcOrder::mark_paid(RuntimeOrigin::signed(BUYER), paid, None));
+
+        assert_noop!(
+            OtcOrder::revoke_kyc_attestation(RuntimeOrigin::signed(MAKER), BUYER, 2),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            OtcOrder::revoke_kyc_attestation(RuntimeOrigin::signed(ATTESTOR), BUYER, 1),
+            Error::<Test>::InvalidWitness
+        );
+        assert_ok!(OtcOrder::revoke_kyc_attestation(RuntimeOrigin::signed(ATTESTOR), BUYER, 2));
+
+        assert!(KycAttestations::<Test>::get(BUYER).is_none());
+        assert!(KycExpiringAt::<Test>::get(100).is_empty());
+        assert!(KycAttestorAccounts::<Test>::get(ATTESTOR).is_empty());
+        assert_eq!(Orders::<Test>::get(open).unwrap().state, OrderState::Canceled);
+        assert_eq!(Orders::<Test>::get(paid).unwrap().state, OrderState::PaidOrCommitted);
+        System::assert_last_event(
+            Event::KycAttestationRevoked { account: BUYER, tier: 1, cancelled_orders: 1 }.into(),
+        );
+
+        // 委员会也可撤销
+        assert_ok!(attest_kyc(BUYER, KycTier::Phone, 100));
+        assert_ok!(OtcOrder::revoke_kyc_attestation(RuntimeOrigin::root(), BUYER, 2));
+        assert_noop!(
+            OtcOrder::revoke_kyc_attestation(RuntimeOrigin::root(), BUYER, 2),
+            Error::<Test>::KycAttestationNotFound
+        );
+    });
+}
+
+#[test]
+fn expired_kyc_attestation_cancels_open_orders() {
+    new_test_ext().execute_with(|| {
+        add_attestor();
+        assert_ok!(attest_kyc(BUYER, KycTier::Phone, 5));
+        let order_id = create_order(BUYER);
+
+        OtcOrder::on_initialize(4);
+        assert_eq!(Orders::<Test>::get(order_id).unwrap().state, OrderState::Created);
+
+        OtcOrder::on_initialize(5);
+        assert!(KycAttestations::<Test>::get(BUYER).is_none());
+        assert!(KycAttestorAccounts::<Test>::get(ATTESTOR).is_empty());
+        assert_eq!(Orders::<Test>::get(order_id).unwrap().state, OrderState::Canceled);
+        System::assert_last_event(
+            Event::KycAttestationExpired { account: BUYER, tier: 1, cancelled_orders: 1 }.into(),
+        );
+    });
+}
+
+#[test]
+fn remove_kyc_attestor_revokes_its_attestations() {
+    new_test_ext().execute_with(|| {
+        add_attestor();
+        assert_ok!(attest_kyc(BUYER, KycTier::Phone, 100));
+        assert_ok!(attest_kyc(2, KycTier::IdDocument, 100));
+        let first = create_order(BUYER);
+        let second = create_order(2);
+
+        assert_noop!(
+            OtcOrder::remove_kyc_attestor(RuntimeOrigin::root(), ATTESTOR, 1),
+            Error::<Test>::InvalidWitness
+        );
+        assert_ok!(OtcOrder::remove_kyc_attestor(RuntimeOrigin::root(), ATTESTOR, 2));
+
+        for (account, order_id) in [(BUYER, first), (2, second)] {
+            assert!(KycAttestations::<Test>::get(account).is_none());
+            assert_eq!(Orders::<Test>::get(order_id).unwrap().state, OrderState::Canceled);
+        }
+        assert!(KycExpiringAt::<Test>::get(100).is_empty());
+        System::assert_last_event(
+            Event::KycAttestorRemoved { attestor: ATTESTOR, revoked_accounts: 2 }.into(),
+        );
+    });
+}
+
+#[test]
+fn migrate_v0_to_v1_backfills_listing_id() {
+    use codec::Encode;
+    use frame_support::{
+        storage::unhashed,
+        traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    };
+
+    new_test_ext().execute_with(|| {
+        let listing_id = create_default_listing();
+        assert_ok!(take(BUYER, listing_id, 1_000 * UNIT));
+        let order = Orders::<Test>::get(0).unwrap();
+
+        // 按 v0 布局（无 listing_id）改写订单
+        let v0 = crate::migrations::v1::OrderV0::<Test> {
+            maker_id: order.maker_id,
+            maker: order.maker,
+            taker: order.taker,
+            price: order.price,
+            qty: order.qty,
+            amount: order.amount,
+            created_at: order.created_at,
+            expire_at: order.expire_at,
+            evidence_until: order.evidence_until,
+            maker_tron_address: order.maker_tron_address.clone(),
+            payment_commit: order.payment_commit,
+            contact_commit: order.contact_commit,
+            state: order.state.clone(),
+            epay_trade_no: None,
+            completed_at: None,
+            is_first_purchase: order.is_first_purchase,
+        };
+        unhashed::put_raw(&Orders::<Test>::hashed_key_for(0), &v0.encode());
+        StorageVersion::new(0).put::<OtcOrder>();
+
+        crate::migrations::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        let migrated = Orders::<Test>::get(0).unwrap();
+        assert_eq!(migrated.listing_id, None);
+        assert_eq!(migrated.taker, BUYER);
+        assert_eq!(migrated.qty, 1_000 * UNIT);
+        assert_eq!(OtcOrder::on_chain_storage_version(), 1);
+    });
+}
@@ -1,6 +1,6 @@
 //! OTC订单KYC认证相关类型定义
 
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use frame_support::pallet_prelude::*;
 use scale_info::TypeInfo;
 
@@ -28,6 +28,8 @@ pub enum KycVerificationResult {
     Exempted,
     /// 跳过：KYC未启用
     Skipped,
+    /// 🆕 通过：持有有效的分级认证
+    Attested(KycTier),
 }
 
 /// KYC验证失败原因
@@ -53,4 +55,51 @@ impl KycFailureReason {
             KycFailureReason::QualityIssue => 3,
         }
     }
-}
\ No newline at end of file
+}
+/// 🆕 KYC分级等级（由外部KYC服务商通过认证方链上背书）
+///
+/// 等级单调递增，高等级隐含低等级的全部验证项。
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, PartialOrd, Ord,
+    TypeInfo, MaxEncodedLen, RuntimeDebug, Default,
+)]
+pub enum KycTier {
+    /// 无有效分级认证
+    #[default]
+    None,
+    /// 手机号已验证
+    Phone,
+    /// 身份证件已验证
+    IdDocument,
+    /// 增强尽职调查（EDD）
+    EnhancedDueDiligence,
+}
+
+impl KycTier {
+    /// 转换为等级代码（0=None, 1=Phone, 2=IdDocument, 3=EnhancedDueDiligence）
+    pub fn to_code(&self) -> u8 {
+        match self {
+            KycTier::None => 0,
+            KycTier::Phone => 1,
+            KycTier::IdDocument => 2,
+            KycTier::EnhancedDueDiligence => 3,
+        }
+    }
+}
+
+/// 🆕 KYC分级认证记录
+///
+/// 链上仅保存服务商签名声明的哈希，不保存任何个人身份信息（PII）。
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct KycAttestation<AccountId, BlockNumber> {
+    /// 认证等级
+    pub tier: KycTier,
+    /// 签发认证的认证方账户
+    pub attestor: AccountId,
+    /// 服务商签名声明的哈希（链下可凭原文核验）
+    pub claim_hash: sp_core::H256,
+    /// 签发区块
+    pub issued_at: BlockNumber,
+    /// 过期区块（达到该高度后认证失效）
+    pub expires_at: BlockNumber,
+}
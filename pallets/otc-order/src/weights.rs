@@ -18,6 +18,24 @@ pub trait WeightInfo {
     fn update_min_judgment_level() -> Weight;
     fn exempt_account_from_kyc() -> Weight;
     fn remove_kyc_exemption() -> Weight;
+
+    // === 挂单相关函数权重 ===
+    fn create_listing() -> Weight;
+    fn cancel_listing() -> Weight;
+    fn take_listing() -> Weight;
+
+    // === 🆕 KYC分级认证函数权重 ===
+    fn add_kyc_attestor() -> Weight;
+    /// `a`：认证方已认证账户数（见证值）
+    fn remove_kyc_attestor(a: u32) -> Weight;
+    fn attest_kyc() -> Weight;
+    /// `o`：被撤销账户的买家订单数（见证值）
+    fn revoke_kyc_attestation(o: u32) -> Weight;
+    fn set_kyc_tier_order_cap() -> Weight;
+
+    // === 🆕 付款证明函数权重 ===
+    fn set_payment_watcher() -> Weight;
+    fn attest_payment() -> Weight;
 }
 
 /// 函数级详细中文注释：默认权重实现（临时占位）
@@ -62,5 +80,53 @@ impl WeightInfo for () {
     fn remove_kyc_exemption() -> Weight {
         Weight::from_parts(20_000, 0)
     }
-}
 
+    // === 挂单相关函数权重实现 ===
+    fn create_listing() -> Weight {
+        Weight::from_parts(20_000, 0)
+    }
+
+    fn cancel_listing() -> Weight {
+        Weight::from_parts(15_000, 0)
+    }
+
+    fn take_listing() -> Weight {
+        Weight::from_parts(30_000, 0)
+    }
+
+    // === 🆕 KYC分级认证函数权重实现 ===
+    fn add_kyc_attestor() -> Weight {
+        Weight::from_parts(20_000, 0)
+    }
+
+    /// 每个账户按最坏情况撤销（BuyerOrders 上限 100 笔）
+    fn remove_kyc_attestor(a: u32) -> Weight {
+        Weight::from_parts(20_000, 0)
+            .saturating_add(Self::revoke_kyc_attestation(100).saturating_mul(a as u64))
+    }
+
+    /// 索引维护含过期索引与认证方索引
+    fn attest_kyc() -> Weight {
+        Weight::from_parts(35_000, 0)
+    }
+
+    /// 每笔订单约 10_000（读取订单 + 可能的取消）
+    fn revoke_kyc_attestation(o: u32) -> Weight {
+        Weight::from_parts(20_000, 0)
+            .saturating_add(Weight::from_parts(10_000, 0).saturating_mul(o as u64))
+    }
+
+    fn set_kyc_tier_order_cap() -> Weight {
+        Weight::from_parts(15_000, 0)
+    }
+
+    // === 🆕 付款证明函数权重实现 ===
+    fn set_payment_watcher() -> Weight {
+        Weight::from_parts(20_000, 0)
+    }
+
+    /// 足额证明包含自动释放（托管释放 + 信用记录）
+    fn attest_payment() -> Weight {
+        Weight::from_parts(40_000, 0)
+    }
+}
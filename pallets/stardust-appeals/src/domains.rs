@@ -5,6 +5,10 @@
 //! ## 版本历史
 //! - v0.1.0 (2025-01-14): 初始版本，支持Domain 1-6
 //! - v0.2.0 (2025-01-15): 新增Domain 7（作品域）
+//! - v0.3.0: 新增Domain 8-12（占卜市场大师/评价、占卜NFT、AI智能体、聊天用户）
+//! - v0.4.0: 新增Domain 13-14（宠物档案、宠物媒体）
+//! - v0.5.0: 新增Domain 15（陵园墓位）
+//! - v0.6.0: 新增Domain 16（联盟推荐女巫标记）
 
 /// 域常量定义模块
 pub mod domains {
@@ -33,6 +37,60 @@ pub mod domains {
     /// - 支持精确定位到具体作品（work_id）
     /// - 与逝者档案投诉分离，避免误伤合法作品
     pub const WORKS: u8 = 7;
+
+    /// 🆕 Domain 8: 占卜市场大师域
+    ///
+    /// - target: 市场订单ID（大师以账户为键，通过被投诉订单定位）
+    /// - 动作：1=暂停（下架、不可接单），2=恢复
+    pub const MARKET_PROVIDER: u8 = 8;
+
+    /// 🆕 Domain 9: 占卜市场评价域
+    ///
+    /// - target: 评价对应的订单ID
+    /// - 动作：1=移除评价
+    pub const MARKET_REVIEW: u8 = 9;
+
+    /// 🆕 Domain 10: 占卜NFT域
+    ///
+    /// - target: nft_id
+    /// - 动作：1=下架挂单，2=冻结（下架并锁定），3=解冻
+    pub const DIVINATION_NFT: u8 = 10;
+
+    /// 🆕 Domain 11: AI智能体域（pallet-deceased-ai）
+    ///
+    /// - target: agent_id
+    /// - 动作：1=暂停智能体，2=恢复
+    pub const AI_AGENT: u8 = 11;
+
+    /// 🆕 Domain 12: 聊天用户域
+    ///
+    /// - target: chat_user_id
+    /// - 动作：1=禁言，2=解除禁言
+    pub const CHAT_USER: u8 = 12;
+
+    /// 🆕 Domain 13: 宠物档案域（pallet-stardust-pet）
+    ///
+    /// - target: pet_id
+    /// - 动作：1=隐藏，2=恢复可见，3=清空主图
+    pub const PET: u8 = 13;
+
+    /// 🆕 Domain 14: 宠物媒体域（pallet-stardust-pet）
+    ///
+    /// - target: media_id（照片/视频/音频/文字条目）
+    /// - 动作：1=删除
+    pub const PET_MEDIA: u8 = 14;
+
+    /// 🆕 Domain 15: 陵园墓位域（pallet-stardust-park）
+    ///
+    /// - target: plot_id
+    /// - 动作：1=治理转让持有人（新持有人取自申诉 new_owner），2=收回墓位
+    pub const PARK_PLOT: u8 = 15;
+
+    /// 🆕 Domain 16: 联盟推荐女巫标记域（pallet-affiliate）
+    ///
+    /// - target: flag_id
+    /// - 动作：1=解除标记（发还暂扣收益），2=确认女巫（暂扣收益没收至国库）
+    pub const AFFILIATE_SYBIL: u8 = 16;
 }
 
 /// 函数级中文注释：获取域的人类可读名称
@@ -43,7 +101,7 @@ pub mod domains {
 /// - 错误消息中的域描述
 ///
 /// ## 参数
-/// - `domain`: 域ID（1-7）
+/// - `domain`: 域ID（1-16）
 ///
 /// ## 返回
 /// - `&'static str`: 域名称字符串
@@ -62,6 +120,15 @@ pub fn get_domain_name(domain: u8) -> &'static str {
         domains::OFFERINGS => "Offerings",
         domains::PARK => "Park",
         domains::WORKS => "Works",  // 🆕
+        domains::MARKET_PROVIDER => "MarketProvider",
+        domains::MARKET_REVIEW => "MarketReview",
+        domains::DIVINATION_NFT => "DivinationNft",
+        domains::AI_AGENT => "AiAgent",
+        domains::CHAT_USER => "ChatUser",
+        domains::PET => "Pet",
+        domains::PET_MEDIA => "PetMedia",
+        domains::PARK_PLOT => "ParkPlot",
+        domains::AFFILIATE_SYBIL => "AffiliateSybil",
         _ => "Unknown",
     }
 }
@@ -93,6 +160,15 @@ pub fn is_valid_domain(domain: u8) -> bool {
             | domains::OFFERINGS
             | domains::PARK
             | domains::WORKS  // 🆕
+            | domains::MARKET_PROVIDER
+            | domains::MARKET_REVIEW
+            | domains::DIVINATION_NFT
+            | domains::AI_AGENT
+            | domains::CHAT_USER
+            | domains::PET
+            | domains::PET_MEDIA
+            | domains::PARK_PLOT
+            | domains::AFFILIATE_SYBIL
     )
 }
 
@@ -109,7 +185,7 @@ pub fn is_valid_domain(domain: u8) -> bool {
 /// ## 示例
 /// ```ignore
 /// let all = get_all_domains();
-/// assert_eq!(all.len(), 7);
+/// assert_eq!(all.len(), 16);
 /// assert!(all.contains(&domains::WORKS));
 /// ```
 pub fn get_all_domains() -> alloc::vec::Vec<u8> {
@@ -121,6 +197,15 @@ pub fn get_all_domains() -> alloc::vec::Vec<u8> {
         domains::OFFERINGS,
         domains::PARK,
         domains::WORKS,  // 🆕
+        domains::MARKET_PROVIDER,
+        domains::MARKET_REVIEW,
+        domains::DIVINATION_NFT,
+        domains::AI_AGENT,
+        domains::CHAT_USER,
+        domains::PET,
+        domains::PET_MEDIA,
+        domains::PARK_PLOT,
+        domains::AFFILIATE_SYBIL,
     ]
 }
 
@@ -133,6 +218,8 @@ mod tests {
         assert_eq!(get_domain_name(domains::GRAVE), "Grave");
         assert_eq!(get_domain_name(domains::DECEASED), "Deceased");
         assert_eq!(get_domain_name(domains::WORKS), "Works");
+        assert_eq!(get_domain_name(domains::CHAT_USER), "ChatUser");
+        assert_eq!(get_domain_name(domains::PET), "Pet");
         assert_eq!(get_domain_name(99), "Unknown");
     }
 
@@ -141,6 +228,9 @@ mod tests {
         assert!(is_valid_domain(domains::GRAVE));
         assert!(is_valid_domain(domains::DECEASED));
         assert!(is_valid_domain(domains::WORKS));
+        assert!(is_valid_domain(domains::PET_MEDIA));
+        assert!(is_valid_domain(domains::PARK_PLOT));
+        assert!(is_valid_domain(domains::AFFILIATE_SYBIL));
         assert!(!is_valid_domain(0));
         assert!(!is_valid_domain(99));
     }
@@ -149,7 +239,7 @@ mod tests {
     fn test_all_domains_contains_works() {
         let all_domains = get_all_domains();
         assert!(all_domains.contains(&domains::WORKS));
-        assert_eq!(all_domains.len(), 7);
+        assert_eq!(all_domains.len(), 16);
     }
 
     #[test]
@@ -16,6 +16,9 @@
 //! - **押金管理**: Phase 1优化 - 使用pallet-balances Holds API
 //! - **限频控制**: 防止恶意申诉刷屏
 //! - **应答自动否决**: 对象所有者及时应答可自动否决申诉
+//! - 🆕 **应答期**: 审批前目标所有者可在限期内提交反证，委员会须等待应答或期满
+//! - 🆕 **裁决记录**: 委员会裁决理由 CID 与计票结果上链存档
+//! - 🆕 **升级公投**: 被驳回的申诉可一次性升级为 pallet-referenda 公投，押金结转
 //!
 //! ## 支持的域（Domain）
 //!
@@ -63,6 +66,8 @@ mod tests;
 mod tests_deposit;
 #[cfg(test)]
 mod tests_last_active;
+#[cfg(test)]
+mod tests_escalation;
 pub mod weights;
 
 #[frame_support::pallet]
@@ -251,6 +256,41 @@ pub mod pallet {
         /// - 测试中使用mock实现（默认返回50）
         /// - 返回None时使用默认值50（标准押金1.0x）
         type ReputationProvider: crate::ReputationProvider<AccountId = Self::AccountId>;
+
+        // ========== 🆕 应答期 / 裁决记录 / 升级公投 ==========
+
+        /// 🆕 申诉目标所有者提供者
+        ///
+        /// ## 用途
+        /// - 确定谁有权在应答期内提交反证（counter-evidence）
+        /// - 返回 None 表示无法确定所有者，该申诉不设应答期
+        type TargetOwnerProvider: crate::TargetOwnerProvider<Self::AccountId>;
+
+        /// 🆕 应答期长度（块）
+        ///
+        /// ## 规则
+        /// - 申诉提交后，目标所有者可在此期限内提交一次反证
+        /// - 期限未到且所有者尚未应答时，委员会不得审批或驳回
+        #[pallet::constant]
+        type ResponsePeriod: Get<BlockNumberFor<Self>>;
+
+        /// 🆕 驳回后可申请升级公投的窗口（块）
+        #[pallet::constant]
+        type EscalationWindow: Get<BlockNumberFor<Self>>;
+
+        /// 🆕 升级公投提交器
+        ///
+        /// ## 实现
+        /// - Runtime中对接 `pallet-referenda`（申诉升级专用轨道）
+        /// - 测试中使用mock实现
+        type Escalation: crate::AppealEscalation<Self::AccountId>;
+
+        /// 🆕 委员会计票来源
+        ///
+        /// ## 实现
+        /// - Runtime中读取内容委员会（pallet-collective）对审批/驳回提案的 `Voting` 记录
+        /// - 测试中使用mock实现
+        type DecisionTally: crate::AppealDecisionTally<Call<Self>>;
     }
 
     #[pallet::pallet]
@@ -288,6 +328,44 @@ pub mod pallet {
         pub new_owner: Option<AccountId>,
     }
 
+    /// 函数级详细中文注释：目标所有者应答记录（反证）。
+    /// - responder：提交应答的目标所有者
+    /// - evidence_cid：反证 CID（IPFS）
+    /// - submitted_at：应答提交块高
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+    pub struct AppealResponse<AccountId, BlockNumber> {
+        pub responder: AccountId,
+        pub evidence_cid: BoundedVec<u8, ConstU32<128>>,
+        pub submitted_at: BlockNumber,
+    }
+
+    /// 函数级详细中文注释：委员会裁决记录。
+    /// - approved：true=通过，false=驳回（取自申诉当时的终态）
+    /// - reasoning_cid：委员会裁决理由 CID
+    /// - ayes/nays：委员会投票计票（取自集体提案关闭时的结果）
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+    pub struct DecisionRecord<BlockNumber> {
+        pub approved: bool,
+        pub reasoning_cid: BoundedVec<u8, ConstU32<128>>,
+        pub ayes: u32,
+        pub nays: u32,
+        pub decided_at: BlockNumber,
+    }
+
+    /// 函数级详细中文注释：升级公投信息（每个申诉至多一次）。
+    /// - referendum：公投索引（pallet-referenda）
+    /// - deposit：结转的押金（驳回后未罚没、仍锁定的原申诉押金余额）
+    /// - refundable：驳回时被罚没的金额；公投通过后由国库退回
+    /// - outcome：None=进行中；Some(true)=公投推翻驳回；Some(false)=维持驳回
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+    pub struct EscalationInfo<Balance, BlockNumber> {
+        pub referendum: u32,
+        pub deposit: Balance,
+        pub refundable: Balance,
+        pub escalated_at: BlockNumber,
+        pub outcome: Option<bool>,
+    }
+
     #[pallet::storage]
     pub type NextId<T: Config> = StorageValue<_, u64, ValueQuery>;
 
@@ -531,6 +609,51 @@ pub mod pallet {
     #[pallet::storage]
     pub type GlobalDepositMultiplier<T: Config> = StorageValue<_, u16, ValueQuery, ConstU16<1000>>;
 
+    // ========== 🆕 应答期 / 裁决记录 / 升级公投 存储 ==========
+
+    /// 函数级中文注释：应答截止块：appeal_id → deadline。
+    /// - 仅在能确定目标所有者时写入；未写入的申诉不受应答期约束。
+    #[pallet::storage]
+    pub type ResponseDeadlines<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, BlockNumberFor<T>, OptionQuery>;
+
+    /// 函数级中文注释：目标所有者应答：appeal_id → AppealResponse。
+    #[pallet::storage]
+    pub type Responses<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        AppealResponse<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// 函数级中文注释：委员会裁决记录：appeal_id → DecisionRecord。
+    #[pallet::storage]
+    pub type DecisionRecords<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, DecisionRecord<BlockNumberFor<T>>, OptionQuery>;
+
+    /// 函数级中文注释：委员会计票快照：appeal_id → (ayes, nays)。
+    /// - 委员会提案执行审批/驳回时从集体提案的 `Voting` 记录读取；record_decision 取走写入裁决记录。
+    #[pallet::storage]
+    pub type DecisionTallies<T: Config> = StorageMap<_, Blake2_128Concat, u64, (u32, u32), OptionQuery>;
+
+    /// 函数级中文注释：驳回信息：appeal_id → (驳回块高, 罚没金额)。
+    /// - 用于判断升级窗口与公投通过后的罚没退回。
+    /// - 存在且未升级时，未罚没的押金（deposit_amount - 罚没额）仍在结转锁定中。
+    #[pallet::storage]
+    pub type Rejections<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, (BlockNumberFor<T>, BalanceOf<T>), OptionQuery>;
+
+    /// 函数级中文注释：升级公投：appeal_id → EscalationInfo（存在即表示已用掉唯一一次升级机会）。
+    #[pallet::storage]
+    pub type Escalations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        EscalationInfo<BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -619,6 +742,56 @@ pub mod pallet {
             old_multiplier: u16,
             new_multiplier: u16,
         },
+
+        // ========== 🆕 应答期 / 裁决记录 / 升级公投 事件 ==========
+
+        /// 🆕 应答期已开启（目标所有者可在 deadline 前提交反证）
+        ResponseWindowOpened {
+            id: u64,
+            owner: T::AccountId,
+            deadline: BlockNumberFor<T>,
+        },
+
+        /// 🆕 目标所有者已提交反证
+        ResponseSubmitted {
+            id: u64,
+            responder: T::AccountId,
+            evidence_cid: BoundedVec<u8, ConstU32<128>>,
+        },
+
+        /// 🆕 委员会裁决记录已写入
+        DecisionRecorded {
+            id: u64,
+            approved: bool,
+            ayes: u32,
+            nays: u32,
+        },
+
+        /// 🆕 升级窗口结束，驳回时结转锁定的押金已释放
+        RejectedDepositReleased {
+            id: u64,
+            amount: BalanceOf<T>,
+        },
+
+        /// 🆕 被驳回的申诉已升级为公投
+        AppealEscalated {
+            id: u64,
+            referendum: u32,
+            deposit: BalanceOf<T>,
+        },
+
+        /// 🆕 升级公投已结算
+        ///
+        /// ## 参数
+        /// - upheld: true=公投推翻驳回（申诉转为已批准），false=维持驳回
+        /// - refunded: 国库退回的原驳回罚没金额
+        /// - slashed: 维持驳回时对结转押金的罚没金额
+        EscalationResolved {
+            id: u64,
+            upheld: bool,
+            refunded: BalanceOf<T>,
+            slashed: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -660,6 +833,35 @@ pub mod pallet {
         /// ## 合法范围
         /// - 100-10000（0.1x-10.0x）
         InvalidMultiplier,
+
+        // ========== 🆕 应答期 / 裁决记录 / 升级公投 相关错误 ==========
+
+        /// 🆕 调用者不是申诉目标的所有者
+        NotTargetOwner,
+        /// 🆕 应答期已结束或该申诉不设应答期
+        ResponseWindowClosed,
+        /// 🆕 应答期尚未结束且所有者未应答，暂不可审批/驳回
+        ResponseWindowOpen,
+        /// 🆕 所有者已提交过应答
+        AlreadyResponded,
+        /// 🆕 裁决理由必填
+        ReasoningRequired,
+        /// 🆕 裁决记录已存在
+        DecisionAlreadyRecorded,
+        /// 🆕 该申诉已升级过（每个申诉仅可升级一次）
+        AlreadyEscalated,
+        /// 🆕 升级窗口已过
+        EscalationWindowClosed,
+        /// 🆕 升级窗口未结束（结转押金暂不可释放）
+        EscalationWindowOpen,
+        /// 🆕 无委员会计票（非委员会提案裁决，或计票已记录）
+        DecisionTallyUnavailable,
+        /// 🆕 升级记录不存在
+        EscalationNotFound,
+        /// 🆕 公投尚未结束
+        EscalationPending,
+        /// 🆕 升级公投已结算
+        EscalationFinalized,
     }
 
     impl<T: Config> Pallet<T> {
@@ -712,6 +914,123 @@ pub mod pallet {
             Self::index_by_status(new_status, id);
         }
 
+        // ========== 🆕 应答期 / 升级公投 辅助函数 ==========
+
+        /// 函数级详细中文注释：为新申诉开启应答期。
+        /// - 仅当能确定目标所有者且所有者不是申诉人本人时开启；
+        /// - 截止块 = 提交块 + ResponsePeriod。
+        fn open_response_window(id: u64, domain: u8, target: u64, who: &T::AccountId, now: BlockNumberFor<T>) {
+            if let Some(owner) = T::TargetOwnerProvider::owner_of(domain, target) {
+                if &owner != who {
+                    let deadline = now.saturating_add(T::ResponsePeriod::get());
+                    ResponseDeadlines::<T>::insert(id, deadline);
+                    Self::deposit_event(Event::ResponseWindowOpened { id, owner, deadline });
+                }
+            }
+        }
+
+        /// 函数级详细中文注释：审批/驳回前校验应答期已结束。
+        /// - 未设应答期、所有者已应答、或已到截止块，均视为结束。
+        fn ensure_response_phase_over(id: u64, now: BlockNumberFor<T>) -> DispatchResult {
+            if let Some(deadline) = ResponseDeadlines::<T>::get(id) {
+                ensure!(
+                    Responses::<T>::contains_key(id) || now >= deadline,
+                    Error::<T>::ResponseWindowOpen
+                );
+            }
+            Ok(())
+        }
+
+        /// 函数级详细中文注释：将申诉置为已批准并写入执行队列。
+        /// - 供 approve_appeal 与升级公投通过后复用；
+        /// - 同一主体只能存在一个处于批准状态的申诉；
+        /// - 返回计划执行块高。
+        fn schedule_approval(
+            id: u64,
+            a: &mut Appeal<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+            now: BlockNumberFor<T>,
+            notice_blocks: Option<BlockNumberFor<T>>,
+        ) -> Result<BlockNumberFor<T>, DispatchError> {
+            // 并发串行化：同一主体只能存在一个处于批准状态的申诉
+            ensure!(
+                PendingBySubject::<T>::get((a.domain, a.target)).is_none(),
+                Error::<T>::AlreadyPending
+            );
+            a.status = 1;
+            // Phase 2治理优化：公示期从governance-params动态查询
+            let nb = notice_blocks.unwrap_or_else(|| pallet_governance_params::Pallet::<T>::get_notice_period());
+            let at = now.saturating_add(nb);
+            a.execute_at = Some(at);
+            a.approved_at = Some(now);
+            // 入队：按块维度插入待执行 id（超出容量则返回 QueueFull 错误）
+            let pushed = QueueByBlock::<T>::mutate(at, |mq| {
+                let mut v = mq.take().unwrap_or_default();
+                let res = v.try_push(id).is_ok();
+                *mq = Some(v);
+                res
+            });
+            ensure!(pushed, Error::<T>::QueueFull);
+            // 标记主体占位，初始化重试计数
+            PendingBySubject::<T>::insert((a.domain, a.target), id);
+            RetryCount::<T>::insert(id, 0u8);
+            Ok(at)
+        }
+
+        /// 函数级详细中文注释：按委员会份额罚没被驳回申诉的押金，余额仍保持锁定。
+        /// - 罚没比例从governance-params动态查询（committee_share）；
+        /// - 罚没部分转入国库；返回 (bps, slashed)。
+        fn slash_rejected_deposit(
+            who: &T::AccountId,
+            deposit_amount: BalanceOf<T>,
+        ) -> Result<(u16, BalanceOf<T>), DispatchError> {
+            let bps: u16 = pallet_governance_params::Pallet::<T>::get_committee_share()
+                .try_into()
+                .unwrap_or(3000); // 默认30%，对应万分比3000
+            let mut slashed = BalanceOf::<T>::zero();
+            if bps != 0 {
+                // 计算罚没额（bps = 30% = 3000）
+                let per = sp_runtime::Perbill::from_parts((bps as u32) * 10_000);
+                slashed = per.mul_floor(deposit_amount);
+
+                // Phase 1.5优化：罚没，转移到国库
+                T::Fungible::transfer_on_hold(
+                    &T::RuntimeHoldReason::from(HoldReason::Appeal),
+                    who,
+                    &T::TreasuryAccount::get(),
+                    slashed,
+                    Precision::BestEffort,
+                    Restriction::Free,
+                    Fortitude::Force,
+                )?;
+            }
+            Ok((bps, slashed))
+        }
+
+        /// 函数级详细中文注释：按委员会份额罚没被驳回申诉的押金，余额释放。
+        fn settle_rejected_deposit(
+            who: &T::AccountId,
+            deposit_amount: BalanceOf<T>,
+        ) -> Result<(u16, BalanceOf<T>), DispatchError> {
+            let (bps, slashed) = Self::slash_rejected_deposit(who, deposit_amount)?;
+            let remaining = deposit_amount.saturating_sub(slashed);
+            if !remaining.is_zero() {
+                T::Fungible::release(
+                    &T::RuntimeHoldReason::from(HoldReason::Appeal),
+                    who,
+                    remaining,
+                    Precision::Exact,
+                )?;
+            }
+            Ok((bps, slashed))
+        }
+
+        /// 函数级中文注释：委员会提案执行审批/驳回时快照计票（Root 直接裁决无计票，不写入）。
+        fn snapshot_decision_tally(id: u64, call: Call<T>) {
+            if let Some(tally) = T::DecisionTally::tally_of(call) {
+                DecisionTallies::<T>::insert(id, tally);
+            }
+        }
+
         /// 函数级详细中文注释：限频检查并计数。
         fn touch_window(who: &T::AccountId, now: BlockNumberFor<T>) -> DispatchResult {
             // 先滚动窗口，再进行严格校验，最后自增计数（避免失败时计数被污染）。
@@ -1278,14 +1597,94 @@ pub mod pallet {
                 .unwrap_or_default()
         }
 
+        /// 函数级详细中文注释：🆕 提交携带 new_owner 的转让类申诉（逝者 owner 2/4、墓位持有人 15/1 共用）。
+        /// - 校验证据/理由 CID、限频，按押金策略冻结押金，写入索引并开启应答期。
+        fn do_submit_transfer_appeal(
+            who: T::AccountId,
+            domain: u8,
+            action: u8,
+            target: u64,
+            new_owner: T::AccountId,
+            evidence_cid: BoundedVec<u8, ConstU32<128>>,
+            reason_cid: BoundedVec<u8, ConstU32<128>>,
+        ) -> DispatchResult {
+            let now = <frame_system::Pallet<T>>::block_number();
+            Self::touch_window(&who, now)?;
+            ensure!(!evidence_cid.is_empty(), Error::<T>::EvidenceRequired);
+            ensure!(
+                (evidence_cid.len() as u32) >= T::MinEvidenceCidLen::get(),
+                Error::<T>::EvidenceTooShort
+            );
+            if !reason_cid.is_empty() {
+                ensure!(
+                    (reason_cid.len() as u32) >= T::MinReasonCidLen::get(),
+                    Error::<T>::ReasonTooShort
+                );
+            }
+            let id = NextId::<T>::mutate(|n| {
+                let x = *n;
+                *n = n.saturating_add(1);
+                x
+            });
+            // Phase 2治理优化：动态押金计算，使用governance-params统一管理
+            // - 类型转换：通过u128中转（runtime中两者都是u128）
+            let deposit_amount = T::AppealDepositPolicy::calc_deposit(&who, domain, target, action)
+                .unwrap_or_else(|| {
+                    use sp_runtime::traits::SaturatedConversion;
+                    let governance_deposit = pallet_governance_params::Pallet::<T>::get_appeal_base_deposit();
+                    let deposit_u128: u128 = governance_deposit.saturated_into();
+                    deposit_u128.saturated_into()
+                });
+            
+            // Phase 1.5优化：使用Fungible Holds API锁定押金
+            T::Fungible::hold(
+                &T::RuntimeHoldReason::from(HoldReason::Appeal),
+                &who,
+                deposit_amount,
+            )?;
+            
+            let rec = Appeal {
+                who: who.clone(),
+                domain,
+                target,
+                action,
+                reason_cid,
+                evidence_cid,
+                evidence_id: None,  // Phase 3: 旧方式不使用统一证据ID
+                deposit_amount,     // Phase 1: 存储押金金额用于release/slash
+                status: 0,
+                execute_at: None,
+                approved_at: None,
+                new_owner: Some(new_owner),
+            };
+            Appeals::<T>::insert(id, rec.clone());
+            
+            // Phase 3.4: 维护索引
+            Self::index_by_user(&who, id);
+            Self::index_by_target(domain, target, id);
+            Self::index_by_status(0, id); // status=0(submitted)
+            // 🆕 开启应答期（目标所有者可提交反证）
+            Self::open_response_window(id, domain, target, &who, now);
+            
+            Self::deposit_event(Event::AppealSubmitted(id, who, domain, target, deposit_amount));
+            Ok(())
+        }
+
         /// 函数级详细中文注释：只读-查找“治理转移逝者 owner”所需参数（根据 target 定位占位中的申诉）。
         /// - 输入：target=deceased_id（仅支持 domain=2）
         /// - 行为：读取 PendingBySubject(2,target) → Appeal → 取 new_owner；要求状态=approved(1)、action=4。
         /// - 返回：Some((appeal_id, new_owner)) 或 None。
         pub fn find_owner_transfer_params(target: u64) -> Option<(u64, T::AccountId)> {
-            let id = PendingBySubject::<T>::get((2u8, target))?;
+            Self::find_transfer_params(2u8, 4u8, target)
+        }
+
+        /// 函数级详细中文注释：🆕 只读-按 (domain, action, target) 查找已批准转让申诉的新所有者。
+        /// - 用于 2/4（逝者 owner 转让）与 15/1（墓位持有人转让）等携带 new_owner 的申诉；
+        /// - 返回：Some((appeal_id, new_owner)) 或 None。
+        pub fn find_transfer_params(domain: u8, action: u8, target: u64) -> Option<(u64, T::AccountId)> {
+            let id = PendingBySubject::<T>::get((domain, target))?;
             let a = Appeals::<T>::get(id)?;
-            if a.status == 1 && a.domain == 2u8 && a.action == 4 {
+            if a.status == 1 && a.domain == domain && a.action == action {
                 if let Some(no) = a.new_owner {
                     return Some((id, no));
                 }
@@ -1423,6 +1822,8 @@ pub mod pallet {
             Self::index_by_user(&who, id);
             Self::index_by_target(domain, target, id);
             Self::index_by_status(0, id); // status=0(submitted)
+            // 🆕 开启应答期（目标所有者可提交反证）
+            Self::open_response_window(id, domain, target, &who, now);
             
             Self::deposit_event(Event::AppealSubmitted(id, who, domain, target, deposit_amount));
             Ok(())
@@ -1507,39 +1908,17 @@ pub mod pallet {
         ) -> DispatchResult {
             <T as Config>::GovernanceOrigin::ensure_origin(origin)?;
             let now = <frame_system::Pallet<T>>::block_number();
-            Appeals::<T>::try_mutate(id, |m| -> DispatchResult {
+            // 🆕 应答期：所有者未应答且未到截止块时不得审批
+            Self::ensure_response_phase_over(id, now)?;
+            let at = Appeals::<T>::try_mutate(id, |m| -> Result<BlockNumberFor<T>, DispatchError> {
                 let a = m.as_mut().ok_or(Error::<T>::NotFound)?;
                 ensure!(a.status == 0, Error::<T>::BadStatus);
-                // 并发串行化：同一主体只能存在一个处于批准状态的申诉
-                ensure!(
-                    PendingBySubject::<T>::get((a.domain, a.target)).is_none(),
-                    Error::<T>::AlreadyPending
-                );
-                a.status = 1;
-                // Phase 2治理优化：公示期从governance-params动态查询
-                let nb = notice_blocks.unwrap_or_else(|| pallet_governance_params::Pallet::<T>::get_notice_period());
-                let at = now.saturating_add(nb);
-                a.execute_at = Some(at);
-                a.approved_at = Some(now);
-                // 入队：按块维度插入待执行 id（超出容量则丢弃，后续可返回 QueueFull 错误）
-                let pushed = QueueByBlock::<T>::mutate(at, |mq| {
-                    let mut v = mq.take().unwrap_or_default();
-                    let res = v.try_push(id).is_ok();
-                    *mq = Some(v);
-                    res
-                });
-                ensure!(pushed, Error::<T>::QueueFull);
-                // 标记主体占位，初始化重试计数
-                PendingBySubject::<T>::insert((a.domain, a.target), id);
-                RetryCount::<T>::insert(id, 0u8);
-                Ok(())
+                Self::schedule_approval(id, a, now, notice_blocks)
             })?;
             // Phase 3.4: 维护状态索引（0→1）
             Self::update_status_index(0, 1, id);
-            Self::deposit_event(Event::AppealApproved(
-                id,
-                now.saturating_add(notice_blocks.unwrap_or_else(|| pallet_governance_params::Pallet::<T>::get_notice_period())),
-            ));
+            Self::snapshot_decision_tally(id, Call::<T>::approve_appeal { id, notice_blocks });
+            Self::deposit_event(Event::AppealApproved(id, at));
             Ok(())
         }
 
@@ -1556,67 +1935,32 @@ pub mod pallet {
             reason_cid: BoundedVec<u8, ConstU32<128>>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            let now = <frame_system::Pallet<T>>::block_number();
-            Self::touch_window(&who, now)?;
-            ensure!(!evidence_cid.is_empty(), Error::<T>::EvidenceRequired);
-            ensure!(
-                (evidence_cid.len() as u32) >= T::MinEvidenceCidLen::get(),
-                Error::<T>::EvidenceTooShort
-            );
-            if !reason_cid.is_empty() {
-                ensure!(
-                    (reason_cid.len() as u32) >= T::MinReasonCidLen::get(),
-                    Error::<T>::ReasonTooShort
-                );
-            }
-            let id = NextId::<T>::mutate(|n| {
-                let x = *n;
-                *n = n.saturating_add(1);
-                x
-            });
-            let domain: u8 = 2;
-            let action: u8 = 4;
-            let target = deceased_id;
-            // Phase 2治理优化：动态押金计算，使用governance-params统一管理
-            // - 类型转换：通过u128中转（runtime中两者都是u128）
-            let deposit_amount = T::AppealDepositPolicy::calc_deposit(&who, domain, target, action)
-                .unwrap_or_else(|| {
-                    use sp_runtime::traits::SaturatedConversion;
-                    let governance_deposit = pallet_governance_params::Pallet::<T>::get_appeal_base_deposit();
-                    let deposit_u128: u128 = governance_deposit.saturated_into();
-                    deposit_u128.saturated_into()
-                });
-            
-            // Phase 1.5优化：使用Fungible Holds API锁定押金
-            T::Fungible::hold(
-                &T::RuntimeHoldReason::from(HoldReason::Appeal),
-                &who,
-                deposit_amount,
-            )?;
-            
-            let rec = Appeal {
-                who: who.clone(),
-                domain,
-                target,
-                action,
-                reason_cid,
+            Self::do_submit_transfer_appeal(who, 2u8, 4u8, deceased_id, new_owner, evidence_cid, reason_cid)
+        }
+
+        /// 函数级详细中文注释：🆕 提交“墓位持有人转让”申诉（domain=15, action=1）。
+        /// - 与 `submit_owner_transfer_appeal` 同流程：押金、应答期、索引一致；
+        /// - 批准后由 Router 调用 `pallet-stardust-park::gov_transfer_plot`，新持有人取自本申诉；
+        /// - 收回墓位（action=2）无需 new_owner，走通用 `submit_appeal`。
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::submit_appeal())]
+        pub fn submit_plot_transfer_appeal(
+            origin: OriginFor<T>,
+            plot_id: u64,
+            new_holder: T::AccountId,
+            evidence_cid: BoundedVec<u8, ConstU32<128>>,
+            reason_cid: BoundedVec<u8, ConstU32<128>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_submit_transfer_appeal(
+                who,
+                crate::domains::domains::PARK_PLOT,
+                1u8,
+                plot_id,
+                new_holder,
                 evidence_cid,
-                evidence_id: None,  // Phase 3: 旧方式不使用统一证据ID
-                deposit_amount,     // Phase 1: 存储押金金额用于release/slash
-                status: 0,
-                execute_at: None,
-                approved_at: None,
-                new_owner: Some(new_owner.clone()),
-            };
-            Appeals::<T>::insert(id, rec.clone());
-            
-            // Phase 3.4: 维护索引
-            Self::index_by_user(&who, id);
-            Self::index_by_target(domain, target, id);
-            Self::index_by_status(0, id); // status=0(submitted)
-            
-            Self::deposit_event(Event::AppealSubmitted(id, who, domain, target, deposit_amount));
-            Ok(())
+                reason_cid,
+            )
         }
 
         /// 函数级详细中文注释：使用统一证据ID提交申诉（Phase 3新增）。
@@ -1702,17 +2046,24 @@ pub mod pallet {
             Self::index_by_user(&who, id);
             Self::index_by_target(domain, target, id);
             Self::index_by_status(0, id); // status=0(submitted)
+            // 🆕 开启应答期（目标所有者可提交反证）
+            Self::open_response_window(id, domain, target, &who, now);
             
             Self::deposit_event(Event::AppealSubmitted(id, who.clone(), domain, target, deposit_amount));
             Self::deposit_event(Event::EvidenceLinked(id, evidence_id));
             Ok(())
         }
 
-        /// 函数级详细中文注释：驳回申诉（退押金并按比例罚没至国库）。
+        /// 函数级详细中文注释：驳回申诉（按比例罚没至国库）。
+        /// - 未罚没的押金在升级窗口内继续锁定，升级时直接结转为公投押金；
+        ///   窗口结束仍未升级时由 release_rejected_deposit 释放。
         #[pallet::call_index(3)]
         #[pallet::weight(<T as Config>::WeightInfo::reject_appeal())]
         pub fn reject_appeal(origin: OriginFor<T>, id: u64) -> DispatchResult {
             <T as Config>::GovernanceOrigin::ensure_origin(origin)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            // 🆕 应答期：所有者未应答且未到截止块时不得驳回
+            Self::ensure_response_phase_over(id, now)?;
             let mut bps: u16 = 0;
             let mut slashed = BalanceOf::<T>::zero();
             Appeals::<T>::try_mutate(id, |m| -> DispatchResult {
@@ -1720,50 +2071,12 @@ pub mod pallet {
                 ensure!(a.status == 0, Error::<T>::BadStatus);
                 a.status = 2;
 
-                // Phase 2治理优化：使用Holds API管理押金罚没
-                // - 罚没比例从governance-params动态查询（committee_share）
-                let deposit_amount = a.deposit_amount;
-                bps = pallet_governance_params::Pallet::<T>::get_committee_share()
-                    .try_into()
-                    .unwrap_or(3000); // 默认30%，对应万分比3000
-                
-                if bps != 0 {
-                    // 计算罚没额（bps = 30% = 3000）
-                    let per = sp_runtime::Perbill::from_parts((bps as u32) * 10_000);
-                    slashed = per.mul_floor(deposit_amount);
-                    
-                    // Phase 1.5优化：罚没，转移到国库
-                    T::Fungible::transfer_on_hold(
-                        &T::RuntimeHoldReason::from(HoldReason::Appeal),
-                        &a.who,
-                        &T::TreasuryAccount::get(),
-                        slashed,
-                        Precision::BestEffort,
-                        Restriction::Free,
-                        Fortitude::Force,
-                    )?;
-                    
-                    // 释放剩余押金
-                    let remaining = deposit_amount.saturating_sub(slashed);
-                    if !remaining.is_zero() {
-                        T::Fungible::release(
-                            &T::RuntimeHoldReason::from(HoldReason::Appeal),
-                            &a.who,
-                            remaining,
-                            Precision::Exact,
-                        )?;
-                    }
-                } else {
-                    // 无罚没，全额释放
-                    T::Fungible::release(
-                        &T::RuntimeHoldReason::from(HoldReason::Appeal),
-                        &a.who,
-                        deposit_amount,
-                        Precision::Exact,
-                    )?;
-                }
+                // Phase 2治理优化：使用Holds API管理押金罚没（余额结转，暂不释放）
+                (bps, slashed) = Self::slash_rejected_deposit(&a.who, a.deposit_amount)?;
                 Ok(())
             })?;
+            // 🆕 记录驳回块高与罚没额（供升级公投使用）
+            Rejections::<T>::insert(id, (now, slashed));
             // 释放主体占位与重试信息（若此前已批准后又被驳回的情况）
             if let Some(a) = Appeals::<T>::get(id) {
                 PendingBySubject::<T>::remove((a.domain, a.target));
@@ -1772,6 +2085,7 @@ pub mod pallet {
             }
             RetryCount::<T>::remove(id);
             NextRetryAt::<T>::remove(id);
+            Self::snapshot_decision_tally(id, Call::<T>::reject_appeal { id });
             Self::deposit_event(Event::AppealRejected(id, bps, slashed));
             Ok(())
         }
@@ -1801,8 +2115,21 @@ pub mod pallet {
                 }
                 if let Some(a) = Appeals::<T>::get(id) {
                     if matches!(a.status, 2 | 3 | 4 | 5) {
+                        // 🆕 升级公投未结算、或押金仍在结转锁定中的驳回申诉暂不清理
+                        let escalation = Escalations::<T>::get(id);
+                        if escalation.as_ref().is_some_and(|e| e.outcome.is_none())
+                            || (escalation.is_none() && Rejections::<T>::contains_key(id))
+                        {
+                            continue;
+                        }
                         Appeals::<T>::remove(id);
                         removed = removed.saturating_add(1);
+                        ResponseDeadlines::<T>::remove(id);
+                        Responses::<T>::remove(id);
+                        DecisionRecords::<T>::remove(id);
+                        DecisionTallies::<T>::remove(id);
+                        Rejections::<T>::remove(id);
+                        Escalations::<T>::remove(id);
                         
                         // Phase 3.4: 清理索引
                         // 注意：由于被清理的状态(2/3/4/5)不在AppealsByStatus索引中（仅索引0和1），
@@ -1873,6 +2200,211 @@ pub mod pallet {
             Ok(())
         }
 
+        /// 函数级详细中文注释：目标所有者在应答期内提交反证（🆕）。
+        ///
+        /// ## 规则
+        /// - 仅 TargetOwnerProvider 返回的目标所有者可调用
+        /// - 申诉须处于待审批状态（status=0），且未超过应答截止块
+        /// - 每个申诉仅可应答一次；应答后委员会即可提前审批/驳回
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::submit_response())]
+        pub fn submit_response(
+            origin: OriginFor<T>,
+            id: u64,
+            evidence_cid: BoundedVec<u8, ConstU32<128>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            let a = Appeals::<T>::get(id).ok_or(Error::<T>::NotFound)?;
+            ensure!(a.status == 0, Error::<T>::BadStatus);
+            let deadline = ResponseDeadlines::<T>::get(id).ok_or(Error::<T>::ResponseWindowClosed)?;
+            ensure!(now < deadline, Error::<T>::ResponseWindowClosed);
+            ensure!(
+                T::TargetOwnerProvider::owner_of(a.domain, a.target).as_ref() == Some(&who),
+                Error::<T>::NotTargetOwner
+            );
+            ensure!(!Responses::<T>::contains_key(id), Error::<T>::AlreadyResponded);
+            ensure!(!evidence_cid.is_empty(), Error::<T>::EvidenceRequired);
+            ensure!(
+                (evidence_cid.len() as u32) >= T::MinEvidenceCidLen::get(),
+                Error::<T>::EvidenceTooShort
+            );
+            Responses::<T>::insert(
+                id,
+                AppealResponse { responder: who.clone(), evidence_cid: evidence_cid.clone(), submitted_at: now },
+            );
+            Self::deposit_event(Event::ResponseSubmitted { id, responder: who, evidence_cid });
+            Ok(())
+        }
+
+        /// 函数级详细中文注释：写入委员会裁决记录（🆕）。
+        ///
+        /// ## 规则
+        /// - 仅治理起源可调用（与审批/驳回相同）
+        /// - 申诉须已被裁决：通过类（1/4/5/6）或驳回（2）
+        /// - 每个申诉仅记录一次；approved 取自申诉当前状态
+        /// - ayes/nays 不由调用方提供：取自委员会提案执行审批/驳回时快照的 `Voting` 记录
+        ///   （DecisionTallies）；Root 直接裁决无委员会计票，不可记录
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::record_decision())]
+        pub fn record_decision(
+            origin: OriginFor<T>,
+            id: u64,
+            reasoning_cid: BoundedVec<u8, ConstU32<128>>,
+        ) -> DispatchResult {
+            <T as Config>::GovernanceOrigin::ensure_origin(origin)?;
+            let a = Appeals::<T>::get(id).ok_or(Error::<T>::NotFound)?;
+            let approved = match a.status {
+                1 | 4 | 5 | 6 => true,
+                2 => false,
+                _ => return Err(Error::<T>::BadStatus.into()),
+            };
+            ensure!(!DecisionRecords::<T>::contains_key(id), Error::<T>::DecisionAlreadyRecorded);
+            ensure!(!reasoning_cid.is_empty(), Error::<T>::ReasoningRequired);
+            ensure!(
+                (reasoning_cid.len() as u32) >= T::MinReasonCidLen::get(),
+                Error::<T>::ReasonTooShort
+            );
+            let (ayes, nays) =
+                DecisionTallies::<T>::take(id).ok_or(Error::<T>::DecisionTallyUnavailable)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            DecisionRecords::<T>::insert(
+                id,
+                DecisionRecord { approved, reasoning_cid, ayes, nays, decided_at: now },
+            );
+            Self::deposit_event(Event::DecisionRecorded { id, approved, ayes, nays });
+            Ok(())
+        }
+
+        /// 函数级详细中文注释：将被驳回的申诉升级为公投（🆕，每个申诉仅一次）。
+        ///
+        /// ## 规则
+        /// - 仅原申诉人可调用，且须在驳回后 EscalationWindow 内
+        /// - 押金结转：驳回时未罚没的押金（deposit_amount - 罚没额）在窗口内一直保持锁定，
+        ///   升级时直接作为公投押金，不重新锁定、不按策略重新定价
+        /// - 公投由 T::Escalation 提交（Runtime 对接 pallet-referenda）
+        /// - 公投结束后调用 finalize_escalation 结算（通过时由公投自动调用）
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::escalate_appeal())]
+        pub fn escalate_appeal(origin: OriginFor<T>, id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            let a = Appeals::<T>::get(id).ok_or(Error::<T>::NotFound)?;
+            ensure!(a.who == who, Error::<T>::NoPermission);
+            ensure!(a.status == 2, Error::<T>::BadStatus);
+            ensure!(!Escalations::<T>::contains_key(id), Error::<T>::AlreadyEscalated);
+            let (rejected_at, slashed) = Rejections::<T>::get(id).ok_or(Error::<T>::BadStatus)?;
+            ensure!(
+                now <= rejected_at.saturating_add(T::EscalationWindow::get()),
+                Error::<T>::EscalationWindowClosed
+            );
+
+            // 押金结转：沿用驳回后仍锁定的原申诉押金余额
+            let deposit = a.deposit_amount.saturating_sub(slashed);
+            let referendum = T::Escalation::escalate(id, &who)?;
+            Escalations::<T>::insert(
+                id,
+                EscalationInfo {
+                    referendum,
+                    deposit,
+                    refundable: slashed,
+                    escalated_at: now,
+                    outcome: None,
+                },
+            );
+            Self::deposit_event(Event::AppealEscalated { id, referendum, deposit });
+            Ok(())
+        }
+
+        /// 函数级详细中文注释：结算升级公投（🆕）。
+        ///
+        /// ## 规则
+        /// - 任何签名账户或 Root 可调用；公投通过时由公投执行自动调用
+        /// - 公投通过：释放结转押金，国库退回原驳回罚没额，申诉转为已批准并进入公示执行
+        /// - 公投未通过（否决/超时/取消/终止）：结转押金按委员会份额罚没，余额释放
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::finalize_escalation())]
+        pub fn finalize_escalation(origin: OriginFor<T>, id: u64) -> DispatchResult {
+            frame_system::ensure_signed_or_root(origin)?;
+            let mut info = Escalations::<T>::get(id).ok_or(Error::<T>::EscalationNotFound)?;
+            ensure!(info.outcome.is_none(), Error::<T>::EscalationFinalized);
+            let upheld = T::Escalation::outcome(info.referendum).ok_or(Error::<T>::EscalationPending)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+
+            let mut refunded = BalanceOf::<T>::zero();
+            let mut slashed = BalanceOf::<T>::zero();
+            if upheld {
+                let (who, at) = Appeals::<T>::try_mutate(
+                    id,
+                    |m| -> Result<(T::AccountId, BlockNumberFor<T>), DispatchError> {
+                        let a = m.as_mut().ok_or(Error::<T>::NotFound)?;
+                        ensure!(a.status == 2, Error::<T>::BadStatus);
+                        let at = Self::schedule_approval(id, a, now, None)?;
+                        Ok((a.who.clone(), at))
+                    },
+                )?;
+                T::Fungible::release(
+                    &T::RuntimeHoldReason::from(HoldReason::Appeal),
+                    &who,
+                    info.deposit,
+                    Precision::BestEffort,
+                )?;
+                // 国库退回驳回时的罚没额（国库余额不足时不阻塞结算）
+                if !info.refundable.is_zero()
+                    && T::Fungible::transfer(
+                        &T::TreasuryAccount::get(),
+                        &who,
+                        info.refundable,
+                        frame_support::traits::tokens::Preservation::Preserve,
+                    )
+                    .is_ok()
+                {
+                    refunded = info.refundable;
+                }
+                // Phase 3.4: 维护状态索引（2→1）
+                Self::update_status_index(2, 1, id);
+                Self::deposit_event(Event::AppealApproved(id, at));
+            } else {
+                let a = Appeals::<T>::get(id).ok_or(Error::<T>::NotFound)?;
+                (_, slashed) = Self::settle_rejected_deposit(&a.who, info.deposit)?;
+            }
+
+            info.outcome = Some(upheld);
+            Escalations::<T>::insert(id, info);
+            Self::deposit_event(Event::EscalationResolved { id, upheld, refunded, slashed });
+            Ok(())
+        }
+
+        /// 函数级详细中文注释：释放驳回后结转锁定的押金（🆕）。
+        ///
+        /// ## 规则
+        /// - 任何签名账户可调用；须已过 EscalationWindow 且申诉未升级
+        /// - 释放驳回时未罚没的押金余额（deposit_amount - 罚没额），并移除驳回信息
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::release_rejected_deposit())]
+        pub fn release_rejected_deposit(origin: OriginFor<T>, id: u64) -> DispatchResult {
+            ensure_signed(origin)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            let a = Appeals::<T>::get(id).ok_or(Error::<T>::NotFound)?;
+            ensure!(a.status == 2, Error::<T>::BadStatus);
+            ensure!(!Escalations::<T>::contains_key(id), Error::<T>::AlreadyEscalated);
+            let (rejected_at, slashed) = Rejections::<T>::get(id).ok_or(Error::<T>::BadStatus)?;
+            ensure!(
+                now > rejected_at.saturating_add(T::EscalationWindow::get()),
+                Error::<T>::EscalationWindowOpen
+            );
+
+            let amount = T::Fungible::release(
+                &T::RuntimeHoldReason::from(HoldReason::Appeal),
+                &a.who,
+                a.deposit_amount.saturating_sub(slashed),
+                Precision::BestEffort,
+            )?;
+            Rejections::<T>::remove(id);
+            Self::deposit_event(Event::RejectedDepositReleased { id, amount });
+            Ok(())
+        }
+
         /// 函数级详细中文注释：调整全局押金乘数（治理接口）
         ///
         /// ## 功能
@@ -2100,6 +2632,8 @@ pub mod pallet {
             Self::index_by_user(&complainant, complaint_id);
             Self::index_by_target(crate::domains::domains::WORKS, work_id, complaint_id);
             Self::index_by_status(0, complaint_id);
+            // 🆕 开启应答期（作品所有者可提交反证）
+            Self::open_response_window(complaint_id, crate::domains::domains::WORKS, work_id, &complainant, now);
 
             // 16. 发出事件
             Self::deposit_event(Event::WorkComplaintSubmitted {
@@ -2145,6 +2679,51 @@ pub trait LastActiveProvider {
     fn last_active_of(domain: u8, target: u64) -> Option<Self::BlockNumber>;
 }
 
+/// 函数级详细中文注释：申诉目标所有者提供者抽象（🆕 应答期）。
+/// - 返回 (domain, target) 当前所有者；None 表示未知或不适用，该申诉不设应答期。
+pub trait TargetOwnerProvider<AccountId> {
+    fn owner_of(domain: u8, target: u64) -> Option<AccountId>;
+}
+
+impl<AccountId> TargetOwnerProvider<AccountId> for () {
+    fn owner_of(_domain: u8, _target: u64) -> Option<AccountId> {
+        None
+    }
+}
+
+/// 函数级详细中文注释：申诉升级公投抽象（🆕）。
+/// - escalate：为被驳回的申诉发起公投，返回公投索引；
+/// - outcome：查询公投结果，None=进行中，Some(true)=通过，Some(false)=未通过（否决/超时/取消/终止）。
+/// - Runtime 中对接 pallet-referenda；公投提案应为 `finalize_escalation(appeal_id)`。
+pub trait AppealEscalation<AccountId> {
+    fn escalate(appeal_id: u64, who: &AccountId) -> Result<u32, sp_runtime::DispatchError>;
+    fn outcome(referendum: u32) -> Option<bool>;
+}
+
+impl<AccountId> AppealEscalation<AccountId> for () {
+    fn escalate(_appeal_id: u64, _who: &AccountId) -> Result<u32, sp_runtime::DispatchError> {
+        Err(sp_runtime::DispatchError::Other("EscalationUnsupported"))
+    }
+    fn outcome(_referendum: u32) -> Option<bool> {
+        None
+    }
+}
+
+/// 函数级详细中文注释：委员会裁决计票抽象（🆕）。
+/// - tally_of：`call` 作为委员会集体提案执行时，返回该提案 `Voting` 记录中的 (ayes, nays)；
+///   非委员会提案（如 Root 直接调用）返回 None。
+/// - Runtime 中按 `hash_of(RuntimeCall::from(call))` 读取 pallet-collective 的 `Voting`
+///   （提案执行期间 `Voting` 尚未移除）。
+pub trait AppealDecisionTally<Call> {
+    fn tally_of(call: Call) -> Option<(u32, u32)>;
+}
+
+impl<Call> AppealDecisionTally<Call> for () {
+    fn tally_of(_call: Call) -> Option<(u32, u32)> {
+        None
+    }
+}
+
 /// 函数级详细中文注释：作品信息提供者接口
 ///
 /// ## 设计目的
@@ -128,6 +128,40 @@ impl pallet_deposits::DepositManager<u64, u128> for MockDepositManager {
     }
 }
 
+/// Mock target owner provider：target=777 的所有者为账户 3，其余未知（不设应答期）
+pub struct MockTargetOwner;
+impl crate::TargetOwnerProvider<u64> for MockTargetOwner {
+    fn owner_of(_domain: u8, target: u64) -> Option<u64> {
+        if target == 777 { Some(3) } else { None }
+    }
+}
+
+parameter_types! {
+    /// 升级公投结果（测试中手动设置）
+    pub static EscalationOutcome: Option<bool> = None;
+    /// 委员会计票（测试中手动设置；None 表示 Root 直接裁决）
+    pub static CommitteeTally: Option<(u32, u32)> = None;
+}
+
+/// Mock decision tally：任意审批/驳回调用均返回 CommitteeTally
+pub struct MockDecisionTally;
+impl crate::AppealDecisionTally<crate::Call<Test>> for MockDecisionTally {
+    fn tally_of(_call: crate::Call<Test>) -> Option<(u32, u32)> {
+        CommitteeTally::get()
+    }
+}
+
+/// Mock escalation：固定返回公投索引 0，结果取自 EscalationOutcome
+pub struct MockEscalation;
+impl crate::AppealEscalation<u64> for MockEscalation {
+    fn escalate(_appeal_id: u64, _who: &u64) -> Result<u32, sp_runtime::DispatchError> {
+        Ok(0)
+    }
+    fn outcome(_referendum: u32) -> Option<bool> {
+        EscalationOutcome::get()
+    }
+}
+
 impl pallet_stardust_appeals::pallet::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = pallet_balances::Pallet<Test>;
@@ -149,6 +183,11 @@ impl pallet_stardust_appeals::pallet::Config for Test {
     type MinEvidenceCidLen = frame_support::traits::ConstU32<5>;
     type MinReasonCidLen = frame_support::traits::ConstU32<5>;
     type LastActiveProvider = MockLastActiveProvider;
+    type TargetOwnerProvider = MockTargetOwner;
+    type ResponsePeriod = frame_support::traits::ConstU64<20>;
+    type EscalationWindow = frame_support::traits::ConstU64<50>;
+    type Escalation = MockEscalation;
+    type DecisionTally = MockDecisionTally;
     type WeightInfo = crate::weights::SubstrateWeight<Test>;
 }
 
@@ -0,0 +1,203 @@
+//! 函数级中文注释：单测覆盖应答期（反证）、委员会裁决记录与驳回后的升级公投。
+
+#![cfg(test)]
+
+use crate::{pallet::Error, pallet::Event as Evt, Pallet as MCG};
+use crate::{DecisionRecords, DecisionTallies, Escalations, HoldReason, Rejections, Responses};
+use frame_support::traits::{fungible::InspectHold, ConstU32};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+
+use crate::mock::{
+    new_test_ext, Balances, CommitteeTally, EscalationOutcome, RuntimeEvent, RuntimeOrigin, System,
+    Test,
+};
+
+/// 辅助函数：创建有效的CID
+fn make_cid(prefix: &str) -> BoundedVec<u8, ConstU32<128>> {
+    format!("Qm{}", prefix).as_bytes().to_vec().try_into().unwrap()
+}
+
+/// 辅助函数：账户1 对 target=777（所有者为账户3）提交申诉，返回申诉ID 0
+fn submit_owned_appeal() {
+    assert_ok!(MCG::<Test>::submit_appeal(
+        RuntimeOrigin::signed(1),
+        2,
+        777,
+        10,
+        make_cid("Reason1234"),
+        make_cid("Evidence1234")
+    ));
+}
+
+#[test]
+fn approve_blocked_until_owner_responds() {
+    new_test_ext().execute_with(|| {
+        submit_owned_appeal();
+        // 应答期内且所有者未应答：不可审批/驳回
+        assert_noop!(
+            MCG::<Test>::approve_appeal(RuntimeOrigin::root(), 0, Some(1)),
+            Error::<Test>::ResponseWindowOpen
+        );
+        assert_noop!(
+            MCG::<Test>::reject_appeal(RuntimeOrigin::root(), 0),
+            Error::<Test>::ResponseWindowOpen
+        );
+        // 非所有者不可应答
+        assert_noop!(
+            MCG::<Test>::submit_response(RuntimeOrigin::signed(2), 0, make_cid("Counter1234")),
+            Error::<Test>::NotTargetOwner
+        );
+        // 所有者应答后即可审批
+        assert_ok!(MCG::<Test>::submit_response(RuntimeOrigin::signed(3), 0, make_cid("Counter1234")));
+        assert!(Responses::<Test>::contains_key(0));
+        assert_noop!(
+            MCG::<Test>::submit_response(RuntimeOrigin::signed(3), 0, make_cid("Counter5678")),
+            Error::<Test>::AlreadyResponded
+        );
+        assert_ok!(MCG::<Test>::approve_appeal(RuntimeOrigin::root(), 0, Some(1)));
+    });
+}
+
+#[test]
+fn response_window_expiry_unblocks_decision() {
+    new_test_ext().execute_with(|| {
+        submit_owned_appeal();
+        // 截止块 = 1 + 20
+        System::set_block_number(21);
+        assert_noop!(
+            MCG::<Test>::submit_response(RuntimeOrigin::signed(3), 0, make_cid("Counter1234")),
+            Error::<Test>::ResponseWindowClosed
+        );
+        assert_ok!(MCG::<Test>::reject_appeal(RuntimeOrigin::root(), 0));
+    });
+}
+
+#[test]
+fn decision_record_written_once() {
+    new_test_ext().execute_with(|| {
+        submit_owned_appeal();
+        // 未裁决前不可记录
+        assert_noop!(
+            MCG::<Test>::record_decision(RuntimeOrigin::root(), 0, make_cid("Reasoning1")),
+            Error::<Test>::BadStatus
+        );
+        System::set_block_number(21);
+        // 委员会提案执行驳回：快照 Voting 记录中的计票
+        CommitteeTally::set(Some((3, 1)));
+        assert_ok!(MCG::<Test>::reject_appeal(RuntimeOrigin::root(), 0));
+        assert_eq!(DecisionTallies::<Test>::get(0), Some((3, 1)));
+        assert_ok!(MCG::<Test>::record_decision(RuntimeOrigin::root(), 0, make_cid("Reasoning1")));
+        let rec = DecisionRecords::<Test>::get(0).unwrap();
+        assert!(!rec.approved);
+        assert_eq!((rec.ayes, rec.nays), (3, 1));
+        assert!(!DecisionTallies::<Test>::contains_key(0));
+        assert_noop!(
+            MCG::<Test>::record_decision(RuntimeOrigin::root(), 0, make_cid("Reasoning2")),
+            Error::<Test>::DecisionAlreadyRecorded
+        );
+    });
+}
+
+#[test]
+fn decision_record_requires_committee_tally() {
+    new_test_ext().execute_with(|| {
+        submit_owned_appeal();
+        System::set_block_number(21);
+        // Root 直接驳回：无委员会计票，不可记录
+        assert_ok!(MCG::<Test>::reject_appeal(RuntimeOrigin::root(), 0));
+        assert_noop!(
+            MCG::<Test>::record_decision(RuntimeOrigin::root(), 0, make_cid("Reasoning1")),
+            Error::<Test>::DecisionTallyUnavailable
+        );
+    });
+}
+
+#[test]
+fn escalation_upheld_reapproves_appeal() {
+    new_test_ext().execute_with(|| {
+        submit_owned_appeal();
+        System::set_block_number(21);
+        assert_ok!(MCG::<Test>::reject_appeal(RuntimeOrigin::root(), 0));
+        // 仅申诉人可升级
+        assert_noop!(
+            MCG::<Test>::escalate_appeal(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(MCG::<Test>::escalate_appeal(RuntimeOrigin::signed(1), 0));
+        // 仅可升级一次
+        assert_noop!(
+            MCG::<Test>::escalate_appeal(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::AlreadyEscalated
+        );
+        // 公投未结束
+        assert_noop!(
+            MCG::<Test>::finalize_escalation(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::EscalationPending
+        );
+        EscalationOutcome::set(Some(true));
+        assert_ok!(MCG::<Test>::finalize_escalation(RuntimeOrigin::root(), 0));
+        assert_eq!(MCG::<Test>::appeal_of(0).unwrap().status, 1);
+        assert_eq!(Escalations::<Test>::get(0).unwrap().outcome, Some(true));
+        let ok = System::events().into_iter().any(|e| {
+            matches!(e.event, RuntimeEvent::MCG(Evt::EscalationResolved { id: 0, upheld: true, .. }))
+        });
+        assert!(ok);
+    });
+}
+
+#[test]
+fn escalation_window_closes() {
+    new_test_ext().execute_with(|| {
+        submit_owned_appeal();
+        System::set_block_number(21);
+        assert_ok!(MCG::<Test>::reject_appeal(RuntimeOrigin::root(), 0));
+        // 驳回块 21 + 窗口 50
+        System::set_block_number(72);
+        assert_noop!(
+            MCG::<Test>::escalate_appeal(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::EscalationWindowClosed
+        );
+    });
+}
+
+#[test]
+fn rejected_deposit_carries_over_into_escalation() {
+    new_test_ext().execute_with(|| {
+        submit_owned_appeal();
+        let deposit = MCG::<Test>::appeal_of(0).unwrap().deposit_amount;
+        System::set_block_number(21);
+        assert_ok!(MCG::<Test>::reject_appeal(RuntimeOrigin::root(), 0));
+        let (_, slashed) = Rejections::<Test>::get(0).unwrap();
+        // 驳回后余额仍锁定
+        let held = Balances::balance_on_hold(&HoldReason::Appeal.into(), &1);
+        assert_eq!(held, deposit - slashed);
+
+        // 升级不重新锁定，结转押金即驳回后的余额
+        assert_ok!(MCG::<Test>::escalate_appeal(RuntimeOrigin::signed(1), 0));
+        assert_eq!(Balances::balance_on_hold(&HoldReason::Appeal.into(), &1), held);
+        assert_eq!(Escalations::<Test>::get(0).unwrap().deposit, held);
+        assert_noop!(
+            MCG::<Test>::release_rejected_deposit(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::AlreadyEscalated
+        );
+    });
+}
+
+#[test]
+fn rejected_deposit_released_after_window() {
+    new_test_ext().execute_with(|| {
+        submit_owned_appeal();
+        System::set_block_number(21);
+        assert_ok!(MCG::<Test>::reject_appeal(RuntimeOrigin::root(), 0));
+        // 窗口内不可释放
+        assert_noop!(
+            MCG::<Test>::release_rejected_deposit(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::EscalationWindowOpen
+        );
+        // 驳回块 21 + 窗口 50
+        System::set_block_number(72);
+        assert_ok!(MCG::<Test>::release_rejected_deposit(RuntimeOrigin::signed(2), 0));
+        assert_eq!(Balances::balance_on_hold(&HoldReason::Appeal.into(), &1), 0);
+        assert!(!Rejections::<Test>::contains_key(0));
+    });
+}
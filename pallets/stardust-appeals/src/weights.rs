@@ -10,6 +10,11 @@ pub trait WeightInfo {
     fn approve_appeal() -> Weight;
     fn reject_appeal() -> Weight;
     fn purge_appeals(limit: u32) -> Weight;
+    fn submit_response() -> Weight;
+    fn record_decision() -> Weight;
+    fn escalate_appeal() -> Weight;
+    fn finalize_escalation() -> Weight;
+    fn release_rejected_deposit() -> Weight;
 }
 
 pub struct SubstrateWeight<T>(core::marker::PhantomData<T>);
@@ -42,4 +47,24 @@ impl<T> WeightInfo for SubstrateWeight<T> {
             .saturating_add(w.reads(1))
             .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(limit.into()))
     }
+    fn submit_response() -> Weight {
+        let w = RocksDbWeight::get();
+        Weight::from_parts(15_000, 0).saturating_add(w.reads_writes(3, 1))
+    }
+    fn record_decision() -> Weight {
+        let w = RocksDbWeight::get();
+        Weight::from_parts(15_000, 0).saturating_add(w.reads_writes(2, 1))
+    }
+    fn escalate_appeal() -> Weight {
+        let w = RocksDbWeight::get();
+        Weight::from_parts(50_000, 0).saturating_add(w.reads_writes(5, 4))
+    }
+    fn finalize_escalation() -> Weight {
+        let w = RocksDbWeight::get();
+        Weight::from_parts(40_000, 0).saturating_add(w.reads_writes(6, 6))
+    }
+    fn release_rejected_deposit() -> Weight {
+        let w = RocksDbWeight::get();
+        Weight::from_parts(25_000, 0).saturating_add(w.reads_writes(4, 2))
+    }
 }
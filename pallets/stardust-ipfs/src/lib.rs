@@ -154,6 +154,15 @@ pub trait IpfsPinner<AccountId, Balance> {
         cid: Vec<u8>,
         tier: Option<PinTier>,  // 新增参数
     ) -> DispatchResult;
+
+    /// 函数级中文注释：🆕 为宠物关联的CID发起pin请求
+    /// - 扣费账户为 SubjectFunding(Pet, pet_id)，其余机制与逝者一致
+    fn pin_cid_for_pet(
+        caller: AccountId,
+        pet_id: u64,
+        cid: Vec<u8>,
+        tier: Option<PinTier>,
+    ) -> DispatchResult;
 }
 
 /// 函数级详细中文注释：内容注册接口 - 新pallet域自动PIN机制
@@ -319,6 +328,11 @@ pub mod pallet {
     /// - 与creator分离，creator用于派生地址，owner用于权限检查
     type OwnerProvider: OwnerProvider<Self::AccountId>;
 
+    /// 函数级中文注释：🆕 宠物owner只读提供者（从pallet-stardust-pet读取）
+    /// - 用于宠物Pin的权限检查与宠物存在性检查
+    /// - 宠物资金账户按 (domain=5, pet_id) 派生，不依赖creator
+    type PetOwnerProvider: OwnerProvider<Self::AccountId>;
+
     /// 函数级中文注释：IPFS 池账户（公共费用来源）
     /// 
     /// 说明：
@@ -356,6 +370,9 @@ pub mod pallet {
     /// - 可通过治理调整
     #[pallet::constant]
     type DefaultBillingPeriod: Get<u32>;
+
+    /// 函数级中文注释：🆕 会员权益（Pin 费用折扣，按主题所有者的会员等级）
+    type MembershipBenefits: pallet_membership::MembershipBenefits<Self::AccountId>;
 }
 
     const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
@@ -1101,6 +1118,8 @@ pub mod pallet {
         OperatorDegradationAlert(T::AccountId, u32),
         /// 主题账户已充值（subject_id, from, to, amount）
         SubjectFunded(u64, T::AccountId, T::AccountId, BalanceOf<T>),
+        /// 🆕 宠物主题资金充值（pet_id, 充值人, 宠物资金账户, 金额）
+        PetSubjectFunded(u64, T::AccountId, T::AccountId, BalanceOf<T>),
         /// 函数级中文注释：已完成一次周期扣费（cid_hash, amount, period_blocks, next_charge_at）。
         PinCharged(T::Hash, BalanceOf<T>, u32, BlockNumberFor<T>),
         /// 函数级中文注释：余额不足进入宽限期（cid_hash）。
@@ -2279,10 +2298,221 @@ pub mod pallet {
                 SubjectType::OtcOrder => 3,   // b"otc"
                 SubjectType::Evidence => 4,   // b"evidence"
                 SubjectType::Custom(_) => 99, // 自定义域统一使用99
+                SubjectType::Pet => 5,        // b"pet"
             };
             
             Self::subject_account_for(domain, subject_id)
         }
+
+        /// 函数级详细中文注释：🆕 通用主题Pin流程（逝者/宠物共用）
+        ///
+        /// 调用方负责主题存在性与权限校验，本函数负责：
+        /// - 防重复、分层配置、运营者分层选择
+        /// - CidToSubject 注册（决定 SubjectFunding 扣费账户）与初始扣费（三层回退）
+        /// - 注册 CidRegistry / 域索引 / 健康巡检 / 周期计费
+        ///
+        /// 参数：
+        /// - `owner`: 主题当前所有者（记录到 PinSubjectOf）
+        /// - `subject_type`: 主题类型（Deceased / Pet）
+        /// - `domain`: 域索引名（如 b"deceased" / b"pet"）
+        pub(crate) fn do_request_pin(
+            caller: T::AccountId,
+            owner: T::AccountId,
+            subject_type: SubjectType,
+            subject_id: u64,
+            cid: Vec<u8>,
+            tier: Option<PinTier>,
+            domain: &[u8],
+        ) -> DispatchResult {
+            // 2. 计算CID哈希
+            use sp_runtime::traits::Hash;
+            let cid_hash = T::Hashing::hash(&cid[..]);
+            
+            // 3. 防重复Pin
+            ensure!(!PinMeta::<T>::contains_key(&cid_hash), Error::<T>::AlreadyPinned);
+            
+            // 4. 获取分层配置
+            let tier = tier.unwrap_or(PinTier::Standard);
+            let tier_config = Self::get_tier_config(&tier)?;
+            
+            // 5. 估算CID大小（简化处理，实际可从OCW获取）
+            let size_bytes = cid.len() as u64 * 1024; // 假设平均1KB/字符
+            
+            // 6. 计算初始Pin费用（根据tier的fee_multiplier调整）
+            let base_fee = Self::calculate_initial_pin_fee(size_bytes, tier_config.replicas)?;
+            let adjusted_fee = base_fee.saturating_mul(tier_config.fee_multiplier.into()) / 10000u32.into();
+            // 🆕 会员 Pin 折扣在扣费时按主题当前所有者计算（four_layer_charge），此处仅用于展示实收金额
+            let charged_fee = Self::apply_member_pin_discount(&owner, adjusted_fee);
+            
+            // 7. 执行初始扣费（使用四层回退机制）
+            // 创建临时的BillingTask用于扣费
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let mut temp_task = BillingTask {
+                billing_period: T::DefaultBillingPeriod::get(),
+                amount_per_period: adjusted_fee,
+                last_charge: current_block,
+                grace_status: GraceStatus::Normal,
+                charge_layer: ChargeLayer::IpfsPool,
+            };
+            
+            // 先注册CidToSubject（four_layer_charge需要）
+            let subject_info = SubjectInfo {
+                subject_type: subject_type.clone(),
+                subject_id,
+                funding_share: 100,  // 独占100%费用
+            };
+            let subject_vec = BoundedVec::try_from(vec![subject_info])
+                .map_err(|_| Error::<T>::BadParams)?;
+            CidToSubject::<T>::insert(&cid_hash, subject_vec);
+            
+            // ⭐ 公共IPFS网络简化PIN分配（无隐私约束）
+            // 优先使用简化算法：更快、更高效
+            let simple_nodes = Self::optimized_pin_allocation(cid_hash, tier.clone(), size_bytes)?;
+            
+            // 同时保留完整的Layer 1/Layer 2逻辑（向后兼容）
+            let selection = Self::select_operators_by_layer(subject_type, tier.clone())?;
+            
+            // 合并Layer 1和Layer 2运营者为完整列表
+            let mut all_operators = selection.core_operators.to_vec();
+            all_operators.extend(selection.community_operators.to_vec());
+            
+            // 更新每个运营者的统计信息（分别处理Layer 1和Layer 2）
+            // Layer 1运营者
+            for operator in selection.core_operators.iter() {
+                Self::update_operator_pin_stats(operator, 1, 0)?;
+                Self::check_operator_capacity_warning(operator);
+                
+                let current_pins = Self::count_operator_pins(operator);
+                let capacity_usage_percent = Self::calculate_capacity_usage(operator);
+                
+                Self::deposit_event(Event::PinAssignedToOperator {
+                    operator: operator.clone(),
+                    cid_hash,
+                    current_pins,
+                    capacity_usage_percent,
+                });
+            }
+            
+            // Layer 2运营者
+            for operator in selection.community_operators.iter() {
+                Self::update_operator_pin_stats(operator, 1, 0)?;
+                Self::check_operator_capacity_warning(operator);
+                
+                let current_pins = Self::count_operator_pins(operator);
+                let capacity_usage_percent = Self::calculate_capacity_usage(operator);
+                
+                Self::deposit_event(Event::PinAssignedToOperator {
+                    operator: operator.clone(),
+                    cid_hash,
+                    current_pins,
+                    capacity_usage_percent,
+                });
+            }
+            
+            // ⭐ 记录分层Pin分配（用于审计和追溯）
+            // 注意：使用truncate_from转换不同bound的BoundedVec
+            let core_ops_for_storage = BoundedVec::truncate_from(selection.core_operators.to_vec());
+            let community_ops_for_storage = BoundedVec::truncate_from(selection.community_operators.to_vec());
+            
+            LayeredPinAssignments::<T>::insert(
+                &cid_hash,
+                LayeredPinAssignment {
+                    core_operators: core_ops_for_storage.clone(),
+                    community_operators: community_ops_for_storage.clone(),
+                    external_used: false,  // 暂不支持Layer 3
+                    external_network: None,
+                },
+            );
+            
+            // 发送分层Pin分配完成事件
+            Self::deposit_event(Event::LayeredPinAssigned {
+                cid_hash,
+                core_operators: core_ops_for_storage,
+                community_operators: community_ops_for_storage,
+                external_used: false,
+            });
+            
+            // 注册到PinAssignments（向后兼容）
+            let operators_bounded = BoundedVec::try_from(all_operators)
+                .map_err(|_| Error::<T>::BadParams)?;
+            PinAssignments::<T>::insert(&cid_hash, operators_bounded);
+            
+            // 执行扣费
+            match Self::four_layer_charge(&cid_hash, &mut temp_task) {
+                Ok(ChargeResult::Success { layer }) => {
+                    // 扣费成功
+                },
+                Ok(ChargeResult::EnterGrace { .. }) => {
+                    // 进入宽限期也允许Pin，但发出警告
+                    Self::deposit_event(Event::IpfsPoolLowBalanceWarning {
+                        current: T::Currency::free_balance(&T::IpfsPoolAccount::get()),
+                    });
+                },
+                Err(e) => return Err(e.into()),
+            }
+            
+            // 8. 注册CID到CidRegistry（用于OCW调用IPFS API）
+            let cid_bounded = BoundedVec::try_from(cid.clone())
+                .map_err(|_| Error::<T>::BadParams)?;
+            CidRegistry::<T>::insert(&cid_hash, cid_bounded);
+            
+            // 9. 注册到域索引
+            let domain = BoundedVec::try_from(domain.to_vec())
+                .map_err(|_| Error::<T>::DomainTooLong)?;
+            DomainPins::<T>::insert(&domain, &cid_hash, ());
+            
+            // 9. 记录分层等级
+            CidTier::<T>::insert(&cid_hash, tier.clone());
+            
+            // 10. 注册到健康巡检队列
+            let next_check = current_block + tier_config.health_check_interval.into();
+            let check_task = HealthCheckTask {
+                tier: tier.clone(),
+                last_check: current_block,
+                last_status: HealthStatus::Unknown,  // 初始状态未知
+                consecutive_failures: 0,
+            };
+            HealthCheckQueue::<T>::insert(next_check, &cid_hash, check_task);
+            
+            // 11. 注册到周期扣费队列
+            let period_fee = Self::calculate_period_fee(size_bytes, tier_config.replicas)?;
+            let period_fee_adjusted = period_fee.saturating_mul(tier_config.fee_multiplier.into()) / 10000u32.into();
+            let billing_period = T::DefaultBillingPeriod::get();
+            let next_billing = current_block + billing_period.into();
+            let billing_task = BillingTask {
+                billing_period,
+                amount_per_period: period_fee_adjusted,
+                last_charge: current_block,
+                grace_status: GraceStatus::Normal,
+                charge_layer: ChargeLayer::IpfsPool,
+            };
+            BillingQueue::<T>::insert(next_billing, &cid_hash, billing_task);
+            
+            // 12. 存储Pin元信息
+            let meta = PinMetadata {
+                replicas: tier_config.replicas,
+                size: size_bytes,
+                created_at: current_block,
+                last_activity: current_block,
+            };
+            PinMeta::<T>::insert(&cid_hash, meta);
+            
+            // 13. 保留旧的存储项（兼容OCW）
+            PendingPins::<T>::insert(&cid_hash, (caller.clone(), tier_config.replicas, subject_id, size_bytes, charged_fee));
+            PinStateOf::<T>::insert(&cid_hash, 0u8);  // 0=Pending
+            PinSubjectOf::<T>::insert(&cid_hash, (owner.clone(), subject_id));
+            
+            // 14. 发送事件
+            Self::deposit_event(Event::PinRequested(
+                cid_hash,
+                caller,
+                tier_config.replicas,
+                size_bytes,
+                charged_fee,
+            ));
+            
+            Ok(())
+        }
         
         /// 函数级详细中文注释：三层回退充电机制（IpfsPool优先）
         /// 
@@ -2295,15 +2525,17 @@ pub mod pallet {
         /// - Ok(ChargeResult::Success)：扣费成功，记录使用的层级
         /// - Ok(ChargeResult::EnterGrace)：进入宽限期
         /// - Err(Error::GraceExpired)：宽限期已过
+        ///
+        /// 🆕 `task.amount_per_period` 为折扣前单价；会员折扣按主题当前所有者的会员等级在每期扣费时计算，
+        /// 会员升级、到期或主题转让后的下一期即按新折扣扣费。
         pub fn four_layer_charge(
             cid_hash: &T::Hash,
             task: &mut BillingTask<BlockNumberFor<T>, BalanceOf<T>>,
         ) -> Result<ChargeResult<BlockNumberFor<T>>, Error<T>> {
-            let amount = task.amount_per_period;
-            
             // 获取Subject信息
             let subjects = CidToSubject::<T>::get(cid_hash)
                 .ok_or(Error::<T>::SubjectNotFound)?;
+            let amount = Self::member_discounted_fee(&subjects, task.amount_per_period);
             
             // ===== 第1层：IpfsPoolAccount（系统公共池）=====
             let pool_account = T::IpfsPoolAccount::get();
@@ -2539,6 +2771,40 @@ pub mod pallet {
             Ok(total.saturated_into())
         }
         
+        /// 函数级详细中文注释：🆕 应用会员 Pin 费用折扣
+        ///
+        /// 参数：
+        /// - owner: 主题所有者（按其会员等级取折扣）
+        /// - fee: 折扣前费用
+        ///
+        /// 返回：fee × (100 - 折扣%) / 100
+        pub fn apply_member_pin_discount(owner: &T::AccountId, fee: BalanceOf<T>) -> BalanceOf<T> {
+            use pallet_membership::MembershipBenefits;
+            let discount = T::MembershipBenefits::pin_discount_percent(owner).min(100);
+            if discount == 0 {
+                return fee;
+            }
+            fee.saturating_mul((100 - discount as u32).into()) / 100u32.into()
+        }
+
+        /// 函数级详细中文注释：🆕 按 CID 主题（首个主题）的当前所有者应用会员 Pin 折扣
+        ///
+        /// 所有者取自 OwnerProvider（逝者）/ PetOwnerProvider（宠物）；其他主题类型或所有者不存在时不打折。
+        fn member_discounted_fee(
+            subjects: &[SubjectInfo],
+            fee: BalanceOf<T>,
+        ) -> BalanceOf<T> {
+            let owner = subjects.first().and_then(|s| match s.subject_type {
+                SubjectType::Deceased => T::OwnerProvider::owner_of(s.subject_id),
+                SubjectType::Pet => T::PetOwnerProvider::owner_of(s.subject_id),
+                _ => None,
+            });
+            match owner {
+                Some(owner) => Self::apply_member_pin_discount(&owner, fee),
+                None => fee,
+            }
+        }
+
         /// 函数级详细中文注释：获取治理账户（辅助函数）
         /// 
         /// 返回一个固定的治理账户地址（用于日志记录）
@@ -2592,9 +2858,8 @@ pub mod pallet {
             let who = ensure_signed(origin)?;
             ensure!(amount != BalanceOf::<T>::default(), Error::<T>::BadParams);
             
-            // ✅ 只检查deceased是否存在（通过CreatorProvider）
-            let _creator = T::CreatorProvider::creator_of(subject_id)
-                .ok_or(Error::<T>::BadParams)?;
+            // ✅ 只检查deceased是否存在
+            ensure!(T::OwnerProvider::owner_of(subject_id).is_some(), Error::<T>::BadParams);
             
             // ✅ 派生SubjectFunding地址（使用统一的v2版本）
             let to = Self::derive_subject_funding_account_v2(SubjectType::Deceased, subject_id);
@@ -2657,194 +2922,55 @@ pub mod pallet {
                 .ok_or(Error::<T>::DeceasedNotFound)?;
             ensure!(caller == owner, Error::<T>::NotOwner);
             
-            let creator = T::CreatorProvider::creator_of(subject_id)
-                .ok_or(Error::<T>::DeceasedNotFound)?;
-            
-            // 2. 计算CID哈希
-            use sp_runtime::traits::Hash;
-            let cid_hash = T::Hashing::hash(&cid[..]);
-            
-            // 3. 防重复Pin
-            ensure!(!PinMeta::<T>::contains_key(&cid_hash), Error::<T>::AlreadyPinned);
-            
-            // 4. 获取分层配置
-            let tier = tier.unwrap_or(PinTier::Standard);
-            let tier_config = Self::get_tier_config(&tier)?;
-            
-            // 5. 估算CID大小（简化处理，实际可从OCW获取）
-            let size_bytes = cid.len() as u64 * 1024; // 假设平均1KB/字符
-            
-            // 6. 计算初始Pin费用（根据tier的fee_multiplier调整）
-            let base_fee = Self::calculate_initial_pin_fee(size_bytes, tier_config.replicas)?;
-            let adjusted_fee = base_fee.saturating_mul(tier_config.fee_multiplier.into()) / 10000u32.into();
-            
-            // 7. 执行初始扣费（使用四层回退机制）
-            // 创建临时的BillingTask用于扣费
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            let mut temp_task = BillingTask {
-                billing_period: T::DefaultBillingPeriod::get(),
-                amount_per_period: adjusted_fee,
-                last_charge: current_block,
-                grace_status: GraceStatus::Normal,
-                charge_layer: ChargeLayer::IpfsPool,
-            };
-            
-            // 先注册CidToSubject（four_layer_charge需要）
-            let subject_info = SubjectInfo {
-                subject_type: SubjectType::Deceased,
-                subject_id,
-                funding_share: 100,  // 独占100%费用
-            };
-            let subject_vec = BoundedVec::try_from(vec![subject_info])
-                .map_err(|_| Error::<T>::BadParams)?;
-            CidToSubject::<T>::insert(&cid_hash, subject_vec);
-            
-            // ⭐ 公共IPFS网络简化PIN分配（无隐私约束）
-            // 优先使用简化算法：更快、更高效
-            let simple_nodes = Self::optimized_pin_allocation(cid_hash, tier.clone(), size_bytes)?;
-            
-            // 同时保留完整的Layer 1/Layer 2逻辑（向后兼容）
-            let selection = Self::select_operators_by_layer(SubjectType::Deceased, tier.clone())?;
-            
-            // 合并Layer 1和Layer 2运营者为完整列表
-            let mut all_operators = selection.core_operators.to_vec();
-            all_operators.extend(selection.community_operators.to_vec());
-            
-            // 更新每个运营者的统计信息（分别处理Layer 1和Layer 2）
-            // Layer 1运营者
-            for operator in selection.core_operators.iter() {
-                Self::update_operator_pin_stats(operator, 1, 0)?;
-                Self::check_operator_capacity_warning(operator);
-                
-                let current_pins = Self::count_operator_pins(operator);
-                let capacity_usage_percent = Self::calculate_capacity_usage(operator);
-                
-                Self::deposit_event(Event::PinAssignedToOperator {
-                    operator: operator.clone(),
-                    cid_hash,
-                    current_pins,
-                    capacity_usage_percent,
-                });
-            }
-            
-            // Layer 2运营者
-            for operator in selection.community_operators.iter() {
-                Self::update_operator_pin_stats(operator, 1, 0)?;
-                Self::check_operator_capacity_warning(operator);
-                
-                let current_pins = Self::count_operator_pins(operator);
-                let capacity_usage_percent = Self::calculate_capacity_usage(operator);
-                
-                Self::deposit_event(Event::PinAssignedToOperator {
-                    operator: operator.clone(),
-                    cid_hash,
-                    current_pins,
-                    capacity_usage_percent,
-                });
-            }
-            
-            // ⭐ 记录分层Pin分配（用于审计和追溯）
-            // 注意：使用truncate_from转换不同bound的BoundedVec
-            let core_ops_for_storage = BoundedVec::truncate_from(selection.core_operators.to_vec());
-            let community_ops_for_storage = BoundedVec::truncate_from(selection.community_operators.to_vec());
-            
-            LayeredPinAssignments::<T>::insert(
-                &cid_hash,
-                LayeredPinAssignment {
-                    core_operators: core_ops_for_storage.clone(),
-                    community_operators: community_ops_for_storage.clone(),
-                    external_used: false,  // 暂不支持Layer 3
-                    external_network: None,
-                },
+            // 2. 通用Pin流程（分层选择 + 扣费 + 计费/巡检入队）
+            Self::do_request_pin(caller, owner, SubjectType::Deceased, subject_id, cid, tier, b"deceased")
+        }
+
+        /// 函数级详细中文注释：🆕 为"宠物主题"发起 Pin
+        ///
+        /// - 授权：caller 必须为宠物 owner（PetOwnerProvider）
+        /// - 扣费：IpfsPool → SubjectFunding(Pet, pet_id) → 宽限期，与逝者一致
+        /// - 域索引：b"pet"
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::request_pin())]
+        pub fn request_pin_for_pet(
+            origin: OriginFor<T>,
+            pet_id: u64,
+            cid: Vec<u8>,
+            tier: Option<PinTier>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let owner = T::PetOwnerProvider::owner_of(pet_id)
+                .ok_or(Error::<T>::SubjectNotFound)?;
+            ensure!(caller == owner, Error::<T>::NotOwner);
+            Self::do_request_pin(caller, owner, SubjectType::Pet, pet_id, cid, tier, b"pet")
+        }
+
+        /// 函数级详细中文注释：🆕 为宠物资金账户充值（任何人可充值）
+        ///
+        /// - 资金流向：caller → SubjectFunding(Pet, pet_id)
+        /// - 仅检查宠物存在；资金只用于该宠物内容的 IPFS 存储扣费
+        #[pallet::call_index(29)]
+        #[pallet::weight(10_000)]
+        pub fn fund_pet_subject_account(
+            origin: OriginFor<T>,
+            pet_id: u64,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(amount != BalanceOf::<T>::default(), Error::<T>::BadParams);
+            ensure!(
+                T::PetOwnerProvider::owner_of(pet_id).is_some(),
+                Error::<T>::SubjectNotFound
             );
-            
-            // 发送分层Pin分配完成事件
-            Self::deposit_event(Event::LayeredPinAssigned {
-                cid_hash,
-                core_operators: core_ops_for_storage,
-                community_operators: community_ops_for_storage,
-                external_used: false,
-            });
-            
-            // 注册到PinAssignments（向后兼容）
-            let operators_bounded = BoundedVec::try_from(all_operators)
-                .map_err(|_| Error::<T>::BadParams)?;
-            PinAssignments::<T>::insert(&cid_hash, operators_bounded);
-            
-            // 执行扣费
-            match Self::four_layer_charge(&cid_hash, &mut temp_task) {
-                Ok(ChargeResult::Success { layer }) => {
-                    // 扣费成功
-                },
-                Ok(ChargeResult::EnterGrace { .. }) => {
-                    // 进入宽限期也允许Pin，但发出警告
-                    Self::deposit_event(Event::IpfsPoolLowBalanceWarning {
-                        current: T::Currency::free_balance(&T::IpfsPoolAccount::get()),
-                    });
-                },
-                Err(e) => return Err(e.into()),
-            }
-            
-            // 8. 注册CID到CidRegistry（用于OCW调用IPFS API）
-            let cid_bounded = BoundedVec::try_from(cid.clone())
-                .map_err(|_| Error::<T>::BadParams)?;
-            CidRegistry::<T>::insert(&cid_hash, cid_bounded);
-            
-            // 9. 注册到域索引
-            let domain = BoundedVec::try_from(b"deceased".to_vec())
-                .map_err(|_| Error::<T>::DomainTooLong)?;
-            DomainPins::<T>::insert(&domain, &cid_hash, ());
-            
-            // 9. 记录分层等级
-            CidTier::<T>::insert(&cid_hash, tier.clone());
-            
-            // 10. 注册到健康巡检队列
-            let next_check = current_block + tier_config.health_check_interval.into();
-            let check_task = HealthCheckTask {
-                tier: tier.clone(),
-                last_check: current_block,
-                last_status: HealthStatus::Unknown,  // 初始状态未知
-                consecutive_failures: 0,
-            };
-            HealthCheckQueue::<T>::insert(next_check, &cid_hash, check_task);
-            
-            // 11. 注册到周期扣费队列
-            let period_fee = Self::calculate_period_fee(size_bytes, tier_config.replicas)?;
-            let period_fee_adjusted = period_fee.saturating_mul(tier_config.fee_multiplier.into()) / 10000u32.into();
-            let billing_period = T::DefaultBillingPeriod::get();
-            let next_billing = current_block + billing_period.into();
-            let billing_task = BillingTask {
-                billing_period,
-                amount_per_period: period_fee_adjusted,
-                last_charge: current_block,
-                grace_status: GraceStatus::Normal,
-                charge_layer: ChargeLayer::IpfsPool,
-            };
-            BillingQueue::<T>::insert(next_billing, &cid_hash, billing_task);
-            
-            // 12. 存储Pin元信息
-            let meta = PinMetadata {
-                replicas: tier_config.replicas,
-                size: size_bytes,
-                created_at: current_block,
-                last_activity: current_block,
-            };
-            PinMeta::<T>::insert(&cid_hash, meta);
-            
-            // 13. 保留旧的存储项（兼容OCW）
-            PendingPins::<T>::insert(&cid_hash, (caller.clone(), tier_config.replicas, subject_id, size_bytes, adjusted_fee));
-            PinStateOf::<T>::insert(&cid_hash, 0u8);  // 0=Pending
-            PinSubjectOf::<T>::insert(&cid_hash, (owner.clone(), subject_id));
-            
-            // 14. 发送事件
-            Self::deposit_event(Event::PinRequested(
-                cid_hash,
-                caller,
-                tier_config.replicas,
-                size_bytes,
-                adjusted_fee,
-            ));
-            
+            let to = Self::derive_subject_funding_account_v2(SubjectType::Pet, pet_id);
+            <T as Config>::Currency::transfer(
+                &who,
+                &to,
+                amount,
+                frame_support::traits::ExistenceRequirement::KeepAlive,
+            )?;
+            Self::deposit_event(Event::PetSubjectFunded(pet_id, who, to, amount));
             Ok(())
         }
 
@@ -4933,6 +5059,21 @@ impl<T: Config> IpfsPinner<<T as frame_system::Config>::AccountId, BalanceOf<T>>
             tier,
         )
     }
+
+    /// 函数级中文注释：🆕 为宠物关联的CID发起pin请求（复用 `request_pin_for_pet`）
+    fn pin_cid_for_pet(
+        caller: <T as frame_system::Config>::AccountId,
+        pet_id: u64,
+        cid: Vec<u8>,
+        tier: Option<PinTier>,
+    ) -> DispatchResult {
+        Self::request_pin_for_pet(
+            OriginFor::<T>::from(Some(caller).into()),
+            pet_id,
+            cid,
+            tier,
+        )
+    }
 }
 
 /// 函数级详细中文注释：ContentRegistry trait实现 - 新pallet域自动PIN机制
@@ -5031,6 +5172,7 @@ impl<T: Config> ContentRegistry for Pallet<T> {
                     3 => SubjectType::Offerings,
                     4 => SubjectType::Evidence,
                     5 => SubjectType::OtcOrder,
+                    6 => SubjectType::Pet,
                     _ => SubjectType::Custom(bounded_domain),
                 }
             })
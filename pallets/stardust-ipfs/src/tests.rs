@@ -43,6 +43,7 @@ parameter_types! {
     pub OperatorEscrowPalletId: frame_support::PalletId = frame_support::PalletId(*b"py/opesc");
     pub const MonthlyPublicFeeQuota: Balance = 100_000_000_000_000; // 100 DUST
     pub const QuotaResetPeriod: BlockNumber = 100; // 简化为 100 块用于测试
+    pub static PinDiscount: u8 = 0;
 }
 
 pub struct IpfsPoolAccount;
@@ -126,6 +127,23 @@ impl crate::CreatorProvider<AccountId> for CreatorMap {
     }
 }
 
+/// 函数级中文注释：会员权益 Mock，Pin 折扣取自 PinDiscount（测试中可随时调整）
+pub struct MockBenefits;
+impl pallet_membership::MembershipBenefits<AccountId> for MockBenefits {
+    fn pin_discount_percent(_who: &AccountId) -> u8 {
+        PinDiscount::get()
+    }
+    fn nft_fee_discount_percent(_who: &AccountId) -> u8 {
+        0
+    }
+    fn anti_spam_bonus_percent(_who: &AccountId) -> u16 {
+        0
+    }
+    fn try_use_free_ai_interpretation(_who: &AccountId) -> bool {
+        false
+    }
+}
+
 impl crate::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -141,11 +159,13 @@ impl crate::Config for Test {
     type DeceasedDomain = DeceasedDomain;
     type OwnerProvider = OwnerMap;
     type CreatorProvider = CreatorMap;
+    type PetOwnerProvider = OwnerMap;
     type IpfsPoolAccount = IpfsPoolAccount;
     type OperatorEscrowAccount = OperatorEscrowAccount;
     type MonthlyPublicFeeQuota = MonthlyPublicFeeQuota;
     type QuotaResetPeriod = QuotaResetPeriod;
     type DefaultBillingPeriod = frame_support::traits::ConstU32<100>; // 100块测试周期
+    type MembershipBenefits = MockBenefits;
 }
 
 fn new_test_ext() -> sp_io::TestExternalities {
@@ -774,6 +794,58 @@ fn four_layer_charge_from_ipfs_pool() {
     });
 }
 
+/// 函数级中文注释：会员折扣在每期扣费时按当前会员等级计算，不写入计费任务单价
+#[test]
+fn member_pin_discount_applied_per_period() {
+    use crate::types::{BillingTask, GraceStatus, ChargeLayer, ChargeResult};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let cid_hash = H256::repeat_byte(98);
+        let amount: Balance = 10_000_000_000_000; // 10 DUST
+        let pool = IpfsPoolAccount::get();
+        let _ = <Test as crate::Config>::Currency::deposit_creating(&pool, 1_000_000_000_000_000);
+
+        let subject_info = crate::types::SubjectInfo {
+            subject_type: crate::types::SubjectType::Deceased,
+            subject_id: 1,
+            funding_share: 100,
+        };
+        let subject_vec = frame_support::BoundedVec::try_from(vec![subject_info]).unwrap();
+        crate::CidToSubject::<Test>::insert(&cid_hash, subject_vec);
+        let empty_operators: frame_support::BoundedVec<AccountId, frame_support::traits::ConstU32<16>> = Default::default();
+        crate::PinAssignments::<Test>::insert(&cid_hash, empty_operators);
+
+        let mut task = BillingTask {
+            billing_period: 100,
+            amount_per_period: amount,
+            last_charge: 1,
+            grace_status: GraceStatus::Normal,
+            charge_layer: ChargeLayer::IpfsPool,
+        };
+
+        // 会员期内：5 折
+        PinDiscount::set(50);
+        let before = Balances::free_balance(&pool);
+        assert_ok!(
+            crate::Pallet::<Test>::four_layer_charge(&cid_hash, &mut task),
+            ChargeResult::Success { layer: ChargeLayer::IpfsPool }
+        );
+        assert_eq!(before - Balances::free_balance(&pool), amount / 2);
+        assert_eq!(task.amount_per_period, amount);
+
+        // 会员到期：下一期按原价扣费
+        PinDiscount::set(0);
+        let before = Balances::free_balance(&pool);
+        assert_ok!(
+            crate::Pallet::<Test>::four_layer_charge(&cid_hash, &mut task),
+            ChargeResult::Success { layer: ChargeLayer::IpfsPool }
+        );
+        assert_eq!(before - Balances::free_balance(&pool), amount);
+    });
+}
+
 /// 函数级中文注释：测试14 - 四层回退扣费（IpfsPool不足，回退到SubjectFunding）
 #[test]
 fn four_layer_charge_fallback_to_subject_funding() {
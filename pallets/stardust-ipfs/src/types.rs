@@ -30,6 +30,7 @@ use sp_runtime::RuntimeDebug;
 /// - OtcOrder：OTC订单（聊天记录、文件等）
 /// - Evidence：证据类数据（法律文件、证明材料等）
 /// - Custom：自定义域（预留扩展，如宠物养成游戏等）
+/// - Pet：宠物纪念档案（🆕 与逝者同等计费，独立资金账户）
 #[derive(Clone, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum SubjectType {
     /// 逝者档案（优先级最高）
@@ -44,6 +45,8 @@ pub enum SubjectType {
     Evidence,
     /// 自定义域（预留扩展）
     Custom(BoundedVec<u8, ConstU32<32>>),
+    /// 🆕 宠物纪念档案（追加在末尾，保持既有编码不变）
+    Pet,
 }
 
 impl Default for SubjectType {
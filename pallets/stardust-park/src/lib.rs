@@ -12,12 +12,24 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+// 🆕 分区与墓位库存类型
+pub mod plots;
+pub use plots::*;
+
+// 🆕 Runtime API：陵园地图
+pub mod runtime_api;
+
+// 🆕 分区与墓位接口权重
+pub mod weights;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use alloc::vec::Vec;
     use frame_support::{pallet_prelude::*, BoundedVec};
     use frame_system::pallet_prelude::*; // 函数级中文注释：在 no_std 环境下显式引入 Vec
+    use sp_runtime::traits::{AtLeast32BitUnsigned, Saturating};
+    use crate::weights::WeightInfo;
 
     /// 函数级中文注释：用于校验某个 RuntimeOrigin 是否具备指定陵园的管理员权限。
     /// 设计目的：
@@ -44,6 +56,34 @@ pub mod pallet {
         type ParkAdmin: ParkAdminOrigin<Self::RuntimeOrigin>;
         /// 函数级中文注释：治理起源（Root / 内容治理签名账户等），用于 gov* 接口与证据记录。
         type GovernanceOrigin: frame_support::traits::EnsureOrigin<Self::RuntimeOrigin>;
+
+        // ===== 🆕 墓位库存 =====
+        /// 墓位价格余额类型（与 Runtime::Balance 对齐）
+        type Balance: Parameter + Member + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
+        /// 墓位价款托管（预订时锁定，确认售出释放给陵园所有者，取消/到期退款）
+        type Escrow: pallet_escrow::Escrow<Self::AccountId, Self::Balance>;
+        /// 安葬对象（逝者/宠物）存在性与安置权限
+        type Occupants: PlotOccupantAccess<Self::AccountId>;
+        /// 每个陵园的运营账户上限
+        #[pallet::constant]
+        type MaxParkOperators: Get<u32>;
+        /// 每个陵园的分区上限
+        #[pallet::constant]
+        type MaxZonesPerPark: Get<u32>;
+        /// 每个分区的墓位上限
+        #[pallet::constant]
+        type MaxPlotsPerZone: Get<u32>;
+        /// 单次批量上架墓位数上限
+        #[pallet::constant]
+        type MaxPlotsPerBatch: Get<u32>;
+        /// 每个墓位可安置对象上限（合葬/家族墓）
+        #[pallet::constant]
+        type MaxOccupantsPerPlot: Get<u32>;
+        /// 预订有效期（区块数），到期未确认任何人可触发退款
+        #[pallet::constant]
+        type ReservationPeriod: Get<BlockNumberFor<Self>>;
+        /// 🆕 分区与墓位接口权重
+        type WeightInfo: WeightInfo;
     }
 
     /// 函数级中文注释：陵园登记信息结构。
@@ -62,6 +102,39 @@ pub mod pallet {
         pub active: bool,
     }
 
+    /// 函数级中文注释：陵园分区
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ParkZone<T: Config> {
+        pub name: BoundedVec<u8, T::MaxRegionLen>,
+        pub kind: ZoneKind,
+        pub metadata_cid: BoundedVec<u8, T::MaxCidLen>,
+        pub active: bool,
+    }
+
+    /// 函数级中文注释：墓位状态类型别名
+    pub type PlotStatusOf<T> = PlotStatus<
+        <T as frame_system::Config>::AccountId,
+        <T as Config>::Balance,
+        BlockNumberFor<T>,
+    >;
+
+    /// 函数级中文注释：墓位
+    /// - (park_id, zone_id, row, number) 唯一；tier 对应陵园价格档位；
+    /// - holder 仅在售出后存在；occupants 为已安置的逝者/宠物。
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Plot<T: Config> {
+        pub park_id: u64,
+        pub zone_id: u32,
+        pub row: u16,
+        pub number: u16,
+        pub tier: u8,
+        pub status: PlotStatusOf<T>,
+        pub holder: Option<T::AccountId>,
+        pub occupants: BoundedVec<PlotOccupant, T::MaxOccupantsPerPlot>,
+    }
+
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
@@ -80,6 +153,55 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    // ===== 🆕 陵园运营与墓位库存 =====
+
+    /// 函数级中文注释：陵园运营账户（由所有者任命，可管理分区/定价/墓位）
+    #[pallet::storage]
+    pub type ParkOperators<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, BoundedVec<T::AccountId, T::MaxParkOperators>, ValueQuery>;
+
+    /// 函数级中文注释：下一个分区ID（按陵园）
+    #[pallet::storage]
+    pub type NextZoneId<T: Config> = StorageMap<_, Blake2_128Concat, u64, u32, ValueQuery>;
+
+    /// 函数级中文注释：分区（park_id, zone_id → ParkZone）
+    #[pallet::storage]
+    pub type ParkZones<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, u64, Twox64Concat, u32, ParkZone<T>, OptionQuery>;
+
+    /// 函数级中文注释：价格档位（park_id, tier → 价格）
+    #[pallet::storage]
+    pub type PriceTiers<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, u64, Twox64Concat, u8, T::Balance, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextPlotId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// 函数级中文注释：墓位
+    #[pallet::storage]
+    pub type Plots<T: Config> = StorageMap<_, Blake2_128Concat, u64, Plot<T>, OptionQuery>;
+
+    /// 函数级中文注释：墓位坐标唯一索引（park_id, zone_id, row, number → plot_id）
+    #[pallet::storage]
+    pub type PlotAt<T: Config> =
+        StorageMap<_, Blake2_128Concat, (u64, u32, u16, u16), u64, OptionQuery>;
+
+    /// 函数级中文注释：分区墓位索引（park_id, zone_id → plot_ids）
+    #[pallet::storage]
+    pub type PlotsByZone<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        Twox64Concat,
+        u32,
+        BoundedVec<u64, T::MaxPlotsPerZone>,
+        ValueQuery,
+    >;
+
+    /// 函数级中文注释：安葬对象所在墓位（一个逝者/宠物同时只安置于一个墓位）
+    #[pallet::storage]
+    pub type PlotOf<T: Config> = StorageMap<_, Blake2_128Concat, PlotOccupant, u64, OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -114,6 +236,30 @@ pub mod pallet {
         GovEvidenceNoted(u8, u64, BoundedVec<u8, T::MaxCidLen>),
         /// 函数级中文注释：治理设置园区封面（Some 设置；None 清空）。
         GovParkCoverSet(u64, bool),
+        /// 🆕 运营账户已更新
+        OperatorsSet { id: u64, count: u32 },
+        /// 🆕 分区新增/更新
+        ZoneAdded { park_id: u64, zone_id: u32, kind: ZoneKind },
+        ZoneUpdated { park_id: u64, zone_id: u32 },
+        /// 🆕 价格档位设置/移除
+        PriceTierSet { park_id: u64, tier: u8, price: Option<T::Balance> },
+        /// 🆕 批量上架墓位（plot_id 区间 [first_plot_id, first_plot_id + count)）
+        PlotsAdded { park_id: u64, zone_id: u32, row: u16, first_plot_id: u64, count: u32 },
+        /// 🆕 墓位停售/恢复可售
+        PlotEnabledSet { plot_id: u64, enabled: bool },
+        /// 🆕 墓位预订（价款已托管）
+        PlotReserved { plot_id: u64, buyer: T::AccountId, price: T::Balance, expires_at: BlockNumberFor<T> },
+        /// 🆕 墓位售出（托管释放给陵园所有者）
+        PlotSold { plot_id: u64, holder: T::AccountId, price: T::Balance },
+        /// 🆕 预订取消/到期（托管退款）
+        PlotReservationCancelled { plot_id: u64, buyer: T::AccountId },
+        /// 🆕 安置/迁出
+        PlotAssigned { plot_id: u64, occupant: PlotOccupant },
+        PlotVacated { plot_id: u64, occupant: PlotOccupant },
+        /// 🆕 墓位持有人变更
+        PlotTransferred { plot_id: u64, from: T::AccountId, to: T::AccountId },
+        /// 🆕 治理收回墓位（恢复可售，清空持有人与安置）
+        PlotReclaimed { plot_id: u64 },
     }
 
     #[pallet::error]
@@ -123,6 +269,37 @@ pub mod pallet {
         NotFound,
         BadCountry,
         TooMany,
+        /// 🆕 分区不存在或已停用
+        ZoneNotFound,
+        ZoneInactive,
+        /// 🆕 墓位不存在
+        PlotNotFound,
+        /// 🆕 墓位坐标已存在
+        PlotExists,
+        /// 🆕 墓位状态不允许该操作
+        BadPlotStatus,
+        /// 🆕 档位未定价
+        TierNotPriced,
+        /// 🆕 陵园已停用
+        ParkInactive,
+        /// 🆕 非墓位持有人
+        NotHolder,
+        /// 🆕 非预订人
+        NotBuyer,
+        /// 🆕 预订尚未到期
+        ReservationNotExpired,
+        /// 🆕 分区类型不接受该安葬对象
+        ZoneMismatch,
+        /// 🆕 安葬对象不存在或无权安置
+        OccupantNotAllowed,
+        /// 🆕 安葬对象已安置于其他墓位
+        AlreadyPlaced,
+        /// 🆕 安葬对象不在该墓位
+        NotPlaced,
+        /// 🆕 分区内存在已预订/已售墓位，不可变更分区类型
+        ZoneInUse,
+        /// 🆕 批量数量非法
+        BadBatch,
     }
 
     // 说明：临时允许 warnings 以通过全局 -D warnings；后续将以 WeightInfo 基准权重替换常量权重
@@ -148,6 +325,88 @@ pub mod pallet {
                 .map(|_| ())
                 .map_err(|_| Error::<T>::NotAdmin.into())
         }
+
+        /// 函数级中文注释：🆕 陵园运营权限校验（所有者 / 运营账户 / ParkAdmin 起源）
+        fn ensure_operator(park_id: u64, origin: OriginFor<T>) -> Result<Park<T>, DispatchError> {
+            let who = ensure_signed(origin.clone())?;
+            let park = Parks::<T>::get(park_id).ok_or(Error::<T>::NotFound)?;
+            if who != park.owner && !ParkOperators::<T>::get(park_id).contains(&who) {
+                T::ParkAdmin::ensure(park_id, origin)?;
+            }
+            Ok(park)
+        }
+
+        /// 函数级中文注释：🆕 墓位托管ID
+        pub fn plot_escrow_id(plot_id: u64) -> u64 {
+            PLOT_ESCROW_NAMESPACE | plot_id
+        }
+
+        /// 函数级中文注释：🆕 安葬对象所在陵园（供 ledger 按陵园排行等只读查询）
+        pub fn park_of_occupant(occupant: &PlotOccupant) -> Option<u64> {
+            PlotOf::<T>::get(occupant)
+                .and_then(Plots::<T>::get)
+                .map(|plot| plot.park_id)
+        }
+
+        /// 函数级中文注释：🆕 变更墓位持有人（持有人转让与治理转让共用）
+        fn do_transfer_plot(plot_id: u64, to: T::AccountId) -> DispatchResult {
+            let from = Plots::<T>::try_mutate(plot_id, |maybe| -> Result<T::AccountId, DispatchError> {
+                let plot = maybe.as_mut().ok_or(Error::<T>::PlotNotFound)?;
+                ensure!(plot.status == PlotStatus::Sold, Error::<T>::BadPlotStatus);
+                let from = plot.holder.replace(to.clone()).ok_or(Error::<T>::BadPlotStatus)?;
+                Ok(from)
+            })?;
+            Self::deposit_event(Event::PlotTransferred { plot_id, from, to });
+            Ok(())
+        }
+
+        /// 函数级中文注释：🆕 分区视图（Runtime API）
+        /// - 仅供 Runtime API 只读查询，不在交易中调用；读取量上限为 MaxZonesPerPark × MaxPlotsPerZone
+        pub fn park_zones(park_id: u64) -> Vec<ZoneView> {
+            ParkZones::<T>::iter_prefix(park_id)
+                .map(|(zone_id, zone)| {
+                    let plot_ids = PlotsByZone::<T>::get(park_id, zone_id);
+                    let available = plot_ids
+                        .iter()
+                        .filter_map(|id| Plots::<T>::get(id))
+                        .filter(|p| p.status == PlotStatus::Available)
+                        .count() as u32;
+                    ZoneView {
+                        zone_id,
+                        name: zone.name.into_inner(),
+                        kind: zone.kind,
+                        metadata_cid: zone.metadata_cid.into_inner(),
+                        active: zone.active,
+                        plot_count: plot_ids.len() as u32,
+                        available,
+                    }
+                })
+                .collect()
+        }
+
+        /// 函数级中文注释：🆕 分区墓位视图（Runtime API，按上架顺序）
+        pub fn zone_plots(park_id: u64, zone_id: u32) -> Vec<PlotView<T::AccountId, T::Balance>> {
+            PlotsByZone::<T>::get(park_id, zone_id)
+                .into_iter()
+                .filter_map(Self::plot_view)
+                .collect()
+        }
+
+        /// 函数级中文注释：🆕 单个墓位视图
+        pub fn plot_view(plot_id: u64) -> Option<PlotView<T::AccountId, T::Balance>> {
+            let plot = Plots::<T>::get(plot_id)?;
+            Some(PlotView {
+                plot_id,
+                zone_id: plot.zone_id,
+                row: plot.row,
+                number: plot.number,
+                tier: plot.tier,
+                price: PriceTiers::<T>::get(plot.park_id, plot.tier),
+                status: plot.status.code(),
+                holder: plot.holder,
+                occupants: plot.occupants.into_inner(),
+            })
+        }
     }
 
     #[allow(warnings)]
@@ -366,5 +625,336 @@ pub mod pallet {
             Self::deposit_event(Event::ParkTransferred { id, new_owner });
             Ok(())
         }
+
+        // ===== 🆕 陵园运营（分区 / 定价 / 墓位库存） =====
+
+        /// 函数级中文注释：设置陵园运营账户（整体覆盖）。
+        /// - 仅所有者或 ParkAdmin 起源；运营账户可管理分区、定价、墓位与确认售出。
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::set_park_operators(operators.len() as u32))]
+        pub fn set_park_operators(
+            origin: OriginFor<T>,
+            id: u64,
+            operators: BoundedVec<T::AccountId, T::MaxParkOperators>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin.clone())?;
+            let park = Parks::<T>::get(id).ok_or(Error::<T>::NotFound)?;
+            if who != park.owner {
+                T::ParkAdmin::ensure(id, origin)?;
+            }
+            let count = operators.len() as u32;
+            ParkOperators::<T>::insert(id, operators);
+            Self::deposit_event(Event::OperatorsSet { id, count });
+            Ok(())
+        }
+
+        /// 函数级中文注释：新增分区（运营权限）。
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::add_zone())]
+        pub fn add_zone(
+            origin: OriginFor<T>,
+            park_id: u64,
+            name: BoundedVec<u8, T::MaxRegionLen>,
+            kind: ZoneKind,
+            metadata_cid: BoundedVec<u8, T::MaxCidLen>,
+        ) -> DispatchResult {
+            Self::ensure_operator(park_id, origin)?;
+            let zone_id = NextZoneId::<T>::get(park_id);
+            ensure!(zone_id < T::MaxZonesPerPark::get(), Error::<T>::TooMany);
+            NextZoneId::<T>::insert(park_id, zone_id.saturating_add(1));
+            ParkZones::<T>::insert(
+                park_id,
+                zone_id,
+                ParkZone::<T> { name, kind, metadata_cid, active: true },
+            );
+            Self::deposit_event(Event::ZoneAdded { park_id, zone_id, kind });
+            Ok(())
+        }
+
+        /// 函数级中文注释：更新分区（运营权限，可选字段）。
+        /// - 分区类型仅在区内无已预订/已售墓位时可变更，避免已安置对象与分区规划冲突；
+        /// - 变更类型时逐个读取区内墓位，权重按 MaxPlotsPerZone 计。
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::update_zone(T::MaxPlotsPerZone::get()))]
+        pub fn update_zone(
+            origin: OriginFor<T>,
+            park_id: u64,
+            zone_id: u32,
+            name: Option<BoundedVec<u8, T::MaxRegionLen>>,
+            kind: Option<ZoneKind>,
+            metadata_cid: Option<BoundedVec<u8, T::MaxCidLen>>,
+            active: Option<bool>,
+        ) -> DispatchResult {
+            Self::ensure_operator(park_id, origin)?;
+            ParkZones::<T>::try_mutate(park_id, zone_id, |maybe| -> DispatchResult {
+                let zone = maybe.as_mut().ok_or(Error::<T>::ZoneNotFound)?;
+                if let Some(k) = kind {
+                    if k != zone.kind {
+                        let in_use = PlotsByZone::<T>::get(park_id, zone_id)
+                            .iter()
+                            .filter_map(|id| Plots::<T>::get(id))
+                            .any(|p| matches!(p.status, PlotStatus::Reserved { .. } | PlotStatus::Sold));
+                        ensure!(!in_use, Error::<T>::ZoneInUse);
+                        zone.kind = k;
+                    }
+                }
+                if let Some(n) = name {
+                    zone.name = n;
+                }
+                if let Some(cid) = metadata_cid {
+                    zone.metadata_cid = cid;
+                }
+                if let Some(a) = active {
+                    zone.active = a;
+                }
+                Ok(())
+            })?;
+            Self::deposit_event(Event::ZoneUpdated { park_id, zone_id });
+            Ok(())
+        }
+
+        /// 函数级中文注释：设置/移除价格档位（运营权限）。
+        /// - 调价仅影响之后的预订；已预订墓位按预订时价格结算。
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_price_tier())]
+        pub fn set_price_tier(
+            origin: OriginFor<T>,
+            park_id: u64,
+            tier: u8,
+            price: Option<T::Balance>,
+        ) -> DispatchResult {
+            Self::ensure_operator(park_id, origin)?;
+            PriceTiers::<T>::set(park_id, tier, price);
+            Self::deposit_event(Event::PriceTierSet { park_id, tier, price });
+            Ok(())
+        }
+
+        /// 函数级中文注释：批量上架墓位（运营权限）。
+        /// - 在分区第 `row` 排上架 `first_number .. first_number + count` 号墓位，档位 `tier`；
+        /// - 坐标重复则整体失败。
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::add_plots(*count))]
+        pub fn add_plots(
+            origin: OriginFor<T>,
+            park_id: u64,
+            zone_id: u32,
+            row: u16,
+            first_number: u16,
+            count: u32,
+            tier: u8,
+        ) -> DispatchResult {
+            Self::ensure_operator(park_id, origin)?;
+            ensure!(count > 0 && count <= T::MaxPlotsPerBatch::get(), Error::<T>::BadBatch);
+            ensure!(
+                (first_number as u32).saturating_add(count) <= (u16::MAX as u32) + 1,
+                Error::<T>::BadBatch
+            );
+            ensure!(ParkZones::<T>::contains_key(park_id, zone_id), Error::<T>::ZoneNotFound);
+            let first_plot_id = NextPlotId::<T>::get();
+            PlotsByZone::<T>::try_mutate(park_id, zone_id, |ids| -> DispatchResult {
+                for i in 0..count {
+                    let number = first_number.saturating_add(i as u16);
+                    let key = (park_id, zone_id, row, number);
+                    ensure!(!PlotAt::<T>::contains_key(key), Error::<T>::PlotExists);
+                    let plot_id = first_plot_id.saturating_add(i as u64);
+                    ids.try_push(plot_id).map_err(|_| Error::<T>::TooMany)?;
+                    PlotAt::<T>::insert(key, plot_id);
+                    Plots::<T>::insert(
+                        plot_id,
+                        Plot::<T> {
+                            park_id,
+                            zone_id,
+                            row,
+                            number,
+                            tier,
+                            status: PlotStatus::Available,
+                            holder: None,
+                            occupants: BoundedVec::default(),
+                        },
+                    );
+                }
+                Ok(())
+            })?;
+            NextPlotId::<T>::put(first_plot_id.saturating_add(count as u64));
+            Self::deposit_event(Event::PlotsAdded { park_id, zone_id, row, first_plot_id, count });
+            Ok(())
+        }
+
+        /// 函数级中文注释：墓位停售/恢复可售（运营权限，仅可售 ↔ 停售）。
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_plot_enabled())]
+        pub fn set_plot_enabled(origin: OriginFor<T>, plot_id: u64, enabled: bool) -> DispatchResult {
+            let plot = Plots::<T>::get(plot_id).ok_or(Error::<T>::PlotNotFound)?;
+            Self::ensure_operator(plot.park_id, origin)?;
+            Plots::<T>::try_mutate(plot_id, |maybe| -> DispatchResult {
+                let plot = maybe.as_mut().ok_or(Error::<T>::PlotNotFound)?;
+                plot.status = match (&plot.status, enabled) {
+                    (PlotStatus::Available | PlotStatus::Disabled, true) => PlotStatus::Available,
+                    (PlotStatus::Available | PlotStatus::Disabled, false) => PlotStatus::Disabled,
+                    _ => return Err(Error::<T>::BadPlotStatus.into()),
+                };
+                Ok(())
+            })?;
+            Self::deposit_event(Event::PlotEnabledSet { plot_id, enabled });
+            Ok(())
+        }
+
+        /// 函数级中文注释：预订墓位（任何人）。
+        /// - 要求陵园与分区启用、墓位可售且档位已定价；
+        /// - 档位价格经 `pallet-escrow` 托管（托管ID = `PLOT_ESCROW_NAMESPACE | plot_id`）；
+        /// - `ReservationPeriod` 内由运营方确认售出，否则可取消/到期退款。
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::reserve_plot())]
+        pub fn reserve_plot(origin: OriginFor<T>, plot_id: u64) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
+            let mut plot = Plots::<T>::get(plot_id).ok_or(Error::<T>::PlotNotFound)?;
+            ensure!(plot.status == PlotStatus::Available, Error::<T>::BadPlotStatus);
+            let park = Parks::<T>::get(plot.park_id).ok_or(Error::<T>::NotFound)?;
+            ensure!(park.active, Error::<T>::ParkInactive);
+            let zone = ParkZones::<T>::get(plot.park_id, plot.zone_id).ok_or(Error::<T>::ZoneNotFound)?;
+            ensure!(zone.active, Error::<T>::ZoneInactive);
+            let price = PriceTiers::<T>::get(plot.park_id, plot.tier).ok_or(Error::<T>::TierNotPriced)?;
+
+            T::Escrow::lock_from(&buyer, Self::plot_escrow_id(plot_id), price)?;
+            let expires_at = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(T::ReservationPeriod::get());
+            plot.status = PlotStatus::Reserved { buyer: buyer.clone(), price, expires_at };
+            Plots::<T>::insert(plot_id, plot);
+            Self::deposit_event(Event::PlotReserved { plot_id, buyer, price, expires_at });
+            Ok(())
+        }
+
+        /// 函数级中文注释：确认售出（运营权限）。
+        /// - 托管价款全额释放给陵园所有者，预订人成为持有人。
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::confirm_plot_sale())]
+        pub fn confirm_plot_sale(origin: OriginFor<T>, plot_id: u64) -> DispatchResult {
+            let mut plot = Plots::<T>::get(plot_id).ok_or(Error::<T>::PlotNotFound)?;
+            let park = Self::ensure_operator(plot.park_id, origin)?;
+            let (buyer, price) = match &plot.status {
+                PlotStatus::Reserved { buyer, price, .. } => (buyer.clone(), *price),
+                _ => return Err(Error::<T>::BadPlotStatus.into()),
+            };
+            T::Escrow::release_all(Self::plot_escrow_id(plot_id), &park.owner)?;
+            plot.status = PlotStatus::Sold;
+            plot.holder = Some(buyer.clone());
+            Plots::<T>::insert(plot_id, plot);
+            Self::deposit_event(Event::PlotSold { plot_id, holder: buyer, price });
+            Ok(())
+        }
+
+        /// 函数级中文注释：取消预订并退款。
+        /// - 预订人或运营方随时可取消；到期后任何人可触发；
+        /// - 托管价款全额退回预订人，墓位恢复可售。
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::cancel_plot_reservation())]
+        pub fn cancel_plot_reservation(origin: OriginFor<T>, plot_id: u64) -> DispatchResult {
+            let who = ensure_signed(origin.clone())?;
+            let mut plot = Plots::<T>::get(plot_id).ok_or(Error::<T>::PlotNotFound)?;
+            let (buyer, expires_at) = match &plot.status {
+                PlotStatus::Reserved { buyer, expires_at, .. } => (buyer.clone(), *expires_at),
+                _ => return Err(Error::<T>::BadPlotStatus.into()),
+            };
+            let expired = <frame_system::Pallet<T>>::block_number() >= expires_at;
+            if who != buyer && !expired {
+                Self::ensure_operator(plot.park_id, origin).map_err(|_| Error::<T>::ReservationNotExpired)?;
+            }
+            T::Escrow::refund_all(Self::plot_escrow_id(plot_id), &buyer)?;
+            plot.status = PlotStatus::Available;
+            Plots::<T>::insert(plot_id, plot);
+            Self::deposit_event(Event::PlotReservationCancelled { plot_id, buyer });
+            Ok(())
+        }
+
+        /// 函数级中文注释：安置逝者/宠物到墓位（墓位持有人）。
+        /// - 分区类型须接受该对象（宠物区仅宠物、混合区均可、其他仅逝者）；
+        /// - 调用者须有权管理该对象（逝者/宠物 owner）；同一对象只能安置于一个墓位。
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::assign_plot())]
+        pub fn assign_plot(origin: OriginFor<T>, plot_id: u64, occupant: PlotOccupant) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut plot = Plots::<T>::get(plot_id).ok_or(Error::<T>::PlotNotFound)?;
+            ensure!(plot.holder.as_ref() == Some(&who), Error::<T>::NotHolder);
+            let zone = ParkZones::<T>::get(plot.park_id, plot.zone_id).ok_or(Error::<T>::ZoneNotFound)?;
+            ensure!(zone.kind.accepts(&occupant), Error::<T>::ZoneMismatch);
+            ensure!(
+                T::Occupants::exists(&occupant) && T::Occupants::can_assign(&who, &occupant),
+                Error::<T>::OccupantNotAllowed
+            );
+            ensure!(!PlotOf::<T>::contains_key(occupant), Error::<T>::AlreadyPlaced);
+            plot.occupants.try_push(occupant).map_err(|_| Error::<T>::TooMany)?;
+            Plots::<T>::insert(plot_id, plot);
+            PlotOf::<T>::insert(occupant, plot_id);
+            Self::deposit_event(Event::PlotAssigned { plot_id, occupant });
+            Ok(())
+        }
+
+        /// 函数级中文注释：迁出安置对象（墓位持有人或运营方，如迁葬）。
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::vacate_plot())]
+        pub fn vacate_plot(origin: OriginFor<T>, plot_id: u64, occupant: PlotOccupant) -> DispatchResult {
+            let who = ensure_signed(origin.clone())?;
+            let mut plot = Plots::<T>::get(plot_id).ok_or(Error::<T>::PlotNotFound)?;
+            if plot.holder.as_ref() != Some(&who) {
+                Self::ensure_operator(plot.park_id, origin).map_err(|_| Error::<T>::NotHolder)?;
+            }
+            let pos = plot
+                .occupants
+                .iter()
+                .position(|o| *o == occupant)
+                .ok_or(Error::<T>::NotPlaced)?;
+            plot.occupants.remove(pos);
+            Plots::<T>::insert(plot_id, plot);
+            PlotOf::<T>::remove(occupant);
+            Self::deposit_event(Event::PlotVacated { plot_id, occupant });
+            Ok(())
+        }
+
+        /// 函数级中文注释：转让墓位（墓位持有人）。
+        /// - 安置对象随墓位保留；争议转让走申诉域 15（治理转让/收回）。
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::transfer_plot())]
+        pub fn transfer_plot(origin: OriginFor<T>, plot_id: u64, new_holder: T::AccountId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let plot = Plots::<T>::get(plot_id).ok_or(Error::<T>::PlotNotFound)?;
+            ensure!(plot.holder.as_ref() == Some(&who), Error::<T>::NotHolder);
+            Self::do_transfer_plot(plot_id, new_holder)
+        }
+
+        /// 函数级中文注释：【治理】转让墓位持有人（记录证据 scope=2）。
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::gov_transfer_plot())]
+        pub fn gov_transfer_plot(
+            origin: OriginFor<T>,
+            plot_id: u64,
+            new_holder: T::AccountId,
+            evidence_cid: Vec<u8>,
+        ) -> DispatchResult {
+            Self::ensure_gov(origin)?;
+            let _ = Self::note_evidence(2u8, plot_id, evidence_cid)?;
+            Self::do_transfer_plot(plot_id, new_holder)
+        }
+
+        /// 函数级中文注释：【治理】收回墓位（记录证据 scope=2）。
+        /// - 清空持有人与安置记录，墓位恢复可售；预订中的墓位先退款给预订人。
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::gov_reclaim_plot(T::MaxOccupantsPerPlot::get()))]
+        pub fn gov_reclaim_plot(origin: OriginFor<T>, plot_id: u64, evidence_cid: Vec<u8>) -> DispatchResult {
+            Self::ensure_gov(origin)?;
+            let _ = Self::note_evidence(2u8, plot_id, evidence_cid)?;
+            let mut plot = Plots::<T>::get(plot_id).ok_or(Error::<T>::PlotNotFound)?;
+            if let PlotStatus::Reserved { buyer, .. } = &plot.status {
+                T::Escrow::refund_all(Self::plot_escrow_id(plot_id), buyer)?;
+            }
+            for occupant in plot.occupants.iter() {
+                PlotOf::<T>::remove(occupant);
+            }
+            plot.occupants = BoundedVec::default();
+            plot.holder = None;
+            plot.status = PlotStatus::Available;
+            Plots::<T>::insert(plot_id, plot);
+            Self::deposit_event(Event::PlotReclaimed { plot_id });
+            Ok(())
+        }
     }
 }
@@ -1,10 +1,12 @@
 // 函数级中文注释：pallet-memo-park的Mock Runtime，用于单元测试
 
 use crate as pallet_memo_park;
+use crate::PlotOccupant;
 use frame_support::{
     parameter_types,
-    traits::ConstU32,
+    traits::{ConstU32, ConstU64},
 };
+use std::{cell::RefCell, collections::BTreeMap};
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage,
@@ -90,6 +92,70 @@ impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureRootOr100 {
     }
 }
 
+thread_local! {
+    /// 托管余额（托管ID → 金额）
+    pub static ESCROWED: RefCell<BTreeMap<u64, u128>> = RefCell::new(BTreeMap::new());
+    /// 托管出账记录（收款人, 金额）
+    pub static PAYOUTS: RefCell<Vec<(u64, u128)>> = RefCell::new(Vec::new());
+}
+
+/// Mock Escrow - 内存记账，不做余额校验；账户 13 视为余额不足
+pub struct MockEscrow;
+impl MockEscrow {
+    fn pay_all(id: u64, to: &u64) -> sp_runtime::DispatchResult {
+        let amount = ESCROWED
+            .with(|m| m.borrow_mut().remove(&id))
+            .ok_or(sp_runtime::DispatchError::Other("NoLock"))?;
+        PAYOUTS.with(|p| p.borrow_mut().push((*to, amount)));
+        Ok(())
+    }
+}
+impl pallet_escrow::Escrow<u64, u128> for MockEscrow {
+    fn lock_from(payer: &u64, id: u64, amount: u128) -> sp_runtime::DispatchResult {
+        if *payer == 13 {
+            return Err(sp_runtime::DispatchError::Other("Insufficient"));
+        }
+        ESCROWED.with(|m| *m.borrow_mut().entry(id).or_default() += amount);
+        Ok(())
+    }
+    fn transfer_from_escrow(id: u64, to: &u64, amount: u128) -> sp_runtime::DispatchResult {
+        ESCROWED.with(|m| {
+            let mut m = m.borrow_mut();
+            let cur = m.entry(id).or_default();
+            *cur = cur.checked_sub(amount).ok_or(sp_runtime::DispatchError::Other("Insufficient"))?;
+            Ok::<_, sp_runtime::DispatchError>(())
+        })?;
+        PAYOUTS.with(|p| p.borrow_mut().push((*to, amount)));
+        Ok(())
+    }
+    fn release_all(id: u64, to: &u64) -> sp_runtime::DispatchResult {
+        Self::pay_all(id, to)
+    }
+    fn refund_all(id: u64, to: &u64) -> sp_runtime::DispatchResult {
+        Self::pay_all(id, to)
+    }
+    fn amount_of(id: u64) -> u128 {
+        ESCROWED.with(|m| m.borrow().get(&id).copied().unwrap_or(0))
+    }
+}
+
+/// Mock 安葬对象 - 逝者/宠物 ID < 100 视为存在，且 owner 为 ID % 10 对应账户
+pub struct MockOccupants;
+impl crate::PlotOccupantAccess<u64> for MockOccupants {
+    fn exists(occupant: &PlotOccupant) -> bool {
+        match occupant {
+            PlotOccupant::Deceased(id)
+            | PlotOccupant::Pet(id) => *id < 100,
+        }
+    }
+    fn can_assign(who: &u64, occupant: &PlotOccupant) -> bool {
+        match occupant {
+            PlotOccupant::Deceased(id)
+            | PlotOccupant::Pet(id) => id % 10 == *who,
+        }
+    }
+}
+
 impl pallet_stardust_park::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxRegionLen = ConstU32<64>;
@@ -97,6 +163,16 @@ impl pallet_stardust_park::Config for Test {
     type MaxParksPerCountry = ConstU32<100>;
     type ParkAdmin = MockParkAdmin;
     type GovernanceOrigin = EnsureRootOr100;
+    type Balance = u128;
+    type Escrow = MockEscrow;
+    type Occupants = MockOccupants;
+    type MaxParkOperators = ConstU32<4>;
+    type MaxZonesPerPark = ConstU32<3>;
+    type MaxPlotsPerZone = ConstU32<10>;
+    type MaxPlotsPerBatch = ConstU32<5>;
+    type MaxOccupantsPerPlot = ConstU32<2>;
+    type ReservationPeriod = ConstU64<10>;
+    type WeightInfo = crate::weights::SubstrateWeight<Test>;
 }
 
 /// 函数级中文注释：创建测试环境
@@ -104,6 +180,8 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
     let t = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
         .unwrap();
+    ESCROWED.with(|m| m.borrow_mut().clear());
+    PAYOUTS.with(|p| p.borrow_mut().clear());
     t.into()
 }
 
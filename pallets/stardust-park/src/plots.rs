@@ -0,0 +1,123 @@
+//! 函数级中文注释：陵园分区与墓位（plot）库存相关类型
+//!
+//! - 分区（zone）按葬式划分（传统墓、草坪葬、壁葬、树葬、宠物区、混合区），分区类型决定可安葬对象；
+//! - 墓位以 (分区, 排, 号) 唯一定位，按价格档位（tier）定价；
+//! - 墓位状态：可售 → 预订（价款托管）→ 已售（持有人可安置逝者/宠物）；运营方可停售。
+
+use alloc::vec::Vec;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// 墓位托管ID命名空间（高32位 "PLOT"），托管ID = `PLOT_ESCROW_NAMESPACE | plot_id`
+pub const PLOT_ESCROW_NAMESPACE: u64 = 0x504c_4f54_0000_0000;
+
+/// 函数级中文注释：分区类型（陵园分区规划）
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum ZoneKind {
+    /// 传统墓
+    Traditional,
+    /// 草坪葬
+    Lawn,
+    /// 壁葬 / 骨灰墙
+    Columbarium,
+    /// 树葬 / 花葬
+    Tree,
+    /// 宠物纪念区（仅宠物）
+    Pet,
+    /// 混合区（人与宠物可同穴）
+    Mixed,
+}
+
+impl ZoneKind {
+    /// 函数级中文注释：分区是否接受该安葬对象
+    /// - 宠物区仅宠物；混合区均可；其他分区仅逝者
+    pub fn accepts(&self, occupant: &PlotOccupant) -> bool {
+        match self {
+            ZoneKind::Pet => matches!(occupant, PlotOccupant::Pet(_)),
+            ZoneKind::Mixed => true,
+            _ => matches!(occupant, PlotOccupant::Deceased(_)),
+        }
+    }
+}
+
+/// 函数级中文注释：墓位安葬对象
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum PlotOccupant {
+    Deceased(u64),
+    Pet(u64),
+}
+
+/// 函数级中文注释：墓位状态
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum PlotStatus<AccountId, Balance, BlockNumber> {
+    /// 可售
+    Available,
+    /// 已预订：价款已托管，待运营方确认或到期退款
+    Reserved {
+        buyer: AccountId,
+        price: Balance,
+        expires_at: BlockNumber,
+    },
+    /// 已售（holder 为持有人）
+    Sold,
+    /// 停售（维护/保留位）
+    Disabled,
+}
+
+impl<AccountId, Balance, BlockNumber> PlotStatus<AccountId, Balance, BlockNumber> {
+    /// 状态编码：0=可售, 1=已预订, 2=已售, 3=停售
+    pub fn code(&self) -> u8 {
+        match self {
+            PlotStatus::Available => 0,
+            PlotStatus::Reserved { .. } => 1,
+            PlotStatus::Sold => 2,
+            PlotStatus::Disabled => 3,
+        }
+    }
+}
+
+/// 函数级中文注释：安葬对象访问接口（由 Runtime 适配 pallet-deceased / pallet-stardust-pet）
+pub trait PlotOccupantAccess<AccountId> {
+    /// 安葬对象是否存在
+    fn exists(occupant: &PlotOccupant) -> bool;
+    /// 是否可由 who 安置（逝者 owner / 宠物 owner）
+    fn can_assign(who: &AccountId, occupant: &PlotOccupant) -> bool;
+}
+
+impl<AccountId> PlotOccupantAccess<AccountId> for () {
+    fn exists(_occupant: &PlotOccupant) -> bool {
+        false
+    }
+    fn can_assign(_who: &AccountId, _occupant: &PlotOccupant) -> bool {
+        false
+    }
+}
+
+/// 函数级中文注释：分区视图（Runtime API）
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, Debug)]
+pub struct ZoneView {
+    pub zone_id: u32,
+    pub name: Vec<u8>,
+    pub kind: ZoneKind,
+    pub metadata_cid: Vec<u8>,
+    pub active: bool,
+    pub plot_count: u32,
+    /// 可售墓位数
+    pub available: u32,
+}
+
+/// 函数级中文注释：墓位视图（Runtime API）
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, Debug)]
+pub struct PlotView<AccountId, Balance> {
+    pub plot_id: u64,
+    pub zone_id: u32,
+    pub row: u16,
+    pub number: u16,
+    pub tier: u8,
+    /// 当前档位价格（档位未定价为 None）
+    pub price: Option<Balance>,
+    /// 状态编码：0=可售, 1=已预订, 2=已售, 3=停售
+    pub status: u8,
+    pub holder: Option<AccountId>,
+    pub occupants: Vec<PlotOccupant>,
+}
@@ -0,0 +1,32 @@
+//! Runtime API 定义
+//!
+//! 本模块定义了陵园地图的 Runtime API：分区列表、分区墓位、墓位详情与安葬对象定位，
+//! 供前端渲染陵园地图与选位购买使用。
+
+use crate::plots::{PlotOccupant, PlotView, ZoneView};
+use alloc::vec::Vec;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// 陵园地图 Runtime API
+    pub trait ParkMapApi<AccountId, Balance>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        /// 获取陵园全部分区（含墓位数与可售数）
+        fn park_zones(park_id: u64) -> Vec<ZoneView>;
+
+        /// 获取分区全部墓位（按上架顺序）
+        fn zone_plots(park_id: u64, zone_id: u32) -> Vec<PlotView<AccountId, Balance>>;
+
+        /// 获取墓位详情
+        fn plot(plot_id: u64) -> Option<PlotView<AccountId, Balance>>;
+
+        /// 查询逝者/宠物安葬的墓位
+        fn plot_of(occupant: PlotOccupant) -> Option<u64>;
+
+        /// 查询逝者/宠物所在陵园
+        fn park_of(occupant: PlotOccupant) -> Option<u64>;
+    }
+}
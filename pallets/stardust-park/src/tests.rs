@@ -1,7 +1,10 @@
 // 函数级中文注释：pallet-memo-park单元测试
 // Phase 3 Week 1 Day 1: 15个核心测试用例
 
-use crate::{mock::*, Error, Event, Parks, ParksByCountry, NextParkId};
+use crate::{
+    mock::*, Error, Event, NextParkId, ParkZones, Parks, ParksByCountry, PlotOccupant, PlotOf,
+    PlotStatus, Plots, ZoneKind,
+};
 use frame_support::{assert_noop, assert_ok, BoundedVec, traits::ConstU32};
 
 /// 辅助函数：创建有效的country_iso2
@@ -495,7 +498,7 @@ fn gov_operations_require_governance() {
                 Some(false),
                 vec![]
             ),
-            Error::<Test>::NotAdmin
+            sp_runtime::DispatchError::BadOrigin
         );
     });
 }
@@ -531,3 +534,210 @@ fn gov_transfer_park_works() {
     });
 }
 
+
+// ==================== 🆕 分区与墓位库存测试 ====================
+
+/// 辅助函数：账户1创建园区0，设置运营账户5，新增传统区0与宠物区1，档位1定价1000，
+/// 传统区第1排上架 1..=3 号（墓位0..=2），宠物区第1排上架1号（墓位3）
+fn setup_plots() {
+    assert_ok!(MemoPark::create_park(RuntimeOrigin::signed(1), country(), region(), metadata_cid()));
+    assert_ok!(MemoPark::set_park_operators(
+        RuntimeOrigin::signed(1),
+        0,
+        BoundedVec::try_from(vec![5u64]).unwrap()
+    ));
+    assert_ok!(MemoPark::add_zone(
+        RuntimeOrigin::signed(5),
+        0,
+        region(),
+        ZoneKind::Traditional,
+        metadata_cid()
+    ));
+    assert_ok!(MemoPark::add_zone(RuntimeOrigin::signed(5), 0, region(), ZoneKind::Pet, metadata_cid()));
+    assert_ok!(MemoPark::set_price_tier(RuntimeOrigin::signed(5), 0, 1, Some(1000)));
+    assert_ok!(MemoPark::add_plots(RuntimeOrigin::signed(5), 0, 0, 1, 1, 3, 1));
+    assert_ok!(MemoPark::add_plots(RuntimeOrigin::signed(5), 0, 1, 1, 1, 1, 1));
+}
+
+/// 辅助函数：账户 buyer 预订并由运营方确认墓位
+fn buy_plot(plot_id: u64, buyer: u64) {
+    assert_ok!(MemoPark::reserve_plot(RuntimeOrigin::signed(buyer), plot_id));
+    assert_ok!(MemoPark::confirm_plot_sale(RuntimeOrigin::signed(5), plot_id));
+}
+
+/// 测试16：分区与墓位上架、地图视图
+#[test]
+fn zones_and_plots_listing_works() {
+    new_test_ext().execute_with(|| {
+        setup_plots();
+
+        // 非运营账户不可上架
+        assert_noop!(
+            MemoPark::add_plots(RuntimeOrigin::signed(2), 0, 0, 2, 1, 1, 1),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        // 坐标重复整体失败
+        assert_noop!(
+            MemoPark::add_plots(RuntimeOrigin::signed(5), 0, 0, 1, 3, 2, 1),
+            Error::<Test>::PlotExists
+        );
+        // 批量数量越界
+        assert_noop!(
+            MemoPark::add_plots(RuntimeOrigin::signed(5), 0, 0, 2, 1, 6, 1),
+            Error::<Test>::BadBatch
+        );
+        // 分区数量上限（MaxZonesPerPark = 3）
+        assert_ok!(MemoPark::add_zone(RuntimeOrigin::signed(5), 0, region(), ZoneKind::Lawn, metadata_cid()));
+        assert_noop!(
+            MemoPark::add_zone(RuntimeOrigin::signed(5), 0, region(), ZoneKind::Tree, metadata_cid()),
+            Error::<Test>::TooMany
+        );
+
+        let plot = Plots::<Test>::get(2).unwrap();
+        assert_eq!((plot.zone_id, plot.row, plot.number, plot.tier), (0, 1, 3, 1));
+
+        let zones = MemoPark::park_zones(0);
+        assert_eq!(zones.len(), 3);
+        assert_eq!((zones[0].plot_count, zones[0].available), (3, 3));
+        assert_eq!(zones[1].kind, ZoneKind::Pet);
+
+        assert_ok!(MemoPark::set_plot_enabled(RuntimeOrigin::signed(5), 1, false));
+        let plots = MemoPark::zone_plots(0, 0);
+        assert_eq!(plots.len(), 3);
+        assert_eq!(plots[1].status, 3);
+        assert_eq!(plots[0].price, Some(1000));
+        assert_eq!(MemoPark::park_zones(0)[0].available, 2);
+    });
+}
+
+/// 测试17：预订 → 确认售出，价款托管释放给陵园所有者
+#[test]
+fn reserve_and_confirm_plot_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_plots();
+
+        // 未定价档位不可预订
+        assert_ok!(MemoPark::add_plots(RuntimeOrigin::signed(5), 0, 0, 2, 1, 1, 9));
+        assert_noop!(MemoPark::reserve_plot(RuntimeOrigin::signed(2), 4), Error::<Test>::TierNotPriced);
+
+        assert_ok!(MemoPark::reserve_plot(RuntimeOrigin::signed(2), 0));
+        assert_eq!(<MockEscrow as pallet_escrow::Escrow<u64, u128>>::amount_of(MemoPark::plot_escrow_id(0)), 1000);
+        System::assert_has_event(
+            Event::PlotReserved { plot_id: 0, buyer: 2, price: 1000, expires_at: 11 }.into(),
+        );
+        assert_noop!(MemoPark::reserve_plot(RuntimeOrigin::signed(3), 0), Error::<Test>::BadPlotStatus);
+
+        // 仅运营方可确认
+        assert_noop!(MemoPark::confirm_plot_sale(RuntimeOrigin::signed(2), 0), sp_runtime::DispatchError::BadOrigin);
+        assert_ok!(MemoPark::confirm_plot_sale(RuntimeOrigin::signed(5), 0));
+        let plot = Plots::<Test>::get(0).unwrap();
+        assert_eq!(plot.status, PlotStatus::Sold);
+        assert_eq!(plot.holder, Some(2));
+        assert_eq!(PAYOUTS.with(|p| p.borrow().clone()), vec![(1, 1000)]);
+    });
+}
+
+/// 测试18：取消预订与到期退款
+#[test]
+fn cancel_reservation_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_plots();
+        assert_ok!(MemoPark::reserve_plot(RuntimeOrigin::signed(2), 0));
+
+        // 未到期第三方不可取消
+        assert_noop!(
+            MemoPark::cancel_plot_reservation(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::ReservationNotExpired
+        );
+        // 到期后任何人可触发退款
+        System::set_block_number(11);
+        assert_ok!(MemoPark::cancel_plot_reservation(RuntimeOrigin::signed(3), 0));
+        assert_eq!(Plots::<Test>::get(0).unwrap().status, PlotStatus::Available);
+        assert_eq!(PAYOUTS.with(|p| p.borrow().clone()), vec![(2, 1000)]);
+
+        // 预订人随时可取消
+        assert_ok!(MemoPark::reserve_plot(RuntimeOrigin::signed(4), 0));
+        assert_ok!(MemoPark::cancel_plot_reservation(RuntimeOrigin::signed(4), 0));
+        assert_eq!(Plots::<Test>::get(0).unwrap().status, PlotStatus::Available);
+    });
+}
+
+/// 测试19：按分区规划安置逝者/宠物
+#[test]
+fn assign_plot_respects_zoning() {
+    new_test_ext().execute_with(|| {
+        setup_plots();
+        buy_plot(0, 2);
+        buy_plot(3, 2);
+
+        // 非持有人不可安置
+        assert_noop!(
+            MemoPark::assign_plot(RuntimeOrigin::signed(3), 0, PlotOccupant::Deceased(13)),
+            Error::<Test>::NotHolder
+        );
+        // 传统区不接受宠物、宠物区不接受逝者
+        assert_noop!(
+            MemoPark::assign_plot(RuntimeOrigin::signed(2), 0, PlotOccupant::Pet(22)),
+            Error::<Test>::ZoneMismatch
+        );
+        assert_noop!(
+            MemoPark::assign_plot(RuntimeOrigin::signed(2), 3, PlotOccupant::Deceased(12)),
+            Error::<Test>::ZoneMismatch
+        );
+        // 非对象 owner 不可安置
+        assert_noop!(
+            MemoPark::assign_plot(RuntimeOrigin::signed(2), 0, PlotOccupant::Deceased(13)),
+            Error::<Test>::OccupantNotAllowed
+        );
+
+        assert_ok!(MemoPark::assign_plot(RuntimeOrigin::signed(2), 0, PlotOccupant::Deceased(12)));
+        assert_ok!(MemoPark::assign_plot(RuntimeOrigin::signed(2), 3, PlotOccupant::Pet(22)));
+        assert_eq!(PlotOf::<Test>::get(PlotOccupant::Deceased(12)), Some(0));
+        assert_eq!(MemoPark::park_of_occupant(&PlotOccupant::Pet(22)), Some(0));
+        assert_noop!(
+            MemoPark::assign_plot(RuntimeOrigin::signed(2), 0, PlotOccupant::Deceased(12)),
+            Error::<Test>::AlreadyPlaced
+        );
+
+        // 已售分区不可变更类型
+        assert_noop!(
+            MemoPark::update_zone(RuntimeOrigin::signed(5), 0, 0, None, Some(ZoneKind::Pet), None, None),
+            Error::<Test>::ZoneInUse
+        );
+
+        // 迁出
+        assert_ok!(MemoPark::vacate_plot(RuntimeOrigin::signed(2), 0, PlotOccupant::Deceased(12)));
+        assert_eq!(PlotOf::<Test>::get(PlotOccupant::Deceased(12)), None);
+        assert_noop!(
+            MemoPark::vacate_plot(RuntimeOrigin::signed(2), 0, PlotOccupant::Deceased(12)),
+            Error::<Test>::NotPlaced
+        );
+    });
+}
+
+/// 测试20：墓位转让与治理收回
+#[test]
+fn transfer_and_reclaim_plot_works() {
+    new_test_ext().execute_with(|| {
+        setup_plots();
+        buy_plot(0, 2);
+        assert_ok!(MemoPark::assign_plot(RuntimeOrigin::signed(2), 0, PlotOccupant::Deceased(12)));
+
+        assert_noop!(MemoPark::transfer_plot(RuntimeOrigin::signed(3), 0, 4), Error::<Test>::NotHolder);
+        assert_ok!(MemoPark::transfer_plot(RuntimeOrigin::signed(2), 0, 4));
+        assert_eq!(Plots::<Test>::get(0).unwrap().holder, Some(4));
+
+        assert_ok!(MemoPark::gov_transfer_plot(RuntimeOrigin::signed(100), 0, 3, b"QmPlot".to_vec()));
+        assert_eq!(Plots::<Test>::get(0).unwrap().holder, Some(3));
+
+        assert_ok!(MemoPark::gov_reclaim_plot(RuntimeOrigin::signed(100), 0, b"QmReclaim".to_vec()));
+        let plot = Plots::<Test>::get(0).unwrap();
+        assert_eq!(plot.status, PlotStatus::Available);
+        assert_eq!(plot.holder, None);
+        assert!(plot.occupants.is_empty());
+        assert_eq!(PlotOf::<Test>::get(PlotOccupant::Deceased(12)), None);
+        assert!(ParkZones::<Test>::get(0, 0).is_some());
+    });
+}
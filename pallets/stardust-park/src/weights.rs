@@ -0,0 +1,124 @@
+//! 函数级中文注释：pallet-stardust-park 分区与墓位接口权重（基准占位）。
+//! - 含迭代的接口按上限参数化：`update_zone` 按 `MaxPlotsPerZone`、`add_plots` 按批量数、
+//!   `gov_reclaim_plot` 按 `MaxOccupantsPerPlot`；
+//! - 后续可通过 frame-benchmarking 自动生成替换本实现。
+
+use frame_support::weights::{constants::RocksDbWeight, Weight};
+
+pub trait WeightInfo {
+    /// 设置运营账户（参数为运营账户数）
+    fn set_park_operators(operators: u32) -> Weight;
+    /// 新增分区
+    fn add_zone() -> Weight;
+    /// 更新分区（参数为区内墓位数上限：变更分区类型时逐个检查墓位状态）
+    fn update_zone(plots: u32) -> Weight;
+    /// 设置价格档位
+    fn set_price_tier() -> Weight;
+    /// 批量上架墓位（参数为本批数量）
+    fn add_plots(count: u32) -> Weight;
+    /// 墓位停售/恢复可售
+    fn set_plot_enabled() -> Weight;
+    /// 预订墓位（含托管锁定）
+    fn reserve_plot() -> Weight;
+    /// 确认售出（含托管释放）
+    fn confirm_plot_sale() -> Weight;
+    /// 取消预订（含托管退款）
+    fn cancel_plot_reservation() -> Weight;
+    /// 安置逝者/宠物
+    fn assign_plot() -> Weight;
+    /// 迁出安置对象
+    fn vacate_plot() -> Weight;
+    /// 持有人转让墓位
+    fn transfer_plot() -> Weight;
+    /// 治理转让墓位
+    fn gov_transfer_plot() -> Weight;
+    /// 治理收回墓位（参数为安置对象数上限）
+    fn gov_reclaim_plot(occupants: u32) -> Weight;
+}
+
+/// 默认实现：基于 RocksDb 权重常量的保守手写占位值。
+pub struct SubstrateWeight<T>(core::marker::PhantomData<T>);
+impl<T> WeightInfo for SubstrateWeight<T> {
+    fn set_park_operators(operators: u32) -> Weight {
+        let w = RocksDbWeight::get();
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(operators.into()))
+            .saturating_add(w.reads_writes(1, 1))
+    }
+
+    fn add_zone() -> Weight {
+        let w = RocksDbWeight::get();
+        Weight::from_parts(20_000_000, 0).saturating_add(w.reads_writes(3, 2))
+    }
+
+    fn update_zone(plots: u32) -> Weight {
+        let w = RocksDbWeight::get();
+        // 陵园、运营账户、分区、分区墓位索引 + 每个墓位一次读取
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(plots.into()))
+            .saturating_add(w.reads_writes(4, 1))
+            .saturating_add(w.reads(plots.into()))
+    }
+
+    fn set_price_tier() -> Weight {
+        let w = RocksDbWeight::get();
+        Weight::from_parts(15_000_000, 0).saturating_add(w.reads_writes(2, 1))
+    }
+
+    fn add_plots(count: u32) -> Weight {
+        let w = RocksDbWeight::get();
+        // 每个墓位：坐标查重 1 读，坐标索引与墓位各 1 写
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(count.into()))
+            .saturating_add(w.reads_writes(5, 2))
+            .saturating_add(w.reads_writes(1, 2).saturating_mul(count.into()))
+    }
+
+    fn set_plot_enabled() -> Weight {
+        let w = RocksDbWeight::get();
+        Weight::from_parts(15_000_000, 0).saturating_add(w.reads_writes(3, 1))
+    }
+
+    fn reserve_plot() -> Weight {
+        let w = RocksDbWeight::get();
+        Weight::from_parts(40_000_000, 0).saturating_add(w.reads_writes(6, 3))
+    }
+
+    fn confirm_plot_sale() -> Weight {
+        let w = RocksDbWeight::get();
+        Weight::from_parts(40_000_000, 0).saturating_add(w.reads_writes(5, 3))
+    }
+
+    fn cancel_plot_reservation() -> Weight {
+        let w = RocksDbWeight::get();
+        Weight::from_parts(40_000_000, 0).saturating_add(w.reads_writes(5, 3))
+    }
+
+    fn assign_plot() -> Weight {
+        let w = RocksDbWeight::get();
+        Weight::from_parts(25_000_000, 0).saturating_add(w.reads_writes(5, 2))
+    }
+
+    fn vacate_plot() -> Weight {
+        let w = RocksDbWeight::get();
+        Weight::from_parts(20_000_000, 0).saturating_add(w.reads_writes(3, 2))
+    }
+
+    fn transfer_plot() -> Weight {
+        let w = RocksDbWeight::get();
+        Weight::from_parts(15_000_000, 0).saturating_add(w.reads_writes(2, 1))
+    }
+
+    fn gov_transfer_plot() -> Weight {
+        let w = RocksDbWeight::get();
+        Weight::from_parts(15_000_000, 0).saturating_add(w.reads_writes(1, 1))
+    }
+
+    fn gov_reclaim_plot(occupants: u32) -> Weight {
+        let w = RocksDbWeight::get();
+        // 预订中的墓位先退款（托管读写），每个安置对象移除一次反查索引
+        Weight::from_parts(40_000_000, 0)
+            .saturating_add(w.reads_writes(3, 3))
+            .saturating_add(w.writes(occupants.into()))
+    }
+}
@@ -5,10 +5,51 @@ extern crate alloc;
 
 pub use pallet::*;
 
+pub mod migrations;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// 函数级详细中文注释：宠物访问接口（与 deceased 的 `DeceasedAccess` 同构）
+///
+/// 供 memorial 供奉、social 关注、stardust-ipfs 计费、appeals 申诉等模块
+/// 以统一方式把宠物当作纪念主体使用，由 Runtime 适配到各模块自己的 trait。
+pub trait PetAccess<AccountId> {
+    /// 宠物是否存在
+    fn pet_exists(pet_id: u64) -> bool;
+    /// 宠物当前所有者
+    fn pet_owner(pet_id: u64) -> Option<AccountId>;
+    /// 是否有权管理该宠物（当前为所有者）
+    fn can_manage(who: &AccountId, pet_id: u64) -> bool;
+    /// 是否公开可见（不存在视为不可见；未设置视为公开）
+    fn is_visible(pet_id: u64) -> bool;
+}
+
+/// 函数级中文注释：宠物关联目标校验（由 Runtime 适配 pallet-deceased / pallet-memorial-space）
+pub trait PetLinkTargets<AccountId> {
+    /// 逝者存在且 `who` 有权把宠物关联到该逝者（逝者 owner）
+    fn can_link_deceased(who: &AccountId, deceased_id: u64) -> bool;
+    /// 纪念空间存在且 `who` 为空间所有者或管理员
+    fn can_link_space(who: &AccountId, space_id: u64) -> bool;
+}
+
+impl<AccountId> PetLinkTargets<AccountId> for () {
+    fn can_link_deceased(_who: &AccountId, _deceased_id: u64) -> bool {
+        false
+    }
+    fn can_link_space(_who: &AccountId, _space_id: u64) -> bool {
+        false
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use alloc::vec::Vec;
+    use codec::DecodeWithMemTracking;
     use frame_support::{pallet_prelude::*, BoundedVec};
     use frame_system::pallet_prelude::*;
 
@@ -18,6 +59,30 @@ pub mod pallet {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         #[pallet::constant]
         type StringLimit: Get<u32>;
+
+        /// 函数级中文注释：🆕 CID 最大长度（主图、简介、媒体）
+        #[pallet::constant]
+        type MaxCidLen: Get<u32>;
+
+        /// 函数级中文注释：🆕 每只宠物最多的媒体/文字条目数
+        #[pallet::constant]
+        type MaxMediaPerPet: Get<u32>;
+
+        /// 函数级中文注释：🆕 每位逝者最多关联的宠物数
+        #[pallet::constant]
+        type MaxPetsPerDeceased: Get<u32>;
+
+        /// 函数级中文注释：🆕 关联目标校验（逝者 owner / 纪念空间管理员）
+        type LinkTargets: PetLinkTargets<Self::AccountId>;
+
+        /// 函数级中文注释：🆕 余额类型（仅用于 IpfsPinner 泛型参数）
+        type Balance: Parameter + Member + Copy + MaxEncodedLen;
+
+        /// 函数级中文注释：🆕 IPFS 自动 pin（主图、简介、媒体 CID；扣费走宠物 SubjectFunding）
+        type IpfsPinner: pallet_stardust_ipfs::IpfsPinner<Self::AccountId, Self::Balance>;
+
+        /// 函数级中文注释：🆕 治理起源（内容申诉裁决执行 / 委员会 / Root）
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
     }
 
 
@@ -36,25 +101,120 @@ pub mod pallet {
         pub created: BlockNumberFor<T>,
     }
 
+    /// 函数级中文注释：🆕 宠物关联目标（替代已删除的墓位）
+    /// - `Deceased`: 关联到人类逝者（主人/家人），逝者页可展示其宠物
+    /// - `Space`: 关联到纪念空间（pallet-memorial-space）
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub enum PetLink {
+        Deceased(u64),
+        Space(u64),
+    }
+
+    /// 函数级中文注释：🆕 宠物纪念档案（与逝者资料对齐的最小字段）
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PetProfile<T: Config> {
+        /// 出生日期（8字节 YYYYMMDD）
+        pub birth: Option<BoundedVec<u8, ConstU32<8>>>,
+        /// 离世日期（8字节 YYYYMMDD）
+        pub death: Option<BoundedVec<u8, ConstU32<8>>>,
+        /// 主图 CID
+        pub main_image_cid: Option<BoundedVec<u8, T::MaxCidLen>>,
+        /// 生平/悼文 CID
+        pub bio_cid: Option<BoundedVec<u8, T::MaxCidLen>>,
+    }
+
+    /// 函数级中文注释：🆕 宠物媒体/文字类型（kind 编码：0=照片, 1=视频, 2=音频, 3=文字）
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub enum PetMediaKind {
+        Photo,
+        Video,
+        Audio,
+        Text,
+    }
+
+    impl PetMediaKind {
+        /// 函数级中文注释：由外部编码构造，非法编码返回 None
+        pub fn from_code(code: u8) -> Option<Self> {
+            match code {
+                0 => Some(Self::Photo),
+                1 => Some(Self::Video),
+                2 => Some(Self::Audio),
+                3 => Some(Self::Text),
+                _ => None,
+            }
+        }
+    }
+
+    /// 函数级中文注释：🆕 宠物媒体/文字条目（内容存 IPFS）
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PetMedia<T: Config> {
+        pub pet_id: u64,
+        pub kind: PetMediaKind,
+        pub cid: BoundedVec<u8, T::MaxCidLen>,
+        pub title: BoundedVec<u8, T::StringLimit>,
+        pub created: BlockNumberFor<T>,
+    }
+
+    /// 函数级中文注释：存储版本（v1：清空墓位关联 `PetInGrave`，见 `migrations`）
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(PhantomData<T>);
 
     #[pallet::storage]
     pub type NextPetId<T: Config> = StorageValue<_, u64, ValueQuery>;
     #[pallet::storage]
     pub type PetOf<T: Config> = StorageMap<_, Blake2_128Concat, u64, Pet<T>, OptionQuery>;
-    /// 函数级中文注释：宠物附着到的墓位（可选）。
+    /// 函数级中文注释：🆕 宠物关联目标（逝者 / 纪念空间，可选）。
+    #[pallet::storage]
+    pub type PetLinkOf<T: Config> = StorageMap<_, Blake2_128Concat, u64, PetLink, OptionQuery>;
+    /// 函数级中文注释：🆕 逝者 → 关联的宠物列表（逝者页展示用）。
+    #[pallet::storage]
+    pub type PetsByDeceased<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, BoundedVec<u64, T::MaxPetsPerDeceased>, ValueQuery>;
+    /// 函数级中文注释：🆕 宠物纪念档案。
+    #[pallet::storage]
+    pub type PetProfileOf<T: Config> = StorageMap<_, Blake2_128Concat, u64, PetProfile<T>, OptionQuery>;
+    /// 函数级中文注释：🆕 宠物可见性（None 视为公开，与 deceased::VisibilityOf 一致）。
+    #[pallet::storage]
+    pub type PetVisibilityOf<T: Config> = StorageMap<_, Blake2_128Concat, u64, bool, OptionQuery>;
+    #[pallet::storage]
+    pub type NextPetMediaId<T: Config> = StorageValue<_, u64, ValueQuery>;
+    /// 函数级中文注释：🆕 媒体/文字条目（全局ID，便于申诉按条目定位）。
+    #[pallet::storage]
+    pub type PetMediaOf<T: Config> = StorageMap<_, Blake2_128Concat, u64, PetMedia<T>, OptionQuery>;
+    /// 函数级中文注释：🆕 宠物 → 媒体ID列表。
     #[pallet::storage]
-    pub type PetInGrave<T: Config> = StorageMap<_, Blake2_128Concat, u64, u64, OptionQuery>;
+    pub type MediaByPet<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, BoundedVec<u64, T::MaxMediaPerPet>, ValueQuery>;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         PetCreated(u64, T::AccountId),
-        /// 宠物附着到墓位
-        PetAttached(u64, u64),
-        /// 宠物从墓位解绑
-        PetDetached(u64),
+        /// 宠物关联到逝者/纪念空间
+        PetLinked(u64, PetLink),
+        /// 宠物解除关联
+        PetUnlinked(u64),
+        /// 纪念档案更新
+        PetProfileUpdated(u64),
+        /// 主图设置（true）或清除（false）
+        PetMainImageSet(u64, bool),
+        /// 可见性变更
+        PetVisibilityChanged(u64, bool),
+        /// 媒体/文字新增（pet_id, media_id）
+        PetMediaAdded(u64, u64),
+        /// 媒体/文字删除（pet_id, media_id）
+        PetMediaRemoved(u64, u64),
+        /// 所有权转移（pet_id, from, to）
+        PetTransferred(u64, T::AccountId, T::AccountId),
+        /// 自动 pin 失败（不影响主流程，可由所有者到 stardust-ipfs 手动补 pin）
+        PetAutoPinFailed(u64, BoundedVec<u8, T::MaxCidLen>),
+        /// 治理证据记录（pet_id, evidence_cid）
+        GovEvidenceNoted(u64, BoundedVec<u8, T::MaxCidLen>),
     }
 
     #[pallet::error]
@@ -62,11 +222,19 @@ pub mod pallet {
         BadInput,
         NotFound,
         NotOwner,
-        GraveNotFound,
+        /// 关联目标不存在或无权关联
+        LinkNotAllowed,
         NotAllowed,
+        /// 宠物未关联任何目标
+        NotLinked,
+        /// 逝者关联的宠物已达上限
+        TooManyPets,
+        /// 媒体条目已达上限
+        TooManyMedia,
+        /// 媒体条目不存在
+        MediaNotFound,
     }
 
-    // 说明：仅保留创建，便于测试；后续可扩展为与 deceased 同结构的访问 Trait。
     #[allow(warnings)]
     #[allow(deprecated)]
     #[pallet::call]
@@ -105,29 +273,315 @@ pub mod pallet {
             Ok(())
         }
 
-        /// 函数级中文注释：将宠物附着到墓位（仅宠物 owner）。
+        /// 函数级详细中文注释：🆕 将宠物关联到人类逝者或纪念空间（替代原墓位附着）。
+        ///
+        /// - 权限：宠物 owner，且对目标有权限（逝者 owner / 空间所有者或管理员）
+        /// - 已有关联时直接改挂，旧的逝者索引同步移除
         #[pallet::call_index(1)]
         #[pallet::weight(10_000)]
-        pub fn attach_to_grave(origin: OriginFor<T>, pet_id: u64, grave_id: u64) -> DispatchResult {
+        pub fn link_pet(origin: OriginFor<T>, pet_id: u64, link: PetLink) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            let pet = PetOf::<T>::get(pet_id).ok_or(Error::<T>::NotFound)?;
-            ensure!(pet.owner == who, Error::<T>::NotOwner);
+            Self::ensure_owner(&who, pet_id)?;
+            let allowed = match link {
+                PetLink::Deceased(id) => T::LinkTargets::can_link_deceased(&who, id),
+                PetLink::Space(id) => T::LinkTargets::can_link_space(&who, id),
+            };
+            ensure!(allowed, Error::<T>::LinkNotAllowed);
 
-            PetInGrave::<T>::insert(pet_id, grave_id);
-            Self::deposit_event(Event::PetAttached(pet_id, grave_id));
+            Self::remove_link_index(pet_id);
+            if let PetLink::Deceased(deceased_id) = link {
+                PetsByDeceased::<T>::try_mutate(deceased_id, |pets| {
+                    pets.try_push(pet_id).map_err(|_| Error::<T>::TooManyPets)
+                })?;
+            }
+            PetLinkOf::<T>::insert(pet_id, link);
+            Self::deposit_event(Event::PetLinked(pet_id, link));
             Ok(())
         }
 
-        /// 函数级中文注释：解绑宠物与墓位（仅宠物 owner）。
+        /// 函数级中文注释：🆕 解除宠物关联（仅宠物 owner）。
         #[pallet::call_index(2)]
         #[pallet::weight(10_000)]
-        pub fn detach_from_grave(origin: OriginFor<T>, pet_id: u64) -> DispatchResult {
+        pub fn unlink_pet(origin: OriginFor<T>, pet_id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_owner(&who, pet_id)?;
+            ensure!(PetLinkOf::<T>::contains_key(pet_id), Error::<T>::NotLinked);
+            Self::remove_link_index(pet_id);
+            Self::deposit_event(Event::PetUnlinked(pet_id));
+            Ok(())
+        }
+
+        /// 函数级详细中文注释：🆕 更新宠物纪念档案（覆盖式，仅宠物 owner）。
+        ///
+        /// - `birth` / `death`: 8字节 YYYYMMDD，None 表示清空
+        /// - `bio_cid`: 生平/悼文 CID，设置时自动 pin
+        /// - 主图通过 `set_pet_main_image` 单独设置，这里保持不变
+        #[pallet::call_index(3)]
+        #[pallet::weight(10_000)]
+        pub fn update_pet_profile(
+            origin: OriginFor<T>,
+            pet_id: u64,
+            birth: Option<Vec<u8>>,
+            death: Option<Vec<u8>>,
+            bio_cid: Option<Vec<u8>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_owner(&who, pet_id)?;
+            let birth = birth.map(Self::bounded_date).transpose()?;
+            let death = death.map(Self::bounded_date).transpose()?;
+            let bio_cid = bio_cid.map(Self::bounded_cid).transpose()?;
+
+            PetProfileOf::<T>::mutate(pet_id, |maybe| {
+                let main_image_cid = maybe.as_ref().and_then(|p| p.main_image_cid.clone());
+                *maybe = Some(PetProfile { birth, death, main_image_cid, bio_cid: bio_cid.clone() });
+            });
+            if let Some(cid) = bio_cid {
+                Self::auto_pin(&who, pet_id, cid);
+            }
+            Self::deposit_event(Event::PetProfileUpdated(pet_id));
+            Ok(())
+        }
+
+        /// 函数级中文注释：🆕 设置或清除宠物主图（仅宠物 owner；设置时自动 pin）。
+        #[pallet::call_index(4)]
+        #[pallet::weight(10_000)]
+        pub fn set_pet_main_image(origin: OriginFor<T>, pet_id: u64, cid: Option<Vec<u8>>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_owner(&who, pet_id)?;
+            let cid = cid.map(Self::bounded_cid).transpose()?;
+            let set = cid.is_some();
+            Self::write_main_image(pet_id, cid.clone());
+            if let Some(cid) = cid {
+                Self::auto_pin(&who, pet_id, cid);
+            }
+            Self::deposit_event(Event::PetMainImageSet(pet_id, set));
+            Ok(())
+        }
+
+        /// 函数级中文注释：🆕 设置宠物可见性（仅宠物 owner）；不可见时关注与非所有者供奉被拒绝。
+        #[pallet::call_index(5)]
+        #[pallet::weight(10_000)]
+        pub fn set_pet_visibility(origin: OriginFor<T>, pet_id: u64, public: bool) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_owner(&who, pet_id)?;
+            PetVisibilityOf::<T>::insert(pet_id, public);
+            Self::deposit_event(Event::PetVisibilityChanged(pet_id, public));
+            Ok(())
+        }
+
+        /// 函数级详细中文注释：🆕 新增宠物媒体/文字条目（仅宠物 owner；自动 pin）。
+        ///
+        /// - `kind`: 0=照片, 1=视频, 2=音频, 3=文字
+        /// - `cid`: 内容 CID；`title`: 标题/说明（可为空）
+        #[pallet::call_index(6)]
+        #[pallet::weight(10_000)]
+        pub fn add_pet_media(
+            origin: OriginFor<T>,
+            pet_id: u64,
+            kind: u8,
+            cid: Vec<u8>,
+            title: Vec<u8>,
+        ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            Self::ensure_owner(&who, pet_id)?;
+            let kind = PetMediaKind::from_code(kind).ok_or(Error::<T>::BadInput)?;
+            let cid = Self::bounded_cid(cid)?;
+            let title: BoundedVec<u8, T::StringLimit> =
+                BoundedVec::try_from(title).map_err(|_| Error::<T>::BadInput)?;
+
+            let media_id = NextPetMediaId::<T>::get();
+            MediaByPet::<T>::try_mutate(pet_id, |ids| {
+                ids.try_push(media_id).map_err(|_| Error::<T>::TooManyMedia)
+            })?;
+            NextPetMediaId::<T>::put(media_id.saturating_add(1));
+            PetMediaOf::<T>::insert(
+                media_id,
+                PetMedia {
+                    pet_id,
+                    kind,
+                    cid: cid.clone(),
+                    title,
+                    created: <frame_system::Pallet<T>>::block_number(),
+                },
+            );
+            Self::auto_pin(&who, pet_id, cid);
+            Self::deposit_event(Event::PetMediaAdded(pet_id, media_id));
+            Ok(())
+        }
+
+        /// 函数级中文注释：🆕 删除宠物媒体/文字条目（仅宠物 owner）。
+        #[pallet::call_index(7)]
+        #[pallet::weight(10_000)]
+        pub fn remove_pet_media(origin: OriginFor<T>, media_id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let media = PetMediaOf::<T>::get(media_id).ok_or(Error::<T>::MediaNotFound)?;
+            Self::ensure_owner(&who, media.pet_id)?;
+            Self::do_remove_media(media.pet_id, media_id);
+            Ok(())
+        }
+
+        /// 函数级中文注释：🆕 转让宠物所有权（仅宠物 owner；关联与档案保持不变）。
+        #[pallet::call_index(8)]
+        #[pallet::weight(10_000)]
+        pub fn transfer_pet(origin: OriginFor<T>, pet_id: u64, new_owner: T::AccountId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_owner(&who, pet_id)?;
+            ensure!(who != new_owner, Error::<T>::BadInput);
+            Self::do_transfer(pet_id, new_owner)
+        }
+
+        /// 函数级中文注释：🆕【治理】设置宠物可见性（申诉域 13 隐藏/恢复）。
+        /// - 起源：T::GovernanceOrigin；需携带证据 CID（明文，不加密）。
+        #[pallet::call_index(9)]
+        #[pallet::weight(10_000)]
+        pub fn gov_set_pet_visibility(
+            origin: OriginFor<T>,
+            pet_id: u64,
+            public: bool,
+            evidence_cid: Vec<u8>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(PetOf::<T>::contains_key(pet_id), Error::<T>::NotFound);
+            Self::note_evidence(pet_id, evidence_cid)?;
+            PetVisibilityOf::<T>::insert(pet_id, public);
+            Self::deposit_event(Event::PetVisibilityChanged(pet_id, public));
+            Ok(())
+        }
+
+        /// 函数级中文注释：🆕【治理】清除宠物主图（申诉域 13）。
+        #[pallet::call_index(10)]
+        #[pallet::weight(10_000)]
+        pub fn gov_clear_pet_main_image(
+            origin: OriginFor<T>,
+            pet_id: u64,
+            evidence_cid: Vec<u8>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(PetOf::<T>::contains_key(pet_id), Error::<T>::NotFound);
+            Self::note_evidence(pet_id, evidence_cid)?;
+            Self::write_main_image(pet_id, None);
+            Self::deposit_event(Event::PetMainImageSet(pet_id, false));
+            Ok(())
+        }
+
+        /// 函数级中文注释：🆕【治理】删除宠物媒体/文字条目（申诉域 14，target=media_id）。
+        #[pallet::call_index(11)]
+        #[pallet::weight(10_000)]
+        pub fn gov_remove_pet_media(
+            origin: OriginFor<T>,
+            media_id: u64,
+            evidence_cid: Vec<u8>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            let media = PetMediaOf::<T>::get(media_id).ok_or(Error::<T>::MediaNotFound)?;
+            Self::note_evidence(media.pet_id, evidence_cid)?;
+            Self::do_remove_media(media.pet_id, media_id);
+            Ok(())
+        }
+
+        /// 函数级中文注释：🆕【治理】强制转让宠物所有权（申诉域 13）。
+        #[pallet::call_index(12)]
+        #[pallet::weight(10_000)]
+        pub fn gov_transfer_pet(
+            origin: OriginFor<T>,
+            pet_id: u64,
+            new_owner: T::AccountId,
+            evidence_cid: Vec<u8>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(PetOf::<T>::contains_key(pet_id), Error::<T>::NotFound);
+            Self::note_evidence(pet_id, evidence_cid)?;
+            Self::do_transfer(pet_id, new_owner)
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// 函数级中文注释：校验宠物存在且调用者为 owner
+        fn ensure_owner(who: &T::AccountId, pet_id: u64) -> DispatchResult {
             let pet = PetOf::<T>::get(pet_id).ok_or(Error::<T>::NotFound)?;
-            ensure!(pet.owner == who, Error::<T>::NotOwner);
-            PetInGrave::<T>::remove(pet_id);
-            Self::deposit_event(Event::PetDetached(pet_id));
+            ensure!(pet.owner == *who, Error::<T>::NotOwner);
             Ok(())
         }
+
+        fn bounded_cid(cid: Vec<u8>) -> Result<BoundedVec<u8, T::MaxCidLen>, DispatchError> {
+            ensure!(!cid.is_empty(), Error::<T>::BadInput);
+            BoundedVec::try_from(cid).map_err(|_| Error::<T>::BadInput.into())
+        }
+
+        /// 函数级中文注释：日期须为 8 位数字 YYYYMMDD（与 deceased 一致）
+        fn bounded_date(date: Vec<u8>) -> Result<BoundedVec<u8, ConstU32<8>>, DispatchError> {
+            ensure!(date.len() == 8 && date.iter().all(|b| b.is_ascii_digit()), Error::<T>::BadInput);
+            BoundedVec::try_from(date).map_err(|_| Error::<T>::BadInput.into())
+        }
+
+        /// 函数级中文注释：移除当前关联及逝者反向索引
+        fn remove_link_index(pet_id: u64) {
+            if let Some(PetLink::Deceased(deceased_id)) = PetLinkOf::<T>::take(pet_id) {
+                PetsByDeceased::<T>::mutate_exists(deceased_id, |maybe| {
+                    if let Some(pets) = maybe.as_mut() {
+                        pets.retain(|id| *id != pet_id);
+                    }
+                    if maybe.as_ref().map_or(false, |pets| pets.is_empty()) {
+                        *maybe = None;
+                    }
+                });
+            }
+        }
+
+        fn write_main_image(pet_id: u64, cid: Option<BoundedVec<u8, T::MaxCidLen>>) {
+            PetProfileOf::<T>::mutate(pet_id, |maybe| {
+                let profile = maybe.get_or_insert_with(|| PetProfile {
+                    birth: None,
+                    death: None,
+                    main_image_cid: None,
+                    bio_cid: None,
+                });
+                profile.main_image_cid = cid;
+            });
+        }
+
+        fn do_remove_media(pet_id: u64, media_id: u64) {
+            PetMediaOf::<T>::remove(media_id);
+            MediaByPet::<T>::mutate(pet_id, |ids| ids.retain(|id| *id != media_id));
+            Self::deposit_event(Event::PetMediaRemoved(pet_id, media_id));
+        }
+
+        fn do_transfer(pet_id: u64, new_owner: T::AccountId) -> DispatchResult {
+            PetOf::<T>::try_mutate(pet_id, |maybe| -> DispatchResult {
+                let pet = maybe.as_mut().ok_or(Error::<T>::NotFound)?;
+                let old = core::mem::replace(&mut pet.owner, new_owner.clone());
+                Self::deposit_event(Event::PetTransferred(pet_id, old, new_owner));
+                Ok(())
+            })
+        }
+
+        /// 函数级中文注释：自动 pin（失败只发事件，不回滚业务操作）
+        fn auto_pin(who: &T::AccountId, pet_id: u64, cid: BoundedVec<u8, T::MaxCidLen>) {
+            if T::IpfsPinner::pin_cid_for_pet(who.clone(), pet_id, cid.to_vec(), None).is_err() {
+                Self::deposit_event(Event::PetAutoPinFailed(pet_id, cid));
+            }
+        }
+
+        fn note_evidence(pet_id: u64, cid: Vec<u8>) -> DispatchResult {
+            let bv: BoundedVec<u8, T::MaxCidLen> =
+                BoundedVec::try_from(cid).map_err(|_| Error::<T>::BadInput)?;
+            Self::deposit_event(Event::GovEvidenceNoted(pet_id, bv));
+            Ok(())
+        }
+    }
+
+    impl<T: Config> PetAccess<T::AccountId> for Pallet<T> {
+        fn pet_exists(pet_id: u64) -> bool {
+            PetOf::<T>::contains_key(pet_id)
+        }
+        fn pet_owner(pet_id: u64) -> Option<T::AccountId> {
+            PetOf::<T>::get(pet_id).map(|p| p.owner)
+        }
+        fn can_manage(who: &T::AccountId, pet_id: u64) -> bool {
+            PetOf::<T>::get(pet_id).map_or(false, |p| p.owner == *who)
+        }
+        fn is_visible(pet_id: u64) -> bool {
+            PetOf::<T>::contains_key(pet_id) && PetVisibilityOf::<T>::get(pet_id).unwrap_or(true)
+        }
     }
 }
@@ -0,0 +1,41 @@
+//! 函数级中文注释：pallet-stardust-pet 存储迁移
+//!
+//! ### v0 → v1：移除宠物与墓位的关联
+//! - 墓位已删除，旧 `PetInGrave`（宠物 → 墓位）成为孤儿数据
+//! - 墓位与逝者/纪念空间无一一对应关系，无法折算为 `PetLinkOf`，因此直接清空该前缀；
+//!   宠物档案不受影响，可通过 `link_pet` 重新关联
+
+use crate::pallet::{Config, Pallet};
+use frame_support::{
+    migrations::VersionedMigration,
+    storage::migration::clear_storage_prefix,
+    traits::{Get, PalletInfoAccess, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+};
+
+/// 函数级中文注释：v0 → v1 迁移（仅在链上存储版本为 0 时执行，执行后置为 1）
+pub type MigrateV0ToV1<T> = VersionedMigration<
+    0,
+    1,
+    v1::UncheckedMigrateV0ToV1<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+pub mod v1 {
+    use super::*;
+
+    /// 需要清空的 v0 存储项
+    pub const STALE_STORAGE: &[u8] = b"PetInGrave";
+
+    /// 函数级中文注释：清空宠物 → 墓位的旧存储前缀（不检查版本，由 `MigrateV0ToV1` 包装）
+    pub struct UncheckedMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let pallet = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+            let res = clear_storage_prefix(pallet, STALE_STORAGE, b"", None, None);
+            T::DbWeight::get().reads_writes(res.loops as u64, res.unique as u64)
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! 函数级中文注释：pallet-stardust-pet 的 Mock Runtime，用于单元测试
+//!
+//! - 逝者 10 的 owner 为 `OWNER`；纪念空间 20 的所有者为 `OWNER`、管理员为 `SPACE_ADMIN`
+//! - IPFS pin 记录到 `Pinned`，`PinFails` 为 true 时返回错误
+//! - 治理起源为 Root（与 runtime 申诉路由一致）
+
+use crate as pallet_stardust_pet;
+use frame_support::{derive_impl, parameter_types, traits::ConstU32};
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+/// 宠物主人 / 逝者 10 的 owner / 空间 20 的所有者
+pub const OWNER: u64 = 1;
+/// 其他账户
+pub const OTHER: u64 = 2;
+/// 纪念空间 20 的管理员
+pub const SPACE_ADMIN: u64 = 3;
+/// 逝者ID
+pub const DECEASED: u64 = 10;
+/// 纪念空间ID
+pub const SPACE: u64 = 20;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Pet: pallet_stardust_pet,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+parameter_types! {
+    /// 已 pin 的（宠物ID, CID）
+    pub static Pinned: Vec<(u64, Vec<u8>)> = Vec::new();
+    /// pin 是否失败
+    pub static PinFails: bool = false;
+}
+
+/// 函数级中文注释：关联目标 mock（逝者 10 归 OWNER；空间 20 归 OWNER，SPACE_ADMIN 为管理员）
+pub struct MockLinkTargets;
+impl pallet_stardust_pet::PetLinkTargets<u64> for MockLinkTargets {
+    fn can_link_deceased(who: &u64, deceased_id: u64) -> bool {
+        deceased_id == DECEASED && *who == OWNER
+    }
+    fn can_link_space(who: &u64, space_id: u64) -> bool {
+        space_id == SPACE && (*who == OWNER || *who == SPACE_ADMIN)
+    }
+}
+
+/// 函数级中文注释：IPFS pin mock
+pub struct MockIpfsPinner;
+impl pallet_stardust_ipfs::IpfsPinner<u64, u64> for MockIpfsPinner {
+    fn pin_cid_for_deceased(
+        _caller: u64,
+        _deceased_id: u64,
+        _cid: Vec<u8>,
+        _tier: Option<pallet_stardust_ipfs::PinTier>,
+    ) -> sp_runtime::DispatchResult {
+        Ok(())
+    }
+    fn pin_cid_for_pet(
+        _caller: u64,
+        pet_id: u64,
+        cid: Vec<u8>,
+        _tier: Option<pallet_stardust_ipfs::PinTier>,
+    ) -> sp_runtime::DispatchResult {
+        if PinFails::get() {
+            return Err(sp_runtime::DispatchError::Other("PinFailed"));
+        }
+        Pinned::mutate(|p| p.push((pet_id, cid)));
+        Ok(())
+    }
+}
+
+impl pallet_stardust_pet::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type StringLimit = ConstU32<32>;
+    type MaxCidLen = ConstU32<64>;
+    type MaxMediaPerPet = ConstU32<2>;
+    type MaxPetsPerDeceased = ConstU32<2>;
+    type LinkTargets = MockLinkTargets;
+    type Balance = u64;
+    type IpfsPinner = MockIpfsPinner;
+    type GovernanceOrigin = frame_system::EnsureRoot<u64>;
+}
+
+/// 函数级中文注释：构建测试环境（区块号设为 1 以记录事件）
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,369 @@
+//! 函数级中文注释：pallet-stardust-pet 单元测试
+
+use crate::{
+    mock::*, Error, Event, MediaByPet, PetAccess, PetLink, PetLinkOf, PetMediaKind, PetMediaOf,
+    PetOf, PetProfileOf, PetVisibilityOf, PetsByDeceased,
+};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+/// 辅助函数：`who` 创建一只宠物，返回宠物ID
+fn create_pet(who: u64) -> u64 {
+    let pet_id = crate::NextPetId::<Test>::get();
+    assert_ok!(Pet::create_pet(
+        RuntimeOrigin::signed(who),
+        b"Lucky".to_vec(),
+        b"dog".to_vec(),
+        b"PET-DOG-001".to_vec(),
+    ));
+    pet_id
+}
+
+/// 辅助函数：宠物 owner 添加一条照片，返回媒体ID
+fn add_photo(pet_id: u64) -> u64 {
+    let media_id = crate::NextPetMediaId::<Test>::get();
+    assert_ok!(Pet::add_pet_media(
+        RuntimeOrigin::signed(OWNER),
+        pet_id,
+        0,
+        b"QmPhoto".to_vec(),
+        b"title".to_vec(),
+    ));
+    media_id
+}
+
+/// 与 runtime `PetTargetAdapter::is_accessible` 相同的供奉/关注门槛
+fn accessible(who: u64, pet_id: u64) -> bool {
+    Pet::can_manage(&who, pet_id) || Pet::is_visible(pet_id)
+}
+
+// ==================== 创建与关联 ====================
+
+#[test]
+fn create_pet_sets_owner() {
+    new_test_ext().execute_with(|| {
+        let pet_id = create_pet(OWNER);
+        let pet = PetOf::<Test>::get(pet_id).unwrap();
+        assert_eq!(pet.owner, OWNER);
+        assert_eq!(pet.name.to_vec(), b"Lucky".to_vec());
+        assert_eq!(Pet::pet_owner(pet_id), Some(OWNER));
+        System::assert_last_event(Event::PetCreated(pet_id, OWNER).into());
+
+        assert_noop!(
+            Pet::create_pet(RuntimeOrigin::signed(OWNER), vec![b'x'; 33], b"dog".to_vec(), vec![]),
+            Error::<Test>::BadInput
+        );
+    });
+}
+
+#[test]
+fn link_pet_requires_pet_owner_and_target_permission() {
+    new_test_ext().execute_with(|| {
+        let pet_id = create_pet(OWNER);
+        assert_noop!(
+            Pet::link_pet(RuntimeOrigin::signed(OTHER), pet_id, PetLink::Deceased(DECEASED)),
+            Error::<Test>::NotOwner
+        );
+        assert_noop!(
+            Pet::link_pet(RuntimeOrigin::signed(OWNER), 99, PetLink::Deceased(DECEASED)),
+            Error::<Test>::NotFound
+        );
+        // 不是该逝者的 owner / 空间不存在
+        assert_noop!(
+            Pet::link_pet(RuntimeOrigin::signed(OWNER), pet_id, PetLink::Deceased(11)),
+            Error::<Test>::LinkNotAllowed
+        );
+        assert_noop!(
+            Pet::link_pet(RuntimeOrigin::signed(OWNER), pet_id, PetLink::Space(21)),
+            Error::<Test>::LinkNotAllowed
+        );
+
+        assert_ok!(Pet::link_pet(RuntimeOrigin::signed(OWNER), pet_id, PetLink::Deceased(DECEASED)));
+        assert_eq!(PetLinkOf::<Test>::get(pet_id), Some(PetLink::Deceased(DECEASED)));
+        assert_eq!(PetsByDeceased::<Test>::get(DECEASED).into_inner(), vec![pet_id]);
+        System::assert_last_event(Event::PetLinked(pet_id, PetLink::Deceased(DECEASED)).into());
+    });
+}
+
+#[test]
+fn space_admin_can_link_own_pet_but_not_to_deceased() {
+    new_test_ext().execute_with(|| {
+        let pet_id = create_pet(SPACE_ADMIN);
+        assert_noop!(
+            Pet::link_pet(RuntimeOrigin::signed(SPACE_ADMIN), pet_id, PetLink::Deceased(DECEASED)),
+            Error::<Test>::LinkNotAllowed
+        );
+        assert_ok!(Pet::link_pet(RuntimeOrigin::signed(SPACE_ADMIN), pet_id, PetLink::Space(SPACE)));
+        assert_eq!(PetLinkOf::<Test>::get(pet_id), Some(PetLink::Space(SPACE)));
+        assert!(PetsByDeceased::<Test>::get(DECEASED).is_empty());
+    });
+}
+
+#[test]
+fn relinking_moves_deceased_index() {
+    new_test_ext().execute_with(|| {
+        let pet_id = create_pet(OWNER);
+        assert_ok!(Pet::link_pet(RuntimeOrigin::signed(OWNER), pet_id, PetLink::Deceased(DECEASED)));
+        assert_ok!(Pet::link_pet(RuntimeOrigin::signed(OWNER), pet_id, PetLink::Space(SPACE)));
+
+        assert_eq!(PetLinkOf::<Test>::get(pet_id), Some(PetLink::Space(SPACE)));
+        assert!(!PetsByDeceased::<Test>::contains_key(DECEASED));
+    });
+}
+
+#[test]
+fn pets_per_deceased_are_capped() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..2 {
+            let pet_id = create_pet(OWNER);
+            assert_ok!(Pet::link_pet(RuntimeOrigin::signed(OWNER), pet_id, PetLink::Deceased(DECEASED)));
+        }
+        let pet_id = create_pet(OWNER);
+        assert_noop!(
+            Pet::link_pet(RuntimeOrigin::signed(OWNER), pet_id, PetLink::Deceased(DECEASED)),
+            Error::<Test>::TooManyPets
+        );
+        // 已关联的宠物改挂不占新名额
+        assert_ok!(Pet::link_pet(RuntimeOrigin::signed(OWNER), 0, PetLink::Deceased(DECEASED)));
+        assert_eq!(PetsByDeceased::<Test>::get(DECEASED).into_inner(), vec![1, 0]);
+    });
+}
+
+#[test]
+fn unlink_pet_requires_owner_and_clears_index() {
+    new_test_ext().execute_with(|| {
+        let pet_id = create_pet(OWNER);
+        assert_noop!(Pet::unlink_pet(RuntimeOrigin::signed(OWNER), pet_id), Error::<Test>::NotLinked);
+
+        assert_ok!(Pet::link_pet(RuntimeOrigin::signed(OWNER), pet_id, PetLink::Deceased(DECEASED)));
+        assert_noop!(Pet::unlink_pet(RuntimeOrigin::signed(OTHER), pet_id), Error::<Test>::NotOwner);
+
+        assert_ok!(Pet::unlink_pet(RuntimeOrigin::signed(OWNER), pet_id));
+        assert!(PetLinkOf::<Test>::get(pet_id).is_none());
+        assert!(!PetsByDeceased::<Test>::contains_key(DECEASED));
+        System::assert_last_event(Event::PetUnlinked(pet_id).into());
+    });
+}
+
+#[test]
+fn transfer_pet_moves_management_rights() {
+    new_test_ext().execute_with(|| {
+        let pet_id = create_pet(OWNER);
+        assert_noop!(Pet::transfer_pet(RuntimeOrigin::signed(OTHER), pet_id, OTHER), Error::<Test>::NotOwner);
+        assert_noop!(Pet::transfer_pet(RuntimeOrigin::signed(OWNER), pet_id, OWNER), Error::<Test>::BadInput);
+
+        assert_ok!(Pet::transfer_pet(RuntimeOrigin::signed(OWNER), pet_id, OTHER));
+        assert!(Pet::can_manage(&OTHER, pet_id));
+        assert!(!Pet::can_manage(&OWNER, pet_id));
+        System::assert_last_event(Event::PetTransferred(pet_id, OWNER, OTHER).into());
+        assert_noop!(
+            Pet::set_pet_visibility(RuntimeOrigin::signed(OWNER), pet_id, false),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+// ==================== 可见性 ====================
+
+#[test]
+fn visibility_defaults_to_public() {
+    new_test_ext().execute_with(|| {
+        let pet_id = create_pet(OWNER);
+        assert!(PetVisibilityOf::<Test>::get(pet_id).is_none());
+        assert!(Pet::is_visible(pet_id));
+        assert!(accessible(OTHER, pet_id));
+        // 不存在的宠物不可见
+        assert!(!Pet::pet_exists(99));
+        assert!(!Pet::is_visible(99));
+        assert!(!accessible(OTHER, 99));
+    });
+}
+
+#[test]
+fn hidden_pet_is_only_accessible_to_owner() {
+    new_test_ext().execute_with(|| {
+        let pet_id = create_pet(OWNER);
+        assert_noop!(
+            Pet::set_pet_visibility(RuntimeOrigin::signed(OTHER), pet_id, false),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(Pet::set_pet_visibility(RuntimeOrigin::signed(OWNER), pet_id, false));
+        System::assert_last_event(Event::PetVisibilityChanged(pet_id, false).into());
+
+        assert!(!Pet::is_visible(pet_id));
+        assert!(accessible(OWNER, pet_id));
+        assert!(!accessible(OTHER, pet_id));
+
+        assert_ok!(Pet::set_pet_visibility(RuntimeOrigin::signed(OWNER), pet_id, true));
+        assert!(accessible(OTHER, pet_id));
+    });
+}
+
+// ==================== 档案与媒体 ====================
+
+#[test]
+fn profile_update_validates_dates_and_pins_bio() {
+    new_test_ext().execute_with(|| {
+        let pet_id = create_pet(OWNER);
+        assert_noop!(
+            Pet::update_pet_profile(RuntimeOrigin::signed(OWNER), pet_id, Some(b"2020-1-1".to_vec()), None, None),
+            Error::<Test>::BadInput
+        );
+        assert_ok!(Pet::set_pet_main_image(RuntimeOrigin::signed(OWNER), pet_id, Some(b"QmMain".to_vec())));
+        assert_ok!(Pet::update_pet_profile(
+            RuntimeOrigin::signed(OWNER),
+            pet_id,
+            Some(b"20100101".to_vec()),
+            Some(b"20230501".to_vec()),
+            Some(b"QmBio".to_vec()),
+        ));
+
+        let profile = PetProfileOf::<Test>::get(pet_id).unwrap();
+        assert_eq!(profile.death.unwrap().to_vec(), b"20230501".to_vec());
+        // 主图不受档案更新影响
+        assert_eq!(profile.main_image_cid.unwrap().to_vec(), b"QmMain".to_vec());
+        assert_eq!(Pinned::get(), vec![(pet_id, b"QmMain".to_vec()), (pet_id, b"QmBio".to_vec())]);
+    });
+}
+
+#[test]
+fn media_is_capped_and_pin_failure_does_not_revert() {
+    new_test_ext().execute_with(|| {
+        let pet_id = create_pet(OWNER);
+        assert_noop!(
+            Pet::add_pet_media(RuntimeOrigin::signed(OWNER), pet_id, 4, b"Qm".to_vec(), vec![]),
+            Error::<Test>::BadInput
+        );
+        assert_noop!(
+            Pet::add_pet_media(RuntimeOrigin::signed(OTHER), pet_id, 0, b"Qm".to_vec(), vec![]),
+            Error::<Test>::NotOwner
+        );
+
+        let media_id = add_photo(pet_id);
+        assert_eq!(PetMediaOf::<Test>::get(media_id).unwrap().kind, PetMediaKind::Photo);
+
+        PinFails::set(true);
+        assert_ok!(Pet::add_pet_media(RuntimeOrigin::signed(OWNER), pet_id, 3, b"QmText".to_vec(), vec![]));
+        System::assert_last_event(Event::PetMediaAdded(pet_id, media_id + 1).into());
+        System::assert_has_event(
+            Event::PetAutoPinFailed(pet_id, b"QmText".to_vec().try_into().unwrap()).into(),
+        );
+
+        assert_noop!(
+            Pet::add_pet_media(RuntimeOrigin::signed(OWNER), pet_id, 0, b"Qm".to_vec(), vec![]),
+            Error::<Test>::TooManyMedia
+        );
+
+        assert_noop!(Pet::remove_pet_media(RuntimeOrigin::signed(OTHER), media_id), Error::<Test>::NotOwner);
+        assert_ok!(Pet::remove_pet_media(RuntimeOrigin::signed(OWNER), media_id));
+        assert_eq!(MediaByPet::<Test>::get(pet_id).into_inner(), vec![media_id + 1]);
+    });
+}
+
+// ==================== 治理（申诉域 13 / 14） ====================
+
+#[test]
+fn gov_actions_require_governance_origin() {
+    new_test_ext().execute_with(|| {
+        let pet_id = create_pet(OWNER);
+        let media_id = add_photo(pet_id);
+        assert_noop!(
+            Pet::gov_set_pet_visibility(RuntimeOrigin::signed(OWNER), pet_id, false, vec![]),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Pet::gov_clear_pet_main_image(RuntimeOrigin::signed(OWNER), pet_id, vec![]),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Pet::gov_remove_pet_media(RuntimeOrigin::signed(OWNER), media_id, vec![]),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Pet::gov_transfer_pet(RuntimeOrigin::signed(OWNER), pet_id, OTHER, vec![]),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn domain_13_hides_restores_and_clears_main_image() {
+    new_test_ext().execute_with(|| {
+        let pet_id = create_pet(OWNER);
+        assert_ok!(Pet::set_pet_main_image(RuntimeOrigin::signed(OWNER), pet_id, Some(b"QmMain".to_vec())));
+
+        // 与申诉路由一致：Root 起源、空证据
+        assert_noop!(
+            Pet::gov_set_pet_visibility(RuntimeOrigin::root(), 99, false, vec![]),
+            Error::<Test>::NotFound
+        );
+        // 动作 1：隐藏
+        assert_ok!(Pet::gov_set_pet_visibility(RuntimeOrigin::root(), pet_id, false, vec![]));
+        assert!(!accessible(OTHER, pet_id));
+        // 动作 2：恢复可见
+        assert_ok!(Pet::gov_set_pet_visibility(RuntimeOrigin::root(), pet_id, true, b"QmEvidence".to_vec()));
+        assert!(accessible(OTHER, pet_id));
+        System::assert_has_event(
+            Event::GovEvidenceNoted(pet_id, b"QmEvidence".to_vec().try_into().unwrap()).into(),
+        );
+        // 动作 3：清空主图
+        assert_ok!(Pet::gov_clear_pet_main_image(RuntimeOrigin::root(), pet_id, vec![]));
+        assert!(PetProfileOf::<Test>::get(pet_id).unwrap().main_image_cid.is_none());
+        System::assert_last_event(Event::PetMainImageSet(pet_id, false).into());
+    });
+}
+
+#[test]
+fn domain_14_removes_media() {
+    new_test_ext().execute_with(|| {
+        let pet_id = create_pet(OWNER);
+        let media_id = add_photo(pet_id);
+
+        assert_ok!(Pet::gov_remove_pet_media(RuntimeOrigin::root(), media_id, vec![]));
+        assert!(PetMediaOf::<Test>::get(media_id).is_none());
+        assert!(MediaByPet::<Test>::get(pet_id).is_empty());
+        System::assert_last_event(Event::PetMediaRemoved(pet_id, media_id).into());
+
+        assert_noop!(
+            Pet::gov_remove_pet_media(RuntimeOrigin::root(), media_id, vec![]),
+            Error::<Test>::MediaNotFound
+        );
+    });
+}
+
+#[test]
+fn gov_transfer_pet_reassigns_owner() {
+    new_test_ext().execute_with(|| {
+        let pet_id = create_pet(OWNER);
+        assert_ok!(Pet::gov_transfer_pet(RuntimeOrigin::root(), pet_id, OTHER, vec![]));
+        assert_eq!(Pet::pet_owner(pet_id), Some(OTHER));
+        System::assert_last_event(Event::PetTransferred(pet_id, OWNER, OTHER).into());
+    });
+}
+
+#[test]
+fn migrate_v0_to_v1_clears_pet_in_grave() {
+    use frame_support::storage::unhashed;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        // 写入 v0 时期宠物 → 墓位的旧键
+        let mut stale = frame_support::storage::storage_prefix(b"Pet", b"PetInGrave").to_vec();
+        stale.extend_from_slice(&[1u8; 24]);
+        unhashed::put_raw(&stale, &[7u8; 8]);
+        // 宠物档案不受影响
+        let pet_id = create_pet(OWNER);
+        StorageVersion::new(0).put::<Pet>();
+
+        crate::migrations::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert!(unhashed::get_raw(&stale).is_none());
+        assert!(PetOf::<Test>::get(pet_id).is_some());
+        assert_eq!(Pet::on_chain_storage_version(), 1);
+
+        // 再次执行为空操作
+        unhashed::put_raw(&stale, &[7u8; 8]);
+        crate::migrations::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert!(unhashed::get_raw(&stale).is_some());
+    });
+}
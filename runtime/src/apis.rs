@@ -44,7 +44,7 @@ use pallet_chat_permission::FriendshipChecker;
 
 // Local module imports
 use super::{
-    AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
+    AccountId, Aura, Balance, Block, BlockNumber, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
     RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
     ChatPermission,
 };
@@ -1060,4 +1060,493 @@ impl_runtime_apis! {
             pallet_tarot::Pallet::<Runtime>::api_get_timeline_analysis(reading_id)
         }
     }
+
+    // ========= Ziwei (紫微斗数) Runtime API =========
+    /// 函数级详细中文注释：紫微斗数解卦 Runtime API 实现
+    ///
+    /// ### 功能说明
+    /// - 基于链上命盘实时计算解卦，不存储解卦结果
+    /// - Private 模式命盘无计算数据，解卦类接口返回 None，需前端解密后调用 `compute_chart`
+    ///
+    /// ### 接口分组
+    /// - 核心：完整解卦、整体评分
+    /// - 宫位：单宫解读、详细文本、十二宫解读
+    /// - 格局：格局列表、格局说明、格局检测
+    /// - 四化：四化分析、宫干四化飞入
+    /// - 运势：大限、流年、多年趋势（最多 20 年）
+    /// - 隐私：加密数据、密钥备份、临时排盘、公开元数据
+    impl pallet_ziwei::runtime_api::ZiweiInterpretationApi<Block> for Runtime {
+        fn get_interpretation(
+            chart_id: u64,
+        ) -> Option<pallet_ziwei::interpretation::ZiweiInterpretation> {
+            pallet_ziwei::Pallet::<Runtime>::api_get_interpretation(chart_id)
+        }
+
+        fn get_overall_score(
+            chart_id: u64,
+        ) -> Option<pallet_ziwei::interpretation::ChartOverallScore> {
+            pallet_ziwei::Pallet::<Runtime>::api_get_interpretation(chart_id)
+                .map(|i| i.overall_score)
+        }
+
+        fn get_palace_interpretation(
+            chart_id: u64,
+            gong_wei: pallet_ziwei::types::GongWei,
+        ) -> Option<pallet_ziwei::interpretation::PalaceInterpretation> {
+            pallet_ziwei::Pallet::<Runtime>::api_get_interpretation(chart_id)?
+                .palace_interpretations
+                .into_iter()
+                .find(|p| p.gong_wei == gong_wei)
+        }
+
+        fn get_palace_detail(
+            chart_id: u64,
+            gong_wei: pallet_ziwei::types::GongWei,
+        ) -> Option<pallet_ziwei::runtime_api::PalaceDetailText> {
+            pallet_ziwei::Pallet::<Runtime>::api_get_palace_detail(chart_id, gong_wei)
+        }
+
+        fn get_all_palace_interpretations(
+            chart_id: u64,
+        ) -> Option<[pallet_ziwei::interpretation::PalaceInterpretation; 12]> {
+            pallet_ziwei::Pallet::<Runtime>::api_get_interpretation(chart_id)
+                .map(|i| i.palace_interpretations)
+        }
+
+        fn get_patterns(
+            chart_id: u64,
+        ) -> Option<Vec<pallet_ziwei::interpretation::PatternInfo>> {
+            pallet_ziwei::Pallet::<Runtime>::api_get_interpretation(chart_id)
+                .map(|i| i.patterns.into_inner())
+        }
+
+        fn get_pattern_detail(
+            pattern_type: pallet_ziwei::interpretation::PatternType,
+        ) -> pallet_ziwei::runtime_api::PatternDetailText {
+            pallet_ziwei::runtime_api::generate_pattern_detail_text(pattern_type)
+        }
+
+        fn has_pattern(
+            chart_id: u64,
+            pattern_type: pallet_ziwei::interpretation::PatternType,
+        ) -> Option<(bool, u8)> {
+            pallet_ziwei::Pallet::<Runtime>::api_has_pattern(chart_id, pattern_type)
+        }
+
+        fn get_si_hua_analysis(
+            chart_id: u64,
+        ) -> Option<pallet_ziwei::interpretation::SiHuaAnalysis> {
+            pallet_ziwei::Pallet::<Runtime>::api_get_interpretation(chart_id)
+                .map(|i| i.si_hua_analysis)
+        }
+
+        fn get_palace_si_hua(
+            chart_id: u64,
+            palace_idx: u8,
+        ) -> Option<([pallet_ziwei::types::SiHuaStar; 4], [u8; 4])> {
+            pallet_ziwei::Pallet::<Runtime>::api_get_palace_si_hua(chart_id, palace_idx)
+        }
+
+        fn get_da_xian_interpretation(
+            chart_id: u64,
+            da_xian_index: u8,
+        ) -> Option<pallet_ziwei::interpretation::DaXianInterpretation> {
+            pallet_ziwei::Pallet::<Runtime>::api_get_da_xian_interpretation(chart_id, da_xian_index)
+        }
+
+        fn get_da_xian_detail(
+            chart_id: u64,
+            da_xian_index: u8,
+        ) -> Option<pallet_ziwei::runtime_api::DaXianDetailText> {
+            pallet_ziwei::Pallet::<Runtime>::api_get_da_xian_detail(chart_id, da_xian_index)
+        }
+
+        fn get_current_da_xian(
+            chart_id: u64,
+            age: u8,
+        ) -> Option<pallet_ziwei::interpretation::DaXianInterpretation> {
+            pallet_ziwei::Pallet::<Runtime>::api_get_current_da_xian(chart_id, age)
+        }
+
+        fn get_liu_nian_fortune(
+            chart_id: u64,
+            year: u16,
+        ) -> Option<pallet_ziwei::runtime_api::LiuNianFortune> {
+            pallet_ziwei::Pallet::<Runtime>::api_get_liu_nian_fortune(chart_id, year)
+        }
+
+        fn get_fortune_trend(
+            chart_id: u64,
+            start_year: u16,
+            end_year: u16,
+        ) -> Vec<pallet_ziwei::runtime_api::LiuNianFortune> {
+            pallet_ziwei::Pallet::<Runtime>::api_get_fortune_trend(chart_id, start_year, end_year)
+        }
+
+        fn get_interpretations_batch(
+            chart_ids: Vec<u64>,
+        ) -> Vec<Option<pallet_ziwei::interpretation::ZiweiInterpretation>> {
+            pallet_ziwei::Pallet::<Runtime>::api_get_interpretations_batch(chart_ids)
+        }
+
+        fn get_encrypted_data(chart_id: u64) -> Option<Vec<u8>> {
+            pallet_ziwei::EncryptedDataStorage::<Runtime>::get(chart_id).map(|d| d.into_inner())
+        }
+
+        fn get_owner_key_backup(chart_id: u64) -> Option<[u8; 80]> {
+            pallet_ziwei::OwnerKeyBackupStorage::<Runtime>::get(chart_id)
+        }
+
+        fn compute_chart(
+            lunar_year: u16,
+            lunar_month: u8,
+            lunar_day: u8,
+            birth_hour: u8,
+            gender: u8,
+            is_leap_month: bool,
+        ) -> Option<pallet_ziwei::runtime_api::ZiweiChartResult> {
+            pallet_ziwei::runtime_api::compute_chart_result(
+                lunar_year,
+                lunar_month,
+                lunar_day,
+                birth_hour,
+                gender,
+                is_leap_month,
+            )
+        }
+
+        fn get_public_metadata(
+            chart_id: u64,
+        ) -> Option<pallet_ziwei::runtime_api::ZiweiPublicMetadata> {
+            pallet_ziwei::Pallet::<Runtime>::api_get_public_metadata(chart_id)
+        }
+    }
+
+    // ========= Meihua (梅花易数) Runtime API =========
+    /// 函数级详细中文注释：梅花易数 Runtime API 实现
+    ///
+    /// ### 接口列表
+    /// - `get_hexagram_detail`: 完整排盘（本卦、变卦、互卦、错卦、综卦、伏卦）
+    /// - `calculate_hexagram_detail`: 按卦数临时排盘（不存储）
+    /// - `get_interpretation_data`: 解卦核心数据
+    /// - `get_ai_interpretation`: AI 解读结果
+    /// - `hexagram_exists` / `get_hexagram_owner`: 存在性与创建者
+    /// - `get_user_hexagrams` / `get_public_hexagrams`: 卦象列表
+    impl pallet_meihua::runtime_api::MeihuaApi<Block, AccountId> for Runtime {
+        fn get_hexagram_detail(
+            hexagram_id: u64,
+        ) -> Option<pallet_meihua::types::FullDivinationDetail> {
+            pallet_meihua::Pallet::<Runtime>::get_hexagram_detail(hexagram_id)
+        }
+
+        fn calculate_hexagram_detail(
+            shang_gua_num: u8,
+            xia_gua_num: u8,
+            dong_yao: u8,
+        ) -> pallet_meihua::types::FullDivinationDetail {
+            pallet_meihua::Pallet::<Runtime>::calculate_hexagram_detail(shang_gua_num, xia_gua_num, dong_yao)
+        }
+
+        fn get_interpretation_data(
+            hexagram_id: u64,
+        ) -> Option<pallet_meihua::interpretation::InterpretationData> {
+            pallet_meihua::Pallet::<Runtime>::get_interpretation_data(hexagram_id)
+        }
+
+        fn get_ai_interpretation(
+            hexagram_id: u64,
+        ) -> Option<pallet_meihua::interpretation::AiInterpretationResult> {
+            pallet_meihua::Pallet::<Runtime>::get_ai_interpretation(hexagram_id)
+        }
+
+        fn hexagram_exists(hexagram_id: u64) -> bool {
+            pallet_meihua::Hexagrams::<Runtime>::contains_key(hexagram_id)
+        }
+
+        fn get_hexagram_owner(hexagram_id: u64) -> Option<AccountId> {
+            pallet_meihua::Pallet::<Runtime>::get_hexagram_owner(hexagram_id)
+        }
+
+        fn get_user_hexagrams(account: AccountId) -> Vec<u64> {
+            pallet_meihua::UserHexagrams::<Runtime>::get(account).into_inner()
+        }
+
+        fn get_public_hexagrams() -> Vec<u64> {
+            pallet_meihua::PublicHexagrams::<Runtime>::get().into_inner()
+        }
+    }
+
+    // ========= Daliuren (大六壬) Runtime API =========
+    /// 函数级详细中文注释：大六壬解盘 Runtime API 实现
+    ///
+    /// ### 接口列表
+    /// - `get_core_interpretation`: 核心解盘（约 20 bytes）
+    /// - `get_full_interpretation`: 完整解盘（三传/四课/天将/神煞/应期）
+    /// - `get_san_chuan_analysis` / `get_ying_qi_analysis`: 分项分析（Private 模式返回 None）
+    /// - `pan_exists` / `get_pan_owner`: 存在性与创建者
+    /// - `get_ai_interpretation_cid`: AI 解读 CID
+    /// - `get_encrypted_data` / `get_owner_key_backup`: 隐私模式数据
+    impl pallet_daliuren::runtime_api::DaLiuRenApi<Block, AccountId> for Runtime {
+        fn get_core_interpretation(pan_id: u64) -> Option<pallet_daliuren::CoreInterpretation> {
+            pallet_daliuren::Pallet::<Runtime>::get_core_interpretation(pan_id)
+        }
+
+        fn get_full_interpretation(
+            pan_id: u64,
+            shi_xiang_type: Option<pallet_daliuren::ShiXiangType>,
+        ) -> Option<pallet_daliuren::FullInterpretation> {
+            pallet_daliuren::Pallet::<Runtime>::get_full_interpretation(pan_id, shi_xiang_type)
+        }
+
+        fn get_san_chuan_analysis(pan_id: u64) -> Option<pallet_daliuren::SanChuanAnalysis> {
+            pallet_daliuren::Pallet::<Runtime>::get_san_chuan_analysis(pan_id)
+        }
+
+        fn get_ying_qi_analysis(
+            pan_id: u64,
+            shi_xiang_type: Option<pallet_daliuren::ShiXiangType>,
+        ) -> Option<pallet_daliuren::YingQiAnalysis> {
+            pallet_daliuren::Pallet::<Runtime>::get_ying_qi_analysis(pan_id, shi_xiang_type)
+        }
+
+        fn pan_exists(pan_id: u64) -> bool {
+            pallet_daliuren::Pans::<Runtime>::contains_key(pan_id)
+        }
+
+        fn get_pan_owner(pan_id: u64) -> Option<AccountId> {
+            pallet_daliuren::Pallet::<Runtime>::get_pan_owner(pan_id)
+        }
+
+        fn get_ai_interpretation_cid(pan_id: u64) -> Option<Vec<u8>> {
+            pallet_daliuren::Pallet::<Runtime>::get_ai_interpretation_cid(pan_id)
+        }
+
+        fn get_encrypted_data(pan_id: u64) -> Option<Vec<u8>> {
+            pallet_daliuren::EncryptedDataStorage::<Runtime>::get(pan_id).map(|d| d.into_inner())
+        }
+
+        fn get_owner_key_backup(pan_id: u64) -> Option<[u8; 80]> {
+            pallet_daliuren::OwnerKeyBackupStorage::<Runtime>::get(pan_id)
+        }
+    }
+
+    // ========= Divination Privacy (统一隐私授权) Runtime API =========
+    /// 函数级详细中文注释：统一隐私授权 Runtime API 实现
+    ///
+    /// ### 功能说明
+    /// - 占卜类型以 u8 传入（与 `DivinationType` 编码一致），无效编码返回空结果
+    /// - 账户地址在返回结构中为 SCALE 编码字节
+    ///
+    /// ### 接口分组
+    /// - 用户密钥、服务提供者、加密记录、授权、悬赏授权
+    impl pallet_divination_privacy::runtime_api::DivinationPrivacyApi<Block, AccountId, BlockNumber> for Runtime {
+        fn get_user_encryption_key(account: AccountId) -> Option<[u8; 32]> {
+            pallet_divination_privacy::UserEncryptionKeys::<Runtime>::get(account).map(|info| info.public_key)
+        }
+
+        fn has_encryption_key(account: AccountId) -> bool {
+            pallet_divination_privacy::UserEncryptionKeys::<Runtime>::contains_key(account)
+        }
+
+        fn get_service_provider(
+            account: AccountId,
+        ) -> Option<pallet_divination_privacy::types::ServiceProviderInfo> {
+            pallet_divination_privacy::Pallet::<Runtime>::api_get_service_provider(account)
+        }
+
+        fn get_providers_by_type(provider_type: u8) -> Vec<AccountId> {
+            pallet_divination_privacy::Pallet::<Runtime>::api_get_providers_by_type(provider_type)
+        }
+
+        fn is_active_provider(account: AccountId) -> bool {
+            pallet_divination_privacy::ServiceProviders::<Runtime>::get(account)
+                .map(|p| p.is_active)
+                .unwrap_or(false)
+        }
+
+        fn get_encrypted_record_info(
+            divination_type: u8,
+            result_id: u64,
+        ) -> Option<pallet_divination_privacy::types::EncryptedRecordInfo> {
+            pallet_divination_privacy::Pallet::<Runtime>::api_get_encrypted_record_info(divination_type, result_id)
+        }
+
+        fn is_encrypted_record(divination_type: u8, result_id: u64) -> bool {
+            pallet_divination_common::DivinationType::from_u8(divination_type)
+                .map(|dt| pallet_divination_privacy::Pallet::<Runtime>::has_encrypted_record(dt, result_id))
+                .unwrap_or(false)
+        }
+
+        fn get_user_encrypted_records(
+            account: AccountId,
+            divination_type: Option<u8>,
+        ) -> Vec<u64> {
+            pallet_divination_privacy::Pallet::<Runtime>::api_get_user_encrypted_records(account, divination_type)
+        }
+
+        fn has_access(divination_type: u8, result_id: u64, account: AccountId) -> bool {
+            pallet_divination_privacy::Pallet::<Runtime>::api_has_access(divination_type, result_id, account)
+        }
+
+        fn get_authorizations(
+            divination_type: u8,
+            result_id: u64,
+        ) -> Vec<pallet_divination_privacy::types::AuthorizationInfo> {
+            pallet_divination_privacy::Pallet::<Runtime>::api_get_authorizations(divination_type, result_id)
+        }
+
+        fn get_provider_grants(account: AccountId) -> Vec<(u8, u64)> {
+            pallet_divination_privacy::Pallet::<Runtime>::api_get_provider_grants(account)
+        }
+
+        fn get_authorization_info(
+            divination_type: u8,
+            result_id: u64,
+            grantee: AccountId,
+        ) -> Option<pallet_divination_privacy::types::AuthorizationInfo> {
+            pallet_divination_privacy::Pallet::<Runtime>::api_get_authorization_info(divination_type, result_id, grantee)
+        }
+
+        fn get_bounty_authorization_status(
+            bounty_id: u64,
+        ) -> pallet_divination_privacy::types::BountyAuthorizationStatus {
+            pallet_divination_privacy::Pallet::<Runtime>::api_get_bounty_authorization_status(bounty_id)
+        }
+
+        fn bounty_requires_authorization(divination_type: u8, result_id: u64) -> bool {
+            pallet_divination_privacy::Pallet::<Runtime>::api_bounty_requires_authorization(divination_type, result_id)
+        }
+
+        fn get_bounty_authorized_answerers(bounty_id: u64) -> Vec<AccountId> {
+            pallet_divination_privacy::BountyAuthorizations::<Runtime>::get(bounty_id).into_inner()
+        }
+    }
+
+    // ========= Deceased Genealogy (族谱) Runtime API =========
+    /// 函数级详细中文注释：逝者族谱 Runtime API 实现
+    ///
+    /// ### 功能说明
+    /// - 基于已批准的亲属关系做多代祖先/后代遍历，深度与结果数量均有上限
+    /// - 共同祖先按双方代数之和由近到远排序
+    /// - `relations_of` 供 GEDCOM 导出工具逐人展开家庭记录
+    impl pallet_deceased::runtime_api::DeceasedGenealogyApi<Block, u64> for Runtime {
+        fn ancestors(id: u64, max_depth: u8) -> Vec<pallet_deceased::GenealogyNode<u64>> {
+            pallet_deceased::Pallet::<Runtime>::ancestors(id, max_depth)
+        }
+
+        fn descendants(id: u64, max_depth: u8) -> Vec<pallet_deceased::GenealogyNode<u64>> {
+            pallet_deceased::Pallet::<Runtime>::descendants(id, max_depth)
+        }
+
+        fn common_ancestors(
+            a: u64,
+            b: u64,
+            max_depth: u8,
+        ) -> Vec<pallet_deceased::CommonAncestor<u64>> {
+            pallet_deceased::Pallet::<Runtime>::common_ancestors(a, b, max_depth)
+        }
+
+        fn relations_of(id: u64) -> Vec<pallet_deceased::KinshipLink<u64>> {
+            pallet_deceased::Pallet::<Runtime>::relations_of(id)
+        }
+    }
+
+    /// 函数级中文注释：逝者纪念日历 Runtime API 实现
+    impl pallet_deceased::runtime_api::DeceasedRemembranceApi<Block, u64> for Runtime {
+        fn upcoming_remembrances(
+            id: u64,
+            within_days: u32,
+        ) -> Vec<pallet_deceased::UpcomingRemembrance> {
+            pallet_deceased::Pallet::<Runtime>::upcoming_remembrances(id, within_days)
+        }
+    }
+
+    /// 函数级中文注释：供奉台账 Runtime API 实现
+    /// - 周排行（全站 / 陵园 / 分类）、供奉回执与回执包含证明
+    impl pallet_ledger::runtime_api::LedgerApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        fn top_targets(
+            week: u64,
+            scope: pallet_ledger::RankScope,
+            limit: u32,
+        ) -> Vec<pallet_ledger::LeaderboardEntry<Balance>> {
+            pallet_ledger::Pallet::<Runtime>::top_targets(week, scope, limit)
+        }
+
+        fn receipt(offering_id: u64) -> Option<pallet_ledger::Receipt<AccountId, Balance, BlockNumber>> {
+            pallet_ledger::Receipts::<Runtime>::get(offering_id)
+        }
+
+        fn receipt_proof(offering_id: u64) -> Option<pallet_ledger::ReceiptProof> {
+            pallet_ledger::Pallet::<Runtime>::receipt_proof(offering_id)
+        }
+
+        fn week_root(week: u64) -> Option<(sp_core::H256, u32)> {
+            pallet_ledger::Pallet::<Runtime>::week_root(week)
+        }
+
+        fn current_week() -> u64 {
+            pallet_ledger::Pallet::<Runtime>::current_week_index()
+        }
+    }
+
+    // 🆕 陵园地图 Runtime API：分区、墓位与安葬对象定位
+    impl pallet_stardust_park::runtime_api::ParkMapApi<Block, AccountId, Balance> for Runtime {
+        fn park_zones(park_id: u64) -> Vec<pallet_stardust_park::ZoneView> {
+            pallet_stardust_park::Pallet::<Runtime>::park_zones(park_id)
+        }
+
+        fn zone_plots(park_id: u64, zone_id: u32) -> Vec<pallet_stardust_park::PlotView<AccountId, Balance>> {
+            pallet_stardust_park::Pallet::<Runtime>::zone_plots(park_id, zone_id)
+        }
+
+        fn plot(plot_id: u64) -> Option<pallet_stardust_park::PlotView<AccountId, Balance>> {
+            pallet_stardust_park::Pallet::<Runtime>::plot_view(plot_id)
+        }
+
+        fn plot_of(occupant: pallet_stardust_park::PlotOccupant) -> Option<u64> {
+            pallet_stardust_park::PlotOf::<Runtime>::get(occupant)
+        }
+
+        fn park_of(occupant: pallet_stardust_park::PlotOccupant) -> Option<u64> {
+            pallet_stardust_park::Pallet::<Runtime>::park_of_occupant(&occupant)
+        }
+    }
+
+    // 🆕 联盟计酬结算模拟 Runtime API：dry-run 周期结算与提案模拟摘要
+    impl pallet_affiliate::runtime_api::AffiliateSimulationApi<Block, AccountId, Balance> for Runtime {
+        fn simulate_settlement(
+            cycle: u32,
+            table: pallet_affiliate::types::PayoutTable,
+            percents: Option<pallet_affiliate::types::LevelPercents>,
+            max_accounts: u32,
+        ) -> pallet_affiliate::types::SettlementSimulation<AccountId, Balance> {
+            pallet_affiliate::Pallet::<Runtime>::simulate_settlement(cycle, table, percents, max_accounts)
+        }
+
+        fn proposal_simulation(proposal_id: u64) -> Option<pallet_affiliate::types::SimulationSummary<Balance>> {
+            pallet_affiliate::ProposalSimulations::<Runtime>::get(proposal_id)
+        }
+
+        fn current_cycle() -> u32 {
+            pallet_affiliate::Pallet::<Runtime>::current_cycle()
+        }
+    }
+
+    // 🆕 会员权益 Runtime API：账户当前生效权益与等级权益配置查询
+    impl pallet_membership::runtime_api::MembershipBenefitsApi<Block, AccountId, BlockNumber> for Runtime {
+        fn member_benefits(who: AccountId) -> pallet_membership::MemberBenefitsView<BlockNumber> {
+            pallet_membership::Pallet::<Runtime>::member_benefits(&who)
+        }
+
+        fn level_benefits(level_id: u8) -> Option<pallet_membership::BenefitConfig> {
+            let level = match level_id {
+                0 => pallet_membership::MembershipLevel::Year1,
+                1 => pallet_membership::MembershipLevel::Year3,
+                2 => pallet_membership::MembershipLevel::Year5,
+                3 => pallet_membership::MembershipLevel::Year10,
+                _ => return None,
+            };
+            Some(pallet_membership::Pallet::<Runtime>::level_benefits(level))
+        }
+    }
 }
@@ -134,6 +134,17 @@ impl pallet_stardust_appeals::Config for Runtime {
     /// - 用于押金系数计算（高信誉=低系数）
     /// - 占位实现：默认返回50（标准押金1.0x）
     type ReputationProvider = DefaultReputationProvider;
+
+    /// 函数级中文注释：🆕 申诉目标所有者（应答期反证提交人）
+    type TargetOwnerProvider = ContentTargetOwnerProvider;
+    /// 函数级中文注释：🆕 应答期：3 天
+    type ResponsePeriod = frame_support::traits::ConstU32<{ 3 * DAYS }>;
+    /// 函数级中文注释：🆕 驳回后可升级公投的窗口：7 天
+    type EscalationWindow = frame_support::traits::ConstU32<{ 7 * DAYS }>;
+    /// 函数级中文注释：🆕 升级公投提交器（pallet-referenda 申诉升级轨道）
+    type Escalation = AppealReferendumEscalation;
+    /// 函数级中文注释：🆕 委员会计票来源（内容委员会集体提案的 Voting 记录）
+    type DecisionTally = ContentCommitteeTally;
 }
 
 /// 函数级中文注释：内容治理申诉的动态押金策略实现（USD锚定版本）
@@ -196,6 +207,11 @@ impl pallet_stardust_appeals::AppealDepositPolicy for ContentAppealDepositPolicy
             (3, 22) | (3, 23) => 10000, // 1.0x
             (2, 1) | (2, 2) | (2, 3) => 10000, // 1.0x
             (2, 4) => 15000, // 治理转移拥有者 1.5x
+            (8, 1) | (10, 2) | (11, 1) => 15000, // 暂停大师/冻结NFT/暂停智能体 1.5x
+            (9, 1) | (10, 1) | (12, 1) => 10000, // 移除评价/下架NFT/禁言 1.0x
+            (13, 1) | (13, 2) | (13, 3) | (14, 1) => 10000, // 🆕 宠物隐藏/恢复/清主图、删除宠物媒体 1.0x
+            (15, 1) | (15, 2) => 15000, // 🆕 墓位持有人转让/收回 1.5x
+            (16, 1) | (16, 2) => 10000, // 🆕 女巫标记解除/确认 1.0x
             _ => return None, // 不支持的域/操作，回退到固定押金
         };
         
@@ -226,6 +242,97 @@ impl pallet_stardust_appeals::LastActiveProvider for ContentLastActiveProvider {
     }
 }
 
+/// 函数级详细中文注释：申诉目标所有者提供者实现（🆕 应答期）。
+/// - 2=逝者档案 owner；7=作品所属逝者 owner；8=订单对应大师；10=NFT 持有者；12=聊天用户账户；
+/// - 🆕 13=宠物 owner；14=宠物媒体所属宠物 owner；15=墓位持有人；
+/// - 其他域返回 None（不设应答期）。
+pub struct ContentTargetOwnerProvider;
+impl pallet_stardust_appeals::TargetOwnerProvider<AccountId> for ContentTargetOwnerProvider {
+    fn owner_of(domain: u8, target: u64) -> Option<AccountId> {
+        use pallet_stardust_appeals::WorksProvider;
+        match domain {
+            2 => pallet_deceased::pallet::DeceasedOf::<Runtime>::get(target).map(|d| d.owner),
+            7 => DeceasedWorksProvider::get_work_owner(target),
+            8 => pallet_divination_market::pallet::Orders::<Runtime>::get(target).map(|o| o.provider),
+            10 => pallet_divination_nft::pallet::Nfts::<Runtime>::get(target).map(|n| n.owner),
+            12 => pallet_chat::pallet::ChatUserIdToAccount::<Runtime>::get(target),
+            13 => pallet_stardust_pet::pallet::PetOf::<Runtime>::get(target).map(|p| p.owner),
+            14 => pallet_stardust_pet::pallet::PetMediaOf::<Runtime>::get(target)
+                .and_then(|m| pallet_stardust_pet::pallet::PetOf::<Runtime>::get(m.pet_id))
+                .map(|p| p.owner),
+            15 => pallet_stardust_park::pallet::Plots::<Runtime>::get(target).and_then(|p| p.holder),
+            16 => pallet_affiliate::pallet::SybilFlags::<Runtime>::get(target).map(|f| f.root),
+            _ => None,
+        }
+    }
+}
+
+/// 函数级详细中文注释：申诉升级公投实现（🆕）。
+/// - 以 `AppealEscalationSubmitter` 账户签名提交公投（提交押金为 0），提案为
+///   `ContentGovernance::finalize_escalation(appeal_id)`，以 Root 起源进入 `appeal_escalation` 轨道；
+/// - 决策押金由申诉人或任何支持者通过 `Referenda::place_decision_deposit` 缴纳，否则公投超时视为未通过；
+/// - 公投通过后执行提案即完成结算；未通过由任何人调用 `finalize_escalation` 结算。
+pub struct AppealReferendumEscalation;
+impl pallet_stardust_appeals::AppealEscalation<AccountId> for AppealReferendumEscalation {
+    fn escalate(appeal_id: u64, _who: &AccountId) -> Result<u32, sp_runtime::DispatchError> {
+        use frame_support::traits::StorePreimage;
+        let call = RuntimeCall::ContentGovernance(
+            pallet_stardust_appeals::Call::finalize_escalation { id: appeal_id },
+        );
+        let proposal = <crate::Preimage as StorePreimage>::bound(call)?;
+        let index = pallet_referenda::ReferendumCount::<Runtime>::get();
+        pallet_referenda::Pallet::<Runtime>::submit(
+            RuntimeOrigin::signed(AppealEscalationSubmitter::account()),
+            alloc::boxed::Box::new(crate::OriginCaller::system(frame_system::RawOrigin::Root)),
+            proposal,
+            frame_support::traits::schedule::DispatchTime::After(0),
+        )?;
+        Ok(index)
+    }
+
+    fn outcome(referendum: u32) -> Option<bool> {
+        use pallet_referenda::ReferendumInfo;
+        match pallet_referenda::ReferendumInfoFor::<Runtime>::get(referendum)? {
+            ReferendumInfo::Ongoing(_) => None,
+            ReferendumInfo::Approved(..) => Some(true),
+            ReferendumInfo::Rejected(..)
+            | ReferendumInfo::Cancelled(..)
+            | ReferendumInfo::TimedOut(..)
+            | ReferendumInfo::Killed(..) => Some(false),
+        }
+    }
+}
+
+/// 函数级详细中文注释：申诉裁决计票实现（🆕）。
+/// - 审批/驳回作为内容委员会（Instance3）集体提案执行时，提案哈希即 `hash_of(RuntimeCall)`，
+///   执行期间该提案的 `Voting` 记录尚未移除，直接读取其 ayes/nays 人数；
+/// - pallet-collective 的 `Votes` 字段不公开，按相同布局解码存储值；
+/// - 计票为显式投票人数，不含关闭时按 prime 默认票计入的弃权成员。
+pub struct ContentCommitteeTally;
+impl pallet_stardust_appeals::AppealDecisionTally<pallet_stardust_appeals::Call<Runtime>>
+    for ContentCommitteeTally
+{
+    fn tally_of(call: pallet_stardust_appeals::Call<Runtime>) -> Option<(u32, u32)> {
+        use sp_runtime::traits::Hash;
+
+        /// 与 `pallet_collective::Votes` 相同的存储布局
+        #[derive(codec::Decode)]
+        struct CommitteeVotes {
+            _index: u32,
+            _threshold: u32,
+            ayes: alloc::vec::Vec<AccountId>,
+            nays: alloc::vec::Vec<AccountId>,
+            _end: crate::BlockNumber,
+        }
+
+        let proposal_hash =
+            <Runtime as frame_system::Config>::Hashing::hash_of(&RuntimeCall::ContentGovernance(call));
+        let key = pallet_collective::Voting::<Runtime, ContentCollective>::hashed_key_for(proposal_hash);
+        frame_support::storage::unhashed::get::<CommitteeVotes>(&key)
+            .map(|votes| (votes.ayes.len() as u32, votes.nays.len() as u32))
+    }
+}
+
 /// 函数级详细中文注释：逝者作品信息提供者实现（Phase 4：阶段4接口补充）
 ///
 /// ## 功能说明
@@ -733,6 +840,13 @@ impl pallet_credit::Config for Runtime {
     
     /// 函数级中文注释：Weight 信息
     type CreditWeightInfo = ();
+    /// 函数级中文注释：跨领域信誉治理权限（Root 或 2/3 议会）
+    type ReputationGovernanceOrigin = frame_support::traits::EitherOfDiverse<
+        frame_system::EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionAtLeast<AccountId, pallet_collective::Instance1, 2, 3>,
+    >;
+    // 🆕 KYC分级：买家额度按 OTC 模块的KYC等级封顶
+    type KycTierProvider = crate::OtcOrder;
 }
 
 parameter_types! {
@@ -777,9 +891,43 @@ parameter_types! {
     pub const ParkMaxRegionLen: u32 = 64;
     pub const ParkMaxCidLen: u32 = 64;
     pub const ParkMaxPerCountry: u32 = 100_000;
+    // 🆕 墓位库存
+    pub const ParkMaxOperators: u32 = 16;
+    pub const ParkMaxZones: u32 = 64;
+    pub const ParkMaxPlotsPerZone: u32 = 2_000;
+    pub const ParkMaxPlotsPerBatch: u32 = 100;
+    pub const ParkMaxOccupantsPerPlot: u32 = 8;
+    /// 预订有效期：3 天（6s/块）
+    pub const ParkPlotReservationPeriod: BlockNumber = 3 * DAYS;
     // pub const GraveMaxFollowers: u32 = 100_000;  // 🗑️ 2025-11-16: 已删除 - pallet-stardust-grave 已移除
 }
 pub struct RootOnlyParkAdmin;
+
+/// 函数级中文注释：🆕 墓位安葬对象适配器。
+/// - 逝者：`pallet-deceased::DeceasedOf` 存在且调用者为 owner；
+/// - 宠物：经 `PetAccess` 校验存在性与管理权限。
+pub struct ParkPlotOccupantsAdapter;
+impl pallet_stardust_park::PlotOccupantAccess<AccountId> for ParkPlotOccupantsAdapter {
+    fn exists(occupant: &pallet_stardust_park::PlotOccupant) -> bool {
+        use pallet_stardust_park::PlotOccupant;
+        use pallet_stardust_pet::PetAccess;
+        match occupant {
+            PlotOccupant::Deceased(id) => pallet_deceased::pallet::DeceasedOf::<Runtime>::contains_key(id),
+            PlotOccupant::Pet(id) => pallet_stardust_pet::Pallet::<Runtime>::pet_exists(*id),
+        }
+    }
+    fn can_assign(who: &AccountId, occupant: &pallet_stardust_park::PlotOccupant) -> bool {
+        use pallet_stardust_park::PlotOccupant;
+        use pallet_stardust_pet::PetAccess;
+        match occupant {
+            PlotOccupant::Deceased(id) => pallet_deceased::pallet::DeceasedOf::<Runtime>::get(id)
+                .map(|d| d.owner == *who)
+                .unwrap_or(false),
+            PlotOccupant::Pet(id) => pallet_stardust_pet::Pallet::<Runtime>::can_manage(who, *id),
+        }
+    }
+}
+
 impl pallet_stardust_park::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type MaxRegionLen = ParkMaxRegionLen;
@@ -791,6 +939,17 @@ impl pallet_stardust_park::Config for Runtime {
         frame_system::EnsureRoot<AccountId>,
         pallet_collective::EnsureProportionAtLeast<AccountId, pallet_collective::Instance1, 2, 3>,
     >;
+    type Balance = Balance;
+    /// 函数级中文注释：🆕 墓位价款托管复用 pallet-escrow（托管ID 命名空间 "PLOT"）
+    type Escrow = pallet_escrow::Pallet<Runtime>;
+    type Occupants = ParkPlotOccupantsAdapter;
+    type MaxParkOperators = ParkMaxOperators;
+    type MaxZonesPerPark = ParkMaxZones;
+    type MaxPlotsPerZone = ParkMaxPlotsPerZone;
+    type MaxPlotsPerBatch = ParkMaxPlotsPerBatch;
+    type MaxOccupantsPerPlot = ParkMaxOccupantsPerPlot;
+    type ReservationPeriod = ParkPlotReservationPeriod;
+    type WeightInfo = pallet_stardust_park::weights::SubstrateWeight<Runtime>;
 }
 
 // 🗑️ 2025-11-16: 已删除 pallet-stardust-grave 参数定义
@@ -911,6 +1070,11 @@ parameter_types! {
     /// - 防止 on_initialize 权重过大导致区块超重
     /// - 可根据链上负载调整
     pub const MaxExpiringArticlesPerBlock: u32 = 50;
+
+    /// 函数级详细中文注释：每块最多发出的纪念提醒数
+    /// - 默认值：100
+    /// - 清明、冬至等集中日期的提醒分摊到多个区块发出
+    pub const MaxRemembranceRemindersPerBlock: u32 = 100;
     // ==========================================================
 }
 
@@ -1024,6 +1188,9 @@ impl pallet_deceased::Config for Runtime {
     /// 函数级中文注释：Unix时间提供器 - 用于时间戳相关功能
     type UnixTime = pallet_timestamp::Pallet<Runtime>;
 
+    /// 函数级中文注释：🆕 会员权益 - 会员享有防刷每日限额加成
+    type MembershipBenefits = pallet_membership::Pallet<Runtime>;
+
     // ========== 🆕 2025-11-26: 逝者创建频率限制配置 ==========
     /// 函数级中文注释：每日最大逝者创建数（每用户）
     /// - 替代押金机制的防滥用措施
@@ -1055,6 +1222,7 @@ impl pallet_deceased::Config for Runtime {
     /// - 50 = 每块最多处理50篇到期文章
     /// - 防止 on_initialize 权重过大
     type MaxExpiringArticlesPerBlock = MaxExpiringArticlesPerBlock;
+    type MaxRemembranceRemindersPerBlock = MaxRemembranceRemindersPerBlock;
     // ==========================================================
 }
 
@@ -1330,13 +1498,35 @@ impl pallet_origin_restriction::Config for Runtime {
 
 // 方案B：移除单点治理账户（内容治理签名账户）
 
-// ===== ledger 配置（精简） =====
+/// 函数级中文注释：🆕 纪念目标所在陵园适配器（ledger 陵园排行）。
+/// - 逝者/宠物：读取 `pallet-stardust-park::PlotOf` 定位安葬墓位所属陵园；
+/// - 纪念空间：不归属陵园。
+pub struct LedgerTargetParkAdapter;
+impl pallet_ledger::TargetParkProvider for LedgerTargetParkAdapter {
+    fn park_of(target: &pallet_ledger::MemorialTarget) -> Option<u64> {
+        use pallet_ledger::MemorialTarget;
+        use pallet_stardust_park::PlotOccupant;
+        let occupant = match target {
+            MemorialTarget::Deceased(id) => PlotOccupant::Deceased(*id),
+            MemorialTarget::Pet(id) => PlotOccupant::Pet(*id),
+            MemorialTarget::Space(_) => return None,
+        };
+        pallet_stardust_park::Pallet::<Runtime>::park_of_occupant(&occupant)
+    }
+}
+
+// ===== ledger 配置（按纪念目标：逝者 / 宠物 / 纪念空间） =====
 impl pallet_ledger::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type GraveId = u64;
     type Balance = Balance;
     /// 一周按 6s/块 × 60 × 60 × 24 × 7 = 100_800 块（可由治理升级调整）
     type BlocksPerWeek = frame_support::traits::ConstU32<100_800>;
+    /// 🆕 每个排行范围每周保留 Top-50
+    type MaxLeaderboardSize = frame_support::traits::ConstU32<50>;
+    /// 🆕 回执保留 52 周（约一年），之后可清理，周根永久保留
+    type ReceiptRetentionWeeks = frame_support::traits::ConstU64<52>;
+    /// 🆕 逝者/宠物按安葬墓位归属陵园（pallet-stardust-park），纪念空间不计入陵园榜
+    type TargetPark = LedgerTargetParkAdapter;
     /// 函数级中文注释：绑定 ledger 手写占位权重（后续可替换为基准生成版）。
     type WeightInfo = pallet_ledger::weights::SubstrateWeight<Runtime>;
 }
@@ -1367,6 +1557,15 @@ parameter_types! {
     /// - 用于接收供奉品交易的平台分成
     /// - 示例：b"memoripl" = Memorial Platform
     pub const MemorialPalletId: frame_support::PalletId = frame_support::PalletId(*b"memoripl");
+
+    /// 函数级中文注释：每块最多执行的预约供奉数
+    /// - 清明、冬至等集中日期的预约分摊到多个区块执行
+    pub const MemorialMaxScheduledOfferingsPerBlock: u32 = 20;
+
+    /// 函数级中文注释：集体供奉众筹参数
+    /// - 单个众筹最多100位出资人，募集期最长30天
+    pub const MemorialMaxCampaignContributors: u32 = 100;
+    pub const MemorialMaxCampaignDuration: BlockNumber = 30 * DAYS;
 }
 
 /// 函数级中文注释：Memorial会员信息提供者适配器
@@ -1381,6 +1580,78 @@ impl pallet_memorial::MembershipProvider<AccountId> for MemorialMembershipProvid
     }
 }
 
+/// 函数级详细中文注释：Memorial 纪念日历适配器（预约供奉）
+///
+/// ## 功能说明
+/// - "今天"与纪念日推算均来自 pallet-deceased 的纪念日历（UTC+8 纪元日）
+/// - 逝者与宠物目标支持纪念日（宠物按档案出生/离世日期推算，清明/冬至不依赖档案）；
+///   其他目标类型返回 None（预约时报 NoUpcomingOccasion）
+pub struct MemorialRemembranceCalendar;
+impl pallet_memorial::RemembranceCalendar for MemorialRemembranceCalendar {
+    fn today() -> u32 {
+        pallet_deceased::Pallet::<Runtime>::today_day_index()
+    }
+
+    fn next_occurrence(
+        target_type: pallet_memorial::TargetType,
+        target_id: u64,
+        occasion: u8,
+        from_day: u32,
+    ) -> Option<u32> {
+        match target_type {
+            pallet_memorial::TargetType::Deceased => {
+                let occasion = pallet_deceased::RemembranceOccasion::from_code(occasion)?;
+                pallet_deceased::Pallet::<Runtime>::next_remembrance_day(target_id, occasion, from_day)
+            },
+            // 🆕 宠物：按宠物档案的出生/离世日期（YYYYMMDD）套用逝者纪念日历的推算
+            pallet_memorial::TargetType::Pet => {
+                if !pallet_stardust_pet::pallet::PetOf::<Runtime>::contains_key(target_id) {
+                    return None;
+                }
+                let occasion = pallet_deceased::RemembranceOccasion::from_code(occasion)?;
+                let profile = pallet_stardust_pet::pallet::PetProfileOf::<Runtime>::get(target_id);
+                let parse = |date: Option<&[u8]>| date.and_then(pallet_deceased::remembrance::parse_ymd);
+                let birth = parse(profile.as_ref().and_then(|p| p.birth.as_ref()).map(|b| b.as_slice()));
+                let death = parse(profile.as_ref().and_then(|p| p.death.as_ref()).map(|b| b.as_slice()));
+                pallet_deceased::remembrance::next_occurrence(occasion, birth, death, from_day)
+                    .map(|(day, _)| day)
+            },
+            _ => None,
+        }
+    }
+}
+
+/// 函数级详细中文注释：Memorial 存储资金账户适配器（集体供奉众筹 - 资助用途）
+///
+/// ## 功能说明
+/// - 逝者目标：返回 stardust-ipfs 的 SubjectFunding 账户（与 `fund_subject_account` 收款地址一致）
+/// - 🆕 宠物目标：返回宠物资金账户（与 `fund_pet_subject_account` 收款地址一致）
+/// - 目标不存在或其他目标类型返回 None
+pub struct MemorialSubjectFunding;
+impl pallet_memorial::SubjectFundingProvider<AccountId> for MemorialSubjectFunding {
+    fn funding_account(target_type: pallet_memorial::TargetType, target_id: u64) -> Option<AccountId> {
+        match target_type {
+            pallet_memorial::TargetType::Deceased
+                if pallet_deceased::pallet::DeceasedOf::<Runtime>::contains_key(target_id) =>
+            {
+                Some(pallet_stardust_ipfs::Pallet::<Runtime>::derive_subject_funding_account_v2(
+                    pallet_stardust_ipfs::SubjectType::Deceased,
+                    target_id,
+                ))
+            },
+            pallet_memorial::TargetType::Pet
+                if pallet_stardust_pet::pallet::PetOf::<Runtime>::contains_key(target_id) =>
+            {
+                Some(pallet_stardust_ipfs::Pallet::<Runtime>::derive_subject_funding_account_v2(
+                    pallet_stardust_ipfs::SubjectType::Pet,
+                    target_id,
+                ))
+            },
+            _ => None,
+        }
+    }
+}
+
 /// 函数级详细中文注释：Deceased 目标适配器（通用供奉系统 - P0）
 ///
 /// ## 功能说明
@@ -1458,29 +1729,22 @@ pub struct PetTargetAdapter;
 impl pallet_memorial::OfferingTarget<AccountId> for PetTargetAdapter {
     /// 检查宠物是否存在
     fn exists(target_id: u64) -> bool {
-        pallet_stardust_pet::pallet::PetOf::<Runtime>::contains_key(target_id)
+        use pallet_stardust_pet::PetAccess;
+        pallet_stardust_pet::Pallet::<Runtime>::pet_exists(target_id)
     }
 
     /// 获取宠物所有者（用于分账）
     fn get_owner(target_id: u64) -> Option<AccountId> {
-        pallet_stardust_pet::pallet::PetOf::<Runtime>::get(target_id).map(|p| p.owner)
+        use pallet_stardust_pet::PetAccess;
+        pallet_stardust_pet::Pallet::<Runtime>::pet_owner(target_id)
     }
 
     /// 检查用户是否可访问该宠物（供奉权限判定）
+    /// - 所有者始终可供奉；其他人仅限公开宠物（PetVisibilityOf 未设置视为公开）
     fn is_accessible(who: &AccountId, target_id: u64) -> bool {
-        if let Some(pet) = pallet_stardust_pet::pallet::PetOf::<Runtime>::get(target_id) {
-            // 如果是所有者，直接允许
-            if pet.owner == *who {
-                return true;
-            }
-
-            // TODO: 检查可见性设置
-            // 当前简化版本：所有宠物都公开可访问
-            // 未来扩展：根据 pet.visibility 判断
-            true
-        } else {
-            false
-        }
+        use pallet_stardust_pet::PetAccess;
+        pallet_stardust_pet::Pallet::<Runtime>::can_manage(who, target_id)
+            || pallet_stardust_pet::Pallet::<Runtime>::is_visible(target_id)
     }
 
     /// 获取宠物显示名称
@@ -1495,6 +1759,68 @@ impl pallet_memorial::OfferingTarget<AccountId> for PetTargetAdapter {
     }
 }
 
+/// 函数级详细中文注释：纪念空间供奉目标适配器（`TargetType::Memorial`）
+///
+/// - 存在性：`pallet_memorial_space::Spaces`
+/// - 受益人：空间所有者
+/// - 供奉权限：与访问权限一致（`can_visit`：公开 / 关注者 / 邀请 / 口令解锁，管理员始终可访问）
+pub struct SpaceTargetAdapter;
+
+impl pallet_memorial::OfferingTarget<AccountId> for SpaceTargetAdapter {
+    fn exists(target_id: u64) -> bool {
+        pallet_memorial_space::Spaces::<Runtime>::contains_key(target_id)
+    }
+
+    fn get_owner(target_id: u64) -> Option<AccountId> {
+        pallet_memorial_space::Spaces::<Runtime>::get(target_id).map(|space| space.owner)
+    }
+
+    fn is_accessible(who: &AccountId, target_id: u64) -> bool {
+        pallet_memorial_space::Pallet::<Runtime>::can_visit(target_id, who)
+    }
+
+    /// 纪念空间无名称字段（展示信息在 layout_cid 中）
+    fn get_display_name(_target_id: u64) -> Option<frame_support::BoundedVec<u8, frame_support::traits::ConstU32<256>>> {
+        None
+    }
+}
+
+/// 函数级详细中文注释：供奉落账回调 → pallet-ledger
+///
+/// - TargetType 映射：Deceased → Deceased，Pet → Pet，Memorial → Space；Event 暂不记账
+/// - 生成回执（入当周 Merkle 树）、累计次数/金额、周活跃与周排行
+pub struct MemorialLedgerHook;
+impl pallet_memorial::OnOfferingRecorded<AccountId> for MemorialLedgerHook {
+    fn on_recorded(
+        offering_id: u64,
+        target_type: pallet_memorial::TargetType,
+        target_id: u64,
+        sacrifice_id: u64,
+        category: u8,
+        who: &AccountId,
+        amount: u128,
+        duration_weeks: Option<u32>,
+    ) {
+        use pallet_ledger::MemorialTarget;
+        use pallet_memorial::TargetType;
+        let target = match target_type {
+            TargetType::Deceased => MemorialTarget::Deceased(target_id),
+            TargetType::Pet => MemorialTarget::Pet(target_id),
+            TargetType::Memorial => MemorialTarget::Space(target_id),
+            TargetType::Event => return,
+        };
+        pallet_ledger::Pallet::<Runtime>::record_offering(
+            offering_id,
+            target,
+            who.clone(),
+            sacrifice_id,
+            category,
+            amount,
+            duration_weeks,
+        );
+    }
+}
+
 /// 函数级详细中文注释：Memorial供奉回调实现（集成affiliate分账）
 ///
 /// ### 核心功能：Affiliate分账处理
@@ -1555,12 +1881,28 @@ impl pallet_memorial::Config for Runtime {
 
     // === Trait 接口 ===
     type MembershipProvider = MemorialMembershipProvider;
+    // 🆕 供奉目标校验：逝者与宠物同为一等纪念主体
+    type DeceasedTarget = DeceasedTargetAdapter;
+    type PetTarget = PetTargetAdapter;
+    type SpaceTarget = SpaceTargetAdapter;
     type OnOfferingCommitted = MemorialOfferingHook;
+    // 🆕 供奉回执与周排行
+    type OnOfferingRecorded = MemorialLedgerHook;
 
     // === P0修复：资金管理配置 ===
     /// 函数级中文注释：平台托管账户PalletId
     type PalletId = MemorialPalletId;
 
+    // === 🆕 预约供奉配置 ===
+    type RemembranceCalendar = MemorialRemembranceCalendar;
+    type MaxScheduledOfferingsPerBlock = MemorialMaxScheduledOfferingsPerBlock;
+
+    // === 🆕 集体供奉众筹配置 ===
+    type Escrow = pallet_escrow::Pallet<Runtime>;
+    type SubjectFunding = MemorialSubjectFunding;
+    type MaxCampaignContributors = MemorialMaxCampaignContributors;
+    type MaxCampaignDuration = MemorialMaxCampaignDuration;
+
     // === 管理员权限 ===
     /// 函数级中文注释：管理员 Origin：Root | 内容委员会(Instance3，2/3)
     type AdminOrigin = frame_support::traits::EitherOfDiverse<
@@ -1569,6 +1911,68 @@ impl pallet_memorial::Config for Runtime {
     >;
 }
 
+// ===== 虚拟纪念空间配置 =====
+
+/// 函数级详细中文注释：纪念空间主体适配器
+/// - 逝者所有者来自 pallet-deceased；关注关系来自 pallet-social
+pub struct MemorialSpaceSubjects;
+impl pallet_memorial_space::SpaceSubjectProvider<AccountId> for MemorialSpaceSubjects {
+    fn deceased_owner(deceased_id: u64) -> Option<AccountId> {
+        pallet_deceased::pallet::DeceasedOf::<Runtime>::get(deceased_id).map(|d| d.owner)
+    }
+
+    fn is_follower(who: &AccountId, deceased_id: u64) -> bool {
+        pallet_social::Pallet::<Runtime>::is_following_deceased(who, deceased_id)
+    }
+}
+
+/// 函数级详细中文注释：纪念空间摆放物适配器
+/// - 供奉记录：pallet-memorial（仅逝者目标）
+/// - 相册/照片：pallet-deceased 媒体模块
+/// - 宠物：pallet-stardust-pet
+pub struct MemorialSpaceObjects;
+impl pallet_memorial_space::SpaceObjectProvider<AccountId> for MemorialSpaceObjects {
+    fn offering_of(offering_id: u64) -> Option<(AccountId, u64)> {
+        pallet_memorial::pallet::OfferingRecords::<Runtime>::get(offering_id)
+            .filter(|r| r.target_type == pallet_memorial::TargetType::Deceased)
+            .map(|r| (r.who, r.target_id))
+    }
+
+    fn album_deceased(album_id: u64, media_id: Option<u64>) -> Option<u64> {
+        let album = pallet_deceased::pallet::Albums::<Runtime>::get(album_id)?;
+        if let Some(media_id) = media_id {
+            if !pallet_deceased::pallet::PhotosByAlbum::<Runtime>::get(album_id).contains(&media_id) {
+                return None;
+            }
+        }
+        Some(album.deceased_id)
+    }
+
+    fn pet_owner(pet_id: u64) -> Option<AccountId> {
+        pallet_stardust_pet::pallet::PetOf::<Runtime>::get(pet_id).map(|p| p.owner)
+    }
+}
+
+parameter_types! {
+    pub const MemorialSpaceMaxCidLen: u32 = 64;
+    pub const MemorialSpaceMaxAdmins: u32 = 10;
+    pub const MemorialSpaceMaxObjects: u32 = 200;
+    /// 函数级中文注释：每个空间最多200条待审核留言，超出后需管理员先审核
+    pub const MemorialSpaceMaxPendingGuestbook: u32 = 200;
+    pub const MemorialSpaceMaxSpacesPerDeceased: u32 = 8;
+}
+
+impl pallet_memorial_space::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Subjects = MemorialSpaceSubjects;
+    type Objects = MemorialSpaceObjects;
+    type MaxCidLen = MemorialSpaceMaxCidLen;
+    type MaxAdmins = MemorialSpaceMaxAdmins;
+    type MaxObjectsPerSpace = MemorialSpaceMaxObjects;
+    type MaxPendingGuestbookEntries = MemorialSpaceMaxPendingGuestbook;
+    type MaxSpacesPerDeceased = MemorialSpaceMaxSpacesPerDeceased;
+}
+
 // ===== Treasury 配置 =====
 parameter_types! {
     pub const TreasuryPalletId: frame_support::PalletId = frame_support::PalletId(*b"py/trsry");
@@ -1791,7 +2195,47 @@ impl pallet_evidence::Config for Runtime {
     type IpfsPinner = StardustIpfs;
     type Balance = Balance;
     type DefaultStoragePrice = ConstU128<{ 1 * crate::UNIT }>;
+    // ============= 案件角色授权配置 =============
+    /// 函数级中文注释：案件决策主体由仲裁陪审团 / 内容委员会动态决定
+    type CaseAccess = EvidenceCaseAccess;
+    /// 函数级中文注释：决策主体公钥管理：Root | 内容委员会(Instance3，2/3)
+    type BodyKeyOrigin = frame_support::traits::EitherOfDiverse<
+        frame_system::EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionAtLeast<AccountId, pallet_collective::Instance3, 2, 3>,
+    >;
+    type MaxCasesPerContent = frame_support::traits::ConstU32<8>;
+}
+
+/// 函数级中文注释：证据案件授权适配器
+/// - 仲裁：陪审团投票中 → 本轮陪审员（主体按 (domain, id, round) 派生，每案每轮独立，
+///   无共享主体公钥，证据由创建者按陪审员个人公钥经 `grant_case_access_to_members` 授权）；
+///   其余进行中阶段 → 内容委员会（主体 `content_`）
+/// - 申诉：已提交/已批准待执行 → 内容委员会
+/// - 已结案/不存在 → None（访问自动失效）
+pub struct EvidenceCaseAccess;
+impl pallet_evidence::case_access::CaseAccessProvider<AccountId> for EvidenceCaseAccess {
+    fn decision_body(
+        case: &pallet_evidence::case_access::EvidenceCase,
+    ) -> Option<([u8; 8], alloc::vec::Vec<AccountId>)> {
+        use pallet_evidence::case_access::EvidenceCase;
+        let committee =
+            || (*b"content_", pallet_collective::Members::<Runtime, pallet_collective::Instance3>::get());
+        match *case {
+            EvidenceCase::Arbitration { domain, id } => {
+                if !pallet_arbitration::pallet::Pallet::<Runtime>::is_case_open(domain, id) {
+                    return None;
+                }
+                pallet_arbitration::pallet::Pallet::<Runtime>::current_jury_body(domain, id)
+                    .or_else(|| Some(committee()))
+            }
+            EvidenceCase::Appeal(appeal_id) => {
+                let appeal = pallet_stardust_appeals::pallet::Appeals::<Runtime>::get(appeal_id)?;
+                (appeal.status == 0 || appeal.status == 1).then(committee)
+            }
+        }
+    }
 }
+
 impl pallet_evidence::pallet::EvidenceAuthorizer<AccountId> for AllowAllEvidenceAuthorizer {
     fn is_authorized(_ns: [u8; 8], _who: &AccountId) -> bool {
         true
@@ -2004,11 +2448,43 @@ impl pallet_identity::Config for Runtime {
     // 新版 pallet-identity 已不需要 BenchmarkHelper 关联类型
 }
 
-// ===== stardust-pet 配置（最小实现） =====
-parameter_types! { pub const PetStringLimit: u32 = 64; }
+// ===== stardust-pet 配置（宠物纪念主体） =====
+parameter_types! {
+    pub const PetStringLimit: u32 = 64;
+    pub const PetMaxCidLen: u32 = 128;
+    pub const PetMaxMediaPerPet: u32 = 256;
+    pub const PetMaxPetsPerDeceased: u32 = 32;
+}
+
+/// 函数级中文注释：🆕 宠物关联目标校验
+/// - 逝者：逝者存在且调用者为逝者 owner
+/// - 纪念空间：空间存在且调用者为空间所有者或管理员
+pub struct PetLinkTargetsAdapter;
+impl pallet_stardust_pet::PetLinkTargets<AccountId> for PetLinkTargetsAdapter {
+    fn can_link_deceased(who: &AccountId, deceased_id: u64) -> bool {
+        pallet_deceased::pallet::DeceasedOf::<Runtime>::get(deceased_id)
+            .map_or(false, |d| d.owner == *who)
+    }
+    fn can_link_space(who: &AccountId, space_id: u64) -> bool {
+        pallet_memorial_space::Spaces::<Runtime>::get(space_id)
+            .map_or(false, |space| pallet_memorial_space::Pallet::<Runtime>::is_admin_of(&space, who))
+    }
+}
+
 impl pallet_stardust_pet::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type StringLimit = PetStringLimit;
+    type MaxCidLen = PetMaxCidLen;
+    type MaxMediaPerPet = PetMaxMediaPerPet;
+    type MaxPetsPerDeceased = PetMaxPetsPerDeceased;
+    type LinkTargets = PetLinkTargetsAdapter;
+    type Balance = Balance;
+    type IpfsPinner = StardustIpfs;
+    /// 函数级中文注释：与 deceased 一致，Root | 内容委员会 2/3 执行 gov* 接口
+    type GovernanceOrigin = frame_support::traits::EitherOfDiverse<
+        frame_system::EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionAtLeast<AccountId, pallet_collective::Instance3, 2, 3>,
+    >;
 }
 // 函数级中文注释：2025-10-20 已删除 pallet-otc-listing 配置
 // 原因：OTC订单重构已完成，挂单机制已由直接选择做市商替代
@@ -2190,6 +2666,8 @@ parameter_types! {
     pub const FirstPurchaseUsdAmount: u64 = 10_000_000;
     /// 金额验证容差（1%，用于处理价格微小波动）
     pub const AmountValidationTolerance: u16 = 100; // 100 bps = 1%
+    /// 🆕 付款证明等待时长（标记付款后 30 分钟内无足额证明则自动争议）
+    pub const PaymentAttestationTimeout: BlockNumber = 30 * crate::MINUTES;
 
     // OTC订单清理配置
     pub const OrderArchiveThresholdDays: u32 = 150; // 5个月
@@ -2421,7 +2899,11 @@ impl pallet_otc_order::Config for Runtime {
     type MakerCredit = MakerCreditImpl;  // ✅ 2025-11-03：做市商信用接口
     type Pricing = PricingProviderImpl;
     type MakerPallet = MakerPalletImpl;  // Maker Pallet 接口
-    type CommitteeOrigin = frame_system::EnsureSigned<AccountId>;  // 🆕 2025-11-13：KYC管理权限
+    // 🆕 KYC管理权限（含认证方名单与等级限额）：Root 或 议会 2/3
+    type CommitteeOrigin = frame_support::traits::EitherOfDiverse<
+        frame_system::EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionAtLeast<AccountId, pallet_collective::Instance1, 2, 3>,
+    >;
     type IdentityProvider = ();  // 🆕 2025-11-13：临时使用空实现（待 pallet_identity 集成）
 
     // 订单超时配置
@@ -2443,9 +2925,33 @@ impl pallet_otc_order::Config for Runtime {
     // 🆕 2025-11-28: 聊天权限管理器
     type ChatPermission = ChatPermission;
 
+    // 🆕 付款证明：标记付款后 30 分钟内无足额证明则自动争议
+    type PaymentAttestationTimeout = PaymentAttestationTimeout;  // 30 分钟（区块）
+    type MaxAttestationTimeoutsPerBlock = ConstU32<20>;  // 同一截止区块最多 20 单
+    // 🆕 KYC分级认证：同一区块最多 200 条到期，单个认证方最多 1_000 个有效认证账户
+    type MaxKycExpiriesPerBlock = ConstU32<200>;
+    type MaxAccountsPerKycAttestor = ConstU32<1_000>;
+    type PaymentEvidence = OtcPaymentEvidence;
+
     type WeightInfo = ();
 }
 
+/// 函数级中文注释：OTC 付款证明证据适配器
+/// - 超时自动争议时，将证明历史的承诺哈希以命名空间 `otc_ord_`、主体为订单ID 登记到 pallet-evidence
+/// - 证据所有者为订单做市商；登记失败（重复/超配额）时返回 None，不影响争议流程
+pub struct OtcPaymentEvidence;
+impl pallet_otc_order::PaymentEvidenceSink<AccountId> for OtcPaymentEvidence {
+    fn attach(order_id: u64, owner: &AccountId, commit: sp_core::H256) -> Option<u64> {
+        pallet_evidence::Pallet::<Runtime>::commit_system_hash(
+            owner.clone(),
+            *b"otc_ord_",
+            order_id,
+            commit,
+        )
+        .ok()
+    }
+}
+
 // 3️⃣ Bridge 模块配置（DUST ↔ USDT 桥接）
 /// 函数级中文注释：Bridge Pallet 配置实现
 /// - 🔴 stable2506 API 变更：RuntimeEvent 自动继承，无需显式设置
@@ -2530,6 +3036,41 @@ impl pallet_affiliate::types::AffiliateDistributor<AccountId, Balance, BlockNumb
     }
 }
 
+// ===== pallet-assets 配置（同质化资产，供托管多资产使用） =====
+parameter_types! {
+    pub const AssetDeposit: Balance = 100 * UNIT;
+    pub const AssetAccountDeposit: Balance = UNIT;
+    pub const AssetMetadataDepositBase: Balance = 10 * UNIT;
+    pub const AssetMetadataDepositPerByte: Balance = UNIT / 10;
+    pub const AssetApprovalDeposit: Balance = UNIT;
+    pub const AssetStringLimit: u32 = 50;
+}
+
+impl pallet_assets::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type RemoveItemsLimit = frame_support::traits::ConstU32<1000>;
+    type AssetId = u32;
+    type AssetIdParameter = codec::Compact<u32>;
+    type Currency = Balances;
+    /// 函数级中文注释：任何签名账户可创建资产（押金 AssetDeposit），强制操作仅 Root。
+    type CreateOrigin = frame_support::traits::AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type AssetDeposit = AssetDeposit;
+    type AssetAccountDeposit = AssetAccountDeposit;
+    type MetadataDepositBase = AssetMetadataDepositBase;
+    type MetadataDepositPerByte = AssetMetadataDepositPerByte;
+    type ApprovalDeposit = AssetApprovalDeposit;
+    type StringLimit = AssetStringLimit;
+    type Holder = ();
+    type Freezer = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
 parameter_types! { pub const EscrowPalletId: frame_support::PalletId = frame_support::PalletId(*b"otc/escw"); }
 impl pallet_escrow::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
@@ -2549,6 +3090,10 @@ impl pallet_escrow::Config for Runtime {
     type MaxExpiringPerBlock = frame_support::traits::ConstU32<200>;
     /// 函数级中文注释：到期策略（示例：NoopPolicy）。
     type ExpiryPolicy = NoopExpiryPolicy;
+    /// 函数级中文注释：同质化资产适配（pallet-assets，资产ID为 u32）。
+    type Assets = pallet_escrow::FungiblesAdapter<crate::Assets>;
+    /// 函数级中文注释：单个托管最多 12 个里程碑（如按月订阅一年）。
+    type MaxMilestones = frame_support::traits::ConstU32<12>;
 }
 /// 函数级中文注释：到期策略占位实现——不做任何资金处理，仅用于演示。
 pub struct NoopExpiryPolicy;
@@ -2565,6 +3110,13 @@ impl pallet_escrow::pallet::ExpiryPolicy<AccountId, BlockNumber> for NoopExpiryP
 
 parameter_types! { pub const ArbMaxEvidence: u32 = 16; pub const ArbMaxCidLen: u32 = 64; }
 
+parameter_types! {
+    /// 陪审员最低质押：1000 DUST
+    pub const ArbMinJurorStake: Balance = 1_000 * UNIT;
+    /// 每位陪审员每轮陪审费：5 DUST
+    pub const ArbJurorFee: Balance = 5 * UNIT;
+}
+
 // 🆕 纠纷押金常量配置（方案 A：托管扣押金，订单金额15%）
 const DISPUTE_RESPONSE_BLOCKS: u32 = 7 * 14400; // 7天（假设每天14400个区块，6秒/区块）
 const DEPOSIT_RATIO_BPS: u16 = 1500; // 15% = 1500 basis points
@@ -2593,6 +3145,21 @@ impl pallet_arbitration::Config for Runtime {
     type RejectedSlashBps = ConstU16<3000>;          // 败诉罚没 30%
     type PartialSlashBps = ConstU16<5000>;           // 部分胜诉罚没 50%
     type TreasuryAccount = TreasuryAccount;
+
+    /// 🆕 陪审团仲裁：按域质押陪审员，首轮 3 人，上诉 7 → 15 人，再上诉升级到内容委员会
+    type MinJurorStake = ArbMinJurorStake;
+    type JurorFee = ArbJurorFee;
+    type InitialJurySize = ConstU32<3>;
+    type MaxJurySize = ConstU32<15>;
+    type MaxJurorsPerDomain = ConstU32<500>;
+    type CommitPeriod = ConstU32<{ 2 * DAYS }>;
+    type RevealPeriod = ConstU32<{ DAYS }>;
+    type AppealPeriod = ConstU32<{ 2 * DAYS }>;
+    type IncoherentSlashBps = ConstU16<1000>;        // 与多数不一致罚没质押 10%
+    /// 抽选种子取申请后第 10 块的区块哈希（不使用可预测的 SimpleRandomness）
+    type JuryDrawDelay = ConstU32<10>;
+    /// 结案登记保留 30 天后可清理
+    type ResolvedRetention = ConstU32<{ 30 * DAYS }>;
 }
 
 // 已移除：Karma 授权命名空间常量
@@ -2746,6 +3313,7 @@ pub struct ContentGovernanceRouter;
 /// 函数级中文注释：内容治理路由器实现。
 /// - 根据 (domain, action) 将调用分发到相应 pallet 的 gov*/force* 接口；
 /// - MVP：先覆盖常见内容域（grave/deceased/deceased-text/deceased-media/offerings/park）；
+/// - 扩展：8/9=占卜市场大师/评价，10=占卜NFT，11=AI智能体，12=聊天用户，13/14=宠物档案/宠物媒体，15=陵园墓位，16=联盟推荐女巫标记；
 /// - 安全：仅在 memo-content-governance Pallet 审批通过后由 Hooks 调用，无需二次权限判断。
 impl pallet_stardust_appeals::AppealRouter<AccountId> for ContentGovernanceRouter {
     fn execute(
@@ -2848,6 +3416,88 @@ impl pallet_stardust_appeals::AppealRouter<AccountId> for ContentGovernanceRoute
                 None,
                 vec![],
             ),
+            // 8=占卜市场大师（target=订单ID，定位被投诉大师）：1=暂停，2=恢复
+            (8, 1) | (8, 2) => {
+                let order = pallet_divination_market::pallet::Orders::<Runtime>::get(target)
+                    .ok_or(sp_runtime::DispatchError::Other("OrderNotFound"))?;
+                pallet_divination_market::pallet::Pallet::<Runtime>::gov_set_provider_suspended(
+                    RuntimeOrigin::root(),
+                    order.provider,
+                    action == 1,
+                )
+            }
+            // 9=占卜市场评价（target=订单ID）：1=移除评价
+            (9, 1) => pallet_divination_market::pallet::Pallet::<Runtime>::gov_remove_review(
+                RuntimeOrigin::root(),
+                target,
+            ),
+            // 10=占卜NFT：1=下架，2=冻结，3=解冻
+            (10, 1) => pallet_divination_nft::pallet::Pallet::<Runtime>::gov_delist_nft(
+                RuntimeOrigin::root(),
+                target,
+            ),
+            (10, 2) | (10, 3) => pallet_divination_nft::pallet::Pallet::<Runtime>::gov_set_nft_frozen(
+                RuntimeOrigin::root(),
+                target,
+                action == 2,
+            ),
+            // 11=AI智能体：1=暂停，2=恢复
+            (11, 1) | (11, 2) => pallet_deceased_ai::pallet::Pallet::<Runtime>::gov_set_agent_suspended(
+                RuntimeOrigin::root(),
+                target,
+                action == 1,
+            ),
+            // 12=聊天用户（target=chat_user_id）：1=禁言，2=解除
+            (12, 1) | (12, 2) => pallet_chat::pallet::Pallet::<Runtime>::gov_set_chat_ban(
+                RuntimeOrigin::root(),
+                target,
+                action == 1,
+            ),
+            // 13=宠物档案（target=pet_id）：1=隐藏，2=恢复可见，3=清空主图
+            (13, 1) | (13, 2) => pallet_stardust_pet::pallet::Pallet::<Runtime>::gov_set_pet_visibility(
+                RuntimeOrigin::root(),
+                target,
+                action == 2,
+                vec![],
+            ),
+            (13, 3) => pallet_stardust_pet::pallet::Pallet::<Runtime>::gov_clear_pet_main_image(
+                RuntimeOrigin::root(),
+                target,
+                vec![],
+            ),
+            // 14=宠物媒体/文字（target=media_id）：1=删除
+            (14, 1) => pallet_stardust_pet::pallet::Pallet::<Runtime>::gov_remove_pet_media(
+                RuntimeOrigin::root(),
+                target,
+                vec![],
+            ),
+            // 🆕 15=陵园墓位（target=plot_id）：1=转让持有人（新持有人取自申诉），2=收回
+            (15, 1) => {
+                if let Some((_id, new_holder)) = pallet_stardust_appeals::pallet::Pallet::<
+                    Runtime,
+                >::find_transfer_params(15, 1, target)
+                {
+                    pallet_stardust_park::pallet::Pallet::<Runtime>::gov_transfer_plot(
+                        RuntimeOrigin::root(),
+                        target,
+                        new_holder,
+                        vec![],
+                    )
+                } else {
+                    Err(sp_runtime::DispatchError::Other("MissingNewOwner"))
+                }
+            }
+            (15, 2) => pallet_stardust_park::pallet::Pallet::<Runtime>::gov_reclaim_plot(
+                RuntimeOrigin::root(),
+                target,
+                vec![],
+            ),
+            // 🆕 域16：联盟推荐女巫标记（1=解除并发还暂扣收益，2=确认并没收）
+            (16, 1) | (16, 2) => pallet_affiliate::pallet::Pallet::<Runtime>::gov_resolve_flag(
+                RuntimeOrigin::root(),
+                target,
+                action == 1,
+            ),
             _ => Err(sp_runtime::DispatchError::Other("UnsupportedContentAction")),
         }
     }
@@ -2907,6 +3557,8 @@ impl pallet_stardust_ipfs::Config for Runtime {
     /// - owner可转让，支持所有权转移
     /// - 与creator分离，creator用于派生地址，owner用于权限检查
     type OwnerProvider = DeceasedOwnerAdapter;
+    /// 函数级中文注释：🆕 宠物owner提供者（宠物Pin权限与存在性检查）
+    type PetOwnerProvider = PetOwnerAdapter;
     
     // ⭐ 新增：双重扣款配置
     /// 函数级中文注释：IPFS 池账户（公共费用来源）
@@ -2939,6 +3591,9 @@ impl pallet_stardust_ipfs::Config for Runtime {
     /// - 7天 = 100,800块
     /// - 28天 = 403,200块
     type DefaultBillingPeriod = DefaultBillingPeriod;
+
+    /// 函数级中文注释：🆕 会员权益 - 按主体拥有者会员等级折扣 Pin 费用
+    type MembershipBenefits = pallet_membership::Pallet<Runtime>;
 }
 
 /// 函数级详细中文注释：逝者creator只读适配器
@@ -3012,6 +3667,16 @@ impl pallet_stardust_ipfs::OwnerProvider<AccountId> for DeceasedOwnerAdapter {
     }
 }
 
+/// 函数级中文注释：🆕 宠物owner提供者（stardust-ipfs 宠物Pin授权）
+/// - 通过 `PetAccess` 读取 pallet-stardust-pet 的当前所有者
+pub struct PetOwnerAdapter;
+impl pallet_stardust_ipfs::OwnerProvider<AccountId> for PetOwnerAdapter {
+    fn owner_of(pet_id: u64) -> Option<AccountId> {
+        use pallet_stardust_pet::PetAccess;
+        pallet_stardust_pet::Pallet::<Runtime>::pet_owner(pet_id)
+    }
+}
+
 /// 函数级中文注释：SLA 数据提供者，从 `pallet-stardust-ipfs` 读取运营者统计
 pub struct SlaFromIpfs;
 // （已下线）SLA Provider 适配器不再实现 endowment 的 trait
@@ -3246,6 +3911,9 @@ parameter_types! {
     
     /// 函数级中文注释：推荐链最大搜索深度（防止无限循环）
     pub const AffiliateMaxSearchHops: u32 = 50;
+
+    /// 函数级中文注释：🆕 推荐人绑定速率窗口（1 天）
+    pub const AffiliateBindWindow: BlockNumber = DAYS;
 }
 
 /// 函数级中文注释：会员信息提供者适配器
@@ -3256,6 +3924,29 @@ impl pallet_affiliate::MembershipProvider<AccountId> for AffiliateMembershipProv
     }
 }
 
+/// 函数级中文注释：🆕 推荐资格校验适配器（反女巫）
+///
+/// 满足其一即视为已校验：
+/// - `pallet-identity` 任一注册商判定为 Reasonable / KnownGood；
+/// - `pallet-credit` 跨领域综合信誉分 ≥ 600（初始分 500，需有正向履约记录）。
+pub struct AffiliateEligibilityAdapter;
+impl pallet_affiliate::ReferralEligibility<AccountId> for AffiliateEligibilityAdapter {
+    fn is_verified(who: &AccountId) -> bool {
+        let judged = pallet_identity::IdentityOf::<Runtime>::get(who)
+            .map(|reg| {
+                reg.judgements.iter().any(|(_, j)| {
+                    matches!(
+                        j,
+                        pallet_identity::Judgement::Reasonable | pallet_identity::Judgement::KnownGood
+                    )
+                })
+            })
+            .unwrap_or(false);
+        judged
+            || <pallet_credit::Pallet<Runtime> as pallet_credit::reputation::ReputationProvider<AccountId>>::aggregate_score(who) >= 600
+    }
+}
+
 // 🆕 2025-10-28 已移除：AffiliateDistributorAdapter（已不再需要）
 // pallet-membership 和 pallet-otc-order 已更新为直接调用 pallet-affiliate
 
@@ -3293,6 +3984,26 @@ impl pallet_affiliate::Config for Runtime {
 
     /// 存储费用账户（3%存储）
     type StorageAccount = DecentralizedStorageAccount;
+
+    /// 🆕 提案模拟摘要最多计入账户数（限制提案创建时的链上计算量）
+    type MaxSimulationAccounts = frame_support::traits::ConstU32<500>;
+
+    /// 🆕 推荐资格校验（身份判定或信用分）
+    type Eligibility = AffiliateEligibilityAdapter;
+
+    /// 🆕 风控预言机权限（Root 或 2/3 议会）
+    type SybilOracleOrigin = frame_support::traits::EitherOfDiverse<
+        frame_system::EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionAtLeast<AccountId, pallet_collective::Instance1, 2, 3>,
+    >;
+
+    /// 🆕 绑定速率：每个推荐人每天最多被绑定 20 次
+    type BindWindow = AffiliateBindWindow;
+    type MaxBindsPerWindow = frame_support::traits::ConstU32<20>;
+
+    /// 🆕 聚类阈值：同区块 5 次绑定 / 5 个同源直推
+    type SameBlockBindThreshold = frame_support::traits::ConstU32<5>;
+    type FundingClusterThreshold = frame_support::traits::ConstU32<5>;
 }
 
 // ========================================
@@ -3380,6 +4091,8 @@ impl pallet_membership::Config for Runtime {
     // 🆕 2025-11-10：连接 pallet_pricing 用于价格查询
     type PricingConfig = Runtime;
     type MinHoldingValueCents = MinHoldingValueCents;
+    // 🆕 会员权益周期：30 天（免费 AI 解读按周期计次）
+    type BenefitPeriod = frame_support::traits::ConstU32<{ 30 * DAYS }>;
     type WeightInfo = ();
 }
 
@@ -3393,17 +4106,167 @@ parameter_types! {
 parameter_types! { pub const MaxVotes: u32 = 256; }
 parameter_types! { pub const MaxTurnoutLimit: Balance = 0; }
 
-// 方案B：已移除 conviction-voting 配置
+// 🆕 conviction-voting 配置见下方「申诉升级公投」段落
 
 parameter_types! { pub const UndecidingTimeout: BlockNumber = 7 * DAYS; }
 
-// 方案B：已移除 referenda 轨道配置
+// 🆕 referenda 仅保留申诉升级轨道（见 AppealEscalationTracks）
 
 parameter_types! { pub const SubmissionDeposit: Balance = 0; }
 parameter_types! { pub const MaxQueued: u32 = 100; }
 parameter_types! { pub const AlarmInterval: BlockNumber = 10; }
 
-// 方案B：已移除 referenda 配置
+// ===== 🆕 申诉升级公投：preimage / scheduler / conviction-voting / referenda =====
+// 说明：仅开放 `appeal_escalation` 一条轨道，且只接受申诉模块的提交账户，
+//       其余 OpenGov 轨道仍按方案B保持关闭。
+
+parameter_types! {
+    pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+    pub const PreimageBaseDeposit: Balance = 1 * UNIT;
+    pub const PreimageByteDeposit: Balance = UNIT / 1_000;
+    pub const PreimageHoldReason: RuntimeHoldReason =
+        RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+    /// 函数级中文注释：申诉升级公投提交账户的 PalletId
+    pub const AppealEscalationPalletId: PalletId = PalletId(*b"ap/escal");
+}
+
+impl pallet_preimage::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+    type Currency = Balances;
+    type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type Consideration = frame_support::traits::fungible::HoldConsideration<
+        AccountId,
+        Balances,
+        PreimageHoldReason,
+        frame_support::traits::LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
+    >;
+}
+
+impl pallet_scheduler::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletsOrigin = crate::OriginCaller;
+    type RuntimeCall = RuntimeCall;
+    type MaximumWeight = MaximumSchedulerWeight;
+    type ScheduleOrigin = frame_system::EnsureRoot<AccountId>;
+    type OriginPrivilegeCmp = frame_support::traits::EqualPrivilegeOnly;
+    type MaxScheduledPerBlock = ConstU32<50>;
+    type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+    type Preimages = crate::Preimage;
+    type BlockNumberProvider = System;
+}
+
+impl pallet_conviction_voting::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_conviction_voting::weights::SubstrateWeight<Runtime>;
+    type Currency = Balances;
+    type Polls = crate::Referenda;
+    type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, AccountId>;
+    type MaxVotes = MaxVotes;
+    type VoteLockingPeriod = VoteLockingPeriod;
+    type BlockNumberProvider = System;
+    type VotingHooks = ();
+}
+
+/// 函数级详细中文注释：申诉升级公投的唯一提交账户（由 PalletId 派生）。
+/// - 作为 Referenda::SubmitOrigin 的白名单，普通账户无法直接提交公投。
+pub struct AppealEscalationSubmitter;
+impl AppealEscalationSubmitter {
+    pub fn account() -> AccountId {
+        AppealEscalationPalletId::get().into_account_truncating()
+    }
+}
+impl frame_support::traits::SortedMembers<AccountId> for AppealEscalationSubmitter {
+    fn sorted_members() -> Vec<AccountId> {
+        vec![Self::account()]
+    }
+}
+
+/// 函数级中文注释：将字符串转为定长轨道名（不足补 0）。
+const fn track_name(s: &str) -> [u8; 25] {
+    let bytes = s.as_bytes();
+    let mut out = [0u8; 25];
+    let mut i = 0;
+    while i < bytes.len() && i < 25 {
+        out[i] = bytes[i];
+        i += 1;
+    }
+    out
+}
+
+/// 函数级详细中文注释：公投轨道配置（仅申诉升级轨道）。
+/// - 轨道 0 `appeal_escalation`：提案起源为 Root（提案固定为 `finalize_escalation`）；
+/// - 决策押金 100 UNIT，决策期 7 天，确认期 1 天；
+/// - 通过率从 100% 线性降至 50%，支持率从 25% 线性降至 1%。
+pub struct AppealEscalationTracks;
+impl pallet_referenda::TracksInfo<Balance, BlockNumber> for AppealEscalationTracks {
+    type Id = u16;
+    type RuntimeOrigin = <RuntimeOrigin as OriginTrait>::PalletsOrigin;
+
+    fn tracks(
+    ) -> impl Iterator<Item = alloc::borrow::Cow<'static, pallet_referenda::Track<Self::Id, Balance, BlockNumber>>>
+    {
+        static DATA: [pallet_referenda::Track<u16, Balance, BlockNumber>; 1] = [pallet_referenda::Track {
+            id: 0,
+            info: pallet_referenda::TrackInfo {
+                name: track_name("appeal_escalation"),
+                max_deciding: 20,
+                decision_deposit: 100 * UNIT,
+                prepare_period: crate::HOURS,
+                decision_period: 7 * DAYS,
+                confirm_period: DAYS,
+                min_enactment_period: 10 * crate::MINUTES,
+                min_approval: pallet_referenda::Curve::LinearDecreasing {
+                    length: Perbill::from_percent(100),
+                    floor: Perbill::from_percent(50),
+                    ceil: Perbill::from_percent(100),
+                },
+                min_support: pallet_referenda::Curve::LinearDecreasing {
+                    length: Perbill::from_percent(100),
+                    floor: Perbill::from_percent(1),
+                    ceil: Perbill::from_percent(25),
+                },
+            },
+        }];
+        DATA.iter().map(alloc::borrow::Cow::Borrowed)
+    }
+
+    fn track_for(origin: &Self::RuntimeOrigin) -> Result<Self::Id, ()> {
+        match frame_system::RawOrigin::try_from(origin.clone()) {
+            Ok(frame_system::RawOrigin::Root) => Ok(0),
+            _ => Err(()),
+        }
+    }
+}
+
+impl pallet_referenda::Config for Runtime {
+    type WeightInfo = pallet_referenda::weights::SubstrateWeight<Runtime>;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type Scheduler = crate::Scheduler;
+    type Currency = Balances;
+    /// 函数级中文注释：仅申诉模块的提交账户可发起公投
+    type SubmitOrigin = frame_support::traits::AsEnsureOriginWithArg<
+        frame_system::EnsureSignedBy<AppealEscalationSubmitter, AccountId>,
+    >;
+    /// 函数级中文注释：取消公投：Root | 理事会 2/3
+    type CancelOrigin = frame_support::traits::EitherOfDiverse<
+        frame_system::EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionAtLeast<AccountId, pallet_collective::Instance1, 2, 3>,
+    >;
+    type KillOrigin = frame_system::EnsureRoot<AccountId>;
+    type Slash = ();
+    type Votes = Balance;
+    type Tally = pallet_conviction_voting::TallyOf<Runtime>;
+    type SubmissionDeposit = SubmissionDeposit;
+    type MaxQueued = MaxQueued;
+    type UndecidingTimeout = UndecidingTimeout;
+    type AlarmInterval = AlarmInterval;
+    type Tracks = AppealEscalationTracks;
+    type Preimages = crate::Preimage;
+    type BlockNumberProvider = System;
+}
 
 /// 函数级详细中文注释：初始化存储费用路由表
 /// - 设置默认的存储费用分配规则：
@@ -3492,6 +4355,12 @@ impl pallet_chat::Config for Runtime {
 
     /// 函数级中文注释：用户个性签名最大长度（256字节）
     type MaxSignatureLength = frame_support::traits::ConstU32<256>;
+
+    /// 函数级中文注释：治理禁言起源：Root | 内容委员会(Instance3，2/3)
+    type GovernanceOrigin = frame_support::traits::EitherOfDiverse<
+        frame_system::EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionAtLeast<AccountId, pallet_collective::Instance3, 2, 3>,
+    >;
 }
 
 // ========= 🆕 2025-11-13: Phase 3 AI Chat Integration =========
@@ -3743,9 +4612,10 @@ impl pallet_social::TargetValidator<AccountId> for SocialTargetValidator {
                 false  // 暂时禁用，等待新的 memorial-space 实现
             },
 
-            // 宠物：检查 pet 是否存在
+            // 宠物：检查 pet 是否存在（PetAccess）
             TargetType::Pet => {
-                pallet_stardust_pet::pallet::PetOf::<Runtime>::contains_key(target.target_id)
+                use pallet_stardust_pet::PetAccess;
+                pallet_stardust_pet::Pallet::<Runtime>::pet_exists(target.target_id)
             },
 
             // 纪念馆：暂时禁用，等待具体需求
@@ -3787,11 +4657,8 @@ impl pallet_social::TargetValidator<AccountId> for SocialTargetValidator {
 
             // 宠物：检查是否为 owner
             TargetType::Pet => {
-                if let Some(pet) = pallet_stardust_pet::pallet::PetOf::<Runtime>::get(target.target_id) {
-                    pet.owner == *who
-                } else {
-                    false
-                }
+                use pallet_stardust_pet::PetAccess;
+                pallet_stardust_pet::Pallet::<Runtime>::can_manage(who, target.target_id)
             },
 
             // 纪念馆：暂时禁用
@@ -3821,10 +4688,10 @@ impl pallet_social::TargetValidator<AccountId> for SocialTargetValidator {
             // 墓地：检查可见性（如果启用）
             TargetType::Grave => false,  // 🗑️ 2025-11-16: 已删除
 
-            // 宠物：默认公开（Pet 结构体没有可见性字段）
+            // 宠物：检查可见性设置（PetVisibilityOf 未设置视为公开）
             TargetType::Pet => {
-                // Pet 默认公开，只要存在就可见
-                pallet_stardust_pet::pallet::PetOf::<Runtime>::contains_key(target.target_id)
+                use pallet_stardust_pet::PetAccess;
+                pallet_stardust_pet::Pallet::<Runtime>::is_visible(target.target_id)
             },
 
             // 纪念馆：暂时禁用
@@ -4317,6 +5184,8 @@ impl pallet_divination_nft::Config for Runtime {
     type OfferValidityPeriod = frame_support::traits::ConstU32<100800>;
     type PlatformAccount = TreasuryAccount;
     type GovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+    /// 🆕 会员权益：卖家平台手续费折扣
+    type MembershipBenefits = pallet_membership::Pallet<Runtime>;
 }
 
 /// 函数级详细中文注释：通用占卜 AI 解读 Pallet 配置
@@ -4347,6 +5216,8 @@ impl pallet_divination_ai::Config for Runtime {
     type TreasuryAccount = TreasuryAccount;
     type ArbitratorOrigin = frame_system::EnsureRoot<AccountId>;
     type GovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+    /// 🆕 会员权益：会员每周期免费 AI 解读次数
+    type MembershipBenefits = pallet_membership::Pallet<Runtime>;
 }
 
 /// 函数级详细中文注释：通用占卜服务市场 Pallet 配置
@@ -4403,6 +5274,9 @@ impl pallet_divination_market::Config for Runtime {
 
     /// 国库账户
     type TreasuryAccount = TreasuryAccount;
+
+    /// 跨领域信誉（与 OTC 共用 pallet-credit 信誉引擎）
+    type Reputation = pallet_credit::Pallet<Runtime>;
 }
 
 // ========= 🆕 2025-11-30 塔罗牌排盘系统配置 =========
@@ -203,7 +203,17 @@ impl frame_system::offchain::SigningTypes for Runtime {
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = (); // 🆕 2025-10-28: RenameDeceasedMediaToData 已移除 - deceased-media整合到deceased
+// 🆕 2025-10-28: RenameDeceasedMediaToData 已移除 - deceased-media整合到deceased
+type Migrations = (
+    // 供奉台账统计维度由墓位改为纪念目标：清空按墓位记账的旧存储
+    pallet_ledger::migrations::MigrateV0ToV1<Runtime>,
+    // OTC 订单新增来源挂单字段：旧订单补 listing_id = None
+    pallet_otc_order::migrations::MigrateV0ToV1<Runtime>,
+    // 逝者族谱：回填已有有向关系（父母/子女）的反向索引
+    pallet_deceased::migrations::MigrateV6ToV7<Runtime>,
+    // 宠物：清空墓位关联（PetInGrave）旧存储
+    pallet_stardust_pet::migrations::MigrateV0ToV1<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -991,6 +1001,31 @@ pub mod runtime {
 	#[runtime::pallet_index(86)]
 	pub type Almanac = pallet_almanac;
 
+	// 🆕 申诉升级公投：被驳回的申诉可一次性升级为公投（仅开放申诉升级轨道）
+	/// 函数级中文注释：原像存储（公投/调度提案的调用数据）
+	#[runtime::pallet_index(87)]
+	pub type Preimage = pallet_preimage;
+
+	/// 函数级中文注释：调度器（公投决议的延迟执行）
+	#[runtime::pallet_index(88)]
+	pub type Scheduler = pallet_scheduler;
+
+	/// 函数级中文注释：信念投票（公投计票，代币持有者锁仓投票）
+	#[runtime::pallet_index(89)]
+	pub type ConvictionVoting = pallet_conviction_voting;
+
+	/// 函数级中文注释：公投（当前仅 `appeal_escalation` 轨道，由申诉模块提交）
+	#[runtime::pallet_index(90)]
+	pub type Referenda = pallet_referenda;
+
+	/// 函数级中文注释：虚拟纪念空间（逝者/家族纪念馆：布局、摆放物、访问控制、留言簿审核）
+	#[runtime::pallet_index(91)]
+	pub type MemorialSpace = pallet_memorial_space;
+
+	/// 函数级中文注释：同质化资产（托管模块的多资产托管）
+	#[runtime::pallet_index(92)]
+	pub type Assets = pallet_assets;
+
 	// 🆕 2025-11-03 Frontier: 以太坊兼容层（官方 Parity Pallet）
 	// ⚠️ 临时禁用以排查 runtime 启动问题
 	// /// 函数级中文注释：EVM 虚拟机（执行以太坊智能合约）
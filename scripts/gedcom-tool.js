@@ -0,0 +1,282 @@
+/**
+ * GEDCOM 族谱导入/导出工具（支持 GEDCOM 5.5.1 与 7.0 的常用子集）
+ *
+ * 导入：
+ *   - INDI 记录 → deceased.createDeceased（NAME / SEX / BIRT.DATE / DEAT.DATE）
+ *   - FAM 记录  → 关系提案：HUSB/WIFE 互为配偶（kind=1），父母 → 每个 CHIL（kind=0 ParentOf）
+ *   - 导入账户同时是双方拥有者，提案与批准在同一笔 utility.batchAll 中完成
+ *   - 个人 xref → 链上 DeceasedId 映射写入 <file>.map.json，中断后重跑会跳过已创建的个人
+ *
+ * 导出：
+ *   - 从起点逝者出发，通过 DeceasedGenealogyApi.relations_of 逐层展开亲属关系
+ *   - 配偶对与父母-子女关系还原为 FAM 记录，输出 GEDCOM 5.5.1
+ *
+ * 用法：
+ *   node gedcom-tool.js import family.ged
+ *   node gedcom-tool.js export <deceasedId> out.ged [--hops 6]
+ *
+ * 环境变量：WS（默认 ws://127.0.0.1:9944）、SIGNER_URI（默认 //Alice）
+ */
+const fs = require('fs');
+const { ApiPromise, WsProvider, Keyring } = require('@polkadot/api');
+const { cryptoWaitReady } = require('@polkadot/util-crypto');
+const { u8aToString } = require('@polkadot/util');
+
+const WS = process.env.WS || 'ws://127.0.0.1:9944';
+const KIND_PARENT_OF = 0;
+const KIND_SPOUSE_OF = 1;
+const KIND_SIBLING_OF = 2;
+const KIND_CHILD_OF = 3;
+const MAX_EXPORT_PEOPLE = 2000;
+
+const MONTHS = {
+  JAN: '01', FEB: '02', MAR: '03', APR: '04', MAY: '05', JUN: '06',
+  JUL: '07', AUG: '08', SEP: '09', OCT: '10', NOV: '11', DEC: '12',
+};
+const MONTH_NAMES = Object.fromEntries(Object.entries(MONTHS).map(([k, v]) => [v, k]));
+
+// ==================== GEDCOM 解析 ====================
+
+/** 解析 GEDCOM 文本为记录树：[{ level, xref, tag, value, children }] */
+function parseGedcom(text) {
+  const roots = [];
+  const stack = [];
+  for (const raw of text.replace(/^\uFEFF/, '').split(/\r?\n/)) {
+    const line = raw.trim();
+    if (!line) continue;
+    const m = line.match(/^(\d+)\s+(?:(@[^@]+@)\s+)?(\S+)(?:\s(.*))?$/);
+    if (!m) continue;
+    const node = { level: Number(m[1]), xref: m[2] || null, tag: m[3], value: m[4] || '', children: [] };
+
+    // CONC/CONT 续行并入上一级
+    if (node.tag === 'CONC' || node.tag === 'CONT') {
+      const parent = stack[node.level - 1];
+      if (parent) parent.value += (node.tag === 'CONT' ? '\n' : '') + node.value;
+      continue;
+    }
+    stack.length = node.level;
+    if (node.level === 0) roots.push(node);
+    else if (stack[node.level - 1]) stack[node.level - 1].children.push(node);
+    stack[node.level] = node;
+  }
+  return roots;
+}
+
+const child = (node, tag) => node.children.find((c) => c.tag === tag);
+const childValues = (node, tag) => node.children.filter((c) => c.tag === tag).map((c) => c.value);
+
+/** GEDCOM 日期 → YYYYMMDD，未知部分补 0（如 "ABT MAR 1930" → 19300300） */
+function toYyyymmdd(date) {
+  if (!date) return '00000000';
+  const tokens = date.toUpperCase().replace(/^(ABT|EST|CAL|BEF|AFT|FROM|BET|TO)\s+/, '').split(/\s+/);
+  let day = '00';
+  let month = '00';
+  let year = '0000';
+  for (const t of tokens) {
+    if (MONTHS[t]) month = MONTHS[t];
+    else if (/^\d{3,4}$/.test(t) && year === '0000') year = t.padStart(4, '0');
+    else if (/^\d{1,2}$/.test(t)) day = t.padStart(2, '0');
+  }
+  return `${year}${month}${day}`;
+}
+
+/** YYYYMMDD → GEDCOM 日期（补 0 的部分省略） */
+function toGedcomDate(ymd) {
+  if (!ymd || !/^\d{8}$/.test(ymd) || ymd.startsWith('0000')) return null;
+  const [y, m, d] = [ymd.slice(0, 4), ymd.slice(4, 6), ymd.slice(6, 8)];
+  return [d !== '00' ? String(Number(d)) : null, MONTH_NAMES[m] || null, y].filter(Boolean).join(' ');
+}
+
+// ==================== 链上交互 ====================
+
+/** 提交交易并等待入块，返回事件列表；失败时抛出模块错误 */
+function submit(api, tx, signer) {
+  return new Promise((resolve, reject) => {
+    tx.signAndSend(signer, ({ status, dispatchError, events }) => {
+      if (!(status.isInBlock || status.isFinalized)) return;
+      if (dispatchError) {
+        if (dispatchError.isModule) {
+          const decoded = api.registry.findMetaError(dispatchError.asModule);
+          reject(new Error(`${decoded.section}.${decoded.name}: ${decoded.docs.join(' ')}`));
+        } else {
+          reject(new Error(dispatchError.toString()));
+        }
+      } else {
+        resolve(events);
+      }
+    }).catch(reject);
+  });
+}
+
+/** 提案并批准一条关系（导入账户同时是双方拥有者） */
+async function relate(api, signer, from, to, kind) {
+  const tx = api.tx.utility.batchAll([
+    api.tx.deceased.proposeRelation(from, to, kind, null),
+    api.tx.deceased.approveRelation(from, to),
+  ]);
+  try {
+    await submit(api, tx, signer);
+    return true;
+  } catch (e) {
+    // 重跑时已存在的关系直接跳过
+    if (/RelationExists|PendingApproval/.test(e.message)) return false;
+    throw e;
+  }
+}
+
+async function importGedcom(api, signer, file) {
+  const records = parseGedcom(fs.readFileSync(file, 'utf8'));
+  const people = records.filter((r) => r.tag === 'INDI');
+  const families = records.filter((r) => r.tag === 'FAM');
+  const mapFile = `${file}.map.json`;
+  const idMap = fs.existsSync(mapFile) ? JSON.parse(fs.readFileSync(mapFile, 'utf8')) : {};
+  console.log(`📖 ${file}: ${people.length} 个人, ${families.length} 个家庭`);
+
+  for (const indi of people) {
+    if (idMap[indi.xref]) continue;
+    const nameNode = child(indi, 'NAME');
+    const name = (nameNode ? nameNode.value : 'Unknown').replace(/\//g, '').replace(/\s+/g, ' ').trim();
+    const sex = (child(indi, 'SEX') || {}).value;
+    const birth = toYyyymmdd((child(child(indi, 'BIRT') || { children: [] }, 'DATE') || {}).value);
+    const death = toYyyymmdd((child(child(indi, 'DEAT') || { children: [] }, 'DATE') || {}).value);
+
+    const tx = api.tx.deceased.createDeceased(name, sex === 'F' ? 1 : 0, null, birth, death, []);
+    const events = await submit(api, tx, signer);
+    const created = events.find(({ event }) =>
+      event.section === 'deceased' && event.method === 'DeceasedCreated');
+    if (!created) throw new Error(`${indi.xref} 创建后未找到 DeceasedCreated 事件`);
+    idMap[indi.xref] = created.event.data[0].toString();
+    fs.writeFileSync(mapFile, JSON.stringify(idMap, null, 2));
+    console.log(`  👤 ${indi.xref} ${name} → ${idMap[indi.xref]}`);
+  }
+
+  let linked = 0;
+  for (const fam of families) {
+    const parents = [...childValues(fam, 'HUSB'), ...childValues(fam, 'WIFE')]
+      .map((x) => idMap[x]).filter(Boolean);
+    const children = childValues(fam, 'CHIL').map((x) => idMap[x]).filter(Boolean);
+    if (parents.length === 2 && await relate(api, signer, parents[0], parents[1], KIND_SPOUSE_OF)) linked += 1;
+    for (const p of parents) {
+      for (const c of children) {
+        if (await relate(api, signer, p, c, KIND_PARENT_OF)) linked += 1;
+      }
+    }
+    console.log(`  👪 ${fam.xref}: ${parents.length} 父母, ${children.length} 子女`);
+  }
+  console.log(`✅ 导入完成：映射已写入 ${mapFile}，新建关系 ${linked} 条`);
+}
+
+async function exportGedcom(api, rootId, file, hops) {
+  const people = new Map(); // id → { name, gender, birth, death }
+  const parentsOf = new Map(); // childId → Set(parentId)
+  const spouses = new Set(); // "a:b"（a < b）
+  const queue = [[String(rootId), 0]];
+  const seen = new Set([String(rootId)]);
+
+  while (queue.length && people.size < MAX_EXPORT_PEOPLE) {
+    const [id, hop] = queue.shift();
+    const info = await api.query.deceased.deceasedOf(id);
+    if (info.isNone) continue;
+    const d = info.unwrap();
+    const text = (v) => (v && v.isSome ? u8aToString(v.unwrap()) : '');
+    people.set(id, {
+      name: u8aToString(d.name),
+      gender: d.gender.isF ? 'F' : 'M',
+      birth: text(d.birthTs),
+      death: text(d.deathTs),
+    });
+
+    const links = await api.call.deceasedGenealogyApi.relationsOf(id);
+    for (const link of links) {
+      const peer = link.peer.toString();
+      const kind = link.kind.toNumber();
+      const outgoing = link.outgoing.isTrue;
+      if (kind === KIND_SPOUSE_OF) {
+        spouses.add([id, peer].sort().join(':'));
+      } else if (kind === KIND_PARENT_OF || kind === KIND_CHILD_OF) {
+        // 有向关系：本人为 from 时 ParentOf 表示本人是父母，ChildOf 表示对方是父母
+        const parentIsSelf = (kind === KIND_PARENT_OF) === outgoing;
+        const [p, c] = parentIsSelf ? [id, peer] : [peer, id];
+        if (!parentsOf.has(c)) parentsOf.set(c, new Set());
+        parentsOf.get(c).add(p);
+      } else if (kind !== KIND_SIBLING_OF) {
+        continue;
+      }
+      if (hop < hops && !seen.has(peer)) {
+        seen.add(peer);
+        queue.push([peer, hop + 1]);
+      }
+    }
+  }
+
+  // 家庭：配偶对 + 按父母集合归并子女
+  const families = new Map(); // "p1:p2" → { parents, children }
+  const family = (parents) => {
+    const key = [...parents].sort().join(':');
+    if (!families.has(key)) families.set(key, { parents: key.split(':'), children: [] });
+    return families.get(key);
+  };
+  spouses.forEach((key) => family(key.split(':')));
+  parentsOf.forEach((parents, c) => {
+    const known = [...parents].filter((p) => people.has(p));
+    if (known.length && people.has(c)) family(known).children.push(c);
+  });
+
+  const xref = (id) => `@I${id}@`;
+  const lines = ['0 HEAD', '1 SOUR STARDUST', '1 GEDC', '2 VERS 5.5.1', '2 FORM LINEAGE-LINKED', '1 CHAR UTF-8'];
+  const famIds = new Map();
+  [...families.keys()].forEach((key, i) => famIds.set(key, `@F${i + 1}@`));
+
+  people.forEach((p, id) => {
+    lines.push(`0 ${xref(id)} INDI`, `1 NAME ${p.name}`, `1 SEX ${p.gender}`);
+    const birth = toGedcomDate(p.birth);
+    const death = toGedcomDate(p.death);
+    if (birth) lines.push('1 BIRT', `2 DATE ${birth}`);
+    lines.push('1 DEAT', ...(death ? [`2 DATE ${death}`] : []));
+    lines.push(`1 REFN ${id}`, '2 TYPE DeceasedId');
+    families.forEach((f, key) => {
+      if (f.parents.includes(id)) lines.push(`1 FAMS ${famIds.get(key)}`);
+      if (f.children.includes(id)) lines.push(`1 FAMC ${famIds.get(key)}`);
+    });
+  });
+
+  families.forEach((f, key) => {
+    lines.push(`0 ${famIds.get(key)} FAM`);
+    f.parents.filter((p) => people.has(p)).forEach((p) => {
+      lines.push(`1 ${people.get(p).gender === 'F' ? 'WIFE' : 'HUSB'} ${xref(p)}`);
+    });
+    f.children.forEach((c) => lines.push(`1 CHIL ${xref(c)}`));
+  });
+  lines.push('0 TRLR');
+
+  fs.writeFileSync(file, `${lines.join('\n')}\n`);
+  console.log(`✅ 导出完成：${people.size} 个人, ${families.size} 个家庭 → ${file}`);
+}
+
+async function main() {
+  const [cmd, arg1, arg2] = process.argv.slice(2);
+  if (!['import', 'export'].includes(cmd) || !arg1 || (cmd === 'export' && !arg2)) {
+    console.log('用法: node gedcom-tool.js import <file.ged> | export <deceasedId> <out.ged> [--hops N]');
+    process.exit(1);
+  }
+
+  await cryptoWaitReady();
+  const api = await ApiPromise.create({ provider: new WsProvider(WS) });
+  try {
+    if (cmd === 'import') {
+      const signer = new Keyring({ type: 'sr25519' }).addFromUri(process.env.SIGNER_URI || '//Alice');
+      await importGedcom(api, signer, arg1);
+    } else {
+      const hopsIdx = process.argv.indexOf('--hops');
+      const hops = hopsIdx !== -1 ? Number(process.argv[hopsIdx + 1]) : 6;
+      await exportGedcom(api, arg1, arg2, hops);
+    }
+  } finally {
+    await api.disconnect();
+  }
+}
+
+main().catch((e) => {
+  console.error(e);
+  process.exit(1);
+});
@@ -0,0 +1,134 @@
+/**
+ * OTC 付款看守服务（本地替身，仅用于开发/测试）
+ *
+ * 真实部署中，看守服务接收银行/支付渠道的收款回调，对
+ * "订单 N 已收到 X 法币" 声明签名后调用 otcOrder.attestPayment 上链。
+ * 本脚本用本地 HTTP 端点模拟回调：
+ *
+ *   POST http://127.0.0.1:8787/webhook
+ *   {"orderId": 12, "amount": 100000000, "currency": "CNY"}
+ *
+ * amount 为按收款渠道汇率折合的 USD 金额（精度 10^6，与订单金额同单位），
+ * 是否足额由链上按订单金额判定，看守服务不再自行声明。
+ *
+ * 用法：
+ *   node otc-payment-watcher.js --register <makerId>   # 做市商(//Alice)登记看守账户(//Ferdie)
+ *   node otc-payment-watcher.js                        # 启动回调端点
+ *   node otc-payment-watcher.js --auto                 # 买家标记付款后按订单金额自动证明（联调用）
+ *
+ * 环境变量：WS（默认 ws://127.0.0.1:9944）、MAKER_URI（默认 //Alice）、
+ *          WATCHER_URI（默认 //Ferdie）、PORT（默认 8787）
+ */
+const http = require('http');
+const { ApiPromise, WsProvider, Keyring } = require('@polkadot/api');
+const { cryptoWaitReady, blake2AsHex } = require('@polkadot/util-crypto');
+const { stringToU8a, u8aConcat, u8aToHex } = require('@polkadot/util');
+
+const WS = process.env.WS || 'ws://127.0.0.1:9944';
+const PORT = Number(process.env.PORT || 8787);
+const STATE_PAID = 1; // OrderState::PaidOrCommitted
+
+/** 提交交易并等待入块，失败时抛出模块错误 */
+function submit(api, tx, signer) {
+  return new Promise((resolve, reject) => {
+    tx.signAndSend(signer, ({ status, dispatchError }) => {
+      if (!(status.isInBlock || status.isFinalized)) return;
+      if (dispatchError) {
+        if (dispatchError.isModule) {
+          const decoded = api.registry.findMetaError(dispatchError.asModule);
+          reject(new Error(`${decoded.section}.${decoded.name}: ${decoded.docs.join(' ')}`));
+        } else {
+          reject(new Error(dispatchError.toString()));
+        }
+      } else {
+        resolve(status.isInBlock ? status.asInBlock : status.asFinalized);
+      }
+    }).catch(reject);
+  });
+}
+
+/** 对付款声明签名，返回上链用的声明哈希（签名原文留在本地日志备查） */
+function signStatement(watcher, { orderId, amount, currency }) {
+  const statement = `otc-payment:${orderId}:${amount}:${currency}:${Date.now()}`;
+  const signature = watcher.sign(stringToU8a(statement));
+  const statementHash = blake2AsHex(u8aConcat(stringToU8a(statement), signature), 256);
+  console.log(`📝 声明: ${statement}`);
+  console.log(`   签名: ${u8aToHex(signature)}`);
+  return statementHash;
+}
+
+async function attest(api, watcher, payload) {
+  const currency = (payload.currency || 'CNY').padEnd(3).slice(0, 3);
+  const statementHash = signStatement(watcher, { ...payload, currency });
+  const tx = api.tx.otcOrder.attestPayment(
+    payload.orderId,
+    payload.amount,
+    stringToU8a(currency),
+    statementHash
+  );
+  const block = await submit(api, tx, watcher);
+  console.log(`✅ 订单 ${payload.orderId} 付款证明已上链，区块: ${block}`);
+}
+
+async function main() {
+  await cryptoWaitReady();
+  const api = await ApiPromise.create({ provider: new WsProvider(WS) });
+  const keyring = new Keyring({ type: 'sr25519' });
+  const maker = keyring.addFromUri(process.env.MAKER_URI || '//Alice');
+  const watcher = keyring.addFromUri(process.env.WATCHER_URI || '//Ferdie');
+
+  const registerIdx = process.argv.indexOf('--register');
+  if (registerIdx !== -1) {
+    const makerId = Number(process.argv[registerIdx + 1]);
+    console.log(`🔑 做市商 ${makerId} 登记看守账户 ${watcher.address}`);
+    await submit(api, api.tx.otcOrder.setPaymentWatcher(makerId, watcher.address), maker);
+    console.log('✅ 登记成功');
+    await api.disconnect();
+    return;
+  }
+
+  if (process.argv.includes('--auto')) {
+    console.log('🤖 自动模式：监听买家标记付款事件');
+    api.query.system.events((events) => {
+      events.forEach(({ event }) => {
+        if (!api.events.otcOrder.OrderStateChanged.is(event)) return;
+        const [orderId, , newState] = event.data;
+        if (newState.toNumber() !== STATE_PAID) return;
+        api.query.otcOrder.orders(orderId).then((order) => {
+          if (order.isNone) return;
+          // 联调用：按订单金额足额证明（amount = 数量 × 单价，折合 USD 需除以 DUST 精度 10^12）
+          const amount = (BigInt(order.unwrap().amount.toString()) / 1_000_000_000_000n).toString();
+          attest(api, watcher, { orderId: orderId.toNumber(), amount, currency: 'CNY' })
+            .catch((e) => console.error(`❌ 订单 ${orderId} 证明失败: ${e.message}`));
+        });
+      });
+    });
+  }
+
+  http.createServer((req, res) => {
+    if (req.method !== 'POST' || req.url !== '/webhook') {
+      res.writeHead(404).end();
+      return;
+    }
+    let body = '';
+    req.on('data', (chunk) => { body += chunk; });
+    req.on('end', async () => {
+      try {
+        const payload = JSON.parse(body);
+        await attest(api, watcher, payload);
+        res.writeHead(200, { 'Content-Type': 'application/json' }).end('{"ok":true}');
+      } catch (e) {
+        console.error(`❌ 回调处理失败: ${e.message}`);
+        res.writeHead(400, { 'Content-Type': 'application/json' })
+          .end(JSON.stringify({ ok: false, error: e.message }));
+      }
+    });
+  }).listen(PORT, '127.0.0.1', () => {
+    console.log(`🏦 付款回调端点: http://127.0.0.1:${PORT}/webhook（看守账户 ${watcher.address}）`);
+  });
+}
+
+main().catch((e) => {
+  console.error(e);
+  process.exit(1);
+});
@@ -104,6 +104,48 @@ impl CacheClient {
         Ok(count)
     }
 
+    /// 获取并删除缓存值（一次性凭证，如登录 nonce）
+    pub async fn take<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, ApiError> {
+        let mut conn = self.manager.clone();
+        let value: Option<String> = redis::cmd("GETDEL")
+            .arg(key)
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| ApiError::Cache(format!("Failed to take key {}: {}", key, e)))?;
+
+        match value {
+            Some(v) => {
+                let data = serde_json::from_str(&v)
+                    .map_err(|e| ApiError::Cache(format!("Failed to deserialize: {}", e)))?;
+                Ok(Some(data))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// 固定窗口计数（首次计数时设置窗口过期时间），返回窗口内的累计次数
+    ///
+    /// INCR 与 EXPIRE 在同一 Lua 脚本中原子执行：即使进程在两步之间崩溃或连接中断，
+    /// 也不会留下没有过期时间、永久计数的 key。
+    pub async fn incr_window(&self, key: &str, window_secs: usize) -> Result<u64, ApiError> {
+        let mut conn = self.manager.clone();
+        let script = redis::Script::new(
+            r"
+            local count = redis.call('INCR', KEYS[1])
+            if count == 1 or redis.call('TTL', KEYS[1]) == -1 then
+                redis.call('EXPIRE', KEYS[1], ARGV[1])
+            end
+            return count
+            ",
+        );
+        script
+            .key(key)
+            .arg(window_secs)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| ApiError::Cache(format!("Failed to incr key {}: {}", key, e)))
+    }
+
     /// 检查 key 是否存在
     pub async fn exists(&self, key: &str) -> Result<bool, ApiError> {
         let mut conn = self.manager.clone();
@@ -85,6 +85,21 @@ impl DivinationClient {
             .await
     }
 
+    /// 八字排盘
+    pub async fn bazi_calculate(&self, request: BaziRequest) -> Result<BaziResponse, ApiError> {
+        self.post_with_retry("/api/bazi/calculate", &request)
+            .await
+    }
+
+    /// 梅花易数起卦
+    pub async fn meihua_calculate(
+        &self,
+        request: MeihuaRequest,
+    ) -> Result<MeihuaResponse, ApiError> {
+        self.post_with_retry("/api/meihua/calculate", &request)
+            .await
+    }
+
     /// 通用 POST 请求（带重试）
     async fn post_with_retry<T: Serialize, R: for<'de> Deserialize<'de>>(
         &self,
@@ -280,6 +295,70 @@ pub struct CardMeaning {
     pub meaning: String,
 }
 
+/// 八字请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaziRequest {
+    /// 输入日历: "solar" | "lunar"
+    #[serde(default = "default_calendar")]
+    pub calendar: String,
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    #[serde(default)]
+    pub minute: u8,
+    /// 农历闰月（仅农历输入有效）
+    #[serde(default)]
+    pub is_leap_month: bool,
+    /// 性别: "male" | "female"
+    pub gender: String,
+    /// 子时模式: "traditional" | "modern"
+    #[serde(default = "default_zishi_mode")]
+    pub zishi_mode: String,
+    /// 出生地经度（1/100000 度，可选，用于真太阳时修正）
+    pub longitude: Option<i32>,
+}
+
+fn default_calendar() -> String {
+    "solar".to_string()
+}
+
+fn default_zishi_mode() -> String {
+    "modern".to_string()
+}
+
+/// 八字响应
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BaziResponse {
+    pub chart: serde_json::Value, // 命盘数据
+}
+
+/// 梅花易数请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeihuaRequest {
+    pub method: String, // 起卦方式: "time" | "numbers" | "manual"
+    /// 公历时间（仅远程占卜服务使用；链上 time/numbers 起卦按出块时间取时辰）
+    pub year: Option<u32>,
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+    pub hour: Option<u8>,
+    /// 双数起卦
+    pub num1: Option<u16>,
+    pub num2: Option<u16>,
+    /// 手动起卦
+    pub shang_gua: Option<u8>,
+    pub xia_gua: Option<u8>,
+    pub dong_yao: Option<u8>,
+    pub question: String,
+}
+
+/// 梅花易数响应
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MeihuaResponse {
+    pub hexagram: serde_json::Value, // 本卦/变卦/互卦/体用
+    pub fortune: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
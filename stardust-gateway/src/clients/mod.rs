@@ -5,8 +5,12 @@ pub mod substrate;
 
 pub use cache::CacheClient;
 pub use divination::{
-    DivinationClient, DaliurenRequest, DaliurenResponse, LiuyaoRequest, LiuyaoResponse,
-    QimenRequest, QimenResponse, TarotRequest, TarotResponse, XiaoliurenRequest,
-    XiaoliurenResponse, ZiweiRequest, ZiweiResponse,
+    BaziRequest, BaziResponse, DivinationClient, DaliurenRequest, DaliurenResponse,
+    LiuyaoRequest, LiuyaoResponse, MeihuaRequest, MeihuaResponse, QimenRequest, QimenResponse,
+    TarotRequest, TarotResponse, XiaoliurenRequest, XiaoliurenResponse, ZiweiRequest,
+    ZiweiResponse,
+};
+pub use substrate::{
+    ChainBlock, DivinationRecord, MortalEra, RawEvent, RuntimeVersionInfo, SubstrateClient,
+    UnsignedPayload,
 };
-pub use substrate::{DivinationRecord, RuntimeVersionInfo, SubstrateClient};
@@ -2,21 +2,39 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::Arc;
-use subxt::{OnlineClient, PolkadotConfig};
+use futures::{Stream, StreamExt};
+use subxt::{
+    backend::{legacy::LegacyRpcMethods, rpc::RpcClient},
+    blocks::Block,
+    config::polkadot::PolkadotExtrinsicParamsBuilder,
+    dynamic::Value as DynValue,
+    tx::SubmittableExtrinsic,
+    utils::AccountId32,
+    OnlineClient, PolkadotConfig,
+};
 
 use crate::models::ApiError;
 
+/// 待签名交易的有效期（区块数，自检查点区块起算）
+const MORTALITY_PERIOD: u64 = 64;
+
 /// Substrate 客户端
 #[derive(Clone)]
 pub struct SubstrateClient {
     /// Subxt 客户端
     client: Arc<OnlineClient<PolkadotConfig>>,
+    /// 传统 RPC 方法（按区块号查询哈希，用于断线重连后补发历史区块）
+    rpc: LegacyRpcMethods<PolkadotConfig>,
 }
 
 impl SubstrateClient {
     /// 连接到 Substrate 节点
     pub async fn connect(ws_url: &str) -> Result<Self, ApiError> {
-        let client = OnlineClient::<PolkadotConfig>::from_url(ws_url)
+        let rpc_client = RpcClient::from_url(ws_url).await.map_err(|e| {
+            ApiError::Blockchain(format!("Failed to connect to Substrate node: {}", e))
+        })?;
+
+        let client = OnlineClient::<PolkadotConfig>::from_rpc_client(rpc_client.clone())
             .await
             .map_err(|e| {
                 ApiError::Blockchain(format!("Failed to connect to Substrate node: {}", e))
@@ -26,6 +44,7 @@ impl SubstrateClient {
 
         Ok(Self {
             client: Arc::new(client),
+            rpc: LegacyRpcMethods::new(rpc_client),
         })
     }
 
@@ -94,12 +113,153 @@ impl SubstrateClient {
         ))
     }
 
+    /// 构造待签名交易（网关不持有私钥，由用户钱包签名后再提交）
+    ///
+    /// 以最新区块为检查点构造有效期 `MORTALITY_PERIOD` 块的 mortal 交易，小费为 0。
+    /// 除 call data 与签名载荷外，同时返回签名扩展的全部参数（era、nonce、tip、
+    /// spec/transaction 版本、创世哈希），钱包据此组装与签名载荷一致的 extrinsic。
+    pub async fn prepare_extrinsic(
+        &self,
+        pallet: &str,
+        call: &str,
+        fields: Vec<DynValue>,
+        signer_address: &str,
+    ) -> Result<UnsignedPayload, ApiError> {
+        let account_id: AccountId32 = signer_address
+            .parse()
+            .map_err(|e| ApiError::BadRequest(format!("Invalid address: {:?}", e)))?;
+
+        let payload = subxt::dynamic::tx(pallet, call, fields);
+
+        let call_data = self
+            .client
+            .tx()
+            .call_data(&payload)
+            .map_err(|e| ApiError::BadRequest(format!("Failed to encode call: {}", e)))?;
+
+        let nonce = self
+            .client
+            .tx()
+            .account_nonce(&account_id)
+            .await
+            .map_err(|e| ApiError::Blockchain(format!("Failed to get account nonce: {}", e)))?;
+
+        let checkpoint = self
+            .client
+            .blocks()
+            .at_latest()
+            .await
+            .map_err(|e| ApiError::Blockchain(format!("Failed to get latest block: {}", e)))?;
+        let checkpoint_number = checkpoint.number() as u64;
+        let checkpoint_hash = checkpoint.hash();
+        let tip: u128 = 0;
+
+        let params = PolkadotExtrinsicParamsBuilder::new()
+            .nonce(nonce)
+            .tip(tip)
+            .mortal_unchecked(checkpoint_number, checkpoint_hash, MORTALITY_PERIOD)
+            .build();
+
+        let partial = self
+            .client
+            .tx()
+            .create_partial_signed_offline(&payload, params)
+            .map_err(|e| ApiError::Blockchain(format!("Failed to build extrinsic: {}", e)))?;
+
+        let runtime = self.client.runtime_version();
+
+        Ok(UnsignedPayload {
+            pallet: pallet.to_string(),
+            call: call.to_string(),
+            call_data: format!("0x{}", hex::encode(call_data)),
+            signer_payload: format!("0x{}", hex::encode(partial.signer_payload())),
+            nonce,
+            era: MortalEra {
+                period: MORTALITY_PERIOD,
+                checkpoint_number,
+                checkpoint_hash: format!("0x{}", hex::encode(checkpoint_hash.0)),
+            },
+            tip: tip.to_string(),
+            spec_version: runtime.spec_version,
+            transaction_version: runtime.transaction_version,
+            genesis_hash: format!("0x{}", hex::encode(self.client.genesis_hash().0)),
+        })
+    }
+
     /// 提交交易（签名后的 extrinsic）
     pub async fn submit_extrinsic(&self, signed_extrinsic: Vec<u8>) -> Result<String, ApiError> {
-        // TODO: 实现交易提交逻辑
-        Err(ApiError::Internal(
-            "Extrinsic submission not implemented".to_string(),
-        ))
+        let hash = SubmittableExtrinsic::from_bytes((*self.client).clone(), signed_extrinsic)
+            .submit()
+            .await
+            .map_err(|e| ApiError::Blockchain(format!("Failed to submit extrinsic: {}", e)))?;
+
+        Ok(format!("0x{}", hex::encode(hash.0)))
+    }
+
+    /// 订阅最终确认区块
+    pub async fn subscribe_finalized(
+        &self,
+    ) -> Result<impl Stream<Item = Result<ChainBlock, ApiError>>, ApiError> {
+        let stream = self
+            .client
+            .blocks()
+            .subscribe_finalized()
+            .await
+            .map_err(|e| ApiError::Blockchain(format!("Failed to subscribe blocks: {}", e)))?;
+
+        Ok(stream.map(|block| {
+            block.map_err(|e| ApiError::Blockchain(format!("Block subscription error: {}", e)))
+        }))
+    }
+
+    /// 按区块号获取区块（用于补发断线期间的历史区块）
+    pub async fn block_at_number(&self, number: u64) -> Result<Option<ChainBlock>, ApiError> {
+        let hash = self
+            .rpc
+            .chain_get_block_hash(Some(number.into()))
+            .await
+            .map_err(|e| ApiError::Blockchain(format!("Failed to get block hash: {}", e)))?;
+
+        match hash {
+            Some(hash) => {
+                let block = self.client.blocks().at(hash).await.map_err(|e| {
+                    ApiError::Blockchain(format!("Failed to get block {}: {}", number, e))
+                })?;
+                Ok(Some(block))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// 解码区块内全部事件（字段转为 JSON，账户以 32 字节数组表示）
+    pub async fn block_events(&self, block: &ChainBlock) -> Result<Vec<RawEvent>, ApiError> {
+        let events = block
+            .events()
+            .await
+            .map_err(|e| ApiError::Blockchain(format!("Failed to fetch events: {}", e)))?;
+
+        let block_number = block.number() as u64;
+        let block_hash = format!("0x{}", hex::encode(block.hash().0));
+        let mut out = Vec::new();
+
+        for event in events.iter() {
+            let event = event
+                .map_err(|e| ApiError::Blockchain(format!("Failed to decode event: {}", e)))?;
+            let fields = event
+                .field_values()
+                .map_err(|e| ApiError::Blockchain(format!("Failed to decode fields: {}", e)))?;
+
+            out.push(RawEvent {
+                block_number,
+                block_hash: block_hash.clone(),
+                event_index: event.index(),
+                pallet: event.pallet_name().to_string(),
+                variant: event.variant_name().to_string(),
+                fields: serde_json::to_value(&fields).unwrap_or(Value::Null),
+            });
+        }
+
+        Ok(out)
     }
 
     /// 健康检查
@@ -120,6 +280,56 @@ impl SubstrateClient {
     }
 }
 
+/// 链上区块
+pub type ChainBlock = Block<PolkadotConfig, OnlineClient<PolkadotConfig>>;
+
+/// 解码后的原始链上事件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawEvent {
+    pub block_number: u64,
+    pub block_hash: String,
+    pub event_index: u32,
+    pub pallet: String,
+    pub variant: String,
+    pub fields: Value,
+}
+
+/// 待签名交易载荷
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnsignedPayload {
+    /// Pallet 名称
+    pub pallet: String,
+    /// 调用名称
+    pub call: String,
+    /// SCALE 编码的 call data（hex）
+    pub call_data: String,
+    /// 钱包需要签名的载荷（hex）
+    pub signer_payload: String,
+    /// 签名账户当前 nonce
+    pub nonce: u64,
+    /// 交易有效期（mortal era）
+    pub era: MortalEra,
+    /// 小费（十进制字符串，避免 u128 超出 JSON 数值精度）
+    pub tip: String,
+    /// 构造时的 Runtime spec 版本
+    pub spec_version: u32,
+    /// 构造时的 Runtime 交易版本
+    pub transaction_version: u32,
+    /// 创世区块哈希（hex）
+    pub genesis_hash: String,
+}
+
+/// 交易有效期：自检查点区块起 `period` 块内有效
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MortalEra {
+    /// 有效期（区块数）
+    pub period: u64,
+    /// 检查点区块号
+    pub checkpoint_number: u64,
+    /// 检查点区块哈希（hex，签名载荷中 CheckMortality 的附加数据）
+    pub checkpoint_hash: String,
+}
+
 /// Runtime 版本信息
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RuntimeVersionInfo {
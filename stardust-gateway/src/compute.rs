@@ -0,0 +1,151 @@
+// 本地排盘模块 - 直接调用链上 pallet 的算法，保证链下与链上结果一致
+use crate::{
+    clients::{BaziRequest, BaziResponse, MeihuaRequest, MeihuaResponse},
+    models::ApiError,
+};
+
+/// 本地八字排盘
+#[cfg(feature = "local-compute")]
+pub fn bazi_chart(req: &BaziRequest) -> Result<BaziResponse, ApiError> {
+    use pallet_bazi_chart::{
+        interpretation::calculate_temp_chart,
+        types::{Gender, InputCalendarType, ZiShiMode},
+    };
+
+    let gender = match req.gender.as_str() {
+        "male" => Gender::Male,
+        "female" => Gender::Female,
+        other => return Err(ApiError::Validation(format!("Invalid gender: {}", other))),
+    };
+    let zishi_mode = match req.zishi_mode.as_str() {
+        "traditional" => ZiShiMode::Traditional,
+        "modern" => ZiShiMode::Modern,
+        other => return Err(ApiError::Validation(format!("Invalid zishi_mode: {}", other))),
+    };
+
+    // 农历先转公历（与链上 calculate_bazi_temp_unified 相同的转换）
+    let (year, month, day, calendar) = match req.calendar.as_str() {
+        "solar" => (req.year, req.month, req.day, InputCalendarType::Solar),
+        "lunar" => {
+            let (y, m, d) = pallet_almanac::lunar::lunar_to_solar(
+                req.year,
+                req.month,
+                req.day,
+                req.is_leap_month,
+            )
+            .ok_or_else(|| ApiError::Validation("Invalid lunar date".to_string()))?;
+            (y, m, d, InputCalendarType::Lunar)
+        }
+        other => return Err(ApiError::Validation(format!("Invalid calendar: {}", other))),
+    };
+
+    let chart = calculate_temp_chart(
+        year,
+        month,
+        day,
+        req.hour,
+        req.minute,
+        gender,
+        zishi_mode,
+        req.longitude,
+        calendar,
+    )
+    .ok_or_else(|| ApiError::Validation("Invalid birth time".to_string()))?;
+
+    Ok(BaziResponse {
+        chart: serde_json::from_str(&chart.to_debug_json())?,
+    })
+}
+
+/// 本地梅花易数起卦
+#[cfg(feature = "local-compute")]
+pub fn meihua_hexagram(req: &MeihuaRequest) -> Result<MeihuaResponse, ApiError> {
+    use pallet_meihua::algorithm;
+
+    let missing = |field: &str| ApiError::Validation(format!("Missing field: {}", field));
+
+    // 时间/双数起卦在链上按出块时间取时辰，不提供本地预览（由路由层拒绝）
+    let (shang, xia, dong) = match req.method.as_str() {
+        "manual" => {
+            let shang = req.shang_gua.ok_or_else(|| missing("shang_gua"))?;
+            let xia = req.xia_gua.ok_or_else(|| missing("xia_gua"))?;
+            let dong = req.dong_yao.ok_or_else(|| missing("dong_yao"))?;
+            if !(1..=8).contains(&shang) || !(1..=8).contains(&xia) || !(1..=6).contains(&dong) {
+                return Err(ApiError::Validation("Invalid gua number".to_string()));
+            }
+            (shang, xia, dong)
+        }
+        other => return Err(ApiError::Validation(format!("Invalid method: {}", other))),
+    };
+
+    let (ben_shang, ben_xia, bian_shang, bian_xia, hu_shang, hu_xia, ti_is_shang, ben_rel, bian_rel, fortune) =
+        algorithm::full_divination(shang, xia, dong);
+
+    Ok(MeihuaResponse {
+        hexagram: serde_json::json!({
+            "shang_gua_num": shang,
+            "xia_gua_num": xia,
+            "dong_yao": dong,
+            "ben_gua": [format!("{:?}", ben_shang.bagua), format!("{:?}", ben_xia.bagua)],
+            "bian_gua": [format!("{:?}", bian_shang.bagua), format!("{:?}", bian_xia.bagua)],
+            "hu_gua": [format!("{:?}", hu_shang.bagua), format!("{:?}", hu_xia.bagua)],
+            "ti_is_shang": ti_is_shang,
+            "ben_relation": format!("{:?}", ben_rel),
+            "bian_relation": format!("{:?}", bian_rel),
+        }),
+        fortune: format!("{:?}", fortune),
+    })
+}
+
+#[cfg(not(feature = "local-compute"))]
+pub fn bazi_chart(_req: &BaziRequest) -> Result<BaziResponse, ApiError> {
+    Err(ApiError::Internal("local-compute feature not enabled".to_string()))
+}
+
+#[cfg(not(feature = "local-compute"))]
+pub fn meihua_hexagram(_req: &MeihuaRequest) -> Result<MeihuaResponse, ApiError> {
+    Err(ApiError::Internal("local-compute feature not enabled".to_string()))
+}
+
+#[cfg(all(test, feature = "local-compute"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_meihua_manual() {
+        let req = MeihuaRequest {
+            method: "manual".to_string(),
+            year: None,
+            month: None,
+            day: None,
+            hour: None,
+            num1: None,
+            num2: None,
+            shang_gua: Some(1),
+            xia_gua: Some(8),
+            dong_yao: Some(3),
+            question: String::new(),
+        };
+
+        let resp = meihua_hexagram(&req).unwrap();
+        assert_eq!(resp.hexagram["dong_yao"], 3);
+    }
+
+    #[test]
+    fn test_local_bazi_rejects_invalid_gender() {
+        let req = BaziRequest {
+            calendar: "solar".to_string(),
+            year: 1990,
+            month: 5,
+            day: 15,
+            hour: 14,
+            minute: 30,
+            is_leap_month: false,
+            gender: "unknown".to_string(),
+            zishi_mode: "modern".to_string(),
+            longitude: None,
+        };
+
+        assert!(bazi_chart(&req).is_err());
+    }
+}
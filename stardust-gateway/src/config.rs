@@ -15,6 +15,9 @@ pub struct Config {
     pub divination: DivinationServiceConfig,
     /// 认证配置
     pub auth: AuthConfig,
+    /// 限流配置
+    #[serde(default)]
+    pub rate_limit: RateLimitConfig,
     /// 日志配置
     pub logging: LoggingConfig,
 }
@@ -69,6 +72,20 @@ pub struct DivinationServiceConfig {
     /// 重试次数
     #[serde(default = "default_divination_retries")]
     pub retries: u32,
+    /// 排盘模式（remote: 调用外部微服务；local: 网关内使用链上同款算法）
+    #[serde(default)]
+    pub mode: ComputeMode,
+}
+
+/// 排盘计算模式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ComputeMode {
+    /// 调用外部占卜微服务
+    #[default]
+    Remote,
+    /// 网关进程内计算（需启用 `local-compute` feature）
+    Local,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -78,6 +95,32 @@ pub struct AuthConfig {
     /// Token 过期时间（秒）
     #[serde(default = "default_token_expiry")]
     pub token_expiry: i64,
+    /// 登录挑战（nonce）有效期（秒）
+    #[serde(default = "default_challenge_ttl")]
+    pub challenge_ttl: usize,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RateLimitConfig {
+    /// 通用接口：每个账户/IP 每秒请求数
+    #[serde(default = "default_requests_per_second")]
+    pub requests_per_second: u32,
+    /// 占卜等计算密集型接口：每个账户/IP 每分钟请求数
+    #[serde(default = "default_divination_per_minute")]
+    pub divination_per_minute: u32,
+    /// 是否使用 Redis 计数（多实例部署时共享限流状态）
+    #[serde(default)]
+    pub use_redis: bool,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            requests_per_second: default_requests_per_second(),
+            divination_per_minute: default_divination_per_minute(),
+            use_redis: false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -143,6 +186,18 @@ fn default_token_expiry() -> i64 {
     86400 // 24小时
 }
 
+fn default_challenge_ttl() -> usize {
+    300 // 5分钟
+}
+
+fn default_requests_per_second() -> u32 {
+    100
+}
+
+fn default_divination_per_minute() -> u32 {
+    10
+}
+
 fn default_log_level() -> String {
     "info".to_string()
 }
@@ -178,6 +233,14 @@ impl Config {
             return Err("Server port must be greater than 0".to_string());
         }
 
+        if self.divination.mode == ComputeMode::Local && !cfg!(feature = "local-compute") {
+            return Err("Local compute mode requires the `local-compute` feature".to_string());
+        }
+
+        if self.rate_limit.requests_per_second == 0 || self.rate_limit.divination_per_minute == 0 {
+            return Err("Rate limit quotas must be greater than 0".to_string());
+        }
+
         Ok(())
     }
 }
@@ -1,5 +1,6 @@
 // 星尘链 API Gateway 主程序入口
 mod clients;
+mod compute;
 mod config;
 mod middleware;
 mod models;
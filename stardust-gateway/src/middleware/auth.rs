@@ -6,8 +6,12 @@ use axum::{
     middleware::Next,
     response::Response,
 };
-use jsonwebtoken::{decode, DecodingKey, Validation};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
+use sp_core::{
+    crypto::{AccountId32, Ss58Codec},
+    ed25519, sr25519, Pair as _,
+};
 
 use crate::{config::Config, models::ApiError};
 
@@ -52,7 +56,7 @@ pub async fn auth_middleware(
 }
 
 /// 验证 JWT Token
-fn verify_token(token: &str, secret: &str) -> Result<Claims, ApiError> {
+pub fn verify_token(token: &str, secret: &str) -> Result<Claims, ApiError> {
     let decoding_key = DecodingKey::from_secret(secret.as_bytes());
     let validation = Validation::default();
 
@@ -61,6 +65,90 @@ fn verify_token(token: &str, secret: &str) -> Result<Claims, ApiError> {
         .map_err(|e| ApiError::Unauthorized(format!("Invalid token: {}", e)))
 }
 
+/// 签发 JWT Token（钱包签名登录成功后调用）
+pub fn issue_token(address: &str, secret: &str, expiry_secs: i64) -> Result<(String, Claims), ApiError> {
+    let now = chrono::Utc::now().timestamp();
+    let claims = Claims {
+        sub: address.to_string(),
+        address: address.to_string(),
+        exp: now + expiry_secs,
+        iat: now,
+    };
+
+    let token = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(secret.as_bytes()),
+    )
+    .map_err(|e| ApiError::Internal(format!("Failed to issue token: {}", e)))?;
+
+    Ok((token, claims))
+}
+
+/// 钱包签名使用的密钥类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyType {
+    Sr25519,
+    Ed25519,
+}
+
+/// 构造登录挑战消息（用户钱包对该消息签名）
+pub fn build_challenge_message(address: &str, nonce: &str, issued_at: i64) -> String {
+    format!(
+        "Stardust Gateway Login\naddress: {}\nnonce: {}\nissued_at: {}",
+        address, nonce, issued_at
+    )
+}
+
+/// 验证钱包签名
+///
+/// 兼容 polkadot.js `signRaw`：扩展钱包会在原文外包裹 `<Bytes>...</Bytes>` 后再签名，
+/// 因此原文和包裹后的消息任一验证通过即视为有效。
+pub fn verify_wallet_signature(
+    address: &str,
+    message: &str,
+    signature_hex: &str,
+    key_type: KeyType,
+) -> Result<(), ApiError> {
+    let account = AccountId32::from_ss58check(address)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid address: {:?}", e)))?;
+    let public_bytes: [u8; 32] = account.into();
+
+    let signature_bytes = hex::decode(signature_hex.trim_start_matches("0x"))
+        .map_err(|e| ApiError::BadRequest(format!("Invalid signature hex: {}", e)))?;
+    let signature_raw: [u8; 64] = signature_bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| ApiError::BadRequest("Signature must be 64 bytes".to_string()))?;
+
+    let wrapped = format!("<Bytes>{}</Bytes>", message);
+    let candidates = [message.as_bytes(), wrapped.as_bytes()];
+
+    let valid = match key_type {
+        KeyType::Sr25519 => {
+            let public = sr25519::Public::from_raw(public_bytes);
+            let signature = sr25519::Signature::from_raw(signature_raw);
+            candidates
+                .iter()
+                .any(|msg| sr25519::Pair::verify(&signature, msg, &public))
+        }
+        KeyType::Ed25519 => {
+            let public = ed25519::Public::from_raw(public_bytes);
+            let signature = ed25519::Signature::from_raw(signature_raw);
+            candidates
+                .iter()
+                .any(|msg| ed25519::Pair::verify(&signature, msg, &public))
+        }
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(ApiError::Unauthorized("Invalid wallet signature".to_string()))
+    }
+}
+
 /// 可选认证中间件（Token 无效也允许通过，但不注入 Claims）
 pub async fn optional_auth_middleware(
     State(config): State<Config>,
@@ -106,4 +194,35 @@ mod tests {
         assert!(verified.is_ok());
         assert_eq!(verified.unwrap().sub, "user123");
     }
+
+    #[test]
+    fn test_issue_token_roundtrip() {
+        let address = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+        let (token, claims) = issue_token(address, "test_secret", 3600).unwrap();
+
+        let verified = verify_token(&token, "test_secret").unwrap();
+        assert_eq!(verified.address, address);
+        assert_eq!(verified.exp, claims.exp);
+        assert!(verify_token(&token, "other_secret").is_err());
+    }
+
+    #[test]
+    fn test_verify_wallet_signature() {
+        let pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+        let address = AccountId32::from(pair.public().0).to_ss58check();
+        let message = build_challenge_message(&address, "abc", 1_700_000_000);
+
+        // 原文签名
+        let signature = hex::encode(pair.sign(message.as_bytes()).0);
+        assert!(verify_wallet_signature(&address, &message, &signature, KeyType::Sr25519).is_ok());
+
+        // polkadot.js signRaw 包裹格式
+        let wrapped = format!("<Bytes>{}</Bytes>", message);
+        let signature = format!("0x{}", hex::encode(pair.sign(wrapped.as_bytes()).0));
+        assert!(verify_wallet_signature(&address, &message, &signature, KeyType::Sr25519).is_ok());
+
+        // 签名内容被篡改
+        let tampered = build_challenge_message(&address, "xyz", 1_700_000_000);
+        assert!(verify_wallet_signature(&address, &tampered, &signature, KeyType::Sr25519).is_err());
+    }
 }
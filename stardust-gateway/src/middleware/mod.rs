@@ -2,5 +2,8 @@
 pub mod auth;
 pub mod rate_limit;
 
-pub use auth::{auth_middleware, optional_auth_middleware, Claims};
+pub use auth::{
+    auth_middleware, build_challenge_message, issue_token, optional_auth_middleware,
+    verify_token, verify_wallet_signature, Claims, KeyType,
+};
 pub use rate_limit::{rate_limit_middleware, strict_rate_limit_middleware, RateLimiterState};
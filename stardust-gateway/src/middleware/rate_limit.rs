@@ -1,45 +1,136 @@
-// 限流中间件 - 基于 IP 和用户的请求限流
+// 限流中间件 - 基于账户和 IP 的请求限流
 use axum::{
     body::Body,
     extract::{ConnectInfo, Request, State},
-    http::StatusCode,
     middleware::Next,
-    response::{IntoResponse, Response},
+    response::Response,
 };
-use governor::{
-    clock::DefaultClock,
-    state::{InMemoryState, NotKeyed},
-    Quota, RateLimiter,
-};
-use std::{net::SocketAddr, num::NonZeroU32, sync::Arc};
+use governor::{clock::DefaultClock, state::keyed::DefaultKeyedStateStore, Quota, RateLimiter};
+use std::{net::SocketAddr, num::NonZeroU32, sync::Arc, time::Duration};
+
+use crate::{clients::CacheClient, middleware::Claims, models::ApiError};
+
+/// 内存限流器清理过期 key 的间隔
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60);
 
-use crate::models::ApiError;
+/// 按 key（账户或 IP）隔离的限流器
+type KeyedLimiter = RateLimiter<String, DefaultKeyedStateStore<String>, DefaultClock>;
 
 /// 限流器状态
 #[derive(Clone)]
 pub struct RateLimiterState {
-    /// 全局限流器（基于 IP）
-    pub global: Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock>>,
+    /// 限流桶名称（区分通用接口和占卜接口，Redis key 前缀）
+    pub bucket: &'static str,
+    /// 按账户/IP 隔离的内存限流器
+    pub limiter: Arc<KeyedLimiter>,
+    /// 窗口内允许的请求数（Redis 计数使用）
+    pub limit: u32,
+    /// 窗口长度（秒，Redis 计数使用）
+    pub window_secs: usize,
+    /// Redis 缓存（可选，多实例部署时共享计数）
+    pub cache: Option<CacheClient>,
 }
 
 impl RateLimiterState {
-    /// 创建新的限流器（每秒最多 100 个请求）
+    /// 创建通用限流器（每个账户/IP 每秒 N 个请求）
     pub fn new(requests_per_second: u32) -> Self {
-        let quota = Quota::per_second(NonZeroU32::new(requests_per_second).unwrap());
+        let limit = NonZeroU32::new(requests_per_second).unwrap();
         Self {
-            global: Arc::new(RateLimiter::direct(quota)),
+            bucket: "global",
+            limiter: Arc::new(RateLimiter::keyed(Quota::per_second(limit))),
+            limit: limit.get(),
+            window_secs: 1,
+            cache: None,
         }
     }
 
-    /// 创建更严格的限流器（用于占卜等计算密集型接口）
+    /// 创建更严格的限流器（用于占卜等计算密集型接口，每个账户/IP 每分钟 N 个请求）
     pub fn new_strict(requests_per_minute: u32) -> Self {
-        let quota = Quota::per_minute(NonZeroU32::new(requests_per_minute).unwrap());
+        let limit = NonZeroU32::new(requests_per_minute).unwrap();
         Self {
-            global: Arc::new(RateLimiter::direct(quota)),
+            bucket: "divination",
+            limiter: Arc::new(RateLimiter::keyed(Quota::per_minute(limit))),
+            limit: limit.get(),
+            window_secs: 60,
+            cache: None,
+        }
+    }
+
+    /// 使用 Redis 计数（固定窗口），Redis 不可用时回退到内存限流器
+    pub fn with_redis(mut self, cache: CacheClient) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// 检查指定 key 是否超过限流
+    pub async fn check(&self, key: &str) -> Result<(), ApiError> {
+        if let Some(cache) = &self.cache {
+            let redis_key = format!("ratelimit:{}:{}", self.bucket, key);
+            match cache.incr_window(&redis_key, self.window_secs).await {
+                Ok(count) if count > self.limit as u64 => return Err(ApiError::RateLimitExceeded),
+                Ok(_) => return Ok(()),
+                Err(e) => {
+                    tracing::warn!("Redis rate limit unavailable, fallback to memory: {}", e);
+                }
+            }
+        }
+
+        self.limiter
+            .check_key(&key.to_string())
+            .map_err(|_| ApiError::RateLimitExceeded)
+    }
+
+    /// 检查请求的 IP 桶，已认证请求再检查账户桶（任一超限即拒绝）
+    ///
+    /// 账户和 IP 各占一个桶：同一 IP 下切换账户、同一账户换 IP 都无法绕过限流。
+    pub async fn check_request(&self, keys: &LimitKeys) -> Result<(), ApiError> {
+        self.check(&keys.ip).await?;
+        if let Some(account) = &keys.account {
+            self.check(account).await?;
+        }
+        Ok(())
+    }
+
+    /// 启动后台任务，定期清理内存限流器中已恢复满额的 key，避免 key 数量无限增长
+    pub fn spawn_cleanup(&self) {
+        let limiter = self.limiter.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(CLEANUP_INTERVAL);
+            loop {
+                interval.tick().await;
+                limiter.retain_recent();
+                limiter.shrink_to_fit();
+            }
+        });
+    }
+}
+
+/// 请求的限流 key：IP 桶总是存在，已认证请求另有账户桶
+pub struct LimitKeys {
+    pub ip: String,
+    pub account: Option<String>,
+}
+
+impl std::fmt::Display for LimitKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.account {
+            Some(account) => write!(f, "{} ({})", account, self.ip),
+            None => write!(f, "{}", self.ip),
         }
     }
 }
 
+/// 计算限流 key：所有请求按 IP 计数，已认证请求同时按账户计数
+fn limit_keys(request: &Request<Body>, addr: &SocketAddr) -> LimitKeys {
+    LimitKeys {
+        ip: format!("ip:{}", addr.ip()),
+        account: request
+            .extensions()
+            .get::<Claims>()
+            .map(|claims| format!("acct:{}", claims.address)),
+    }
+}
+
 /// 全局限流中间件
 pub async fn rate_limit_middleware(
     State(limiter): State<RateLimiterState>,
@@ -47,10 +138,12 @@ pub async fn rate_limit_middleware(
     request: Request<Body>,
     next: Next,
 ) -> Result<Response, ApiError> {
+    let keys = limit_keys(&request, &addr);
+
     // 检查是否超过限流
-    if limiter.global.check().is_err() {
-        tracing::warn!("Rate limit exceeded for IP: {}", addr.ip());
-        return Err(ApiError::RateLimitExceeded);
+    if let Err(e) = limiter.check_request(&keys).await {
+        tracing::warn!("Rate limit exceeded for {}", keys);
+        return Err(e);
     }
 
     Ok(next.run(request).await)
@@ -63,11 +156,13 @@ pub async fn strict_rate_limit_middleware(
     request: Request<Body>,
     next: Next,
 ) -> Result<Response, ApiError> {
-    match limiter.global.check() {
+    let keys = limit_keys(&request, &addr);
+
+    match limiter.check_request(&keys).await {
         Ok(_) => Ok(next.run(request).await),
-        Err(_) => {
-            tracing::warn!("Strict rate limit exceeded for IP: {}", addr.ip());
-            Err(ApiError::RateLimitExceeded)
+        Err(e) => {
+            tracing::warn!("Strict rate limit exceeded for {}", keys);
+            Err(e)
         }
     }
 }
@@ -81,10 +176,46 @@ mod tests {
         let limiter = RateLimiterState::new(2); // 每秒 2 个请求
 
         // 前两个请求应该通过
-        assert!(limiter.global.check().is_ok());
-        assert!(limiter.global.check().is_ok());
+        assert!(limiter.check("ip:127.0.0.1").await.is_ok());
+        assert!(limiter.check("ip:127.0.0.1").await.is_ok());
 
         // 第三个请求应该被限流
-        assert!(limiter.global.check().is_err());
+        assert!(limiter.check("ip:127.0.0.1").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_is_keyed() {
+        let limiter = RateLimiterState::new_strict(1); // 每分钟 1 个请求
+
+        assert!(limiter.check("acct:alice").await.is_ok());
+        assert!(limiter.check("acct:alice").await.is_err());
+
+        // 其他账户不受影响
+        assert!(limiter.check("acct:bob").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_authenticated_request_checks_ip_and_account() {
+        let limiter = RateLimiterState::new_strict(1); // 每分钟 1 个请求
+
+        let alice = LimitKeys {
+            ip: "ip:10.0.0.1".into(),
+            account: Some("acct:alice".into()),
+        };
+        assert!(limiter.check_request(&alice).await.is_ok());
+
+        // 同一 IP 换账户：IP 桶已用尽
+        let bob = LimitKeys {
+            ip: "ip:10.0.0.1".into(),
+            account: Some("acct:bob".into()),
+        };
+        assert!(limiter.check_request(&bob).await.is_err());
+
+        // 同一账户换 IP：账户桶已用尽
+        let alice_elsewhere = LimitKeys {
+            ip: "ip:10.0.0.2".into(),
+            account: Some("acct:alice".into()),
+        };
+        assert!(limiter.check_request(&alice_elsewhere).await.is_err());
     }
 }
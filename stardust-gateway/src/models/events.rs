@@ -0,0 +1,290 @@
+// 账户事件模型 - WebSocket 推送的消息类型与账户事件过滤
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+
+use crate::clients::substrate::RawEvent;
+
+/// 服务端推送消息
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WsMessage {
+    /// 订阅成功
+    Subscribed {
+        account: String,
+        from_block: Option<u64>,
+    },
+    /// 已处理的最终确认区块（客户端记录后用于断线重连的 from_block）
+    Block { number: u64, hash: String },
+    /// 聊天消息（pallet-chat）
+    ChatMessage {
+        block_number: u64,
+        event: String,
+        msg_id: Option<u64>,
+        data: Value,
+    },
+    /// 占卜服务订单状态变更（pallet-divination-market）
+    MarketOrder {
+        block_number: u64,
+        event: String,
+        order_id: u64,
+        data: Value,
+    },
+    /// IPFS 固定状态变更（pallet-stardust-ipfs）
+    PinStatus {
+        block_number: u64,
+        event: String,
+        cid_hash: String,
+        data: Value,
+    },
+    /// 托管资金变动（pallet-escrow）
+    Escrow {
+        block_number: u64,
+        event: String,
+        escrow_id: u64,
+        data: Value,
+    },
+    /// 仲裁进展（pallet-arbitration）
+    Arbitration {
+        block_number: u64,
+        event: String,
+        dispute_id: u64,
+        data: Value,
+    },
+    /// 心跳响应
+    Pong,
+    /// 错误
+    Error { message: String },
+}
+
+/// 客户端指令
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum WsCommand {
+    /// 追加关注的订单/托管/仲裁 ID（订阅前已存在、事件中不含账户的对象）
+    Watch {
+        #[serde(default)]
+        order_ids: Vec<u64>,
+        #[serde(default)]
+        escrow_ids: Vec<u64>,
+        #[serde(default)]
+        dispute_ids: Vec<u64>,
+    },
+    /// 心跳
+    Ping,
+}
+
+/// 账户事件过滤器
+///
+/// 事件字段中出现该账户即视为相关；订单、托管、仲裁和 IPFS 固定的后续事件
+/// 只携带 ID/哈希，因此首次命中时记录 ID，之后按 ID 继续推送。
+#[derive(Debug, Clone)]
+pub struct AccountFilter {
+    account: [u8; 32],
+    order_ids: HashSet<u64>,
+    escrow_ids: HashSet<u64>,
+    dispute_ids: HashSet<u64>,
+    pin_hashes: HashSet<String>,
+}
+
+impl AccountFilter {
+    /// 创建过滤器
+    pub fn new(account: [u8; 32]) -> Self {
+        Self {
+            account,
+            order_ids: HashSet::new(),
+            escrow_ids: HashSet::new(),
+            dispute_ids: HashSet::new(),
+            pin_hashes: HashSet::new(),
+        }
+    }
+
+    /// 处理客户端 watch 指令
+    pub fn watch(&mut self, order_ids: Vec<u64>, escrow_ids: Vec<u64>, dispute_ids: Vec<u64>) {
+        self.order_ids.extend(order_ids);
+        self.escrow_ids.extend(escrow_ids);
+        self.dispute_ids.extend(dispute_ids);
+    }
+
+    /// 判断事件是否与账户相关，相关则转换为推送消息
+    pub fn classify(&mut self, ev: &RawEvent) -> Option<WsMessage> {
+        let mine = contains_account(&ev.fields, &self.account);
+
+        match ev.pallet.as_str() {
+            "Chat" if mine => Some(WsMessage::ChatMessage {
+                block_number: ev.block_number,
+                event: ev.variant.clone(),
+                msg_id: field(&ev.fields, "msg_id", 0).and_then(Value::as_u64),
+                data: ev.fields.clone(),
+            }),
+            "DivinationMarket" if is_order_event(&ev.variant) => {
+                let order_id = field(&ev.fields, "order_id", 0).and_then(Value::as_u64)?;
+                if mine {
+                    self.order_ids.insert(order_id);
+                }
+                self.order_ids.contains(&order_id).then(|| WsMessage::MarketOrder {
+                    block_number: ev.block_number,
+                    event: ev.variant.clone(),
+                    order_id,
+                    data: ev.fields.clone(),
+                })
+            }
+            "StardustIpfs" if ev.variant.starts_with("Pin") => {
+                let cid_hash = field(&ev.fields, "cid_hash", 0).and_then(as_bytes32)?;
+                let cid_hash = format!("0x{}", hex::encode(cid_hash));
+                if mine {
+                    self.pin_hashes.insert(cid_hash.clone());
+                }
+                self.pin_hashes.contains(&cid_hash).then(|| WsMessage::PinStatus {
+                    block_number: ev.block_number,
+                    event: ev.variant.clone(),
+                    cid_hash,
+                    data: ev.fields.clone(),
+                })
+            }
+            "Escrow" => {
+                let escrow_id = field(&ev.fields, "id", 0).and_then(Value::as_u64)?;
+                if mine {
+                    self.escrow_ids.insert(escrow_id);
+                }
+                self.escrow_ids.contains(&escrow_id).then(|| WsMessage::Escrow {
+                    block_number: ev.block_number,
+                    event: ev.variant.clone(),
+                    escrow_id,
+                    data: ev.fields.clone(),
+                })
+            }
+            "Arbitration" => {
+                let dispute_id = field(&ev.fields, "id", 1).and_then(Value::as_u64)?;
+                if mine {
+                    self.dispute_ids.insert(dispute_id);
+                }
+                self.dispute_ids.contains(&dispute_id).then(|| WsMessage::Arbitration {
+                    block_number: ev.block_number,
+                    event: ev.variant.clone(),
+                    dispute_id,
+                    data: ev.fields.clone(),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// 订单生命周期相关事件
+fn is_order_event(variant: &str) -> bool {
+    variant.starts_with("Order")
+        || variant.starts_with("FollowUp")
+        || variant.starts_with("Review")
+        || variant == "InterpretationSubmitted"
+}
+
+/// 按字段名（具名字段）或位置（元组字段）取值
+fn field<'a>(fields: &'a Value, name: &str, position: usize) -> Option<&'a Value> {
+    match fields {
+        Value::Object(map) => map.get(name),
+        Value::Array(items) => items.get(position),
+        _ => None,
+    }
+}
+
+/// 解析 32 字节值（AccountId32 / H256 在 JSON 中为字节数组，可能被单元素数组包裹）
+fn as_bytes32(value: &Value) -> Option<[u8; 32]> {
+    let items = value.as_array()?;
+    if items.len() == 1 {
+        return as_bytes32(&items[0]);
+    }
+    if items.len() != 32 {
+        return None;
+    }
+
+    let mut out = [0u8; 32];
+    for (i, item) in items.iter().enumerate() {
+        out[i] = u8::try_from(item.as_u64()?).ok()?;
+    }
+    Some(out)
+}
+
+/// 递归检查字段中是否包含指定账户
+fn contains_account(value: &Value, account: &[u8; 32]) -> bool {
+    if as_bytes32(value).as_ref() == Some(account) {
+        return true;
+    }
+
+    match value {
+        Value::Array(items) => items.iter().any(|v| contains_account(v, account)),
+        Value::Object(map) => map.values().any(|v| contains_account(v, account)),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(pallet: &str, variant: &str, fields: Value) -> RawEvent {
+        RawEvent {
+            block_number: 10,
+            block_hash: "0x00".to_string(),
+            event_index: 0,
+            pallet: pallet.to_string(),
+            variant: variant.to_string(),
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_chat_message_filtered_by_account() {
+        let alice = [1u8; 32];
+        let bob = [2u8; 32];
+        let mut filter = AccountFilter::new(alice);
+
+        let ev = raw(
+            "Chat",
+            "MessageSent",
+            serde_json::json!({ "msg_id": 7, "sender": [bob], "receiver": [alice] }),
+        );
+        assert!(matches!(
+            filter.classify(&ev),
+            Some(WsMessage::ChatMessage { msg_id: Some(7), .. })
+        ));
+
+        let mut other = AccountFilter::new([3u8; 32]);
+        assert!(other.classify(&ev).is_none());
+    }
+
+    #[test]
+    fn test_order_tracked_after_creation() {
+        let alice = [1u8; 32];
+        let mut filter = AccountFilter::new(alice);
+
+        // 订单已支付事件只有 order_id，创建前不推送
+        let paid = raw("DivinationMarket", "OrderPaid", serde_json::json!({ "order_id": 5 }));
+        assert!(filter.classify(&paid).is_none());
+
+        let created = raw(
+            "DivinationMarket",
+            "OrderCreated",
+            serde_json::json!({ "order_id": 5, "customer": [alice], "provider": [[9u8; 32]] }),
+        );
+        assert!(filter.classify(&created).is_some());
+        assert!(matches!(
+            filter.classify(&paid),
+            Some(WsMessage::MarketOrder { order_id: 5, .. })
+        ));
+    }
+
+    #[test]
+    fn test_watch_dispute_ids() {
+        let mut filter = AccountFilter::new([1u8; 32]);
+        let ev = raw(
+            "Arbitration",
+            "Arbitrated",
+            serde_json::json!({ "domain": [1, 2, 3, 4, 5, 6, 7, 8], "id": 42, "decision": 0 }),
+        );
+        assert!(filter.classify(&ev).is_none());
+
+        filter.watch(vec![], vec![], vec![42]);
+        assert!(filter.classify(&ev).is_some());
+    }
+}
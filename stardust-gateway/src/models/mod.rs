@@ -1,6 +1,8 @@
 // 模型模块入口
 pub mod error;
+pub mod events;
 pub mod response;
 
 pub use error::{ApiError, ApiResult, ErrorResponse};
+pub use events::{AccountFilter, WsCommand, WsMessage};
 pub use response::{ApiResponse, HealthResponse, PageResponse, ServiceStatus};
@@ -0,0 +1,113 @@
+// 钱包签名登录路由 - challenge/response 获取 JWT
+use axum::{extract::State, Json};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    middleware::{build_challenge_message, issue_token, verify_wallet_signature, KeyType},
+    models::{ApiError, ApiResponse},
+    AppState,
+};
+
+/// 获取登录挑战请求
+#[derive(Debug, Deserialize)]
+pub struct ChallengeRequest {
+    /// SS58 地址
+    pub address: String,
+}
+
+/// 登录挑战响应
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChallengeResponse {
+    /// 随机 nonce
+    pub nonce: String,
+    /// 需要钱包签名的完整消息
+    pub message: String,
+    /// 挑战过期时间戳
+    pub expires_at: i64,
+}
+
+/// 签名登录请求
+#[derive(Debug, Deserialize)]
+pub struct LoginRequest {
+    /// SS58 地址
+    pub address: String,
+    /// 挑战 nonce
+    pub nonce: String,
+    /// 对挑战消息的签名（hex，可带 0x 前缀）
+    pub signature: String,
+    /// 密钥类型，默认 sr25519
+    #[serde(default = "default_key_type")]
+    pub key_type: KeyType,
+}
+
+fn default_key_type() -> KeyType {
+    KeyType::Sr25519
+}
+
+/// 登录响应
+#[derive(Debug, Serialize)]
+pub struct LoginResponse {
+    pub token: String,
+    pub address: String,
+    pub expires_at: i64,
+}
+
+fn challenge_key(address: &str, nonce: &str) -> String {
+    format!("auth:challenge:{}:{}", address, nonce)
+}
+
+/// 获取登录挑战
+pub async fn challenge_handler(
+    State(state): State<AppState>,
+    Json(req): Json<ChallengeRequest>,
+) -> Result<Json<ApiResponse<ChallengeResponse>>, ApiError> {
+    use sp_core::crypto::{AccountId32, Ss58Codec};
+
+    AccountId32::from_ss58check(&req.address)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid address: {:?}", e)))?;
+
+    let ttl = state.config.auth.challenge_ttl;
+    let issued_at = chrono::Utc::now().timestamp();
+    let nonce = uuid::Uuid::new_v4().simple().to_string();
+    let message = build_challenge_message(&req.address, &nonce, issued_at);
+
+    // 挑战消息存入 Redis，登录时一次性取出，防止重放
+    state
+        .cache
+        .set_ex(&challenge_key(&req.address, &nonce), &message, ttl)
+        .await?;
+
+    Ok(Json(ApiResponse::success(ChallengeResponse {
+        nonce,
+        message,
+        expires_at: issued_at + ttl as i64,
+    })))
+}
+
+/// 提交签名登录，验证通过后签发 JWT
+pub async fn login_handler(
+    State(state): State<AppState>,
+    Json(req): Json<LoginRequest>,
+) -> Result<Json<ApiResponse<LoginResponse>>, ApiError> {
+    let message: String = state
+        .cache
+        .take(&challenge_key(&req.address, &req.nonce))
+        .await?
+        .ok_or_else(|| ApiError::Unauthorized("Challenge expired or not found".to_string()))?;
+
+    verify_wallet_signature(&req.address, &message, &req.signature, req.key_type)?;
+
+    let (token, claims) = issue_token(
+        &req.address,
+        &state.config.auth.jwt_secret,
+        state.config.auth.token_expiry,
+    )?;
+
+    tracing::info!("钱包签名登录成功 - 用户: {}", req.address);
+
+    Ok(Json(ApiResponse::success(LoginResponse {
+        token,
+        address: claims.address,
+        expires_at: claims.exp,
+    })))
+}
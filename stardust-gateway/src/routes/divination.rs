@@ -2,11 +2,15 @@
 use axum::{extract::State, Extension, Json};
 use serde::{Deserialize, Serialize};
 
+use subxt::dynamic::Value as DynValue;
+
 use crate::{
     clients::{
-        DaliurenRequest, LiuyaoRequest, QimenRequest, TarotRequest, XiaoliurenRequest,
-        ZiweiRequest,
+        BaziRequest, DaliurenRequest, LiuyaoRequest, MeihuaRequest, QimenRequest, TarotRequest,
+        UnsignedPayload, XiaoliurenRequest, ZiweiRequest,
     },
+    compute,
+    config::ComputeMode,
     middleware::Claims,
     models::{ApiError, ApiResponse},
     AppState,
@@ -162,6 +166,249 @@ pub async fn tarot_handler(
     }))))
 }
 
+/// 八字排盘
+pub async fn bazi_handler(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<BaziRequest>,
+) -> Result<Json<ApiResponse<serde_json::Value>>, ApiError> {
+    tracing::info!("八字排盘请求 - 用户: {}", claims.address);
+
+    let cache_key = format!(
+        "div:bazi:{}:{}:{}:{}:{}:{}:{}:{}:{}:{:?}",
+        req.calendar,
+        req.year,
+        req.month,
+        req.day,
+        req.is_leap_month,
+        req.hour,
+        req.minute,
+        req.gender,
+        req.zishi_mode,
+        req.longitude
+    );
+
+    let result = state
+        .cache
+        .get_or_set(&cache_key, 7200, || async {
+            let response = match state.config.divination.mode {
+                ComputeMode::Local => compute::bazi_chart(&req)?,
+                ComputeMode::Remote => state.divination.bazi_calculate(req.clone()).await?,
+            };
+            Ok::<_, ApiError>(serde_json::json!({
+                "chart": response.chart,
+            }))
+        })
+        .await?;
+
+    Ok(Json(ApiResponse::success(result)))
+}
+
+/// 梅花易数起卦
+pub async fn meihua_handler(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<MeihuaRequest>,
+) -> Result<Json<ApiResponse<serde_json::Value>>, ApiError> {
+    tracing::info!("梅花易数请求 - 用户: {}", claims.address);
+
+    ensure_meihua_previewable(&req)?;
+
+    let response = match state.config.divination.mode {
+        ComputeMode::Local => compute::meihua_hexagram(&req)?,
+        ComputeMode::Remote => state.divination.meihua_calculate(req).await?,
+    };
+
+    Ok(Json(ApiResponse::success(serde_json::json!({
+        "hexagram": response.hexagram,
+        "fortune": response.fortune,
+    }))))
+}
+
+/// 时间/双数起卦在链上按出块时间取时辰，链下无法预知，预览结果会与存证卦象不一致，
+/// 因此只允许预览手动起卦；时间/双数起卦请直接上链存证
+fn ensure_meihua_previewable(req: &MeihuaRequest) -> Result<(), ApiError> {
+    match req.method.as_str() {
+        "manual" => Ok(()),
+        "time" | "numbers" => Err(ApiError::Validation(format!(
+            "Method {} uses block time on chain and cannot be previewed; submit it on chain or use manual",
+            req.method
+        ))),
+        other => Err(ApiError::Validation(format!("Invalid method: {}", other))),
+    }
+}
+
+/// 链上存证请求（构造待签名交易）
+#[derive(Debug, Deserialize)]
+#[serde(tag = "system", rename_all = "lowercase")]
+pub enum ChainSubmitRequest {
+    /// 八字命盘 → BaziChart::create_bazi_chart
+    Bazi {
+        /// 命盘名称（最多 32 字节）
+        name: Option<String>,
+        #[serde(flatten)]
+        chart: BaziRequest,
+    },
+    /// 梅花卦象 → Meihua::divine_*
+    Meihua {
+        #[serde(flatten)]
+        hexagram: MeihuaRequest,
+        #[serde(default)]
+        is_public: bool,
+        /// 性别（0: 未指定, 1: 男, 2: 女）
+        #[serde(default)]
+        gender: u8,
+        /// 占卜类别（0-6）
+        #[serde(default)]
+        category: u8,
+    },
+}
+
+/// 构造链上存证交易，返回待签名载荷
+pub async fn prepare_submit_handler(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<ChainSubmitRequest>,
+) -> Result<Json<ApiResponse<UnsignedPayload>>, ApiError> {
+    let (pallet, call, fields) = build_chain_call(req)?;
+
+    tracing::info!("构造链上存证交易 - 用户: {}, 调用: {}::{}", claims.address, pallet, call);
+
+    let payload = state
+        .substrate
+        .prepare_extrinsic(pallet, call, fields, &claims.address)
+        .await?;
+
+    Ok(Json(ApiResponse::success(payload)))
+}
+
+/// 提交已签名交易
+#[derive(Debug, Deserialize)]
+pub struct SignedExtrinsicRequest {
+    /// 签名后的完整 extrinsic（hex）
+    pub extrinsic: String,
+}
+
+pub async fn submit_handler(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<SignedExtrinsicRequest>,
+) -> Result<Json<ApiResponse<serde_json::Value>>, ApiError> {
+    let bytes = hex::decode(req.extrinsic.trim_start_matches("0x"))
+        .map_err(|e| ApiError::BadRequest(format!("Invalid extrinsic hex: {}", e)))?;
+
+    let tx_hash = state.substrate.submit_extrinsic(bytes).await?;
+
+    tracing::info!("提交链上存证交易 - 用户: {}, 交易: {}", claims.address, tx_hash);
+
+    Ok(Json(ApiResponse::success(serde_json::json!({
+        "tx_hash": tx_hash,
+    }))))
+}
+
+/// 将排盘请求映射为 pallet 调用参数
+fn build_chain_call(
+    req: ChainSubmitRequest,
+) -> Result<(&'static str, &'static str, Vec<DynValue>), ApiError> {
+    let missing = |field: &str| ApiError::Validation(format!("Missing field: {}", field));
+    let u = |v: u64| DynValue::u128(v as u128);
+
+    match req {
+        ChainSubmitRequest::Bazi { name, chart } => {
+            let name = match name {
+                Some(n) if n.len() > 32 => {
+                    return Err(ApiError::Validation("Name too long (max 32 bytes)".to_string()))
+                }
+                Some(n) => DynValue::unnamed_variant("Some", [DynValue::from_bytes(n.as_bytes())]),
+                None => DynValue::unnamed_variant("None", []),
+            };
+            let input = match chart.calendar.as_str() {
+                "solar" => DynValue::named_variant(
+                    "Solar",
+                    [
+                        ("year", u(chart.year as u64)),
+                        ("month", u(chart.month as u64)),
+                        ("day", u(chart.day as u64)),
+                        ("hour", u(chart.hour as u64)),
+                        ("minute", u(chart.minute as u64)),
+                    ],
+                ),
+                "lunar" => DynValue::named_variant(
+                    "Lunar",
+                    [
+                        ("year", u(chart.year as u64)),
+                        ("month", u(chart.month as u64)),
+                        ("day", u(chart.day as u64)),
+                        ("is_leap_month", DynValue::bool(chart.is_leap_month)),
+                        ("hour", u(chart.hour as u64)),
+                        ("minute", u(chart.minute as u64)),
+                    ],
+                ),
+                other => return Err(ApiError::Validation(format!("Invalid calendar: {}", other))),
+            };
+            let gender = match chart.gender.as_str() {
+                "male" => DynValue::unnamed_variant("Male", []),
+                "female" => DynValue::unnamed_variant("Female", []),
+                other => return Err(ApiError::Validation(format!("Invalid gender: {}", other))),
+            };
+            let zishi_mode = match chart.zishi_mode.as_str() {
+                "traditional" => DynValue::unnamed_variant("Traditional", []),
+                "modern" => DynValue::unnamed_variant("Modern", []),
+                other => return Err(ApiError::Validation(format!("Invalid zishi_mode: {}", other))),
+            };
+            let longitude = match chart.longitude {
+                Some(lng) => DynValue::unnamed_variant("Some", [DynValue::i128(lng as i128)]),
+                None => DynValue::unnamed_variant("None", []),
+            };
+
+            Ok((
+                "BaziChart",
+                "create_bazi_chart",
+                vec![name, input, gender, zishi_mode, longitude],
+            ))
+        }
+        ChainSubmitRequest::Meihua {
+            hexagram,
+            is_public,
+            gender,
+            category,
+        } => {
+            let question_hash = DynValue::from_bytes(sp_core::hashing::blake2_256(
+                hexagram.question.as_bytes(),
+            ));
+            let tail = [
+                question_hash,
+                DynValue::bool(is_public),
+                u(gender as u64),
+                u(category as u64),
+            ];
+
+            // 时间/双数起卦在链上按出块时间取时辰（不预览），手动起卦与链下预览严格一致
+            match hexagram.method.as_str() {
+                "time" => Ok(("Meihua", "divine_by_gregorian_time", tail.to_vec())),
+                "numbers" => {
+                    let mut fields = vec![
+                        u(hexagram.num1.ok_or_else(|| missing("num1"))? as u64),
+                        u(hexagram.num2.ok_or_else(|| missing("num2"))? as u64),
+                    ];
+                    fields.extend(tail);
+                    Ok(("Meihua", "divine_by_numbers", fields))
+                }
+                "manual" => {
+                    let mut fields = vec![
+                        u(hexagram.shang_gua.ok_or_else(|| missing("shang_gua"))? as u64),
+                        u(hexagram.xia_gua.ok_or_else(|| missing("xia_gua"))? as u64),
+                        u(hexagram.dong_yao.ok_or_else(|| missing("dong_yao"))? as u64),
+                    ];
+                    fields.extend(tail);
+                    Ok(("Meihua", "divine_manual", fields))
+                }
+                other => Err(ApiError::Validation(format!("Invalid method: {}", other))),
+            }
+        }
+    }
+}
+
 /// 查询用户占卜历史
 #[derive(Debug, Deserialize)]
 pub struct DivinationHistoryQuery {
@@ -205,3 +452,80 @@ pub async fn divination_history_handler(
 
     Ok(Json(ApiResponse::success(json_records)))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meihua(method: &str) -> MeihuaRequest {
+        MeihuaRequest {
+            method: method.to_string(),
+            year: Some(2024),
+            month: Some(3),
+            day: Some(15),
+            hour: Some(14),
+            num1: Some(3),
+            num2: Some(5),
+            shang_gua: Some(1),
+            xia_gua: Some(8),
+            dong_yao: Some(3),
+            question: "?".to_string(),
+        }
+    }
+
+    fn submit(method: &str) -> ChainSubmitRequest {
+        ChainSubmitRequest::Meihua {
+            hexagram: meihua(method),
+            is_public: false,
+            gender: 0,
+            category: 0,
+        }
+    }
+
+    #[test]
+    fn test_meihua_preview_only_for_manual() {
+        assert!(ensure_meihua_previewable(&meihua("manual")).is_ok());
+
+        // 链上按出块时间起卦的方式不可预览
+        assert!(matches!(
+            ensure_meihua_previewable(&meihua("time")),
+            Err(ApiError::Validation(_))
+        ));
+        assert!(matches!(
+            ensure_meihua_previewable(&meihua("numbers")),
+            Err(ApiError::Validation(_))
+        ));
+        assert!(ensure_meihua_previewable(&meihua("dice")).is_err());
+    }
+
+    #[test]
+    fn test_meihua_chain_call_mapping() {
+        let (pallet, call, fields) = build_chain_call(submit("manual")).unwrap();
+        assert_eq!((pallet, call), ("Meihua", "divine_manual"));
+        assert_eq!(fields.len(), 7);
+
+        let (_, call, fields) = build_chain_call(submit("numbers")).unwrap();
+        assert_eq!(call, "divine_by_numbers");
+        assert_eq!(fields.len(), 6);
+
+        // 时间起卦不上传客户端时间
+        let (_, call, fields) = build_chain_call(submit("time")).unwrap();
+        assert_eq!(call, "divine_by_gregorian_time");
+        assert_eq!(fields.len(), 4);
+
+        assert!(build_chain_call(submit("dice")).is_err());
+    }
+
+    #[test]
+    fn test_meihua_chain_call_requires_fields() {
+        let mut req = meihua("manual");
+        req.dong_yao = None;
+        let result = build_chain_call(ChainSubmitRequest::Meihua {
+            hexagram: req,
+            is_public: true,
+            gender: 1,
+            category: 1,
+        });
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+}
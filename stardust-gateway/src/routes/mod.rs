@@ -1,7 +1,9 @@
 // 路由模块入口
+pub mod auth;
 pub mod blockchain;
 pub mod divination;
 pub mod health;
+pub mod ws;
 
 use axum::{
     middleware,
@@ -10,20 +12,39 @@ use axum::{
 };
 
 use crate::{
-    middleware::{auth_middleware, strict_rate_limit_middleware, RateLimiterState},
+    middleware::{
+        auth_middleware, optional_auth_middleware, rate_limit_middleware,
+        strict_rate_limit_middleware, RateLimiterState,
+    },
     AppState,
 };
 
 /// 构建所有路由
 pub fn build_routes(state: AppState) -> Router {
-    // 创建限流器
-    let global_limiter = RateLimiterState::new(100); // 每秒 100 个请求
-    let divination_limiter = RateLimiterState::new_strict(10); // 每分钟 10 个占卜请求
+    // 创建限流器（按账户/IP 分桶）
+    let rate_config = &state.config.rate_limit;
+    let mut global_limiter = RateLimiterState::new(rate_config.requests_per_second);
+    let mut divination_limiter = RateLimiterState::new_strict(rate_config.divination_per_minute);
+    if rate_config.use_redis {
+        global_limiter = global_limiter.with_redis(state.cache.clone());
+        divination_limiter = divination_limiter.with_redis(state.cache.clone());
+    }
+    global_limiter.spawn_cleanup();
+    divination_limiter.spawn_cleanup();
 
     Router::new()
         // 健康检查（无需认证）
         .route("/health", get(health::health_handler))
         .route("/version", get(health::version_handler))
+        // 钱包签名登录（无需认证）
+        .nest(
+            "/api/v1/auth",
+            Router::new()
+                .route("/challenge", post(auth::challenge_handler))
+                .route("/login", post(auth::login_handler)),
+        )
+        // 账户事件订阅（WebSocket，JWT 通过 token 查询参数或 Header 认证）
+        .route("/api/v1/ws", get(ws::ws_handler))
         // 区块链查询（无需认证）
         .nest(
             "/api/v1/chain",
@@ -42,6 +63,10 @@ pub fn build_routes(state: AppState) -> Router {
                 .route("/daliuren", post(divination::daliuren_handler))
                 .route("/qimen", post(divination::qimen_handler))
                 .route("/tarot", post(divination::tarot_handler))
+                .route("/bazi", post(divination::bazi_handler))
+                .route("/meihua", post(divination::meihua_handler))
+                .route("/submit/prepare", post(divination::prepare_submit_handler))
+                .route("/submit", post(divination::submit_handler))
                 .route("/history", get(divination::divination_history_handler))
                 .layer(middleware::from_fn_with_state(
                     divination_limiter,
@@ -52,5 +77,15 @@ pub fn build_routes(state: AppState) -> Router {
                     auth_middleware,
                 )),
         )
+        // 通用限流（所有请求按 IP 计数，已认证请求同时按账户计数）
+        .layer(middleware::from_fn_with_state(
+            global_limiter,
+            rate_limit_middleware,
+        ))
+        // 可选认证（最外层，先于通用限流执行，使限流能按账户分桶；Token 无效不拦截）
+        .layer(middleware::from_fn_with_state(
+            state.config.clone(),
+            optional_auth_middleware,
+        ))
         .with_state(state)
 }
@@ -0,0 +1,155 @@
+// WebSocket 订阅路由 - 推送与认证账户相关的链上事件
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Query, State,
+    },
+    http::{header, HeaderMap},
+    response::Response,
+};
+use futures::StreamExt;
+use serde::Deserialize;
+use sp_core::crypto::{AccountId32, Ss58Codec};
+
+use crate::{
+    clients::ChainBlock,
+    middleware::verify_token,
+    models::{AccountFilter, ApiError, WsCommand, WsMessage},
+    AppState,
+};
+
+/// 断线重连时最多补发的区块数（约 1 小时）
+const MAX_REPLAY_BLOCKS: u64 = 600;
+
+/// 订阅参数
+#[derive(Debug, Deserialize)]
+pub struct WsQuery {
+    /// JWT（浏览器 WebSocket 无法设置 Header 时通过查询参数传递）
+    pub token: Option<String>,
+    /// 从该区块号开始补发（断线重连时传入最后收到的区块号 + 1）
+    pub from_block: Option<u64>,
+}
+
+/// 账户事件订阅入口
+pub async fn ws_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<WsQuery>,
+) -> Result<Response, ApiError> {
+    let header_token = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|h| h.to_str().ok())
+        .and_then(|h| h.strip_prefix("Bearer "))
+        .map(str::to_string);
+    let token = query
+        .token
+        .or(header_token)
+        .ok_or_else(|| ApiError::Unauthorized("Missing token".to_string()))?;
+
+    let claims = verify_token(&token, &state.config.auth.jwt_secret)?;
+    let account = AccountId32::from_ss58check(&claims.address)
+        .map_err(|e| ApiError::Unauthorized(format!("Invalid address in token: {:?}", e)))?;
+
+    tracing::info!("WebSocket 订阅 - 用户: {}", claims.address);
+
+    let from_block = query.from_block;
+    Ok(ws.on_upgrade(move |mut socket| async move {
+        let filter = AccountFilter::new(account.into());
+        if let Err(e) = run_subscription(&mut socket, &state, &claims.address, filter, from_block).await {
+            tracing::warn!("WebSocket 订阅中断 - 用户: {}, 原因: {}", claims.address, e);
+            let _ = send(&mut socket, &WsMessage::Error { message: e.to_string() }).await;
+        }
+    }))
+}
+
+/// 订阅主循环：推送最终确认区块中的相关事件，同时处理客户端指令
+async fn run_subscription(
+    socket: &mut WebSocket,
+    state: &AppState,
+    address: &str,
+    mut filter: AccountFilter,
+    from_block: Option<u64>,
+) -> Result<(), ApiError> {
+    let mut blocks = Box::pin(state.substrate.subscribe_finalized().await?);
+    let mut next_block = from_block;
+
+    send(
+        socket,
+        &WsMessage::Subscribed {
+            account: address.to_string(),
+            from_block,
+        },
+    )
+    .await?;
+
+    loop {
+        tokio::select! {
+            block = blocks.next() => {
+                let Some(block) = block else { return Ok(()) };
+                let block = block?;
+                let number = block.number() as u64;
+
+                // 补发断线期间（或订阅流跳过）的区块
+                if let Some(start) = next_block {
+                    let start = start.max(number.saturating_sub(MAX_REPLAY_BLOCKS));
+                    for n in start..number {
+                        if let Some(missed) = state.substrate.block_at_number(n).await? {
+                            push_block(socket, state, &mut filter, &missed).await?;
+                        }
+                    }
+                }
+
+                push_block(socket, state, &mut filter, &block).await?;
+                next_block = Some(number + 1);
+            }
+            msg = socket.recv() => {
+                match msg {
+                    Some(Ok(Message::Text(text))) => match serde_json::from_str::<WsCommand>(&text) {
+                        Ok(WsCommand::Watch { order_ids, escrow_ids, dispute_ids }) => {
+                            filter.watch(order_ids, escrow_ids, dispute_ids);
+                        }
+                        Ok(WsCommand::Ping) => send(socket, &WsMessage::Pong).await?,
+                        Err(e) => {
+                            send(socket, &WsMessage::Error { message: format!("Invalid command: {}", e) }).await?;
+                        }
+                    },
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return Ok(()),
+                    Some(Ok(_)) => {}
+                }
+            }
+        }
+    }
+}
+
+/// 推送单个区块中的相关事件，最后推送区块号用于客户端记录断点
+async fn push_block(
+    socket: &mut WebSocket,
+    state: &AppState,
+    filter: &mut AccountFilter,
+    block: &ChainBlock,
+) -> Result<(), ApiError> {
+    for event in state.substrate.block_events(block).await? {
+        if let Some(message) = filter.classify(&event) {
+            send(socket, &message).await?;
+        }
+    }
+
+    send(
+        socket,
+        &WsMessage::Block {
+            number: block.number() as u64,
+            hash: format!("0x{}", hex::encode(block.hash().0)),
+        },
+    )
+    .await
+}
+
+async fn send(socket: &mut WebSocket, message: &WsMessage) -> Result<(), ApiError> {
+    let text = serde_json::to_string(message)
+        .map_err(|e| ApiError::Internal(format!("Failed to serialize message: {}", e)))?;
+    socket
+        .send(Message::Text(text))
+        .await
+        .map_err(|e| ApiError::Internal(format!("WebSocket send failed: {}", e)))
+}
@@ -6,9 +6,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::config::DeepSeekConfig;
 use crate::blockchain::types::{DivinationType, InterpretationType};
+use crate::knowledge::bundle::LocalBundle;
 
 pub use deepseek::DeepSeekClient;
-pub use prompt_builder::PromptBuilder;
+pub use prompt_builder::{BundleVersions, PromptBuilder};
 
 /// AI生成的解读结果
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,6 +52,12 @@ pub struct Metadata {
     pub word_count: usize,
     pub reading_time_minutes: u32,
     pub confidence_score: Option<f32>,
+    /// 生成解读所用的知识库版本
+    #[serde(default)]
+    pub knowledge_version: Option<u32>,
+    /// 生成解读所用的 Prompt 模板版本
+    #[serde(default)]
+    pub prompt_template_version: Option<u32>,
 }
 
 /// AI服务
@@ -71,6 +78,16 @@ impl AiService {
         })
     }
 
+    /// 安装已通过链上校验的知识库与 Prompt 模板资料包
+    pub fn install_bundles(&mut self, knowledge: &LocalBundle, prompts: &LocalBundle) -> Result<()> {
+        self.prompt_builder.install_bundles(knowledge, prompts)
+    }
+
+    /// 当前资料包版本
+    pub fn bundle_versions(&self) -> BundleVersions {
+        self.prompt_builder.versions()
+    }
+
     /// 生成解读
     pub async fn generate_interpretation(
         &mut self,
@@ -78,7 +95,8 @@ impl AiService {
         interpretation_type: InterpretationType,
         divination_data: &serde_json::Value,
     ) -> Result<InterpretationContent> {
-        // 1. 构造Prompt
+        // 1. 构造Prompt（记录所用资料包版本）
+        let versions = self.prompt_builder.versions();
         let prompt = self.prompt_builder.build_prompt(
             divination_type,
             interpretation_type,
@@ -93,6 +111,7 @@ impl AiService {
             divination_type,
             interpretation_type,
             &raw_response,
+            versions,
         )?;
 
         Ok(content)
@@ -104,6 +123,7 @@ impl AiService {
         divination_type: DivinationType,
         interpretation_type: InterpretationType,
         raw_response: &str,
+        versions: BundleVersions,
     ) -> Result<InterpretationContent> {
         // 提取各个章节
         let sections = self.extract_sections(raw_response);
@@ -140,6 +160,8 @@ impl AiService {
                 word_count,
                 reading_time_minutes: reading_time,
                 confidence_score: Some(0.85),
+                knowledge_version: Some(versions.knowledge),
+                prompt_template_version: Some(versions.prompt_template),
             },
         })
     }
@@ -4,8 +4,18 @@ use std::path::Path;
 
 use crate::blockchain::types::{DivinationType, InterpretationType};
 use crate::error::OracleError;
+use crate::knowledge::bundle::LocalBundle;
 use crate::knowledge::BaziKnowledgeBase;
 
+/// 当前使用的资料包版本
+///
+/// 随解读结果一并上链，标识该解读由哪个知识库/模板版本生成
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BundleVersions {
+    pub knowledge: u32,
+    pub prompt_template: u32,
+}
+
 /// Prompt构造器
 ///
 /// 负责根据占卜类型和解读类型构造AI Prompt
@@ -14,6 +24,10 @@ pub struct PromptBuilder {
     template_cache: std::collections::HashMap<String, String>,
     /// 八字知识库
     knowledge_base: BaziKnowledgeBase,
+    /// 已校验的 Prompt 模板资料包（为空时从 prompts/ 目录读取）
+    prompt_bundle: Option<LocalBundle>,
+    /// 当前资料包版本
+    versions: BundleVersions,
 }
 
 impl PromptBuilder {
@@ -24,9 +38,34 @@ impl PromptBuilder {
         Ok(Self {
             template_cache: std::collections::HashMap::new(),
             knowledge_base: BaziKnowledgeBase::load()?,
+            prompt_bundle: None,
+            versions: BundleVersions::default(),
         })
     }
 
+    /// 安装已通过链上校验的资料包
+    ///
+    /// 调用方负责先用 `bundle::verify_bundle` 核对链上记录；
+    /// 新知识库解析失败时保持原资料包不变
+    pub fn install_bundles(&mut self, knowledge: &LocalBundle, prompts: &LocalBundle) -> Result<()> {
+        let knowledge_base = BaziKnowledgeBase::from_bundle(knowledge)?;
+
+        self.knowledge_base = knowledge_base;
+        self.prompt_bundle = Some(prompts.clone());
+        self.template_cache.clear();
+        self.versions = BundleVersions {
+            knowledge: knowledge.manifest.version,
+            prompt_template: prompts.manifest.version,
+        };
+
+        Ok(())
+    }
+
+    /// 当前资料包版本
+    pub fn versions(&self) -> BundleVersions {
+        self.versions
+    }
+
     /// 构造Prompt
     pub fn build_prompt(
         &mut self,
@@ -148,6 +187,18 @@ impl PromptBuilder {
             return Ok(template.clone());
         }
 
+        // 已安装模板资料包时只从资料包读取
+        if let Some(bundle) = &self.prompt_bundle {
+            let relative = path.trim_start_matches("prompts/");
+            let template = bundle
+                .contents
+                .get(relative)
+                .cloned()
+                .unwrap_or_else(|| self.get_default_template(path));
+            self.template_cache.insert(path.to_string(), template.clone());
+            return Ok(template);
+        }
+
         // 从文件加载
         if Path::new(path).exists() {
             let template = fs::read_to_string(path)
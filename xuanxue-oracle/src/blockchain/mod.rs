@@ -5,7 +5,8 @@ pub mod types;
 pub mod runtime;
 
 use anyhow::Result;
-use subxt::{OnlineClient, PolkadotConfig, tx::PairSigner};
+use codec::Decode;
+use subxt::{dynamic::Value, OnlineClient, PolkadotConfig, tx::PairSigner};
 use sp_core::sr25519::Pair;
 use sp_core::Pair as PairT;
 use tracing::{info, warn, error, debug};
@@ -15,6 +16,7 @@ use crate::ai::AiService;
 use crate::storage::IpfsClient;
 use crate::divination::DivinationDataFetcher;
 use crate::error::OracleError;
+use crate::knowledge::bundle::{verify_bundle, AnchoredBundle, BundleKind, LocalBundle};
 
 pub use events::*;
 // pub use extrinsics::*;
@@ -57,14 +59,21 @@ impl EventMonitor {
         // 初始化数据获取器
         let data_fetcher = DivinationDataFetcher::new(client.clone());
 
-        Ok(Self {
+        let mut monitor = Self {
             config,
             client,
             signer,
             ai_service,
             ipfs_client,
             data_fetcher,
-        })
+        };
+
+        // 加载链上已批准的资料包（未批准则拒绝启动）
+        let knowledge_version = monitor.config.knowledge.knowledge_version;
+        let prompt_version = monitor.config.knowledge.prompt_version;
+        monitor.load_bundles(knowledge_version, prompt_version).await?;
+
+        Ok(monitor)
     }
 
     /// 获取区块链端点
@@ -77,6 +86,111 @@ impl EventMonitor {
         self.signer.public()
     }
 
+    /// 查询链上资料包记录
+    async fn query_bundle(&self, kind: BundleKind, version: u32) -> Result<Option<AnchoredBundle>> {
+        let address = subxt::dynamic::storage(
+            "DivinationAi",
+            "KnowledgeBundles",
+            vec![
+                Value::unnamed_variant(kind.variant_name(), []),
+                Value::u128(version as u128),
+            ],
+        );
+
+        let fetched = self.client
+            .storage()
+            .at_latest()
+            .await
+            .map_err(|e| OracleError::Blockchain(format!("Failed to get storage: {}", e)))?
+            .fetch(&address)
+            .await
+            .map_err(|e| OracleError::Blockchain(format!("Failed to fetch bundle: {}", e)))?;
+
+        let Some(thunk) = fetched else {
+            return Ok(None);
+        };
+
+        let bundle = manual_types::KnowledgeBundle::decode(&mut thunk.encoded())
+            .map_err(|e| OracleError::Blockchain(format!("Failed to decode bundle: {}", e)))?;
+
+        Ok(Some(AnchoredBundle {
+            kind,
+            version: bundle.version,
+            cid: String::from_utf8_lossy(&bundle.cid).to_string(),
+            content_hash: bundle.content_hash,
+            approved: bundle.status == 1,
+        }))
+    }
+
+    /// 构建本地资料包并核对链上记录
+    async fn build_verified_bundle(&self, kind: BundleKind, version: u32) -> Result<LocalBundle> {
+        let dir = match kind {
+            BundleKind::KnowledgeBase => &self.config.knowledge.knowledge_dir,
+            BundleKind::PromptTemplate => &self.config.knowledge.prompts_dir,
+        };
+
+        let local = LocalBundle::build(kind, version, dir)?;
+        let anchored = self.query_bundle(kind, version).await?;
+
+        verify_bundle(&local, anchored.as_ref()).map_err(|reason| {
+            OracleError::Config(format!("{:?} v{}: {}", kind, version, reason))
+        })?;
+
+        if let Some(anchored) = anchored {
+            debug!("   {:?} v{} CID: {}", kind, version, anchored.cid);
+        }
+
+        Ok(local)
+    }
+
+    /// 加载指定版本的知识库与 Prompt 模板资料包
+    ///
+    /// 两个资料包都通过校验才会替换当前资料包，否则保持原状
+    async fn load_bundles(&mut self, knowledge_version: u32, prompt_version: u32) -> Result<()> {
+        let knowledge = self.build_verified_bundle(BundleKind::KnowledgeBase, knowledge_version).await?;
+        let prompts = self.build_verified_bundle(BundleKind::PromptTemplate, prompt_version).await?;
+
+        self.ai_service.install_bundles(&knowledge, &prompts)?;
+
+        info!(
+            "📚 Bundles loaded: knowledge v{} ({}), prompts v{} ({})",
+            knowledge_version,
+            knowledge.manifest.content_hash_hex(),
+            prompt_version,
+            prompts.manifest.content_hash_hex(),
+        );
+
+        Ok(())
+    }
+
+    /// 处理资料包批准事件：热加载新版本
+    async fn handle_bundle_approved(&mut self, event: &subxt::events::EventDetails<PolkadotConfig>) -> Result<()> {
+        let approved = manual_types::BundleApprovedEvent::decode(&mut event.field_bytes())
+            .map_err(|e| OracleError::Blockchain(format!("Failed to decode event: {}", e)))?;
+        let Some(kind) = BundleKind::from_u8(approved.kind) else {
+            return Ok(());
+        };
+
+        let current = self.ai_service.bundle_versions();
+        let (knowledge_version, prompt_version) = match kind {
+            BundleKind::KnowledgeBase => (approved.version, current.prompt_template),
+            BundleKind::PromptTemplate => (current.knowledge, approved.version),
+        };
+
+        // 只升级不回退
+        if knowledge_version < current.knowledge || prompt_version < current.prompt_template {
+            return Ok(());
+        }
+
+        info!("🔄 Bundle approved: {:?} v{}, reloading...", kind, approved.version);
+        if let Err(e) = self.load_bundles(knowledge_version, prompt_version).await {
+            warn!("⚠️  Hot reload refused: {}", e);
+            warn!("   Keeping knowledge v{}, prompts v{}", current.knowledge, current.prompt_template);
+        }
+
+        Ok(())
+    }
+
     /// 确保Oracle节点已注册
     pub async fn ensure_registered(&self) -> Result<()> {
         let _account_id = self.signer.public();
@@ -209,6 +323,11 @@ impl EventMonitor {
         let pallet_name = event.pallet_name();
         let event_name = event.variant_name();
 
+        // 资料包批准后热加载
+        if pallet_name == "DivinationAi" && event_name == "BundleApproved" {
+            return self.handle_bundle_approved(&event).await;
+        }
+
         // 只处理DivinationAi模块的InterpretationRequested事件
         if pallet_name == "DivinationAi" && event_name == "InterpretationRequested" {
             info!("🔔 Detected InterpretationRequested event");
@@ -303,14 +422,18 @@ impl EventMonitor {
         let content_cid = self.ipfs_client.upload_json(&interpretation).await?;
         info!("✅ Uploaded to IPFS: {}", content_cid);
 
-        // 7. 提交结果到链上
+        // 7. 提交结果到链上（引用生成时的资料包版本）
         info!("📤 Submitting result to blockchain...");
+        let knowledge_version = interpretation.metadata.knowledge_version.unwrap_or_default();
+        let prompt_template_version = interpretation.metadata.prompt_template_version.unwrap_or_default();
         self.submit_result(
             event.request_id,
             content_cid.clone(),
             None,
             "deepseek-chat-v2.5".to_string(),
             "zh-CN".to_string(),
+            knowledge_version,
+            prompt_template_version,
         ).await?;
         info!("✅ Result submitted for request #{}", event.request_id);
         info!("   CID: {}", content_cid);
@@ -359,6 +482,8 @@ impl EventMonitor {
         summary_cid: Option<String>,
         model_version: String,
         language: String,
+        knowledge_version: u32,
+        prompt_template_version: u32,
     ) -> Result<()> {
         debug!("Submitting submit_result transaction...");
 
@@ -368,6 +493,8 @@ impl EventMonitor {
             summary_cid: summary_cid.map(|s| s.as_bytes().to_vec()),
             model_version: model_version.as_bytes().to_vec(),
             language: language.as_bytes().to_vec(),
+            knowledge_version,
+            prompt_template_version,
         };
 
         // TODO: 实际的交易提交
@@ -379,6 +506,8 @@ impl EventMonitor {
         //         params.summary_cid,
         //         params.model_version,
         //         params.language,
+        //         params.knowledge_version,
+        //         params.prompt_template_version,
         //     );
         //
         // let signer = PairSigner::new(self.signer.clone());
@@ -389,7 +518,10 @@ impl EventMonitor {
         //     .wait_for_finalized_success()
         //     .await?;
 
-        debug!("Transaction submitted (mock): submit_result({}, {})", request_id, content_cid);
+        debug!(
+            "Transaction submitted (mock): submit_result({}, {}, kb v{}, prompts v{})",
+            request_id, content_cid, knowledge_version, prompt_template_version
+        );
         Ok(())
     }
 }
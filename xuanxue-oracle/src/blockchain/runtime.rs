@@ -57,6 +57,29 @@ pub mod manual_types {
         pub summary_cid: Option<Vec<u8>>,
         pub model_version: Vec<u8>,
         pub language: Vec<u8>,
+        pub knowledge_version: u32,
+        pub prompt_template_version: u32,
+    }
+
+    /// KnowledgeBundle (链上存储类型)
+    #[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+    pub struct KnowledgeBundle {
+        pub kind: u8,
+        pub version: u32,
+        pub cid: Vec<u8>,
+        pub content_hash: [u8; 32],
+        pub proposer: [u8; 32],
+        pub proposed_at: u32,
+        /// 0 = Proposed, 1 = Approved, 2 = Revoked
+        pub status: u8,
+        pub approved_at: Option<u32>,
+    }
+
+    /// DivinationAi pallet的BundleApproved事件
+    #[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+    pub struct BundleApprovedEvent {
+        pub kind: u8,
+        pub version: u32,
     }
 
     /// InterpretationRequest (链上存储类型)
@@ -11,6 +11,8 @@ pub struct Config {
     pub oracle: OracleConfig,
     pub cache: CacheConfig,
     pub logging: LoggingConfig,
+    #[serde(default)]
+    pub knowledge: KnowledgeConfig,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -49,6 +51,33 @@ pub struct CacheConfig {
     pub ttl_seconds: u64,
 }
 
+/// 知识库与 Prompt 模板资料包配置
+///
+/// 启动时按配置版本核对链上记录，未批准或哈希不一致则拒绝启动
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct KnowledgeConfig {
+    /// 知识库目录
+    pub knowledge_dir: String,
+    /// 知识库资料包版本
+    pub knowledge_version: u32,
+    /// Prompt 模板目录
+    pub prompts_dir: String,
+    /// Prompt 模板资料包版本
+    pub prompt_version: u32,
+}
+
+impl Default for KnowledgeConfig {
+    fn default() -> Self {
+        Self {
+            knowledge_dir: "knowledge".to_string(),
+            knowledge_version: 1,
+            prompts_dir: "prompts".to_string(),
+            prompt_version: 1,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct LoggingConfig {
     pub level: String,
@@ -0,0 +1,236 @@
+/// 资料包（内容寻址）
+///
+/// 知识库目录与 Prompt 模板目录分别打包为资料包：
+/// - 清单（manifest）按相对路径排序记录每个文件的 blake2-256 哈希
+/// - 清单哈希即资料包的内容哈希，与链上 `DivinationAi::KnowledgeBundles` 记录比对
+/// - 资料包整体（清单 + 文件内容）以 JSON 上传 IPFS，CID 由治理批准后锚定上链
+///
+/// 只有链上已批准、且内容哈希一致的资料包才允许加载。
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sp_core::hashing::blake2_256;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// 资料包类型（与 pallet-divination-ai 的 `BundleKind` 编码一致）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BundleKind {
+    KnowledgeBase = 0,
+    PromptTemplate = 1,
+}
+
+impl BundleKind {
+    /// 链上枚举变体名称
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            Self::KnowledgeBase => "KnowledgeBase",
+            Self::PromptTemplate => "PromptTemplate",
+        }
+    }
+
+    /// 从链上编码值解析
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::KnowledgeBase),
+            1 => Some(Self::PromptTemplate),
+            _ => None,
+        }
+    }
+}
+
+/// 资料包清单
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleManifest {
+    /// 资料包类型
+    pub kind: BundleKind,
+    /// 版本号
+    pub version: u32,
+    /// 相对路径 -> 文件 blake2-256 哈希（hex）
+    pub files: BTreeMap<String, String>,
+}
+
+impl BundleManifest {
+    /// 内容哈希
+    ///
+    /// 只覆盖文件列表，不含版本号：同一份内容换版本号重新提议时哈希不变，便于核对
+    pub fn content_hash(&self) -> [u8; 32] {
+        let encoded = serde_json::to_vec(&self.files).unwrap_or_default();
+        blake2_256(&encoded)
+    }
+
+    /// 内容哈希（hex，带 0x 前缀）
+    pub fn content_hash_hex(&self) -> String {
+        format!("0x{}", hex_encode(&self.content_hash()))
+    }
+}
+
+/// 本地资料包（清单 + 文件内容）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalBundle {
+    pub manifest: BundleManifest,
+    /// 相对路径 -> 文件内容
+    pub contents: BTreeMap<String, String>,
+}
+
+impl LocalBundle {
+    /// 从目录构建资料包
+    ///
+    /// 递归读取目录下所有文件（跳过隐藏文件），路径统一使用 `/` 分隔
+    pub fn build<P: AsRef<Path>>(kind: BundleKind, version: u32, dir: P) -> Result<Self> {
+        let dir = dir.as_ref();
+        let mut contents = BTreeMap::new();
+        Self::collect(dir, dir, &mut contents)
+            .with_context(|| format!("无法读取资料包目录: {}", dir.display()))?;
+
+        let files = contents
+            .iter()
+            .map(|(path, content)| (path.clone(), hex_encode(&blake2_256(content.as_bytes()))))
+            .collect();
+
+        Ok(Self {
+            manifest: BundleManifest { kind, version, files },
+            contents,
+        })
+    }
+
+    fn collect(root: &Path, dir: &Path, out: &mut BTreeMap<String, String>) -> Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let hidden = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .map(|n| n.starts_with('.'))
+                .unwrap_or(false);
+            if hidden {
+                continue;
+            }
+
+            if path.is_dir() {
+                Self::collect(root, &path, out)?;
+            } else {
+                let relative = path
+                    .strip_prefix(root)?
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                let content = fs::read_to_string(&path)
+                    .with_context(|| format!("无法读取文件: {}", path.display()))?;
+                out.insert(relative, content);
+            }
+        }
+        Ok(())
+    }
+
+    /// 内容哈希
+    pub fn content_hash(&self) -> [u8; 32] {
+        self.manifest.content_hash()
+    }
+}
+
+/// 链上资料包记录（解码自 `DivinationAi::KnowledgeBundles`）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnchoredBundle {
+    pub kind: BundleKind,
+    pub version: u32,
+    pub cid: String,
+    pub content_hash: [u8; 32],
+    pub approved: bool,
+}
+
+/// 资料包校验失败原因
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BundleRejection {
+    /// 链上没有该版本
+    NotAnchored,
+    /// 链上未批准或已撤销
+    NotApproved,
+    /// 本地内容与链上哈希不一致
+    HashMismatch { local: String, anchored: String },
+}
+
+impl std::fmt::Display for BundleRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotAnchored => write!(f, "资料包未在链上登记"),
+            Self::NotApproved => write!(f, "资料包未经治理批准或已撤销"),
+            Self::HashMismatch { local, anchored } => {
+                write!(f, "资料包内容哈希不一致: 本地 {} / 链上 {}", local, anchored)
+            }
+        }
+    }
+}
+
+/// 核对本地资料包与链上记录
+pub fn verify_bundle(
+    local: &LocalBundle,
+    anchored: Option<&AnchoredBundle>,
+) -> std::result::Result<(), BundleRejection> {
+    let anchored = anchored.ok_or(BundleRejection::NotAnchored)?;
+    if !anchored.approved {
+        return Err(BundleRejection::NotApproved);
+    }
+
+    let local_hash = local.content_hash();
+    if local_hash != anchored.content_hash {
+        return Err(BundleRejection::HashMismatch {
+            local: format!("0x{}", hex_encode(&local_hash)),
+            anchored: format!("0x{}", hex_encode(&anchored.content_hash)),
+        });
+    }
+
+    Ok(())
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anchored(local: &LocalBundle, approved: bool) -> AnchoredBundle {
+        AnchoredBundle {
+            kind: local.manifest.kind,
+            version: local.manifest.version,
+            cid: "QmBundle".to_string(),
+            content_hash: local.content_hash(),
+            approved,
+        }
+    }
+
+    #[test]
+    fn test_build_knowledge_bundle() {
+        let bundle = LocalBundle::build(BundleKind::KnowledgeBase, 1, "knowledge").unwrap();
+        assert!(bundle.manifest.files.contains_key("bazi/basics/tiangan.json"));
+
+        // 相同内容哈希稳定
+        let again = LocalBundle::build(BundleKind::KnowledgeBase, 2, "knowledge").unwrap();
+        assert_eq!(bundle.content_hash(), again.content_hash());
+    }
+
+    #[test]
+    fn test_verify_bundle() {
+        let bundle = LocalBundle::build(BundleKind::PromptTemplate, 1, "prompts").unwrap();
+
+        assert_eq!(verify_bundle(&bundle, None), Err(BundleRejection::NotAnchored));
+        assert_eq!(
+            verify_bundle(&bundle, Some(&anchored(&bundle, false))),
+            Err(BundleRejection::NotApproved)
+        );
+        assert!(verify_bundle(&bundle, Some(&anchored(&bundle, true))).is_ok());
+
+        let mut tampered = bundle.clone();
+        tampered
+            .manifest
+            .files
+            .insert("bazi/extra.txt".to_string(), "00".to_string());
+        assert!(matches!(
+            verify_bundle(&tampered, Some(&anchored(&bundle, true))),
+            Err(BundleRejection::HashMismatch { .. })
+        ));
+    }
+}
@@ -8,14 +8,22 @@
 /// - 用神调候
 /// - 解读规则
 ///
-/// 知识库以JSON格式存储，支持缓存和动态查询
+/// 知识库以JSON格式存储，支持缓存和动态查询。
+/// 生产环境通过 [`bundle`] 打包为内容寻址的资料包，链上批准后才允许加载。
 
-use anyhow::{Context, Result};
+pub mod bundle;
+
+use anyhow::{anyhow, Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use tracing::debug;
 
+use self::bundle::LocalBundle;
+
+/// 默认知识库目录
+pub const DEFAULT_KNOWLEDGE_DIR: &str = "knowledge";
+
 /// 天干信息结构
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct TianganInfo {
@@ -175,27 +183,56 @@ impl BaziKnowledgeBase {
     /// let kb = BaziKnowledgeBase::load()?;
     /// ```
     pub fn load() -> Result<Self> {
+        Self::load_from(DEFAULT_KNOWLEDGE_DIR)
+    }
+
+    /// 从指定目录加载知识库
+    pub fn load_from(dir: &str) -> Result<Self> {
+        Self::parse(|path| {
+            let full_path = format!("{}/{}", dir, path);
+            fs::read_to_string(&full_path).with_context(|| format!("无法读取文件: {}", full_path))
+        })
+    }
+
+    /// 从资料包加载知识库
+    ///
+    /// 直接使用资料包中已计算哈希的内容，保证加载的正是链上锚定的版本
+    pub fn from_bundle(bundle: &LocalBundle) -> Result<Self> {
+        Self::parse(|path| {
+            bundle
+                .contents
+                .get(path)
+                .cloned()
+                .ok_or_else(|| anyhow!("资料包缺少文件: {}", path))
+        })
+    }
+
+    /// 按相对路径读取并解析各知识库文件
+    fn parse<F>(read: F) -> Result<Self>
+    where
+        F: Fn(&str) -> Result<String>,
+    {
         debug!("开始加载八字知识库");
 
-        let tiangan_data = Self::load_json_map("knowledge/bazi/basics/tiangan.json")
+        let tiangan_data = Self::load_json_map(&read, "bazi/basics/tiangan.json")
             .context("加载天干知识库失败")?;
 
-        let dizhi_data = Self::load_json_map("knowledge/bazi/basics/dizhi.json")
+        let dizhi_data = Self::load_json_map(&read, "bazi/basics/dizhi.json")
             .context("加载地支知识库失败")?;
 
-        let wuxing_data = Self::load_json_value("knowledge/bazi/basics/wuxing.json")
+        let wuxing_data = Self::load_json_value(&read, "bazi/basics/wuxing.json")
             .context("加载五行知识库失败")?;
 
-        let shishen_data = Self::load_json_value("knowledge/bazi/basics/shishen.json")
+        let shishen_data = Self::load_json_value(&read, "bazi/basics/shishen.json")
             .context("加载十神知识库失败")?;
 
-        let pattern_data = Self::load_json_map("knowledge/bazi/patterns/zhengge.json")
+        let pattern_data = Self::load_json_map(&read, "bazi/patterns/zhengge.json")
             .context("加载格局知识库失败")?;
 
-        let tiaohuo_data = Self::load_json_value("knowledge/bazi/yongshen/tiaohuo.json")
+        let tiaohuo_data = Self::load_json_value(&read, "bazi/yongshen/tiaohuo.json")
             .context("加载调候用神知识库失败")?;
 
-        let interpretation_rules = Self::load_json_value("knowledge/bazi/interpretations/core_rules.json")
+        let interpretation_rules = Self::load_json_value(&read, "bazi/interpretations/core_rules.json")
             .context("加载解读规则知识库失败")?;
 
         debug!("八字知识库加载完成");
@@ -212,12 +249,12 @@ impl BaziKnowledgeBase {
     }
 
     /// 加载JSON文件并解析为HashMap
-    fn load_json_map<T>(path: &str) -> Result<HashMap<String, T>>
+    fn load_json_map<T, F>(read: &F, path: &str) -> Result<HashMap<String, T>>
     where
         T: for<'de> Deserialize<'de>,
+        F: Fn(&str) -> Result<String>,
     {
-        let content = fs::read_to_string(path)
-            .with_context(|| format!("无法读取文件: {}", path))?;
+        let content = read(path)?;
 
         let map: HashMap<String, T> = serde_json::from_str(&content)
             .with_context(|| format!("无法解析JSON: {}", path))?;
@@ -226,9 +263,11 @@ impl BaziKnowledgeBase {
     }
 
     /// 加载JSON文件为Value
-    fn load_json_value(path: &str) -> Result<serde_json::Value> {
-        let content = fs::read_to_string(path)
-            .with_context(|| format!("无法读取文件: {}", path))?;
+    fn load_json_value<F>(read: &F, path: &str) -> Result<serde_json::Value>
+    where
+        F: Fn(&str) -> Result<String>,
+    {
+        let content = read(path)?;
 
         let value: serde_json::Value = serde_json::from_str(&content)
             .with_context(|| format!("无法解析JSON: {}", path))?;
@@ -433,4 +472,16 @@ mod tests {
         assert!(enriched.contains("日主特性"), "应该包含日主特性");
         assert!(enriched.contains("格局理论"), "应该包含格局理论");
     }
+
+    #[test]
+    fn test_load_from_bundle() {
+        let bundle = LocalBundle::build(
+            bundle::BundleKind::KnowledgeBase,
+            1,
+            DEFAULT_KNOWLEDGE_DIR,
+        )
+        .unwrap();
+        let kb = BaziKnowledgeBase::from_bundle(&bundle).unwrap();
+        assert_eq!(kb.tiangan_data.len(), 10);
+    }
 }
@@ -17,6 +17,8 @@ use tracing_subscriber;
 
 use crate::config::Config;
 use crate::blockchain::EventMonitor;
+use crate::knowledge::bundle::{BundleKind, LocalBundle};
+use crate::storage::IpfsClient;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -31,6 +33,11 @@ async fn main() -> Result<()> {
     let config = Config::load()?;
     info!("✅ Configuration loaded");
 
+    // `xuanxue-oracle bundle`: 打包并上传资料包，输出提议参数
+    if std::env::args().nth(1).as_deref() == Some("bundle") {
+        return publish_bundles(&config).await;
+    }
+
     // 初始化区块链连接
     let mut event_monitor = EventMonitor::new(config).await?;
     info!("✅ Connected to blockchain at {}", event_monitor.endpoint());
@@ -45,3 +52,26 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+/// 打包知识库与 Prompt 模板并上传 IPFS
+///
+/// 输出的 CID 与内容哈希用于 `DivinationAi::propose_bundle`，治理批准后 Oracle 才会加载
+async fn publish_bundles(config: &Config) -> Result<()> {
+    let ipfs_client = IpfsClient::new(config.ipfs.clone())?;
+    let targets = [
+        (BundleKind::KnowledgeBase, config.knowledge.knowledge_version, &config.knowledge.knowledge_dir),
+        (BundleKind::PromptTemplate, config.knowledge.prompt_version, &config.knowledge.prompts_dir),
+    ];
+
+    for (kind, version, dir) in targets {
+        let bundle = LocalBundle::build(kind, version, dir)?;
+        let cid = ipfs_client.upload_json(&bundle).await?;
+
+        info!("📦 {:?} v{} ({} files)", kind, version, bundle.manifest.files.len());
+        info!("   CID: {}", cid);
+        info!("   Content hash: {}", bundle.manifest.content_hash_hex());
+        info!("   propose_bundle({}, {}, \"{}\", {})", kind.variant_name(), version, cid, bundle.manifest.content_hash_hex());
+    }
+
+    Ok(())
+}